@@ -83,6 +83,34 @@ impl<NI: Idx> Afforest<NI> {
         Self(v.into_boxed_slice())
     }
 
+    /// Creates a disjoint-set struct that joins all elements with the same
+    /// label, where element `i` has the label `labels[i]`, e.g., the
+    /// component ids of a previous WCC run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a label is not less than the number of elements.
+    pub(crate) fn from_components(labels: &[NI]) -> Self {
+        // Parents must not point to higher ids, so each set is rooted at its
+        // lowest element.
+        let mut roots = vec![None; labels.len()];
+        let parents = labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                assert!(
+                    label.index() < labels.len(),
+                    "component id {label:?} is out of bounds for {} nodes",
+                    labels.len()
+                );
+                let root = *roots[label.index()].get_or_insert(NI::new(i));
+                Atomic::new(root)
+            })
+            .collect::<Vec<_>>();
+
+        Self(parents.into_boxed_slice())
+    }
+
     fn parent(&self, i: NI) -> NI {
         self.0[i.index()].load(Ordering::SeqCst)
     }
@@ -0,0 +1,229 @@
+//! Betweenness centrality.
+//!
+//! The betweenness of a node is the number of shortest paths between all
+//! other pairs of nodes that pass through the node, where each pair with
+//! multiple shortest paths contributes the fraction of its paths that visit
+//! the node. Nodes with a high betweenness connect otherwise loosely
+//! connected parts of the graph.
+//!
+//! The scores are computed with the algorithm of Brandes [1], which is shared
+//! with [`edge_betweenness`](crate::edge_betweenness). Sources are processed
+//! in parallel and, on large graphs, a uniform sample of source nodes gives
+//! an unbiased estimate of the scores [2].
+//!
+//! Edges are unweighted, i.e., every edge has a length of `1`.
+//!
+//! [1] Ulrik Brandes:
+//! "A Faster Algorithm for Betweenness Centrality",
+//! Journal of Mathematical Sociology, 2001
+//!
+//! [2] Ulrik Brandes, Christian Pich:
+//! "Centrality Estimation in Large Networks",
+//! International Journal of Bifurcation and Chaos, 2007
+
+use rayon::prelude::*;
+
+use crate::edge_betweenness::{betweenness_inner, Scores};
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct BetweennessConfig {
+    /// If set, only shortest paths from the given number of source nodes,
+    /// chosen uniformly at random, are counted and the scores are scaled up
+    /// accordingly.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub sample_size: Option<usize>,
+
+    /// The seed for choosing source nodes at random.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = BetweennessConfig::DEFAULT_SEED))]
+    pub seed: u64,
+
+    /// If set, the given number of nodes with the highest
+    /// betweenness is returned by [`TopK::top_nodes`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub top_k: Option<usize>,
+}
+
+impl Default for BetweennessConfig {
+    fn default() -> Self {
+        Self {
+            sample_size: None,
+            seed: Self::DEFAULT_SEED,
+            top_k: None,
+        }
+    }
+}
+
+impl TopK for BetweennessConfig {
+    fn top_k(&self) -> Option<usize> {
+        self.top_k
+    }
+}
+
+impl BetweennessConfig {
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(sample_size: Option<usize>, seed: u64) -> Self {
+        Self {
+            sample_size,
+            seed,
+            top_k: None,
+        }
+    }
+}
+
+/// Computes the betweenness of every node of the directed graph, following
+/// outgoing edges.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 3)])
+///     .build();
+///
+/// let scores = betweenness(&graph, BetweennessConfig::default());
+///
+/// // The paths 0 -> 2 and 0 -> 3 pass through node 1.
+/// assert_eq!(scores, vec![0.0, 2.0, 2.0, 0.0]);
+/// ```
+pub fn betweenness<NI, G>(graph: &G, config: BetweennessConfig) -> Vec<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighbors<NI> + Sync,
+{
+    betweenness_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`betweenness`] and reports the number of processed source nodes of
+/// the `betweenness` task to the given tracker.
+pub fn betweenness_with_progress<NI, G, T>(
+    graph: &G,
+    config: BetweennessConfig,
+    tracker: &T,
+) -> Vec<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    betweenness_inner(
+        graph.node_count().index(),
+        Scores::Nodes,
+        config.sample_size,
+        config.seed,
+        |node| graph.out_neighbors(node).map(|&target| (target, 0)),
+        tracker,
+    )
+}
+
+/// Computes the betweenness of every node of the undirected graph.
+///
+/// Each unordered pair of nodes is counted once.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// // two triangles connected by the edge (2, 3)
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+///     .build();
+///
+/// let scores = betweenness_undirected(&graph, BetweennessConfig::default());
+///
+/// // The 6 pairs of nodes in different triangles, excluding 2 and 3,
+/// // are connected via both 2 and 3.
+/// assert_eq!(scores, vec![0.0, 0.0, 6.0, 6.0, 0.0, 0.0]);
+/// ```
+pub fn betweenness_undirected<NI, G>(graph: &G, config: BetweennessConfig) -> Vec<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    betweenness_undirected_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`betweenness_undirected`] and reports the number of processed
+/// source nodes of the `betweenness` task to the given tracker.
+pub fn betweenness_undirected_with_progress<NI, G, T>(
+    graph: &G,
+    config: BetweennessConfig,
+    tracker: &T,
+) -> Vec<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let mut scores = betweenness_inner(
+        graph.node_count().index(),
+        Scores::Nodes,
+        config.sample_size,
+        config.seed,
+        |node| graph.neighbors(node).map(|&target| (target, 0)),
+        tracker,
+    );
+
+    // Both end nodes of a pair are sources, so every pair is counted twice.
+    scores.par_iter_mut().for_each(|score| *score /= 2.0);
+
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, DirectedCsrGraph, GraphBuilder, UndirectedCsrGraph};
+
+    #[test]
+    fn test_betweenness_path() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 3)])
+            .build();
+
+        let scores = betweenness_undirected(&graph, BetweennessConfig::default());
+
+        assert_eq!(scores, vec![0.0, 2.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_betweenness_splits_shortest_paths() {
+        // a diamond, node 3 is reached via 1 and 2
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (1, 3), (2, 3)])
+            .build();
+
+        let scores = betweenness(&graph, BetweennessConfig::default());
+
+        assert_eq!(scores, vec![0.0, 0.5, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_betweenness_sampling() {
+        let edges = (0..100_u32)
+            .map(|node| (node, (node + 1) % 100))
+            .collect::<Vec<_>>();
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(edges)
+            .build();
+
+        let exact = betweenness_undirected(&graph, BetweennessConfig::default());
+        let sampled = betweenness_undirected(&graph, BetweennessConfig::new(Some(50), 7));
+
+        // On a cycle, every source contributes the same total dependency.
+        let total = |scores: &[f64]| scores.iter().sum::<f64>();
+        assert!((total(&exact) - total(&sampled)).abs() < 1E-6);
+        assert_ne!(exact, sampled);
+    }
+}
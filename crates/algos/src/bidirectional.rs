@@ -0,0 +1,455 @@
+//! Shortest paths between a single pair of nodes.
+//!
+//! A bidirectional search runs two searches at once, one forward from the
+//! source node and one backward from the target node, and stops as soon as
+//! they have met on a shortest path. Each search only needs to cover about
+//! half of the distance, which usually visits a small fraction of the nodes
+//! that a single-source search visits before reaching the target. Only the
+//! visited nodes are stored, so the cost of a query does not depend on the
+//! size of the graph.
+//!
+//! [`bidirectional_bfs`] counts the edges on a path and expands the smaller
+//! of the two search frontiers one level at a time.
+//! [`bidirectional_dijkstra`] sums the edge weights, which must not be
+//! negative, and settles the closer of the two next nodes at a time.
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 2), (2, 3), (0, 4), (4, 3)])
+//!     .build();
+//!
+//! let path = bidirectional_bfs(&graph, 0, 3).unwrap();
+//!
+//! assert_eq!(path.distance, 2.0);
+//! assert_eq!(path.path, vec![0, 4, 3]);
+//! ```
+
+use graph_builder::time::Instant;
+use std::{collections::BinaryHeap, hash::Hash};
+
+use ahash::AHashMap;
+use log::info;
+
+use crate::{prelude::*, shortest_path::QueueEntry};
+
+/// The nodes that a path can move to from a given node and the nodes that it
+/// can arrive from, ignoring edge values.
+///
+/// For directed graphs, paths follow outgoing edges. For undirected graphs,
+/// both are the neighbors of the node.
+pub trait BidirectionalNeighbors<NI: Idx> {
+    fn forward_neighbors(&self, node: NI) -> &[NI];
+
+    fn backward_neighbors(&self, node: NI) -> &[NI];
+}
+
+impl<NI: Idx, NV> BidirectionalNeighbors<NI> for DirectedCsrGraph<NI, NV> {
+    fn forward_neighbors(&self, node: NI) -> &[NI] {
+        self.out_neighbors(node).as_slice()
+    }
+
+    fn backward_neighbors(&self, node: NI) -> &[NI] {
+        self.in_neighbors(node).as_slice()
+    }
+}
+
+impl<NI: Idx, NV> BidirectionalNeighbors<NI> for UndirectedCsrGraph<NI, NV> {
+    fn forward_neighbors(&self, node: NI) -> &[NI] {
+        self.neighbors(node).as_slice()
+    }
+
+    fn backward_neighbors(&self, node: NI) -> &[NI] {
+        self.neighbors(node).as_slice()
+    }
+}
+
+/// The nodes that a shortest path can arrive at a given node from, together
+/// with the weight of the connecting edge.
+///
+/// For directed graphs, these are the incoming edges. For undirected graphs,
+/// they are the same as the [`WeightedNeighbors`].
+pub trait WeightedBackwardNeighbors<NI: Idx>: WeightedNeighbors<NI> {
+    fn weighted_backward_neighbors(&self, node: NI) -> &[Target<NI, f32>];
+}
+
+impl<NI: Idx, NV> WeightedBackwardNeighbors<NI> for DirectedCsrGraph<NI, NV, f32> {
+    fn weighted_backward_neighbors(&self, node: NI) -> &[Target<NI, f32>] {
+        self.in_neighbors_with_values(node).as_slice()
+    }
+}
+
+impl<NI: Idx, NV> WeightedBackwardNeighbors<NI> for UndirectedCsrGraph<NI, NV, f32> {
+    fn weighted_backward_neighbors(&self, node: NI) -> &[Target<NI, f32>] {
+        self.neighbors_with_values(node).as_slice()
+    }
+}
+
+/// A shortest path between a source and a target node.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PairPath<NI> {
+    /// The number of edges on the path or, for weighted searches, the sum of
+    /// their weights.
+    pub distance: f32,
+    /// The nodes on the path from the source to the target node, including
+    /// both.
+    pub path: Vec<NI>,
+    /// The number of nodes that the forward and the backward search have
+    /// visited together.
+    pub visited_nodes: usize,
+}
+
+/// Computes a path with the fewest edges from `source` to `target`, or
+/// `None` if `target` is not reachable from `source`.
+pub fn bidirectional_bfs<NI, G>(graph: &G, source: NI, target: NI) -> Option<PairPath<NI>>
+where
+    NI: Idx + Hash,
+    G: Graph<NI> + BidirectionalNeighbors<NI>,
+{
+    let start = Instant::now();
+
+    let mut forward = Search::new(source);
+    let mut backward = Search::new(target);
+    let mut forward_frontier = vec![source];
+    let mut backward_frontier = vec![target];
+    let mut meeting = (source == target).then_some((0.0, source));
+
+    while meeting.is_none() && !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        // All meetings found while expanding a whole level are candidates for
+        // a shortest path, the closest of them is one.
+        if forward_frontier.len() <= backward_frontier.len() {
+            forward_frontier =
+                forward.expand_level(&forward_frontier, &backward, &mut meeting, |n| {
+                    graph.forward_neighbors(n)
+                });
+        } else {
+            backward_frontier =
+                backward.expand_level(&backward_frontier, &forward, &mut meeting, |n| {
+                    graph.backward_neighbors(n)
+                });
+        }
+    }
+
+    let path = PairPath::new(meeting?, &forward, &backward);
+    info!("Computed bidirectional BFS in {:?}", start.elapsed());
+    Some(path)
+}
+
+/// Computes a shortest path from `source` to `target`, or `None` if `target`
+/// is not reachable from `source`.
+pub fn bidirectional_dijkstra<NI, G>(graph: &G, source: NI, target: NI) -> Option<PairPath<NI>>
+where
+    NI: Idx + Hash,
+    G: Graph<NI> + WeightedBackwardNeighbors<NI>,
+{
+    let start = Instant::now();
+
+    let mut forward = Search::new(source);
+    let mut backward = Search::new(target);
+    let mut meeting = (source == target).then_some((0.0, source));
+
+    while let (Some(forward_min), Some(backward_min)) =
+        (forward.min_priority(), backward.min_priority())
+    {
+        // Any path that is shorter than the best meeting so far would have
+        // to pass nodes that neither search has settled yet.
+        if meeting.is_some_and(|(distance, _)| forward_min + backward_min >= distance) {
+            break;
+        }
+
+        if forward_min <= backward_min {
+            forward.settle_next(&backward, &mut meeting, |n| graph.weighted_neighbors(n));
+        } else {
+            backward.settle_next(&forward, &mut meeting, |n| {
+                graph.weighted_backward_neighbors(n)
+            });
+        }
+    }
+
+    let path = PairPath::new(meeting?, &forward, &backward);
+    info!("Computed bidirectional Dijkstra in {:?}", start.elapsed());
+    Some(path)
+}
+
+impl<NI: Idx + Hash> PairPath<NI> {
+    fn new((distance, meeting): (f32, NI), forward: &Search<NI>, backward: &Search<NI>) -> Self {
+        let mut path = forward.path_to_origin(meeting);
+        path.reverse();
+        path.extend(backward.path_to_origin(meeting).into_iter().skip(1));
+
+        Self {
+            distance,
+            path,
+            visited_nodes: forward.visited.len() + backward.visited.len(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Visit<NI> {
+    distance: f32,
+    predecessor: NI,
+    settled: bool,
+}
+
+/// One direction of a bidirectional search, starting at its origin node.
+struct Search<NI> {
+    origin: NI,
+    visited: AHashMap<NI, Visit<NI>>,
+    queue: BinaryHeap<QueueEntry<NI>>,
+}
+
+impl<NI: Idx + Hash> Search<NI> {
+    fn new(origin: NI) -> Self {
+        let mut visited = AHashMap::new();
+        visited.insert(
+            origin,
+            Visit {
+                distance: 0.0,
+                predecessor: origin,
+                settled: false,
+            },
+        );
+        let queue = BinaryHeap::from([QueueEntry {
+            priority: 0.0,
+            node: origin,
+        }]);
+
+        Self {
+            origin,
+            visited,
+            queue,
+        }
+    }
+
+    fn distance(&self, node: NI) -> Option<f32> {
+        self.visited.get(&node).map(|visit| visit.distance)
+    }
+
+    /// Returns a lower bound for the distance of the next node to settle.
+    fn min_priority(&self) -> Option<f32> {
+        self.queue.peek().map(|entry| entry.priority)
+    }
+
+    /// Visits all unvisited neighbors of the frontier and returns them as
+    /// the next frontier.
+    fn expand_level<'a, F>(
+        &mut self,
+        frontier: &[NI],
+        other: &Self,
+        meeting: &mut Option<(f32, NI)>,
+        neighbors: F,
+    ) -> Vec<NI>
+    where
+        NI: 'a,
+        F: Fn(NI) -> &'a [NI],
+    {
+        let mut next_frontier = Vec::new();
+
+        for &node in frontier {
+            let distance = self.visited[&node].distance + 1.0;
+            for &neighbor in neighbors(node) {
+                if self.visited.contains_key(&neighbor) {
+                    continue;
+                }
+                self.visited.insert(
+                    neighbor,
+                    Visit {
+                        distance,
+                        predecessor: node,
+                        settled: true,
+                    },
+                );
+                next_frontier.push(neighbor);
+                update_meeting(meeting, neighbor, distance, other);
+            }
+        }
+
+        next_frontier
+    }
+
+    /// Settles the closest unsettled node and relaxes its edges.
+    fn settle_next<'a, F>(&mut self, other: &Self, meeting: &mut Option<(f32, NI)>, neighbors: F)
+    where
+        NI: 'a,
+        F: Fn(NI) -> &'a [Target<NI, f32>],
+    {
+        let Some(QueueEntry { node, .. }) = self.queue.pop() else {
+            return;
+        };
+        let visit = self
+            .visited
+            .get_mut(&node)
+            .expect("queued nodes are visited");
+        if std::mem::replace(&mut visit.settled, true) {
+            return;
+        }
+
+        let distance = visit.distance;
+        for Target { target, value } in neighbors(node) {
+            let candidate = distance + value;
+            let visit = self.visited.entry(*target).or_insert(Visit {
+                distance: f32::INFINITY,
+                predecessor: node,
+                settled: false,
+            });
+            if !visit.settled && candidate < visit.distance {
+                visit.distance = candidate;
+                visit.predecessor = node;
+                self.queue.push(QueueEntry {
+                    priority: candidate,
+                    node: *target,
+                });
+                update_meeting(meeting, *target, candidate, other);
+            }
+        }
+    }
+
+    /// Returns the nodes from `node` back to the origin, including both.
+    fn path_to_origin(&self, mut node: NI) -> Vec<NI> {
+        let mut path = vec![node];
+        while node != self.origin {
+            node = self.visited[&node].predecessor;
+            path.push(node);
+        }
+        path
+    }
+}
+
+/// Records `node` as the best meeting if the other search has visited it and
+/// the combined distance is shorter than the best meeting so far.
+fn update_meeting<NI: Idx + Hash>(
+    meeting: &mut Option<(f32, NI)>,
+    node: NI,
+    distance: f32,
+    other: &Search<NI>,
+) {
+    if let Some(other_distance) = other.distance(node) {
+        let total = distance + other_distance;
+        if meeting.map_or(true, |(best, _)| total < best) {
+            *meeting = Some((total, node));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    fn graph() -> DirectedCsrGraph<u32, (), f32> {
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges_with_values(vec![
+                (0, 1, 4.0),
+                (0, 2, 2.0),
+                (1, 2, 5.0),
+                (1, 3, 10.0),
+                (2, 4, 3.0),
+                (3, 5, 11.0),
+                (4, 3, 4.0),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra() {
+        let path = bidirectional_dijkstra(&graph(), 0, 5).unwrap();
+
+        assert_eq!(path.distance, 20.0);
+        assert_eq!(path.path, vec![0, 2, 4, 3, 5]);
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra_unreachable() {
+        let graph = graph();
+
+        assert_eq!(bidirectional_dijkstra(&graph, 3, 0), None);
+        assert_eq!(bidirectional_dijkstra(&graph, 3, 3).unwrap().path, vec![3]);
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra_undirected() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 5.0)])
+            .build();
+
+        let path = bidirectional_dijkstra(&graph, 2, 0).unwrap();
+
+        assert_eq!(path.distance, 2.0);
+        assert_eq!(path.path, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_bidirectional_bfs() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (0, 5),
+                (5, 4),
+                (4, 0),
+                (6, 0),
+            ])
+            .build();
+
+        let path = bidirectional_bfs(&graph, 0, 4).unwrap();
+        assert_eq!(path.distance, 2.0);
+        assert_eq!(path.path, vec![0, 5, 4]);
+
+        let path = bidirectional_bfs(&graph, 1, 5).unwrap();
+        assert_eq!(path.path, vec![1, 2, 3, 4, 0, 5]);
+
+        assert_eq!(bidirectional_bfs(&graph, 2, 6), None);
+    }
+
+    #[test]
+    fn test_matches_dijkstra() {
+        let edges = erdos_renyi::<u32>(ErdosRenyiConfig {
+            node_count: 60,
+            edge_probability: 0.05,
+            directed: true,
+            seed: 7,
+        });
+        let weighted: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(
+                edges
+                    .iter()
+                    .map(|&(s, t)| (s, t, ((s * 7 + t * 13) % 10) as f32)),
+            )
+            .build();
+        let unweighted: UndirectedCsrGraph<u32> = GraphBuilder::new().edges(edges.clone()).build();
+        let unit: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(edges.iter().map(|&(s, t)| (s, t, 1.0)))
+            .build();
+
+        for source in 0..weighted.node_count() {
+            let expected = dijkstra(&weighted, source);
+            let expected_hops = dijkstra(&unit, source);
+            for target in 0..weighted.node_count() {
+                let path = bidirectional_dijkstra(&weighted, source, target);
+                assert_eq!(
+                    path.map(|p| p.distance),
+                    expected
+                        .is_reachable(target)
+                        .then_some(expected.distance(target))
+                );
+
+                let path = bidirectional_bfs(&unweighted, source, target);
+                assert_eq!(
+                    path.as_ref().map(|p| p.distance),
+                    expected_hops
+                        .is_reachable(target)
+                        .then_some(expected_hops.distance(target))
+                );
+                if let Some(path) = path {
+                    assert_eq!(path.path.len() as f32, path.distance + 1.0);
+                }
+            }
+        }
+    }
+}
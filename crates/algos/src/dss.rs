@@ -219,6 +219,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
     fn test_union_parallel() {
         let barrier = Arc::new(Barrier::new(2));
         let dss = Arc::new(DisjointSetStruct::new(1000));
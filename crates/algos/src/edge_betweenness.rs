@@ -0,0 +1,459 @@
+//! Edge betweenness centrality.
+//!
+//! The betweenness of an edge is the number of shortest paths between all
+//! pairs of nodes that pass through the edge, where each pair with multiple
+//! shortest paths contributes the fraction of its paths that use the edge.
+//! Edges with a high betweenness connect otherwise loosely connected parts of
+//! the graph, e.g., bridges between communities. Repeatedly removing the edge
+//! with the highest betweenness reveals these communities (Girvan-Newman).
+//!
+//! The scores are computed with the algorithm of Brandes [1], which runs a
+//! BFS from every source node and accumulates the dependencies of the source
+//! on the edges of the BFS DAG in reverse BFS order [2]. Sources are processed
+//! in parallel. On large graphs, a uniform sample of source nodes gives an
+//! unbiased estimate of the scores at a fraction of the cost [3].
+//!
+//! Edges are unweighted, i.e., every edge has a length of `1`.
+//!
+//! [1] Ulrik Brandes:
+//! "A Faster Algorithm for Betweenness Centrality",
+//! Journal of Mathematical Sociology, 2001
+//!
+//! [2] Ulrik Brandes:
+//! "On Variants of Shortest-Path Betweenness Centrality and their Generic Computation",
+//! Social Networks, 2008
+//!
+//! [3] Ulrik Brandes, Christian Pich:
+//! "Centrality Estimation in Large Networks",
+//! International Journal of Bifurcation and Chaos, 2007
+
+use graph_builder::time::Instant;
+
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+/// The number of source nodes that are processed between two progress
+/// updates.
+const SOURCES_PER_BATCH: usize = 1024;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct EdgeBetweennessConfig {
+    /// If set, only shortest paths from the given number of source nodes,
+    /// chosen uniformly at random, are counted and the scores are scaled up
+    /// accordingly.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub sample_size: Option<usize>,
+
+    /// The seed for choosing source nodes at random.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = EdgeBetweennessConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for EdgeBetweennessConfig {
+    fn default() -> Self {
+        Self {
+            sample_size: None,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl EdgeBetweennessConfig {
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(sample_size: Option<usize>, seed: u64) -> Self {
+        Self { sample_size, seed }
+    }
+}
+
+/// Computes the betweenness of every edge of the directed graph, following
+/// outgoing edges.
+///
+/// The scores are keyed by the edge ids of [`DirectedEdgeIds`].
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 3)])
+///     .build();
+///
+/// let scores = edge_betweenness(&graph, EdgeBetweennessConfig::default());
+///
+/// // The paths 0 -> 2, 1 -> 2, 0 -> 3 and 1 -> 3 use the edge (1, 2).
+/// assert_eq!(scores[graph.out_edge_id(1, 2).unwrap()], 4.0);
+/// assert_eq!(scores[graph.out_edge_id(2, 3).unwrap()], 3.0);
+/// ```
+pub fn edge_betweenness<NI, G>(graph: &G, config: EdgeBetweennessConfig) -> EdgeValues<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighbors<NI> + DirectedEdgeIds<NI> + Sync,
+{
+    edge_betweenness_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`edge_betweenness`] and reports the number of processed source
+/// nodes of the `edge_betweenness` task to the given tracker.
+pub fn edge_betweenness_with_progress<NI, G, T>(
+    graph: &G,
+    config: EdgeBetweennessConfig,
+    tracker: &T,
+) -> EdgeValues<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighbors<NI> + DirectedEdgeIds<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let scores = betweenness_inner(
+        graph.node_count().index(),
+        Scores::Edges(graph.edge_id_count()),
+        config.sample_size,
+        config.seed,
+        |node| {
+            graph
+                .out_neighbors(node)
+                .copied()
+                .zip(graph.out_edge_ids(node))
+        },
+        tracker,
+    );
+
+    EdgeValues::from(scores)
+}
+
+/// Computes the betweenness of every edge of the undirected graph.
+///
+/// The scores are keyed by the edge ids of [`UndirectedEdgeIds`]. Both ids
+/// of an edge carry the same score, which counts each unordered pair of
+/// nodes once. Parallel edges share the paths between their end nodes
+/// evenly.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// // two triangles connected by the edge (2, 3)
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+///     .build();
+///
+/// let scores = edge_betweenness_undirected(&graph, EdgeBetweennessConfig::default());
+///
+/// // All 9 pairs of nodes in different triangles are connected via (2, 3).
+/// assert_eq!(scores[graph.edge_id(2, 3).unwrap()], 9.0);
+/// assert_eq!(scores[graph.edge_id(3, 2).unwrap()], 9.0);
+/// assert_eq!(scores[graph.edge_id(0, 1).unwrap()], 1.0);
+/// ```
+pub fn edge_betweenness_undirected<NI, G>(
+    graph: &G,
+    config: EdgeBetweennessConfig,
+) -> EdgeValues<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + UndirectedEdgeIds<NI> + Sync,
+{
+    edge_betweenness_undirected_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`edge_betweenness_undirected`] and reports the number of processed
+/// source nodes of the `edge_betweenness` task to the given tracker.
+pub fn edge_betweenness_undirected_with_progress<NI, G, T>(
+    graph: &G,
+    config: EdgeBetweennessConfig,
+    tracker: &T,
+) -> EdgeValues<f64>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + UndirectedEdgeIds<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let node_count = graph.node_count().index();
+    let mut scores = betweenness_inner(
+        node_count,
+        Scores::Edges(graph.edge_id_count()),
+        config.sample_size,
+        config.seed,
+        |node| graph.neighbors(node).copied().zip(graph.edge_ids(node)),
+        tracker,
+    );
+
+    // Each direction of an edge only received the dependencies of the
+    // sources on its side. Summing up both directions counts every pair of
+    // nodes twice, so the sum is divided by the number of ids, which also
+    // splits the score among parallel edges.
+    let mut edges = (0..node_count)
+        .into_par_iter()
+        .flat_map_iter(|u| {
+            let u = NI::new(u);
+            graph
+                .neighbors(u)
+                .zip(graph.edge_ids(u))
+                .map(move |(&v, id)| (NI::min(u, v), NI::max(u, v), id))
+        })
+        .collect::<Vec<_>>();
+    edges.par_sort_unstable();
+
+    let mut start = 0;
+    while start < edges.len() {
+        let (u, v, _) = edges[start];
+        let end = start
+            + edges[start..]
+                .iter()
+                .take_while(|(s, t, _)| *s == u && *t == v)
+                .count();
+        let group = &edges[start..end];
+        let score = group.iter().map(|(_, _, id)| scores[*id]).sum::<f64>() / group.len() as f64;
+        group.iter().for_each(|(_, _, id)| scores[*id] = score);
+        start = end;
+    }
+
+    EdgeValues::from(scores)
+}
+
+/// Determines whether the dependencies of the source nodes are accumulated
+/// on the edges or on the nodes of the graph.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Scores {
+    /// One score per edge id.
+    Edges(usize),
+    /// One score per node.
+    Nodes,
+}
+
+impl Scores {
+    fn count(self, node_count: usize) -> usize {
+        match self {
+            Scores::Edges(edge_id_count) => edge_id_count,
+            Scores::Nodes => node_count,
+        }
+    }
+}
+
+/// Runs the algorithm of Brandes from all or a sample of source nodes.
+///
+/// `neighbors` yields the neighbors of a node together with the ids of the
+/// connecting edges. Edge ids are ignored if node scores are computed.
+pub(crate) fn betweenness_inner<NI, F, I, T>(
+    node_count: usize,
+    kind: Scores,
+    sample_size: Option<usize>,
+    seed: u64,
+    neighbors: F,
+    tracker: &T,
+) -> Vec<f64>
+where
+    NI: Idx,
+    F: Fn(NI) -> I + Sync,
+    I: Iterator<Item = (NI, usize)>,
+    T: ProgressTracker + ?Sized,
+{
+    let start = Instant::now();
+
+    let mut sources = (0..node_count).map(NI::new).collect::<Vec<_>>();
+    let mut scale = 1.0;
+    if let Some(sample_size) = sample_size.filter(|&size| size < node_count) {
+        WyRand::new_seed(seed).shuffle(&mut sources);
+        sources.truncate(sample_size);
+        scale = node_count as f64 / usize::max(sample_size, 1) as f64;
+    }
+
+    let task = match kind {
+        Scores::Edges(_) => "edge_betweenness",
+        Scores::Nodes => "betweenness",
+    };
+    let mut scores = vec![0.0; kind.count(node_count)];
+
+    for (batch, chunk) in sources.chunks(SOURCES_PER_BATCH).enumerate() {
+        let batch_scores = chunk
+            .par_iter()
+            .fold(
+                || Dependencies::new(node_count, kind),
+                |mut dependencies, &source| {
+                    dependencies.accumulate(source, &neighbors);
+                    dependencies
+                },
+            )
+            .map(|dependencies| dependencies.scores)
+            .reduce_with(|mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            });
+
+        if let Some(batch_scores) = batch_scores {
+            scores
+                .par_iter_mut()
+                .zip(batch_scores)
+                .for_each(|(score, batch_score)| *score += batch_score);
+        }
+
+        let processed = usize::min((batch + 1) * SOURCES_PER_BATCH, sources.len());
+        tracker.progress(task, processed, Some(sources.len()));
+        if tracker.is_cancelled() {
+            break;
+        }
+    }
+
+    if scale != 1.0 {
+        scores.par_iter_mut().for_each(|score| *score *= scale);
+    }
+
+    info!(
+        "Computed {task} from {} source nodes in {:?}",
+        sources.len(),
+        start.elapsed()
+    );
+
+    scores
+}
+
+/// The state of a single thread, which is reset after each source node.
+struct Dependencies<NI> {
+    distances: Vec<isize>,
+    path_counts: Vec<f64>,
+    dependencies: Vec<f64>,
+    order: Vec<NI>,
+    kind: Scores,
+    scores: Vec<f64>,
+}
+
+impl<NI: Idx> Dependencies<NI> {
+    fn new(node_count: usize, kind: Scores) -> Self {
+        Self {
+            distances: vec![-1; node_count],
+            path_counts: vec![0.0; node_count],
+            dependencies: vec![0.0; node_count],
+            order: Vec::new(),
+            kind,
+            scores: vec![0.0; kind.count(node_count)],
+        }
+    }
+
+    fn accumulate<F, I>(&mut self, source: NI, neighbors: &F)
+    where
+        F: Fn(NI) -> I,
+        I: Iterator<Item = (NI, usize)>,
+    {
+        self.distances[source.index()] = 0;
+        self.path_counts[source.index()] = 1.0;
+        self.order.push(source);
+
+        // BFS that counts the shortest paths to each node. The order vector
+        // doubles as the queue.
+        let mut next = 0;
+        while let Some(&v) = self.order.get(next) {
+            next += 1;
+            let distance = self.distances[v.index()];
+            for (w, _) in neighbors(v) {
+                if self.distances[w.index()] < 0 {
+                    self.distances[w.index()] = distance + 1;
+                    self.order.push(w);
+                }
+                if self.distances[w.index()] == distance + 1 {
+                    self.path_counts[w.index()] += self.path_counts[v.index()];
+                }
+            }
+        }
+
+        // Nodes are visited in reverse BFS order, so the dependencies of all
+        // successors are final when a node is visited.
+        for &v in self.order.iter().rev() {
+            let distance = self.distances[v.index()];
+            let path_count = self.path_counts[v.index()];
+            for (w, id) in neighbors(v) {
+                if self.distances[w.index()] == distance + 1 {
+                    let dependency = path_count / self.path_counts[w.index()]
+                        * (1.0 + self.dependencies[w.index()]);
+                    if let Scores::Edges(_) = self.kind {
+                        self.scores[id] += dependency;
+                    }
+                    self.dependencies[v.index()] += dependency;
+                }
+            }
+            // The dependency of the source on `v` is final once all
+            // successors of `v` have been visited.
+            if matches!(self.kind, Scores::Nodes) && v != source {
+                self.scores[v.index()] += self.dependencies[v.index()];
+            }
+        }
+
+        for v in self.order.drain(..) {
+            self.distances[v.index()] = -1;
+            self.path_counts[v.index()] = 0.0;
+            self.dependencies[v.index()] = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, DirectedCsrGraph, GraphBuilder, UndirectedCsrGraph};
+
+    #[test]
+    fn test_edge_betweenness_path() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 3)])
+            .build();
+
+        let scores = edge_betweenness_undirected(&graph, EdgeBetweennessConfig::default());
+
+        assert_eq!(scores.as_slice(), &[3.0, 3.0, 4.0, 4.0, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_edge_betweenness_splits_shortest_paths() {
+        // a diamond, node 3 is reached via 1 and 2
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (1, 3), (2, 3)])
+            .build();
+
+        let scores = edge_betweenness(&graph, EdgeBetweennessConfig::default());
+
+        assert_eq!(scores.as_slice(), &[1.5, 1.5, 1.5, 1.5]);
+    }
+
+    #[test]
+    fn test_edge_betweenness_parallel_edges() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 1), (1, 2)])
+            .build();
+
+        let scores = edge_betweenness_undirected(&graph, EdgeBetweennessConfig::default());
+
+        // The pairs (0, 1) and (0, 2) are split among the parallel edges.
+        assert_eq!(scores[graph.edge_id(0, 1).unwrap()], 1.0);
+        assert_eq!(scores[graph.edge_id(1, 2).unwrap()], 2.0);
+        assert_eq!(scores.as_slice().iter().sum::<f64>(), 8.0);
+    }
+
+    #[test]
+    fn test_edge_betweenness_sampling() {
+        let edges = (0..100_u32)
+            .map(|node| (node, (node + 1) % 100))
+            .collect::<Vec<_>>();
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(edges)
+            .build();
+
+        let exact = edge_betweenness_undirected(&graph, EdgeBetweennessConfig::default());
+        let sampled = edge_betweenness_undirected(&graph, EdgeBetweennessConfig::new(Some(50), 7));
+
+        // On a cycle, every source contributes the same total dependency.
+        let total = |scores: &EdgeValues<f64>| scores.as_slice().iter().sum::<f64>();
+        assert!((total(&exact) - total(&sampled)).abs() < 1E-6);
+        assert_ne!(exact, sampled);
+    }
+}
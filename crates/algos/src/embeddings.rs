@@ -0,0 +1,42 @@
+//! Node embeddings, i.e., dense vectors that represent the position of each
+//! node in the graph, computed by [`fast_rp`](crate::fast_rp::fast_rp) or
+//! [`node2vec`](crate::node2vec::node2vec).
+
+/// An embedding vector of the same dimension for each node.
+///
+/// The vectors are stored in a single row-major buffer, where the vector of
+/// node `u` starts at `u * dimension`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Embeddings {
+    dimension: usize,
+    values: Vec<f32>,
+}
+
+impl Embeddings {
+    pub(crate) fn new(dimension: usize, values: Vec<f32>) -> Self {
+        assert!(dimension > 0, "embedding dimension must be positive");
+        assert_eq!(values.len() % dimension, 0);
+        Self { dimension, values }
+    }
+
+    /// Returns the number of values per node.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.values.len() / self.dimension
+    }
+
+    /// Returns the embedding vector of the given node.
+    pub fn embedding(&self, node: usize) -> &[f32] {
+        &self.values[node * self.dimension..(node + 1) * self.dimension]
+    }
+
+    /// Returns the row-major buffer of all embedding vectors.
+    pub fn into_vec(self) -> Vec<f32> {
+        self.values
+    }
+}
@@ -0,0 +1,256 @@
+//! Fast random projection (FastRP) node embeddings.
+//!
+//! FastRP [1] assigns a sparse random vector to each node and iteratively
+//! averages the vectors of neighboring nodes. The embedding of a node is the
+//! sum of the normalized intermediate vectors of all iterations, so that the
+//! `k`-th iteration contributes information about the `k`-hop neighborhood.
+//!
+//! The initial vectors follow the very sparse random projection by Li et al.,
+//! where each value is `sqrt(3)` or `-sqrt(3)` with a probability of `1/6`
+//! each and `0` otherwise. The vector of a node only depends on the seed and
+//! the node id, which makes the embeddings reproducible.
+//!
+//! [1] Haochen Chen, Syed Fahad Sultan, Yingtao Tian, Muhao Chen, Steven Skiena:
+//! "Fast and Accurate Network Embeddings via Very Sparse Random Projection",
+//! CIKM 2019
+
+use graph_builder::time::Instant;
+
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::embeddings::Embeddings;
+use crate::prelude::*;
+use crate::random_walk::{derive_seed, WalkNeighbors};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct FastRpConfig {
+    /// The number of values per node embedding.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = FastRpConfig::DEFAULT_EMBEDDING_DIMENSION))]
+    pub embedding_dimension: usize,
+
+    /// The number of propagation steps, i.e., the
+    /// largest neighborhood that is taken into account.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = FastRpConfig::DEFAULT_ITERATIONS))]
+    pub iterations: usize,
+
+    /// The initial vector of each node is scaled by its
+    /// degree to the power of this value. Negative values
+    /// reduce the influence of high-degree nodes.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = FastRpConfig::DEFAULT_NORMALIZATION_STRENGTH))]
+    pub normalization_strength: f32,
+
+    /// The seed for the initial random vectors.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = FastRpConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for FastRpConfig {
+    fn default() -> Self {
+        Self {
+            embedding_dimension: Self::DEFAULT_EMBEDDING_DIMENSION,
+            iterations: Self::DEFAULT_ITERATIONS,
+            normalization_strength: Self::DEFAULT_NORMALIZATION_STRENGTH,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl FastRpConfig {
+    pub const DEFAULT_EMBEDDING_DIMENSION: usize = 128;
+    pub const DEFAULT_ITERATIONS: usize = 3;
+    pub const DEFAULT_NORMALIZATION_STRENGTH: f32 = 0.0;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(embedding_dimension: usize, iterations: usize) -> Self {
+        Self {
+            embedding_dimension,
+            iterations,
+            ..Self::default()
+        }
+    }
+}
+
+/// Computes FastRP embeddings for all nodes of the given graph.
+///
+/// For directed graphs, the vectors are propagated along outgoing edges,
+/// i.e., a node is embedded based on the nodes it can reach.
+///
+/// # Panics
+///
+/// Panics if `config.embedding_dimension` is zero.
+pub fn fast_rp<NI, G>(graph: &G, config: FastRpConfig) -> Embeddings
+where
+    NI: Idx,
+    G: Graph<NI> + WalkNeighbors<NI> + Sync,
+{
+    fast_rp_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`fast_rp`] and reports each finished iteration of the `fast_rp`
+/// task to the given tracker.
+///
+/// # Panics
+///
+/// Panics if `config.embedding_dimension` is zero.
+pub fn fast_rp_with_progress<NI, G, T>(graph: &G, config: FastRpConfig, tracker: &T) -> Embeddings
+where
+    NI: Idx,
+    G: Graph<NI> + WalkNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    assert!(
+        config.embedding_dimension > 0,
+        "embedding dimension must be positive"
+    );
+
+    let start = Instant::now();
+    let node_count = graph.node_count().index();
+    let dimension = config.embedding_dimension;
+
+    let mut current = vec![0.0_f32; node_count * dimension];
+    current
+        .par_chunks_mut(dimension)
+        .enumerate()
+        .for_each(|(node, vector)| {
+            let degree = graph.walk_neighbors(NI::new(node)).len() as f32;
+            // Nodes without neighbors keep an unscaled vector, so that the
+            // nodes pointing to them still get a non-zero embedding.
+            let scale = if degree == 0.0 {
+                1.0
+            } else {
+                degree.powf(config.normalization_strength)
+            };
+            initial_vector(config.seed, node, scale, vector);
+        });
+
+    let mut embeddings = vec![0.0_f32; node_count * dimension];
+    let mut next = vec![0.0_f32; node_count * dimension];
+
+    for iteration in 0..config.iterations {
+        let iteration_start = Instant::now();
+
+        next.par_chunks_mut(dimension)
+            .zip(embeddings.par_chunks_mut(dimension))
+            .enumerate()
+            .for_each(|(node, (vector, embedding))| {
+                vector.fill(0.0);
+                let neighbors = graph.walk_neighbors(NI::new(node));
+                for neighbor in neighbors {
+                    let offset = neighbor.index() * dimension;
+                    vector
+                        .iter_mut()
+                        .zip(&current[offset..offset + dimension])
+                        .for_each(|(value, neighbor_value)| *value += neighbor_value);
+                }
+
+                let norm = vector.iter().map(|value| value * value).sum::<f32>().sqrt();
+                if norm > 0.0 {
+                    vector.iter_mut().for_each(|value| *value /= norm);
+                }
+
+                embedding
+                    .iter_mut()
+                    .zip(vector.iter())
+                    .for_each(|(embedding, value)| *embedding += value);
+            });
+
+        std::mem::swap(&mut current, &mut next);
+        tracker.progress("fast_rp", iteration + 1, Some(config.iterations));
+
+        info!(
+            "Finished iteration {} in {:?}",
+            iteration,
+            iteration_start.elapsed()
+        );
+
+        if tracker.is_cancelled() {
+            break;
+        }
+    }
+
+    info!(
+        "Computed FastRP embeddings with dimension {} in {:?}",
+        dimension,
+        start.elapsed()
+    );
+
+    Embeddings::new(dimension, embeddings)
+}
+
+fn initial_vector(seed: u64, node: usize, scale: f32, vector: &mut [f32]) {
+    let value = 3.0_f32.sqrt() * scale;
+    let mut rng = WyRand::new_seed(derive_seed(seed, node as u64));
+    for entry in vector.iter_mut() {
+        *entry = match rng.generate_range(0_u8..6) {
+            0 => value,
+            1 => -value,
+            _ => 0.0,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, DirectedCsrGraph, GraphBuilder, UndirectedCsrGraph};
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        let dot = a.iter().zip(b).map(|(a, b)| a * b).sum::<f32>();
+        let norm_a = a.iter().map(|a| a * a).sum::<f32>().sqrt();
+        let norm_b = b.iter().map(|b| b * b).sum::<f32>().sqrt();
+        dot / (norm_a * norm_b)
+    }
+
+    #[test]
+    fn test_fast_rp_shape_and_reproducibility() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 3)])
+            .build();
+
+        let config = FastRpConfig::new(16, 2);
+        let embeddings = fast_rp(&graph, config);
+
+        assert_eq!(embeddings.dimension(), 16);
+        assert_eq!(embeddings.node_count(), 4);
+        assert_eq!(embeddings, fast_rp(&graph, config));
+        assert_ne!(
+            embeddings,
+            fast_rp(&graph, FastRpConfig { seed: 7, ..config })
+        );
+    }
+
+    #[test]
+    fn test_fast_rp_similar_neighborhoods() {
+        // 0 and 1 share all their neighbors, 4 has none in common with them
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 2), (0, 3), (1, 2), (1, 3), (4, 5), (4, 6)])
+            .build();
+
+        let embeddings = fast_rp(&graph, FastRpConfig::new(64, 1));
+
+        let similar = cosine(embeddings.embedding(0), embeddings.embedding(1));
+        let different = cosine(embeddings.embedding(0), embeddings.embedding(4));
+
+        assert!((similar - 1.0).abs() < 1E-6);
+        assert!(different < similar);
+    }
+
+    #[test]
+    fn test_fast_rp_node_without_out_neighbors() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2)])
+            .build();
+
+        let embeddings = fast_rp(&graph, FastRpConfig::new(64, 3));
+
+        assert!(embeddings.embedding(2).iter().all(|value| *value == 0.0));
+        assert!(embeddings.embedding(1).iter().any(|value| *value != 0.0));
+    }
+}
@@ -0,0 +1,506 @@
+//! Data structures that hold the nodes an algorithm works on next.
+//!
+//! Traversal algorithms like BFS, delta-stepping or Pregel process the graph
+//! in rounds, where each round visits the nodes of a frontier and produces
+//! the frontier of the next round. This module provides the frontiers that
+//! the algorithms of this crate use, so that user-written algorithms can
+//! share them:
+//!
+//! * [`AtomicBitSet`] is a set of flags that threads can set concurrently,
+//!   e.g., to mark nodes as visited exactly once.
+//! * [`DenseFrontier`] stores one bit per node of the graph. It is the better
+//!   choice if a large fraction of the nodes is active, e.g., in the middle
+//!   rounds of a BFS or the first supersteps of a Pregel computation.
+//! * [`SparseFrontier`] stores the ids of the active nodes. It is the better
+//!   choice if only a few nodes are active.
+//! * [`BucketQueue`] groups nodes by priority into buckets, e.g., by their
+//!   tentative distance in delta-stepping.
+//!
+//! ```
+//! use graph::prelude::*;
+//! use rayon::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)])
+//!     .build();
+//!
+//! // A level-synchronous, parallel BFS from node 0.
+//! let visited = AtomicBitSet::new(graph.node_count() as usize);
+//! visited.insert(0);
+//! let mut frontier = SparseFrontier::from(vec![0]);
+//! let mut levels = 0;
+//!
+//! while !frontier.is_empty() {
+//!     frontier = frontier
+//!         .par_iter()
+//!         .flat_map_iter(|&node| graph.out_neighbors(node).copied())
+//!         .filter(|&neighbor| visited.insert(neighbor as usize))
+//!         .collect();
+//!     levels += 1;
+//! }
+//!
+//! assert_eq!(levels, 4);
+//! assert_eq!(visited.count(), 5);
+//! ```
+
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use rayon::prelude::*;
+
+use crate::prelude::Idx;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A fixed-size set of flags that can be set concurrently.
+///
+/// Each flag takes a single bit. [`insert`](Self::insert) atomically sets a
+/// flag and returns whether it was unset before, so that among multiple
+/// threads that insert the same index, exactly one succeeds.
+#[derive(Debug)]
+pub struct AtomicBitSet {
+    words: Box<[AtomicU64]>,
+    capacity: usize,
+}
+
+impl AtomicBitSet {
+    /// Creates a set for the indices `0..capacity` with all flags unset.
+    pub fn new(capacity: usize) -> Self {
+        let words = (0..(capacity + BITS - 1) / BITS)
+            .map(|_| AtomicU64::new(0))
+            .collect();
+
+        Self { words, capacity }
+    }
+
+    /// Creates a set for the indices `0..capacity` with all flags set.
+    pub fn full(capacity: usize) -> Self {
+        let words = (0..(capacity + BITS - 1) / BITS)
+            .map(|word| {
+                let bits = usize::min(capacity - word * BITS, BITS);
+                AtomicU64::new(u64::MAX >> (BITS - bits))
+            })
+            .collect();
+
+        Self { words, capacity }
+    }
+
+    /// Returns the number of flags of the set.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the flag at the given index and returns `true` if it was unset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not smaller than the capacity.
+    #[inline]
+    pub fn insert(&self, index: usize) -> bool {
+        assert!(index < self.capacity, "index out of bounds");
+        let mask = 1 << (index % BITS);
+        self.words[index / BITS].fetch_or(mask, Ordering::AcqRel) & mask == 0
+    }
+
+    /// Unsets the flag at the given index and returns `true` if it was set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not smaller than the capacity.
+    #[inline]
+    pub fn remove(&self, index: usize) -> bool {
+        assert!(index < self.capacity, "index out of bounds");
+        let mask = 1 << (index % BITS);
+        self.words[index / BITS].fetch_and(!mask, Ordering::AcqRel) & mask != 0
+    }
+
+    /// Returns `true` if the flag at the given index is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not smaller than the capacity.
+    #[inline]
+    pub fn contains(&self, index: usize) -> bool {
+        assert!(index < self.capacity, "index out of bounds");
+        self.words[index / BITS].load(Ordering::Acquire) & (1 << (index % BITS)) != 0
+    }
+
+    /// Returns the number of set flags.
+    pub fn count(&self) -> usize {
+        self.words
+            .par_iter()
+            .map(|word| word.load(Ordering::Acquire).count_ones() as usize)
+            .sum()
+    }
+
+    /// Unsets all flags.
+    pub fn clear(&mut self) {
+        self.words
+            .par_iter_mut()
+            .for_each(|word| *word.get_mut() = 0);
+    }
+
+    /// Returns the indices of all set flags in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(word, bits)| Ones::new(word, bits.load(Ordering::Acquire)))
+    }
+
+    /// Returns the indices of all set flags in parallel.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = Ones> + '_ {
+        self.words
+            .par_iter()
+            .enumerate()
+            .map(|(word, bits)| Ones::new(word, bits.load(Ordering::Acquire)))
+    }
+}
+
+/// The indices of the set flags of a single word of an [`AtomicBitSet`].
+#[derive(Clone, Debug)]
+pub struct Ones {
+    offset: usize,
+    bits: u64,
+}
+
+impl Ones {
+    fn new(word: usize, bits: u64) -> Self {
+        Self {
+            offset: word * BITS,
+            bits,
+        }
+    }
+}
+
+impl Iterator for Ones {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let bit = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some(self.offset + bit)
+    }
+}
+
+/// A frontier that stores a bit for each node of the graph.
+///
+/// Nodes can be inserted concurrently, which makes it suitable for rounds in
+/// which many nodes become active. Iterating the frontier takes time linear
+/// in the number of nodes of the graph, independent of the number of active
+/// nodes.
+#[derive(Debug)]
+pub struct DenseFrontier<NI> {
+    bits: AtomicBitSet,
+    _node: PhantomData<NI>,
+}
+
+impl<NI: Idx> DenseFrontier<NI> {
+    /// Creates an empty frontier for a graph with the given node count.
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            bits: AtomicBitSet::new(node_count),
+            _node: PhantomData,
+        }
+    }
+
+    /// Creates a frontier that contains all nodes of a graph with the given
+    /// node count.
+    pub fn full(node_count: usize) -> Self {
+        Self {
+            bits: AtomicBitSet::full(node_count),
+            _node: PhantomData,
+        }
+    }
+
+    /// Adds the node to the frontier and returns `true` if it was not
+    /// contained before.
+    #[inline]
+    pub fn insert(&self, node: NI) -> bool {
+        self.bits.insert(node.index())
+    }
+
+    /// Returns `true` if the frontier contains the node.
+    #[inline]
+    pub fn contains(&self, node: NI) -> bool {
+        self.bits.contains(node.index())
+    }
+
+    /// Returns the number of nodes in the frontier.
+    pub fn len(&self) -> usize {
+        self.bits.count()
+    }
+
+    /// Returns `true` if the frontier contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.bits
+            .words
+            .par_iter()
+            .all(|word| word.load(Ordering::Acquire) == 0)
+    }
+
+    /// Removes all nodes from the frontier.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// Returns the nodes of the frontier in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = NI> + '_ {
+        self.bits.iter().map(NI::new)
+    }
+
+    /// Returns the nodes of the frontier in parallel.
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = NI> + '_ {
+        self.bits.par_iter().flat_map_iter(|ones| ones.map(NI::new))
+    }
+
+    /// Converts the frontier into a sparse frontier with the same nodes in
+    /// ascending order.
+    pub fn to_sparse(&self) -> SparseFrontier<NI> {
+        self.bits
+            .par_iter()
+            .flat_map_iter(|ones| ones.map(NI::new))
+            .collect()
+    }
+}
+
+/// A frontier that stores the ids of its nodes.
+///
+/// Its memory and the time to iterate it are linear in the number of active
+/// nodes, which makes it suitable for rounds in which only a few nodes are
+/// active. A node may be contained multiple times, unless the nodes are
+/// filtered when the frontier is built, e.g., with an [`AtomicBitSet`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SparseFrontier<NI> {
+    nodes: Vec<NI>,
+}
+
+impl<NI: Idx> SparseFrontier<NI> {
+    /// Creates an empty frontier.
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    /// Adds the node to the frontier.
+    pub fn push(&mut self, node: NI) {
+        self.nodes.push(node);
+    }
+
+    /// Returns the number of nodes in the frontier.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the frontier contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the nodes of the frontier.
+    pub fn as_slice(&self) -> &[NI] {
+        &self.nodes
+    }
+
+    /// Returns the nodes of the frontier in parallel.
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, NI> {
+        self.nodes.par_iter()
+    }
+
+    /// Converts the frontier into a dense frontier for a graph with the
+    /// given node count.
+    pub fn to_dense(&self, node_count: usize) -> DenseFrontier<NI> {
+        let frontier = DenseFrontier::new(node_count);
+        self.nodes.par_iter().for_each(|&node| {
+            frontier.insert(node);
+        });
+        frontier
+    }
+
+    /// Returns the nodes of the frontier.
+    pub fn into_vec(self) -> Vec<NI> {
+        self.nodes
+    }
+}
+
+impl<NI> From<Vec<NI>> for SparseFrontier<NI> {
+    fn from(nodes: Vec<NI>) -> Self {
+        Self { nodes }
+    }
+}
+
+impl<NI> FromIterator<NI> for SparseFrontier<NI> {
+    fn from_iter<I: IntoIterator<Item = NI>>(iter: I) -> Self {
+        Self {
+            nodes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<NI: Send> FromParallelIterator<NI> for SparseFrontier<NI> {
+    fn from_par_iter<I: IntoParallelIterator<Item = NI>>(par_iter: I) -> Self {
+        Self {
+            nodes: par_iter.into_par_iter().collect(),
+        }
+    }
+}
+
+/// A queue that groups nodes into buckets of ascending priority.
+///
+/// Delta-stepping, for example, puts a node into bucket `i` if its tentative
+/// distance lies in `[i * delta, (i + 1) * delta)` and processes the buckets
+/// in ascending order. The queue is not synchronized, so parallel algorithms
+/// keep one queue per thread and merge the buckets of the same priority.
+#[derive(Clone, Debug)]
+pub struct BucketQueue<NI> {
+    buckets: Vec<Vec<NI>>,
+}
+
+impl<NI: Idx> Default for BucketQueue<NI> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<NI: Idx> BucketQueue<NI> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self {
+            buckets: Vec::new(),
+        }
+    }
+
+    /// Adds the node to the given bucket.
+    pub fn push(&mut self, bucket: usize, node: NI) {
+        if bucket >= self.buckets.len() {
+            self.buckets.resize_with(bucket + 1, Vec::new);
+        }
+        self.buckets[bucket].push(node);
+    }
+
+    /// Returns the nodes of the given bucket.
+    pub fn bucket(&self, bucket: usize) -> &[NI] {
+        self.buckets.get(bucket).map_or(&[], Vec::as_slice)
+    }
+
+    /// Removes the nodes of the given bucket and returns them.
+    pub fn take(&mut self, bucket: usize) -> Vec<NI> {
+        self.buckets
+            .get_mut(bucket)
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Removes the nodes of the given bucket, but keeps its memory for
+    /// nodes that are added later.
+    pub fn clear(&mut self, bucket: usize) {
+        if let Some(bucket) = self.buckets.get_mut(bucket) {
+            bucket.clear();
+        }
+    }
+
+    /// Returns the first bucket that is not empty, starting from the given
+    /// bucket, or `None` if all of these buckets are empty.
+    pub fn next_non_empty(&self, from: usize) -> Option<usize> {
+        (from..self.buckets.len()).find(|&bucket| !self.buckets[bucket].is_empty())
+    }
+
+    /// Returns `true` if all buckets are empty.
+    pub fn is_empty(&self) -> bool {
+        self.next_non_empty(0).is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn atomic_bit_set_insert_and_remove() {
+        let set = AtomicBitSet::new(130);
+
+        assert!(set.insert(0));
+        assert!(set.insert(129));
+        assert!(!set.insert(129));
+        assert!(set.contains(129));
+        assert!(!set.contains(64));
+        assert_eq!(set.count(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 129]);
+
+        assert!(set.remove(0));
+        assert!(!set.remove(0));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![129]);
+    }
+
+    #[test]
+    fn atomic_bit_set_concurrent_insert() {
+        let set = AtomicBitSet::new(1000);
+
+        let inserted = (0..10_000)
+            .into_par_iter()
+            .filter(|i| set.insert(i % 1000))
+            .count();
+
+        assert_eq!(inserted, 1000);
+        assert_eq!(set.count(), 1000);
+    }
+
+    #[test]
+    fn atomic_bit_set_full() {
+        let mut set = AtomicBitSet::full(70);
+
+        assert_eq!(set.count(), 70);
+        assert_eq!(set.iter().last(), Some(69));
+
+        set.clear();
+        assert_eq!(set.count(), 0);
+
+        assert_eq!(AtomicBitSet::full(64).count(), 64);
+        assert_eq!(AtomicBitSet::full(0).count(), 0);
+    }
+
+    #[test]
+    fn dense_and_sparse_frontiers() {
+        let dense = DenseFrontier::<u32>::new(100);
+        assert!(dense.is_empty());
+
+        dense.insert(42);
+        dense.insert(7);
+        dense.insert(99);
+
+        assert_eq!(dense.len(), 3);
+        assert!(dense.contains(42));
+
+        let sparse = dense.to_sparse();
+        assert_eq!(sparse.as_slice(), &[7, 42, 99]);
+
+        let dense = sparse.to_dense(100);
+        assert_eq!(dense.iter().collect::<Vec<_>>(), vec![7, 42, 99]);
+        assert_eq!(dense.par_iter().count(), 3);
+    }
+
+    #[test]
+    fn bucket_queue() {
+        let mut queue = BucketQueue::<u32>::new();
+        assert!(queue.is_empty());
+
+        queue.push(3, 1);
+        queue.push(1, 2);
+        queue.push(3, 4);
+
+        assert_eq!(queue.next_non_empty(0), Some(1));
+        assert_eq!(queue.bucket(3), &[1, 4]);
+        assert_eq!(queue.bucket(8), &[] as &[u32]);
+
+        assert_eq!(queue.take(1), vec![2]);
+        assert_eq!(queue.next_non_empty(0), Some(3));
+
+        queue.clear(3);
+        assert!(queue.is_empty());
+    }
+}
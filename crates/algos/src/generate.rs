@@ -0,0 +1,443 @@
+//! Random graph generators.
+//!
+//! The generators return the edges of a random graph, which can be turned
+//! into a graph using the [`GraphBuilder`](crate::prelude::GraphBuilder):
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let config = RmatConfig::new(8, 16);
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .csr_layout(CsrLayout::Deduplicated)
+//!     .edges(rmat(config))
+//!     .node_values(std::iter::repeat(()).take(config.node_count()))
+//!     .build();
+//!
+//! assert_eq!(graph.node_count(), 256);
+//! ```
+//!
+//! Edges are generated in parallel where possible. Each thread uses its own
+//! random number generator, which is seeded based on the configured seed and
+//! the part of the graph it generates, so the results are reproducible.
+//!
+//! - **R-MAT** [1] generates graphs with a skewed degree distribution by
+//!   recursively choosing one of the four quadrants of the adjacency matrix
+//!   for each edge, as done by the Graph500 benchmark. The graphs may
+//!   contain duplicate edges and self-loops.
+//! - **Erdős–Rényi** [2] graphs contain each possible edge with the same
+//!   probability, independently of all other edges.
+//! - **Barabási–Albert** [3] graphs grow by adding nodes one at a time, which
+//!   connect to existing nodes with a probability proportional to their
+//!   degree, which leads to a power-law degree distribution.
+//!
+//! [1] Deepayan Chakrabarti, Yiping Zhan, Christos Faloutsos:
+//! "R-MAT: A Recursive Model for Graph Mining",
+//! SDM 2004
+//!
+//! [2] Vladimir Batagelj, Ulrik Brandes:
+//! "Efficient generation of large random networks",
+//! Physical Review E 71, 2005
+//!
+//! [3] Albert-László Barabási, Réka Albert:
+//! "Emergence of Scaling in Random Networks",
+//! Science 286, 1999
+
+use graph_builder::time::Instant;
+
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::{prelude::*, random_walk::derive_seed};
+
+/// The number of R-MAT edges generated with the same random number generator.
+const RMAT_CHUNK_SIZE: usize = 1 << 16;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct RmatConfig {
+    /// The graph contains `2^scale` nodes.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RmatConfig::DEFAULT_SCALE))]
+    pub scale: u32,
+
+    /// The graph contains `edge_factor * 2^scale` edges.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RmatConfig::DEFAULT_EDGE_FACTOR))]
+    pub edge_factor: usize,
+
+    /// The probability of choosing the upper left quadrant.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RmatConfig::DEFAULT_A))]
+    pub a: f64,
+
+    /// The probability of choosing the upper right quadrant.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RmatConfig::DEFAULT_B))]
+    pub b: f64,
+
+    /// The probability of choosing the lower left quadrant. The lower
+    /// right quadrant is chosen with probability `1 - a - b - c`.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RmatConfig::DEFAULT_C))]
+    pub c: f64,
+
+    /// The seed for choosing the quadrants.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RmatConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for RmatConfig {
+    fn default() -> Self {
+        Self {
+            scale: Self::DEFAULT_SCALE,
+            edge_factor: Self::DEFAULT_EDGE_FACTOR,
+            a: Self::DEFAULT_A,
+            b: Self::DEFAULT_B,
+            c: Self::DEFAULT_C,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl RmatConfig {
+    pub const DEFAULT_SCALE: u32 = 20;
+    pub const DEFAULT_EDGE_FACTOR: usize = 16;
+    pub const DEFAULT_A: f64 = 0.57;
+    pub const DEFAULT_B: f64 = 0.19;
+    pub const DEFAULT_C: f64 = 0.19;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(scale: u32, edge_factor: usize) -> Self {
+        Self {
+            scale,
+            edge_factor,
+            ..Self::default()
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        1 << self.scale
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_factor << self.scale
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct ErdosRenyiConfig {
+    /// The number of nodes in the graph.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub node_count: usize,
+
+    /// The probability of each possible edge to be contained in the graph.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub edge_probability: f64,
+
+    /// If set, `(u, v)` and `(v, u)` are considered different edges,
+    /// otherwise only edges `(u, v)` with `u > v` are generated.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub directed: bool,
+
+    /// The seed for choosing the edges.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = ErdosRenyiConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl ErdosRenyiConfig {
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(node_count: usize, edge_probability: f64, directed: bool) -> Self {
+        Self {
+            node_count,
+            edge_probability,
+            directed,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct BarabasiAlbertConfig {
+    /// The number of nodes in the graph.
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub node_count: usize,
+
+    /// The number of existing nodes each new node is connected to.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = BarabasiAlbertConfig::DEFAULT_EDGES_PER_NODE))]
+    pub edges_per_node: usize,
+
+    /// The seed for choosing the nodes to connect to.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = BarabasiAlbertConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl BarabasiAlbertConfig {
+    pub const DEFAULT_EDGES_PER_NODE: usize = 4;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(node_count: usize, edges_per_node: usize) -> Self {
+        Self {
+            node_count,
+            edges_per_node,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+/// Generates the edges of an R-MAT graph with `config.node_count()` nodes.
+///
+/// The quadrant probabilities must not be negative and `a + b + c` must be
+/// at most `1`.
+pub fn rmat<NI: Idx>(config: RmatConfig) -> Vec<(NI, NI)> {
+    let start = Instant::now();
+    let edge_count = config.edge_count();
+
+    let edges = (0..edge_count)
+        .into_par_iter()
+        .step_by(RMAT_CHUNK_SIZE)
+        .flat_map_iter(|chunk_start| {
+            let chunk_end = usize::min(chunk_start + RMAT_CHUNK_SIZE, edge_count);
+            let chunk = (chunk_start / RMAT_CHUNK_SIZE) as u64;
+            let mut rng = WyRand::new_seed(derive_seed(config.seed, chunk));
+            (chunk_start..chunk_end)
+                .map(|_| rmat_edge(&config, &mut rng))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    info!(
+        "Generated {} R-MAT edges in {:?}",
+        edges.len(),
+        start.elapsed()
+    );
+
+    edges
+}
+
+fn rmat_edge<NI: Idx>(config: &RmatConfig, rng: &mut WyRand) -> (NI, NI) {
+    let ab = config.a + config.b;
+    let abc = ab + config.c;
+
+    let (mut source, mut target) = (0, 0);
+    for _ in 0..config.scale {
+        let r = rng.generate::<f64>();
+        let (source_bit, target_bit) = if r < config.a {
+            (0, 0)
+        } else if r < ab {
+            (0, 1)
+        } else if r < abc {
+            (1, 0)
+        } else {
+            (1, 1)
+        };
+        source = (source << 1) | source_bit;
+        target = (target << 1) | target_bit;
+    }
+
+    (NI::new(source), NI::new(target))
+}
+
+/// Generates the edges of an Erdős–Rényi graph with `config.node_count`
+/// nodes, which contains each possible edge with `config.edge_probability`.
+///
+/// Instead of drawing a random number for each possible edge, the number of
+/// skipped edges until the next generated edge is drawn from a geometric
+/// distribution, so the runtime is linear in the number of generated edges.
+pub fn erdos_renyi<NI: Idx>(config: ErdosRenyiConfig) -> Vec<(NI, NI)> {
+    let start = Instant::now();
+
+    let edges = (0..config.node_count)
+        .into_par_iter()
+        .flat_map_iter(|source| {
+            let mut rng = WyRand::new_seed(derive_seed(config.seed, source as u64));
+            erdos_renyi_targets(&config, source, &mut rng)
+                .into_iter()
+                .map(move |target| (NI::new(source), NI::new(target)))
+        })
+        .collect::<Vec<_>>();
+
+    info!(
+        "Generated {} Erdős–Rényi edges in {:?}",
+        edges.len(),
+        start.elapsed()
+    );
+
+    edges
+}
+
+fn erdos_renyi_targets(config: &ErdosRenyiConfig, source: usize, rng: &mut WyRand) -> Vec<usize> {
+    // Directed graphs connect to all other nodes, undirected graphs only to
+    // nodes with a smaller id, so that each edge is generated once.
+    let candidates = if config.directed {
+        config.node_count.saturating_sub(1)
+    } else {
+        source
+    };
+    let to_target = |candidate: usize| {
+        if config.directed && candidate >= source {
+            candidate + 1
+        } else {
+            candidate
+        }
+    };
+
+    let p = config.edge_probability;
+    if p <= 0.0 {
+        return Vec::new();
+    }
+    if p >= 1.0 {
+        return (0..candidates).map(to_target).collect();
+    }
+
+    let log_q = (1.0 - p).ln();
+    let mut targets = Vec::new();
+    let mut candidate = 0_usize;
+    loop {
+        let r = rng.generate::<f64>();
+        // `1 - r` is in `(0, 1]`, so the skip is never negative.
+        let skip = ((1.0 - r).ln() / log_q).floor() as usize;
+        candidate = candidate.saturating_add(skip);
+        if candidate >= candidates {
+            break;
+        }
+        targets.push(to_target(candidate));
+        candidate += 1;
+    }
+    targets
+}
+
+/// Generates the edges of a Barabási–Albert graph with `config.node_count`
+/// nodes.
+///
+/// The first `config.edges_per_node` nodes are not connected to each other.
+/// Each following node `u` is connected to `config.edges_per_node` distinct
+/// nodes with a smaller id by an edge `(u, v)`. Each node is chosen with a
+/// probability proportional to its degree, except for the first new node,
+/// which connects to all initial nodes.
+///
+/// `config.edges_per_node` must be at least `1` and smaller than the node
+/// count. Nodes are added one after another, so the graph is generated on a
+/// single thread.
+pub fn barabasi_albert<NI: Idx>(config: BarabasiAlbertConfig) -> Vec<(NI, NI)> {
+    let start = Instant::now();
+
+    let BarabasiAlbertConfig {
+        node_count,
+        edges_per_node,
+        seed,
+    } = config;
+
+    let new_nodes = node_count.saturating_sub(edges_per_node);
+    let mut edges = Vec::with_capacity(new_nodes * edges_per_node);
+    // Every node occurs once per edge it is part of, so that sampling from
+    // this list chooses nodes proportional to their degree.
+    let mut endpoints = Vec::with_capacity(2 * new_nodes * edges_per_node);
+    let mut targets = (0..edges_per_node).collect::<Vec<_>>();
+    let mut rng = WyRand::new_seed(seed);
+
+    for source in edges_per_node..node_count {
+        for &target in &targets {
+            edges.push((NI::new(source), NI::new(target)));
+            endpoints.push(target);
+            endpoints.push(source);
+        }
+
+        targets.clear();
+        while targets.len() < edges_per_node {
+            let target = endpoints[rng.generate_range(0..endpoints.len())];
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+    }
+
+    info!(
+        "Generated {} Barabási–Albert edges in {:?}",
+        edges.len(),
+        start.elapsed()
+    );
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    #[test]
+    fn test_rmat() {
+        let config = RmatConfig::new(6, 8);
+
+        let edges = rmat::<u32>(config);
+
+        assert_eq!(edges.len(), 512);
+        assert!(edges.iter().all(|&(s, t)| s < 64 && t < 64));
+        assert_eq!(edges, rmat::<u32>(config));
+        assert_ne!(edges, rmat::<u32>(RmatConfig { seed: 7, ..config }));
+    }
+
+    #[test]
+    fn test_rmat_is_skewed() {
+        let config = RmatConfig::new(10, 16);
+
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(rmat(config))
+            .build();
+
+        // Node 0 is in the upper left quadrant on every level.
+        let max_degree = (0..graph.node_count())
+            .map(|node| graph.out_degree(node))
+            .max()
+            .unwrap();
+        assert_eq!(graph.out_degree(0), max_degree);
+    }
+
+    #[test]
+    fn test_erdos_renyi_directed() {
+        let edges = erdos_renyi::<u32>(ErdosRenyiConfig::new(4, 1.0, true));
+
+        assert_eq!(edges.len(), 12);
+        assert!(edges.iter().all(|&(s, t)| s != t));
+    }
+
+    #[test]
+    fn test_erdos_renyi_undirected() {
+        let edges = erdos_renyi::<u32>(ErdosRenyiConfig::new(4, 1.0, false));
+
+        assert_eq!(edges, vec![(1, 0), (2, 0), (2, 1), (3, 0), (3, 1), (3, 2)]);
+        assert!(erdos_renyi::<u32>(ErdosRenyiConfig::new(4, 0.0, false)).is_empty());
+    }
+
+    #[test]
+    fn test_erdos_renyi_edge_count() {
+        let config = ErdosRenyiConfig::new(1000, 0.01, true);
+
+        let edges = erdos_renyi::<u32>(config);
+
+        // The expected number of edges is 9990.
+        assert!((9000..11000).contains(&edges.len()));
+        assert_eq!(edges, erdos_renyi::<u32>(config));
+    }
+
+    #[test]
+    fn test_barabasi_albert() {
+        let config = BarabasiAlbertConfig::new(100, 3);
+
+        let edges = barabasi_albert::<u32>(config);
+
+        assert_eq!(edges.len(), 97 * 3);
+        for chunk in edges.chunks(3) {
+            let source = chunk[0].0;
+            assert!(chunk.iter().all(|&(s, t)| s == source && t < s));
+            assert!(
+                chunk[0].1 != chunk[1].1 && chunk[1].1 != chunk[2].1 && chunk[0].1 != chunk[2].1
+            );
+        }
+        assert_eq!(edges, barabasi_albert::<u32>(config));
+    }
+}
@@ -0,0 +1,287 @@
+//! Approximates the neighborhood function and distance-based centralities
+//! with HyperBall [1].
+//!
+//! The ball of radius `t` around a node contains all nodes within distance
+//! `t` of it, following outgoing edges on directed graphs. HyperBall keeps
+//! a HyperLogLog counter per node that estimates the size of its ball. The
+//! ball of radius `t + 1` is the union of the node's ball of radius `t` and
+//! the balls of radius `t` of its neighbors, so each iteration merges the
+//! counters along all edges, until no counter changes anymore.
+//!
+//! The sum of all ball sizes for radius `t` is the neighborhood function,
+//! i.e., the number of pairs of nodes within distance `t`, from which the
+//! effective diameter follows. The growth of each ball tells how many nodes
+//! are at distance `t`, which yields closeness and harmonic centrality
+//! without a breadth-first search from every node.
+//!
+//! [1] Paolo Boldi, Sebastiano Vigna:
+//! "In-Core Computation of Geometric Centralities with HyperBall: A Hundred
+//! Billion Nodes and Beyond", ICDM Workshops 2013
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 2), (2, 3)])
+//!     .build();
+//!
+//! let result = hyperball(&graph, HyperBallConfig::default());
+//!
+//! assert_eq!(result.iterations, 4);
+//! assert!((result.neighborhood_function[3] - 10.0).abs() < 0.5);
+//! assert!(result.harmonic[0] > result.harmonic[2]);
+//! ```
+
+use graph_builder::time::Instant;
+
+use log::info;
+use rayon::prelude::*;
+
+use crate::{hyperloglog, prelude::*};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct HyperBallConfig {
+    /// The base 2 logarithm of the number of registers per HyperLogLog
+    /// counter, between 4 and 16.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = HyperBallConfig::DEFAULT_PRECISION))]
+    pub precision: u8,
+
+    /// The maximum radius of the balls, which bounds the number of
+    /// iterations.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = HyperBallConfig::DEFAULT_MAX_ITERATIONS))]
+    pub max_iterations: usize,
+
+    /// If set, the given number of nodes with the highest
+    /// closeness or harmonic centrality is returned by
+    /// [`TopK::top_nodes`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub top_k: Option<usize>,
+}
+
+impl Default for HyperBallConfig {
+    fn default() -> Self {
+        Self {
+            precision: Self::DEFAULT_PRECISION,
+            max_iterations: Self::DEFAULT_MAX_ITERATIONS,
+            top_k: None,
+        }
+    }
+}
+
+impl TopK for HyperBallConfig {
+    fn top_k(&self) -> Option<usize> {
+        self.top_k
+    }
+}
+
+impl HyperBallConfig {
+    pub const DEFAULT_PRECISION: u8 = 8;
+    pub const DEFAULT_MAX_ITERATIONS: usize = 1000;
+
+    pub fn new(precision: u8, max_iterations: usize) -> Self {
+        Self {
+            precision,
+            max_iterations,
+            top_k: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyperBallResult {
+    /// The estimated number of pairs of nodes within distance `t` at index
+    /// `t`, including pairs of a node with itself.
+    pub neighborhood_function: Vec<f64>,
+    /// The estimated number of reachable nodes divided by the sum of their
+    /// distances for each node, or zero if no other node is reachable.
+    pub closeness: Vec<f64>,
+    /// The estimated sum of the inverse distances to all reachable nodes.
+    pub harmonic: Vec<f64>,
+    /// The number of iterations, which is one more than the radius at which
+    /// all balls were complete, unless `max_iterations` was reached.
+    pub iterations: usize,
+}
+
+impl HyperBallResult {
+    /// Returns the smallest distance, interpolated between integers, within
+    /// which the given fraction of all connected pairs of nodes lie. The
+    /// fraction is typically `0.9`.
+    pub fn effective_diameter(&self, quantile: f64) -> f64 {
+        let nf = &self.neighborhood_function;
+        let Some(&total) = nf.last() else {
+            return 0.0;
+        };
+        let threshold = quantile * total;
+
+        match nf.iter().position(|&pairs| pairs >= threshold) {
+            Some(0) | None => 0.0,
+            Some(t) => {
+                let (below, above) = (nf[t - 1], nf[t]);
+                (t - 1) as f64 + (threshold - below) / (above - below)
+            }
+        }
+    }
+}
+
+/// Runs HyperBall on the graph, following outgoing edges on directed graphs.
+pub fn hyperball<NI, G>(graph: &G, config: HyperBallConfig) -> HyperBallResult
+where
+    NI: Idx,
+    G: Graph<NI> + BidirectionalNeighbors<NI> + Sync,
+{
+    let start = Instant::now();
+
+    let node_count = graph.node_count().index();
+    let width = hyperloglog::register_count(config.precision);
+
+    let mut current = vec![0_u8; node_count * width];
+    current
+        .par_chunks_mut(width)
+        .enumerate()
+        .for_each(|(node, counter)| hyperloglog::insert(counter, node as u64));
+    let mut next = current.clone();
+
+    // The estimated ball size of each node for the previous radius.
+    let mut sizes = vec![1.0; node_count];
+    let mut distance_sums = vec![0.0; node_count];
+    let mut harmonic = vec![0.0; node_count];
+    let mut neighborhood_function = vec![node_count as f64];
+    let mut iterations = 0;
+
+    for radius in 1..=config.max_iterations {
+        let current_ref = &current;
+        let changed = next
+            .par_chunks_mut(width)
+            .zip(sizes.par_iter_mut())
+            .zip(distance_sums.par_iter_mut())
+            .zip(harmonic.par_iter_mut())
+            .enumerate()
+            .map(|(node, (((counter, size), distance_sum), harmonic))| {
+                counter.copy_from_slice(&current_ref[node * width..(node + 1) * width]);
+                let mut changed = false;
+                for neighbor in graph.forward_neighbors(NI::new(node)) {
+                    let neighbor = neighbor.index();
+                    changed |= hyperloglog::merge(
+                        counter,
+                        &current_ref[neighbor * width..(neighbor + 1) * width],
+                    );
+                }
+
+                if changed {
+                    // Balls never shrink, even if the estimates do.
+                    let new_size = hyperloglog::estimate(counter).max(*size);
+                    let growth = new_size - *size;
+                    *distance_sum += radius as f64 * growth;
+                    *harmonic += growth / radius as f64;
+                    *size = new_size;
+                }
+
+                changed
+            })
+            .reduce(|| false, |a, b| a | b);
+
+        std::mem::swap(&mut current, &mut next);
+        iterations = radius;
+
+        if !changed {
+            break;
+        }
+        neighborhood_function.push(sizes.par_iter().sum());
+    }
+
+    let closeness = sizes
+        .par_iter()
+        .zip(distance_sums.par_iter())
+        .map(|(size, distance_sum)| {
+            if *distance_sum > 0.0 {
+                (size - 1.0) / distance_sum
+            } else {
+                0.0
+            }
+        })
+        .collect();
+
+    info!(
+        "Ran HyperBall for {iterations} iterations in {:?}",
+        start.elapsed()
+    );
+
+    HyperBallResult {
+        neighborhood_function,
+        closeness,
+        harmonic,
+        iterations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::GraphBuilder;
+
+    #[test]
+    fn test_path() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4)])
+            .build();
+
+        let result = hyperball(&graph, HyperBallConfig::new(12, 100));
+
+        // The balls are complete at radius 4, the fifth iteration changes
+        // nothing.
+        assert_eq!(result.iterations, 5);
+        let expected = [5.0, 13.0, 19.0, 23.0, 25.0];
+        for (actual, expected) in result.neighborhood_function.iter().zip(expected) {
+            assert!((actual - expected).abs() < 0.5, "{actual} != {expected}");
+        }
+
+        // Distances from the center are 1, 1, 2, 2.
+        assert!((result.closeness[2] - 4.0 / 6.0).abs() < 0.05);
+        assert!((result.harmonic[2] - 3.0).abs() < 0.05);
+        assert!(result.harmonic[2] > result.harmonic[0]);
+    }
+
+    #[test]
+    fn test_directed() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (3, 2)])
+            .build();
+
+        let result = hyperball(&graph, HyperBallConfig::default());
+
+        assert_eq!(result.closeness[2], 0.0);
+        assert_eq!(result.harmonic[2], 0.0);
+        assert!((result.harmonic[0] - 1.5).abs() < 0.05);
+        assert!((result.neighborhood_function.last().unwrap() - 8.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_effective_diameter() {
+        let result = HyperBallResult {
+            neighborhood_function: vec![10.0, 50.0, 90.0, 100.0],
+            closeness: vec![],
+            harmonic: vec![],
+            iterations: 4,
+        };
+
+        assert_eq!(result.effective_diameter(0.9), 2.0);
+        assert_eq!(result.effective_diameter(0.7), 1.5);
+        assert_eq!(result.effective_diameter(0.05), 0.0);
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges((0..10).map(|n| (n, n + 1)).collect::<Vec<_>>())
+            .build();
+
+        let result = hyperball(&graph, HyperBallConfig::new(8, 3));
+
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.neighborhood_function.len(), 4);
+    }
+}
@@ -0,0 +1,111 @@
+//! HyperLogLog counters [1], which estimate the number of distinct items
+//! they have seen in a fixed number of bytes.
+//!
+//! A counter consists of `2^precision` one byte registers. Counters are
+//! stored back to back in a single slice, so that algorithms can keep one
+//! counter per node and merge them along edges.
+//!
+//! [1] Philippe Flajolet, Éric Fusy, Olivier Gandouet, Frédéric Meunier:
+//! "HyperLogLog: the analysis of a near-optimal cardinality estimation
+//! algorithm", Analysis of Algorithms, AofA 2007
+
+use crate::random_walk::derive_seed;
+
+pub(crate) const MIN_PRECISION: u8 = 4;
+pub(crate) const MAX_PRECISION: u8 = 16;
+
+/// Returns the number of registers of a counter with the given precision.
+pub(crate) fn register_count(precision: u8) -> usize {
+    assert!(
+        (MIN_PRECISION..=MAX_PRECISION).contains(&precision),
+        "precision must be between {MIN_PRECISION} and {MAX_PRECISION}, got {precision}"
+    );
+    1 << precision
+}
+
+/// Adds an item to the counter.
+pub(crate) fn insert(registers: &mut [u8], item: u64) {
+    let precision = registers.len().trailing_zeros();
+    let hash = derive_seed(0, item);
+    let register = (hash >> (64 - precision)) as usize;
+    // The sentinel bit bounds the rank if all remaining bits are zero.
+    let rank = ((hash << precision) | (1 << (precision - 1))).leading_zeros() as u8 + 1;
+    registers[register] = registers[register].max(rank);
+}
+
+/// Adds all items seen by `source` to `target` and returns whether `target`
+/// changed.
+pub(crate) fn merge(target: &mut [u8], source: &[u8]) -> bool {
+    let mut changed = false;
+    for (target, &source) in target.iter_mut().zip(source) {
+        if source > *target {
+            *target = source;
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Estimates the number of distinct items seen by the counter.
+pub(crate) fn estimate(registers: &[u8]) -> f64 {
+    let m = registers.len() as f64;
+    let alpha = match registers.len() {
+        16 => 0.673,
+        32 => 0.697,
+        64 => 0.709,
+        _ => 0.7213 / (1.0 + 1.079 / m),
+    };
+
+    let (sum, zeros) = registers.iter().fold((0.0, 0), |(sum, zeros), &register| {
+        (
+            sum + 2_f64.powi(-(register as i32)),
+            zeros + (register == 0) as usize,
+        )
+    });
+
+    let estimate = alpha * m * m / sum;
+    if estimate <= 2.5 * m && zeros > 0 {
+        // Linear counting is more accurate for small cardinalities.
+        m * (m / zeros as f64).ln()
+    } else {
+        estimate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate() {
+        let mut registers = vec![0; register_count(10)];
+        assert_eq!(estimate(&registers), 0.0);
+
+        for item in 0..100_000 {
+            insert(&mut registers, item);
+        }
+
+        let error = (estimate(&registers) - 100_000.0).abs() / 100_000.0;
+        assert!(error < 0.1, "error {error}");
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = vec![0; register_count(8)];
+        let mut b = vec![0; register_count(8)];
+        (0..500).for_each(|item| insert(&mut a, item));
+        (250..1000).for_each(|item| insert(&mut b, item));
+
+        assert!(merge(&mut a, &b));
+        assert!(!merge(&mut a, &b));
+
+        let error = (estimate(&a) - 1000.0).abs() / 1000.0;
+        assert!(error < 0.2, "error {error}");
+    }
+
+    #[test]
+    #[should_panic(expected = "precision must be between")]
+    fn test_invalid_precision() {
+        register_count(20);
+    }
+}
@@ -0,0 +1,625 @@
+//! Graph and subgraph isomorphism checks for small graphs.
+//!
+//! The checks use the VF2 algorithm [1], which extends a partial mapping
+//! between the nodes of two graphs one node pair at a time and prunes pairs
+//! that cannot be part of a complete mapping. The running time is
+//! exponential in the worst case, so the checks are meant for small graphs,
+//! e.g., to compare the result of an algorithm with an expected graph in a
+//! test or to deduplicate a catalog of patterns.
+//!
+//! Graphs are first converted into a [`SmallGraph`]. Node labels, e.g., the
+//! node values of a graph, are compared by passing a matching function to
+//! [`isomorphism_by`] or [`subgraph_isomorphism_by`].
+//!
+//! [1] Luigi P. Cordella, Pasquale Foggia, Carlo Sansone, Mario Vento:
+//! "A (Sub)Graph Isomorphism Algorithm for Matching Large Graphs",
+//! IEEE Transactions on Pattern Analysis and Machine Intelligence, 2004
+
+use std::marker::PhantomData;
+
+use crate::prelude::*;
+
+const NONE: usize = usize::MAX;
+
+/// A compact representation of a small directed or undirected graph that
+/// supports the adjacency queries needed by the isomorphism checks.
+///
+/// Parallel edges and self-loops are supported and must exist equally often
+/// in both graphs for them to be isomorphic.
+#[derive(Clone, Debug)]
+pub struct SmallGraph<NI> {
+    directed: bool,
+    /// The targets of the outgoing edges of each node together with the
+    /// number of edges to that target, sorted by target.
+    out: Vec<Vec<(usize, u32)>>,
+    /// The nodes connected to each node in either direction, without the
+    /// node itself.
+    neighbors: Vec<Vec<usize>>,
+    out_degrees: Vec<usize>,
+    in_degrees: Vec<usize>,
+    _node: PhantomData<NI>,
+}
+
+impl<NI: Idx> SmallGraph<NI> {
+    /// Creates a small graph from the outgoing edges of a directed graph.
+    pub fn from_directed<G>(graph: &G) -> Self
+    where
+        G: Graph<NI> + DirectedNeighbors<NI>,
+    {
+        let out = (0..graph.node_count().index())
+            .map(|node| count_targets(graph.out_neighbors(NI::new(node))))
+            .collect();
+
+        Self::from_out(true, out)
+    }
+
+    /// Creates a small graph from an undirected graph.
+    pub fn from_undirected<G>(graph: &G) -> Self
+    where
+        G: Graph<NI> + UndirectedNeighbors<NI>,
+    {
+        let out = (0..graph.node_count().index())
+            .map(|node| count_targets(graph.neighbors(NI::new(node))))
+            .collect();
+
+        Self::from_out(false, out)
+    }
+
+    fn from_out(directed: bool, out: Vec<Vec<(usize, u32)>>) -> Self {
+        let node_count = out.len();
+        let mut neighbors = vec![Vec::new(); node_count];
+        let mut out_degrees = vec![0; node_count];
+        let mut in_degrees = vec![0; node_count];
+
+        for (source, targets) in out.iter().enumerate() {
+            for &(target, count) in targets {
+                out_degrees[source] += count as usize;
+                in_degrees[target] += count as usize;
+                if source != target {
+                    neighbors[source].push(target);
+                    neighbors[target].push(source);
+                }
+            }
+        }
+
+        neighbors.iter_mut().for_each(|neighbors| {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        });
+
+        Self {
+            directed,
+            out,
+            neighbors,
+            out_degrees,
+            in_degrees,
+            _node: PhantomData,
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.out.len()
+    }
+
+    /// Returns `true` if the graph was created from a directed graph.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the number of edges from `source` to `target`.
+    fn edge_count(&self, source: usize, target: usize) -> u32 {
+        let targets = &self.out[source];
+        targets
+            .binary_search_by_key(&target, |(target, _)| *target)
+            .map_or(0, |i| targets[i].1)
+    }
+}
+
+fn count_targets<'a, NI: Idx>(targets: impl Iterator<Item = &'a NI>) -> Vec<(usize, u32)> {
+    let mut targets = targets.map(|target| target.index()).collect::<Vec<_>>();
+    targets.sort_unstable();
+
+    let mut counts = Vec::<(usize, u32)>::with_capacity(targets.len());
+    for target in targets {
+        match counts.last_mut() {
+            Some((last, count)) if *last == target => *count += 1,
+            _ => counts.push((target, 1)),
+        }
+    }
+    counts
+}
+
+/// Returns `true` if the two graphs are isomorphic.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let a: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 3)])
+///     .build();
+/// let b: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(3, 1), (1, 0), (0, 2)])
+///     .build();
+/// let c: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (0, 2), (0, 3)])
+///     .build();
+///
+/// let (a, b, c) = (
+///     SmallGraph::from_undirected(&a),
+///     SmallGraph::from_undirected(&b),
+///     SmallGraph::from_undirected(&c),
+/// );
+///
+/// assert!(is_isomorphic(&a, &b));
+/// assert!(!is_isomorphic(&a, &c));
+/// ```
+pub fn is_isomorphic<NI: Idx>(a: &SmallGraph<NI>, b: &SmallGraph<NI>) -> bool {
+    isomorphism(a, b).is_some()
+}
+
+/// Returns a mapping from the nodes of `a` to the nodes of `b` that
+/// preserves all edges, or `None` if the graphs are not isomorphic.
+pub fn isomorphism<NI: Idx>(a: &SmallGraph<NI>, b: &SmallGraph<NI>) -> Option<Vec<NI>> {
+    isomorphism_by(a, b, |_, _| true)
+}
+
+/// Returns a mapping from the nodes of `a` to the nodes of `b` that
+/// preserves all edges and only maps nodes `u` to nodes `v` for which
+/// `node_match(u, v)` returns `true`, or `None` if there is no such mapping.
+///
+/// # Example
+///
+/// Compare graphs by their topology and node values:
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let a: DirectedCsrGraph<u32, char> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2)])
+///     .node_values(vec!['x', 'y', 'z'])
+///     .build();
+/// let b: DirectedCsrGraph<u32, char> = GraphBuilder::new()
+///     .edges(vec![(2, 0), (0, 1)])
+///     .node_values(vec!['y', 'z', 'x'])
+///     .build();
+///
+/// let mapping = isomorphism_by(
+///     &SmallGraph::from_directed(&a),
+///     &SmallGraph::from_directed(&b),
+///     |u, v| a.node_value(u) == b.node_value(v),
+/// );
+///
+/// assert_eq!(mapping, Some(vec![2, 0, 1]));
+/// ```
+pub fn isomorphism_by<NI, F>(
+    a: &SmallGraph<NI>,
+    b: &SmallGraph<NI>,
+    node_match: F,
+) -> Option<Vec<NI>>
+where
+    NI: Idx,
+    F: Fn(NI, NI) -> bool,
+{
+    if a.directed != b.directed || a.node_count() != b.node_count() {
+        return None;
+    }
+
+    let degrees = |graph: &SmallGraph<NI>| {
+        let mut degrees = graph
+            .out_degrees
+            .iter()
+            .copied()
+            .zip(graph.in_degrees.iter().copied())
+            .collect::<Vec<_>>();
+        degrees.sort_unstable();
+        degrees
+    };
+    if degrees(a) != degrees(b) {
+        return None;
+    }
+
+    Matcher::new(a, b, node_match, Mode::Isomorphism).find()
+}
+
+/// Returns `true` if `pattern` is isomorphic to an induced subgraph of
+/// `target`.
+pub fn is_subgraph_isomorphic<NI: Idx>(pattern: &SmallGraph<NI>, target: &SmallGraph<NI>) -> bool {
+    subgraph_isomorphism(pattern, target).is_some()
+}
+
+/// Returns an injective mapping from the nodes of `pattern` to the nodes of
+/// `target`, such that two pattern nodes are connected by as many edges as
+/// the target nodes they are mapped to, or `None` if there is no such
+/// mapping.
+///
+/// The pattern must match an induced subgraph of the target, i.e., target
+/// nodes that are mapped to unconnected pattern nodes must be unconnected as
+/// well.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let triangle: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 0)])
+///     .build();
+/// let path: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2)])
+///     .build();
+/// let target: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3)])
+///     .build();
+///
+/// let target = SmallGraph::from_undirected(&target);
+///
+/// assert!(subgraph_isomorphism(&SmallGraph::from_undirected(&triangle), &target).is_some());
+/// assert_eq!(
+///     subgraph_isomorphism(&SmallGraph::from_undirected(&path), &target),
+///     Some(vec![0, 2, 3])
+/// );
+/// ```
+pub fn subgraph_isomorphism<NI: Idx>(
+    pattern: &SmallGraph<NI>,
+    target: &SmallGraph<NI>,
+) -> Option<Vec<NI>> {
+    subgraph_isomorphism_by(pattern, target, |_, _| true)
+}
+
+/// Returns a mapping like [`subgraph_isomorphism`] that only maps pattern
+/// nodes `u` to target nodes `v` for which `node_match(u, v)` returns
+/// `true`.
+pub fn subgraph_isomorphism_by<NI, F>(
+    pattern: &SmallGraph<NI>,
+    target: &SmallGraph<NI>,
+    node_match: F,
+) -> Option<Vec<NI>>
+where
+    NI: Idx,
+    F: Fn(NI, NI) -> bool,
+{
+    if pattern.directed != target.directed || pattern.node_count() > target.node_count() {
+        return None;
+    }
+
+    Matcher::new(pattern, target, node_match, Mode::Subgraph).find()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Isomorphism,
+    Subgraph,
+}
+
+impl Mode {
+    /// Compares a property of a pattern node with that of a target node.
+    fn fits(self, pattern: usize, target: usize) -> bool {
+        match self {
+            Mode::Isomorphism => pattern == target,
+            Mode::Subgraph => pattern <= target,
+        }
+    }
+}
+
+/// The state of the VF2 search.
+struct Matcher<'g, NI, F> {
+    pattern: &'g SmallGraph<NI>,
+    target: &'g SmallGraph<NI>,
+    node_match: F,
+    mode: Mode,
+    /// The order in which pattern nodes are matched.
+    order: Vec<usize>,
+    /// The target node of each mapped pattern node and vice versa.
+    pattern_core: Vec<usize>,
+    target_core: Vec<usize>,
+    /// The depth at which a node became a neighbor of a mapped node or was
+    /// mapped itself, `0` if neither happened yet.
+    pattern_depth: Vec<usize>,
+    target_depth: Vec<usize>,
+}
+
+impl<'g, NI, F> Matcher<'g, NI, F>
+where
+    NI: Idx,
+    F: Fn(NI, NI) -> bool,
+{
+    fn new(
+        pattern: &'g SmallGraph<NI>,
+        target: &'g SmallGraph<NI>,
+        node_match: F,
+        mode: Mode,
+    ) -> Self {
+        Self {
+            pattern,
+            target,
+            node_match,
+            mode,
+            order: match_order(pattern),
+            pattern_core: vec![NONE; pattern.node_count()],
+            target_core: vec![NONE; target.node_count()],
+            pattern_depth: vec![0; pattern.node_count()],
+            target_depth: vec![0; target.node_count()],
+        }
+    }
+
+    fn find(mut self) -> Option<Vec<NI>> {
+        self.search(0)
+            .then(|| self.pattern_core.iter().map(|v| NI::new(*v)).collect())
+    }
+
+    fn search(&mut self, depth: usize) -> bool {
+        if depth == self.order.len() {
+            return true;
+        }
+
+        let u = self.order[depth];
+
+        // If `u` is connected to a mapped node, it can only be mapped to a
+        // node connected to the image of that node.
+        let candidates = match self.pattern.neighbors[u]
+            .iter()
+            .find(|w| self.pattern_core[**w] != NONE)
+        {
+            Some(w) => self.target.neighbors[self.pattern_core[*w]].clone(),
+            None => (0..self.target.node_count()).collect(),
+        };
+
+        for v in candidates {
+            if self.target_core[v] == NONE && self.is_feasible(u, v) {
+                self.push(u, v, depth + 1);
+                if self.search(depth + 1) {
+                    return true;
+                }
+                self.pop(u, v, depth + 1);
+            }
+        }
+
+        false
+    }
+
+    fn is_feasible(&self, u: usize, v: usize) -> bool {
+        let (pattern, target, mode) = (self.pattern, self.target, self.mode);
+
+        if !mode.fits(pattern.out_degrees[u], target.out_degrees[v])
+            || !mode.fits(pattern.in_degrees[u], target.in_degrees[v])
+            || pattern.edge_count(u, u) != target.edge_count(v, v)
+            || !(self.node_match)(NI::new(u), NI::new(v))
+        {
+            return false;
+        }
+
+        // Edges to mapped nodes must exist equally often in both graphs.
+        let mut mapped = 0;
+        for &w in &pattern.neighbors[u] {
+            let x = self.pattern_core[w];
+            if x != NONE {
+                if pattern.edge_count(u, w) != target.edge_count(v, x)
+                    || pattern.edge_count(w, u) != target.edge_count(x, v)
+                {
+                    return false;
+                }
+                mapped += 1;
+            }
+        }
+        let target_mapped = target.neighbors[v]
+            .iter()
+            .filter(|x| self.target_core[**x] != NONE)
+            .count();
+        if mapped != target_mapped {
+            return false;
+        }
+
+        // Look ahead: unmapped neighbors that are adjacent to mapped nodes
+        // and those that are not must suffice to extend the mapping.
+        let lookahead = |graph: &SmallGraph<NI>, node: usize, core: &[usize], depth: &[usize]| {
+            graph.neighbors[node]
+                .iter()
+                .filter(|w| core[**w] == NONE)
+                .fold((0, 0), |(terminal, new), w| {
+                    if depth[*w] != 0 {
+                        (terminal + 1, new)
+                    } else {
+                        (terminal, new + 1)
+                    }
+                })
+        };
+        let (pattern_terminal, pattern_new) =
+            lookahead(pattern, u, &self.pattern_core, &self.pattern_depth);
+        let (target_terminal, target_new) =
+            lookahead(target, v, &self.target_core, &self.target_depth);
+
+        mode.fits(pattern_terminal, target_terminal) && mode.fits(pattern_new, target_new)
+    }
+
+    fn push(&mut self, u: usize, v: usize, depth: usize) {
+        self.pattern_core[u] = v;
+        self.target_core[v] = u;
+
+        for (graph, node, depths) in [
+            (self.pattern, u, &mut self.pattern_depth),
+            (self.target, v, &mut self.target_depth),
+        ] {
+            if depths[node] == 0 {
+                depths[node] = depth;
+            }
+            for &w in &graph.neighbors[node] {
+                if depths[w] == 0 {
+                    depths[w] = depth;
+                }
+            }
+        }
+    }
+
+    fn pop(&mut self, u: usize, v: usize, depth: usize) {
+        self.pattern_core[u] = NONE;
+        self.target_core[v] = NONE;
+
+        for (graph, node, depths) in [
+            (self.pattern, u, &mut self.pattern_depth),
+            (self.target, v, &mut self.target_depth),
+        ] {
+            if depths[node] == depth {
+                depths[node] = 0;
+            }
+            for &w in &graph.neighbors[node] {
+                if depths[w] == depth {
+                    depths[w] = 0;
+                }
+            }
+        }
+    }
+}
+
+/// Orders the pattern nodes such that each node is connected to as many
+/// previous nodes as possible, preferring nodes with a high degree. This
+/// detects infeasible mappings early.
+fn match_order<NI: Idx>(pattern: &SmallGraph<NI>) -> Vec<usize> {
+    let node_count = pattern.node_count();
+    let mut order = Vec::with_capacity(node_count);
+    let mut ordered = vec![false; node_count];
+    let mut connections = vec![0; node_count];
+
+    while order.len() < node_count {
+        let next = (0..node_count)
+            .filter(|node| !ordered[*node])
+            .max_by_key(|node| {
+                (
+                    connections[*node],
+                    pattern.neighbors[*node].len(),
+                    usize::MAX - node,
+                )
+            })
+            .expect("an unordered node");
+
+        order.push(next);
+        ordered[next] = true;
+        for &neighbor in &pattern.neighbors[next] {
+            connections[neighbor] += 1;
+        }
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    fn undirected(edges: Vec<(u32, u32)>) -> SmallGraph<u32> {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(edges)
+            .build();
+        SmallGraph::from_undirected(&graph)
+    }
+
+    fn directed(edges: Vec<(u32, u32)>) -> SmallGraph<u32> {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(edges)
+            .build();
+        SmallGraph::from_directed(&graph)
+    }
+
+    fn assert_mapping(a: &SmallGraph<u32>, b: &SmallGraph<u32>, mapping: &[u32]) {
+        for u in 0..a.node_count() {
+            for &(w, count) in &a.out[u] {
+                assert_eq!(
+                    b.edge_count(mapping[u] as usize, mapping[w] as usize),
+                    count
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_isomorphic_petersen_graphs() {
+        let outer = (0..5).map(|i| (i, (i + 1) % 5));
+        let spokes = (0..5).map(|i| (i, i + 5));
+        let inner = (0..5).map(|i| (i + 5, (i + 2) % 5 + 5));
+        let a = undirected(outer.chain(spokes).chain(inner).collect());
+
+        // the same graph with node ids permuted
+        let permutation = [3, 7, 1, 9, 0, 5, 2, 8, 6, 4];
+        let b = undirected(
+            a.out
+                .iter()
+                .enumerate()
+                .flat_map(|(u, targets)| {
+                    targets
+                        .iter()
+                        .filter(move |(v, _)| u < *v)
+                        .map(move |(v, _)| (permutation[u], permutation[*v]))
+                })
+                .collect(),
+        );
+
+        let mapping = isomorphism(&a, &b).unwrap();
+        assert_mapping(&a, &b, &mapping);
+    }
+
+    #[test]
+    fn test_non_isomorphic_graphs_with_equal_degrees() {
+        // a 6-cycle and two triangles
+        let a = undirected(vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        let b = undirected(vec![(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+
+        assert!(!is_isomorphic(&a, &b));
+    }
+
+    #[test]
+    fn test_directed_isomorphism() {
+        let a = directed(vec![(0, 1), (1, 2), (2, 0), (0, 0)]);
+        let b = directed(vec![(1, 0), (0, 2), (2, 1), (2, 2)]);
+        let c = directed(vec![(1, 0), (2, 0), (2, 1), (2, 2)]);
+
+        assert_eq!(isomorphism(&a, &b), Some(vec![2, 1, 0]));
+        assert!(!is_isomorphic(&a, &c));
+        assert!(!is_isomorphic(
+            &a,
+            &undirected(vec![(0, 1), (1, 2), (2, 0)])
+        ));
+    }
+
+    #[test]
+    fn test_parallel_edges() {
+        let a = undirected(vec![(0, 1), (0, 1), (1, 2)]);
+        let b = undirected(vec![(0, 1), (1, 2), (1, 2)]);
+        let c = undirected(vec![(0, 1), (1, 2), (2, 0)]);
+
+        assert_eq!(isomorphism(&a, &b), Some(vec![2, 1, 0]));
+        assert!(!is_isomorphic(&a, &c));
+    }
+
+    #[test]
+    fn test_labeled_isomorphism() {
+        let a = undirected(vec![(0, 1), (1, 2)]);
+        let labels_a = [1, 2, 1];
+        let labels_b = [2, 1, 1];
+
+        let mapping = isomorphism_by(&a, &a, |u, v| labels_a[u as usize] == labels_b[v as usize]);
+        assert_eq!(mapping, None);
+
+        let b = undirected(vec![(1, 0), (0, 2)]);
+        let mapping = isomorphism_by(&a, &b, |u, v| labels_a[u as usize] == labels_b[v as usize]);
+        assert_eq!(mapping, Some(vec![1, 0, 2]));
+    }
+
+    #[test]
+    fn test_induced_subgraph_isomorphism() {
+        let k4 = undirected(vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        let triangle = undirected(vec![(0, 1), (1, 2), (2, 0)]);
+        let cycle = undirected(vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+        let path = undirected(vec![(0, 1), (1, 2)]);
+
+        assert!(is_subgraph_isomorphic(&triangle, &k4));
+        assert!(is_subgraph_isomorphic(&path, &cycle));
+        assert!(!is_subgraph_isomorphic(&cycle, &k4));
+        assert!(!is_subgraph_isomorphic(&path, &k4));
+        assert!(!is_subgraph_isomorphic(&k4, &triangle));
+    }
+}
@@ -0,0 +1,166 @@
+//! K-core decomposition.
+//!
+//! The k-core of an undirected graph is its largest subgraph in which every
+//! node has at least `k` neighbors. The core number of a node is the largest
+//! `k` for which the node is part of the k-core. Nodes with a high core
+//! number belong to densely connected regions of the graph. The largest core
+//! number is also known as the degeneracy of the graph.
+//!
+//! The core numbers are computed with the algorithm of Batagelj and
+//! Zaversnik [1], which repeatedly removes a node of minimum degree. Nodes
+//! are kept in buckets by their current degree, so the algorithm runs in
+//! linear time, but on a single thread.
+//!
+//! Self-loops are ignored, parallel edges are counted once per edge.
+//!
+//! [1] Vladimir Batagelj, Matjaž Zaversnik:
+//! "An O(m) Algorithm for Cores Decomposition of Networks",
+//! arXiv:cs/0310049, 2003
+
+use graph_builder::time::Instant;
+
+use log::info;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+/// The number of removed nodes between two progress updates.
+const NODES_PER_BATCH: usize = 1 << 16;
+
+/// Computes the core number of every node of the undirected graph.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// // a triangle with a pendant node
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3)])
+///     .build();
+///
+/// let core_numbers = k_core(&graph);
+///
+/// assert_eq!(core_numbers, vec![2, 2, 2, 1]);
+/// ```
+pub fn k_core<NI, G>(graph: &G) -> Vec<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    k_core_with_progress(graph, &NoProgress)
+}
+
+/// Runs [`k_core`] and reports the number of removed nodes of the `k_core`
+/// task to the given tracker.
+pub fn k_core_with_progress<NI, G, T>(graph: &G, tracker: &T) -> Vec<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let start = Instant::now();
+    let node_count = graph.node_count().index();
+
+    let mut degrees = (0..node_count)
+        .into_par_iter()
+        .map(|u| {
+            let u = NI::new(u);
+            graph.neighbors(u).filter(|&&v| v != u).count()
+        })
+        .collect::<Vec<_>>();
+    let max_degree = degrees.iter().copied().max().unwrap_or_default();
+
+    // `order` holds the nodes sorted by their current degree, `bin_starts[d]`
+    // is the position of the first node with degree `d` in `order` and
+    // `positions[u]` is the position of `u` in `order`.
+    let mut bin_starts = vec![0; max_degree + 1];
+    degrees.iter().for_each(|&degree| bin_starts[degree] += 1);
+    let mut next_start = 0;
+    for bin_start in bin_starts.iter_mut() {
+        let bin_size = *bin_start;
+        *bin_start = next_start;
+        next_start += bin_size;
+    }
+
+    let mut order = vec![0; node_count];
+    let mut positions = vec![0; node_count];
+    let mut next_positions = bin_starts.clone();
+    for (u, &degree) in degrees.iter().enumerate() {
+        positions[u] = next_positions[degree];
+        order[positions[u]] = u;
+        next_positions[degree] += 1;
+    }
+
+    // Removes the node with the smallest degree, whose degree is its core
+    // number, and moves each neighbor with a larger degree one bin down by
+    // swapping it with the first node of its bin.
+    for i in 0..node_count {
+        let u = order[i];
+        for &v in graph.neighbors(NI::new(u)) {
+            let v = v.index();
+            if degrees[v] > degrees[u] {
+                let degree = degrees[v];
+                let first = order[bin_starts[degree]];
+                if first != v {
+                    order.swap(positions[v], bin_starts[degree]);
+                    positions[first] = positions[v];
+                    positions[v] = bin_starts[degree];
+                }
+                bin_starts[degree] += 1;
+                degrees[v] -= 1;
+            }
+        }
+
+        if (i + 1) % NODES_PER_BATCH == 0 || i + 1 == node_count {
+            tracker.progress("k_core", i + 1, Some(node_count));
+            if tracker.is_cancelled() {
+                break;
+            }
+        }
+    }
+
+    info!(
+        "Computed core numbers of {node_count} nodes in {:?}",
+        start.elapsed()
+    );
+
+    degrees.into_par_iter().map(NI::new).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder, UndirectedCsrGraph};
+
+    #[test]
+    fn test_k_core_clique() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4)])
+            .build();
+
+        assert_eq!(k_core(&graph), vec![3, 3, 3, 3, 1]);
+    }
+
+    #[test]
+    fn test_k_core_chain_of_cores() {
+        // a 4-cycle, which is a 2-core, attached to a path
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5)])
+            .build();
+
+        assert_eq!(k_core(&graph), vec![2, 2, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_k_core_ignores_self_loops() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 0), (0, 1), (3, 3)])
+            .build();
+
+        assert_eq!(k_core(&graph), vec![1, 1, 0, 0]);
+    }
+}
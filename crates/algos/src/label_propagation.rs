@@ -0,0 +1,460 @@
+//! Label Propagation community detection.
+//!
+//! Every node starts in its own community. In each iteration, every node
+//! adopts the label that occurs most frequently among its neighbors [1].
+//! Densely connected groups of nodes quickly agree on a single label, which
+//! then identifies their community.
+//!
+//! The implementation updates all labels synchronously and in parallel based
+//! on the labels of the previous iteration. A node keeps its current label if
+//! it is among the most frequent ones, other ties are broken by a seeded hash,
+//! so that the result only depends on the graph and the seed.
+//!
+//! [1] Usha Nandini Raghavan, Réka Albert, Soundar Kumara:
+//! "Near linear time algorithm to detect community structures in large-scale networks",
+//! Physical Review E, 2007
+
+use graph_builder::time::Instant;
+
+use ahash::AHashMap;
+use log::info;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct LabelPropagationConfig {
+    /// The maximum number of label propagation iterations.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LabelPropagationConfig::DEFAULT_MAX_ITERATIONS))]
+    pub max_iterations: usize,
+
+    /// If the fraction of nodes that changed their label in an
+    /// iteration is at most the tolerance value, the computation stops.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LabelPropagationConfig::DEFAULT_TOLERANCE))]
+    pub tolerance: f64,
+
+    /// The seed for breaking ties between equally frequent labels.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LabelPropagationConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for LabelPropagationConfig {
+    fn default() -> Self {
+        Self {
+            max_iterations: Self::DEFAULT_MAX_ITERATIONS,
+            tolerance: Self::DEFAULT_TOLERANCE,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl LabelPropagationConfig {
+    pub const DEFAULT_MAX_ITERATIONS: usize = 10;
+    pub const DEFAULT_TOLERANCE: f64 = 0.0;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(max_iterations: usize, tolerance: f64, seed: u64) -> Self {
+        Self {
+            max_iterations,
+            tolerance,
+            seed,
+        }
+    }
+}
+
+/// Known communities of some nodes that community detection starts from.
+///
+/// Each seed pairs a node with the id of its seed community, nodes with the
+/// same seed community id start in the same community. Unseeded nodes start
+/// in a community of their own. Each node should be seeded at most once.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Deduplicated)
+///     .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+///     .build();
+///
+/// // Nodes 0 and 1 are known to belong to another group than 4 and 5.
+/// let seeds = CommunitySeeds::fixed(vec![(0, 0), (1, 0), (4, 1), (5, 1)]);
+/// let (communities, _) =
+///     label_propagation_from_seeds(&graph, LabelPropagationConfig::default(), &seeds);
+///
+/// assert_eq!(communities, vec![0, 0, 0, 1, 1, 1]);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommunitySeeds<NI> {
+    seeds: Vec<(NI, NI)>,
+    fixed: bool,
+}
+
+impl<NI: Idx> CommunitySeeds<NI> {
+    /// Creates seeds that are only used as a warm start, i.e., seeded nodes
+    /// may leave their seed community.
+    pub fn warm_start<I>(seeds: I) -> Self
+    where
+        I: IntoIterator<Item = (NI, NI)>,
+    {
+        Self {
+            seeds: seeds.into_iter().collect(),
+            fixed: false,
+        }
+    }
+
+    /// Creates seeds that are fixed, i.e., seeded nodes stay in their seed
+    /// community and the algorithm only extends these communities with
+    /// unseeded nodes.
+    pub fn fixed<I>(seeds: I) -> Self
+    where
+        I: IntoIterator<Item = (NI, NI)>,
+    {
+        Self {
+            seeds: seeds.into_iter().collect(),
+            fixed: true,
+        }
+    }
+
+    /// Returns the pairs of node and seed community id.
+    pub fn seeds(&self) -> &[(NI, NI)] {
+        &self.seeds
+    }
+
+    /// Returns `true` if seeded nodes stay in their seed community.
+    pub fn is_fixed(&self) -> bool {
+        self.fixed
+    }
+
+    /// Returns the initial community of each node together with a flag per
+    /// node that is set if the node must not change its community.
+    ///
+    /// Each seed community is identified by its first seeded node, so that
+    /// all community ids are node ids.
+    pub(crate) fn initial_communities(&self, node_count: usize) -> (Vec<usize>, Vec<bool>) {
+        let mut communities = (0..node_count).collect::<Vec<_>>();
+        let mut fixed = vec![false; node_count];
+        let mut representatives = AHashMap::<usize, usize>::new();
+
+        for (node, community) in &self.seeds {
+            let node = node.index();
+            assert!(node < node_count, "seed node {node} does not exist");
+            communities[node] = *representatives.entry(community.index()).or_insert(node);
+            fixed[node] = self.fixed;
+        }
+
+        (communities, fixed)
+    }
+}
+
+/// Computes communities of the given undirected graph using label
+/// propagation.
+///
+/// Returns the community id per node together with the number of executed
+/// iterations. Community ids are consecutive, starting at zero.
+pub fn label_propagation<NI, G>(graph: &G, config: LabelPropagationConfig) -> (Vec<NI>, usize)
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    label_propagation_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`label_propagation`] and reports each finished iteration of the
+/// `label_propagation` task to the given tracker.
+pub fn label_propagation_with_progress<NI, G, T>(
+    graph: &G,
+    config: LabelPropagationConfig,
+    tracker: &T,
+) -> (Vec<NI>, usize)
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let node_count = graph.node_count().index();
+    let labels = (0..node_count).collect::<Vec<_>>();
+
+    label_propagation_inner(graph, config, labels, &vec![false; node_count], tracker)
+}
+
+/// Runs [`label_propagation`] starting from the given seed communities
+/// instead of a community per node.
+///
+/// # Panics
+///
+/// Panics if a seed refers to a node that does not exist.
+pub fn label_propagation_from_seeds<NI, G>(
+    graph: &G,
+    config: LabelPropagationConfig,
+    seeds: &CommunitySeeds<NI>,
+) -> (Vec<NI>, usize)
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    label_propagation_from_seeds_with_progress(graph, config, seeds, &NoProgress)
+}
+
+/// Runs [`label_propagation_from_seeds`] and reports each finished iteration
+/// of the `label_propagation` task to the given tracker.
+///
+/// # Panics
+///
+/// Panics if a seed refers to a node that does not exist.
+pub fn label_propagation_from_seeds_with_progress<NI, G, T>(
+    graph: &G,
+    config: LabelPropagationConfig,
+    seeds: &CommunitySeeds<NI>,
+    tracker: &T,
+) -> (Vec<NI>, usize)
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let (labels, fixed) = seeds.initial_communities(graph.node_count().index());
+
+    label_propagation_inner(graph, config, labels, &fixed, tracker)
+}
+
+fn label_propagation_inner<NI, G, T>(
+    graph: &G,
+    config: LabelPropagationConfig,
+    mut labels: Vec<usize>,
+    fixed: &[bool],
+    tracker: &T,
+) -> (Vec<NI>, usize)
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let start = Instant::now();
+    let node_count = graph.node_count().index();
+    let mut iteration = 0;
+
+    while iteration < config.max_iterations {
+        let iteration_start = Instant::now();
+
+        let next_labels = (0..node_count)
+            .into_par_iter()
+            .map(|u| {
+                if fixed[u] {
+                    labels[u]
+                } else {
+                    next_label(graph, &labels, u, iteration, config.seed)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let changed = labels
+            .par_iter()
+            .zip(next_labels.par_iter())
+            .filter(|(old, new)| old != new)
+            .count();
+
+        labels = next_labels;
+        iteration += 1;
+        tracker.progress("label_propagation", iteration, Some(config.max_iterations));
+
+        info!(
+            "Finished iteration {} with {} changed labels in {:?}",
+            iteration,
+            changed,
+            iteration_start.elapsed()
+        );
+
+        if changed as f64 <= config.tolerance * node_count as f64 || tracker.is_cancelled() {
+            break;
+        }
+    }
+
+    let communities = relabel_communities(&labels);
+
+    info!(
+        "Computed label propagation in {} iterations in {:?}",
+        iteration,
+        start.elapsed()
+    );
+
+    (communities, iteration)
+}
+
+fn next_label<NI, G>(graph: &G, labels: &[usize], u: usize, iteration: usize, seed: u64) -> usize
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI>,
+{
+    let current = labels[u];
+
+    let mut neighbor_labels = graph
+        .neighbors(NI::new(u))
+        .map(|v| v.index())
+        .filter(|v| *v != u)
+        .map(|v| labels[v])
+        .collect::<Vec<_>>();
+
+    if neighbor_labels.is_empty() {
+        return current;
+    }
+
+    neighbor_labels.sort_unstable();
+
+    let mut best = current;
+    let mut best_count = 0;
+    let mut best_priority = 0;
+
+    let mut runs = neighbor_labels.iter().peekable();
+    while let Some(&label) = runs.next() {
+        let mut count = 1;
+        while runs.next_if_eq(&&label).is_some() {
+            count += 1;
+        }
+        let priority = tie_breaker(seed, iteration, u, label);
+        if count > best_count
+            || (count == best_count
+                && best != current
+                && (label == current || priority > best_priority))
+        {
+            best = label;
+            best_count = count;
+            best_priority = priority;
+        }
+    }
+
+    best
+}
+
+/// Returns a pseudo-random priority for choosing `label` at node `u`.
+fn tie_breaker(seed: u64, iteration: usize, u: usize, label: usize) -> u64 {
+    // SplitMix64 finalizer, applied to a combination of all inputs.
+    let mut x = seed
+        ^ (iteration as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (u as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+        ^ (label as u64).wrapping_mul(0x1656_67B1_9E37_79F9);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// Relabels communities to consecutive ids in the order of their first node.
+pub(crate) fn relabel_communities<NI: Idx>(labels: &[usize]) -> Vec<NI> {
+    let mut ids = AHashMap::<usize, usize>::new();
+    labels
+        .iter()
+        .map(|label| {
+            let next = ids.len();
+            NI::new(*ids.entry(*label).or_insert(next))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder, UndirectedCsrGraph};
+
+    // two 4-cliques connected by a single edge
+    fn two_cliques() -> UndirectedCsrGraph<usize> {
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(vec![
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (4, 5),
+                (4, 6),
+                (4, 7),
+                (5, 6),
+                (5, 7),
+                (6, 7),
+                (3, 4),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn test_lpa_two_cliques() {
+        let graph = two_cliques();
+
+        let (communities, iterations) =
+            label_propagation(&graph, LabelPropagationConfig::default());
+
+        assert!(iterations <= LabelPropagationConfig::DEFAULT_MAX_ITERATIONS);
+
+        let (a, e) = (communities[0], communities[4]);
+        assert_ne!(a, e);
+        assert!(communities[..4].iter().all(|c| *c == a));
+        assert!(communities[4..].iter().all(|c| *c == e));
+    }
+
+    #[test]
+    fn test_lpa_isolated_nodes() {
+        let gdl = "(a),(b),(c)";
+
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .gdl_str::<usize, _>(gdl)
+            .build()
+            .unwrap();
+
+        let (communities, iterations) =
+            label_propagation(&graph, LabelPropagationConfig::default());
+
+        assert_eq!(communities, vec![0, 1, 2]);
+        assert_eq!(iterations, 1);
+    }
+
+    #[test]
+    fn test_lpa_from_fixed_seeds() {
+        let graph = two_cliques();
+
+        // Node 3 is forced into the community of the other clique.
+        let seeds = CommunitySeeds::fixed(vec![(3, 1), (4, 1), (0, 0)]);
+        let (communities, _) =
+            label_propagation_from_seeds(&graph, LabelPropagationConfig::default(), &seeds);
+
+        assert_eq!(communities[3], communities[4]);
+        assert_ne!(communities[0], communities[3]);
+        assert!(communities[..3].iter().all(|c| *c == communities[0]));
+        assert!(communities[4..].iter().all(|c| *c == communities[4]));
+    }
+
+    #[test]
+    fn test_lpa_from_warm_start_seeds() {
+        let graph = two_cliques();
+
+        let seeds = CommunitySeeds::warm_start(vec![(0, 7), (1, 7), (2, 7), (3, 7)]);
+        let (communities, _) =
+            label_propagation_from_seeds(&graph, LabelPropagationConfig::default(), &seeds);
+
+        assert!(communities[..4].iter().all(|c| *c == communities[0]));
+        assert_ne!(communities[0], communities[4]);
+    }
+
+    #[test]
+    fn test_lpa_is_deterministic() {
+        let gdl = "(a)-->(b)-->(c)-->(d)-->(e)-->(a),(a)-->(c),(b)-->(d)";
+
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .gdl_str::<usize, _>(gdl)
+            .build()
+            .unwrap();
+
+        let config = LabelPropagationConfig::new(20, 0.0, 7);
+
+        assert_eq!(
+            label_propagation(&graph, config),
+            label_propagation(&graph, config)
+        );
+    }
+}
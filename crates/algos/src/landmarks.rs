@@ -0,0 +1,429 @@
+//! Landmark-based distance oracle (ALT).
+//!
+//! The index stores the distances from and to a small number of landmark
+//! nodes. By the triangle inequality, the distance from `u` to `v` is at least
+//! `d(L, v) - d(L, u)` and `d(u, L) - d(v, L)` for every landmark `L` and at
+//! most `d(u, L) + d(L, v)`. Both bounds take `O(k)` time for `k` landmarks,
+//! independent of the size of the graph, and the lower bound never
+//! overestimates, which makes it an admissible heuristic for A*
+//! (A* + Landmarks + Triangle inequality, hence ALT [1]).
+//!
+//! Landmarks are either chosen at random or one after another as the node
+//! that is farthest from all previously chosen landmarks, which spreads them
+//! over the periphery of the graph and usually gives tighter bounds. Nodes
+//! that neither reach a landmark nor are reached by one count as infinitely
+//! far away, so, e.g., each connected component of an undirected graph gets
+//! a landmark before any component gets a second one.
+//!
+//! Edge weights must not be negative.
+//!
+//! [1] Andrew V. Goldberg, Chris Harrelson:
+//! "Computing the Shortest Path: A* Search Meets Graph Theory",
+//! Proceedings of the 16th Annual ACM-SIAM Symposium on Discrete Algorithms, 2005
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4)])
+//!     .build();
+//!
+//! let config = LandmarkConfig::new(1, LandmarkSelection::Random, 7);
+//! let index = LandmarkIndex::unweighted(&graph, config);
+//!
+//! // With a single landmark, the bounds enclose the actual distance of 2.
+//! assert!(index.lower_bound(1, 3) <= 2.0);
+//! assert!(index.upper_bound(1, 3) >= 2.0);
+//! ```
+
+use graph_builder::time::Instant;
+use std::collections::BinaryHeap;
+
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::{prelude::*, shortest_path::QueueEntry};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum LandmarkSelection {
+    /// Chooses the first landmark at random and each further landmark as the
+    /// node with the largest distance to its closest landmark.
+    Farthest,
+    /// Chooses all landmarks uniformly at random, which allows computing
+    /// their distances in parallel.
+    Random,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct LandmarkConfig {
+    /// The number of landmarks. More landmarks give tighter bounds, but
+    /// take more memory and make each query slower.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LandmarkConfig::DEFAULT_LANDMARK_COUNT))]
+    pub landmark_count: usize,
+
+    /// How the landmarks are chosen.
+    #[cfg_attr(feature = "clap", clap(long, value_enum, default_value_t = LandmarkConfig::DEFAULT_SELECTION))]
+    pub selection: LandmarkSelection,
+
+    /// The seed for choosing landmarks at random.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LandmarkConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for LandmarkConfig {
+    fn default() -> Self {
+        Self {
+            landmark_count: Self::DEFAULT_LANDMARK_COUNT,
+            selection: Self::DEFAULT_SELECTION,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl LandmarkConfig {
+    pub const DEFAULT_LANDMARK_COUNT: usize = 16;
+    pub const DEFAULT_SELECTION: LandmarkSelection = LandmarkSelection::Farthest;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(landmark_count: usize, selection: LandmarkSelection, seed: u64) -> Self {
+        Self {
+            landmark_count,
+            selection,
+            seed,
+        }
+    }
+}
+
+/// The distances from and to a set of landmark nodes.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LandmarkIndex<NI> {
+    landmarks: Vec<NI>,
+    // Both are stored per node, i.e., the distances of node `u` are at
+    // `u * k..(u + 1) * k` for `k` landmarks, so a query reads two
+    // contiguous ranges.
+    from_landmarks: Vec<f32>,
+    to_landmarks: Vec<f32>,
+}
+
+impl<NI: Idx> LandmarkIndex<NI> {
+    /// Builds the index for a weighted graph, following outgoing edges.
+    pub fn weighted<G>(graph: &G, config: LandmarkConfig) -> Self
+    where
+        G: Graph<NI> + WeightedBackwardNeighbors<NI> + Sync,
+    {
+        Self::build(
+            graph.node_count().index(),
+            config,
+            |node| {
+                graph
+                    .weighted_neighbors(node)
+                    .iter()
+                    .map(|target| (target.target, target.value))
+            },
+            |node| {
+                graph
+                    .weighted_backward_neighbors(node)
+                    .iter()
+                    .map(|target| (target.target, target.value))
+            },
+        )
+    }
+
+    /// Builds the index for an unweighted graph, in which every edge has a
+    /// weight of `1`, following outgoing edges.
+    pub fn unweighted<G>(graph: &G, config: LandmarkConfig) -> Self
+    where
+        G: Graph<NI> + BidirectionalNeighbors<NI> + Sync,
+    {
+        Self::build(
+            graph.node_count().index(),
+            config,
+            |node| graph.forward_neighbors(node).iter().map(|&n| (n, 1.0)),
+            |node| graph.backward_neighbors(node).iter().map(|&n| (n, 1.0)),
+        )
+    }
+
+    fn build<F, B, FI, BI>(
+        node_count: usize,
+        config: LandmarkConfig,
+        forward: F,
+        backward: B,
+    ) -> Self
+    where
+        F: Fn(NI) -> FI + Sync,
+        B: Fn(NI) -> BI + Sync,
+        FI: Iterator<Item = (NI, f32)>,
+        BI: Iterator<Item = (NI, f32)>,
+    {
+        let start = Instant::now();
+        let landmark_count = config.landmark_count.min(node_count);
+        let mut rng = WyRand::new_seed(config.seed);
+
+        let distances = |landmark: NI| {
+            rayon::join(
+                || distances(node_count, landmark, &forward),
+                || distances(node_count, landmark, &backward),
+            )
+        };
+
+        let (landmarks, searches): (Vec<NI>, Vec<_>) = match config.selection {
+            LandmarkSelection::Random => {
+                let mut nodes = (0..node_count).map(NI::new).collect::<Vec<_>>();
+                rng.shuffle(&mut nodes);
+                nodes.truncate(landmark_count);
+                nodes
+                    .into_par_iter()
+                    .map(|landmark| (landmark, distances(landmark)))
+                    .unzip()
+            }
+            LandmarkSelection::Farthest => {
+                let mut landmarks = Vec::with_capacity(landmark_count);
+                let mut searches = Vec::with_capacity(landmark_count);
+                let mut closest = vec![f32::INFINITY; node_count];
+                let mut next = (node_count > 0).then(|| NI::new(rng.generate_range(0..node_count)));
+
+                while let Some(landmark) = next.filter(|_| landmarks.len() < landmark_count) {
+                    let (from, to) = distances(landmark);
+                    closest
+                        .par_iter_mut()
+                        .zip(from.par_iter().zip(to.par_iter()))
+                        .for_each(|(closest, (from, to))| *closest = closest.min(*from).min(*to));
+
+                    landmarks.push(landmark);
+                    searches.push((from, to));
+                    // Landmarks have a distance of 0, so the next landmark is
+                    // only `None` once all nodes are landmarks.
+                    next = farthest(&closest).map(NI::new);
+                }
+
+                (landmarks, searches)
+            }
+        };
+
+        let (from, to): (Vec<_>, Vec<_>) = searches.into_iter().unzip();
+        let from_landmarks = per_node(node_count, &from);
+        let to_landmarks = per_node(node_count, &to);
+
+        info!(
+            "Built landmark index with {} landmarks in {:?}",
+            landmarks.len(),
+            start.elapsed()
+        );
+
+        Self {
+            landmarks,
+            from_landmarks,
+            to_landmarks,
+        }
+    }
+
+    /// Returns the landmark nodes in the order in which they were chosen.
+    pub fn landmarks(&self) -> &[NI] {
+        &self.landmarks
+    }
+
+    /// Returns a lower bound for the distance from `source` to `target`,
+    /// which is infinite if the landmarks prove that `target` is not
+    /// reachable from `source`.
+    pub fn lower_bound(&self, source: NI, target: NI) -> f32 {
+        let (from_source, to_source) = self.node_distances(source);
+        let (from_target, to_target) = self.node_distances(target);
+
+        // Differences of two infinite distances are `NaN` and ignored by `max`.
+        (0..self.landmarks.len()).fold(0.0_f32, |bound, i| {
+            bound
+                .max(from_target[i] - from_source[i])
+                .max(to_source[i] - to_target[i])
+        })
+    }
+
+    /// Returns an upper bound for the distance from `source` to `target`,
+    /// which is the length of the shortest path through a landmark, or
+    /// infinite if there is no such path. The bound is exact if a landmark
+    /// lies on a shortest path from `source` to `target`.
+    pub fn upper_bound(&self, source: NI, target: NI) -> f32 {
+        if source == target {
+            return 0.0;
+        }
+
+        let (_, to_source) = self.node_distances(source);
+        let (from_target, _) = self.node_distances(target);
+
+        to_source
+            .iter()
+            .zip(from_target)
+            .map(|(to, from)| to + from)
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Computes the shortest path from `source` to `target` using A*, guided
+    /// by the lower bounds of the index.
+    pub fn astar<G>(&self, graph: &G, source: NI, target: NI) -> ShortestPaths<NI>
+    where
+        G: Graph<NI> + WeightedNeighbors<NI>,
+    {
+        astar(graph, source, target, |node| self.lower_bound(node, target))
+    }
+
+    fn node_distances(&self, node: NI) -> (&[f32], &[f32]) {
+        let k = self.landmarks.len();
+        let range = node.index() * k..(node.index() + 1) * k;
+        (
+            &self.from_landmarks[range.clone()],
+            &self.to_landmarks[range],
+        )
+    }
+}
+
+/// Returns the distances from `origin` to all nodes, following the edges
+/// returned by `neighbors`.
+fn distances<NI, F, I>(node_count: usize, origin: NI, neighbors: F) -> Vec<f32>
+where
+    NI: Idx,
+    F: Fn(NI) -> I,
+    I: Iterator<Item = (NI, f32)>,
+{
+    let mut distances = vec![f32::INFINITY; node_count];
+    let mut settled = vec![false; node_count];
+    distances[origin.index()] = 0.0;
+
+    let mut queue = BinaryHeap::from([QueueEntry {
+        priority: 0.0,
+        node: origin,
+    }]);
+
+    while let Some(QueueEntry { node, priority }) = queue.pop() {
+        if std::mem::replace(&mut settled[node.index()], true) {
+            continue;
+        }
+        for (target, weight) in neighbors(node) {
+            let candidate = priority + weight;
+            if candidate < distances[target.index()] {
+                distances[target.index()] = candidate;
+                queue.push(QueueEntry {
+                    priority: candidate,
+                    node: target,
+                });
+            }
+        }
+    }
+
+    distances
+}
+
+/// Interleaves the distances of all landmarks, such that the distances of
+/// each node are stored next to each other.
+fn per_node(node_count: usize, per_landmark: &[Vec<f32>]) -> Vec<f32> {
+    (0..node_count)
+        .into_par_iter()
+        .flat_map_iter(|node| per_landmark.iter().map(move |distances| distances[node]))
+        .collect()
+}
+
+/// Returns the node with the largest distance to its closest landmark,
+/// preferring lower node ids, or `None` if all nodes are landmarks.
+fn farthest(closest: &[f32]) -> Option<usize> {
+    closest
+        .par_iter()
+        .enumerate()
+        .filter(|(_, distance)| **distance > 0.0)
+        .reduce_with(|a, b| if b.1 > a.1 { b } else { a })
+        .map(|(node, _)| node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    fn graph() -> DirectedCsrGraph<u32, (), f32> {
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges_with_values(vec![
+                (0, 1, 4.0),
+                (0, 2, 2.0),
+                (1, 2, 5.0),
+                (1, 3, 10.0),
+                (2, 4, 3.0),
+                (3, 5, 11.0),
+                (4, 3, 4.0),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn test_bounds_enclose_distances() {
+        let graph = graph();
+
+        for selection in [LandmarkSelection::Farthest, LandmarkSelection::Random] {
+            let index = LandmarkIndex::weighted(&graph, LandmarkConfig::new(2, selection, 3));
+            assert_eq!(index.landmarks().len(), 2);
+
+            for source in 0..graph.node_count() {
+                let paths = dijkstra(&graph, source);
+                for target in 0..graph.node_count() {
+                    let distance = paths.distance(target);
+                    assert!(index.lower_bound(source, target) <= distance);
+                    assert!(index.upper_bound(source, target) >= distance);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_with_all_landmarks() {
+        let graph = graph();
+        let index = LandmarkIndex::weighted(
+            &graph,
+            LandmarkConfig::new(100, LandmarkSelection::Farthest, 3),
+        );
+
+        assert_eq!(index.landmarks().len(), 6);
+        assert_eq!(index.upper_bound(0, 5), 20.0);
+        assert_eq!(index.lower_bound(0, 5), 20.0);
+        assert_eq!(index.lower_bound(3, 0), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_farthest_selection() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4), (5, 6)])
+            .build();
+
+        let index = LandmarkIndex::unweighted(
+            &graph,
+            LandmarkConfig::new(3, LandmarkSelection::Farthest, 1),
+        );
+        let landmarks = index.landmarks();
+
+        // The second landmark is in the other component, the third one at
+        // the far end of the path.
+        assert_eq!(landmarks.len(), 3);
+        assert!(landmarks[..2].iter().any(|&node| node >= 5));
+        assert_eq!(index.lower_bound(0, 4), 4.0);
+    }
+
+    #[test]
+    fn test_astar() {
+        let graph = graph();
+        let index = LandmarkIndex::weighted(
+            &graph,
+            LandmarkConfig::new(2, LandmarkSelection::Farthest, 3),
+        );
+
+        let paths = index.astar(&graph, 0, 5);
+
+        assert_eq!(paths.distance(5), 20.0);
+        assert_eq!(paths.path_to(5), Some(vec![0, 2, 4, 3, 5]));
+    }
+}
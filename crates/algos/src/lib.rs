@@ -139,18 +139,92 @@
 //!
 //! assert_eq!(ranks, expected);
 //! ```
+//!
+//! # WebAssembly
+//!
+//! With `default-features = false`, the crate compiles to
+//! `wasm32-unknown-unknown`. On that target, algorithms such as PageRank,
+//! BFS-based traversals and WCC run single-threaded on the calling thread.
+//! Algorithms that use rayon run on the calling thread as well, since the
+//! rayon thread pool falls back to it if no threads can be spawned.
 
 pub mod afforest;
+pub mod betweenness;
+pub mod bidirectional;
+pub mod descendants;
 pub mod dss;
+pub mod edge_betweenness;
+pub mod embeddings;
+pub mod fast_rp;
+pub mod frontier;
+pub mod generate;
+pub mod hyperball;
+pub mod isomorphism;
+mod hyperloglog;
+pub mod k_core;
+pub mod label_propagation;
+pub mod landmarks;
+pub mod local_community;
+pub mod louvain;
+pub mod node2vec;
+pub mod normalize;
 pub mod page_rank;
+pub mod partition;
+pub mod pregel;
 pub mod prelude;
+pub mod progress;
+pub mod projection;
+pub mod random_walk;
+pub mod reachability;
+pub mod sampling;
+pub mod shortest_path;
+pub mod spmv;
 pub mod sssp;
+pub mod summary;
+pub mod top_k;
 pub mod triangle_count;
 pub mod utils;
 pub mod wcc;
+pub mod weights;
 
 const DEFAULT_PARALLELISM: usize = 4;
 
+/// The number of node ranges per worker that kernels create with
+/// [`edge_balanced_partition`](graph_builder::edge_balanced::edge_balanced_partition).
+/// Workers claim ranges one at a time, so that workers that finish early
+/// take over ranges of the others.
+const RANGES_PER_WORKER: usize = 64;
+
+/// Returns the number of workers that [`run_workers`] runs.
+fn worker_count() -> usize {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    return std::thread::available_parallelism().map_or(DEFAULT_PARALLELISM, |p| p.get());
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    return 1;
+}
+
+/// Runs `worker` on all available threads and waits until every thread is
+/// done. Workers are expected to claim their share of the work from shared
+/// state, e.g., an atomic chunk counter.
+///
+/// On targets without threads, i.e., `wasm32-unknown-unknown`, a single
+/// worker runs on the current thread and processes all of the work.
+fn run_workers<F>(worker: F)
+where
+    F: Fn() + Sync,
+{
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    std::thread::scope(|s| {
+        for _ in 0..worker_count() {
+            s.spawn(&worker);
+        }
+    });
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    worker();
+}
+
 // Related to https://github.com/rust-lang/rust/issues/72686
 // `unused_crate_dependencies` does not differentiate between `dev-dependencies` and `dependencies`
 // so we fake the usage by blank importing the dev0dependencies in test scope.
@@ -0,0 +1,344 @@
+//! Local community detection around a seed node.
+//!
+//! The algorithm finds a community with a low conductance around a seed node
+//! in two steps [1]:
+//!
+//! 1. **Push**: Personalized PageRank scores with respect to the seed node
+//!    are approximated by pushing probability mass from the seed node to its
+//!    neighbors, until the residual mass of every node is small compared to
+//!    its degree.
+//! 2. **Sweep**: Nodes are sorted by their score divided by their degree.
+//!    Among all prefixes of this order, the one with the lowest conductance
+//!    is returned as the community.
+//!
+//! Both steps only touch nodes close to the seed node, so the running time
+//! depends on the size of the community rather than the size of the graph.
+//!
+//! [1] Reid Andersen, Fan Chung, Kevin Lang:
+//! "Local Graph Partitioning using PageRank Vectors",
+//! IEEE Symposium on Foundations of Computer Science, 2006
+
+use graph_builder::time::Instant;
+
+use ahash::{AHashMap, AHashSet};
+use log::info;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct LocalCommunityConfig {
+    /// The probability to teleport back to the seed node in each step of
+    /// the personalized PageRank random walk. Higher values result in
+    /// smaller communities.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LocalCommunityConfig::DEFAULT_ALPHA))]
+    pub alpha: f64,
+
+    /// The residual mass per unit of degree below which nodes are no longer
+    /// pushed. Lower values explore a larger part of the graph.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LocalCommunityConfig::DEFAULT_EPSILON))]
+    pub epsilon: f64,
+}
+
+impl Default for LocalCommunityConfig {
+    fn default() -> Self {
+        Self {
+            alpha: Self::DEFAULT_ALPHA,
+            epsilon: Self::DEFAULT_EPSILON,
+        }
+    }
+}
+
+impl LocalCommunityConfig {
+    pub const DEFAULT_ALPHA: f64 = 0.15;
+    pub const DEFAULT_EPSILON: f64 = 1E-4;
+
+    pub fn new(alpha: f64, epsilon: f64) -> Self {
+        Self { alpha, epsilon }
+    }
+}
+
+/// The community found around a seed node.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalCommunity<NI> {
+    /// The nodes of the community in descending order of their degree
+    /// normalized personalized PageRank score. The seed node is always
+    /// part of the community.
+    pub nodes: Vec<NI>,
+    /// The number of edges leaving the community divided by the smaller of
+    /// the volumes of the community and the rest of the graph.
+    pub conductance: f64,
+    /// The sum of the degrees of the nodes of the community.
+    pub volume: usize,
+}
+
+/// Finds a community with a low conductance around the given seed node of
+/// the undirected graph.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// // two triangles connected by a single edge
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Deduplicated)
+///     .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+///     .build();
+///
+/// let mut community = local_community(&graph, 0, LocalCommunityConfig::default());
+/// community.nodes.sort_unstable();
+///
+/// assert_eq!(community.nodes, vec![0, 1, 2]);
+/// assert_eq!(community.conductance, 1.0 / 7.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the seed node does not exist.
+pub fn local_community<NI, G>(
+    graph: &G,
+    seed_node: NI,
+    config: LocalCommunityConfig,
+) -> LocalCommunity<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI>,
+{
+    assert!(
+        seed_node < graph.node_count(),
+        "seed node {seed_node:?} does not exist"
+    );
+
+    let start = Instant::now();
+
+    let scores = approximate_personalized_page_rank(graph, seed_node, config);
+    let community = sweep_cut(graph, seed_node, scores);
+
+    info!(
+        "Found a community of {} nodes with a conductance of {:.6} in {:?}",
+        community.nodes.len(),
+        community.conductance,
+        start.elapsed()
+    );
+
+    community
+}
+
+/// Approximates the personalized PageRank scores with respect to the seed
+/// node using the push algorithm of Andersen, Chung and Lang.
+///
+/// Returns the score of every node that received probability mass in
+/// descending order of the scores. The error of each score is at most
+/// `epsilon` times the degree of its node.
+///
+/// # Panics
+///
+/// Panics if the seed node does not exist.
+pub fn approximate_personalized_page_rank<NI, G>(
+    graph: &G,
+    seed_node: NI,
+    config: LocalCommunityConfig,
+) -> Vec<(NI, f64)>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI>,
+{
+    assert!(
+        seed_node < graph.node_count(),
+        "seed node {seed_node:?} does not exist"
+    );
+
+    let LocalCommunityConfig { alpha, epsilon } = config;
+
+    let mut scores = AHashMap::<usize, f64>::new();
+    let mut residuals = AHashMap::<usize, f64>::new();
+    residuals.insert(seed_node.index(), 1.0);
+    let mut queue = vec![seed_node.index()];
+
+    while let Some(u) = queue.pop() {
+        let residual = residuals[&u];
+        let degree = graph.degree(NI::new(u)).index() as f64;
+
+        if degree == 0.0 {
+            *scores.entry(u).or_insert(0.0) += residual;
+            residuals.insert(u, 0.0);
+            continue;
+        }
+        if residual < epsilon * degree {
+            continue;
+        }
+
+        // Lazy random walk: keep half of the remaining mass at `u` and
+        // spread the other half evenly among its neighbors.
+        *scores.entry(u).or_insert(0.0) += alpha * residual;
+        let remaining = (1.0 - alpha) * residual / 2.0;
+        residuals.insert(u, remaining);
+        if remaining >= epsilon * degree {
+            queue.push(u);
+        }
+
+        let share = remaining / degree;
+        for v in graph.neighbors(NI::new(u)) {
+            let residual = residuals.entry(v.index()).or_insert(0.0);
+            let threshold = epsilon * graph.degree(*v).index() as f64;
+            let was_queued = *residual >= threshold;
+            *residual += share;
+            if !was_queued && *residual >= threshold {
+                queue.push(v.index());
+            }
+        }
+    }
+
+    let mut scores = scores
+        .into_iter()
+        .map(|(node, score)| (NI::new(node), score))
+        .collect::<Vec<_>>();
+    scores.sort_unstable_by(|(u, su), (v, sv)| sv.total_cmp(su).then(u.cmp(v)));
+    scores
+}
+
+/// Returns the prefix with the lowest conductance among the nodes sorted by
+/// their degree normalized score.
+fn sweep_cut<NI, G>(graph: &G, seed_node: NI, scores: Vec<(NI, f64)>) -> LocalCommunity<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI>,
+{
+    let mut order = scores
+        .into_iter()
+        .filter(|(node, score)| *score > 0.0 || *node == seed_node)
+        .map(|(node, score)| {
+            let degree = graph.degree(node).index();
+            (node, score / usize::max(degree, 1) as f64)
+        })
+        .collect::<Vec<_>>();
+    // The seed node receives no score if its residual is below the push
+    // threshold from the start.
+    if !order.iter().any(|(node, _)| *node == seed_node) {
+        order.push((seed_node, 0.0));
+    }
+    // Ties are broken by node id to make the order deterministic, the seed
+    // node always comes first.
+    order.sort_unstable_by(|(u, su), (v, sv)| {
+        (*v == seed_node)
+            .cmp(&(*u == seed_node))
+            .then(sv.total_cmp(su))
+            .then(u.cmp(v))
+    });
+
+    let total_volume = 2 * graph.edge_count().index();
+
+    let mut members = AHashSet::<usize>::with_capacity(order.len());
+    let mut volume = 0;
+    let mut cut = 0_isize;
+
+    let mut best_len = 1;
+    let mut best_conductance = f64::INFINITY;
+    let mut best_volume = 0;
+
+    for (len, (u, _)) in order.iter().enumerate() {
+        members.insert(u.index());
+        let degree = graph.degree(*u).index();
+        let internal = graph
+            .neighbors(*u)
+            .filter(|v| members.contains(&v.index()))
+            .count();
+
+        // Edges to previous members no longer leave the community, all
+        // other edges of `u` do.
+        volume += degree;
+        cut += degree as isize - 2 * internal as isize;
+
+        let denominator = usize::min(volume, total_volume - volume);
+        let conductance = if denominator == 0 {
+            1.0
+        } else {
+            cut as f64 / denominator as f64
+        };
+
+        if conductance < best_conductance {
+            best_len = len + 1;
+            best_conductance = conductance;
+            best_volume = volume;
+        }
+    }
+
+    LocalCommunity {
+        nodes: order[..best_len].iter().map(|(node, _)| *node).collect(),
+        conductance: best_conductance,
+        volume: best_volume,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder, UndirectedCsrGraph};
+
+    // two 4-cliques connected by a single edge
+    fn two_cliques() -> UndirectedCsrGraph<usize> {
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(vec![
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (4, 5),
+                (4, 6),
+                (4, 7),
+                (5, 6),
+                (5, 7),
+                (6, 7),
+                (3, 4),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn test_local_community_two_cliques() {
+        let graph = two_cliques();
+
+        for (seed_node, expected) in [(0, vec![0, 1, 2, 3]), (6, vec![4, 5, 6, 7])] {
+            let mut community = local_community(&graph, seed_node, LocalCommunityConfig::default());
+            community.nodes.sort_unstable();
+
+            assert_eq!(community.nodes, expected);
+            assert_eq!(community.volume, 13);
+            assert_eq!(community.conductance, 1.0 / 13.0);
+        }
+    }
+
+    #[test]
+    fn test_approximate_personalized_page_rank() {
+        let graph = two_cliques();
+
+        let scores =
+            approximate_personalized_page_rank(&graph, 0, LocalCommunityConfig::new(0.15, 1E-6));
+
+        let total = scores.iter().map(|(_, score)| score).sum::<f64>();
+        assert!(total <= 1.0 && total > 0.99);
+        let score = |node| scores.iter().find(|(v, _)| *v == node).unwrap().1;
+        assert_eq!(scores[0].0, 0);
+        assert!(score(3) > score(4));
+        assert!(score(4) > score(7));
+    }
+
+    #[test]
+    fn test_local_community_of_isolated_node() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1)])
+            .node_count(3)
+            .build();
+
+        let community = local_community(&graph, 2, LocalCommunityConfig::default());
+
+        assert_eq!(community.nodes, vec![2]);
+        assert_eq!(community.volume, 0);
+    }
+}
@@ -0,0 +1,740 @@
+//! Louvain community detection.
+//!
+//! The algorithm greedily optimizes the modularity of a partitioning of an
+//! undirected graph [1]. It repeats two phases until the modularity no longer
+//! improves:
+//!
+//! 1. **Local moving**: Each node is moved to the community of one of its
+//!    neighbors if this results in the highest modularity gain.
+//! 2. **Aggregation**: The communities are contracted into single nodes,
+//!    edges between them are summed up to weighted edges.
+//!
+//! Nodes are visited in a seeded random order during local moving, which
+//! makes the result depend only on the graph and the seed.
+//!
+//! [1] Vincent D. Blondel, Jean-Loup Guillaume, Renaud Lambiotte, Etienne Lefebvre:
+//! "Fast unfolding of communities in large networks",
+//! Journal of Statistical Mechanics: Theory and Experiment, 2008
+
+use graph_builder::time::Instant;
+
+use ahash::AHashMap;
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::label_propagation::relabel_communities;
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct LouvainConfig {
+    /// The maximum number of local moving passes per level.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LouvainConfig::DEFAULT_MAX_ITERATIONS))]
+    pub max_iterations: usize,
+
+    /// If the modularity gain of a level is below the
+    /// tolerance value, the computation stops.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LouvainConfig::DEFAULT_TOLERANCE))]
+    pub tolerance: f64,
+
+    /// The seed for randomized node visiting orders.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = LouvainConfig::DEFAULT_SEED))]
+    pub seed: u64,
+
+    /// If set, the result additionally contains the communities of every
+    /// level, see [`Dendrogram`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub dendrogram: bool,
+}
+
+impl Default for LouvainConfig {
+    fn default() -> Self {
+        Self {
+            max_iterations: Self::DEFAULT_MAX_ITERATIONS,
+            tolerance: Self::DEFAULT_TOLERANCE,
+            seed: Self::DEFAULT_SEED,
+            dendrogram: false,
+        }
+    }
+}
+
+impl LouvainConfig {
+    pub const DEFAULT_MAX_ITERATIONS: usize = 10;
+    pub const DEFAULT_TOLERANCE: f64 = 1E-4;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(max_iterations: usize, tolerance: f64, seed: u64) -> Self {
+        Self {
+            max_iterations,
+            tolerance,
+            seed,
+            dendrogram: false,
+        }
+    }
+}
+
+/// The result of running Louvain on a graph.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Louvain<NI> {
+    /// The community id per node. Community ids are consecutive,
+    /// starting at zero.
+    pub communities: Vec<NI>,
+    /// The modularity of the final communities.
+    pub modularity: f64,
+    /// The number of levels, i.e., how often the graph has been aggregated.
+    pub levels: usize,
+    /// The communities of every level if [`LouvainConfig::dendrogram`] is
+    /// set, `None` otherwise.
+    pub dendrogram: Option<Dendrogram<NI>>,
+}
+
+/// The communities that Louvain found on each level.
+///
+/// Level `0` contains the communities of the first local moving phase, each
+/// following level the communities of the graph aggregated from the previous
+/// level. Communities get coarser with each level, the last level contains
+/// the final communities. Community ids are consecutive per level, starting
+/// at zero.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Deduplicated)
+///     .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+///     .build();
+///
+/// let config = LouvainConfig {
+///     dendrogram: true,
+///     ..LouvainConfig::default()
+/// };
+/// let result = louvain(&graph, config);
+/// let dendrogram = result.dendrogram.unwrap();
+///
+/// assert_eq!(dendrogram.level_count(), result.levels);
+/// assert_eq!(dendrogram.cut(2), Some(&[0, 0, 0, 1, 1, 1][..]));
+/// assert_eq!(dendrogram.cut(1), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dendrogram<NI> {
+    levels: Vec<Vec<NI>>,
+    community_counts: Vec<usize>,
+}
+
+impl<NI: Idx> Dendrogram<NI> {
+    /// Returns the number of levels.
+    pub fn level_count(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns the community id per node on the given level, or `None` if
+    /// the level does not exist.
+    pub fn level(&self, level: usize) -> Option<&[NI]> {
+        self.levels.get(level).map(Vec::as_slice)
+    }
+
+    /// Returns the number of communities on the given level, or `None` if
+    /// the level does not exist.
+    pub fn community_count(&self, level: usize) -> Option<usize> {
+        self.community_counts.get(level).copied()
+    }
+
+    /// Returns the community id per node on the finest level that has at
+    /// most `community_count` communities, or `None` if every level has
+    /// more communities.
+    pub fn cut(&self, community_count: usize) -> Option<&[NI]> {
+        self.community_counts
+            .iter()
+            .position(|count| *count <= community_count)
+            .map(|level| self.levels[level].as_slice())
+    }
+}
+
+/// Computes communities of the given undirected graph using the Louvain
+/// method.
+///
+/// Parallel edges increase the weight between two nodes, a self-loop is
+/// treated like an edge that stays inside the community of its node.
+pub fn louvain<NI, G>(graph: &G, config: LouvainConfig) -> Louvain<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    louvain_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`louvain`] and reports each finished level of the `louvain` task
+/// to the given tracker. The number of levels is not known upfront.
+pub fn louvain_with_progress<NI, G, T>(graph: &G, config: LouvainConfig, tracker: &T) -> Louvain<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    louvain_inner(graph, config, None, tracker)
+}
+
+/// Runs [`louvain`] starting from the given seed communities instead of a
+/// community per node.
+///
+/// Fixed seed communities are only extended by unseeded nodes and never
+/// merged with each other. The seed communities are applied even if they
+/// decrease the modularity.
+///
+/// # Panics
+///
+/// Panics if a seed refers to a node that does not exist.
+pub fn louvain_from_seeds<NI, G>(
+    graph: &G,
+    config: LouvainConfig,
+    seeds: &CommunitySeeds<NI>,
+) -> Louvain<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    louvain_from_seeds_with_progress(graph, config, seeds, &NoProgress)
+}
+
+/// Runs [`louvain_from_seeds`] and reports each finished level of the
+/// `louvain` task to the given tracker.
+///
+/// # Panics
+///
+/// Panics if a seed refers to a node that does not exist.
+pub fn louvain_from_seeds_with_progress<NI, G, T>(
+    graph: &G,
+    config: LouvainConfig,
+    seeds: &CommunitySeeds<NI>,
+    tracker: &T,
+) -> Louvain<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    louvain_inner(graph, config, Some(seeds), tracker)
+}
+
+fn louvain_inner<NI, G, T>(
+    graph: &G,
+    config: LouvainConfig,
+    seeds: Option<&CommunitySeeds<NI>>,
+    tracker: &T,
+) -> Louvain<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let start = Instant::now();
+    let mut rng = WyRand::new_seed(config.seed);
+
+    let mut level = Level::from_graph(graph);
+    let mut communities = (0..level.node_count()).collect::<Vec<_>>();
+    let mut modularity = level.modularity(&communities);
+    let mut levels = 0;
+
+    let (mut initial, mut fixed) = match seeds {
+        Some(seeds) => seeds.initial_communities(level.node_count()),
+        None => (communities.clone(), vec![false; level.node_count()]),
+    };
+    let mut dendrogram = Dendrogram {
+        levels: Vec::new(),
+        community_counts: Vec::new(),
+    };
+
+    loop {
+        let level_start = Instant::now();
+        let assignment = level.local_moving(initial, &fixed, config.max_iterations, &mut rng);
+        let level_modularity = level.modularity(&assignment);
+        let gain = level_modularity - modularity;
+        let seeded = levels == 0 && seeds.is_some();
+
+        info!(
+            "Finished level {} with a modularity of {:.6} in {:?}",
+            levels,
+            level_modularity,
+            level_start.elapsed()
+        );
+
+        if gain < config.tolerance && !seeded {
+            break;
+        }
+
+        let (coarse, mapping) = level.aggregate(&assignment);
+        // A community that contains a fixed node must not join another one.
+        let mut coarse_fixed = vec![false; coarse.node_count()];
+        fixed
+            .iter()
+            .zip(mapping.iter())
+            .for_each(|(fixed, community)| coarse_fixed[*community] |= fixed);
+        communities
+            .iter_mut()
+            .for_each(|community| *community = mapping[*community]);
+        modularity = level_modularity;
+        levels += 1;
+        if config.dendrogram {
+            dendrogram.levels.push(relabel_communities(&communities));
+            dendrogram.community_counts.push(coarse.node_count());
+        }
+        tracker.progress("louvain", levels, None);
+
+        if coarse.node_count() == level.node_count() || tracker.is_cancelled() {
+            break;
+        }
+        initial = (0..coarse.node_count()).collect();
+        fixed = coarse_fixed;
+        level = coarse;
+    }
+
+    let communities = relabel_communities::<NI>(&communities);
+
+    info!(
+        "Computed {} communities with a modularity of {:.6} in {:?}",
+        communities.iter().map(|c| c.index() + 1).max().unwrap_or(0),
+        modularity,
+        start.elapsed()
+    );
+
+    Louvain {
+        communities,
+        modularity,
+        levels,
+        dendrogram: config.dendrogram.then_some(dendrogram),
+    }
+}
+
+/// Computes the modularity of the given communities, where `communities`
+/// contains the community id of each node.
+pub fn modularity<NI, G>(graph: &G, communities: &[NI]) -> f64
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    let communities = communities.iter().map(|c| c.index()).collect::<Vec<_>>();
+    let communities = relabel_communities::<usize>(&communities);
+    Level::from_graph(graph).modularity(&communities)
+}
+
+/// Contracts the nodes of each community into a single node and returns the
+/// resulting quotient graph, where `assignment` contains the community id of
+/// each node.
+///
+/// Community `c` becomes node `c` of the quotient graph, which therefore has
+/// as many nodes as the largest community id plus one. The value of an edge
+/// between two communities is the number of edges between their nodes. Edges
+/// inside a community are contracted into a self-loop whose value is the
+/// number of these edges. Parallel edges of the given graph are counted
+/// individually.
+///
+/// This is the aggregation phase of [`louvain`], which can be used to
+/// visualize or further process a graph at the granularity of communities.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Deduplicated)
+///     .edges(vec![(0, 1), (1, 2), (0, 2), (2, 3), (3, 4)])
+///     .build();
+///
+/// let quotient = contract(&graph, &[0, 0, 0, 1, 1]);
+///
+/// assert_eq!(quotient.node_count(), 2);
+/// assert_eq!(
+///     quotient.neighbors_with_values(1).as_slice(),
+///     &[Target::new(0, 1.0), Target::new(1, 1.0), Target::new(1, 1.0)]
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if `assignment` does not contain a community id for every node.
+pub fn contract<NI, G>(graph: &G, assignment: &[NI]) -> UndirectedCsrGraph<NI, (), f64>
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    assert_eq!(
+        assignment.len(),
+        graph.node_count().index(),
+        "expected a community for each node"
+    );
+
+    let communities = assignment.iter().map(|c| c.index()).collect::<Vec<_>>();
+    let community_count = communities.iter().map(|c| c + 1).max().unwrap_or(0);
+    let adjacency = Level::from_graph(graph).contract(&communities, community_count);
+
+    // Every edge between two communities is contained in the adjacency of
+    // both, every edge inside a community twice in its own adjacency.
+    let edges = adjacency
+        .into_iter()
+        .enumerate()
+        .flat_map(|(u, neighbors)| {
+            neighbors
+                .into_iter()
+                .filter(move |(v, _)| u <= *v)
+                .map(move |(v, weight)| {
+                    let weight = if u == v { weight / 2.0 } else { weight };
+                    (NI::new(u), NI::new(v), weight)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    GraphBuilder::new()
+        .csr_layout(CsrLayout::Sorted)
+        .edges_with_values(edges)
+        .node_count(community_count)
+        .build()
+}
+
+/// A weighted graph, where a self-loop carries the weight of the edges
+/// inside a contracted community.
+struct Level {
+    adjacency: Vec<Vec<(usize, f64)>>,
+    degrees: Vec<f64>,
+    total_weight: f64,
+}
+
+impl Level {
+    fn from_graph<NI, G>(graph: &G) -> Self
+    where
+        NI: Idx,
+        G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    {
+        let adjacency = (0..graph.node_count().index())
+            .into_par_iter()
+            .map(|u| {
+                let mut neighbors = graph
+                    .neighbors(NI::new(u))
+                    .map(|v| v.index())
+                    .collect::<Vec<_>>();
+                neighbors.sort_unstable();
+                let mut weighted = Vec::<(usize, f64)>::with_capacity(neighbors.len());
+                for v in neighbors {
+                    match weighted.last_mut() {
+                        Some((last, weight)) if *last == v => *weight += 1.0,
+                        _ => weighted.push((v, 1.0)),
+                    }
+                }
+                weighted
+            })
+            .collect::<Vec<_>>();
+
+        Self::from_adjacency(adjacency)
+    }
+
+    fn from_adjacency(adjacency: Vec<Vec<(usize, f64)>>) -> Self {
+        let degrees = adjacency
+            .iter()
+            .map(|neighbors| neighbors.iter().map(|(_, weight)| weight).sum())
+            .collect::<Vec<f64>>();
+        let total_weight = degrees.iter().sum();
+
+        Self {
+            adjacency,
+            degrees,
+            total_weight,
+        }
+    }
+
+    fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    fn modularity(&self, communities: &[usize]) -> f64 {
+        if self.total_weight == 0.0 {
+            return 0.0;
+        }
+
+        let mut internal = vec![0.0; self.node_count()];
+        let mut totals = vec![0.0; self.node_count()];
+
+        for (u, neighbors) in self.adjacency.iter().enumerate() {
+            let cu = communities[u];
+            totals[cu] += self.degrees[u];
+            for (v, weight) in neighbors {
+                if communities[*v] == cu {
+                    internal[cu] += weight;
+                }
+            }
+        }
+
+        internal
+            .iter()
+            .zip(totals.iter())
+            .map(|(internal, total)| {
+                internal / self.total_weight - (total / self.total_weight).powi(2)
+            })
+            .sum()
+    }
+
+    /// Moves nodes between communities, starting from the given ones, until
+    /// no move improves the modularity or `max_iterations` passes have been
+    /// executed. Fixed nodes are never moved. Returns the community of each
+    /// node.
+    fn local_moving(
+        &self,
+        mut communities: Vec<usize>,
+        fixed: &[bool],
+        max_iterations: usize,
+        rng: &mut WyRand,
+    ) -> Vec<usize> {
+        let mut totals = vec![0.0; self.node_count()];
+        for (community, degree) in communities.iter().zip(self.degrees.iter()) {
+            totals[*community] += degree;
+        }
+
+        if self.total_weight == 0.0 {
+            return communities;
+        }
+
+        let mut order = (0..self.node_count()).collect::<Vec<_>>();
+        let mut weights = AHashMap::<usize, f64>::new();
+
+        for _ in 0..max_iterations {
+            rng.shuffle(&mut order);
+            let mut moved = 0;
+
+            for &u in &order {
+                if fixed[u] {
+                    continue;
+                }
+                let current = communities[u];
+                let degree = self.degrees[u];
+
+                weights.clear();
+                weights.insert(current, 0.0);
+                for &(v, weight) in &self.adjacency[u] {
+                    if v != u {
+                        *weights.entry(communities[v]).or_insert(0.0) += weight;
+                    }
+                }
+
+                totals[current] -= degree;
+
+                let gain = |community: usize, weight: f64| {
+                    weight - totals[community] * degree / self.total_weight
+                };
+
+                let mut best = current;
+                let mut best_gain = gain(current, weights[&current]);
+                for (&community, &weight) in &weights {
+                    let gain = gain(community, weight);
+                    // Ties are broken by community id to be independent of
+                    // the iteration order of the map.
+                    if gain > best_gain
+                        || (gain == best_gain && best != current && community < best)
+                    {
+                        best = community;
+                        best_gain = gain;
+                    }
+                }
+
+                totals[best] += degree;
+                if best != current {
+                    communities[u] = best;
+                    moved += 1;
+                }
+            }
+
+            if moved == 0 {
+                break;
+            }
+        }
+
+        communities
+    }
+
+    /// Contracts the given communities into single nodes and returns the
+    /// coarse graph together with the coarse node id of each node.
+    fn aggregate(&self, communities: &[usize]) -> (Level, Vec<usize>) {
+        let mut mapping = vec![usize::MAX; self.node_count()];
+        let mut coarse_count = 0;
+        for &community in communities {
+            if mapping[community] == usize::MAX {
+                mapping[community] = coarse_count;
+                coarse_count += 1;
+            }
+        }
+
+        let node_mapping = communities.iter().map(|c| mapping[*c]).collect::<Vec<_>>();
+        let adjacency = self.contract(&node_mapping, coarse_count);
+
+        (Self::from_adjacency(adjacency), node_mapping)
+    }
+
+    /// Sums up the weights of the edges between each pair of communities,
+    /// where every community id is smaller than `community_count`. Returns
+    /// the weighted adjacency of the communities, sorted by neighbor.
+    fn contract(&self, communities: &[usize], community_count: usize) -> Vec<Vec<(usize, f64)>> {
+        let mut adjacency = vec![AHashMap::<usize, f64>::new(); community_count];
+        for (u, neighbors) in self.adjacency.iter().enumerate() {
+            let cu = communities[u];
+            for &(v, weight) in neighbors {
+                let cv = communities[v];
+                *adjacency[cu].entry(cv).or_insert(0.0) += weight;
+            }
+        }
+
+        adjacency
+            .into_iter()
+            .map(|neighbors| {
+                let mut neighbors = neighbors.into_iter().collect::<Vec<_>>();
+                neighbors.sort_unstable_by_key(|(v, _)| *v);
+                neighbors
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder, UndirectedCsrGraph};
+
+    // two 4-cliques connected by a single edge
+    fn two_cliques() -> UndirectedCsrGraph<usize> {
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(vec![
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (4, 5),
+                (4, 6),
+                (4, 7),
+                (5, 6),
+                (5, 7),
+                (6, 7),
+                (3, 4),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn test_louvain_two_cliques() {
+        let graph = two_cliques();
+
+        let result = louvain(&graph, LouvainConfig::default());
+
+        let (a, e) = (result.communities[0], result.communities[4]);
+        assert_ne!(a, e);
+        assert!(result.communities[..4].iter().all(|c| *c == a));
+        assert!(result.communities[4..].iter().all(|c| *c == e));
+        assert!(result.levels >= 1);
+
+        // 2 * (12 / 26 - (13 / 26)^2)
+        let expected = 2.0 * (12.0 / 26.0 - 0.25);
+        assert!((result.modularity - expected).abs() < 1E-9);
+        assert!((modularity(&graph, &result.communities) - expected).abs() < 1E-9);
+    }
+
+    #[test]
+    fn test_louvain_dendrogram() {
+        let graph = two_cliques();
+
+        let result = louvain(&graph, LouvainConfig::default());
+        assert!(result.dendrogram.is_none());
+
+        let config = LouvainConfig {
+            dendrogram: true,
+            ..LouvainConfig::default()
+        };
+        let result = louvain(&graph, config);
+        let dendrogram = result.dendrogram.unwrap();
+
+        assert_eq!(dendrogram.level_count(), result.levels);
+        assert_eq!(
+            dendrogram.level(result.levels - 1),
+            Some(result.communities.as_slice())
+        );
+        assert_eq!(dendrogram.community_count(result.levels - 1), Some(2));
+        assert_eq!(dendrogram.cut(8), dendrogram.level(0));
+        assert_eq!(dendrogram.cut(2), Some(result.communities.as_slice()));
+        assert_eq!(dendrogram.cut(1), None);
+        assert_eq!(dendrogram.level(result.levels), None);
+    }
+
+    #[test]
+    fn test_louvain_from_fixed_seeds() {
+        let graph = two_cliques();
+
+        let seeds = CommunitySeeds::fixed(vec![(0, 0), (4, 1), (3, 1)]);
+        let result = louvain_from_seeds(&graph, LouvainConfig::default(), &seeds);
+
+        assert_eq!(result.communities[3], result.communities[4]);
+        assert_ne!(result.communities[0], result.communities[3]);
+        assert!(result.levels >= 1);
+    }
+
+    #[test]
+    fn test_louvain_from_warm_start_seeds() {
+        let graph = two_cliques();
+
+        let seeds = CommunitySeeds::warm_start(vec![(0, 0), (1, 0), (4, 1), (5, 1)]);
+        let result = louvain_from_seeds(&graph, LouvainConfig::default(), &seeds);
+
+        let (a, e) = (result.communities[0], result.communities[4]);
+        assert_ne!(a, e);
+        assert!(result.communities[..4].iter().all(|c| *c == a));
+        assert!(result.communities[4..].iter().all(|c| *c == e));
+    }
+
+    #[test]
+    fn test_modularity_single_community() {
+        let graph = two_cliques();
+
+        let communities = vec![0; graph.node_count()];
+
+        assert!(modularity(&graph, &communities).abs() < 1E-9);
+    }
+
+    #[test]
+    fn test_contract_two_cliques() {
+        let graph = two_cliques();
+
+        let quotient = contract(&graph, &[0, 0, 0, 0, 2, 2, 2, 2]);
+
+        assert_eq!(quotient.node_count(), 3);
+        assert_eq!(quotient.degree(1), 0);
+        assert_eq!(
+            quotient.neighbors_with_values(2).as_slice(),
+            &[
+                Target::new(0, 1.0),
+                Target::new(2, 6.0),
+                Target::new(2, 6.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_louvain_without_edges() {
+        let gdl = "(a),(b),(c)";
+
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .gdl_str::<usize, _>(gdl)
+            .build()
+            .unwrap();
+
+        let result = louvain(&graph, LouvainConfig::default());
+
+        assert_eq!(result.communities, vec![0, 1, 2]);
+        assert_eq!(result.modularity, 0.0);
+        assert_eq!(result.levels, 0);
+    }
+}
@@ -0,0 +1,406 @@
+//! Node2Vec node embeddings.
+//!
+//! Node2Vec [1] computes biased random walks (see [`random_walk`](crate::random_walk))
+//! and treats each walk like a sentence of words. The embeddings are then
+//! learned using the skip-gram model with negative sampling [2], i.e., the
+//! embeddings of nodes that appear close to each other in the walks are
+//! trained to be similar, while the embeddings of randomly sampled nodes
+//! are trained to be dissimilar.
+//!
+//! The walks are computed in parallel, but the training runs on a single
+//! thread, which makes the embeddings depend only on the graph and the seed.
+//!
+//! [1] Aditya Grover, Jure Leskovec:
+//! "node2vec: Scalable Feature Learning for Networks",
+//! KDD 2016
+//!
+//! [2] Tomas Mikolov, Ilya Sutskever, Kai Chen, Greg Corrado, Jeffrey Dean:
+//! "Distributed Representations of Words and Phrases and their Compositionality",
+//! NIPS 2013
+
+use graph_builder::time::Instant;
+
+use log::info;
+use nanorand::{Rng, WyRand};
+
+use crate::embeddings::Embeddings;
+use crate::prelude::*;
+use crate::random_walk::{random_walks, RandomWalkConfig, WalkNeighbors};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct Node2VecConfig {
+    /// The number of values per node embedding.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_EMBEDDING_DIMENSION))]
+    pub embedding_dimension: usize,
+
+    /// The maximum number of nodes in a walk, including the start node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_WALK_LENGTH))]
+    pub walk_length: usize,
+
+    /// The number of walks that start at each node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_WALKS_PER_NODE))]
+    pub walks_per_node: usize,
+
+    /// The node2vec parameter `p`, a higher value makes
+    /// walks less likely to return to the previous node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_RETURN_FACTOR))]
+    pub return_factor: f64,
+
+    /// The node2vec parameter `q`, a higher value makes
+    /// walks stay closer to the previous node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_IN_OUT_FACTOR))]
+    pub in_out_factor: f64,
+
+    /// The maximum distance between two nodes of a
+    /// walk that are trained to be similar.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_WINDOW_SIZE))]
+    pub window_size: usize,
+
+    /// The number of random nodes that are trained to
+    /// be dissimilar for each pair of similar nodes.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_NEGATIVE_SAMPLES))]
+    pub negative_samples: usize,
+
+    /// The number of training passes over all walks.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_ITERATIONS))]
+    pub iterations: usize,
+
+    /// The initial learning rate, which linearly
+    /// decreases towards zero during training.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_LEARNING_RATE))]
+    pub learning_rate: f32,
+
+    /// The seed for the walks, the initial embeddings
+    /// and the negative samples.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = Node2VecConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for Node2VecConfig {
+    fn default() -> Self {
+        Self {
+            embedding_dimension: Self::DEFAULT_EMBEDDING_DIMENSION,
+            walk_length: Self::DEFAULT_WALK_LENGTH,
+            walks_per_node: Self::DEFAULT_WALKS_PER_NODE,
+            return_factor: Self::DEFAULT_RETURN_FACTOR,
+            in_out_factor: Self::DEFAULT_IN_OUT_FACTOR,
+            window_size: Self::DEFAULT_WINDOW_SIZE,
+            negative_samples: Self::DEFAULT_NEGATIVE_SAMPLES,
+            iterations: Self::DEFAULT_ITERATIONS,
+            learning_rate: Self::DEFAULT_LEARNING_RATE,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl Node2VecConfig {
+    pub const DEFAULT_EMBEDDING_DIMENSION: usize = 128;
+    pub const DEFAULT_WALK_LENGTH: usize = RandomWalkConfig::DEFAULT_WALK_LENGTH;
+    pub const DEFAULT_WALKS_PER_NODE: usize = RandomWalkConfig::DEFAULT_WALKS_PER_NODE;
+    pub const DEFAULT_RETURN_FACTOR: f64 = RandomWalkConfig::DEFAULT_RETURN_FACTOR;
+    pub const DEFAULT_IN_OUT_FACTOR: f64 = RandomWalkConfig::DEFAULT_IN_OUT_FACTOR;
+    pub const DEFAULT_WINDOW_SIZE: usize = 10;
+    pub const DEFAULT_NEGATIVE_SAMPLES: usize = 5;
+    pub const DEFAULT_ITERATIONS: usize = 1;
+    pub const DEFAULT_LEARNING_RATE: f32 = 0.025;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(embedding_dimension: usize) -> Self {
+        Self {
+            embedding_dimension,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the configuration of the random walks that are used
+    /// for training.
+    pub fn walk_config(&self) -> RandomWalkConfig {
+        RandomWalkConfig {
+            walk_length: self.walk_length,
+            walks_per_node: self.walks_per_node,
+            return_factor: self.return_factor,
+            in_out_factor: self.in_out_factor,
+            seed: self.seed,
+        }
+    }
+}
+
+/// Computes Node2Vec embeddings for all nodes of the given graph.
+///
+/// For directed graphs, the walks follow outgoing edges.
+///
+/// # Panics
+///
+/// Panics if `config.embedding_dimension` is zero.
+pub fn node2vec<NI, G>(graph: &G, config: Node2VecConfig) -> Embeddings
+where
+    NI: Idx,
+    G: Graph<NI> + WalkNeighbors<NI> + Sync,
+{
+    node2vec_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`node2vec`] and reports the number of walks that the model has
+/// been trained on as the `node2vec` task to the given tracker. Updates are
+/// sent in batches of about one percent of all training steps.
+///
+/// # Panics
+///
+/// Panics if `config.embedding_dimension` is zero.
+pub fn node2vec_with_progress<NI, G, T>(
+    graph: &G,
+    config: Node2VecConfig,
+    tracker: &T,
+) -> Embeddings
+where
+    NI: Idx,
+    G: Graph<NI> + WalkNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    assert!(
+        config.embedding_dimension > 0,
+        "embedding dimension must be positive"
+    );
+
+    let start = Instant::now();
+    let node_count = graph.node_count().index();
+    let dimension = config.embedding_dimension;
+
+    let walks = random_walks(graph, config.walk_config());
+    let walks = walks
+        .into_iter()
+        .map(|walk| walk.into_iter().map(Idx::index).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut rng = WyRand::new_seed(config.seed);
+    let mut embeddings = (0..node_count * dimension)
+        .map(|_| (rng.generate::<f32>() - 0.5) / dimension as f32)
+        .collect::<Vec<_>>();
+    let mut contexts = vec![0.0_f32; node_count * dimension];
+
+    let negatives = NegativeSampler::new(node_count, &walks);
+    let mut gradient = vec![0.0_f32; dimension];
+
+    let steps = config.iterations * walks.len();
+    let total_steps = steps.max(1) as f32;
+    let progress_batch = (steps / 100).max(1);
+    let min_learning_rate = config.learning_rate * 1E-4;
+
+    'training: for iteration in 0..config.iterations {
+        let iteration_start = Instant::now();
+
+        for (i, walk) in walks.iter().enumerate() {
+            let step = (iteration * walks.len() + i) as f32;
+            let learning_rate =
+                (config.learning_rate * (1.0 - step / total_steps)).max(min_learning_rate);
+
+            for (position, &node) in walk.iter().enumerate() {
+                let from = position.saturating_sub(config.window_size);
+                let to = (position + config.window_size + 1).min(walk.len());
+
+                for (other, &context) in walk.iter().enumerate().take(to).skip(from) {
+                    if other == position {
+                        continue;
+                    }
+
+                    let embedding = &mut embeddings[node * dimension..(node + 1) * dimension];
+                    gradient.fill(0.0);
+
+                    train_pair(
+                        embedding,
+                        &mut contexts[context * dimension..(context + 1) * dimension],
+                        &mut gradient,
+                        1.0,
+                        learning_rate,
+                    );
+
+                    for _ in 0..config.negative_samples {
+                        let negative = negatives.sample(&mut rng);
+                        if negative == context {
+                            continue;
+                        }
+                        train_pair(
+                            embedding,
+                            &mut contexts[negative * dimension..(negative + 1) * dimension],
+                            &mut gradient,
+                            0.0,
+                            learning_rate,
+                        );
+                    }
+
+                    embedding
+                        .iter_mut()
+                        .zip(gradient.iter())
+                        .for_each(|(value, gradient)| *value += gradient);
+                }
+            }
+
+            let completed = iteration * walks.len() + i + 1;
+            if completed % progress_batch == 0 || completed == steps {
+                tracker.progress("node2vec", completed, Some(steps));
+                if tracker.is_cancelled() {
+                    break 'training;
+                }
+            }
+        }
+
+        info!(
+            "Finished iteration {} in {:?}",
+            iteration,
+            iteration_start.elapsed()
+        );
+    }
+
+    info!(
+        "Computed Node2Vec embeddings with dimension {} in {:?}",
+        dimension,
+        start.elapsed()
+    );
+
+    Embeddings::new(dimension, embeddings)
+}
+
+/// Updates the context vector for a single (node, context) pair and
+/// accumulates the update of the node embedding in `gradient`.
+fn train_pair(
+    embedding: &[f32],
+    context: &mut [f32],
+    gradient: &mut [f32],
+    label: f32,
+    learning_rate: f32,
+) {
+    let dot = embedding
+        .iter()
+        .zip(context.iter())
+        .map(|(a, b)| a * b)
+        .sum::<f32>();
+    let g = (label - sigmoid(dot)) * learning_rate;
+
+    gradient
+        .iter_mut()
+        .zip(context.iter())
+        .for_each(|(gradient, context)| *gradient += g * context);
+    context
+        .iter_mut()
+        .zip(embedding.iter())
+        .for_each(|(context, embedding)| *context += g * embedding);
+}
+
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x.clamp(-6.0, 6.0)).exp())
+}
+
+/// Samples nodes proportional to their frequency in the walks
+/// raised to the power of `0.75`.
+struct NegativeSampler {
+    cumulative: Vec<f64>,
+}
+
+impl NegativeSampler {
+    fn new(node_count: usize, walks: &[Vec<usize>]) -> Self {
+        let mut frequencies = vec![0_u64; node_count];
+        walks
+            .iter()
+            .flatten()
+            .for_each(|node| frequencies[*node] += 1);
+
+        let mut total = 0.0;
+        let cumulative = frequencies
+            .into_iter()
+            .map(|frequency| {
+                total += (frequency as f64).powf(0.75);
+                total
+            })
+            .collect();
+
+        Self { cumulative }
+    }
+
+    fn sample(&self, rng: &mut WyRand) -> usize {
+        let total = self.cumulative.last().copied().unwrap_or(0.0);
+        let target = rng.generate::<f64>() * total;
+        self.cumulative
+            .partition_point(|sum| *sum <= target)
+            .min(self.cumulative.len().saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder, UndirectedCsrGraph};
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        let dot = a.iter().zip(b).map(|(a, b)| a * b).sum::<f32>();
+        let norm_a = a.iter().map(|a| a * a).sum::<f32>().sqrt();
+        let norm_b = b.iter().map(|b| b * b).sum::<f32>().sqrt();
+        dot / (norm_a * norm_b)
+    }
+
+    // two 4-cliques connected by a single edge
+    fn two_cliques() -> UndirectedCsrGraph<usize> {
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(vec![
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (4, 5),
+                (4, 6),
+                (4, 7),
+                (5, 6),
+                (5, 7),
+                (6, 7),
+                (3, 4),
+            ])
+            .build()
+    }
+
+    fn config() -> Node2VecConfig {
+        Node2VecConfig {
+            walk_length: 20,
+            walks_per_node: 20,
+            window_size: 3,
+            iterations: 5,
+            ..Node2VecConfig::new(16)
+        }
+    }
+
+    #[test]
+    fn test_node2vec_is_reproducible() {
+        let graph = two_cliques();
+
+        let embeddings = node2vec(&graph, config());
+
+        assert_eq!(embeddings.dimension(), 16);
+        assert_eq!(embeddings.node_count(), 8);
+        assert_eq!(embeddings, node2vec(&graph, config()));
+        assert_ne!(
+            embeddings,
+            node2vec(
+                &graph,
+                Node2VecConfig {
+                    seed: 7,
+                    ..config()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_node2vec_clusters() {
+        let graph = two_cliques();
+
+        let embeddings = node2vec(&graph, config());
+
+        let same = cosine(embeddings.embedding(0), embeddings.embedding(1));
+        let other = cosine(embeddings.embedding(0), embeddings.embedding(6));
+
+        assert!(same > other, "{same} <= {other}");
+    }
+}
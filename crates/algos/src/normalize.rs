@@ -0,0 +1,208 @@
+//! Post-processing of node scores, such as page rank scores or distances.
+//!
+//! Scores are transformed in place and in parallel. Statistics that a
+//! transformation depends on, e.g., the minimum and maximum for
+//! [`Normalization::MinMax`], are computed over all finite scores only and
+//! non-finite scores, e.g., the infinite distances of unreachable nodes,
+//! are left unchanged by those transformations.
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let mut scores = vec![1.0, 3.0, 2.0, 5.0];
+//! normalize(&mut scores, Normalization::MinMax);
+//!
+//! assert_eq!(scores, vec![0.0, 0.5, 0.25, 1.0]);
+//! ```
+
+use graph_builder::time::Instant;
+
+use log::info;
+use rayon::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Normalization {
+    /// Scales the scores linearly to `[0, 1]`, i.e., `(x - min) / (max - min)`.
+    /// If all scores are equal, they are set to `0`.
+    MinMax,
+    /// Divides the scores by the sum of their absolute values.
+    L1,
+    /// Divides the scores by the square root of the sum of their squares.
+    L2,
+    /// Shifts and scales the scores to a mean of `0` and a standard
+    /// deviation of `1`. If all scores are equal, they are set to `0`.
+    Standard,
+    /// Replaces each score `x` with `ln(1 + x)`, which compresses scores
+    /// spanning several orders of magnitude. Scores must be greater than `-1`.
+    Log,
+    /// Replaces each score with its rank, where the highest score has rank
+    /// `1` and equal scores share the lowest of their ranks. `NaN` scores
+    /// are ranked last.
+    Rank,
+}
+
+/// Applies the given normalization to the scores in place.
+pub fn normalize(scores: &mut [f32], normalization: Normalization) {
+    let start = Instant::now();
+
+    match normalization {
+        Normalization::MinMax => min_max(scores),
+        Normalization::L1 => {
+            let norm = finite_sum(scores, |x| x.abs());
+            scale(scores, 0.0, norm);
+        }
+        Normalization::L2 => {
+            let norm = finite_sum(scores, |x| x * x).sqrt();
+            scale(scores, 0.0, norm);
+        }
+        Normalization::Standard => standardize(scores),
+        Normalization::Log => scores.par_iter_mut().for_each(|x| *x = x.ln_1p()),
+        Normalization::Rank => rank(scores),
+    }
+
+    info!(
+        "Applied {:?} normalization to {} scores in {:?}",
+        normalization,
+        scores.len(),
+        start.elapsed()
+    );
+}
+
+/// Computes `(x - offset) / divisor` for all finite scores, or sets them to
+/// `0` if the divisor is `0`.
+fn scale(scores: &mut [f32], offset: f64, divisor: f64) {
+    scores
+        .par_iter_mut()
+        .filter(|x| x.is_finite())
+        .for_each(|x| {
+            *x = if divisor == 0.0 {
+                0.0
+            } else {
+                ((*x as f64 - offset) / divisor) as f32
+            }
+        });
+}
+
+fn finite_sum(scores: &[f32], f: impl Fn(f64) -> f64 + Sync) -> f64 {
+    scores
+        .par_iter()
+        .filter(|x| x.is_finite())
+        .map(|&x| f(x as f64))
+        .sum()
+}
+
+fn min_max(scores: &mut [f32]) {
+    let (min, max) = scores
+        .par_iter()
+        .filter(|x| x.is_finite())
+        .fold(
+            || (f32::INFINITY, f32::NEG_INFINITY),
+            |(min, max), &x| (min.min(x), max.max(x)),
+        )
+        .reduce(
+            || (f32::INFINITY, f32::NEG_INFINITY),
+            |(min1, max1), (min2, max2)| (min1.min(min2), max1.max(max2)),
+        );
+
+    if min <= max {
+        scale(scores, min as f64, max as f64 - min as f64);
+    }
+}
+
+fn standardize(scores: &mut [f32]) {
+    let (count, sum) = scores
+        .par_iter()
+        .filter(|x| x.is_finite())
+        .map(|&x| (1_usize, x as f64))
+        .reduce(|| (0, 0.0), |(c1, s1), (c2, s2)| (c1 + c2, s1 + s2));
+
+    if count == 0 {
+        return;
+    }
+
+    let mean = sum / count as f64;
+    let variance = finite_sum(scores, |x| (x - mean) * (x - mean)) / count as f64;
+    scale(scores, mean, variance.sqrt());
+}
+
+fn rank(scores: &mut [f32]) {
+    let mut order = (0..scores.len()).collect::<Vec<_>>();
+    order.par_sort_unstable_by(|&a, &b| {
+        let (a, b) = (scores[a], scores[b]);
+        a.is_nan().cmp(&b.is_nan()).then(b.total_cmp(&a))
+    });
+
+    let mut ranks = vec![0_f32; scores.len()];
+    let mut rank = 1;
+    for (position, &node) in order.iter().enumerate() {
+        if position > 0 && scores[node] != scores[order[position - 1]] {
+            rank = position + 1;
+        }
+        ranks[node] = rank as f32;
+    }
+
+    scores.copy_from_slice(&ranks);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalized(scores: &[f32], normalization: Normalization) -> Vec<f32> {
+        let mut scores = scores.to_vec();
+        normalize(&mut scores, normalization);
+        scores
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(
+            normalized(&[2.0, 4.0, f32::INFINITY, 3.0], Normalization::MinMax),
+            vec![0.0, 1.0, f32::INFINITY, 0.5]
+        );
+        assert_eq!(
+            normalized(&[7.0, 7.0], Normalization::MinMax),
+            vec![0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_l1_and_l2() {
+        assert_eq!(
+            normalized(&[1.0, -3.0, 4.0], Normalization::L1),
+            vec![0.125, -0.375, 0.5]
+        );
+        assert_eq!(normalized(&[3.0, 4.0], Normalization::L2), vec![0.6, 0.8]);
+        assert_eq!(normalized(&[0.0, 0.0], Normalization::L2), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_standard() {
+        assert_eq!(
+            normalized(&[1.0, 3.0, 5.0, 7.0], Normalization::Standard),
+            vec![-1.341_640_8, -0.447_213_6, 0.447_213_6, 1.341_640_8]
+        );
+    }
+
+    #[test]
+    fn test_log() {
+        assert_eq!(
+            normalized(&[0.0, std::f32::consts::E - 1.0], Normalization::Log),
+            vec![0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(
+            normalized(&[0.5, f32::NAN, 0.9, 0.5, 0.1], Normalization::Rank),
+            vec![2.0, 5.0, 1.0, 2.0, 4.0]
+        );
+    }
+}
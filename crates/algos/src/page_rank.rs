@@ -1,15 +1,13 @@
-use crate::{prelude::*, DEFAULT_PARALLELISM};
+use crate::{prelude::*, run_workers, worker_count, RANGES_PER_WORKER};
 
 use atomic_float::AtomicF64;
 use graph_builder::SharedMut;
 use log::info;
 use rayon::prelude::*;
 
-use std::sync::atomic::Ordering;
-use std::thread::available_parallelism;
-use std::time::Instant;
-
-const CHUNK_SIZE: usize = 16384;
+use graph_builder::time::Instant;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -29,6 +27,14 @@ pub struct PageRankConfig {
     /// surfer will continue at any step.
     #[cfg_attr(feature = "clap", clap(long, default_value_t = PageRankConfig::DEFAULT_DAMPING_FACTOR))]
     pub damping_factor: f32,
+
+    /// If set, the given number of highest-ranked nodes is
+    /// logged after the last iteration and returned by
+    /// [`TopK::top_nodes`]. The computation itself always
+    /// scores all nodes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub top_k: Option<usize>,
 }
 
 impl Default for PageRankConfig {
@@ -37,10 +43,17 @@ impl Default for PageRankConfig {
             max_iterations: Self::DEFAULT_MAX_ITERATIONS,
             tolerance: Self::DEFAULT_TOLERANCE,
             damping_factor: Self::DEFAULT_DAMPING_FACTOR,
+            top_k: None,
         }
     }
 }
 
+impl TopK for PageRankConfig {
+    fn top_k(&self) -> Option<usize> {
+        self.top_k
+    }
+}
+
 impl PageRankConfig {
     pub const DEFAULT_MAX_ITERATIONS: usize = 20;
     pub const DEFAULT_TOLERANCE: f64 = 1E-4;
@@ -51,6 +64,7 @@ impl PageRankConfig {
             max_iterations,
             tolerance,
             damping_factor,
+            top_k: None,
         }
     }
 }
@@ -59,15 +73,93 @@ pub fn page_rank<NI, G>(graph: &G, config: PageRankConfig) -> (Vec<f32>, usize,
 where
     NI: Idx,
     G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+{
+    page_rank_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`page_rank`] and reports each finished iteration of the `page_rank`
+/// task to the given tracker.
+pub fn page_rank_with_progress<NI, G, T>(
+    graph: &G,
+    config: PageRankConfig,
+    tracker: &T,
+) -> (Vec<f32>, usize, f64)
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    let node_count = graph.node_count().index();
+    let initial_scores = vec![1_f32 / node_count as f32; node_count];
+
+    page_rank_inner(graph, config, initial_scores, tracker)
+}
+
+/// Runs [`page_rank`] starting from the given scores instead of the uniform
+/// distribution, e.g., the scores of a previous run on a slightly changed
+/// graph. The closer the initial scores are to the final scores, the fewer
+/// iterations are needed to reach the tolerance.
+///
+/// # Panics
+///
+/// Panics if the number of initial scores differs from the number of nodes.
+pub fn page_rank_from_scores<NI, G>(
+    graph: &G,
+    config: PageRankConfig,
+    initial_scores: Vec<f32>,
+) -> (Vec<f32>, usize, f64)
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+{
+    page_rank_from_scores_with_progress(graph, config, initial_scores, &NoProgress)
+}
+
+/// Runs [`page_rank_from_scores`] and reports each finished iteration of the
+/// `page_rank` task to the given tracker.
+///
+/// # Panics
+///
+/// Panics if the number of initial scores differs from the number of nodes.
+pub fn page_rank_from_scores_with_progress<NI, G, T>(
+    graph: &G,
+    config: PageRankConfig,
+    initial_scores: Vec<f32>,
+    tracker: &T,
+) -> (Vec<f32>, usize, f64)
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
+{
+    assert_eq!(
+        initial_scores.len(),
+        graph.node_count().index(),
+        "expected one initial score per node"
+    );
+
+    page_rank_inner(graph, config, initial_scores, tracker)
+}
+
+fn page_rank_inner<NI, G, T>(
+    graph: &G,
+    config: PageRankConfig,
+    mut scores: Vec<f32>,
+    tracker: &T,
+) -> (Vec<f32>, usize, f64)
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+    T: ProgressTracker + ?Sized,
 {
     let PageRankConfig {
         max_iterations,
         tolerance,
         damping_factor,
+        ..
     } = config;
 
     let node_count = graph.node_count().index();
-    let init_score = 1_f32 / node_count as f32;
     let base_score = (1.0_f32 - damping_factor) / node_count as f32;
 
     let mut out_scores = Vec::with_capacity(node_count);
@@ -75,20 +167,27 @@ where
     (0..node_count)
         .into_par_iter()
         .map(NI::new)
-        .map(|node| init_score / graph.out_degree(node).index() as f32)
+        .map(|node| scores[node.index()] / graph.out_degree(node).index() as f32)
         .collect_into_vec(&mut out_scores);
 
-    let mut scores = vec![init_score; node_count];
-
     let scores_ptr = SharedMut::new(scores.as_mut_ptr());
     let out_scores_ptr = SharedMut::new(out_scores.as_mut_ptr());
 
+    // The work per node is dominated by summing up the scores of its incoming
+    // neighbors, so the ranges are balanced by in-degree.
+    let ranges = edge_balanced_partition(
+        graph.node_count(),
+        worker_count() * RANGES_PER_WORKER,
+        |node| graph.in_degree(node).index(),
+    );
+
     let mut iteration = 0;
 
     loop {
         let start = Instant::now();
         let error = page_rank_iteration(
             graph,
+            &ranges,
             base_score,
             damping_factor,
             &out_scores_ptr,
@@ -103,8 +202,12 @@ where
         );
 
         iteration += 1;
+        tracker.progress("page_rank", iteration, Some(max_iterations));
 
-        if error < tolerance || iteration == max_iterations {
+        if error < tolerance || iteration == max_iterations || tracker.is_cancelled() {
+            if let Some(top_nodes) = config.top_nodes::<NI, _>(&scores) {
+                info!("Top {} nodes: {:?}", top_nodes.len(), top_nodes);
+            }
             return (scores, iteration, error);
         }
     }
@@ -112,6 +215,7 @@ where
 
 fn page_rank_iteration<NI, G>(
     graph: &G,
+    ranges: &[Range<NI>],
     base_score: f32,
     damping_factor: f32,
     out_scores: &SharedMut<f32>,
@@ -121,47 +225,34 @@ where
     NI: Idx,
     G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
 {
-    let next_chunk = Atomic::new(NI::zero());
+    let next_range = AtomicUsize::new(0);
     let total_error = AtomicF64::new(0_f64);
 
-    std::thread::scope(|s| {
-        let num_threads = available_parallelism().map_or(DEFAULT_PARALLELISM, |p| p.get());
-
-        for _ in 0..num_threads {
-            s.spawn(|| {
-                let mut error = 0_f64;
-
-                loop {
-                    let start = NI::fetch_add(&next_chunk, NI::new(CHUNK_SIZE), Ordering::AcqRel);
-                    if start >= graph.node_count() {
-                        break;
-                    }
+    run_workers(|| {
+        let mut error = 0_f64;
 
-                    let end = (start + NI::new(CHUNK_SIZE)).min(graph.node_count());
+        while let Some(range) = ranges.get(next_range.fetch_add(1, Ordering::AcqRel)) {
+            for u in range.start.range(range.end) {
+                let incoming_total = graph
+                    .in_neighbors(u)
+                    .map(|v| unsafe { out_scores.add(v.index()).read() })
+                    .sum::<f32>();
 
-                    for u in start.range(end) {
-                        let incoming_total = graph
-                            .in_neighbors(u)
-                            .map(|v| unsafe { out_scores.add(v.index()).read() })
-                            .sum::<f32>();
+                let old_score = unsafe { scores.add(u.index()).read() };
+                let new_score = base_score + damping_factor * incoming_total;
 
-                        let old_score = unsafe { scores.add(u.index()).read() };
-                        let new_score = base_score + damping_factor * incoming_total;
+                unsafe { scores.add(u.index()).write(new_score) };
+                let diff = (new_score - old_score) as f64;
+                error += f64::abs(diff);
 
-                        unsafe { scores.add(u.index()).write(new_score) };
-                        let diff = (new_score - old_score) as f64;
-                        error += f64::abs(diff);
-
-                        unsafe {
-                            out_scores
-                                .add(u.index())
-                                .write(new_score / graph.out_degree(u).index() as f32)
-                        }
-                    }
+                unsafe {
+                    out_scores
+                        .add(u.index())
+                        .write(new_score / graph.out_degree(u).index() as f32)
                 }
-                total_error.fetch_add(error, Ordering::SeqCst);
-            });
+            }
         }
+        total_error.fetch_add(error, Ordering::SeqCst);
     });
 
     total_error.load(Ordering::SeqCst)
@@ -195,4 +286,30 @@ mod tests {
 
         assert_eq!(scores, expected);
     }
+
+    #[test]
+    fn test_pr_from_scores() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 0), (1, 0), (2, 3), (3, 0)])
+            .build();
+
+        let config = PageRankConfig::new(100, 1E-6, PageRankConfig::DEFAULT_DAMPING_FACTOR);
+        let (scores, cold_iterations, _) = page_rank(&graph, config);
+        let (warm_scores, warm_iterations, _) =
+            page_rank_from_scores(&graph, config, scores.clone());
+
+        assert!(warm_iterations < cold_iterations);
+        for (warm, cold) in warm_scores.iter().zip(scores.iter()) {
+            assert!((warm - cold).abs() < 1E-5);
+        }
+    }
+
+    #[test]
+    #[should_panic = "expected one initial score per node"]
+    fn test_pr_from_scores_wrong_length() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+
+        page_rank_from_scores(&graph, PageRankConfig::default(), vec![0.5]);
+    }
 }
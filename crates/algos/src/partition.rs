@@ -0,0 +1,490 @@
+//! Multilevel k-way graph partitioning.
+//!
+//! The algorithm assigns each node of an undirected graph to one of `k`
+//! partitions such that the partitions have roughly the same size and the
+//! number of edges between partitions (the edge cut) is small. It follows the
+//! multilevel scheme popularized by METIS [1]:
+//!
+//! 1. **Coarsening**: The graph is repeatedly contracted by collapsing the
+//!    nodes of a heavy-edge matching into a single node until it is small.
+//! 2. **Initial partitioning**: The coarsest graph is partitioned by growing
+//!    regions in breadth-first order.
+//! 3. **Uncoarsening**: The partitioning is projected back to the finer
+//!    graphs and improved on each level by greedily moving boundary nodes to
+//!    the neighboring partition with the highest gain.
+//!
+//! [1] George Karypis, Vipin Kumar:
+//! "A Fast and High Quality Multilevel Scheme for Partitioning Irregular Graphs",
+//! SIAM Journal on Scientific Computing, 1998
+
+use graph_builder::time::Instant;
+use std::collections::VecDeque;
+
+use ahash::AHashMap;
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct PartitionConfig {
+    /// The number of partitions.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = PartitionConfig::DEFAULT_PARTITIONS))]
+    pub partitions: usize,
+
+    /// The allowed imbalance of partition sizes, e.g., `0.03` allows each
+    /// partition to be 3% larger than the average partition size.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = PartitionConfig::DEFAULT_IMBALANCE))]
+    pub imbalance: f64,
+
+    /// Coarsening stops once the graph has at most this many nodes per
+    /// partition.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = PartitionConfig::DEFAULT_COARSEN_TO))]
+    pub coarsen_to: usize,
+
+    /// The maximum number of refinement passes per level.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = PartitionConfig::DEFAULT_REFINEMENT_ROUNDS))]
+    pub refinement_rounds: usize,
+
+    /// The seed for randomized matching and node visiting orders.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = PartitionConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for PartitionConfig {
+    fn default() -> Self {
+        Self {
+            partitions: Self::DEFAULT_PARTITIONS,
+            imbalance: Self::DEFAULT_IMBALANCE,
+            coarsen_to: Self::DEFAULT_COARSEN_TO,
+            refinement_rounds: Self::DEFAULT_REFINEMENT_ROUNDS,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl PartitionConfig {
+    pub const DEFAULT_PARTITIONS: usize = 2;
+    pub const DEFAULT_IMBALANCE: f64 = 0.03;
+    pub const DEFAULT_COARSEN_TO: usize = 20;
+    pub const DEFAULT_REFINEMENT_ROUNDS: usize = 8;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(partitions: usize, imbalance: f64) -> Self {
+        Self {
+            partitions,
+            imbalance,
+            ..Self::default()
+        }
+    }
+}
+
+/// The result of partitioning a graph.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Partitioning {
+    /// The partition id per node.
+    pub partitions: Vec<usize>,
+    /// The number of nodes per partition.
+    pub partition_sizes: Vec<usize>,
+    /// The number of edges whose end nodes are in different partitions.
+    pub edge_cut: u64,
+}
+
+impl Partitioning {
+    /// Returns the size of the largest partition divided by the average
+    /// partition size.
+    pub fn imbalance(&self) -> f64 {
+        let node_count = self.partitions.len();
+        let max_size = self.partition_sizes.iter().copied().max().unwrap_or(0);
+        if node_count == 0 {
+            return 1.0;
+        }
+        max_size as f64 * self.partition_sizes.len() as f64 / node_count as f64
+    }
+}
+
+/// Partitions the given undirected graph into `config.partitions` parts.
+///
+/// Self-loops are ignored, parallel edges are treated as a single edge with
+/// a higher weight.
+pub fn partition<NI, G>(graph: &G, config: PartitionConfig) -> Partitioning
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    let start = Instant::now();
+    let k = usize::max(1, config.partitions);
+    let mut rng = WyRand::new_seed(config.seed);
+
+    let mut levels = vec![Level::from_graph(graph)];
+    let mut mappings = Vec::new();
+
+    let coarsen_to = usize::max(config.coarsen_to, 1) * k;
+    loop {
+        let level = levels.last().unwrap();
+        if level.node_count() <= coarsen_to {
+            break;
+        }
+        let (coarse, mapping) = level.coarsen(&mut rng);
+        // Stop if matching no longer reduces the graph noticeably.
+        if coarse.node_count() * 10 > level.node_count() * 9 {
+            break;
+        }
+        levels.push(coarse);
+        mappings.push(mapping);
+    }
+
+    info!(
+        "Coarsened graph to {} nodes in {} levels in {:?}",
+        levels.last().unwrap().node_count(),
+        levels.len(),
+        start.elapsed()
+    );
+
+    let total_weight = levels[0].node_weights.iter().sum::<u64>();
+    let max_weight = ((total_weight as f64 / k as f64) * (1.0 + config.imbalance)).ceil() as u64;
+
+    let coarsest = levels.last().unwrap();
+    let mut parts = coarsest.grow_regions(k, total_weight, &mut rng);
+    coarsest.refine(
+        &mut parts,
+        k,
+        max_weight,
+        config.refinement_rounds,
+        &mut rng,
+    );
+
+    for (level, mapping) in levels.iter().rev().skip(1).zip(mappings.iter().rev()) {
+        parts = mapping.iter().map(|coarse| parts[*coarse]).collect();
+        level.refine(
+            &mut parts,
+            k,
+            max_weight,
+            config.refinement_rounds,
+            &mut rng,
+        );
+    }
+
+    let edge_cut = (0..graph.node_count().index())
+        .into_par_iter()
+        .map(|u| {
+            graph
+                .neighbors(NI::new(u))
+                .filter(|v| v.index() > u && parts[v.index()] != parts[u])
+                .count() as u64
+        })
+        .sum();
+
+    let mut partition_sizes = vec![0; k];
+    for part in &parts {
+        partition_sizes[*part] += 1;
+    }
+
+    info!(
+        "Computed {k} partitions with an edge cut of {edge_cut} in {:?}",
+        start.elapsed()
+    );
+
+    Partitioning {
+        partitions: parts,
+        partition_sizes,
+        edge_cut,
+    }
+}
+
+/// A weighted graph without self-loops in CSR representation.
+struct Level {
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<u64>,
+    node_weights: Vec<u64>,
+}
+
+impl Level {
+    fn from_graph<NI, G>(graph: &G) -> Self
+    where
+        NI: Idx,
+        G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    {
+        let node_count = graph.node_count().index();
+
+        let adjacency = (0..node_count)
+            .into_par_iter()
+            .map(|u| {
+                let mut neighbors = graph
+                    .neighbors(NI::new(u))
+                    .map(|v| v.index())
+                    .filter(|v| *v != u)
+                    .collect::<Vec<_>>();
+                neighbors.sort_unstable();
+                let mut weighted = Vec::<(usize, u64)>::with_capacity(neighbors.len());
+                for v in neighbors {
+                    match weighted.last_mut() {
+                        Some((last, weight)) if *last == v => *weight += 1,
+                        _ => weighted.push((v, 1)),
+                    }
+                }
+                weighted
+            })
+            .collect::<Vec<_>>();
+
+        Self::from_adjacency(adjacency, vec![1; node_count])
+    }
+
+    fn from_adjacency(adjacency: Vec<Vec<(usize, u64)>>, node_weights: Vec<u64>) -> Self {
+        let mut offsets = Vec::with_capacity(adjacency.len() + 1);
+        let mut targets = Vec::new();
+        let mut weights = Vec::new();
+
+        offsets.push(0);
+        for neighbors in adjacency {
+            for (target, weight) in neighbors {
+                targets.push(target);
+                weights.push(weight);
+            }
+            offsets.push(targets.len());
+        }
+
+        Self {
+            offsets,
+            targets,
+            weights,
+            node_weights,
+        }
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_weights.len()
+    }
+
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, u64)> + '_ {
+        let range = self.offsets[node]..self.offsets[node + 1];
+        self.targets[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.weights[range].iter().copied())
+    }
+
+    fn shuffled_nodes(&self, rng: &mut WyRand) -> Vec<usize> {
+        let mut nodes = (0..self.node_count()).collect::<Vec<_>>();
+        rng.shuffle(&mut nodes);
+        nodes
+    }
+
+    /// Contracts a heavy-edge matching and returns the coarse graph together
+    /// with the coarse node id of each node.
+    fn coarsen(&self, rng: &mut WyRand) -> (Level, Vec<usize>) {
+        const UNMATCHED: usize = usize::MAX;
+
+        let mut mapping = vec![UNMATCHED; self.node_count()];
+        let mut coarse_count = 0;
+
+        for u in self.shuffled_nodes(rng) {
+            if mapping[u] != UNMATCHED {
+                continue;
+            }
+            let partner = self
+                .neighbors(u)
+                .filter(|(v, _)| mapping[*v] == UNMATCHED)
+                .max_by_key(|(_, weight)| *weight)
+                .map(|(v, _)| v);
+
+            mapping[u] = coarse_count;
+            if let Some(v) = partner {
+                mapping[v] = coarse_count;
+            }
+            coarse_count += 1;
+        }
+
+        let mut node_weights = vec![0; coarse_count];
+        let mut adjacency = vec![AHashMap::<usize, u64>::new(); coarse_count];
+
+        for u in 0..self.node_count() {
+            let cu = mapping[u];
+            node_weights[cu] += self.node_weights[u];
+            for (v, weight) in self.neighbors(u) {
+                let cv = mapping[v];
+                if cu != cv {
+                    *adjacency[cu].entry(cv).or_insert(0) += weight;
+                }
+            }
+        }
+
+        let adjacency = adjacency
+            .into_iter()
+            .map(|neighbors| {
+                let mut neighbors = neighbors.into_iter().collect::<Vec<_>>();
+                neighbors.sort_unstable();
+                neighbors
+            })
+            .collect();
+
+        (Self::from_adjacency(adjacency, node_weights), mapping)
+    }
+
+    /// Assigns nodes to partitions by growing regions in breadth-first order
+    /// until each region reaches its target weight.
+    fn grow_regions(&self, k: usize, total_weight: u64, rng: &mut WyRand) -> Vec<usize> {
+        const UNASSIGNED: usize = usize::MAX;
+
+        let mut parts = vec![UNASSIGNED; self.node_count()];
+        let mut seeds = self.shuffled_nodes(rng).into_iter();
+        let mut assigned_weight = 0;
+
+        for part in 0..k {
+            let target = total_weight * (part as u64 + 1) / k as u64;
+            let mut queue = VecDeque::new();
+
+            while assigned_weight < target {
+                let u = match queue.pop_front() {
+                    Some(u) => u,
+                    // Continue with a new seed, if the region is disconnected.
+                    None => match seeds.by_ref().find(|u| parts[*u] == UNASSIGNED) {
+                        Some(u) => u,
+                        None => break,
+                    },
+                };
+                if parts[u] != UNASSIGNED {
+                    continue;
+                }
+                parts[u] = part;
+                assigned_weight += self.node_weights[u];
+                queue.extend(
+                    self.neighbors(u)
+                        .map(|(v, _)| v)
+                        .filter(|v| parts[*v] == UNASSIGNED),
+                );
+            }
+        }
+
+        for part in parts.iter_mut().filter(|p| **p == UNASSIGNED) {
+            *part = k - 1;
+        }
+
+        parts
+    }
+
+    /// Greedily moves nodes to the adjacent partition with the highest gain in
+    /// edge cut as long as the balance constraint is not violated. Nodes of
+    /// overweight partitions are moved even if the edge cut increases.
+    fn refine(
+        &self,
+        parts: &mut [usize],
+        k: usize,
+        max_weight: u64,
+        rounds: usize,
+        rng: &mut WyRand,
+    ) {
+        let mut part_weights = vec![0; k];
+        for (u, part) in parts.iter().enumerate() {
+            part_weights[*part] += self.node_weights[u];
+        }
+
+        let mut connectivity = vec![0_u64; k];
+
+        for _ in 0..rounds {
+            let mut moved = 0;
+
+            for u in self.shuffled_nodes(rng) {
+                let own = parts[u];
+                let weight = self.node_weights[u];
+
+                connectivity.iter_mut().for_each(|c| *c = 0);
+                let mut boundary = false;
+                for (v, edge_weight) in self.neighbors(u) {
+                    connectivity[parts[v]] += edge_weight;
+                    boundary |= parts[v] != own;
+                }
+
+                let overweight = part_weights[own] > max_weight;
+                if !boundary && !overweight {
+                    continue;
+                }
+
+                let best = (0..k)
+                    .filter(|p| *p != own && part_weights[*p] + weight <= max_weight)
+                    .filter(|p| overweight || connectivity[*p] > 0)
+                    .max_by_key(|p| (connectivity[*p], std::cmp::Reverse(part_weights[*p])));
+
+                if let Some(best) = best {
+                    let gain = connectivity[best] as i64 - connectivity[own] as i64;
+                    let improves_balance = part_weights[best] + weight < part_weights[own];
+                    if gain > 0 || overweight || (gain == 0 && improves_balance) {
+                        part_weights[own] -= weight;
+                        part_weights[best] += weight;
+                        parts[u] = best;
+                        moved += 1;
+                    }
+                }
+            }
+
+            if moved == 0 {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder, UndirectedCsrGraph};
+
+    fn cliques(count: usize, size: usize) -> UndirectedCsrGraph<usize> {
+        let mut edges = Vec::new();
+        for c in 0..count {
+            let offset = c * size;
+            for u in 0..size {
+                for v in u + 1..size {
+                    edges.push((offset + u, offset + v));
+                }
+            }
+            // connect consecutive cliques by a single edge
+            if c > 0 {
+                edges.push((offset - 1, offset));
+            }
+        }
+
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(edges)
+            .build()
+    }
+
+    #[test]
+    fn test_partition_cliques() {
+        let graph = cliques(4, 32);
+
+        let result = partition(&graph, PartitionConfig::new(4, 0.03));
+
+        assert_eq!(result.partitions.len(), 128);
+        assert_eq!(result.partition_sizes, vec![32; 4]);
+        assert_eq!(result.edge_cut, 3);
+        assert_eq!(result.imbalance(), 1.0);
+    }
+
+    #[test]
+    fn test_partition_balance() {
+        let graph = cliques(1, 100);
+
+        let result = partition(&graph, PartitionConfig::new(3, 0.05));
+
+        let max_size = (100.0_f64 / 3.0 * 1.05).ceil() as usize;
+        assert!(result.partition_sizes.iter().all(|size| *size <= max_size));
+        assert_eq!(result.partition_sizes.iter().sum::<usize>(), 100);
+    }
+
+    #[test]
+    fn test_single_partition() {
+        let graph = cliques(2, 4);
+
+        let result = partition(&graph, PartitionConfig::new(1, 0.0));
+
+        assert_eq!(result.partitions, vec![0; 8]);
+        assert_eq!(result.edge_cut, 0);
+    }
+}
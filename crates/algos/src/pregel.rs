@@ -0,0 +1,484 @@
+//! A vertex-centric computation engine following the Pregel model [1].
+//!
+//! A computation is expressed from the perspective of a single node: in each
+//! superstep, a node receives the messages sent to it in the previous
+//! superstep, updates its state and sends messages to other nodes, usually its
+//! neighbors. A node can vote to halt, which deactivates it until it receives
+//! a new message. The computation terminates when all nodes have halted and no
+//! messages are in flight, or when the maximum number of supersteps is
+//! reached.
+//!
+//! Nodes are computed in parallel. In addition to messages, nodes can
+//! contribute to a global sum, which is available to all nodes in the next
+//! superstep, e.g., to compute a convergence criterion.
+//!
+//! [1] Grzegorz Malewicz et al.:
+//! "Pregel: A System for Large-Scale Graph Processing",
+//! SIGMOD 2010
+//!
+//! # Example
+//!
+//! Computing connected components by propagating the minimum node id:
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! struct MinLabel;
+//!
+//! impl<G: PregelGraph<usize>> PregelComputation<usize, G> for MinLabel {
+//!     type State = usize;
+//!     type Message = usize;
+//!
+//!     fn init(&self, _graph: &G, node: usize) -> usize {
+//!         node
+//!     }
+//!
+//!     fn compute(
+//!         &self,
+//!         ctx: &mut PregelContext<'_, usize, G, usize>,
+//!         label: &mut usize,
+//!         messages: &[usize],
+//!     ) {
+//!         let min = messages.iter().copied().min().unwrap_or(*label);
+//!         if ctx.superstep() == 0 || min < *label {
+//!             *label = min;
+//!             ctx.send_to_neighbors(min);
+//!         }
+//!         ctx.vote_to_halt();
+//!     }
+//! }
+//!
+//! let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 2), (3, 4)])
+//!     .build();
+//!
+//! let result = pregel(&graph, &MinLabel, PregelConfig::default());
+//!
+//! assert_eq!(result.states, vec![0, 0, 0, 3, 3]);
+//! ```
+
+use graph_builder::time::Instant;
+
+use log::info;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct PregelConfig {
+    /// The maximum number of supersteps.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = PregelConfig::DEFAULT_MAX_SUPERSTEPS))]
+    pub max_supersteps: usize,
+}
+
+impl Default for PregelConfig {
+    fn default() -> Self {
+        Self {
+            max_supersteps: Self::DEFAULT_MAX_SUPERSTEPS,
+        }
+    }
+}
+
+impl PregelConfig {
+    pub const DEFAULT_MAX_SUPERSTEPS: usize = 100;
+
+    pub fn new(max_supersteps: usize) -> Self {
+        Self { max_supersteps }
+    }
+}
+
+/// A graph on which Pregel computations can be run.
+///
+/// Messages sent to the neighbors of a node are sent along outgoing edges for
+/// directed graphs and along all edges for undirected graphs.
+pub trait PregelGraph<NI: Idx>: Graph<NI> + Sync {
+    /// The edge value type.
+    type EV;
+
+    /// Calls the given function for each neighbor of the given node.
+    fn for_each_neighbor<F>(&self, node: NI, f: F)
+    where
+        F: FnMut(NI, &Self::EV);
+}
+
+macro_rules! impl_pregel_graph {
+    ($graph:ident, $neighbors:ident) => {
+        impl<NI, NV, EV> PregelGraph<NI> for $graph<NI, NV, EV>
+        where
+            NI: Idx,
+            NV: Send + Sync,
+            EV: Send + Sync,
+        {
+            type EV = EV;
+
+            fn for_each_neighbor<F>(&self, node: NI, mut f: F)
+            where
+                F: FnMut(NI, &EV),
+            {
+                for t in self.$neighbors(node) {
+                    f(t.target, &t.value);
+                }
+            }
+        }
+    };
+}
+
+impl_pregel_graph!(DirectedCsrGraph, out_neighbors_with_values);
+impl_pregel_graph!(UndirectedCsrGraph, neighbors_with_values);
+impl_pregel_graph!(DirectedALGraph, out_neighbors_with_values);
+impl_pregel_graph!(UndirectedALGraph, neighbors_with_values);
+
+/// A vertex-centric computation.
+pub trait PregelComputation<NI: Idx, G: PregelGraph<NI>>: Sync {
+    /// The state of a single node.
+    type State: Send + Sync;
+
+    /// The type of messages sent between nodes.
+    type Message: Copy + Send + Sync;
+
+    /// Returns the initial state of the given node.
+    fn init(&self, graph: &G, node: NI) -> Self::State;
+
+    /// Computes the given node in the current superstep.
+    ///
+    /// `messages` contains all messages that were sent to the node in the
+    /// previous superstep in no particular order.
+    fn compute(
+        &self,
+        ctx: &mut PregelContext<'_, NI, G, Self::Message>,
+        state: &mut Self::State,
+        messages: &[Self::Message],
+    );
+}
+
+/// Provides access to the graph and to message passing during
+/// [`PregelComputation::compute`].
+pub struct PregelContext<'a, NI: Idx, G, M> {
+    graph: &'a G,
+    node: NI,
+    superstep: usize,
+    aggregated: f64,
+    outbox: &'a mut Vec<(NI, M)>,
+    aggregate: &'a mut f64,
+    halted: bool,
+}
+
+impl<'a, NI, G, M> PregelContext<'a, NI, G, M>
+where
+    NI: Idx,
+    G: PregelGraph<NI>,
+    M: Copy,
+{
+    /// Returns the graph.
+    pub fn graph(&self) -> &G {
+        self.graph
+    }
+
+    /// Returns the node that is currently computed.
+    pub fn node(&self) -> NI {
+        self.node
+    }
+
+    /// Returns the current superstep, starting at 0.
+    pub fn superstep(&self) -> usize {
+        self.superstep
+    }
+
+    /// Returns the sum of all values that were passed to
+    /// [`PregelContext::aggregate`] in the previous superstep.
+    pub fn aggregated(&self) -> f64 {
+        self.aggregated
+    }
+
+    /// Adds the given value to the global sum of the current superstep.
+    pub fn aggregate(&mut self, value: f64) {
+        *self.aggregate += value;
+    }
+
+    /// Sends a message to the given node.
+    pub fn send_to(&mut self, target: NI, message: M) {
+        self.outbox.push((target, message));
+    }
+
+    /// Sends the same message to all neighbors of the current node.
+    pub fn send_to_neighbors(&mut self, message: M) {
+        let outbox = &mut self.outbox;
+        self.graph
+            .for_each_neighbor(self.node, |target, _| outbox.push((target, message)));
+    }
+
+    /// Sends a message to all neighbors of the current node, which is
+    /// computed from the neighbor and the value of the connecting edge.
+    pub fn send_to_neighbors_with<F>(&mut self, mut message: F)
+    where
+        F: FnMut(NI, &G::EV) -> M,
+    {
+        let outbox = &mut self.outbox;
+        self.graph.for_each_neighbor(self.node, |target, value| {
+            outbox.push((target, message(target, value)))
+        });
+    }
+
+    /// Deactivates the current node. The node is computed again, once it
+    /// receives a message.
+    pub fn vote_to_halt(&mut self) {
+        self.halted = true;
+    }
+}
+
+/// The result of a Pregel computation.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PregelResult<S> {
+    /// The final state per node.
+    pub states: Vec<S>,
+    /// The number of executed supersteps.
+    pub supersteps: usize,
+    /// `true`, if all nodes halted before the maximum number of supersteps
+    /// was reached.
+    pub converged: bool,
+    /// The aggregated value of the last superstep.
+    pub aggregated: f64,
+}
+
+/// Runs the given computation on the given graph.
+pub fn pregel<NI, G, C>(graph: &G, computation: &C, config: PregelConfig) -> PregelResult<C::State>
+where
+    NI: Idx,
+    G: PregelGraph<NI>,
+    C: PregelComputation<NI, G>,
+{
+    let start = Instant::now();
+    let node_count = graph.node_count().index();
+
+    let mut states = (0..node_count)
+        .into_par_iter()
+        .map(|node| computation.init(graph, NI::new(node)))
+        .collect::<Vec<_>>();
+    let mut active = DenseFrontier::<NI>::full(node_count);
+    let mut inbox = Inbox::<C::Message>::empty(node_count);
+    let mut aggregated = 0.0;
+
+    let mut superstep = 0;
+    let mut converged = false;
+
+    while superstep < config.max_supersteps {
+        let step_start = Instant::now();
+
+        let next_active = DenseFrontier::<NI>::new(node_count);
+
+        let (messages, aggregate, active_count) = states
+            .par_iter_mut()
+            .enumerate()
+            .fold(
+                || (Vec::new(), 0.0, 0),
+                |(mut outbox, mut aggregate, mut active_count), (node, state)| {
+                    let messages = inbox.messages(node);
+                    if active.contains(NI::new(node)) || !messages.is_empty() {
+                        let mut ctx = PregelContext {
+                            graph,
+                            node: NI::new(node),
+                            superstep,
+                            aggregated,
+                            outbox: &mut outbox,
+                            aggregate: &mut aggregate,
+                            halted: false,
+                        };
+                        computation.compute(&mut ctx, state, messages);
+                        if !ctx.halted {
+                            next_active.insert(NI::new(node));
+                            active_count += 1;
+                        }
+                    }
+                    (outbox, aggregate, active_count)
+                },
+            )
+            .reduce(
+                || (Vec::new(), 0.0, 0),
+                |(mut messages, aggregate, active_count), (other, other_aggregate, other_count)| {
+                    messages.extend(other);
+                    (
+                        messages,
+                        aggregate + other_aggregate,
+                        active_count + other_count,
+                    )
+                },
+            );
+
+        let message_count = messages.len();
+        active = next_active;
+        inbox = Inbox::new(messages, node_count);
+        aggregated = aggregate;
+        superstep += 1;
+
+        info!(
+            "Finished superstep {} with {} active nodes and {} messages in {:?}",
+            superstep - 1,
+            active_count,
+            message_count,
+            step_start.elapsed()
+        );
+
+        if active_count == 0 && message_count == 0 {
+            converged = true;
+            break;
+        }
+    }
+
+    info!(
+        "Computed Pregel in {} supersteps in {:?}",
+        superstep,
+        start.elapsed()
+    );
+
+    PregelResult {
+        states,
+        supersteps: superstep,
+        converged,
+        aggregated,
+    }
+}
+
+/// The messages of a superstep grouped by target node.
+struct Inbox<M> {
+    offsets: Vec<usize>,
+    messages: Vec<M>,
+}
+
+impl<M: Copy + Send + Sync> Inbox<M> {
+    fn empty(node_count: usize) -> Self {
+        Self {
+            offsets: vec![0; node_count + 1],
+            messages: Vec::new(),
+        }
+    }
+
+    fn new<NI: Idx>(mut messages: Vec<(NI, M)>, node_count: usize) -> Self {
+        messages.par_sort_unstable_by_key(|(target, _)| *target);
+
+        let (targets, messages): (Vec<NI>, Vec<M>) = messages.into_par_iter().unzip();
+
+        let offsets = (0..=node_count)
+            .into_par_iter()
+            .map(|node| targets.partition_point(|target| target.index() < node))
+            .collect();
+
+        Self { offsets, messages }
+    }
+
+    fn messages(&self, node: usize) -> &[M] {
+        &self.messages[self.offsets[node]..self.offsets[node + 1]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    struct MinLabel;
+
+    impl<G: PregelGraph<usize>> PregelComputation<usize, G> for MinLabel {
+        type State = usize;
+        type Message = usize;
+
+        fn init(&self, _graph: &G, node: usize) -> usize {
+            node
+        }
+
+        fn compute(
+            &self,
+            ctx: &mut PregelContext<'_, usize, G, usize>,
+            label: &mut usize,
+            messages: &[usize],
+        ) {
+            let min = messages.iter().copied().min().unwrap_or(*label);
+            if ctx.superstep() == 0 || min < *label {
+                *label = min;
+                ctx.send_to_neighbors(min);
+            }
+            ctx.vote_to_halt();
+        }
+    }
+
+    struct ShortestPaths {
+        source: usize,
+    }
+
+    impl PregelComputation<usize, DirectedCsrGraph<usize, (), f32>> for ShortestPaths {
+        type State = f32;
+        type Message = f32;
+
+        fn init(&self, _graph: &DirectedCsrGraph<usize, (), f32>, node: usize) -> f32 {
+            if node == self.source {
+                0.0
+            } else {
+                f32::INFINITY
+            }
+        }
+
+        fn compute(
+            &self,
+            ctx: &mut PregelContext<'_, usize, DirectedCsrGraph<usize, (), f32>, f32>,
+            distance: &mut f32,
+            messages: &[f32],
+        ) {
+            let min = messages.iter().copied().fold(f32::INFINITY, f32::min);
+            if min < *distance || (ctx.superstep() == 0 && ctx.node() == self.source) {
+                *distance = f32::min(*distance, min);
+                let distance = *distance;
+                ctx.send_to_neighbors_with(|_, weight| distance + weight);
+            }
+            ctx.aggregate(1.0);
+            ctx.vote_to_halt();
+        }
+    }
+
+    #[test]
+    fn test_min_label_propagation() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(vec![(0, 1), (1, 2), (2, 3), (4, 5), (6, 6)])
+            .build();
+
+        let result = pregel(&graph, &MinLabel, PregelConfig::default());
+
+        assert_eq!(result.states, vec![0, 0, 0, 0, 4, 4, 6]);
+        assert!(result.converged);
+        // the minimum label reaches the end of the path in superstep 3,
+        // superstep 4 processes the last message
+        assert_eq!(result.supersteps, 5);
+    }
+
+    #[test]
+    fn test_weighted_shortest_paths() {
+        let graph: DirectedCsrGraph<usize, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 4.0), (0, 2, 1.0), (2, 1, 2.0), (1, 3, 1.0)])
+            .build();
+
+        let result = pregel(
+            &graph,
+            &ShortestPaths { source: 0 },
+            PregelConfig::default(),
+        );
+
+        assert_eq!(result.states, vec![0.0, 3.0, 1.0, 4.0]);
+        assert!(result.converged);
+    }
+
+    #[test]
+    fn test_max_supersteps() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 3)])
+            .build();
+
+        let result = pregel(&graph, &MinLabel, PregelConfig::new(2));
+
+        assert_eq!(result.supersteps, 2);
+        assert!(!result.converged);
+        assert_eq!(result.states, vec![0, 0, 1, 2]);
+    }
+}
@@ -1,7 +1,36 @@
+pub use crate::betweenness::*;
+pub use crate::bidirectional::*;
+pub use crate::descendants::*;
+pub use crate::edge_betweenness::*;
+pub use crate::embeddings::*;
+pub use crate::fast_rp::*;
+pub use crate::frontier::*;
+pub use crate::generate::*;
+pub use crate::hyperball::*;
+pub use crate::isomorphism::*;
+pub use crate::k_core::*;
+pub use crate::label_propagation::*;
+pub use crate::landmarks::*;
+pub use crate::local_community::*;
+pub use crate::louvain::*;
+pub use crate::node2vec::*;
+pub use crate::normalize::*;
 pub use crate::page_rank::*;
+pub use crate::partition::*;
+pub use crate::pregel::*;
+pub use crate::progress::*;
+pub use crate::projection::*;
+pub use crate::random_walk::*;
+pub use crate::reachability::*;
+pub use crate::sampling::*;
+pub use crate::shortest_path::*;
+pub use crate::spmv::*;
 pub use crate::sssp::*;
+pub use crate::summary::*;
+pub use crate::top_k::*;
 pub use crate::triangle_count::*;
 pub use crate::utils::*;
 pub use crate::wcc::*;
+pub use crate::weights::*;
 
 pub use graph_builder::prelude::*;
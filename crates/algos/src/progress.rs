@@ -0,0 +1,127 @@
+//! Progress reporting for long-running algorithms.
+//!
+//! Algorithms that run in multiple iterations or batches provide a
+//! `*_with_progress` variant that takes a [`ProgressTracker`]. The tracker
+//! is notified from the thread that drives the computation after each
+//! completed batch of work, never from within the parallel parts of an
+//! algorithm.
+//!
+//! Closures can be used as trackers:
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 2), (2, 0)])
+//!     .build();
+//!
+//! let tracker = |task: &str, completed: usize, total: Option<usize>| {
+//!     println!("{task}: {completed}/{total:?}");
+//! };
+//!
+//! page_rank_with_progress(&graph, PageRankConfig::default(), &tracker);
+//! ```
+//!
+//! Trackers can also stop a running algorithm early by implementing
+//! [`ProgressTracker::is_cancelled`], e.g., to enforce a timeout.
+
+/// Receives progress updates of a running algorithm.
+pub trait ProgressTracker: Sync {
+    /// Called after a batch of work of the given task has been completed.
+    ///
+    /// `completed` is the total number of steps completed so far. `total` is
+    /// the maximum number of steps if it is known upfront. Algorithms that
+    /// converge early finish before `completed` reaches `total`.
+    fn progress(&self, task: &str, completed: usize, total: Option<usize>);
+
+    /// Returns whether the algorithm should stop.
+    ///
+    /// Algorithms check this after each progress update and return early if
+    /// it is set. The returned result is incomplete and should be discarded.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+impl<F> ProgressTracker for F
+where
+    F: Fn(&str, usize, Option<usize>) + Sync,
+{
+    fn progress(&self, task: &str, completed: usize, total: Option<usize>) {
+        self(task, completed, total)
+    }
+}
+
+/// A tracker that ignores all progress updates.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoProgress;
+
+impl ProgressTracker for NoProgress {
+    fn progress(&self, _task: &str, _completed: usize, _total: Option<usize>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::prelude::{
+        page_rank_with_progress, CsrLayout, DirectedCsrGraph, GraphBuilder, PageRankConfig,
+    };
+
+    #[test]
+    fn test_closure_tracker() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 1)])
+            .build();
+
+        let updates = Mutex::new(Vec::new());
+        let tracker = |task: &str, completed: usize, total: Option<usize>| {
+            updates
+                .lock()
+                .unwrap()
+                .push((task.to_string(), completed, total));
+        };
+
+        let config = PageRankConfig::new(3, 0.0, 0.85);
+        let (_, iterations, _) = page_rank_with_progress(&graph, config, &tracker);
+
+        let updates = updates.into_inner().unwrap();
+        assert_eq!(iterations, 3);
+        assert_eq!(
+            updates,
+            (1..=3)
+                .map(|i| ("page_rank".to_string(), i, Some(3)))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_cancelled_tracker() {
+        struct CancelAfterFirstUpdate(AtomicUsize);
+
+        impl ProgressTracker for CancelAfterFirstUpdate {
+            fn progress(&self, _task: &str, _completed: usize, _total: Option<usize>) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+
+            fn is_cancelled(&self) -> bool {
+                self.0.load(Ordering::Relaxed) > 0
+            }
+        }
+
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 1)])
+            .build();
+
+        let tracker = CancelAfterFirstUpdate(AtomicUsize::new(0));
+        let config = PageRankConfig::new(20, 0.0, 0.85);
+        let (_, iterations, _) = page_rank_with_progress(&graph, config, &tracker);
+
+        assert_eq!(iterations, 1);
+        assert_eq!(tracker.0.into_inner(), 1);
+    }
+}
@@ -0,0 +1,313 @@
+//! Projects a bipartite graph onto one of its sides.
+//!
+//! A bipartite graph, e.g., of users and the items they interacted with,
+//! connects nodes of one side only to nodes of the other side. Its
+//! projection onto one side, the one-mode graph, connects two nodes of that
+//! side if they share at least one neighbor, e.g., two users that interacted
+//! with the same item. The weight of the edge measures their overlap:
+//!
+//! * `Count` is the number of shared neighbors.
+//! * `Jaccard` is the number of shared neighbors divided by the number of
+//!   neighbors of either node.
+//! * `Newman` sums `1 / (d - 1)` over all shared neighbors with degree `d`
+//!   as in [1], so that popular items contribute less to the overlap.
+//!
+//! The projection of a neighbor with degree `d` contains `d * (d - 1) / 2`
+//! edges, so a few hubs can dominate the run time and the size of the
+//! result. Neighbors with a degree above `max_degree` are therefore ignored.
+//! Each node of the projection is computed independently and in parallel.
+//!
+//! [1] M. E. J. Newman:
+//! "Scientific collaboration networks. II. Shortest paths, weighted
+//! networks, and centrality", Physical Review E 64, 2001
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! // users 0, 1 and 2 and items 3 and 4
+//! let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 3), (1, 3), (1, 4), (2, 4)])
+//!     .build();
+//!
+//! let (users, mapping) =
+//!     project_bipartite(&graph, |node| node < 3, ProjectionConfig::default());
+//!
+//! assert_eq!(mapping, vec![0, 1, 2]);
+//! assert_eq!(users.edge_count(), 2);
+//! assert_eq!(
+//!     users.neighbors_with_values(1).as_slice(),
+//!     &[Target::new(0, 1.0), Target::new(2, 1.0)]
+//! );
+//! ```
+
+use graph_builder::time::Instant;
+
+use ahash::AHashMap;
+use graph_builder::graph::csr::Csr;
+use log::info;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum ProjectionWeighting {
+    /// The number of shared neighbors.
+    Count,
+    /// The number of shared neighbors divided by the size of the union of
+    /// both neighborhoods.
+    Jaccard,
+    /// The sum of `1 / (d - 1)` over all shared neighbors with degree `d`.
+    Newman,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct ProjectionConfig {
+    /// How the overlap of two nodes is turned into an edge weight.
+    #[cfg_attr(feature = "clap", clap(long, value_enum, default_value_t = ProjectionConfig::DEFAULT_WEIGHTING))]
+    pub weighting: ProjectionWeighting,
+
+    /// The minimum number of shared neighbors for two nodes to be connected.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = ProjectionConfig::DEFAULT_MIN_COUNT))]
+    pub min_count: usize,
+
+    /// If set, neighbors with a higher degree are ignored.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub max_degree: Option<usize>,
+}
+
+impl Default for ProjectionConfig {
+    fn default() -> Self {
+        Self {
+            weighting: Self::DEFAULT_WEIGHTING,
+            min_count: Self::DEFAULT_MIN_COUNT,
+            max_degree: None,
+        }
+    }
+}
+
+impl ProjectionConfig {
+    pub const DEFAULT_WEIGHTING: ProjectionWeighting = ProjectionWeighting::Count;
+    pub const DEFAULT_MIN_COUNT: usize = 1;
+
+    pub fn new(
+        weighting: ProjectionWeighting,
+        min_count: usize,
+        max_degree: Option<usize>,
+    ) -> Self {
+        Self {
+            weighting,
+            min_count,
+            max_degree,
+        }
+    }
+}
+
+/// Projects the graph onto the nodes for which `side` returns `true`.
+///
+/// Returns the projection together with the original id of each of its
+/// nodes, which keep the relative order of their original ids. Edges
+/// between two nodes of the selected side are ignored. Neighbor lists of
+/// the projection are sorted.
+pub fn project_bipartite<NI, G, S>(
+    graph: &G,
+    side: S,
+    config: ProjectionConfig,
+) -> (UndirectedCsrGraph<NI, (), f32>, Vec<NI>)
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+    S: Fn(NI) -> bool + Sync,
+{
+    let start = Instant::now();
+
+    let node_count = graph.node_count().index();
+    let selected = (0..node_count)
+        .into_par_iter()
+        .map(|node| side(NI::new(node)))
+        .collect::<Vec<_>>();
+    let mapping = selected
+        .iter()
+        .enumerate()
+        .filter_map(|(node, selected)| selected.then_some(NI::new(node)))
+        .collect::<Vec<_>>();
+    let mut projected = vec![NI::zero(); node_count];
+    for (id, node) in mapping.iter().enumerate() {
+        projected[node.index()] = NI::new(id);
+    }
+
+    let max_degree = config.max_degree.unwrap_or(usize::MAX);
+    // The number of neighbors on the other side, for Jaccard weights.
+    let other_side_degrees = mapping
+        .par_iter()
+        .map(|&node| {
+            graph
+                .neighbors(node)
+                .filter(|neighbor| !selected[neighbor.index()])
+                .count()
+        })
+        .collect::<Vec<_>>();
+
+    let neighbor_lists = mapping
+        .par_iter()
+        .enumerate()
+        .map(|(id, &node)| {
+            let mut overlaps = AHashMap::<usize, (usize, f64)>::new();
+            for shared in graph.neighbors(node) {
+                let degree = graph.degree(*shared).index();
+                if selected[shared.index()] || degree > max_degree {
+                    continue;
+                }
+                for other in graph.neighbors(*shared) {
+                    if other == &node || !selected[other.index()] {
+                        continue;
+                    }
+                    let overlap = overlaps
+                        .entry(projected[other.index()].index())
+                        .or_default();
+                    overlap.0 += 1;
+                    overlap.1 += 1.0 / (degree - 1) as f64;
+                }
+            }
+
+            let mut neighbors = overlaps
+                .into_iter()
+                .filter(|(_, (count, _))| *count >= config.min_count)
+                .map(|(other, (count, newman))| {
+                    let weight = match config.weighting {
+                        ProjectionWeighting::Count => count as f64,
+                        ProjectionWeighting::Jaccard => {
+                            let union = other_side_degrees[id] + other_side_degrees[other] - count;
+                            count as f64 / union as f64
+                        }
+                        ProjectionWeighting::Newman => newman,
+                    };
+                    (NI::new(other), weight as f32)
+                })
+                .collect::<Vec<_>>();
+            neighbors.sort_unstable_by_key(|(other, _)| *other);
+            neighbors
+        })
+        .collect::<Vec<_>>();
+
+    let mut offsets = Vec::with_capacity(mapping.len() + 1);
+    offsets.push(NI::zero());
+    for neighbors in &neighbor_lists {
+        offsets.push(NI::new(
+            offsets[offsets.len() - 1].index() + neighbors.len(),
+        ));
+    }
+    let (targets, values) = neighbor_lists.into_iter().flatten().unzip();
+    let csr = Csr::from_raw_parts(offsets, targets, values)
+        .expect("neighbor lists are valid by construction");
+    let projection = UndirectedCsrGraph::from(csr);
+
+    info!(
+        "Projected {} of {node_count} nodes with {:?} weights in {:?}",
+        mapping.len(),
+        config.weighting,
+        start.elapsed()
+    );
+
+    (projection, mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    // Users 0 to 3 and items 4 to 6, item 6 is popular.
+    fn ratings() -> UndirectedCsrGraph<u32> {
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(vec![
+                (0, 4),
+                (1, 4),
+                (1, 5),
+                (2, 5),
+                (0, 6),
+                (1, 6),
+                (2, 6),
+                (3, 6),
+            ])
+            .build()
+    }
+
+    fn weight(graph: &UndirectedCsrGraph<u32, (), f32>, u: u32, v: u32) -> Option<f32> {
+        graph
+            .neighbors_with_values(u)
+            .find(|t| t.target == v)
+            .map(|t| t.value)
+    }
+
+    #[test]
+    fn test_count() {
+        let graph = ratings();
+
+        let (users, mapping) =
+            project_bipartite(&graph, |node| node < 4, ProjectionConfig::default());
+
+        assert_eq!(mapping, vec![0, 1, 2, 3]);
+        assert_eq!(users.edge_count(), 6);
+        assert_eq!(weight(&users, 0, 1), Some(2.0));
+        assert_eq!(weight(&users, 1, 0), Some(2.0));
+        assert_eq!(weight(&users, 1, 2), Some(2.0));
+        assert_eq!(weight(&users, 0, 3), Some(1.0));
+    }
+
+    #[test]
+    fn test_other_side() {
+        let graph = ratings();
+
+        let (items, mapping) =
+            project_bipartite(&graph, |node| node >= 4, ProjectionConfig::default());
+
+        assert_eq!(mapping, vec![4, 5, 6]);
+        assert_eq!(weight(&items, 0, 1), Some(1.0));
+        assert_eq!(weight(&items, 1, 2), Some(2.0));
+        assert_eq!(weight(&items, 0, 2), Some(2.0));
+    }
+
+    #[test]
+    fn test_weightings() {
+        let graph = ratings();
+        let project = |weighting| {
+            let config = ProjectionConfig::new(weighting, 1, None);
+            project_bipartite(&graph, |node| node < 4, config).0
+        };
+
+        let jaccard = project(ProjectionWeighting::Jaccard);
+        // {4, 6} and {4, 5, 6}
+        assert_eq!(weight(&jaccard, 0, 1), Some(2.0 / 3.0));
+        assert_eq!(weight(&jaccard, 0, 3), Some(0.5));
+
+        let newman = project(ProjectionWeighting::Newman);
+        // 1 / (2 - 1) for item 4 and 1 / (4 - 1) for item 6
+        assert_eq!(weight(&newman, 0, 1), Some(1.0 + 1.0 / 3.0));
+        assert_eq!(weight(&newman, 2, 3), Some(1.0 / 3.0));
+    }
+
+    #[test]
+    fn test_pruning() {
+        let graph = ratings();
+
+        let config = ProjectionConfig::new(ProjectionWeighting::Count, 1, Some(3));
+        let (users, _) = project_bipartite(&graph, |node| node < 4, config);
+        assert_eq!(users.edge_count(), 2);
+        assert_eq!(users.degree(3), 0);
+
+        let config = ProjectionConfig::new(ProjectionWeighting::Count, 2, None);
+        let (users, _) = project_bipartite(&graph, |node| node < 4, config);
+        assert_eq!(users.edge_count(), 2);
+        assert_eq!(weight(&users, 0, 2), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! Biased second-order random walks.
+//!
+//! A random walk starts at a node and repeatedly moves to a random neighbor
+//! of the current node. The walks follow the strategy introduced by
+//! node2vec [1], which biases the choice of the next node based on the
+//! previously visited node using two parameters:
+//!
+//! - The **return factor** `p` controls the likelihood of immediately
+//!   revisiting the previous node. A high value makes returning less likely.
+//! - The **in-out factor** `q` controls whether the walk stays close to the
+//!   previous node or moves further away. A high value keeps the walk local,
+//!   a low value favors exploring distant nodes.
+//!
+//! With `p = q = 1`, each neighbor is chosen with the same probability.
+//! Biased steps are sampled using rejection sampling, which does not require
+//! precomputing transition probabilities for every edge.
+//!
+//! Each walk uses its own random number generator, which is seeded based on
+//! the configured seed, the start node and the walk index. The walks are
+//! therefore reproducible, even though they are computed in parallel.
+//!
+//! [1] Aditya Grover, Jure Leskovec:
+//! "node2vec: Scalable Feature Learning for Networks",
+//! KDD 2016
+
+use graph_builder::time::Instant;
+
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct RandomWalkConfig {
+    /// The maximum number of nodes in a walk, including the start node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RandomWalkConfig::DEFAULT_WALK_LENGTH))]
+    pub walk_length: usize,
+
+    /// The number of walks that start at each node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RandomWalkConfig::DEFAULT_WALKS_PER_NODE))]
+    pub walks_per_node: usize,
+
+    /// The node2vec parameter `p`, a higher value makes
+    /// walks less likely to return to the previous node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RandomWalkConfig::DEFAULT_RETURN_FACTOR))]
+    pub return_factor: f64,
+
+    /// The node2vec parameter `q`, a higher value makes
+    /// walks stay closer to the previous node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RandomWalkConfig::DEFAULT_IN_OUT_FACTOR))]
+    pub in_out_factor: f64,
+
+    /// The seed for choosing the next node of a walk.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = RandomWalkConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for RandomWalkConfig {
+    fn default() -> Self {
+        Self {
+            walk_length: Self::DEFAULT_WALK_LENGTH,
+            walks_per_node: Self::DEFAULT_WALKS_PER_NODE,
+            return_factor: Self::DEFAULT_RETURN_FACTOR,
+            in_out_factor: Self::DEFAULT_IN_OUT_FACTOR,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl RandomWalkConfig {
+    pub const DEFAULT_WALK_LENGTH: usize = 80;
+    pub const DEFAULT_WALKS_PER_NODE: usize = 10;
+    pub const DEFAULT_RETURN_FACTOR: f64 = 1.0;
+    pub const DEFAULT_IN_OUT_FACTOR: f64 = 1.0;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(walk_length: usize, walks_per_node: usize) -> Self {
+        Self {
+            walk_length,
+            walks_per_node,
+            ..Self::default()
+        }
+    }
+}
+
+/// The nodes that a walk can move to from a given node.
+///
+/// For directed graphs, walks follow outgoing edges.
+pub trait WalkNeighbors<NI: Idx> {
+    fn walk_neighbors(&self, node: NI) -> &[NI];
+}
+
+impl<NI: Idx, NV> WalkNeighbors<NI> for DirectedCsrGraph<NI, NV> {
+    fn walk_neighbors(&self, node: NI) -> &[NI] {
+        self.out_neighbors(node).as_slice()
+    }
+}
+
+impl<NI: Idx, NV> WalkNeighbors<NI> for UndirectedCsrGraph<NI, NV> {
+    fn walk_neighbors(&self, node: NI) -> &[NI] {
+        self.neighbors(node).as_slice()
+    }
+}
+
+/// Computes `config.walks_per_node` random walks starting at each node.
+///
+/// The walks are ordered by round, i.e., the first `node_count` walks are
+/// the first walks of each node in node order. A walk ends early if it
+/// reaches a node without neighbors.
+pub fn random_walks<NI, G>(graph: &G, config: RandomWalkConfig) -> Vec<Vec<NI>>
+where
+    NI: Idx,
+    G: Graph<NI> + WalkNeighbors<NI> + Sync,
+{
+    let start = Instant::now();
+    let node_count = graph.node_count().index();
+
+    let walks = (0..config.walks_per_node * node_count)
+        .into_par_iter()
+        .map(|walk| {
+            let node = NI::new(walk % node_count);
+            let mut rng = WyRand::new_seed(derive_seed(config.seed, walk as u64));
+            random_walk(graph, node, &config, &mut rng)
+        })
+        .collect::<Vec<_>>();
+
+    info!(
+        "Computed {} random walks in {:?}",
+        walks.len(),
+        start.elapsed()
+    );
+
+    walks
+}
+
+fn random_walk<NI, G>(graph: &G, start: NI, config: &RandomWalkConfig, rng: &mut WyRand) -> Vec<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + WalkNeighbors<NI>,
+{
+    let mut walk = Vec::with_capacity(config.walk_length);
+    if config.walk_length == 0 {
+        return walk;
+    }
+    walk.push(start);
+
+    let biased = config.return_factor != 1.0 || config.in_out_factor != 1.0;
+    let return_weight = 1.0 / config.return_factor;
+    let out_weight = 1.0 / config.in_out_factor;
+    let max_weight = return_weight.max(out_weight).max(1.0);
+
+    let mut previous = None;
+    let mut current = start;
+
+    while walk.len() < config.walk_length {
+        let neighbors = graph.walk_neighbors(current);
+        if neighbors.is_empty() {
+            break;
+        }
+
+        let next = loop {
+            let candidate = neighbors[rng.generate_range(0..neighbors.len())];
+            let Some(previous) = previous else {
+                break candidate;
+            };
+            if !biased {
+                break candidate;
+            }
+            let weight = if candidate == previous {
+                return_weight
+            } else if graph.walk_neighbors(previous).contains(&candidate) {
+                1.0
+            } else {
+                out_weight
+            };
+            if rng.generate::<f64>() * max_weight < weight {
+                break candidate;
+            }
+        };
+
+        walk.push(next);
+        previous = Some(current);
+        current = next;
+    }
+
+    walk
+}
+
+/// Derives the seed of a single walk or node from the configured seed.
+pub(crate) fn derive_seed(seed: u64, index: u64) -> u64 {
+    // SplitMix64, so that consecutive indices get unrelated seeds.
+    let mut x = seed.wrapping_add(index.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    #[test]
+    fn test_walks_follow_edges() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 1)])
+            .build();
+
+        let walks = random_walks(&graph, RandomWalkConfig::new(10, 2));
+
+        assert_eq!(walks.len(), 6);
+        for (i, walk) in walks.iter().enumerate() {
+            assert_eq!(walk.len(), 10);
+            assert_eq!(walk[0], i % 3);
+            for step in walk.windows(2) {
+                assert!(graph.out_neighbors(step[0]).as_slice().contains(&step[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_walks_end_at_dead_ends() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2)])
+            .build();
+
+        let walks = random_walks(&graph, RandomWalkConfig::new(5, 1));
+
+        assert_eq!(walks, vec![vec![0, 1, 2], vec![1, 2], vec![2]]);
+    }
+
+    #[test]
+    fn test_walks_are_reproducible() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (0, 3), (1, 2), (2, 3), (3, 4)])
+            .build();
+
+        let config = RandomWalkConfig {
+            return_factor: 4.0,
+            in_out_factor: 0.25,
+            ..RandomWalkConfig::new(20, 3)
+        };
+
+        let walks = random_walks(&graph, config);
+
+        assert_eq!(walks, random_walks(&graph, config));
+        assert_ne!(
+            walks,
+            random_walks(&graph, RandomWalkConfig { seed: 7, ..config })
+        );
+    }
+
+    #[test]
+    fn test_high_return_factor_avoids_backtracking() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 3), (3, 0)])
+            .build();
+
+        let config = RandomWalkConfig {
+            return_factor: 1E9,
+            ..RandomWalkConfig::new(10, 1)
+        };
+
+        for walk in random_walks(&graph, config) {
+            for step in walk.windows(3) {
+                assert_ne!(step[0], step[2]);
+            }
+        }
+    }
+}
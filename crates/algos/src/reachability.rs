@@ -0,0 +1,472 @@
+//! Reachability queries on directed graphs.
+//!
+//! Nodes in the same strongly connected component (SCC) reach each other, so
+//! the index first contracts each SCC into a single node, which turns the
+//! graph into a directed acyclic graph (DAG), the condensation. SCCs are
+//! numbered in topological order, i.e., every edge of the condensation points
+//! from a lower to a higher SCC id, which rules out half of all queries.
+//!
+//! Each SCC is further labeled with intervals from several randomized
+//! depth-first traversals of the condensation as in GRAIL [1]: the interval
+//! of a node contains the intervals of all nodes that it reaches, so a
+//! single missing containment proves that a target is not reachable. Only
+//! queries that pass all checks fall back to a depth-first search, which
+//! skips every node whose intervals exclude the target. Most queries are
+//! therefore answered in constant time and the index takes linear space.
+//!
+//! [1] Hilmi Yildirim, Vineet Chaoji, Mohammed J. Zaki:
+//! "GRAIL: Scalable Reachability Index for Large Graphs",
+//! Proceedings of the VLDB Endowment, 2010
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 0), (1, 2), (3, 2)])
+//!     .build();
+//!
+//! let index = ReachabilityIndex::new(&graph, ReachabilityConfig::default());
+//!
+//! assert!(index.can_reach(1, 0));
+//! assert!(index.can_reach(0, 2));
+//! assert!(!index.can_reach(2, 0));
+//! assert!(!index.can_reach(0, 3));
+//! ```
+
+use graph_builder::time::Instant;
+
+use ahash::AHashSet;
+use log::info;
+use nanorand::{Rng, WyRand};
+use rayon::prelude::*;
+
+use crate::{prelude::*, random_walk::derive_seed};
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct ReachabilityConfig {
+    /// The number of intervals per SCC. More intervals rule out more
+    /// unreachable targets without a search, but take more memory.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = ReachabilityConfig::DEFAULT_LABEL_COUNT))]
+    pub label_count: usize,
+
+    /// The seed for randomizing the traversals that compute the intervals.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = ReachabilityConfig::DEFAULT_SEED))]
+    pub seed: u64,
+}
+
+impl Default for ReachabilityConfig {
+    fn default() -> Self {
+        Self {
+            label_count: Self::DEFAULT_LABEL_COUNT,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+impl ReachabilityConfig {
+    pub const DEFAULT_LABEL_COUNT: usize = 3;
+    pub const DEFAULT_SEED: u64 = 42;
+
+    pub fn new(label_count: usize, seed: u64) -> Self {
+        Self { label_count, seed }
+    }
+}
+
+/// Computes the strongly connected components of the graph using Tarjan's
+/// algorithm and returns the component of each node.
+///
+/// Components are numbered in topological order, i.e., for every edge
+/// `(u, v)` the component of `u` is at most the component of `v`.
+pub fn strongly_connected_components<NI, G>(graph: &G) -> Vec<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighbors<NI>,
+{
+    let start = Instant::now();
+
+    let node_count = graph.node_count().index();
+    let mut index = vec![usize::MAX; node_count];
+    let mut lowlink = vec![0; node_count];
+    let mut on_stack = vec![false; node_count];
+    let mut components = vec![0; node_count];
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut component_count = 0;
+
+    for root in 0..node_count {
+        if index[root] != usize::MAX {
+            continue;
+        }
+
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        // The recursion of Tarjan's algorithm, unrolled to not overflow the
+        // call stack on deep graphs.
+        let mut calls = vec![(root, graph.out_neighbors(NI::new(root)))];
+
+        while let Some((node, neighbors)) = calls.last_mut() {
+            let node = *node;
+            if let Some(neighbor) = neighbors.next() {
+                let neighbor = neighbor.index();
+                if index[neighbor] == usize::MAX {
+                    index[neighbor] = next_index;
+                    lowlink[neighbor] = next_index;
+                    next_index += 1;
+                    stack.push(neighbor);
+                    on_stack[neighbor] = true;
+                    calls.push((neighbor, graph.out_neighbors(NI::new(neighbor))));
+                } else if on_stack[neighbor] {
+                    lowlink[node] = lowlink[node].min(index[neighbor]);
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some((parent, _)) = calls.last() {
+                lowlink[*parent] = lowlink[*parent].min(lowlink[node]);
+            }
+
+            if lowlink[node] == index[node] {
+                loop {
+                    let member = stack.pop().expect("the node itself is on the stack");
+                    on_stack[member] = false;
+                    components[member] = component_count;
+                    if member == node {
+                        break;
+                    }
+                }
+                component_count += 1;
+            }
+        }
+    }
+
+    // Tarjan's algorithm completes components in reverse topological order.
+    let components = components
+        .into_par_iter()
+        .map(|component| NI::new(component_count - 1 - component))
+        .collect();
+
+    info!(
+        "Computed {component_count} strongly connected components in {:?}",
+        start.elapsed()
+    );
+
+    components
+}
+
+/// An index that answers whether a node can reach another node.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReachabilityIndex<NI> {
+    components: Vec<NI>,
+    // The condensation in CSR format.
+    offsets: Vec<usize>,
+    targets: Vec<NI>,
+    // The intervals of component `c` are at `c * k..(c + 1) * k` for `k`
+    // labels.
+    labels: Vec<(NI, NI)>,
+    label_count: usize,
+}
+
+impl<NI: Idx> ReachabilityIndex<NI> {
+    pub fn new<G>(graph: &G, config: ReachabilityConfig) -> Self
+    where
+        G: Graph<NI> + DirectedNeighbors<NI> + Sync,
+    {
+        let components = strongly_connected_components(graph);
+
+        let start = Instant::now();
+        let component_count = components
+            .par_iter()
+            .map(|c| c.index() + 1)
+            .max()
+            .unwrap_or_default();
+        let (offsets, targets) = condensation(graph, &components, component_count);
+        info!(
+            "Computed condensation with {} edges in {:?}",
+            targets.len(),
+            start.elapsed()
+        );
+
+        let start = Instant::now();
+        let label_count = config.label_count.max(1);
+        let per_label = (0..label_count)
+            .into_par_iter()
+            .map(|label| {
+                let mut rng = WyRand::new_seed(derive_seed(config.seed, label as u64));
+                interval_labels(&offsets, &targets, &mut rng)
+            })
+            .collect::<Vec<_>>();
+        let labels = (0..component_count)
+            .into_par_iter()
+            .flat_map_iter(|c| per_label.iter().map(move |labels| labels[c]))
+            .collect();
+        info!(
+            "Computed {label_count} interval labels in {:?}",
+            start.elapsed()
+        );
+
+        Self {
+            components,
+            offsets,
+            targets,
+            labels,
+            label_count,
+        }
+    }
+
+    /// Returns whether there is a path from `source` to `target`. Every node
+    /// can reach itself.
+    pub fn can_reach(&self, source: NI, target: NI) -> bool {
+        let source = self.components[source.index()];
+        let target = self.components[target.index()];
+
+        if source == target {
+            return true;
+        }
+        if source > target || !self.contains(source, target) {
+            return false;
+        }
+
+        let mut visited = AHashSet::new();
+        let mut stack = vec![source];
+        while let Some(component) = stack.pop() {
+            for &next in self.successors(component) {
+                if next == target {
+                    return true;
+                }
+                if next < target && self.contains(next, target) && visited.insert(next.index()) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns the strongly connected component of each node, numbered in
+    /// topological order.
+    pub fn components(&self) -> &[NI] {
+        &self.components
+    }
+
+    /// Returns the number of strongly connected components.
+    pub fn component_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn successors(&self, component: NI) -> &[NI] {
+        &self.targets[self.offsets[component.index()]..self.offsets[component.index() + 1]]
+    }
+
+    /// Returns whether all intervals of `source` contain those of `target`,
+    /// which is necessary for `source` to reach `target`.
+    fn contains(&self, source: NI, target: NI) -> bool {
+        let k = self.label_count;
+        let source = &self.labels[source.index() * k..(source.index() + 1) * k];
+        let target = &self.labels[target.index() * k..(target.index() + 1) * k];
+
+        source
+            .iter()
+            .zip(target)
+            .all(|((s_low, s_high), (t_low, t_high))| s_low <= t_low && t_high <= s_high)
+    }
+}
+
+/// Returns the deduplicated edges between different components in CSR
+/// format, i.e., as offsets and targets.
+pub(crate) fn condensation<NI, G>(
+    graph: &G,
+    components: &[NI],
+    component_count: usize,
+) -> (Vec<usize>, Vec<NI>)
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighbors<NI> + Sync,
+{
+    let mut edges = (0..graph.node_count().index())
+        .into_par_iter()
+        .flat_map_iter(|node| {
+            let source = components[node];
+            graph
+                .out_neighbors(NI::new(node))
+                .map(move |target| (source, components[target.index()]))
+                .filter(|(source, target)| source != target)
+        })
+        .collect::<Vec<_>>();
+    edges.par_sort_unstable();
+    edges.dedup();
+
+    let mut offsets = vec![0; component_count + 1];
+    for (source, _) in &edges {
+        offsets[source.index() + 1] += 1;
+    }
+    for c in 0..component_count {
+        offsets[c + 1] += offsets[c];
+    }
+    let targets = edges.into_iter().map(|(_, target)| target).collect();
+
+    (offsets, targets)
+}
+
+/// Labels each node of the DAG with the interval from the lowest post-order
+/// rank of all nodes it reaches to its own rank, following a depth-first
+/// traversal with random root and child order.
+fn interval_labels<NI: Idx>(offsets: &[usize], targets: &[NI], rng: &mut WyRand) -> Vec<(NI, NI)> {
+    let node_count = offsets.len() - 1;
+    let successors = |node: usize| &targets[offsets[node]..offsets[node + 1]];
+
+    let mut labels = vec![(NI::zero(), NI::zero()); node_count];
+    let mut visited = vec![false; node_count];
+    let mut rank = 0;
+
+    let mut roots = (0..node_count).collect::<Vec<_>>();
+    rng.shuffle(&mut roots);
+
+    for root in roots {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+
+        // Each entry is a node, the position at which its children are
+        // rotated and the number of children visited so far.
+        let mut stack = vec![(root, random_offset(successors(root).len(), rng), 0)];
+
+        while let Some((node, offset, position)) = stack.last_mut() {
+            let children = successors(*node);
+            if *position < children.len() {
+                let child = children[(*offset + *position) % children.len()].index();
+                *position += 1;
+                if !visited[child] {
+                    visited[child] = true;
+                    let offset = random_offset(successors(child).len(), rng);
+                    stack.push((child, offset, 0));
+                }
+                continue;
+            }
+
+            // All children are labeled, since the graph has no cycles.
+            let low = children
+                .iter()
+                .map(|child| labels[child.index()].0)
+                .fold(NI::new(rank), NI::min);
+            labels[*node] = (low, NI::new(rank));
+            rank += 1;
+            stack.pop();
+        }
+    }
+
+    labels
+}
+
+fn random_offset(len: usize, rng: &mut WyRand) -> usize {
+    if len == 0 {
+        0
+    } else {
+        rng.generate_range(0..len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::GraphBuilder;
+
+    fn reachable(graph: &DirectedCsrGraph<u32>, source: u32) -> Vec<bool> {
+        let mut reachable = vec![false; graph.node_count() as usize];
+        reachable[source as usize] = true;
+        let mut stack = vec![source];
+        while let Some(node) = stack.pop() {
+            for &next in graph.out_neighbors(node) {
+                if !std::mem::replace(&mut reachable[next as usize], true) {
+                    stack.push(next);
+                }
+            }
+        }
+        reachable
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 0)])
+            .build();
+
+        let components = strongly_connected_components(&graph);
+
+        assert_eq!(components, vec![1, 1, 1, 2, 2, 0]);
+    }
+
+    #[test]
+    fn test_can_reach() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![
+                (0, 1),
+                (1, 2),
+                (2, 0),
+                (2, 3),
+                (3, 4),
+                (4, 3),
+                (5, 0),
+                (6, 4),
+            ])
+            .build();
+
+        let index = ReachabilityIndex::new(&graph, ReachabilityConfig::default());
+
+        assert_eq!(index.component_count(), 4);
+        assert!(index.can_reach(0, 2));
+        assert!(index.can_reach(2, 1));
+        assert!(index.can_reach(5, 4));
+        assert!(index.can_reach(6, 6));
+        assert!(!index.can_reach(3, 0));
+        assert!(!index.can_reach(6, 0));
+        assert!(!index.can_reach(5, 6));
+    }
+
+    #[test]
+    fn test_matches_search() {
+        for seed in 0..4 {
+            let edges = erdos_renyi::<u32>(ErdosRenyiConfig {
+                node_count: 80,
+                edge_probability: 0.02,
+                directed: true,
+                seed,
+            });
+            let graph: DirectedCsrGraph<u32> = GraphBuilder::new().edges(edges).build();
+
+            let index = ReachabilityIndex::new(&graph, ReachabilityConfig::new(2, seed));
+
+            for source in 0..graph.node_count() {
+                let expected = reachable(&graph, source);
+                for target in 0..graph.node_count() {
+                    assert_eq!(
+                        index.can_reach(source, target),
+                        expected[target as usize],
+                        "{source} -> {target}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deep_graph() {
+        let edges = (0..100_000_u32)
+            .map(|node| (node, node + 1))
+            .collect::<Vec<_>>();
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new().edges(edges).build();
+
+        let index = ReachabilityIndex::new(&graph, ReachabilityConfig::default());
+
+        assert!(index.can_reach(0, 100_000));
+        assert!(!index.can_reach(100_000, 0));
+    }
+}
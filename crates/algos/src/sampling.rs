@@ -0,0 +1,480 @@
+//! Samples representative subgraphs of large graphs.
+//!
+//! Each method selects a fraction of the nodes and returns the subgraph they
+//! induce, i.e., the selected nodes and all edges between them, together
+//! with the original id of each subgraph node.
+//!
+//! * `Node` selects nodes uniformly at random, which keeps the degree
+//!   distribution but few edges of sparse graphs.
+//! * `Edge` selects edges uniformly at random and keeps both end nodes,
+//!   which prefers high-degree nodes and keeps more edges.
+//! * `RandomWalk` follows random walks that return to their start node with
+//!   `restart_probability`, which keeps the local structure around the start
+//!   nodes.
+//! * `ForestFire` spreads a fire from random seed nodes as in [1], where each
+//!   burning node ignites a geometrically distributed number of neighbors,
+//!   about `burn_probability / (1 - burn_probability)` on average. The
+//!   samples keep communities as well as degree and diameter properties.
+//!
+//! Walks and fires that run out of unvisited nodes continue from a new
+//! random node, so every method returns the configured number of nodes.
+//!
+//! [1] Jure Leskovec, Christos Faloutsos:
+//! "Sampling from Large Graphs", KDD 2006
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)])
+//!     .build();
+//!
+//! let config = SamplingConfig::new(SamplingMethod::ForestFire, 0.5, 42);
+//! let (sample, mapping) = sample(&graph, config);
+//!
+//! assert_eq!(sample.node_count(), 2);
+//! assert_eq!(mapping.len(), 2);
+//! ```
+
+use graph_builder::time::Instant;
+use std::collections::VecDeque;
+
+use log::info;
+use nanorand::{Rng, WyRand};
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum SamplingMethod {
+    /// Selects nodes uniformly at random.
+    Node,
+    /// Selects the end nodes of edges chosen uniformly at random.
+    Edge,
+    /// Selects the nodes visited by random walks with restarts.
+    RandomWalk,
+    /// Selects the nodes burned by a forest fire.
+    ForestFire,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct SamplingConfig {
+    /// How the nodes are selected.
+    #[cfg_attr(feature = "clap", clap(long, value_enum, default_value_t = SamplingConfig::DEFAULT_METHOD))]
+    pub method: SamplingMethod,
+
+    /// The fraction of nodes to select, greater than 0 and at most 1.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = SamplingConfig::DEFAULT_FRACTION))]
+    pub fraction: f64,
+
+    /// The seed for all random decisions.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = SamplingConfig::DEFAULT_SEED))]
+    pub seed: u64,
+
+    /// The probability of a random walk to return to its start node.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = SamplingConfig::DEFAULT_RESTART_PROBABILITY))]
+    pub restart_probability: f64,
+
+    /// The probability of a forest fire to spread to one more neighbor.
+    #[cfg_attr(feature = "clap", clap(long, default_value_t = SamplingConfig::DEFAULT_BURN_PROBABILITY))]
+    pub burn_probability: f64,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            method: Self::DEFAULT_METHOD,
+            fraction: Self::DEFAULT_FRACTION,
+            seed: Self::DEFAULT_SEED,
+            restart_probability: Self::DEFAULT_RESTART_PROBABILITY,
+            burn_probability: Self::DEFAULT_BURN_PROBABILITY,
+        }
+    }
+}
+
+impl SamplingConfig {
+    pub const DEFAULT_METHOD: SamplingMethod = SamplingMethod::RandomWalk;
+    pub const DEFAULT_FRACTION: f64 = 0.1;
+    pub const DEFAULT_SEED: u64 = 42;
+    pub const DEFAULT_RESTART_PROBABILITY: f64 = 0.15;
+    pub const DEFAULT_BURN_PROBABILITY: f64 = 0.7;
+
+    pub fn new(method: SamplingMethod, fraction: f64, seed: u64) -> Self {
+        Self {
+            method,
+            fraction,
+            seed,
+            ..Self::default()
+        }
+    }
+}
+
+/// Samples a subgraph and returns it together with the original id of each
+/// subgraph node. Subgraph nodes keep the relative order of their original
+/// ids.
+///
+/// # Panics
+///
+/// Panics if the graph has no nodes or if `fraction` is not in `(0, 1]`.
+pub fn sample<NI, G>(graph: &G, config: SamplingConfig) -> (G::Subgraph, Vec<NI>)
+where
+    NI: Idx,
+    G: Graph<NI> + BidirectionalNeighbors<NI> + InducedSubgraphOp<NI>,
+{
+    let nodes = sample_nodes(graph, config);
+
+    let start = Instant::now();
+    let mut selected = vec![false; graph.node_count().index()];
+    for node in &nodes {
+        selected[node.index()] = true;
+    }
+    let subgraph = graph.induced_subgraph(|node| selected[node.index()]);
+    info!("Created sampled subgraph in {:?}", start.elapsed());
+
+    subgraph
+}
+
+/// Selects the nodes of a sample and returns them in ascending order.
+///
+/// # Panics
+///
+/// Panics if `fraction` is not in `(0, 1]`.
+pub fn sample_nodes<NI, G>(graph: &G, config: SamplingConfig) -> Vec<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + BidirectionalNeighbors<NI>,
+{
+    assert!(
+        config.fraction > 0.0 && config.fraction <= 1.0,
+        "fraction must be greater than 0 and at most 1, got {}",
+        config.fraction
+    );
+
+    let start = Instant::now();
+    let node_count = graph.node_count().index();
+    let target =
+        ((config.fraction * node_count as f64).round() as usize).clamp(1, node_count.max(1));
+    let mut sample = Sample::new(node_count, target, config.seed);
+
+    match config.method {
+        SamplingMethod::Node => {}
+        SamplingMethod::Edge => sample.edges(graph),
+        SamplingMethod::RandomWalk => sample.random_walks(graph, config.restart_probability),
+        SamplingMethod::ForestFire => sample.forest_fire(graph, config.burn_probability),
+    }
+    // Uniform node sampling, and the fallback for methods that got stuck.
+    while !sample.is_complete() {
+        let Some(node) = sample.next_start() else {
+            break;
+        };
+        sample.select(node);
+    }
+
+    let nodes = sample
+        .selected
+        .iter()
+        .enumerate()
+        .filter_map(|(node, selected)| selected.then_some(NI::new(node)))
+        .collect::<Vec<_>>();
+
+    info!(
+        "Sampled {} of {node_count} nodes with {:?} sampling in {:?}",
+        nodes.len(),
+        config.method,
+        start.elapsed()
+    );
+
+    nodes
+}
+
+struct Sample {
+    selected: Vec<bool>,
+    count: usize,
+    target: usize,
+    // A random permutation of all nodes, from which new start nodes are
+    // taken in order, skipping nodes that are already selected.
+    starts: Vec<usize>,
+    next: usize,
+    rng: WyRand,
+}
+
+impl Sample {
+    fn new(node_count: usize, target: usize, seed: u64) -> Self {
+        let mut rng = WyRand::new_seed(seed);
+        let mut starts = (0..node_count).collect::<Vec<_>>();
+        rng.shuffle(&mut starts);
+
+        Self {
+            selected: vec![false; node_count],
+            count: 0,
+            target,
+            starts,
+            next: 0,
+            rng,
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.count >= self.target
+    }
+
+    /// Selects the node and returns whether it was not selected before.
+    fn select(&mut self, node: usize) -> bool {
+        if self.selected[node] || self.is_complete() {
+            return false;
+        }
+        self.selected[node] = true;
+        self.count += 1;
+        true
+    }
+
+    /// Returns a random node that is not selected yet.
+    fn next_start(&mut self) -> Option<usize> {
+        while let Some(&node) = self.starts.get(self.next) {
+            self.next += 1;
+            if !self.selected[node] {
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    fn edges<NI, G>(&mut self, graph: &G)
+    where
+        NI: Idx,
+        G: Graph<NI> + BidirectionalNeighbors<NI>,
+    {
+        let node_count = self.selected.len();
+        let mut offsets = Vec::with_capacity(node_count + 1);
+        offsets.push(0);
+        for node in 0..node_count {
+            let degree = graph.forward_neighbors(NI::new(node)).len();
+            offsets.push(offsets[node] + degree);
+        }
+        let edge_count = offsets[node_count];
+        if edge_count == 0 {
+            return;
+        }
+
+        // Nodes without edges can only be selected by the fallback, so stop
+        // once edges mostly hit nodes that are already selected.
+        let mut misses = 0;
+        while !self.is_complete() && misses < 16 * self.target {
+            let edge = self.rng.generate_range(0..edge_count);
+            let source = offsets.partition_point(|&offset| offset <= edge) - 1;
+            let target = graph.forward_neighbors(NI::new(source))[edge - offsets[source]];
+
+            let new_source = self.select(source);
+            let new_target = self.select(target.index());
+            if !new_source && !new_target {
+                misses += 1;
+            }
+        }
+    }
+
+    fn random_walks<NI, G>(&mut self, graph: &G, restart_probability: f64)
+    where
+        NI: Idx,
+        G: Graph<NI> + BidirectionalNeighbors<NI>,
+    {
+        // A walk that only visits selected nodes for this many steps is
+        // stuck in a small part of the graph and continues elsewhere.
+        const MAX_STALLED_STEPS: usize = 100;
+
+        while !self.is_complete() {
+            let Some(start) = self.next_start() else {
+                return;
+            };
+            self.select(start);
+
+            let mut current = start;
+            let mut stalled = 0;
+            while !self.is_complete() && stalled < MAX_STALLED_STEPS {
+                let neighbors = graph.forward_neighbors(NI::new(current));
+                current =
+                    if neighbors.is_empty() || self.rng.generate::<f64>() < restart_probability {
+                        start
+                    } else {
+                        neighbors[self.rng.generate_range(0..neighbors.len())].index()
+                    };
+
+                if self.select(current) {
+                    stalled = 0;
+                } else {
+                    stalled += 1;
+                }
+            }
+        }
+    }
+
+    fn forest_fire<NI, G>(&mut self, graph: &G, burn_probability: f64)
+    where
+        NI: Idx,
+        G: Graph<NI> + BidirectionalNeighbors<NI>,
+    {
+        let mut burning = VecDeque::new();
+        let mut candidates = Vec::new();
+
+        while !self.is_complete() {
+            let Some(seed) = self.next_start() else {
+                return;
+            };
+            self.select(seed);
+            burning.push_back(seed);
+
+            while let Some(node) = burning.pop_front() {
+                if self.is_complete() {
+                    return;
+                }
+
+                candidates.clear();
+                candidates.extend(
+                    graph
+                        .forward_neighbors(NI::new(node))
+                        .iter()
+                        .chain(graph.backward_neighbors(NI::new(node)))
+                        .map(|neighbor| neighbor.index())
+                        .filter(|neighbor| !self.selected[*neighbor]),
+                );
+                self.rng.shuffle(&mut candidates);
+
+                let mut burn_count = 0;
+                while self.rng.generate::<f64>() < burn_probability {
+                    burn_count += 1;
+                }
+
+                for &neighbor in &candidates {
+                    if burn_count == 0 {
+                        break;
+                    }
+                    // Undirected and reciprocal edges repeat neighbors.
+                    if self.select(neighbor) {
+                        burning.push_back(neighbor);
+                        burn_count -= 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::GraphBuilder;
+
+    const METHODS: [SamplingMethod; 4] = [
+        SamplingMethod::Node,
+        SamplingMethod::Edge,
+        SamplingMethod::RandomWalk,
+        SamplingMethod::ForestFire,
+    ];
+
+    fn random_graph() -> DirectedCsrGraph<u32> {
+        let edges = erdos_renyi::<u32>(ErdosRenyiConfig {
+            node_count: 500,
+            edge_probability: 0.01,
+            directed: true,
+            seed: 3,
+        });
+        GraphBuilder::new().edges(edges).build()
+    }
+
+    #[test]
+    fn test_sample_size() {
+        let graph = random_graph();
+
+        for method in METHODS {
+            let nodes = sample_nodes::<u32, _>(&graph, SamplingConfig::new(method, 0.2, 7));
+
+            assert_eq!(nodes.len(), 100, "{method:?}");
+            assert!(nodes.windows(2).all(|w| w[0] < w[1]), "{method:?}");
+            assert!(nodes.iter().all(|node| *node < 500), "{method:?}");
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let graph = random_graph();
+
+        for method in METHODS {
+            let config = SamplingConfig::new(method, 0.1, 11);
+            let a = sample_nodes::<u32, _>(&graph, config);
+            let b = sample_nodes::<u32, _>(&graph, config);
+            let c = sample_nodes::<u32, _>(&graph, SamplingConfig { seed: 12, ..config });
+
+            assert_eq!(a, b, "{method:?}");
+            assert_ne!(a, c, "{method:?}");
+        }
+    }
+
+    #[test]
+    fn test_sample_subgraph() {
+        let graph = random_graph();
+
+        let (subgraph, mapping) = sample(&graph, SamplingConfig::default());
+
+        assert_eq!(subgraph.node_count() as usize, mapping.len());
+        for node in 0..subgraph.node_count() {
+            for &target in subgraph.out_neighbors(node) {
+                let original = graph.out_neighbors(mapping[node as usize]);
+                assert!(original.as_slice().contains(&mapping[target as usize]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk_keeps_connectivity() {
+        // Two cliques connected by a single edge.
+        let mut edges = Vec::new();
+        for offset in [0_u32, 10] {
+            for s in 0..10 {
+                for t in 0..10 {
+                    if s != t {
+                        edges.push((offset + s, offset + t));
+                    }
+                }
+            }
+        }
+        edges.push((9, 10));
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new().edges(edges).build();
+
+        for method in [SamplingMethod::RandomWalk, SamplingMethod::ForestFire] {
+            let config = SamplingConfig {
+                restart_probability: 0.5,
+                ..SamplingConfig::new(method, 0.25, 1)
+            };
+            let (subgraph, _) = sample(&graph, config);
+
+            assert_eq!(subgraph.node_count(), 5);
+            assert!(subgraph.edge_count() >= 4, "{method:?}");
+        }
+    }
+
+    #[test]
+    fn test_fraction_one() {
+        let graph = random_graph();
+
+        for method in METHODS {
+            let nodes = sample_nodes::<u32, _>(&graph, SamplingConfig::new(method, 1.0, 0));
+
+            assert_eq!(nodes, (0..500).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "fraction must be greater than 0")]
+    fn test_invalid_fraction() {
+        sample_nodes::<u32, _>(
+            &random_graph(),
+            SamplingConfig::new(SamplingMethod::Node, 0.0, 0),
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Single-source shortest paths with path reconstruction.
+//!
+//! Dijkstra's algorithm settles nodes in order of their distance to the start
+//! node and remembers the predecessor of each node on its shortest path.
+//! Unlike [`delta_stepping`](crate::sssp::delta_stepping), it runs on a
+//! single thread, but the predecessors allow reconstructing the actual paths.
+//!
+//! A* additionally uses a heuristic that estimates the remaining distance of
+//! each node to a target node. Nodes that are likely closer to the target are
+//! settled first and the search stops once the target is settled, which
+//! usually visits far fewer nodes than Dijkstra's algorithm. The result is
+//! only guaranteed to be a shortest path if the heuristic never overestimates
+//! the remaining distance.
+//!
+//! Edge weights must not be negative.
+
+use graph_builder::time::Instant;
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use log::info;
+
+use crate::prelude::*;
+
+/// The nodes that a shortest path can move to from a given node, together
+/// with the weight of the connecting edge.
+///
+/// For directed graphs, paths follow outgoing edges.
+pub trait WeightedNeighbors<NI: Idx> {
+    fn weighted_neighbors(&self, node: NI) -> &[Target<NI, f32>];
+}
+
+impl<NI: Idx, NV> WeightedNeighbors<NI> for DirectedCsrGraph<NI, NV, f32> {
+    fn weighted_neighbors(&self, node: NI) -> &[Target<NI, f32>] {
+        self.out_neighbors_with_values(node).as_slice()
+    }
+}
+
+impl<NI: Idx, NV> WeightedNeighbors<NI> for UndirectedCsrGraph<NI, NV, f32> {
+    fn weighted_neighbors(&self, node: NI) -> &[Target<NI, f32>] {
+        self.neighbors_with_values(node).as_slice()
+    }
+}
+
+/// The distances from a start node and the shortest path tree rooted at it.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShortestPaths<NI> {
+    start_node: NI,
+    distances: Vec<f32>,
+    predecessors: Vec<NI>,
+}
+
+impl<NI: Idx> ShortestPaths<NI> {
+    fn new(start_node: NI, node_count: usize) -> Self {
+        let mut distances = vec![f32::INFINITY; node_count];
+        distances[start_node.index()] = 0.0;
+        let predecessors = (0..node_count).map(NI::new).collect();
+        Self {
+            start_node,
+            distances,
+            predecessors,
+        }
+    }
+
+    pub fn start_node(&self) -> NI {
+        self.start_node
+    }
+
+    /// Returns the distance of the given node to the start node, which is
+    /// infinite for unreachable nodes.
+    pub fn distance(&self, node: NI) -> f32 {
+        self.distances[node.index()]
+    }
+
+    /// Returns the distance of each node to the start node.
+    pub fn distances(&self) -> &[f32] {
+        &self.distances
+    }
+
+    /// Returns the predecessor of each node on its shortest path.
+    ///
+    /// The start node and unreachable nodes are their own predecessors.
+    pub fn predecessors(&self) -> &[NI] {
+        &self.predecessors
+    }
+
+    /// Returns whether a path from the start node to the given node has been
+    /// found.
+    pub fn is_reachable(&self, node: NI) -> bool {
+        self.distance(node).is_finite()
+    }
+
+    /// Returns the nodes on the shortest path from the start node to the
+    /// given node, including both, or `None` if no path has been found.
+    pub fn path_to(&self, target: NI) -> Option<Vec<NI>> {
+        if !self.is_reachable(target) {
+            return None;
+        }
+
+        let mut path = vec![target];
+        let mut node = target;
+        while node != self.start_node {
+            node = self.predecessors[node.index()];
+            path.push(node);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn into_parts(self) -> (Vec<f32>, Vec<NI>) {
+        (self.distances, self.predecessors)
+    }
+}
+
+/// Computes the shortest paths from `start_node` to all reachable nodes.
+pub fn dijkstra<NI, G>(graph: &G, start_node: NI) -> ShortestPaths<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + WeightedNeighbors<NI>,
+{
+    let start = Instant::now();
+    let paths = search(graph, start_node, None, |_| 0.0);
+    info!("Computed Dijkstra in {:?}", start.elapsed());
+    paths
+}
+
+/// Computes the shortest path from `start_node` to `target_node`, guided by
+/// a heuristic that estimates the distance of a node to the target.
+///
+/// Only nodes that have been settled before the target have their final
+/// distance, the distances of all other nodes are upper bounds or infinite.
+pub fn astar<NI, G, H>(
+    graph: &G,
+    start_node: NI,
+    target_node: NI,
+    heuristic: H,
+) -> ShortestPaths<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + WeightedNeighbors<NI>,
+    H: Fn(NI) -> f32,
+{
+    let start = Instant::now();
+    let paths = search(graph, start_node, Some(target_node), heuristic);
+    info!("Computed A* in {:?}", start.elapsed());
+    paths
+}
+
+fn search<NI, G, H>(
+    graph: &G,
+    start_node: NI,
+    target_node: Option<NI>,
+    heuristic: H,
+) -> ShortestPaths<NI>
+where
+    NI: Idx,
+    G: Graph<NI> + WeightedNeighbors<NI>,
+    H: Fn(NI) -> f32,
+{
+    let node_count = graph.node_count().index();
+    let mut paths = ShortestPaths::new(start_node, node_count);
+    let mut settled = vec![false; node_count];
+
+    let mut queue = BinaryHeap::new();
+    queue.push(QueueEntry {
+        priority: heuristic(start_node),
+        node: start_node,
+    });
+
+    while let Some(QueueEntry { node, .. }) = queue.pop() {
+        if std::mem::replace(&mut settled[node.index()], true) {
+            continue;
+        }
+        if Some(node) == target_node {
+            break;
+        }
+
+        let distance = paths.distances[node.index()];
+        for Target { target, value } in graph.weighted_neighbors(node) {
+            let candidate = distance + value;
+            if !settled[target.index()] && candidate < paths.distances[target.index()] {
+                paths.distances[target.index()] = candidate;
+                paths.predecessors[target.index()] = node;
+                queue.push(QueueEntry {
+                    priority: candidate + heuristic(*target),
+                    node: *target,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// Orders the priority queue by ascending priority.
+pub(crate) struct QueueEntry<NI> {
+    pub(crate) priority: f32,
+    pub(crate) node: NI,
+}
+
+impl<NI> PartialEq for QueueEntry<NI> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<NI> Eq for QueueEntry<NI> {}
+
+impl<NI> PartialOrd for QueueEntry<NI> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<NI> Ord for QueueEntry<NI> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    fn graph() -> DirectedCsrGraph<usize, (), f32> {
+        let gdl = "(a:A)
+                        (b:B)
+                        (c:C)
+                        (d:D)
+                        (e:E)
+                        (f:F)
+                        (a)-[{cost:  4.0 }]->(b)
+                        (a)-[{cost:  2.0 }]->(c)
+                        (b)-[{cost:  5.0 }]->(c)
+                        (b)-[{cost: 10.0 }]->(d)
+                        (c)-[{cost:  3.0 }]->(e)
+                        (d)-[{cost: 11.0 }]->(f)
+                        (e)-[{cost:  4.0 }]->(d)";
+
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .gdl_str::<usize, _>(gdl)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra(&graph(), 0);
+
+        assert_eq!(paths.distances(), &[0.0, 4.0, 2.0, 9.0, 5.0, 20.0]);
+        assert_eq!(paths.path_to(5), Some(vec![0, 2, 4, 3, 5]));
+        assert_eq!(paths.path_to(0), Some(vec![0]));
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable() {
+        let paths = dijkstra(&graph(), 3);
+
+        assert!(!paths.is_reachable(0));
+        assert_eq!(paths.distance(0), f32::INFINITY);
+        assert_eq!(paths.path_to(0), None);
+        assert_eq!(paths.path_to(5), Some(vec![3, 5]));
+    }
+
+    #[test]
+    fn test_dijkstra_undirected() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 1.0), (1, 2, 1.0), (0, 2, 5.0)])
+            .build();
+
+        let paths = dijkstra(&graph, 2);
+
+        assert_eq!(paths.distances(), &[2.0, 1.0, 0.0]);
+        assert_eq!(paths.path_to(0), Some(vec![2, 1, 0]));
+    }
+
+    #[test]
+    fn test_astar() {
+        let graph = graph();
+        // The remaining distances to `f`, which never overestimate.
+        let estimates = [20.0, 16.0, 18.0, 11.0, 15.0, 0.0];
+
+        let paths = astar(&graph, 0, 5, |node| estimates[node]);
+
+        assert_eq!(paths.distance(5), 20.0);
+        assert_eq!(paths.path_to(5), Some(vec![0, 2, 4, 3, 5]));
+    }
+
+    #[test]
+    fn test_astar_stops_at_target() {
+        let graph = graph();
+
+        let paths = astar(&graph, 0, 2, |_| 0.0);
+
+        assert_eq!(paths.path_to(2), Some(vec![0, 2]));
+        assert!(!paths.is_reachable(5));
+    }
+}
@@ -0,0 +1,401 @@
+//! Sparse matrix-vector multiplication over the adjacency matrix of a graph.
+//!
+//! Many graph algorithms can be expressed in the language of linear algebra
+//! as in GraphBLAS [1]: a breadth-first search step is a multiplication of the
+//! frontier vector with the adjacency matrix using the `or-and` semiring, a
+//! Bellman-Ford relaxation step uses the `min-plus` semiring and a PageRank
+//! iteration uses the conventional `plus-times` semiring.
+//!
+//! The multiplication computes `y = x ⊕.⊗ A`, i.e., for each node `v`,
+//! `y[v]` is the semiring sum of `x[u] ⊗ A[u][v]` over all edges `(u, v)`.
+//! The matrix entry `A[u][v]` is the value of the edge or the semiring one for
+//! graphs without edge values. The computation pulls values along incoming
+//! edges and is parallelized over the nodes.
+//!
+//! [1] Jeremy Kepner et al.:
+//! "Mathematical foundations of the GraphBLAS",
+//! IEEE High Performance Extreme Computing Conference 2016
+//!
+//! # Example
+//!
+//! Breadth-first search from node 0:
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)])
+//!     .build();
+//!
+//! let mut visited = vec![true, false, false, false, false];
+//! let mut frontier = visited.clone();
+//! let mut levels = vec![0, 0, 0, 0, 0];
+//!
+//! for level in 1.. {
+//!     // only compute nodes that have not been visited yet
+//!     frontier = masked_spmv(&graph, OrAnd, &frontier, &visited, true);
+//!     if !frontier.contains(&true) {
+//!         break;
+//!     }
+//!     for (node, reached) in frontier.iter().enumerate().filter(|(_, r)| **r) {
+//!         visited[node] = true;
+//!         levels[node] = level;
+//!     }
+//! }
+//!
+//! assert_eq!(levels, vec![0, 1, 1, 2, 3]);
+//! ```
+
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+/// A semiring defines the addition and multiplication used in
+/// [`spmv`] and [`masked_spmv`].
+pub trait Semiring<T>: Copy + Send + Sync {
+    /// The identity of addition.
+    fn zero(&self) -> T;
+
+    /// The identity of multiplication.
+    fn one(&self) -> T;
+
+    fn add(&self, a: T, b: T) -> T;
+
+    fn multiply(&self, a: T, b: T) -> T;
+}
+
+/// The conventional semiring with `+` as addition and `*` as multiplication.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlusTimes;
+
+/// The tropical semiring with `min` as addition and `+` as multiplication.
+///
+/// Infinity, the identity of `min`, is `INFINITY` for floats and `MAX` for
+/// integers. Integer sums saturate at `MAX`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MinPlus;
+
+/// The boolean semiring with `or` as addition and `and` as multiplication.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OrAnd;
+
+macro_rules! impl_numeric_semirings {
+    ($infinity:ident, $plus:ident, $($ty:ty),+ $(,)?) => {
+        $(
+            impl Semiring<$ty> for PlusTimes {
+                #[inline]
+                fn zero(&self) -> $ty {
+                    0 as $ty
+                }
+
+                #[inline]
+                fn one(&self) -> $ty {
+                    1 as $ty
+                }
+
+                #[inline]
+                fn add(&self, a: $ty, b: $ty) -> $ty {
+                    a + b
+                }
+
+                #[inline]
+                fn multiply(&self, a: $ty, b: $ty) -> $ty {
+                    a * b
+                }
+            }
+
+            impl Semiring<$ty> for MinPlus {
+                #[inline]
+                fn zero(&self) -> $ty {
+                    <$ty>::$infinity
+                }
+
+                #[inline]
+                fn one(&self) -> $ty {
+                    0 as $ty
+                }
+
+                #[inline]
+                fn add(&self, a: $ty, b: $ty) -> $ty {
+                    if b < a {
+                        b
+                    } else {
+                        a
+                    }
+                }
+
+                #[inline]
+                fn multiply(&self, a: $ty, b: $ty) -> $ty {
+                    $plus!(a, b)
+                }
+            }
+        )+
+    };
+}
+
+// Infinity absorbs finite values in float sums.
+macro_rules! float_add {
+    ($a:expr, $b:expr) => {
+        $a + $b
+    };
+}
+
+// Sums stay at `MAX`, i.e., infinity, instead of overflowing.
+macro_rules! saturating_add {
+    ($a:expr, $b:expr) => {
+        $a.saturating_add($b)
+    };
+}
+
+impl_numeric_semirings!(INFINITY, float_add, f32, f64);
+impl_numeric_semirings!(MAX, saturating_add, u32, u64, usize);
+
+impl Semiring<bool> for OrAnd {
+    #[inline]
+    fn zero(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn one(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn add(&self, a: bool, b: bool) -> bool {
+        a || b
+    }
+
+    #[inline]
+    fn multiply(&self, a: bool, b: bool) -> bool {
+        a && b
+    }
+}
+
+/// An edge value that can be used as an entry of the adjacency matrix.
+pub trait MatrixValue<T> {
+    /// Returns the matrix entry for an edge with this value.
+    fn entry<S: Semiring<T>>(&self, semiring: &S) -> T;
+}
+
+impl<T> MatrixValue<T> for () {
+    #[inline]
+    fn entry<S: Semiring<T>>(&self, semiring: &S) -> T {
+        semiring.one()
+    }
+}
+
+macro_rules! impl_matrix_value {
+    ($value:ty => $($ty:ty),+) => {
+        $(
+            impl MatrixValue<$ty> for $value {
+                #[inline]
+                fn entry<S: Semiring<$ty>>(&self, _semiring: &S) -> $ty {
+                    <$ty>::from(*self)
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_value!(f32 => f32, f64);
+impl_matrix_value!(f64 => f64);
+impl_matrix_value!(u32 => u32, u64);
+impl_matrix_value!(u64 => u64);
+impl_matrix_value!(usize => usize);
+impl_matrix_value!(bool => bool);
+
+/// A graph whose adjacency matrix can be multiplied with a vector.
+///
+/// For directed graphs, values are pulled along incoming edges. For
+/// undirected graphs, the adjacency matrix is symmetric.
+pub trait SpmvGraph<NI: Idx>: Graph<NI> + Sync {
+    /// The edge value type.
+    type EV;
+
+    /// Calls the given function for each edge `(source, node)` of the given
+    /// node.
+    fn for_each_in_neighbor<F>(&self, node: NI, f: F)
+    where
+        F: FnMut(NI, &Self::EV);
+}
+
+macro_rules! impl_spmv_graph {
+    ($graph:ident, $neighbors:ident) => {
+        impl<NI, NV, EV> SpmvGraph<NI> for $graph<NI, NV, EV>
+        where
+            NI: Idx,
+            NV: Send + Sync,
+            EV: Send + Sync,
+        {
+            type EV = EV;
+
+            #[inline]
+            fn for_each_in_neighbor<F>(&self, node: NI, mut f: F)
+            where
+                F: FnMut(NI, &EV),
+            {
+                for t in self.$neighbors(node) {
+                    f(t.target, &t.value);
+                }
+            }
+        }
+    };
+}
+
+impl_spmv_graph!(DirectedCsrGraph, in_neighbors_with_values);
+impl_spmv_graph!(UndirectedCsrGraph, neighbors_with_values);
+
+/// Multiplies the vector `x` with the adjacency matrix of the graph using
+/// the given semiring.
+///
+/// # Panics
+///
+/// Panics if the length of `x` does not match the node count.
+pub fn spmv<NI, G, S, T>(graph: &G, semiring: S, x: &[T]) -> Vec<T>
+where
+    NI: Idx,
+    G: SpmvGraph<NI>,
+    G::EV: MatrixValue<T>,
+    S: Semiring<T>,
+    T: Copy + Send + Sync,
+{
+    multiply(graph, semiring, x, |_| true)
+}
+
+/// Multiplies the vector `x` with the adjacency matrix of the graph using
+/// the given semiring, but only computes entries selected by the mask.
+///
+/// If `complement` is `false`, the entries where `mask` is `true` are
+/// computed, otherwise the entries where `mask` is `false`. All other entries
+/// are set to the semiring zero.
+///
+/// # Panics
+///
+/// Panics if the length of `x` or `mask` does not match the node count.
+pub fn masked_spmv<NI, G, S, T>(
+    graph: &G,
+    semiring: S,
+    x: &[T],
+    mask: &[bool],
+    complement: bool,
+) -> Vec<T>
+where
+    NI: Idx,
+    G: SpmvGraph<NI>,
+    G::EV: MatrixValue<T>,
+    S: Semiring<T>,
+    T: Copy + Send + Sync,
+{
+    assert_eq!(
+        mask.len(),
+        graph.node_count().index(),
+        "mask length must match node count"
+    );
+    multiply(graph, semiring, x, |node| mask[node] != complement)
+}
+
+fn multiply<NI, G, S, T, M>(graph: &G, semiring: S, x: &[T], selected: M) -> Vec<T>
+where
+    NI: Idx,
+    G: SpmvGraph<NI>,
+    G::EV: MatrixValue<T>,
+    S: Semiring<T>,
+    T: Copy + Send + Sync,
+    M: Fn(usize) -> bool + Sync,
+{
+    assert_eq!(
+        x.len(),
+        graph.node_count().index(),
+        "vector length must match node count"
+    );
+
+    (0..x.len())
+        .into_par_iter()
+        .map(|node| {
+            let mut sum = semiring.zero();
+            if selected(node) {
+                graph.for_each_in_neighbor(NI::new(node), |source, value| {
+                    let product = semiring.multiply(x[source.index()], value.entry(&semiring));
+                    sum = semiring.add(sum, product);
+                });
+            }
+            sum
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    #[test]
+    fn test_plus_times() {
+        let graph: DirectedCsrGraph<u32, (), f64> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.5), (0, 2, 0.5), (1, 2, 1.0), (2, 0, 1.0)])
+            .build();
+
+        let y = spmv(&graph, PlusTimes, &[1.0, 2.0, 4.0]);
+
+        assert_eq!(y, vec![4.0, 0.5, 2.5]);
+    }
+
+    #[test]
+    fn test_min_plus_bellman_ford() {
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 4.0), (0, 2, 1.0), (2, 1, 2.0), (1, 3, 1.0)])
+            .build();
+
+        let mut distances = vec![0.0, f32::INFINITY, f32::INFINITY, f32::INFINITY];
+        for _ in 0..graph.node_count() {
+            let relaxed = spmv(&graph, MinPlus, &distances);
+            distances = distances
+                .iter()
+                .zip(relaxed)
+                .map(|(d, r)| f32::min(*d, r))
+                .collect();
+        }
+
+        assert_eq!(distances, vec![0.0, 3.0, 1.0, 4.0]);
+    }
+
+    #[test]
+    fn test_min_plus_infinity() {
+        let graph: DirectedCsrGraph<u32, (), u64> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 2, 5), (1, 2, 1), (2, 3, u64::MAX)])
+            .build();
+
+        assert_eq!(Semiring::<f32>::zero(&MinPlus), f32::INFINITY);
+        assert_eq!(
+            spmv(&graph, MinPlus, &[3, u64::MAX, 7, 1]),
+            vec![u64::MAX, u64::MAX, 8, u64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_masked_or_and() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 3)])
+            .build();
+
+        let frontier = vec![false, true, false, false];
+        let visited = vec![true, true, false, false];
+
+        assert_eq!(
+            spmv(&graph, OrAnd, &frontier),
+            vec![true, false, true, false]
+        );
+        assert_eq!(
+            masked_spmv(&graph, OrAnd, &frontier, &visited, true),
+            vec![false, false, true, false]
+        );
+        assert_eq!(
+            masked_spmv(&graph, OrAnd, &frontier, &visited, false),
+            vec![true, false, false, false]
+        );
+    }
+}
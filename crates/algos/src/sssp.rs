@@ -1,13 +1,36 @@
+//! Single-source shortest paths on graphs with non-negative edge weights.
+//!
+//! [`delta_stepping`] implements the delta-stepping algorithm [1]. Nodes are
+//! kept in buckets of width `delta` by their tentative distance and the
+//! buckets are processed in ascending order, where all nodes of a bucket are
+//! relaxed in parallel. Small values of `delta` approach Dijkstra's algorithm
+//! with little parallelism, large values approach Bellman-Ford with many
+//! redundant relaxations. [`auto_delta`] chooses a width from the edge weights
+//! and degrees of the graph.
+//!
+//! The implementation follows the GAP benchmark suite [2]. Each thread keeps
+//! its own buckets, which are merged into a shared frontier after each
+//! bucket. With bucket fusion, a thread keeps relaxing the nodes it added to
+//! the current bucket itself as long as that bucket is small, which avoids a
+//! global synchronization for each of the many tiny rounds that a bucket
+//! often takes to settle.
+//!
+//! [1] Ulrich Meyer, Peter Sanders:
+//! "Δ-stepping: a parallelizable shortest path algorithm",
+//! Journal of Algorithms, 2003
+//!
+//! [2] Scott Beamer, Krste Asanović, David Patterson:
+//! "The GAP Benchmark Suite",
+//! arXiv:1508.03619, 2015
+
 use crate::prelude::*;
 
 use atomic_float::AtomicF32;
 use log::info;
 use rayon::prelude::*;
 
-use std::{
-    sync::atomic::{AtomicUsize, Ordering},
-    time::Instant,
-};
+use graph_builder::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 const INF: f32 = f32::MAX;
 const NO_BIN: usize = usize::MAX;
@@ -33,12 +56,104 @@ impl DeltaSteppingConfig {
     pub fn new(start_node: usize, delta: f32) -> Self {
         Self { start_node, delta }
     }
+
+    /// Creates a config for the given start node with a delta chosen by
+    /// [`auto_delta`].
+    pub fn with_auto_delta<NI, G>(graph: &G, start_node: usize) -> Self
+    where
+        NI: Idx,
+        G: Graph<NI> + DirectedNeighborsWithValues<NI, f32> + Sync,
+    {
+        Self::new(start_node, auto_delta(graph))
+    }
+}
+
+/// Chooses the bucket width for [`delta_stepping`] based on the average edge
+/// weight and the average out-degree of the graph.
+///
+/// For random edge weights, a width of about the maximum weight divided by
+/// the degree keeps the number of relaxations linear in the number of edges
+/// while the number of buckets stays small [1]. The maximum weight is
+/// estimated as twice the average weight, which is robust against outliers.
+/// The width is at least the smallest positive edge weight, since narrower
+/// buckets would only add empty buckets, e.g., every edge of an unweighted
+/// graph is relaxed in BFS order with a width of `1`.
+///
+/// # Example
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+///     .edges_with_values(vec![(0, 1, 0.5), (0, 2, 1.5), (1, 2, 0.5), (2, 0, 1.5)])
+///     .build();
+///
+/// // twice the average weight of 1.0 divided by the average degree of 4 / 3
+/// assert_eq!(auto_delta(&graph), 1.5);
+/// ```
+pub fn auto_delta<NI, G>(graph: &G) -> f32
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighborsWithValues<NI, f32> + Sync,
+{
+    let node_count = graph.node_count().index();
+    let edge_count = graph.edge_count().index();
+
+    if node_count == 0 || edge_count == 0 {
+        return 1.0;
+    }
+
+    let (weight_sum, min_weight) = (0..node_count)
+        .into_par_iter()
+        .map(|node| {
+            graph.out_neighbors_with_values(NI::new(node)).fold(
+                (0.0_f64, INF),
+                |(sum, min), target| {
+                    let min = if target.value > 0.0 {
+                        f32::min(min, target.value)
+                    } else {
+                        min
+                    };
+                    (sum + target.value as f64, min)
+                },
+            )
+        })
+        .reduce(
+            || (0.0, INF),
+            |(sum1, min1), (sum2, min2)| (sum1 + sum2, f32::min(min1, min2)),
+        );
+
+    let average_weight = weight_sum / edge_count as f64;
+    let average_degree = edge_count as f64 / node_count as f64;
+    let delta = (2.0 * average_weight / average_degree) as f32;
+
+    if min_weight == INF {
+        // All edges have a weight of zero.
+        1.0
+    } else {
+        f32::max(delta, min_weight)
+    }
 }
 
 pub fn delta_stepping<NI, G>(graph: &G, config: DeltaSteppingConfig) -> Vec<AtomicF32>
 where
     NI: Idx,
     G: Graph<NI> + DirectedNeighborsWithValues<NI, f32> + Sync,
+{
+    delta_stepping_with_progress(graph, config, &NoProgress)
+}
+
+/// Runs [`delta_stepping`] and reports the number of processed bins of the
+/// `sssp` task to the given tracker. The number of bins is not known upfront.
+pub fn delta_stepping_with_progress<NI, G, T>(
+    graph: &G,
+    config: DeltaSteppingConfig,
+    tracker: &T,
+) -> Vec<AtomicF32>
+where
+    NI: Idx,
+    G: Graph<NI> + DirectedNeighborsWithValues<NI, f32> + Sync,
+    T: ProgressTracker + ?Sized,
 {
     let start = Instant::now();
 
@@ -57,9 +172,10 @@ where
     let mut frontier_len = 1;
 
     let mut local_bins = Vec::with_capacity(thread_count);
-    local_bins.resize_with(thread_count, ThreadLocalBins::<NI>::new);
+    local_bins.resize_with(thread_count, BucketQueue::<NI>::new);
 
     let mut curr_bin = 0;
+    let mut processed_bins = 0;
 
     while curr_bin != NO_BIN {
         frontier_idx.store(0, Ordering::Relaxed);
@@ -85,15 +201,20 @@ where
         frontier_len = frontier_slices(&mut frontier, &local_bins, next_bin)
             .par_iter_mut()
             .zip(local_bins.par_iter_mut())
-            .filter(|(_, local_bins)| local_bins.contains(next_bin))
             .map(|(slice, local_bins)| {
-                slice.copy_from_slice(local_bins.slice(next_bin));
+                slice.copy_from_slice(local_bins.bucket(next_bin));
                 local_bins.clear(next_bin);
                 slice.len()
             })
             .sum();
 
         curr_bin = next_bin;
+
+        processed_bins += 1;
+        tracker.progress("sssp", processed_bins, None);
+        if tracker.is_cancelled() {
+            break;
+        }
     }
 
     info!("Computed SSSP in {:?}", start.elapsed());
@@ -102,13 +223,13 @@ where
 }
 
 fn process_shared_bin<'bins, NI, G>(
-    bins: &'bins mut ThreadLocalBins<NI>,
+    bins: &'bins mut BucketQueue<NI>,
     curr_bin: usize,
     graph: &G,
     (frontier, frontier_idx, frontier_len): (&[NI], &AtomicUsize, usize),
     distance: &[AtomicF32],
     delta: f32,
-) -> &'bins mut ThreadLocalBins<NI>
+) -> &'bins mut BucketQueue<NI>
 where
     NI: Idx,
     G: Graph<NI> + DirectedNeighborsWithValues<NI, f32> + Sync,
@@ -132,45 +253,38 @@ where
 }
 
 fn process_local_bins<'bins, NI, G>(
-    bins: &'bins mut ThreadLocalBins<NI>,
+    bins: &'bins mut BucketQueue<NI>,
     curr_bin: usize,
     graph: &G,
     distance: &[AtomicF32],
     delta: f32,
-) -> &'bins mut ThreadLocalBins<NI>
+) -> &'bins mut BucketQueue<NI>
 where
     NI: Idx,
     G: Graph<NI> + DirectedNeighborsWithValues<NI, f32> + Sync,
 {
-    while curr_bin < bins.len()
-        && !bins.is_empty(curr_bin)
-        && bins.bin_len(curr_bin) < BIN_SIZE_THRESHOLD
-    {
-        let current_bin_copy = bins.clone(curr_bin);
-        bins.clear(curr_bin);
-
-        for node in current_bin_copy {
-            relax_edges(graph, distance, bins, node, delta);
+    while !bins.bucket(curr_bin).is_empty() && bins.bucket(curr_bin).len() < BIN_SIZE_THRESHOLD {
+        for node in bins.take(curr_bin) {
+            // Mirrors the staleness guard of GAP: an entry whose distance
+            // dropped below the current bin has already been relaxed from a
+            // lower bin. Duplicate entries within the current bin are not
+            // detected and relaxed again, which only costs time.
+            if distance[node.index()].load(Ordering::Acquire) >= delta * curr_bin as f32 {
+                relax_edges(graph, distance, bins, node, delta);
+            }
         }
     }
     bins
 }
 
-fn min_non_empty_bin<NI: Idx>(local_bins: &mut ThreadLocalBins<NI>, curr_bin: usize) -> usize {
-    let mut next_local_bin = NO_BIN;
-    for bin in curr_bin..local_bins.len() {
-        if !local_bins.is_empty(bin) {
-            next_local_bin = bin;
-            break;
-        }
-    }
-    next_local_bin
+fn min_non_empty_bin<NI: Idx>(local_bins: &mut BucketQueue<NI>, curr_bin: usize) -> usize {
+    local_bins.next_non_empty(curr_bin).unwrap_or(NO_BIN)
 }
 
 fn relax_edges<NI, G>(
     graph: &G,
     distances: &[AtomicF32],
-    local_bins: &mut ThreadLocalBins<NI>,
+    local_bins: &mut BucketQueue<NI>,
     node: NI,
     delta: f32,
 ) where
@@ -190,9 +304,6 @@ fn relax_edges<NI, G>(
             ) {
                 Ok(_) => {
                     let dest_bin = (new_distance / delta) as usize;
-                    if dest_bin >= local_bins.len() {
-                        local_bins.resize(dest_bin + 1);
-                    }
                     local_bins.push(dest_bin, *target);
                     break;
                 }
@@ -205,75 +316,21 @@ fn relax_edges<NI, G>(
 
 fn frontier_slices<'a, NI: Idx>(
     frontier: &'a mut [NI],
-    bins: &[ThreadLocalBins<NI>],
+    bins: &[BucketQueue<NI>],
     next_bin: usize,
 ) -> Vec<&'a mut [NI]> {
     let mut slices = Vec::with_capacity(bins.len());
     let mut tail = frontier;
 
     for local_bins in bins.iter() {
-        if local_bins.contains(next_bin) {
-            let (head, remainder) = tail.split_at_mut(local_bins.bin_len(next_bin));
-            slices.push(head);
-            tail = remainder;
-        } else {
-            slices.push(&mut []);
-        }
+        let (head, remainder) = tail.split_at_mut(local_bins.bucket(next_bin).len());
+        slices.push(head);
+        tail = remainder;
     }
 
     slices
 }
 
-#[derive(Debug)]
-struct ThreadLocalBins<T> {
-    bins: Vec<Vec<T>>,
-}
-
-impl<T> ThreadLocalBins<T>
-where
-    T: Clone,
-{
-    fn new() -> Self {
-        Self { bins: vec![vec![]] }
-    }
-
-    fn contains(&self, bin: usize) -> bool {
-        self.len() > bin
-    }
-
-    fn len(&self) -> usize {
-        self.bins.len()
-    }
-
-    fn bin_len(&self, bin: usize) -> usize {
-        self.bins[bin].len()
-    }
-
-    fn is_empty(&self, bin: usize) -> bool {
-        self.bins[bin].is_empty()
-    }
-
-    fn clone(&self, bin: usize) -> Vec<T> {
-        self.bins[bin].clone()
-    }
-
-    fn clear(&mut self, bin: usize) {
-        self.bins[bin].clear();
-    }
-
-    fn slice(&self, bin: usize) -> &[T] {
-        &self.bins[bin]
-    }
-
-    fn resize(&mut self, new_len: usize) {
-        self.bins.resize_with(new_len, Vec::new)
-    }
-
-    fn push(&mut self, bin: usize, val: T) {
-        self.bins[bin].push(val);
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,4 +368,36 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_sssp_auto_delta() {
+        // a grid with unit weights to the right and higher weights downwards
+        let edges = (0..100_usize)
+            .flat_map(|node| {
+                let right = (node % 10 < 9).then(|| (node, node + 1, 1.0));
+                let down = (node < 90).then(|| (node, node + 10, 2.5));
+                right.into_iter().chain(down)
+            })
+            .collect::<Vec<_>>();
+        let graph: DirectedCsrGraph<usize, (), f32> =
+            GraphBuilder::new().edges_with_values(edges).build();
+
+        let config = DeltaSteppingConfig::with_auto_delta(&graph, 0);
+        assert!(config.delta >= 1.0);
+
+        let actual = delta_stepping(&graph, config);
+        for node in 0..100 {
+            let expected = (node % 10) as f32 + (node / 10) as f32 * 2.5;
+            assert_eq!(actual[node].load(Ordering::Relaxed), expected);
+        }
+    }
+
+    #[test]
+    fn test_auto_delta_zero_weights() {
+        let graph: DirectedCsrGraph<usize, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 0.0)])
+            .build();
+
+        assert_eq!(auto_delta(&graph), 1.0);
+    }
 }
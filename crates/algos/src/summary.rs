@@ -0,0 +1,224 @@
+//! Percentile summaries of algorithm results, such as page rank scores or
+//! community sizes.
+//!
+//! Percentiles are computed from an [HDR histogram](https://hdrhistogram.org)
+//! that keeps three significant digits, so they have a relative error of at
+//! most 0.1% and take constant memory, independent of the number of values.
+//! Each thread records into its own histogram and the histograms are merged
+//! afterwards. The maximum and the mean are exact.
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let sizes = (1..=100).collect::<Vec<u64>>();
+//! let summary = ResultSummary::from_counts(&sizes).unwrap();
+//!
+//! assert_eq!(summary.p50, 50.0);
+//! assert_eq!(summary.max, 100.0);
+//! assert_eq!(summary.mean, 50.5);
+//! ```
+
+use graph_builder::time::Instant;
+
+use hdrhistogram::Histogram;
+use log::info;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+const SIGNIFICANT_DIGITS: u8 = 3;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResultSummary {
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+impl ResultSummary {
+    /// Summarizes the given scores, e.g., page rank scores.
+    ///
+    /// Negative and non-finite scores, e.g., the infinite distances of
+    /// unreachable nodes, are ignored. Returns `None` if there are no other
+    /// scores.
+    pub fn from_scores(scores: &[f32]) -> Option<Self> {
+        let start = Instant::now();
+
+        let valid = |x: &&f32| x.is_finite() && **x >= 0.0;
+        let (count, sum, max, min_positive) = scores
+            .par_iter()
+            .filter(valid)
+            .map(|&x| {
+                let positive = if x > 0.0 { x as f64 } else { f64::INFINITY };
+                (1_usize, x as f64, x as f64, positive)
+            })
+            .reduce(
+                || (0, 0.0, 0.0, f64::INFINITY),
+                |(c1, s1, m1, p1), (c2, s2, m2, p2)| (c1 + c2, s1 + s2, m1.max(m2), p1.min(p2)),
+            );
+
+        if count == 0 {
+            return None;
+        }
+
+        // The histogram records integers, so scores are recorded as multiples
+        // of the smallest positive score, which keeps their relative precision.
+        let unit = if min_positive.is_finite() {
+            min_positive
+        } else {
+            1.0
+        };
+        let histogram = histogram(
+            scores
+                .par_iter()
+                .filter(valid)
+                .map(|&x| (x as f64 / unit).round() as u64),
+        );
+
+        let summary = Self::new(&histogram, unit, max, sum / count as f64);
+
+        info!("Summarized {count} scores in {:?}", start.elapsed());
+
+        Some(summary)
+    }
+
+    /// Summarizes the given counts, e.g., community sizes. Returns `None` if
+    /// there are no counts.
+    pub fn from_counts(counts: &[u64]) -> Option<Self> {
+        if counts.is_empty() {
+            return None;
+        }
+
+        let start = Instant::now();
+
+        let histogram = histogram(counts.par_iter().copied());
+        let sum = counts.par_iter().map(|&c| c as f64).sum::<f64>();
+        let max = counts.par_iter().copied().max().unwrap_or_default() as f64;
+
+        let summary = Self::new(&histogram, 1.0, max, sum / counts.len() as f64);
+
+        info!(
+            "Summarized {} counts in {:?}",
+            counts.len(),
+            start.elapsed()
+        );
+
+        Some(summary)
+    }
+
+    fn new(histogram: &Histogram<u64>, unit: f64, max: f64, mean: f64) -> Self {
+        // Percentiles are the highest value that is equivalent to the recorded
+        // values, which may exceed the actual maximum.
+        let percentile = |q| (histogram.value_at_quantile(q) as f64 * unit).min(max);
+        Self {
+            p50: percentile(0.5),
+            p90: percentile(0.9),
+            p99: percentile(0.99),
+            max,
+            mean,
+        }
+    }
+
+    /// Summarizes the sizes of the communities, e.g., of weakly connected
+    /// components, where node `i` belongs to community `communities[i]`.
+    pub fn from_communities<NI: Idx>(communities: &[NI]) -> Option<Self> {
+        Self::from_counts(&community_sizes(communities))
+    }
+}
+
+/// Returns the number of nodes in each community, in ascending order of the
+/// community ids. Community ids that are not used by any node are left out.
+pub fn community_sizes<NI: Idx>(communities: &[NI]) -> Vec<u64> {
+    let community_count = communities
+        .par_iter()
+        .map(|c| c.index() + 1)
+        .max()
+        .unwrap_or_default();
+
+    let mut sizes = vec![0_u64; community_count];
+    for community in communities {
+        sizes[community.index()] += 1;
+    }
+    sizes.retain(|&size| size > 0);
+    sizes
+}
+
+fn histogram(values: impl ParallelIterator<Item = u64>) -> Histogram<u64> {
+    let new_histogram =
+        || Histogram::<u64>::new(SIGNIFICANT_DIGITS).expect("valid number of significant digits");
+
+    values
+        .fold(new_histogram, |mut histogram, value| {
+            histogram.saturating_record(value);
+            histogram
+        })
+        .reduce(new_histogram, |mut left, right| {
+            left.add(right)
+                .expect("auto-resizing histograms can be added");
+            left
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= expected * 1E-3,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn test_from_counts() {
+        let counts = (1..=1000).collect::<Vec<u64>>();
+
+        let summary = ResultSummary::from_counts(&counts).unwrap();
+
+        assert_close(summary.p50, 500.0);
+        assert_close(summary.p90, 900.0);
+        assert_close(summary.p99, 990.0);
+        assert_eq!(summary.max, 1000.0);
+        assert_eq!(summary.mean, 500.5);
+        assert_eq!(ResultSummary::from_counts(&[]), None);
+    }
+
+    #[test]
+    fn test_from_scores() {
+        let mut scores = (1..=1000).map(|i| i as f32 * 1E-6).collect::<Vec<_>>();
+        scores.push(f32::INFINITY);
+        scores.push(f32::NAN);
+
+        let summary = ResultSummary::from_scores(&scores).unwrap();
+
+        assert_close(summary.p50, 500E-6);
+        assert_close(summary.p90, 900E-6);
+        assert_close(summary.p99, 990E-6);
+        assert_close(summary.max, 1000E-6);
+        assert_close(summary.mean, 500.5E-6);
+    }
+
+    #[test]
+    fn test_from_scores_without_valid_scores() {
+        assert_eq!(ResultSummary::from_scores(&[f32::INFINITY, -1.0]), None);
+
+        let summary = ResultSummary::from_scores(&[0.0, 0.0]).unwrap();
+        assert_eq!(summary.p99, 0.0);
+        assert_eq!(summary.max, 0.0);
+    }
+
+    #[test]
+    fn test_from_communities() {
+        let communities = [0_u32, 0, 0, 3, 3, 5];
+
+        assert_eq!(community_sizes(&communities), vec![3, 2, 1]);
+
+        let summary = ResultSummary::from_communities(&communities).unwrap();
+        assert_eq!(summary.max, 3.0);
+        assert_eq!(summary.mean, 2.0);
+    }
+}
@@ -0,0 +1,216 @@
+//! Extracting the highest-scoring nodes from algorithm results.
+//!
+//! Finding the `k` highest scores by sorting all scores takes `O(n log n)`
+//! time and a copy of the result, which is wasteful if `k` is much smaller
+//! than the number of nodes. Instead, each thread keeps the `k` highest
+//! scores it has seen so far in a bounded min-heap and the per-thread heaps
+//! are merged at the end, which takes `O(n log k)` time and `O(k)` memory
+//! per thread.
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let scores = vec![0.1, 0.4, 0.2, 0.3];
+//!
+//! assert_eq!(top_k::<u32, _>(&scores, 2), vec![(1, 0.4), (3, 0.3)]);
+//! ```
+//!
+//! The configurations of centrality algorithms have a `top_k` option, which
+//! is applied to their scores via [`TopK::top_nodes`]:
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (1, 2), (2, 3)])
+//!     .build();
+//!
+//! let config = BetweennessConfig {
+//!     top_k: Some(1),
+//!     ..BetweennessConfig::default()
+//! };
+//! let scores = betweenness(&graph, config);
+//!
+//! assert_eq!(config.top_nodes::<u32, _>(&scores), Some(vec![(1, 2.0)]));
+//! ```
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+/// Returns the `k` highest-scoring nodes together with their scores, where
+/// node `i` has the score at position `i`.
+///
+/// The nodes are ordered by descending score, nodes with equal scores by
+/// ascending node id. Scores that are not comparable with themselves, i.e.,
+/// `NaN`, are skipped.
+pub fn top_k<NI, T>(scores: &[T], k: usize) -> Vec<(NI, T)>
+where
+    NI: Idx,
+    T: PartialOrd + Copy + Send + Sync,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let heap = scores
+        .par_iter()
+        .enumerate()
+        .filter(|(_, score)| score.partial_cmp(score).is_some())
+        .fold(
+            || BinaryHeap::with_capacity(k + 1),
+            |mut heap, (node, &score)| {
+                push_bounded(&mut heap, Entry { score, node }, k);
+                heap
+            },
+        )
+        .reduce(BinaryHeap::new, |mut left, mut right| {
+            if left.len() < right.len() {
+                std::mem::swap(&mut left, &mut right);
+            }
+            for entry in right {
+                push_bounded(&mut left, entry, k);
+            }
+            left
+        });
+
+    // The heap's order is the reverse of the result order.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Entry { score, node }| (NI::new(node), score))
+        .collect()
+}
+
+/// Implemented by the configurations of score-valued algorithms to limit
+/// their result to the highest-scoring nodes.
+pub trait TopK {
+    /// The number of highest-scoring nodes to return, if set.
+    fn top_k(&self) -> Option<usize>;
+
+    /// Returns the [`top_k`](TopK::top_k) highest-scoring nodes of the given
+    /// scores, see [`top_k`](fn@top_k), or `None` if the option is not set.
+    fn top_nodes<NI, T>(&self, scores: &[T]) -> Option<Vec<(NI, T)>>
+    where
+        NI: Idx,
+        T: PartialOrd + Copy + Send + Sync,
+    {
+        TopK::top_k(self).map(|k| top_k(scores, k))
+    }
+}
+
+fn push_bounded<T: PartialOrd>(heap: &mut BinaryHeap<Entry<T>>, entry: Entry<T>, k: usize) {
+    if heap.len() < k {
+        heap.push(entry);
+    } else if let Some(mut worst) = heap.peek_mut() {
+        if entry < *worst {
+            *worst = entry;
+        }
+    }
+}
+
+/// Orders entries such that better entries, i.e., higher scores and lower
+/// node ids, are smaller, so that the top of the heap is the worst entry.
+struct Entry<T> {
+    score: T,
+    node: usize,
+}
+
+impl<T: PartialOrd> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Entry<T> {}
+
+impl<T: PartialOrd> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .partial_cmp(&self.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| self.node.cmp(&other.node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_k() {
+        let scores = [0.5, 0.1, 0.9, 0.3, 0.7];
+
+        assert_eq!(
+            top_k::<u32, _>(&scores, 3),
+            vec![(2, 0.9), (4, 0.7), (0, 0.5)]
+        );
+    }
+
+    #[test]
+    fn test_top_k_larger_than_scores() {
+        let scores = [1_u64, 3, 2];
+
+        assert_eq!(top_k::<usize, _>(&scores, 5), vec![(1, 3), (2, 2), (0, 1)]);
+        assert!(top_k::<usize, _>(&scores, 0).is_empty());
+    }
+
+    #[test]
+    fn test_top_k_ties_and_nan() {
+        let scores = [1.0, f32::NAN, 2.0, 1.0, 1.0];
+
+        assert_eq!(
+            top_k::<u32, _>(&scores, 3),
+            vec![(2, 2.0), (0, 1.0), (3, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_top_k_matches_sort() {
+        let scores = (0..100_000_u64)
+            .map(|i| (i.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 40) as f32)
+            .collect::<Vec<_>>();
+
+        let mut expected = scores
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(node, score)| (node as u32, score))
+            .collect::<Vec<_>>();
+        expected.sort_by(|(n1, s1), (n2, s2)| s2.total_cmp(s1).then(n1.cmp(n2)));
+        expected.truncate(100);
+
+        assert_eq!(top_k::<u32, _>(&scores, 100), expected);
+    }
+
+    #[test]
+    fn test_top_nodes() {
+        let scores = [0.5, 0.1, 0.9];
+
+        let config = PageRankConfig::default();
+        assert_eq!(config.top_nodes::<u32, _>(&scores), None);
+
+        let config = PageRankConfig {
+            top_k: Some(2),
+            ..PageRankConfig::default()
+        };
+        assert_eq!(
+            config.top_nodes::<u32, _>(&scores),
+            Some(vec![(2, 0.9), (0, 0.5)])
+        );
+
+        let config = HyperBallConfig {
+            top_k: Some(1),
+            ..HyperBallConfig::default()
+        };
+        assert_eq!(config.top_nodes::<u32, _>(&scores), Some(vec![(2, 0.9)]));
+    }
+}
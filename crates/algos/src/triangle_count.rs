@@ -1,13 +1,80 @@
-use crate::{prelude::*, DEFAULT_PARALLELISM};
+use crate::{prelude::*, run_workers, worker_count, RANGES_PER_WORKER};
 
+use ahash::AHashSet;
 use log::info;
 use num_format::{Locale, ToFormattedString};
+use rayon::prelude::*;
 
+use graph_builder::time::Instant;
+use std::cmp::Reverse;
 use std::sync::atomic::AtomicU64;
-use std::thread::available_parallelism;
-use std::{sync::atomic::Ordering, time::Instant};
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
-const CHUNK_SIZE: usize = 64;
+/// Nodes with fewer lower neighbors are always intersected by merging.
+const HASH_MIN_DEGREE: usize = 32;
+/// The estimated cost of a hash set insert or lookup relative to a step of
+/// merging two sorted neighbor lists.
+const HASH_COST_FACTOR: usize = 4;
+
+/// How the neighbor lists of two nodes are intersected to find their common
+/// neighbors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum IntersectionStrategy {
+    /// Merges the sorted neighbor lists, which is the fastest option if the
+    /// degrees of both nodes are similar.
+    Merge,
+    /// Inserts the neighbors of a node into a hash set once and looks up the
+    /// neighbors of each of its neighbors, which avoids scanning the
+    /// neighbors of high-degree nodes over and over again.
+    Hash,
+    /// Chooses between merging and hashing per node, based on its degree
+    /// and the degrees of its neighbors.
+    Adaptive,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "clap", derive(clap::Args))]
+pub struct TriangleCountConfig {
+    /// How the neighbor lists of two nodes are intersected.
+    #[cfg_attr(feature = "clap", clap(long, value_enum, default_value_t = TriangleCountConfig::DEFAULT_INTERSECTION))]
+    pub intersection: IntersectionStrategy,
+
+    /// If set, each edge is oriented from the node with the lower degree to
+    /// the node with the higher degree on an internal copy of the edges, so
+    /// that the graph neither needs to be degree-ordered with
+    /// [`relabel_graph`] nor deduplicated.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "clap", clap(long))]
+    pub orient_by_degree: bool,
+}
+
+impl Default for TriangleCountConfig {
+    fn default() -> Self {
+        Self {
+            intersection: Self::DEFAULT_INTERSECTION,
+            orient_by_degree: false,
+        }
+    }
+}
+
+impl TriangleCountConfig {
+    pub const DEFAULT_INTERSECTION: IntersectionStrategy = IntersectionStrategy::Adaptive;
+
+    pub fn new(intersection: IntersectionStrategy, orient_by_degree: bool) -> Self {
+        Self {
+            intersection,
+            orient_by_degree,
+        }
+    }
+}
 
 pub fn relabel_graph<NI, G, EV>(graph: &mut G)
 where
@@ -24,54 +91,166 @@ where
     NI: Idx,
     G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
 {
-    let start = Instant::now();
+    // The size hint is the exact degree for CSR graphs. Other graphs fall
+    // back to ranges with roughly the same number of nodes.
+    count_triangles_by(
+        graph,
+        |u| graph.neighbors(u).size_hint().0,
+        |u| merge_triangles(graph, u),
+    )
+}
 
-    let next_chunk = Atomic::new(NI::zero());
-    let total_triangles = AtomicU64::new(0);
+/// Counts the triangles like [`global_triangle_count`], but intersects
+/// neighbor lists as configured.
+///
+/// Unless `orient_by_degree` is set, the same requirements as for
+/// [`global_triangle_count`] apply.
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// // Neither deduplicated nor degree-ordered.
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(1, 2), (1, 3), (1, 4), (1, 0), (2, 3), (4, 0), (0, 4)])
+///     .build();
+///
+/// let config = TriangleCountConfig::new(IntersectionStrategy::Adaptive, true);
+///
+/// assert_eq!(global_triangle_count_with_config(&graph, config), 2);
+/// ```
+pub fn global_triangle_count_with_config<NI, G>(graph: &G, config: TriangleCountConfig) -> u64
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    if config.orient_by_degree {
+        let start = Instant::now();
+        let oriented = DegreeOriented::new(graph);
+        info!("Oriented edges by degree in {:?}", start.elapsed());
 
-    std::thread::scope(|s| {
-        let num_threads = available_parallelism().map_or(DEFAULT_PARALLELISM, |p| p.get());
+        count_with_strategy(&oriented, config.intersection)
+    } else {
+        count_with_strategy(graph, config.intersection)
+    }
+}
 
-        for _ in 0..num_threads {
-            s.spawn(|| {
-                let mut triangles = 0;
+/// Counts the triangles like [`global_triangle_count`], but intersects the
+/// neighbor lists using hash sets, see [`IntersectionStrategy::Hash`].
+pub fn global_triangle_count_hash<NI, G>(graph: &G) -> u64
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    count_with_strategy(graph, IntersectionStrategy::Hash)
+}
 
-                loop {
-                    let start = NI::fetch_add(&next_chunk, NI::new(CHUNK_SIZE), Ordering::AcqRel);
-                    if start >= graph.node_count() {
-                        break;
-                    }
+fn count_with_strategy<NI, G>(graph: &G, intersection: IntersectionStrategy) -> u64
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    match intersection {
+        IntersectionStrategy::Merge => count_triangles(graph, |u| merge_triangles(graph, u)),
+        IntersectionStrategy::Hash => count_triangles(graph, |u| hash_triangles(graph, u)),
+        IntersectionStrategy::Adaptive => count_triangles(graph, |u| {
+            if prefers_hash(graph, u) {
+                hash_triangles(graph, u)
+            } else {
+                merge_triangles(graph, u)
+            }
+        }),
+    }
+}
 
-                    let end = (start + NI::new(CHUNK_SIZE)).min(graph.node_count());
-
-                    for u in start.range(end) {
-                        for &v in graph.neighbors(u) {
-                            if v > u {
-                                break;
-                            }
-
-                            let mut it = put_back_iterator(graph.neighbors(u));
-
-                            for &w in graph.neighbors(v) {
-                                if w > v {
-                                    break;
-                                }
-                                while let Some(x) = it.next() {
-                                    if x >= &w {
-                                        if x == &w {
-                                            triangles += 1;
-                                        }
-                                        it.put_back(x);
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
+/// Counts the triangles like [`global_triangle_count`], but looks up the
+/// neighbors of indexed nodes in the index of the [`HybridView`] instead of
+/// merging neighbor lists.
+///
+/// On graphs with a few nodes of very high degree, merging the neighbor
+/// lists of these nodes dominates the run time of [`global_triangle_count`].
+/// As for [`global_triangle_count`], the graph must be degree-ordered and
+/// built with [`CsrLayout::Deduplicated`].
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let mut graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Deduplicated)
+///     .edges(vec![(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (3, 4)])
+///     .build();
+///
+/// relabel_graph(&mut graph);
+/// let view = HybridView::new(&graph, 3);
+///
+/// assert_eq!(global_triangle_count_hybrid(&view), 2);
+/// ```
+pub fn global_triangle_count_hybrid<NI, G>(view: &HybridView<'_, G>) -> u64
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    let graph = view.graph();
+
+    count_triangles(graph, |u| {
+        if !view.is_indexed(u) {
+            return merge_triangles(graph, u);
+        }
+
+        let mut triangles = 0;
+        for &v in graph.neighbors(u) {
+            if v > u {
+                break;
+            }
+            for &w in graph.neighbors(v) {
+                if w > v {
+                    break;
                 }
-                total_triangles.fetch_add(triangles, Ordering::AcqRel);
-            });
+                if view.contains_edge(u, w) {
+                    triangles += 1;
+                }
+            }
+        }
+        triangles
+    })
+}
+
+fn count_triangles<NI, G, F>(graph: &G, node_triangles: F) -> u64
+where
+    NI: Idx,
+    G: Graph<NI> + UndirectedDegrees<NI> + Sync,
+    F: Fn(NI) -> u64 + Sync,
+{
+    count_triangles_by(graph, |u| graph.degree(u).index(), node_triangles)
+}
+
+// Sums up the triangles of all nodes in parallel, balancing the work of the
+// workers by the given degree.
+fn count_triangles_by<NI, G, D, F>(graph: &G, degree: D, node_triangles: F) -> u64
+where
+    NI: Idx,
+    G: Graph<NI> + Sync,
+    D: Fn(NI) -> usize + Sync,
+    F: Fn(NI) -> u64 + Sync,
+{
+    let start = Instant::now();
+
+    let ranges = edge_balanced_partition(
+        graph.node_count(),
+        worker_count() * RANGES_PER_WORKER,
+        degree,
+    );
+    let next_range = AtomicUsize::new(0);
+    let total_triangles = AtomicU64::new(0);
+
+    run_workers(|| {
+        let mut triangles = 0;
+
+        while let Some(range) = ranges.get(next_range.fetch_add(1, Ordering::AcqRel)) {
+            for u in range.start.range(range.end) {
+                triangles += node_triangles(u);
+            }
         }
+        total_triangles.fetch_add(triangles, Ordering::AcqRel);
     });
 
     let tc = total_triangles.load(Ordering::SeqCst);
@@ -85,6 +264,160 @@ where
     tc
 }
 
+// Counts the triangles `(u, v, w)` with `w < v < u` by merging the sorted
+// neighbor lists of `u` and `v`.
+fn merge_triangles<NI, G>(graph: &G, u: NI) -> u64
+where
+    NI: Idx,
+    G: UndirectedNeighbors<NI>,
+{
+    let mut triangles = 0;
+
+    for &v in graph.neighbors(u) {
+        if v > u {
+            break;
+        }
+
+        let mut it = put_back_iterator(graph.neighbors(u));
+
+        for &w in graph.neighbors(v) {
+            if w > v {
+                break;
+            }
+            while let Some(x) = it.next() {
+                if x >= &w {
+                    if x == &w {
+                        triangles += 1;
+                    }
+                    it.put_back(x);
+                    break;
+                }
+            }
+        }
+    }
+
+    triangles
+}
+
+// Counts the same triangles as `merge_triangles`, but looks up the neighbors
+// of `v` in a hash set of the neighbors of `u`.
+fn hash_triangles<NI, G>(graph: &G, u: NI) -> u64
+where
+    NI: Idx,
+    G: UndirectedNeighbors<NI>,
+{
+    let lower = graph
+        .neighbors(u)
+        .take_while(|&&v| v <= u)
+        .map(|v| v.index())
+        .collect::<AHashSet<_>>();
+
+    graph
+        .neighbors(u)
+        .take_while(|&&v| v <= u)
+        .map(|&v| {
+            graph
+                .neighbors(v)
+                .take_while(|&&w| w <= v)
+                .filter(|w| lower.contains(&w.index()))
+                .count() as u64
+        })
+        .sum()
+}
+
+// Merging scans the `d` lower neighbors of `u` once per lower neighbor `v`
+// in addition to the neighbors of `v`, whereas hashing inserts the
+// neighbors of `u` once and looks up the neighbors of each `v`.
+fn prefers_hash<NI, G>(graph: &G, u: NI) -> bool
+where
+    NI: Idx,
+    G: UndirectedDegrees<NI> + UndirectedNeighbors<NI>,
+{
+    let lower = graph.neighbors(u).take_while(|&&v| v <= u);
+    let (d, neighbor_degrees) = lower.fold((0, 0), |(d, degrees), &v| {
+        (d + 1, degrees + graph.degree(v).index())
+    });
+
+    d >= HASH_MIN_DEGREE && d * d + neighbor_degrees > HASH_COST_FACTOR * (d + neighbor_degrees)
+}
+
+/// The edges of an undirected graph, oriented from lower to higher degree.
+///
+/// Nodes are renamed by their rank in descending order of degree, and each
+/// node only keeps its neighbors of a lower rank in ascending order, i.e.,
+/// its neighbors with a higher degree. This is the layout that counting on a
+/// graph relabeled by [`relabel_graph`] relies on.
+struct DegreeOriented<NI> {
+    offsets: Vec<usize>,
+    targets: Vec<NI>,
+}
+
+impl<NI: Idx> DegreeOriented<NI> {
+    fn new<G>(graph: &G) -> Self
+    where
+        G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+    {
+        let node_count = graph.node_count().index();
+
+        let mut order = (0..node_count).map(NI::new).collect::<Vec<_>>();
+        order.par_sort_unstable_by_key(|&u| (Reverse(graph.degree(u)), u));
+
+        let mut rank = vec![NI::zero(); node_count];
+        for (r, u) in order.iter().enumerate() {
+            rank[u.index()] = NI::new(r);
+        }
+
+        let neighbors = order
+            .par_iter()
+            .enumerate()
+            .map(|(r, &u)| {
+                let r = NI::new(r);
+                let mut neighbors = graph
+                    .neighbors(u)
+                    .map(|v| rank[v.index()])
+                    .filter(|&v| v < r)
+                    .collect::<Vec<_>>();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect::<Vec<_>>();
+
+        let mut offsets = Vec::with_capacity(node_count + 1);
+        offsets.push(0);
+        for neighbors in &neighbors {
+            offsets.push(offsets[offsets.len() - 1] + neighbors.len());
+        }
+        let targets = neighbors.into_iter().flatten().collect();
+
+        Self { offsets, targets }
+    }
+}
+
+impl<NI: Idx> Graph<NI> for DegreeOriented<NI> {
+    fn node_count(&self) -> NI {
+        NI::new(self.offsets.len() - 1)
+    }
+
+    fn edge_count(&self) -> NI {
+        NI::new(self.targets.len())
+    }
+}
+
+impl<NI: Idx> UndirectedDegrees<NI> for DegreeOriented<NI> {
+    fn degree(&self, node: NI) -> NI {
+        NI::new(self.offsets[node.index() + 1] - self.offsets[node.index()])
+    }
+}
+
+impl<NI: Idx> UndirectedNeighbors<NI> for DegreeOriented<NI> {
+    type NeighborsIterator<'a> = std::slice::Iter<'a, NI>;
+
+    fn neighbors(&self, node: NI) -> Self::NeighborsIterator<'_> {
+        self.targets[self.offsets[node.index()]..self.offsets[node.index() + 1]].iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +461,71 @@ mod tests {
 
         assert_eq!(global_triangle_count(&graph), 2);
     }
+
+    #[test]
+    fn test_tc_hybrid() {
+        let edges = (1..200_usize)
+            .map(|node| (0, node))
+            .chain((1..199).map(|node| (node, node + 1)))
+            .collect::<Vec<_>>();
+
+        let mut graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(edges)
+            .build();
+        relabel_graph(&mut graph);
+
+        for threshold in [1, 10, usize::MAX] {
+            let view = HybridView::new(&graph, threshold);
+            assert_eq!(global_triangle_count_hybrid(&view), 198);
+        }
+        assert_eq!(global_triangle_count(&graph), 198);
+    }
+
+    #[test]
+    fn test_tc_strategies() {
+        // a wheel around node 0 with additional chords
+        let edges = (1..200_usize)
+            .map(|node| (0, node))
+            .chain((1..199).map(|node| (node, node + 1)))
+            .chain((1..190).step_by(3).map(|node| (node, node + 2)))
+            .collect::<Vec<_>>();
+
+        let mut graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(edges)
+            .build();
+
+        let oriented = [
+            IntersectionStrategy::Merge,
+            IntersectionStrategy::Hash,
+            IntersectionStrategy::Adaptive,
+        ]
+        .map(|strategy| {
+            global_triangle_count_with_config(&graph, TriangleCountConfig::new(strategy, true))
+        });
+
+        relabel_graph(&mut graph);
+        let expected = global_triangle_count(&graph);
+
+        assert_eq!(expected, 198 + 2 * 63);
+        assert_eq!(oriented, [expected; 3]);
+        assert_eq!(global_triangle_count_hash(&graph), expected);
+        assert_eq!(
+            global_triangle_count_with_config(&graph, TriangleCountConfig::default()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_tc_orientation_handles_duplicates_and_loops() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Unsorted)
+            .edges(vec![(0, 1), (1, 0), (1, 2), (2, 0), (2, 2), (2, 3)])
+            .build();
+
+        let config = TriangleCountConfig::new(IntersectionStrategy::Merge, true);
+
+        assert_eq!(global_triangle_count_with_config(&graph, config), 1);
+    }
 }
@@ -12,6 +12,17 @@
 //! The idea is motivated by power law graphs, which usually have
 //! a very large component.
 //!
+//! Afforest runs in three phases:
+//!
+//! 1. In each of `neighbor_rounds` rounds, every node is linked to its next
+//!    neighbor, followed by compressing the union find, so that the links
+//!    of the next round find the set ids in few steps.
+//! 2. The largest intermediate component is estimated by looking up the
+//!    components of `sampling_size` random nodes.
+//! 3. The remaining edges are linked, skipping all nodes in the largest
+//!    component. Since most nodes of a power law graph end up in that
+//!    component after a few rounds, most edges are never visited.
+//!
 //! The module contains three functions to compute wcc:
 //!
 //! - `wcc_baseline` computes components by linking all connected
@@ -20,6 +31,16 @@
 //! - `wcc_afforest_dss` implements the algorithm presented in [1]
 //!   but uses a disjoint set struct [2] to represent components
 //!
+//! `wcc_afforest_from_components` runs `wcc_afforest` starting from a
+//! previous component assignment, such that most nodes already share the
+//! largest component and can be skipped while linking the remaining edges.
+//!
+//! Afforest runs on undirected graphs. The functions above accept directed
+//! graphs and traverse them through an [`UndirectedView`], which follows
+//! outgoing and incoming edges without building an undirected copy of the
+//! graph. `wcc_undirected` accepts any undirected graph, e.g., an
+//! [`UndirectedCsrGraph`] or such a view.
+//!
 //! [1] Michael Sutton, Tal Ben-Nun, Amnon Barak:
 //! "Optimizing Parallel Graph Connectivity Computation via Subgraph Sampling",
 //! Symposium on Parallel and Distributed Processing, IPDPS 2018
@@ -28,8 +49,10 @@
 //! In Proc. 23rd ACM Symposium on Theory of Computing, 1994
 
 use ahash::AHashMap;
+use graph_builder::time::Instant;
 use log::info;
-use std::{hash::Hash, time::Instant};
+use std::hash::Hash;
+use std::ops::Range;
 
 use crate::prelude::*;
 use rayon::prelude::*;
@@ -41,7 +64,9 @@ pub use crate::dss::DisjointSetStruct;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "clap", derive(clap::Args))]
 pub struct WccConfig {
-    /// Number of nodes to be processed in batch by a single thread.
+    /// Average number of nodes to be processed in batch by a single thread.
+    /// Batches are balanced by degree, so batches of high-degree nodes
+    /// contain fewer nodes.
     #[cfg_attr(feature = "clap", clap(long, default_value_t = WccConfig::DEFAULT_CHUNK_SIZE))]
     pub chunk_size: usize,
 
@@ -105,18 +130,19 @@ where
     NI: Idx,
     G: Graph<NI> + DirectedNeighbors<NI> + Sync,
 {
-    let node_count = graph.node_count().index();
-    let dss = DisjointSetStruct::new(node_count);
-
-    (0..node_count)
-        .into_par_iter()
-        .chunks(config.chunk_size)
-        .for_each(|chunk| {
-            for u in chunk {
-                let u = NI::new(u);
-                graph.out_neighbors(u).for_each(|v| dss.union(u, *v));
-            }
-        });
+    let dss = DisjointSetStruct::new(graph.node_count().index());
+
+    // The size hint is the exact out-degree for CSR graphs.
+    let ranges = node_ranges(graph.node_count(), config, |u| {
+        graph.out_neighbors(u).size_hint().0
+    });
+
+    ranges.into_par_iter().for_each(|range| {
+        for u in range.start.range(range.end) {
+            graph.out_neighbors(u).for_each(|v| dss.union(u, *v));
+        }
+    });
+    dss.compress();
 
     dss
 }
@@ -133,11 +159,77 @@ where
     let comp = Afforest::new(graph.node_count().index());
     info!("Afforest creation took {:?}", start.elapsed());
 
+    wcc(&UndirectedView::new(graph), &comp, config);
+
+    comp
+}
+
+/// Computes Wcc using the Afforest algorithm on an undirected graph.
+///
+/// ```
+/// use graph::prelude::*;
+///
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (2, 1), (3, 4)])
+///     .build();
+///
+/// let components = wcc_undirected(&graph, WccConfig::default()).to_vec();
+///
+/// assert_eq!(components[0], components[2]);
+/// assert_ne!(components[0], components[3]);
+/// ```
+pub fn wcc_undirected<NI, G>(graph: &G, config: WccConfig) -> impl Components<NI>
+where
+    NI: Idx + Hash,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+{
+    let start = Instant::now();
+    let comp = Afforest::new(graph.node_count().index());
+    info!("Afforest creation took {:?}", start.elapsed());
+
     wcc(graph, &comp, config);
 
     comp
 }
 
+/// Computes Wcc using the Afforest algorithm, starting from the given
+/// component assignment instead of singleton components, e.g., the result of
+/// a previous run on a graph that has since gained edges. Node `i` starts in
+/// component `initial_components[i]`.
+///
+/// Nodes that share an initial component must be connected in `graph`, since
+/// components are only ever joined, not split. Component ids must be less than
+/// the number of nodes, which holds for the ids returned by the functions in
+/// this module.
+///
+/// # Panics
+///
+/// Panics if the number of initial components differs from the number of
+/// nodes or if a component id is out of bounds.
+pub fn wcc_afforest_from_components<NI, G>(
+    graph: &G,
+    config: WccConfig,
+    initial_components: &[NI],
+) -> impl Components<NI>
+where
+    NI: Idx + Hash,
+    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+{
+    assert_eq!(
+        initial_components.len(),
+        graph.node_count().index(),
+        "expected one initial component per node"
+    );
+
+    let start = Instant::now();
+    let comp = Afforest::from_components(initial_components);
+    info!("Afforest creation took {:?}", start.elapsed());
+
+    wcc(&UndirectedView::new(graph), &comp, config);
+
+    comp
+}
+
 /// Computes Wcc using the Afforest algorithm as described in the original
 /// paper (see module description). The backing union find structure can
 /// achieve better cache locality compared to the disjoint set struct variant.
@@ -150,7 +242,7 @@ where
     let dss = DisjointSetStruct::new(graph.node_count().index());
     info!("DSS creation took {:?}", start.elapsed());
 
-    wcc(graph, &dss, config);
+    wcc(&UndirectedView::new(graph), &dss, config);
 
     dss
 }
@@ -158,23 +250,23 @@ where
 fn wcc<NI, G, UF>(graph: &G, comp: &UF, config: WccConfig)
 where
     NI: Idx + Hash,
-    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
     UF: UnionFind<NI> + Send + Sync,
 {
     let start = Instant::now();
-    sample_subgraph(graph, comp, config);
-    info!("Link subgraph took {:?}", start.elapsed());
+    let ranges = node_ranges(graph.node_count(), config, |u| graph.degree(u).index());
+    info!("Partitioning nodes took {:?}", start.elapsed());
 
     let start = Instant::now();
-    comp.compress();
-    info!("Sample compress took {:?}", start.elapsed());
+    sample_subgraph(graph, comp, &ranges, config);
+    info!("Link subgraph took {:?}", start.elapsed());
 
     let start = Instant::now();
     let largest_component = find_largest_component(comp, config);
     info!("Get component took {:?}", start.elapsed());
 
     let start = Instant::now();
-    link_remaining(graph, comp, largest_component, config);
+    link_remaining(graph, comp, &ranges, largest_component, config);
     info!("Link remaining took {:?}", start.elapsed());
 
     let start = Instant::now();
@@ -182,62 +274,43 @@ where
     info!("Final compress took {:?}", start.elapsed());
 }
 
-// Sample a subgraph by looking at the first `NEIGHBOR_ROUNDS` many targets of each node.
-fn sample_subgraph<NI, G, UF>(graph: &G, uf: &UF, config: WccConfig)
+// Splits the nodes into ranges of `config.chunk_size` nodes on average that
+// are balanced by the given degree.
+fn node_ranges<NI, F>(node_count: NI, config: WccConfig, degree: F) -> Vec<Range<NI>>
 where
     NI: Idx,
-    G: Graph<NI> + DirectedNeighbors<NI> + Sync,
-    UF: UnionFind<NI> + Send + Sync,
+    F: Fn(NI) -> usize + Sync,
 {
-    (0..graph.node_count().index())
-        .into_par_iter()
-        .chunks(config.chunk_size)
-        .for_each(|chunk| {
-            for u in chunk {
-                let u = NI::new(u);
-
-                for v in graph.out_neighbors(u).take(config.neighbor_rounds) {
-                    uf.union(u, *v);
-                }
-            }
-        });
+    let range_count = (node_count.index() + config.chunk_size - 1) / config.chunk_size;
+    edge_balanced_partition(node_count, range_count, degree)
 }
 
-// Sample a subgraph by looking at the first `NEIGHBOR_ROUNDS` many targets of each node.
-// In contrast to `sample_subgraph`, the method calls `compress` for each neighbor round.
-#[allow(dead_code)]
-fn sample_subgraph_afforest<NI, G>(graph: &G, af: &Afforest<NI>, config: WccConfig)
+// Sample a subgraph by linking each node to its `r`-th neighbor in round
+// `r` of `config.neighbor_rounds`. The union find is compressed after each
+// round, which keeps its trees flat for the links of the next round.
+fn sample_subgraph<NI, G, UF>(graph: &G, uf: &UF, ranges: &[Range<NI>], config: WccConfig)
 where
     NI: Idx,
-    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+    G: Graph<NI> + UndirectedNeighbors<NI> + Sync,
+    UF: UnionFind<NI> + Send + Sync,
 {
     let neighbor_rounds = config.neighbor_rounds;
     for r in 0..neighbor_rounds {
-        info!("Neighbor round {} of {neighbor_rounds}", r + 1);
-
         let start = Instant::now();
-        (0..graph.node_count().index())
-            .into_par_iter()
-            .chunks(config.chunk_size)
-            .for_each(|chunk| {
-                for u in chunk {
-                    let u = NI::new(u);
-                    if r < graph.out_degree(u).index() {
-                        for v in graph.out_neighbors(u).skip(r).take(1) {
-                            af.union(u, *v);
-                        }
-                    }
+        ranges.par_iter().for_each(|range| {
+            for u in range.start.range(range.end) {
+                if let Some(v) = graph.neighbors(u).nth(r) {
+                    uf.union(u, *v);
                 }
-            });
+            }
+        });
+        uf.compress();
 
         info!(
-            "Neighbor round {r} of {neighbor_rounds} took {:?}",
+            "Neighbor round {} of {neighbor_rounds} took {:?}",
+            r + 1,
             start.elapsed()
         );
-
-        let start = Instant::now();
-        af.compress();
-        info!("Compress took {:?}", start.elapsed());
     }
 }
 
@@ -271,33 +344,30 @@ where
 }
 
 // Process the remaining edges while skipping nodes that are in the largest component.
-fn link_remaining<NI, G, UF>(graph: &G, uf: &UF, skip_component: NI, config: WccConfig)
-where
+fn link_remaining<NI, G, UF>(
+    graph: &G,
+    uf: &UF,
+    ranges: &[Range<NI>],
+    skip_component: NI,
+    config: WccConfig,
+) where
     NI: Idx,
-    G: Graph<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
+    G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
     UF: UnionFind<NI> + Send + Sync,
 {
-    (0..graph.node_count().index())
-        .into_par_iter()
-        .chunks(config.chunk_size)
-        .for_each(|chunk| {
-            for u in chunk {
-                let u = NI::new(u);
-                if uf.find(u) == skip_component {
-                    continue;
-                }
-
-                if graph.out_degree(u).index() > config.neighbor_rounds {
-                    for v in graph.out_neighbors(u).skip(config.neighbor_rounds) {
-                        uf.union(u, *v);
-                    }
-                }
+    ranges.par_iter().for_each(|range| {
+        for u in range.start.range(range.end) {
+            if uf.find(u) == skip_component {
+                continue;
+            }
 
-                for v in graph.in_neighbors(u) {
+            if graph.degree(u).index() > config.neighbor_rounds {
+                for v in graph.neighbors(u).skip(config.neighbor_rounds) {
                     uf.union(u, *v);
                 }
             }
-        });
+        }
+    });
 }
 
 #[cfg(test)]
@@ -327,4 +397,69 @@ mod tests {
         assert_eq!(res.component(2), res.component(3));
         assert_ne!(res.component(1), res.component(2));
     }
+
+    #[test]
+    fn two_components_undirected() {
+        let graph: UndirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (2, 1), (3, 4), (5, 4)])
+            .build();
+
+        let res = wcc_undirected(&graph, WccConfig::new(16384, 1, 1024));
+
+        assert_eq!(res.component(0), res.component(2));
+        assert_eq!(res.component(3), res.component(5));
+        assert_ne!(res.component(0), res.component(3));
+    }
+
+    #[test]
+    fn nodes_without_out_edges_afforest() {
+        // Node 0 only has incoming edges, which are skipped while sampling
+        // outgoing edges.
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(1, 0), (2, 0), (3, 0), (4, 5)])
+            .build();
+
+        let res = wcc_afforest(&graph, WccConfig::new(16384, 1, 1024)).to_vec();
+
+        assert_eq!(res[0..4], [res[0]; 4]);
+        assert_eq!(res[4], res[5]);
+        assert_ne!(res[0], res[4]);
+    }
+
+    #[test]
+    fn afforest_from_components() {
+        let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+            .edges(vec![(0, 1), (2, 3), (4, 5), (1, 2)])
+            .build();
+
+        // The result of a previous run, before the edge (1, 2) was added.
+        let initial = [0, 0, 2, 2, 5, 5];
+        let res = wcc_afforest_from_components(&graph, WccConfig::default(), &initial);
+
+        assert_eq!(res.to_vec(), vec![0, 0, 0, 0, 4, 4]);
+    }
+
+    #[test]
+    fn afforest_matches_baseline() {
+        // the cycles of the permutation `u -> 7u + 3`, a path and isolated nodes
+        let edges = (0..200)
+            .map(|u| (u, (u * 7 + 3) % 200))
+            .chain((200..220).map(|u| (u, u + 1)))
+            .collect::<Vec<_>>();
+        let graph: DirectedCsrGraph<usize> =
+            GraphBuilder::new().edges(edges).node_count(230).build();
+
+        let expected = wcc_baseline(&graph, WccConfig::default()).to_vec();
+
+        for neighbor_rounds in [0, 1, 2, 5] {
+            let config = WccConfig::new(16, neighbor_rounds, 64);
+            let actual = wcc_afforest(&graph, config).to_vec();
+
+            for u in 0..graph.node_count() {
+                for v in 0..graph.node_count() {
+                    assert_eq!(expected[u] == expected[v], actual[u] == actual[v]);
+                }
+            }
+        }
+    }
 }
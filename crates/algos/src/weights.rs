@@ -0,0 +1,154 @@
+//! Transformations of edge weights, e.g., to turn similarity graphs into
+//! distance graphs before running shortest path algorithms.
+//!
+//! Both functions create a new graph and keep the input graph unchanged.
+//! Arbitrary transformations are available via
+//! [`MapEdgeValuesOp::map_edge_values`], arbitrary filters via
+//! [`FilterEdgesOp::filter_edges`].
+//!
+//! ```
+//! use graph::prelude::*;
+//!
+//! let similarities: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+//!     .edges_with_values(vec![(0, 1, 0.5), (1, 2, 0.25), (0, 2, 0.01)])
+//!     .build();
+//!
+//! let relevant = threshold_weights(&similarities, Some(0.1), None);
+//! let distances = transform_weights(&relevant, WeightTransform::Inverse);
+//!
+//! assert_eq!(distances.edge_count(), 2);
+//! assert_eq!(
+//!     distances.out_neighbors_with_values(1).as_slice(),
+//!     &[Target::new(2, 4.0)]
+//! );
+//! ```
+
+use graph_builder::time::Instant;
+
+use log::info;
+
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum WeightTransform {
+    /// Replaces each weight `w` with `1 / w`. Weights of `0` become infinite.
+    Inverse,
+    /// Replaces each weight `w` with `ln(w)`.
+    Log,
+    /// Replaces each weight `w` with `-ln(w)`, which turns probabilities
+    /// into non-negative distances whose sums correspond to products of the
+    /// probabilities.
+    NegativeLog,
+    /// Replaces each weight `w` with `1 - w`, which turns similarities in
+    /// `[0, 1]`, e.g., cosine similarities, into distances.
+    Complement,
+}
+
+impl WeightTransform {
+    /// Applies the transformation to a single weight.
+    pub fn apply(self, weight: f32) -> f32 {
+        match self {
+            WeightTransform::Inverse => weight.recip(),
+            WeightTransform::Log => weight.ln(),
+            WeightTransform::NegativeLog => -weight.ln(),
+            WeightTransform::Complement => 1.0 - weight,
+        }
+    }
+}
+
+/// Creates a graph with the same edges, where each weight is transformed.
+pub fn transform_weights<NI, G>(graph: &G, transform: WeightTransform) -> G::Mapped
+where
+    NI: Idx,
+    G: MapEdgeValuesOp<NI, f32, f32>,
+{
+    let start = Instant::now();
+    let transformed = graph.map_edge_values(|_, _, weight| transform.apply(weight));
+    info!(
+        "Applied {:?} transform to edge weights in {:?}",
+        transform,
+        start.elapsed()
+    );
+    transformed
+}
+
+/// Creates a graph with the same nodes that only contains the edges with a
+/// weight of at least `min_weight` and at most `max_weight`. Missing bounds
+/// are unbounded. Edges with a `NaN` weight are removed.
+pub fn threshold_weights<NI, G>(
+    graph: &G,
+    min_weight: Option<f32>,
+    max_weight: Option<f32>,
+) -> G::Filtered
+where
+    NI: Idx,
+    G: FilterEdgesOp<NI, f32>,
+{
+    let start = Instant::now();
+    let min_weight = min_weight.unwrap_or(f32::NEG_INFINITY);
+    let max_weight = max_weight.unwrap_or(f32::INFINITY);
+    let filtered = graph.filter_edges(|_, _, weight| min_weight <= weight && weight <= max_weight);
+    info!(
+        "Filtered edges with weights in [{min_weight}, {max_weight}] in {:?}",
+        start.elapsed()
+    );
+    filtered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{CsrLayout, GraphBuilder};
+
+    #[test]
+    fn test_transforms() {
+        assert_eq!(WeightTransform::Inverse.apply(4.0), 0.25);
+        assert_eq!(WeightTransform::Inverse.apply(0.0), f32::INFINITY);
+        assert_eq!(WeightTransform::Log.apply(1.0), 0.0);
+        assert_eq!(WeightTransform::NegativeLog.apply(1.0), 0.0);
+        assert!((WeightTransform::NegativeLog.apply(0.5) - 2_f32.ln()).abs() < 1E-6);
+        assert_eq!(WeightTransform::Complement.apply(0.25), 0.75);
+    }
+
+    #[test]
+    fn test_transform_undirected() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.5), (1, 2, 0.25)])
+            .build();
+
+        let transformed = transform_weights(&graph, WeightTransform::Complement);
+
+        assert_eq!(
+            transformed.neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 0.5), Target::new(2, 0.75)]
+        );
+    }
+
+    #[test]
+    fn test_threshold() {
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![
+                (0, 1, 0.5),
+                (1, 2, 2.0),
+                (2, 0, f32::NAN),
+                (2, 3, 5.0),
+            ])
+            .build();
+
+        assert_eq!(threshold_weights(&graph, None, None).edge_count(), 3);
+        assert_eq!(threshold_weights(&graph, Some(1.0), None).edge_count(), 2);
+        assert_eq!(threshold_weights(&graph, None, Some(2.0)).edge_count(), 2);
+
+        let filtered = threshold_weights(&graph, Some(1.0), Some(3.0));
+        assert_eq!(filtered.node_count(), 4);
+        assert_eq!(filtered.edge_count(), 1);
+        assert_eq!(filtered.out_degree(1), 1);
+    }
+}
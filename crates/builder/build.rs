@@ -19,18 +19,6 @@ pub fn test() {
 }
 "#;
 
-// Checks if the new_uninit feature can be enabled
-const NEW_UNINIT_PROBE: &str = r#"
-#![feature(new_uninit)]
-#![allow(dead_code)]
-
-use std::mem::MaybeUninit;
-
-pub fn test() -> Box<[MaybeUninit<usize>]> {
-    Box::<[usize]>::new_uninit_slice(42)
-}
-"#;
-
 // Checks if the slice_partition_dedup feature can be enabled
 const SLICE_PARTITION_DEDUP_PROBE: &str = r#"
 #![feature(slice_partition_dedup)]
@@ -59,7 +47,6 @@ fn main() {
 
     if !force_fallback_impl {
         test_for_feature("maybe_uninit_write_slice", MAYBE_UNINIT_WRITE_SLICE_PROBE);
-        test_for_feature("new_uninit", NEW_UNINIT_PROBE);
         test_for_feature("slice_partition_dedup", SLICE_PARTITION_DEDUP_PROBE);
         test_for_feature("doc_cfg", DOC_CFG_PROBE);
     }
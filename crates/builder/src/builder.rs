@@ -1,13 +1,29 @@
 use std::{convert::TryFrom, marker::PhantomData};
 
 use crate::{
-    graph::csr::{CsrLayout, NodeValues},
+    graph::csr::{CsrLayout, CsrStrategy, NodeValues},
     index::Idx,
-    input::{edgelist::EdgeList, InputCapabilities, InputPath},
+    input::{
+        csv_file::CsvInput,
+        edgelist::{EdgeList, EdgeListInput, EdgeReport, Edges, ParallelEdges, SelfLoops},
+        node_values::{join_node_values, NodeValuesInput},
+        InputCapabilities, InputPath, ParseValue,
+    },
     prelude::edgelist::{EdgeIterator, EdgeWithValueIterator},
-    Error,
+    DirectedALGraph, DirectedCsrGraph, Error, UndirectedALGraph, UndirectedCsrGraph,
 };
-use std::path::Path as StdPath;
+use std::path::{Path as StdPath, PathBuf};
+
+#[cfg(feature = "tokio")]
+use crate::input::async_read::read_file_async;
+#[cfg(feature = "jsonl")]
+use crate::input::json_lines::{JsonLinesInput, JsonValue};
+#[cfg(feature = "parquet")]
+use crate::input::parquet_file::ParquetInput;
+#[cfg(feature = "arrow")]
+use crate::input::record_batch::{ArrowEdgeList, ArrowIdx, ArrowInput, ArrowValue};
+#[cfg(feature = "arrow")]
+use arrow_array::RecordBatch;
 
 pub struct Uninitialized {
     csr_layout: CsrLayout,
@@ -20,6 +36,7 @@ where
 {
     csr_layout: CsrLayout,
     edges: Edges,
+    node_count: Option<usize>,
     _node: PhantomData<NI>,
 }
 
@@ -30,6 +47,7 @@ where
     csr_layout: CsrLayout,
     node_values: NodeValues<NV>,
     edge_list: EdgeList<NI, EV>,
+    node_count: Option<usize>,
 }
 
 pub struct FromEdgesWithValues<NI, Edges, EV>
@@ -39,9 +57,80 @@ where
 {
     csr_layout: CsrLayout,
     edges: Edges,
+    node_count: Option<usize>,
     _node: PhantomData<NI>,
 }
 
+/// Wraps one of the in-memory edge states and applies [`SelfLoops`] and
+/// [`ParallelEdges`] policies to its edges before building the graph.
+///
+/// By default, all edges are kept, and the only way to remove self-loops and
+/// parallel edges is [`CsrLayout::Deduplicated`], which always removes both
+/// and keeps an arbitrary one of the parallel edge values. The policies make
+/// this explicit and report what was removed. Since `build` returns the graph
+/// together with an [`EdgeReport`], the report cannot be ignored by accident.
+///
+/// Policies are applied to the edge list, before the CSR layout is applied.
+/// Nodes whose edges are all removed remain in the graph.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let (graph, report): (UndirectedCsrGraph<usize, (), u32>, _) = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .edges_with_values(vec![(0, 0, 1), (0, 1, 2), (1, 0, 3), (1, 2, 4)])
+///     .self_loops(SelfLoops::Count)
+///     .parallel_edges(ParallelEdges::Aggregate(u32::max))
+///     .build();
+///
+/// // An undirected self-loop appears twice in the neighbors of its node.
+/// assert_eq!(
+///     graph.neighbors_with_values(0).as_slice(),
+///     &[Target::new(0, 1), Target::new(0, 1), Target::new(1, 3)]
+/// );
+/// assert_eq!(report.self_loops, 1);
+/// assert_eq!(report.dropped_self_loops, 0);
+/// assert_eq!(report.dropped_parallel_edges, 1);
+/// ```
+pub struct WithEdgePolicies<State, EV> {
+    inner: State,
+    self_loops: SelfLoops,
+    parallel_edges: ParallelEdges<EV>,
+}
+
+/// Wraps one of the file states and joins the node values of a separate
+/// node values file to the graph while building it.
+///
+/// The node values file contains a node id and a value per record, e.g.,
+/// [`CsvNodeInput`](crate::input::CsvNodeInput) or
+/// `ParquetNodeInput`. Values are joined by node id. Nodes without a record
+/// get the default value and nodes that only occur in the node values file
+/// are isolated nodes of the graph. If a node count is set, every node id of
+/// the node values file must be smaller than it.
+pub struct WithNodeValuesFile<State, NV, Input> {
+    inner: State,
+    input: Input,
+    path: PathBuf,
+    _values: PhantomData<NV>,
+}
+
+/// Wraps one of the edge states and builds the CSR graph using the given
+/// [`CsrStrategy`] instead of the default one.
+///
+/// For one-shot analytic jobs, building the graph is often the dominant
+/// cost. [`CsrStrategy::SortBased`] with [`SortAlgorithm::Radix`] is usually
+/// the fastest option for [`CsrLayout::Sorted`] and
+/// [`CsrLayout::Deduplicated`] on large graphs, at the cost of additional
+/// memory for two copies of the edges.
+///
+/// [`SortAlgorithm::Radix`]: crate::graph::csr::SortAlgorithm::Radix
+pub struct WithCsrStrategy<State> {
+    inner: State,
+    csr_strategy: CsrStrategy,
+}
+
 #[cfg(feature = "gdl")]
 #[cfg_attr(all(feature = "gdl", has_doc_cfg), doc(cfg(feature = "gdl")))]
 pub struct FromGdlString<NI>
@@ -64,6 +153,110 @@ where
     _node: PhantomData<NI>,
 }
 
+pub struct FromCsvInput<NI, EV>
+where
+    NI: Idx,
+{
+    csr_layout: CsrLayout,
+    input: CsvInput<NI, EV>,
+}
+
+pub struct FromCsvPath<NI, EV, P>
+where
+    NI: Idx,
+    P: AsRef<StdPath>,
+{
+    csr_layout: CsrLayout,
+    input: CsvInput<NI, EV>,
+    path: P,
+    node_path: Option<PathBuf>,
+    node_count: Option<usize>,
+}
+
+#[cfg(feature = "jsonl")]
+#[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+pub struct FromJsonLinesInput<NI, NV, EV>
+where
+    NI: Idx,
+{
+    csr_layout: CsrLayout,
+    input: JsonLinesInput<NI, NV, EV>,
+}
+
+#[cfg(feature = "jsonl")]
+#[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+pub struct FromJsonLinesPath<NI, NV, EV, P>
+where
+    NI: Idx,
+    P: AsRef<StdPath>,
+{
+    csr_layout: CsrLayout,
+    input: JsonLinesInput<NI, NV, EV>,
+    path: P,
+}
+
+#[cfg(feature = "jsonl")]
+#[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+pub struct FromJsonLinesPaths<NI, NV, EV, P, NP>
+where
+    NI: Idx,
+    P: AsRef<StdPath>,
+    NP: AsRef<StdPath>,
+{
+    csr_layout: CsrLayout,
+    input: JsonLinesInput<NI, NV, EV>,
+    path: P,
+    node_path: NP,
+}
+
+#[cfg(feature = "arrow")]
+#[cfg_attr(all(feature = "arrow", has_doc_cfg), doc(cfg(feature = "arrow")))]
+pub struct FromArrowInput<NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    csr_layout: CsrLayout,
+    input: ArrowInput<NI, EV>,
+}
+
+#[cfg(feature = "arrow")]
+#[cfg_attr(all(feature = "arrow", has_doc_cfg), doc(cfg(feature = "arrow")))]
+pub struct FromRecordBatches<NI, EV, Batches>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+    Batches: IntoIterator<Item = RecordBatch>,
+{
+    csr_layout: CsrLayout,
+    input: ArrowInput<NI, EV>,
+    batches: Batches,
+}
+
+#[cfg(feature = "parquet")]
+#[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+pub struct FromParquetInput<NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    csr_layout: CsrLayout,
+    input: ParquetInput<NI, EV>,
+}
+
+#[cfg(feature = "parquet")]
+#[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+pub struct FromParquetPath<NI, EV, P>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+    P: AsRef<StdPath>,
+{
+    csr_layout: CsrLayout,
+    input: ParquetInput<NI, EV>,
+    path: P,
+}
+
 pub struct FromInput<NI, P, Format>
 where
     P: AsRef<StdPath>,
@@ -77,6 +270,18 @@ where
     _format: PhantomData<Format>,
 }
 
+pub struct FromEdgeListPath<NI, EV, P>
+where
+    NI: Idx,
+    P: AsRef<StdPath>,
+{
+    csr_layout: CsrLayout,
+    path: P,
+    node_path: Option<PathBuf>,
+    node_count: Option<usize>,
+    _idx: PhantomData<(NI, EV)>,
+}
+
 pub struct FromPath<NI, P, Format>
 where
     P: AsRef<StdPath>,
@@ -198,6 +403,7 @@ impl GraphBuilder<Uninitialized> {
             state: FromEdges {
                 csr_layout: self.state.csr_layout,
                 edges,
+                node_count: None,
                 _node: PhantomData,
             },
         }
@@ -229,6 +435,7 @@ impl GraphBuilder<Uninitialized> {
             state: FromEdgesWithValues {
                 csr_layout: self.state.csr_layout,
                 edges,
+                node_count: None,
                 _node: PhantomData,
             },
         }
@@ -256,9 +463,11 @@ impl GraphBuilder<Uninitialized> {
     /// assert_eq!(g.edge_count(), 2);
     /// ```
     ///
-    /// One can also create weighted graphs using GDL. There needs to be exactly
-    /// one edge property with the same type as specified for the edge value.
-    /// The property key is not relevant.
+    /// One can also create weighted graphs using GDL. The edge value is read
+    /// from the `weight` property of a relationship. If there is no such
+    /// property, the property with the smallest key is used. Float edge values
+    /// can also be written as integers, e.g., `{weight: 2}`. Building fails if
+    /// a value cannot be converted into the value type.
     ///
     /// ```
     /// use graph_builder::prelude::*;
@@ -335,6 +544,122 @@ impl GraphBuilder<Uninitialized> {
         }
     }
 
+    /// Creates a graph by reading edges from a delimited text file, e.g., CSV.
+    ///
+    /// The given input configures the delimiter, whether there is a header
+    /// row, comment lines and which columns contain the source and target
+    /// node ids and the edge values.
+    ///
+    /// See [`CsvInput`] for an example.
+    pub fn csv_input<NI, EV>(self, input: CsvInput<NI, EV>) -> GraphBuilder<FromCsvInput<NI, EV>>
+    where
+        NI: Idx,
+    {
+        GraphBuilder {
+            state: FromCsvInput {
+                csr_layout: self.state.csr_layout,
+                input,
+            },
+        }
+    }
+
+    /// Creates a graph by reading edges from a JSON Lines file.
+    ///
+    /// The given input configures which fields contain the source and target
+    /// node ids and the edge values. Optionally, node values can be read from
+    /// a separate node file.
+    ///
+    /// See [`JsonLinesInput`] for an example.
+    #[cfg(feature = "jsonl")]
+    #[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+    pub fn jsonl_input<NI, NV, EV>(
+        self,
+        input: JsonLinesInput<NI, NV, EV>,
+    ) -> GraphBuilder<FromJsonLinesInput<NI, NV, EV>>
+    where
+        NI: Idx,
+    {
+        GraphBuilder {
+            state: FromJsonLinesInput {
+                csr_layout: self.state.csr_layout,
+                input,
+            },
+        }
+    }
+
+    /// Creates a graph from Arrow record batches.
+    ///
+    /// Each row of a record batch represents an edge. The given input
+    /// configures which columns contain the source and target node ids and
+    /// the edge values. Columns that already have the Arrow type matching the
+    /// node id type are used without copying.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use arrow_array::{RecordBatch, UInt64Array};
+    /// use graph_builder::prelude::*;
+    ///
+    /// let batch = RecordBatch::try_from_iter(vec![
+    ///     ("source", Arc::new(UInt64Array::from(vec![0, 0, 1, 2])) as _),
+    ///     ("target", Arc::new(UInt64Array::from(vec![1, 2, 2, 3])) as _),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let graph: UndirectedCsrGraph<u64> = GraphBuilder::new()
+    ///     .arrow_input(ArrowInput::default())
+    ///     .record_batches(vec![batch])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(graph.node_count(), 4);
+    /// assert_eq!(graph.edge_count(), 4);
+    /// assert_eq!(graph.degree(2), 3);
+    /// ```
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(all(feature = "arrow", has_doc_cfg), doc(cfg(feature = "arrow")))]
+    pub fn arrow_input<NI, EV>(
+        self,
+        input: ArrowInput<NI, EV>,
+    ) -> GraphBuilder<FromArrowInput<NI, EV>>
+    where
+        NI: ArrowIdx,
+        EV: ArrowValue,
+    {
+        GraphBuilder {
+            state: FromArrowInput {
+                csr_layout: self.state.csr_layout,
+                input,
+            },
+        }
+    }
+
+    /// Creates a graph by reading edges from a Parquet file.
+    ///
+    /// The given input configures which columns contain the source and
+    /// target node ids and the edge values. Row groups are read in parallel.
+    ///
+    /// See [`ParquetInput`] for an example.
+    #[cfg(feature = "parquet")]
+    #[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+    pub fn parquet_input<NI, EV>(
+        self,
+        input: ParquetInput<NI, EV>,
+    ) -> GraphBuilder<FromParquetInput<NI, EV>>
+    where
+        NI: ArrowIdx,
+        EV: ArrowValue,
+    {
+        GraphBuilder {
+            state: FromParquetInput {
+                csr_layout: self.state.csr_layout,
+                input,
+            },
+        }
+    }
+
     /// Creates a graph by reading it from the given file format.
     ///
     /// # Examples
@@ -400,17 +725,99 @@ where
                 csr_layout: self.state.csr_layout,
                 node_values,
                 edge_list,
+                node_count: self.state.node_count,
             },
         }
     }
 
+    /// Sets how self-loops are handled, see [`WithEdgePolicies`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let (graph, report): (DirectedCsrGraph<usize>, _) = GraphBuilder::new()
+    ///     .edges(vec![(0, 0), (0, 1), (1, 1)])
+    ///     .self_loops(SelfLoops::Drop)
+    ///     .build();
+    ///
+    /// assert_eq!(graph.edge_count(), 1);
+    /// assert_eq!(report.dropped_self_loops, 2);
+    /// ```
+    pub fn self_loops(
+        self,
+        self_loops: SelfLoops,
+    ) -> GraphBuilder<WithEdgePolicies<FromEdges<NI, Edges>, ()>> {
+        GraphBuilder::with_edge_policies(self.state).self_loops(self_loops)
+    }
+
+    /// Sets how parallel edges are handled, see [`WithEdgePolicies`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let (graph, report): (UndirectedCsrGraph<usize>, _) = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (1, 0), (0, 1), (1, 2)])
+    ///     .parallel_edges(ParallelEdges::Deduplicate)
+    ///     .build();
+    ///
+    /// assert_eq!(graph.edge_count(), 2);
+    /// assert_eq!(report.dropped_parallel_edges, 2);
+    /// ```
+    pub fn parallel_edges(
+        self,
+        parallel_edges: ParallelEdges,
+    ) -> GraphBuilder<WithEdgePolicies<FromEdges<NI, Edges>, ()>> {
+        GraphBuilder::with_edge_policies(self.state).parallel_edges(parallel_edges)
+    }
+
+    /// Sets how the CSR is built, see [`WithCsrStrategy`].
+    pub fn csr_strategy(
+        self,
+        csr_strategy: CsrStrategy,
+    ) -> GraphBuilder<WithCsrStrategy<FromEdges<NI, Edges>>> {
+        GraphBuilder::with_csr_strategy(self.state, csr_strategy)
+    }
+
+    /// Sets the number of nodes, which must be larger than every node id of
+    /// the edges. Nodes without edges are isolated nodes of the graph.
+    ///
+    /// # Panics
+    ///
+    /// Building the graph panics if an edge contains a node id that is not
+    /// smaller than `node_count`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (1, 2)])
+    ///     .node_count(5)
+    ///     .build();
+    ///
+    /// assert_eq!(graph.node_count(), 5);
+    /// assert_eq!(graph.out_degree(4), 0);
+    /// ```
+    #[must_use]
+    pub fn node_count(mut self, node_count: usize) -> Self {
+        self.state.node_count = Some(node_count);
+        self
+    }
+
     /// Build the graph from the given vec of edges.
     pub fn build<Graph>(self) -> Graph
     where
         Graph: From<(EdgeList<NI, ()>, CsrLayout)>,
     {
+        let edge_list = EdgeList::from(EdgeIterator(self.state.edges));
+
         Graph::from((
-            EdgeList::from(EdgeIterator(self.state.edges)),
+            with_node_count(edge_list, self.state.node_count),
             self.state.csr_layout,
         ))
     }
@@ -437,95 +844,965 @@ where
                 csr_layout: self.state.csr_layout,
                 node_values,
                 edge_list,
+                node_count: self.state.node_count,
             },
         }
     }
 
+    /// Sets how self-loops are handled, see [`WithEdgePolicies`].
+    pub fn self_loops(
+        self,
+        self_loops: SelfLoops,
+    ) -> GraphBuilder<WithEdgePolicies<FromEdgesWithValues<NI, Edges, EV>, EV>> {
+        GraphBuilder::with_edge_policies(self.state).self_loops(self_loops)
+    }
+
+    /// Sets how parallel edges are handled, see [`WithEdgePolicies`].
+    ///
+    /// # Example
+    ///
+    /// Sum up the values of parallel edges:
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let (graph, report): (DirectedCsrGraph<usize, (), f32>, _) = GraphBuilder::new()
+    ///     .edges_with_values(vec![(0, 1, 0.5), (1, 0, 1.0), (0, 1, 2.0)])
+    ///     .parallel_edges(ParallelEdges::Aggregate(|a, b| a + b))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     graph.out_neighbors_with_values(0).as_slice(),
+    ///     &[Target::new(1, 2.5)]
+    /// );
+    /// assert_eq!(report.dropped_parallel_edges, 1);
+    /// ```
+    pub fn parallel_edges(
+        self,
+        parallel_edges: ParallelEdges<EV>,
+    ) -> GraphBuilder<WithEdgePolicies<FromEdgesWithValues<NI, Edges, EV>, EV>> {
+        GraphBuilder::with_edge_policies(self.state).parallel_edges(parallel_edges)
+    }
+
+    /// Sets how the CSR is built, see [`WithCsrStrategy`].
+    pub fn csr_strategy(
+        self,
+        csr_strategy: CsrStrategy,
+    ) -> GraphBuilder<WithCsrStrategy<FromEdgesWithValues<NI, Edges, EV>>> {
+        GraphBuilder::with_csr_strategy(self.state, csr_strategy)
+    }
+
+    /// Sets the number of nodes, which must be larger than every node id of
+    /// the edges. Nodes without edges are isolated nodes of the graph.
+    ///
+    /// # Panics
+    ///
+    /// Building the graph panics if an edge contains a node id that is not
+    /// smaller than `node_count`.
+    #[must_use]
+    pub fn node_count(mut self, node_count: usize) -> Self {
+        self.state.node_count = Some(node_count);
+        self
+    }
+
     /// Build the graph from the given vec of edges.
     pub fn build<Graph>(self) -> Graph
     where
+        EV: Copy + Send,
         Graph: From<(EdgeList<NI, EV>, CsrLayout)>,
     {
+        let edge_list = EdgeList::new(self.state.edges.into_iter().collect());
+
         Graph::from((
-            EdgeList::new(self.state.edges.into_iter().collect()),
+            with_node_count(edge_list, self.state.node_count),
             self.state.csr_layout,
         ))
     }
 }
 
 impl<NI: Idx, NV, EV> GraphBuilder<FromEdgeListAndNodeValues<NI, NV, EV>> {
+    /// Sets how self-loops are handled, see [`WithEdgePolicies`].
+    pub fn self_loops(
+        self,
+        self_loops: SelfLoops,
+    ) -> GraphBuilder<WithEdgePolicies<FromEdgeListAndNodeValues<NI, NV, EV>, EV>> {
+        GraphBuilder::with_edge_policies(self.state).self_loops(self_loops)
+    }
+
+    /// Sets how parallel edges are handled, see [`WithEdgePolicies`].
+    pub fn parallel_edges(
+        self,
+        parallel_edges: ParallelEdges<EV>,
+    ) -> GraphBuilder<WithEdgePolicies<FromEdgeListAndNodeValues<NI, NV, EV>, EV>> {
+        GraphBuilder::with_edge_policies(self.state).parallel_edges(parallel_edges)
+    }
+
     pub fn build<Graph>(self) -> Graph
     where
+        EV: Copy + Send + Sync,
         Graph: From<(NodeValues<NV>, EdgeList<NI, EV>, CsrLayout)>,
     {
         Graph::from((
             self.state.node_values,
-            self.state.edge_list,
+            with_node_count(self.state.edge_list, self.state.node_count),
             self.state.csr_layout,
         ))
     }
 }
 
-#[cfg(feature = "gdl")]
-#[cfg_attr(all(feature = "gdl", has_doc_cfg), doc(cfg(feature = "gdl")))]
-impl<NI> GraphBuilder<FromGdlString<NI>>
+/// Applies an explicit node count to an edge list from memory.
+///
+/// Panics if the node count is too small, since building from in-memory
+/// edges cannot fail.
+fn with_node_count<NI, EV>(
+    edge_list: EdgeList<NI, EV>,
+    node_count: Option<usize>,
+) -> EdgeList<NI, EV>
 where
     NI: Idx,
+    EV: Copy + Send + Sync,
 {
-    /// Builds the graph from the given GDL string.
-    pub fn build<Graph>(self) -> Result<Graph, Error>
-    where
-        Graph: From<(gdl::Graph, CsrLayout)>,
-    {
-        let gdl_graph = self.state.gdl.parse::<gdl::Graph>()?;
-        let graph = Graph::from((gdl_graph, self.state.csr_layout));
-        Ok(graph)
+    match node_count {
+        Some(node_count) => edge_list
+            .with_node_count(node_count)
+            .unwrap_or_else(|e| panic!("{e}")),
+        None => edge_list,
     }
 }
 
-#[cfg(feature = "gdl")]
-#[cfg_attr(all(feature = "gdl", has_doc_cfg), doc(cfg(feature = "gdl")))]
-impl<'a, NI> GraphBuilder<FromGdlGraph<'a, NI>>
+/// Extends the node count of an edge list read from a file by the nodes of
+/// an optional node file and an explicit node count.
+fn with_nodes<NI, EV>(
+    edge_list: EdgeList<NI, EV>,
+    node_path: Option<PathBuf>,
+    node_count: Option<usize>,
+) -> Result<EdgeList<NI, EV>, Error>
 where
     NI: Idx,
+    EV: Copy + Send + Sync,
 {
-    /// Build the graph from the given GDL graph.
-    pub fn build<Graph>(self) -> Result<Graph, Error>
-    where
-        Graph: From<(&'a gdl::Graph, CsrLayout)>,
-    {
-        Ok(Graph::from((self.state.gdl_graph, self.state.csr_layout)))
+    let edge_list = match node_path {
+        Some(node_path) => edge_list.with_node_file(node_path)?,
+        None => edge_list,
+    };
+    match node_count {
+        Some(node_count) => edge_list.with_node_count(node_count),
+        None => Ok(edge_list),
     }
 }
 
-impl<NI, Path, Format> GraphBuilder<FromInput<NI, Path, Format>>
+/// Reads the node values file and joins it to a graph whose edges contain
+/// `edge_node_count` nodes.
+fn read_node_values<NI, NV, Input>(
+    input: &Input,
+    path: &StdPath,
+    edge_node_count: usize,
+    node_count: Option<usize>,
+) -> Result<NodeValues<NV>, Error>
 where
-    Path: AsRef<StdPath>,
     NI: Idx,
-    Format: InputCapabilities<NI>,
-    Format::GraphInput: TryFrom<InputPath<Path>>,
+    NV: Default,
+    Input: NodeValuesInput<NI, NV>,
 {
-    /// Set the location where the graph is stored.
-    pub fn path(self, path: Path) -> GraphBuilder<FromPath<NI, Path, Format>> {
+    let records = input.read_node_values(path)?;
+    let node_values = join_node_values(edge_node_count, records);
+
+    match node_count {
+        Some(node_count) if node_values.0.len() > node_count => Err(Error::InvalidNodeCount {
+            node_count,
+            max_node_id: node_values.0.len() - 1,
+        }),
+        _ => Ok(node_values),
+    }
+}
+
+impl<State, NV, Input> GraphBuilder<WithNodeValuesFile<State, NV, Input>> {
+    fn with_node_values_file<NP: AsRef<StdPath>>(inner: State, input: Input, path: NP) -> Self {
         GraphBuilder {
-            state: FromPath {
-                csr_layout: self.state.csr_layout,
-                path,
-                _idx: PhantomData,
-                _format: PhantomData,
+            state: WithNodeValuesFile {
+                inner,
+                input,
+                path: path.as_ref().to_path_buf(),
+                _values: PhantomData,
             },
         }
     }
 }
 
-impl<NI, Path, Format> GraphBuilder<FromPath<NI, Path, Format>>
-where
+impl<State> GraphBuilder<WithCsrStrategy<State>> {
+    fn with_csr_strategy(inner: State, csr_strategy: CsrStrategy) -> Self {
+        GraphBuilder {
+            state: WithCsrStrategy {
+                inner,
+                csr_strategy,
+            },
+        }
+    }
+
+    /// Sets how the CSR is built.
+    #[must_use]
+    pub fn csr_strategy(mut self, csr_strategy: CsrStrategy) -> Self {
+        self.state.csr_strategy = csr_strategy;
+        self
+    }
+}
+
+impl<NI, Edges> GraphBuilder<WithCsrStrategy<FromEdges<NI, Edges>>>
+where
+    NI: Idx,
+    Edges: IntoIterator<Item = (NI, NI)>,
+{
+    /// Build the graph from the given edges using the CSR strategy.
+    pub fn build<Graph>(self) -> Graph
+    where
+        Graph: From<(EdgeList<NI, ()>, CsrLayout, CsrStrategy)>,
+    {
+        let WithCsrStrategy {
+            inner,
+            csr_strategy,
+        } = self.state;
+        let edge_list = EdgeList::from(EdgeIterator(inner.edges));
+
+        Graph::from((
+            with_node_count(edge_list, inner.node_count),
+            inner.csr_layout,
+            csr_strategy,
+        ))
+    }
+}
+
+impl<NI, Edges, EV> GraphBuilder<WithCsrStrategy<FromEdgesWithValues<NI, Edges, EV>>>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+    Edges: IntoIterator<Item = (NI, NI, EV)>,
+{
+    /// Build the graph from the given edges using the CSR strategy.
+    pub fn build<Graph>(self) -> Graph
+    where
+        Graph: From<(EdgeList<NI, EV>, CsrLayout, CsrStrategy)>,
+    {
+        let WithCsrStrategy {
+            inner,
+            csr_strategy,
+        } = self.state;
+        let edge_list = EdgeList::new(inner.edges.into_iter().collect());
+
+        Graph::from((
+            with_node_count(edge_list, inner.node_count),
+            inner.csr_layout,
+            csr_strategy,
+        ))
+    }
+}
+
+impl<NI, Path, Format> GraphBuilder<WithCsrStrategy<FromPath<NI, Path, Format>>>
+where
+    Path: AsRef<StdPath>,
+    NI: Idx,
+    Format: InputCapabilities<NI>,
+    Format::GraphInput: TryFrom<InputPath<Path>>,
+    crate::Error: From<<Format::GraphInput as TryFrom<InputPath<Path>>>::Error>,
+{
+    /// Build the graph from the given input format and path using the CSR
+    /// strategy.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(Format::GraphInput, CsrLayout, CsrStrategy)>,
+    {
+        let WithCsrStrategy {
+            inner,
+            csr_strategy,
+        } = self.state;
+        let input = Format::GraphInput::try_from(InputPath(inner.path))?;
+
+        Ok(Graph::from((input, inner.csr_layout, csr_strategy)))
+    }
+}
+
+/// Graphs that know whether their edges are directed, which decides whether
+/// the edges `(u, v)` and `(v, u)` are parallel.
+pub trait EdgeDirection {
+    const DIRECTED: bool;
+}
+
+impl<NI: Idx, NV, EV> EdgeDirection for DirectedCsrGraph<NI, NV, EV> {
+    const DIRECTED: bool = true;
+}
+
+impl<NI: Idx, NV, EV> EdgeDirection for UndirectedCsrGraph<NI, NV, EV> {
+    const DIRECTED: bool = false;
+}
+
+impl<NI: Idx, NV, EV> EdgeDirection for DirectedALGraph<NI, NV, EV> {
+    const DIRECTED: bool = true;
+}
+
+impl<NI: Idx, NV, EV> EdgeDirection for UndirectedALGraph<NI, NV, EV> {
+    const DIRECTED: bool = false;
+}
+
+impl<State, EV> GraphBuilder<WithEdgePolicies<State, EV>> {
+    fn with_edge_policies(inner: State) -> Self {
+        GraphBuilder {
+            state: WithEdgePolicies {
+                inner,
+                self_loops: SelfLoops::default(),
+                parallel_edges: ParallelEdges::default(),
+            },
+        }
+    }
+
+    /// Sets how self-loops are handled.
+    #[must_use]
+    pub fn self_loops(mut self, self_loops: SelfLoops) -> Self {
+        self.state.self_loops = self_loops;
+        self
+    }
+
+    /// Sets how parallel edges are handled.
+    #[must_use]
+    pub fn parallel_edges(mut self, parallel_edges: ParallelEdges<EV>) -> Self {
+        self.state.parallel_edges = parallel_edges;
+        self
+    }
+}
+
+impl<NI, Edges> GraphBuilder<WithEdgePolicies<FromEdges<NI, Edges>, ()>>
+where
+    NI: Idx,
+    Edges: IntoIterator<Item = (NI, NI)>,
+{
+    /// Build the graph from the given edges after applying the policies.
+    pub fn build<Graph>(self) -> (Graph, EdgeReport)
+    where
+        Graph: From<(EdgeList<NI, ()>, CsrLayout)> + EdgeDirection,
+    {
+        let WithEdgePolicies {
+            inner,
+            self_loops,
+            parallel_edges,
+        } = self.state;
+        let edge_list = EdgeList::from(EdgeIterator(inner.edges));
+        let (edge_list, report) = with_node_count(edge_list, inner.node_count).apply_edge_policies(
+            Graph::DIRECTED,
+            self_loops,
+            parallel_edges,
+        );
+
+        (Graph::from((edge_list, inner.csr_layout)), report)
+    }
+}
+
+impl<NI, Edges, EV> GraphBuilder<WithEdgePolicies<FromEdgesWithValues<NI, Edges, EV>, EV>>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+    Edges: IntoIterator<Item = (NI, NI, EV)>,
+{
+    /// Build the graph from the given edges after applying the policies.
+    pub fn build<Graph>(self) -> (Graph, EdgeReport)
+    where
+        Graph: From<(EdgeList<NI, EV>, CsrLayout)> + EdgeDirection,
+    {
+        let WithEdgePolicies {
+            inner,
+            self_loops,
+            parallel_edges,
+        } = self.state;
+        let edge_list = EdgeList::from(EdgeWithValueIterator(inner.edges));
+        let (edge_list, report) = with_node_count(edge_list, inner.node_count).apply_edge_policies(
+            Graph::DIRECTED,
+            self_loops,
+            parallel_edges,
+        );
+
+        (Graph::from((edge_list, inner.csr_layout)), report)
+    }
+}
+
+impl<NI, NV, EV> GraphBuilder<WithEdgePolicies<FromEdgeListAndNodeValues<NI, NV, EV>, EV>>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+{
+    /// Build the graph from the given edges and node values after applying
+    /// the policies.
+    pub fn build<Graph>(self) -> (Graph, EdgeReport)
+    where
+        Graph: From<(NodeValues<NV>, EdgeList<NI, EV>, CsrLayout)> + EdgeDirection,
+    {
+        let WithEdgePolicies {
+            inner,
+            self_loops,
+            parallel_edges,
+        } = self.state;
+        let (edge_list, report) = with_node_count(inner.edge_list, inner.node_count)
+            .apply_edge_policies(Graph::DIRECTED, self_loops, parallel_edges);
+
+        (
+            Graph::from((inner.node_values, edge_list, inner.csr_layout)),
+            report,
+        )
+    }
+}
+
+#[cfg(feature = "gdl")]
+#[cfg_attr(all(feature = "gdl", has_doc_cfg), doc(cfg(feature = "gdl")))]
+impl<NI> GraphBuilder<FromGdlString<NI>>
+where
+    NI: Idx,
+{
+    /// Builds the graph from the given GDL string.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: TryFrom<(gdl::Graph, CsrLayout), Error = Error>,
+    {
+        let gdl_graph = self.state.gdl.parse::<gdl::Graph>()?;
+        Graph::try_from((gdl_graph, self.state.csr_layout))
+    }
+}
+
+#[cfg(feature = "gdl")]
+#[cfg_attr(all(feature = "gdl", has_doc_cfg), doc(cfg(feature = "gdl")))]
+impl<'a, NI> GraphBuilder<FromGdlGraph<'a, NI>>
+where
+    NI: Idx,
+{
+    /// Build the graph from the given GDL graph.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: TryFrom<(&'a gdl::Graph, CsrLayout), Error = Error>,
+    {
+        Graph::try_from((self.state.gdl_graph, self.state.csr_layout))
+    }
+}
+
+impl<NI, EV> GraphBuilder<FromCsvInput<NI, EV>>
+where
+    NI: Idx,
+{
+    /// Set the location of the delimited file.
+    pub fn path<P>(self, path: P) -> GraphBuilder<FromCsvPath<NI, EV, P>>
+    where
+        P: AsRef<StdPath>,
+    {
+        GraphBuilder {
+            state: FromCsvPath {
+                csr_layout: self.state.csr_layout,
+                input: self.state.input,
+                path,
+                node_path: None,
+                node_count: None,
+            },
+        }
+    }
+}
+
+impl<NI, EV, P> GraphBuilder<FromCsvPath<NI, EV, P>>
+where
+    NI: Idx,
+    EV: ParseValue + Copy + Send + Sync,
+    P: AsRef<StdPath>,
+{
+    /// Sets a node file that declares nodes without edges. The file contains
+    /// a node id per line, everything following the id is ignored.
+    #[must_use]
+    pub fn node_path<NP: AsRef<StdPath>>(mut self, node_path: NP) -> Self {
+        self.state.node_path = Some(node_path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the number of nodes, which must be larger than every node id of
+    /// the edges and the node file. Nodes without edges are isolated nodes
+    /// of the graph.
+    #[must_use]
+    pub fn node_count(mut self, node_count: usize) -> Self {
+        self.state.node_count = Some(node_count);
+        self
+    }
+
+    /// Sets a file from which node values are read and joined to the graph
+    /// by node id, see [`WithNodeValuesFile`].
+    pub fn node_values_file<NV, Input, NP>(
+        self,
+        input: Input,
+        path: NP,
+    ) -> GraphBuilder<WithNodeValuesFile<FromCsvPath<NI, EV, P>, NV, Input>>
+    where
+        Input: NodeValuesInput<NI, NV>,
+        NP: AsRef<StdPath>,
+    {
+        GraphBuilder::with_node_values_file(self.state, input, path)
+    }
+
+    /// Build the graph from the given delimited file.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(EdgeList<NI, EV>, CsrLayout)>,
+    {
+        let edges = self.state.input.read(self.state.path)?;
+        let edges = with_nodes(edges, self.state.node_path, self.state.node_count)?;
+
+        Ok(Graph::from((edges, self.state.csr_layout)))
+    }
+}
+
+impl<NI, EV, P, NV, Input> GraphBuilder<WithNodeValuesFile<FromCsvPath<NI, EV, P>, NV, Input>>
+where
+    NI: Idx,
+    EV: ParseValue + Copy + Send + Sync,
+    P: AsRef<StdPath>,
+    NV: Default,
+    Input: NodeValuesInput<NI, NV>,
+{
+    /// Build the graph from the given delimited file and node values file.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(NodeValues<NV>, EdgeList<NI, EV>, CsrLayout)>,
+    {
+        let WithNodeValuesFile {
+            inner, input, path, ..
+        } = self.state;
+
+        let edges = inner.input.read(inner.path)?;
+        let edges = with_nodes(edges, inner.node_path, inner.node_count)?;
+        let node_values = read_node_values(
+            &input,
+            &path,
+            edges.max_node_id().index() + 1,
+            inner.node_count,
+        )?;
+
+        Ok(Graph::from((node_values, edges, inner.csr_layout)))
+    }
+}
+
+#[cfg(feature = "jsonl")]
+#[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+impl<NI, NV, EV> GraphBuilder<FromJsonLinesInput<NI, NV, EV>>
+where
+    NI: Idx,
+{
+    /// Set the location of the edge file.
+    pub fn path<P>(self, path: P) -> GraphBuilder<FromJsonLinesPath<NI, NV, EV, P>>
+    where
+        P: AsRef<StdPath>,
+    {
+        GraphBuilder {
+            state: FromJsonLinesPath {
+                csr_layout: self.state.csr_layout,
+                input: self.state.input,
+                path,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "jsonl")]
+#[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+impl<NI, NV, EV, P> GraphBuilder<FromJsonLinesPath<NI, NV, EV, P>>
+where
+    NI: Idx,
+    NV: JsonValue + Send + Sync,
+    EV: JsonValue + Copy + Send + Sync,
+    P: AsRef<StdPath>,
+{
+    /// Set the location of the node file from which node values are read.
+    pub fn node_path<NP>(self, node_path: NP) -> GraphBuilder<FromJsonLinesPaths<NI, NV, EV, P, NP>>
+    where
+        NP: AsRef<StdPath>,
+    {
+        GraphBuilder {
+            state: FromJsonLinesPaths {
+                csr_layout: self.state.csr_layout,
+                input: self.state.input,
+                path: self.state.path,
+                node_path,
+            },
+        }
+    }
+
+    /// Build the graph from the given edge file.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(EdgeList<NI, EV>, CsrLayout)>,
+    {
+        let edges = self.state.input.read(self.state.path)?;
+
+        Ok(Graph::from((edges, self.state.csr_layout)))
+    }
+}
+
+#[cfg(feature = "jsonl")]
+#[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+impl<NI, NV, EV, P, NP> GraphBuilder<FromJsonLinesPaths<NI, NV, EV, P, NP>>
+where
+    NI: Idx,
+    NV: JsonValue + Send + Sync,
+    EV: JsonValue + Copy + Send + Sync,
+    P: AsRef<StdPath>,
+    NP: AsRef<StdPath>,
+{
+    /// Build the graph from the given edge and node files.
+    ///
+    /// The graph contains all nodes that occur in either of the files. Nodes
+    /// that are missing in the node file get the default node value.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(NodeValues<NV>, EdgeList<NI, EV>, CsrLayout)>,
+    {
+        let input = self.state.input;
+        let edges = input.read(self.state.path)?;
+        let nodes = input.read_nodes(self.state.node_path)?;
+
+        let node_values = input.node_values(nodes, edges.max_node_id().index() + 1)?;
+
+        Ok(Graph::from((node_values, edges, self.state.csr_layout)))
+    }
+}
+
+#[cfg(feature = "arrow")]
+#[cfg_attr(all(feature = "arrow", has_doc_cfg), doc(cfg(feature = "arrow")))]
+impl<NI, EV> GraphBuilder<FromArrowInput<NI, EV>>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    /// Set the record batches that contain the edges of the graph.
+    pub fn record_batches<Batches>(
+        self,
+        batches: Batches,
+    ) -> GraphBuilder<FromRecordBatches<NI, EV, Batches>>
+    where
+        Batches: IntoIterator<Item = RecordBatch>,
+    {
+        GraphBuilder {
+            state: FromRecordBatches {
+                csr_layout: self.state.csr_layout,
+                input: self.state.input,
+                batches,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "arrow")]
+#[cfg_attr(all(feature = "arrow", has_doc_cfg), doc(cfg(feature = "arrow")))]
+impl<NI, EV, Batches> GraphBuilder<FromRecordBatches<NI, EV, Batches>>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+    Batches: IntoIterator<Item = RecordBatch>,
+{
+    /// Build the graph from the given record batches.
+    ///
+    /// Returns an error if a configured column is missing, contains null
+    /// values or has an unsupported data type.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(ArrowEdgeList<NI, EV>, CsrLayout)>,
+    {
+        let edges = ArrowEdgeList::try_new(&self.state.input, self.state.batches)?;
+
+        Ok(Graph::from((edges, self.state.csr_layout)))
+    }
+}
+
+#[cfg(feature = "parquet")]
+#[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+impl<NI, EV> GraphBuilder<FromParquetInput<NI, EV>>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    /// Set the location of the Parquet file.
+    pub fn path<P>(self, path: P) -> GraphBuilder<FromParquetPath<NI, EV, P>>
+    where
+        P: AsRef<StdPath>,
+    {
+        GraphBuilder {
+            state: FromParquetPath {
+                csr_layout: self.state.csr_layout,
+                input: self.state.input,
+                path,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+#[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+impl<NI, EV, P> GraphBuilder<FromParquetPath<NI, EV, P>>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+    P: AsRef<StdPath>,
+{
+    /// Sets a file from which node values are read and joined to the graph
+    /// by node id, see [`WithNodeValuesFile`].
+    pub fn node_values_file<NV, Input, NP>(
+        self,
+        input: Input,
+        path: NP,
+    ) -> GraphBuilder<WithNodeValuesFile<FromParquetPath<NI, EV, P>, NV, Input>>
+    where
+        Input: NodeValuesInput<NI, NV>,
+        NP: AsRef<StdPath>,
+    {
+        GraphBuilder::with_node_values_file(self.state, input, path)
+    }
+
+    /// Build the graph from the given Parquet file.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(ArrowEdgeList<NI, EV>, CsrLayout)>,
+    {
+        let edges = self.state.input.read(self.state.path)?;
+
+        Ok(Graph::from((edges, self.state.csr_layout)))
+    }
+}
+
+#[cfg(feature = "parquet")]
+#[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+impl<NI, EV, P, NV, Input> GraphBuilder<WithNodeValuesFile<FromParquetPath<NI, EV, P>, NV, Input>>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+    P: AsRef<StdPath>,
+    NV: Default,
+    Input: NodeValuesInput<NI, NV>,
+{
+    /// Build the graph from the given Parquet file and node values file.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(NodeValues<NV>, ArrowEdgeList<NI, EV>, CsrLayout)>,
+    {
+        let WithNodeValuesFile {
+            inner, input, path, ..
+        } = self.state;
+
+        let edges = inner.input.read(inner.path)?;
+        let node_values = read_node_values(&input, &path, edges.max_node_id().index() + 1, None)?;
+
+        Ok(Graph::from((node_values, edges, inner.csr_layout)))
+    }
+}
+
+impl<NI, Path, Format> GraphBuilder<FromInput<NI, Path, Format>>
+where
+    Path: AsRef<StdPath>,
+    NI: Idx,
+    Format: InputCapabilities<NI>,
+    Format::GraphInput: TryFrom<InputPath<Path>>,
+{
+    /// Set the location where the graph is stored.
+    pub fn path(self, path: Path) -> GraphBuilder<FromPath<NI, Path, Format>> {
+        GraphBuilder {
+            state: FromPath {
+                csr_layout: self.state.csr_layout,
+                path,
+                _idx: PhantomData,
+                _format: PhantomData,
+            },
+        }
+    }
+}
+
+impl<NI, EV, P> GraphBuilder<FromPath<NI, P, EdgeListInput<NI, EV>>>
+where
+    NI: Idx,
+    EV: ParseValue + std::fmt::Debug + Send + Sync,
+    P: AsRef<StdPath>,
+{
+    /// Sets a node file that declares nodes without edges.
+    ///
+    /// A node file contains a node id per line. The node count of the graph
+    /// is one more than the largest node id of the edges and the node file,
+    /// so nodes that only appear in the node file are isolated nodes of the
+    /// graph. Everything following the id on a line is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use graph_builder::prelude::*;
+    ///
+    /// let dir = std::env::temp_dir();
+    /// let path = dir.join("graph_builder_node_path_example.el");
+    /// let node_path = dir.join("graph_builder_node_path_example.nodes");
+    /// std::fs::write(&path, "0 1\n1 2\n").unwrap();
+    /// std::fs::write(&node_path, "0\n1\n2\n3\n4\n").unwrap();
+    ///
+    /// let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+    ///     .file_format(EdgeListInput::default())
+    ///     .path(&path)
+    ///     .node_path(&node_path)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(graph.node_count(), 5);
+    /// assert_eq!(graph.edge_count(), 2);
+    /// ```
+    pub fn node_path<NP: AsRef<StdPath>>(
+        self,
+        node_path: NP,
+    ) -> GraphBuilder<FromEdgeListPath<NI, EV, P>> {
+        GraphBuilder::<FromEdgeListPath<NI, EV, P>>::from_path(self.state).node_path(node_path)
+    }
+
+    /// Sets the number of nodes, which must be larger than every node id of
+    /// the edges. Nodes without edges are isolated nodes of the graph.
+    pub fn node_count(self, node_count: usize) -> GraphBuilder<FromEdgeListPath<NI, EV, P>> {
+        GraphBuilder::<FromEdgeListPath<NI, EV, P>>::from_path(self.state).node_count(node_count)
+    }
+
+    /// Sets a file from which node values are read and joined to the graph
+    /// by node id, see [`WithNodeValuesFile`].
+    pub fn node_values_file<NV, Input, NP>(
+        self,
+        input: Input,
+        path: NP,
+    ) -> GraphBuilder<WithNodeValuesFile<FromEdgeListPath<NI, EV, P>, NV, Input>>
+    where
+        Input: NodeValuesInput<NI, NV>,
+        NP: AsRef<StdPath>,
+    {
+        GraphBuilder::<FromEdgeListPath<NI, EV, P>>::from_path(self.state)
+            .node_values_file(input, path)
+    }
+}
+
+impl<NI, EV, P> GraphBuilder<FromEdgeListPath<NI, EV, P>>
+where
+    NI: Idx,
+    EV: ParseValue + std::fmt::Debug + Send + Sync,
+    P: AsRef<StdPath>,
+{
+    fn from_path(state: FromPath<NI, P, EdgeListInput<NI, EV>>) -> Self {
+        GraphBuilder {
+            state: FromEdgeListPath {
+                csr_layout: state.csr_layout,
+                path: state.path,
+                node_path: None,
+                node_count: None,
+                _idx: PhantomData,
+            },
+        }
+    }
+
+    /// Sets a node file that declares nodes without edges, see
+    /// [`GraphBuilder::node_path`].
+    #[must_use]
+    pub fn node_path<NP: AsRef<StdPath>>(mut self, node_path: NP) -> Self {
+        self.state.node_path = Some(node_path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the number of nodes, which must be larger than every node id of
+    /// the edges and the node file.
+    #[must_use]
+    pub fn node_count(mut self, node_count: usize) -> Self {
+        self.state.node_count = Some(node_count);
+        self
+    }
+
+    /// Sets a file from which node values are read and joined to the graph
+    /// by node id, see [`WithNodeValuesFile`].
+    pub fn node_values_file<NV, Input, NP>(
+        self,
+        input: Input,
+        path: NP,
+    ) -> GraphBuilder<WithNodeValuesFile<FromEdgeListPath<NI, EV, P>, NV, Input>>
+    where
+        Input: NodeValuesInput<NI, NV>,
+        NP: AsRef<StdPath>,
+    {
+        GraphBuilder::with_node_values_file(self.state, input, path)
+    }
+
+    /// Build the graph from the given edge list and node file.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        EV: Copy,
+        Graph: From<(EdgeList<NI, EV>, CsrLayout)>,
+    {
+        let edges = EdgeList::try_from(InputPath(self.state.path))?;
+        let edges = with_nodes(edges, self.state.node_path, self.state.node_count)?;
+
+        Ok(Graph::from((edges, self.state.csr_layout)))
+    }
+}
+
+impl<NI, EV, P, NV, Input> GraphBuilder<WithNodeValuesFile<FromEdgeListPath<NI, EV, P>, NV, Input>>
+where
+    NI: Idx,
+    EV: ParseValue + Copy + std::fmt::Debug + Send + Sync,
+    P: AsRef<StdPath>,
+    NV: Default,
+    Input: NodeValuesInput<NI, NV>,
+{
+    /// Build the graph from the given edge list and node values file.
+    pub fn build<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: From<(NodeValues<NV>, EdgeList<NI, EV>, CsrLayout)>,
+    {
+        let WithNodeValuesFile {
+            inner, input, path, ..
+        } = self.state;
+
+        let edges = EdgeList::<NI, EV>::try_from(InputPath(inner.path))?;
+        let edges = with_nodes(edges, inner.node_path, inner.node_count)?;
+        let node_values = read_node_values(
+            &input,
+            &path,
+            edges.max_node_id().index() + 1,
+            inner.node_count,
+        )?;
+
+        Ok(Graph::from((node_values, edges, inner.csr_layout)))
+    }
+}
+
+impl<NI, Path, Format> GraphBuilder<FromPath<NI, Path, Format>>
+where
     Path: AsRef<StdPath>,
     NI: Idx,
     Format: InputCapabilities<NI>,
     Format::GraphInput: TryFrom<InputPath<Path>>,
     crate::Error: From<<Format::GraphInput as TryFrom<InputPath<Path>>>::Error>,
 {
+    /// Sets how the CSR is built, see [`WithCsrStrategy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let path = [env!("CARGO_MANIFEST_DIR"), "resources", "example.el"]
+    ///     .iter()
+    ///     .collect::<std::path::PathBuf>();
+    ///
+    /// let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+    ///     .csr_layout(CsrLayout::Sorted)
+    ///     .file_format(EdgeListInput::default())
+    ///     .path(path)
+    ///     .csr_strategy(CsrStrategy::SortBased(SortAlgorithm::Radix))
+    ///     .build()
+    ///     .expect("loading failed");
+    ///
+    /// assert_eq!(graph.node_count(), 4);
+    /// assert_eq!(graph.edge_count(), 5);
+    /// ```
+    pub fn csr_strategy(
+        self,
+        csr_strategy: CsrStrategy,
+    ) -> GraphBuilder<WithCsrStrategy<FromPath<NI, Path, Format>>> {
+        GraphBuilder::with_csr_strategy(self.state, csr_strategy)
+    }
+
     /// Build the graph from the given input format and path.
     pub fn build<Graph>(self) -> Result<Graph, Error>
     where
@@ -538,3 +1815,78 @@ where
         Ok(graph)
     }
 }
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(all(feature = "tokio", has_doc_cfg), doc(cfg(feature = "tokio")))]
+impl<NI, Path, Format> GraphBuilder<FromPath<NI, Path, Format>>
+where
+    Path: AsRef<StdPath>,
+    NI: Idx,
+    Format: InputCapabilities<NI> + 'static,
+    Format::GraphInput: TryFrom<InputPath<Path>> + for<'a> TryFrom<&'a [u8], Error = Error>,
+    crate::Error: From<<Format::GraphInput as TryFrom<InputPath<Path>>>::Error>,
+{
+    /// Build the graph from the given input format and path without blocking
+    /// the threads of the async runtime.
+    ///
+    /// The file is read into memory using [`tokio::fs`]. Parsing the input
+    /// and building the graph run on the blocking thread pool of the runtime.
+    /// With the `object_store` feature, the path may also be the URL of an
+    /// object, e.g., `s3://bucket/edges.el`, see
+    /// [`object_store`](crate::input::object_store).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let path = [env!("CARGO_MANIFEST_DIR"), "resources", "example.el"]
+    ///     .iter()
+    ///     .collect::<std::path::PathBuf>();
+    ///
+    /// let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+    ///     .file_format(EdgeListInput::default())
+    ///     .path(path)
+    ///     .build_async()
+    ///     .await
+    ///     .expect("loading failed");
+    ///
+    /// assert_eq!(graph.node_count(), 4);
+    /// assert_eq!(graph.edge_count(), 5);
+    /// # });
+    /// ```
+    pub async fn build_async<Graph>(self) -> Result<Graph, Error>
+    where
+        Graph: TryFrom<(Format::GraphInput, CsrLayout)> + Send + 'static,
+        crate::Error: From<Graph::Error>,
+    {
+        let bytes = read_file_async(self.state.path.as_ref()).await?;
+        let csr_layout = self.state.csr_layout;
+
+        spawn_blocking(move || {
+            let input = Format::GraphInput::try_from(&bytes[..])?;
+            let graph = Graph::try_from((input, csr_layout))?;
+            Ok(graph)
+        })
+        .await
+    }
+}
+
+/// Runs the given function on the blocking thread pool of the tokio runtime
+/// and resumes its panic, if any, on the calling task.
+#[cfg(feature = "tokio")]
+async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(error) => std::panic::resume_unwind(error.into_panic()),
+    }
+}
@@ -0,0 +1,296 @@
+//! Differences between two graphs that share an id space.
+//!
+//! [`diff`] and [`diff_undirected`] compare two graphs node by node and
+//! report every node and edge that only exists in one of them. The changes
+//! are produced lazily in ascending order of their source node, so only the
+//! neighbors of a single node are held in memory at a time, which allows
+//! comparing graphs that are too large to materialize their difference.
+//!
+//! Node ids are assumed to identify the same node in both graphs. A node
+//! exists in a graph if its id is smaller than the node count of the graph.
+
+use std::cmp::Ordering;
+
+use crate::{index::Idx, DirectedNeighbors, Graph, UndirectedNeighbors};
+
+/// A node or edge that exists in only one of two compared graphs.
+///
+/// Changes are described from the first to the second graph, e.g., an added
+/// edge exists in the second graph, but not in the first one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GraphChange<NI> {
+    AddedNode(NI),
+    RemovedNode(NI),
+    AddedEdge { source: NI, target: NI },
+    RemovedEdge { source: NI, target: NI },
+}
+
+/// Returns the changes from the directed graph `a` to the directed graph `b`.
+///
+/// Edges are compared by their outgoing neighbors. Parallel edges are
+/// compared by their number, e.g., if an edge exists twice in `a` and once
+/// in `b`, a single removed edge is reported. Edge values are ignored.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let a: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 0)])
+///     .build();
+/// let b: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (2, 0), (2, 3)])
+///     .build();
+///
+/// assert_eq!(
+///     diff(&a, &b).collect::<Vec<_>>(),
+///     vec![
+///         GraphChange::RemovedEdge { source: 1, target: 2 },
+///         GraphChange::AddedEdge { source: 2, target: 3 },
+///         GraphChange::AddedNode(3),
+///     ]
+/// );
+/// assert_eq!(diff(&a, &a).next(), None);
+/// ```
+pub fn diff<'a, NI, A, B>(a: &'a A, b: &'a B) -> impl Iterator<Item = GraphChange<NI>> + 'a
+where
+    NI: Idx,
+    A: Graph<NI> + DirectedNeighbors<NI>,
+    B: Graph<NI> + DirectedNeighbors<NI>,
+{
+    diff_by(
+        a,
+        b,
+        |a, node| a.out_neighbors(node),
+        |b, node| b.out_neighbors(node),
+        |_, _| true,
+    )
+}
+
+/// Returns the changes from the undirected graph `a` to the undirected
+/// graph `b`.
+///
+/// Each edge is reported once with its smaller node as `source`. Parallel
+/// edges are compared by their number, edge values are ignored.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let a: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (1, 2), (2, 3)])
+///     .build();
+/// let b: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(1, 0), (2, 0)])
+///     .build();
+///
+/// assert_eq!(
+///     diff_undirected(&a, &b).collect::<Vec<_>>(),
+///     vec![
+///         GraphChange::AddedEdge { source: 0, target: 2 },
+///         GraphChange::RemovedEdge { source: 1, target: 2 },
+///         GraphChange::RemovedEdge { source: 2, target: 3 },
+///         GraphChange::RemovedNode(3),
+///     ]
+/// );
+/// ```
+pub fn diff_undirected<'a, NI, A, B>(
+    a: &'a A,
+    b: &'a B,
+) -> impl Iterator<Item = GraphChange<NI>> + 'a
+where
+    NI: Idx,
+    A: Graph<NI> + UndirectedNeighbors<NI>,
+    B: Graph<NI> + UndirectedNeighbors<NI>,
+{
+    diff_by(
+        a,
+        b,
+        |a, node| a.neighbors(node),
+        |b, node| b.neighbors(node),
+        |source, target| source <= target,
+    )
+}
+
+fn diff_by<'a, NI, A, B, NA, NB, I, J, F>(
+    a: &'a A,
+    b: &'a B,
+    neighbors_a: NA,
+    neighbors_b: NB,
+    keep: F,
+) -> impl Iterator<Item = GraphChange<NI>> + 'a
+where
+    NI: Idx,
+    A: Graph<NI>,
+    B: Graph<NI>,
+    NA: Fn(&'a A, NI) -> I + 'a,
+    NB: Fn(&'a B, NI) -> J + 'a,
+    I: Iterator<Item = &'a NI>,
+    J: Iterator<Item = &'a NI>,
+    F: Fn(NI, NI) -> bool + Copy + 'a,
+{
+    let (a_count, b_count) = (a.node_count(), b.node_count());
+    let node_count = NI::max(a_count, b_count).index();
+
+    (0..node_count).flat_map(move |node| {
+        let node = NI::new(node);
+        let mut changes = Vec::new();
+
+        match (node < a_count, node < b_count) {
+            (true, false) => changes.push(GraphChange::RemovedNode(node)),
+            (false, true) => changes.push(GraphChange::AddedNode(node)),
+            _ => {}
+        }
+
+        let a_targets = if node < a_count {
+            sorted_targets(neighbors_a(a, node), |target| keep(node, target))
+        } else {
+            Vec::new()
+        };
+        let b_targets = if node < b_count {
+            sorted_targets(neighbors_b(b, node), |target| keep(node, target))
+        } else {
+            Vec::new()
+        };
+
+        diff_sorted(&a_targets, &b_targets, |target, added| {
+            changes.push(if added {
+                GraphChange::AddedEdge {
+                    source: node,
+                    target,
+                }
+            } else {
+                GraphChange::RemovedEdge {
+                    source: node,
+                    target,
+                }
+            })
+        });
+
+        changes.into_iter()
+    })
+}
+
+fn sorted_targets<'a, NI: Idx>(
+    neighbors: impl Iterator<Item = &'a NI>,
+    keep: impl Fn(NI) -> bool,
+) -> Vec<NI> {
+    let mut targets = neighbors
+        .copied()
+        .filter(|target| keep(*target))
+        .collect::<Vec<_>>();
+    targets.sort_unstable();
+    targets
+}
+
+/// Calls `f` for each element that is contained more often in one of the
+/// sorted slices than in the other, with `true` if it is contained more
+/// often in `b`.
+fn diff_sorted<NI: Idx>(a: &[NI], b: &[NI], mut f: impl FnMut(NI, bool)) {
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                f(a[i], false);
+                i += 1;
+            }
+            Ordering::Greater => {
+                f(b[j], true);
+                j += 1;
+            }
+            Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    a[i..].iter().for_each(|target| f(*target, false));
+    b[j..].iter().for_each(|target| f(*target, true));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn diff_identical_graphs() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 0)])
+            .build();
+
+        assert_eq!(diff(&graph, &graph).count(), 0);
+    }
+
+    #[test]
+    fn diff_parallel_edges() {
+        let a: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (0, 1), (0, 1), (1, 0)])
+            .build();
+        let b: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 0), (1, 0)])
+            .build();
+
+        assert_eq!(
+            diff(&a, &b).collect::<Vec<_>>(),
+            vec![
+                GraphChange::RemovedEdge {
+                    source: 0,
+                    target: 1
+                },
+                GraphChange::RemovedEdge {
+                    source: 0,
+                    target: 1
+                },
+                GraphChange::AddedEdge {
+                    source: 1,
+                    target: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_removed_nodes() {
+        let a: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (2, 3), (3, 0)])
+            .build();
+        let b: DirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+
+        assert_eq!(
+            diff(&a, &b).collect::<Vec<_>>(),
+            vec![
+                GraphChange::RemovedNode(2),
+                GraphChange::RemovedEdge {
+                    source: 2,
+                    target: 3
+                },
+                GraphChange::RemovedNode(3),
+                GraphChange::RemovedEdge {
+                    source: 3,
+                    target: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_undirected_unsorted_graphs() {
+        let a: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Unsorted)
+            .edges(vec![(3, 0), (0, 2), (1, 0), (1, 1)])
+            .build();
+        let b: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (2, 0), (0, 3), (1, 1)])
+            .build();
+
+        assert_eq!(diff_undirected(&a, &b).count(), 0);
+        assert_eq!(diff_undirected(&b, &a).count(), 0);
+    }
+}
@@ -0,0 +1,120 @@
+//! Degree-aware partitioning of the node set into ranges of work.
+//!
+//! Parallel graph kernels usually split the nodes into chunks that are
+//! claimed by worker threads. Chunks with a fixed number of nodes balance
+//! badly on graphs with a power-law degree distribution, since a single chunk
+//! may contain a large share of the edges. [`edge_balanced_partition`]
+//! instead splits the nodes into ranges that contain roughly the same number
+//! of edges.
+
+use std::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::index::Idx;
+
+/// Splits the nodes `0..node_count` into at most `range_count` consecutive
+/// ranges with roughly equal work.
+///
+/// The work of a node is its degree, as returned by `degree`, plus one, so
+/// that nodes without edges are also accounted for. The boundaries of the
+/// ranges are found by a binary search over the prefix sum of the work of
+/// all nodes. Every range contains at least one node, so a node with a very
+/// high degree may form a range on its own that contains more than the
+/// average work. The ranges cover all nodes in ascending order.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (0, 2), (0, 3), (0, 4), (0, 5), (1, 2), (2, 3)])
+///     .build();
+///
+/// let ranges = edge_balanced_partition(graph.node_count(), 2, |node| {
+///     graph.out_degree(node).index()
+/// });
+///
+/// assert_eq!(ranges, vec![0..1, 1..6]);
+/// ```
+pub fn edge_balanced_partition<NI, F>(
+    node_count: NI,
+    range_count: usize,
+    degree: F,
+) -> Vec<Range<NI>>
+where
+    NI: Idx,
+    F: Fn(NI) -> usize + Sync,
+{
+    let node_count = node_count.index();
+    if node_count == 0 {
+        return Vec::new();
+    }
+    let range_count = usize::max(range_count, 1);
+
+    let mut prefix_sum = (0..node_count)
+        .into_par_iter()
+        .map(|node| degree(NI::new(node)) + 1)
+        .collect::<Vec<_>>();
+    prefix_sum.insert(0, 0);
+    for node in 1..prefix_sum.len() {
+        prefix_sum[node] += prefix_sum[node - 1];
+    }
+    let total_work = prefix_sum[node_count] as u64;
+
+    let mut boundaries = Vec::with_capacity(range_count + 1);
+    boundaries.push(0);
+    for range in 1..range_count {
+        let work = (total_work * range as u64 / range_count as u64) as usize;
+        let boundary = prefix_sum.partition_point(|&prefix| prefix < work);
+        if boundary > *boundaries.last().unwrap() && boundary < node_count {
+            boundaries.push(boundary);
+        }
+    }
+    boundaries.push(node_count);
+
+    boundaries
+        .windows(2)
+        .map(|w| NI::new(w[0])..NI::new(w[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_degrees() {
+        let ranges = edge_balanced_partition::<usize, _>(8, 4, |_| 1);
+        assert_eq!(ranges, vec![0..2, 2..4, 4..6, 6..8]);
+    }
+
+    #[test]
+    fn skewed_degrees() {
+        let ranges =
+            edge_balanced_partition::<u32, _>(10, 3, |node| if node == 2 { 100 } else { 0 });
+        assert_eq!(ranges, vec![0..3, 3..10]);
+    }
+
+    #[test]
+    fn more_ranges_than_nodes() {
+        let ranges = edge_balanced_partition::<u64, _>(3, 10, |node| node as usize);
+        assert_eq!(ranges, vec![0..1, 1..2, 2..3]);
+    }
+
+    #[test]
+    fn single_range() {
+        let ranges = edge_balanced_partition::<usize, _>(5, 1, |node| node);
+        assert_eq!(ranges, vec![0..5]);
+
+        let ranges = edge_balanced_partition::<usize, _>(5, 0, |node| node);
+        assert_eq!(ranges, vec![0..5]);
+    }
+
+    #[test]
+    fn no_nodes() {
+        let ranges = edge_balanced_partition::<usize, _>(0, 4, |node| node);
+        assert!(ranges.is_empty());
+    }
+}
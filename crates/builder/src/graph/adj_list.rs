@@ -3,11 +3,10 @@ use crate::{
     CsrLayout, DirectedDegrees, DirectedNeighbors, DirectedNeighborsWithValues, Graph, Target,
     UndirectedDegrees, UndirectedNeighbors, UndirectedNeighborsWithValues,
 };
-use crate::{EdgeMutation, EdgeMutationWithValues};
+use crate::{time::Instant, EdgeMutation, EdgeMutationWithValues};
 
 use log::info;
 use std::sync::{RwLock, RwLockReadGuard};
-use std::time::Instant;
 
 use crate::graph::csr::NodeValues;
 use rayon::prelude::*;
@@ -878,6 +877,7 @@ mod test {
     }
 
     #[test]
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
     fn directed_al_graph_add_edge_parallel() {
         let g = GraphBuilder::new()
             .csr_layout(CsrLayout::Unsorted)
@@ -992,6 +992,7 @@ mod test {
     }
 
     #[test]
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
     fn undirected_al_graph_add_edge_parallel() {
         let g = GraphBuilder::new()
             .csr_layout(CsrLayout::Unsorted)
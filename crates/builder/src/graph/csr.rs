@@ -7,31 +7,44 @@ use std::{
     io::{BufReader, Read, Write},
     iter::FromIterator,
     mem::{ManuallyDrop, MaybeUninit},
+    ops::{Range, RangeBounds},
     path::PathBuf,
     sync::atomic::Ordering::Acquire,
-    time::Instant,
 };
 
+use fxhash::FxHashMap;
 use rayon::prelude::*;
 
 use crate::{
     compat::*,
-    graph_ops::{DeserializeGraphOp, SerializeGraphOp, ToUndirectedOp},
+    graph::sort::par_radix_sort_by_key,
+    graph_ops::{
+        nodes_with_degree_in, top_degree_nodes, DegreeQueryOp, DeserializeGraphOp, EgoSubgraphOp,
+        FilterEdgesOp, GraphStatsOp, InducedSubgraphOp, MapEdgeValuesOp, NeighborsWithinOp,
+        OutNeighborsWithinOp, RelabelOp, SerializeGraphOp, ToUndirectedOp, ValidateOp,
+    },
     index::Idx,
-    input::{edgelist::Edges, Direction},
-    DirectedDegrees, DirectedNeighbors, DirectedNeighborsWithValues, Error, Graph,
-    NodeValues as NodeValuesTrait, SharedMut, Target, UndirectedDegrees, UndirectedNeighbors,
-    UndirectedNeighborsWithValues,
+    input::{edgelist::Edges, Direction, EdgeList},
+    stats::{DegreeStats, GraphStats},
+    time::Instant,
+    validation::{reverse_violations, sorted_targets, validate_csr, ValidationReport, Violation},
+    DirectedDegrees, DirectedEdgeIds, DirectedNeighbors, DirectedNeighborsWithValues, Error, Graph,
+    NodeValues as NodeValuesTrait, SharedMut, Target, UndirectedDegrees, UndirectedEdgeIds,
+    UndirectedNeighbors, UndirectedNeighborsWithValues,
 };
 
 #[cfg(feature = "dotgraph")]
 use crate::input::DotGraph;
-#[cfg(feature = "dotgraph")]
 use std::hash::Hash;
 
 /// Defines how the neighbor list of individual nodes are organized within the
 /// CSR target array.
 #[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum CsrLayout {
     /// Neighbor lists are sorted and may contain duplicate target ids. This is
     /// the default representation.
@@ -41,9 +54,72 @@ pub enum CsrLayout {
     Unsorted,
     /// Neighbor lists are sorted and do not contain duplicate target ids.
     /// Self-loops, i.e., edges in the form of `(u, u)` are removed.
+    /// To handle self-loops and parallel edges separately, see
+    /// [`WithEdgePolicies`](crate::builder::WithEdgePolicies).
     Deduplicated,
 }
 
+/// Defines how the CSR target array is built from an edge list.
+///
+/// The strategy does not change the resulting graph, except for the order
+/// of parallel edges with different values and the order of neighbors for
+/// [`CsrLayout::Unsorted`], but it changes the time and memory needed to
+/// build it.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: DirectedCsrGraph<usize> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .edges(vec![(0, 2), (1, 2), (0, 1), (2, 0)])
+///     .csr_strategy(CsrStrategy::SortBased(SortAlgorithm::Radix))
+///     .build();
+///
+/// assert_eq!(graph.out_neighbors(0).as_slice(), &[1, 2]);
+/// assert_eq!(graph.in_neighbors(2).as_slice(), &[0, 1]);
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CsrStrategy {
+    /// Counts the degree of each node, computes the offsets as their prefix
+    /// sum and writes each target to its position in the target array.
+    /// Neighbor lists are sorted individually afterwards, if the layout
+    /// requires it. This needs two passes over the edges, but no memory
+    /// besides the CSR itself. This is the default strategy.
+    #[default]
+    CountingSort,
+    /// Sorts all `(source, target)` pairs using the given algorithm and
+    /// derives the offsets and targets from the sorted pairs. This needs
+    /// memory for a copy of the edges, but produces sorted neighbor lists
+    /// without a separate sorting step, which pays off for
+    /// [`CsrLayout::Sorted`] and [`CsrLayout::Deduplicated`].
+    SortBased(SortAlgorithm),
+}
+
+/// Defines how the `(source, target)` pairs are sorted by
+/// [`CsrStrategy::SortBased`].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SortAlgorithm {
+    /// A parallel comparison sort. This is the default algorithm.
+    #[default]
+    Comparison,
+    /// A parallel least-significant-digit radix sort on the node ids. It
+    /// needs memory for a second copy of the edges, but its running time is
+    /// linear in the number of edges.
+    Radix,
+}
+
 /// A Compressed-Sparse-Row data structure to represent sparse graphs.
 ///
 /// The data structure is composed of two arrays: `offsets` and `targets`. For a
@@ -83,6 +159,18 @@ impl<Index: Idx, NI, EV> Csr<Index, NI, EV> {
         to - from
     }
 
+    /// Returns the number of bytes used by the offsets and targets.
+    pub(crate) fn memory_bytes(&self) -> usize {
+        std::mem::size_of_val(&*self.offsets) + std::mem::size_of_val(&*self.targets)
+    }
+
+    fn degrees(&self) -> Vec<u64> {
+        self.offsets
+            .par_windows(2)
+            .map(|w| (w[1] - w[0]).index() as u64)
+            .collect()
+    }
+
     #[inline]
     pub(crate) fn targets_with_values(&self, i: Index) -> &[Target<NI, EV>] {
         let from = self.offsets[i.index()];
@@ -90,6 +178,30 @@ impl<Index: Idx, NI, EV> Csr<Index, NI, EV> {
 
         &self.targets[from.index()..to.index()]
     }
+
+    /// Returns the positions of the targets of the given node, which serve
+    /// as the ids of the corresponding edges.
+    #[inline]
+    pub(crate) fn edge_ids(&self, i: Index) -> Range<usize> {
+        let from = self.offsets[i.index()];
+        let to = self.offsets[(i + Index::new(1)).index()];
+
+        from.index()..to.index()
+    }
+}
+
+impl<Index: Idx, NI: PartialEq, EV> Csr<Index, NI, EV> {
+    /// Returns the position of the first occurrence of `target` among the
+    /// targets of the given node.
+    pub(crate) fn edge_id(&self, i: Index, target: NI) -> Option<usize> {
+        let ids = self.edge_ids(i);
+        let start = ids.start;
+
+        self.targets[ids]
+            .iter()
+            .position(|t| t.target == target)
+            .map(|position| start + position)
+    }
 }
 
 impl<Index: Idx, NI> Csr<Index, NI, ()> {
@@ -117,6 +229,177 @@ impl<Index: Idx, NI> Csr<Index, NI, ()> {
     }
 }
 
+impl<NI: Idx, EV> Csr<NI, NI, EV> {
+    /// Creates a CSR from its raw parts.
+    ///
+    /// `offsets` must contain `node_count + 1` non-decreasing entries starting
+    /// at `0` and ending at the number of targets. The neighbor list of node `u`
+    /// is stored at `targets[offsets[u]..offsets[u + 1]]` and the corresponding
+    /// edge values at the same positions in `values`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::graph::csr::Csr;
+    ///
+    /// let csr = Csr::<u32, u32, f32>::from_raw_parts(
+    ///     vec![0, 2, 3, 3],
+    ///     vec![1, 2, 2],
+    ///     vec![0.5, 1.0, 1.5],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(csr.offsets(), &[0, 2, 3, 3]);
+    /// assert_eq!(csr.into_raw_parts().1, vec![1, 2, 2]);
+    /// ```
+    pub fn from_raw_parts(
+        offsets: Vec<NI>,
+        targets: Vec<NI>,
+        values: Vec<EV>,
+    ) -> Result<Self, Error> {
+        let invalid = |reason: &str| {
+            Err(Error::InvalidCsr {
+                reason: reason.to_string(),
+            })
+        };
+
+        if offsets.is_empty() {
+            return invalid("offsets must contain at least one entry");
+        }
+        if offsets[0] != NI::zero() {
+            return invalid("offsets must start at 0");
+        }
+        if offsets.windows(2).any(|w| w[0] > w[1]) {
+            return invalid("offsets must be non-decreasing");
+        }
+        if offsets[offsets.len() - 1].index() != targets.len() {
+            return invalid("last offset must be equal to the number of targets");
+        }
+        if targets.len() != values.len() {
+            return invalid("targets and values must have the same length");
+        }
+
+        let node_count = NI::new(offsets.len() - 1);
+        if targets.iter().any(|&t| t >= node_count) {
+            return invalid("targets must be smaller than the node count");
+        }
+
+        let targets = targets
+            .into_iter()
+            .zip(values)
+            .map(|(target, value)| Target::new(target, value))
+            .collect::<Vec<_>>();
+
+        Ok(Csr::new(
+            offsets.into_boxed_slice(),
+            targets.into_boxed_slice(),
+        ))
+    }
+
+    /// Returns the offsets array, see [`Csr`] for details.
+    pub fn offsets(&self) -> &[NI] {
+        &self.offsets
+    }
+
+    /// Returns the targets array, where each target carries its edge value.
+    pub fn targets_with_values_raw(&self) -> &[Target<NI, EV>] {
+        &self.targets
+    }
+
+    /// Decomposes the CSR into its offsets, targets and edge values.
+    pub fn into_raw_parts(self) -> (Vec<NI>, Vec<NI>, Vec<EV>) {
+        let (targets, values) = self
+            .targets
+            .into_vec()
+            .into_iter()
+            .map(|t| (t.target, t.value))
+            .unzip();
+
+        (self.offsets.into_vec(), targets, values)
+    }
+}
+
+impl<NI: Idx, EV: Copy> Csr<NI, NI, EV> {
+    /// Creates the CSR of the reversed relationships.
+    ///
+    /// Turns a CSR of outgoing neighbor lists into one of incoming neighbor
+    /// lists and vice versa. The resulting neighbor lists are sorted.
+    pub fn transpose(&self) -> Self {
+        let node_count = self.node_count().index();
+        let mut degrees = vec![NI::zero(); node_count + 1];
+        for t in self.targets.iter() {
+            degrees[t.target.index()] += NI::new(1);
+        }
+
+        let mut offsets = prefix_sum(degrees);
+        offsets.pop();
+        let mut next = offsets.clone();
+
+        let mut targets = Vec::with_capacity(self.targets.len());
+        targets.resize_with(self.targets.len(), MaybeUninit::uninit);
+        for source in 0..node_count {
+            let source = NI::new(source);
+            for t in self.targets_with_values(source) {
+                let pos = &mut next[t.target.index()];
+                targets[pos.index()].write(Target::new(source, t.value));
+                *pos += NI::new(1);
+            }
+        }
+
+        // SAFETY: every position in `targets` has been written exactly once,
+        //         since the offsets are derived from the in-degrees.
+        let targets = targets
+            .into_iter()
+            .map(|t| unsafe { t.assume_init() })
+            .collect::<Vec<_>>();
+
+        Csr::new(offsets.into_boxed_slice(), targets.into_boxed_slice())
+    }
+
+    // Node `u` becomes node `permutation[u]`, `original` is the inverse
+    // permutation. The resulting neighbor lists are sorted.
+    fn relabel(&self, permutation: &[NI], original: &[NI]) -> Self {
+        let mut offsets = Vec::with_capacity(original.len() + 1);
+        let mut targets = Vec::with_capacity(self.targets.len());
+        offsets.push(NI::zero());
+
+        for &node in original {
+            let start = targets.len();
+            targets.extend(
+                self.targets_with_values(node)
+                    .iter()
+                    .map(|t| Target::new(permutation[t.target.index()], t.value)),
+            );
+            targets[start..].sort_unstable();
+            offsets.push(NI::new(targets.len()));
+        }
+
+        Csr::new(offsets.into_boxed_slice(), targets.into_boxed_slice())
+    }
+
+    // Replaces each value with `f(node, target, value)`, where `node` owns
+    // the neighbor list. Offsets and the order of targets are kept.
+    fn map_values<EV2, F>(&self, f: F) -> Csr<NI, NI, EV2>
+    where
+        EV: Sync,
+        EV2: Send,
+        F: Fn(NI, NI, EV) -> EV2 + Sync,
+    {
+        let f = &f;
+        let targets = (0..self.node_count().index())
+            .into_par_iter()
+            .flat_map_iter(|node| {
+                let node = NI::new(node);
+                self.targets_with_values(node)
+                    .iter()
+                    .map(move |t| Target::new(t.target, f(node, t.target, t.value)))
+            })
+            .collect::<Vec<_>>();
+
+        Csr::new(self.offsets.clone(), targets.into_boxed_slice())
+    }
+}
+
 pub trait SwapCsr<Index: Idx, NI, EV> {
     fn swap_csr(&mut self, csr: Csr<Index, NI, EV>) -> &mut Self;
 }
@@ -220,6 +503,98 @@ where
     }
 }
 
+impl<NI, EV, E> From<(&'_ E, NI, Direction, CsrLayout, CsrStrategy)> for Csr<NI, NI, EV>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+    E: Edges<NI = NI, EV = EV>,
+{
+    fn from(
+        (edge_list, node_count, direction, csr_layout, csr_strategy): (
+            &'_ E,
+            NI,
+            Direction,
+            CsrLayout,
+            CsrStrategy,
+        ),
+    ) -> Self {
+        match csr_strategy {
+            CsrStrategy::CountingSort => Csr::from((edge_list, node_count, direction, csr_layout)),
+            CsrStrategy::SortBased(sort_algorithm) => {
+                sort_based_csr(edge_list, node_count, direction, csr_layout, sort_algorithm)
+            }
+        }
+    }
+}
+
+fn sort_based_csr<NI, EV, E>(
+    edge_list: &E,
+    node_count: NI,
+    direction: Direction,
+    csr_layout: CsrLayout,
+    sort_algorithm: SortAlgorithm,
+) -> Csr<NI, NI, EV>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+    E: Edges<NI = NI, EV = EV>,
+{
+    let start = Instant::now();
+    let edges = edge_list.edges();
+    let mut pairs = match direction {
+        Direction::Outgoing => edges
+            .map(|(s, t, v)| (s, Target::new(t, v)))
+            .collect::<Vec<_>>(),
+        Direction::Incoming => edges
+            .map(|(s, t, v)| (t, Target::new(s, v)))
+            .collect::<Vec<_>>(),
+        Direction::Undirected => edges
+            .flat_map_iter(|(s, t, v)| [(s, Target::new(t, v)), (t, Target::new(s, v))])
+            .collect::<Vec<_>>(),
+    };
+    info!("Collected edge pairs in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    match sort_algorithm {
+        SortAlgorithm::Comparison => {
+            pairs.par_sort_unstable_by_key(|(source, target)| (*source, target.target))
+        }
+        SortAlgorithm::Radix => {
+            let max_key = node_count.index().saturating_sub(1);
+            par_radix_sort_by_key(&mut pairs, max_key, |(_, target)| target.target.index());
+            par_radix_sort_by_key(&mut pairs, max_key, |(source, _)| source.index());
+        }
+    }
+    info!("Sorted edge pairs in {:?}", start.elapsed());
+
+    let start = Instant::now();
+    // The offset of a node is the number of pairs with a smaller source.
+    let offsets = (0..=node_count.index())
+        .into_par_iter()
+        .map(|node| NI::new(pairs.partition_point(|(source, _)| source.index() < node)))
+        .collect::<Vec<_>>();
+    let mut targets = pairs
+        .into_par_iter()
+        .map(|(_, target)| target)
+        .collect::<Vec<_>>();
+    info!("Computed offset and target arrays in {:?}", start.elapsed());
+
+    let (offsets, targets) = match csr_layout {
+        CsrLayout::Unsorted | CsrLayout::Sorted => (offsets, targets),
+        CsrLayout::Deduplicated => {
+            let start = Instant::now();
+            let offsets_targets = sort_and_deduplicate_targets(&offsets, &mut targets[..]);
+            info!("Deduplicated targets in {:?}", start.elapsed());
+            offsets_targets
+        }
+    };
+
+    Csr {
+        offsets: offsets.into_boxed_slice(),
+        targets: targets.into_boxed_slice(),
+    }
+}
+
 unsafe impl<NI, EV> ToByteSlice for Target<NI, EV>
 where
     NI: ToByteSlice,
@@ -276,9 +651,13 @@ where
         read.read_exact(type_name_len.as_mut_byte_slice())?;
         let [type_name_len] = type_name_len;
 
-        let mut type_name = vec![0_u8; type_name_len];
-        read.read_exact(type_name.as_mut_byte_slice())?;
-        let type_name = String::from_utf8(type_name).expect("could not read type name");
+        // Reading at most the announced length avoids allocating for corrupt
+        // lengths, a short or invalid name is reported as a type mismatch.
+        let mut type_name = Vec::new();
+        read.by_ref()
+            .take(type_name_len as u64)
+            .read_to_end(&mut type_name)?;
+        let type_name = String::from_utf8_lossy(&type_name).into_owned();
 
         let expected_type_name = std::any::type_name::<NI>().to_string();
 
@@ -294,23 +673,63 @@ where
 
         let [node_count, edge_count] = meta;
 
-        let mut offsets = Box::new_uninit_slice_compat(node_count.index() + 1);
-        let offsets_ptr = offsets.as_mut_ptr() as *mut NI;
-        let offsets_ptr =
-            unsafe { std::slice::from_raw_parts_mut(offsets_ptr, node_count.index() + 1) };
-        read.read_exact(offsets_ptr.as_mut_byte_slice())?;
+        let offsets_len = count(node_count)?
+            .checked_add(1)
+            .ok_or_else(|| invalid_count("node count"))?;
+        let offsets = read_slice::<NI, _>(read, offsets_len)?;
+        let targets = read_slice::<Target<NI, EV>, _>(read, count(edge_count)?)?;
+
+        Ok(Csr::new(offsets, targets))
+    }
+}
+
+/// Converts a count read from the input, which must not be negative.
+fn count<NI: Idx>(count: NI) -> Result<usize, Error> {
+    if count < NI::zero() {
+        return Err(invalid_count("count"));
+    }
+    Ok(count.index())
+}
 
-        let mut targets = Box::new_uninit_slice_compat(edge_count.index());
-        let targets_ptr = targets.as_mut_ptr() as *mut Target<NI, EV>;
-        let targets_ptr =
-            unsafe { std::slice::from_raw_parts_mut(targets_ptr, edge_count.index()) };
-        read.read_exact(targets_ptr.as_mut_byte_slice())?;
+fn invalid_count(what: &str) -> Error {
+    Error::IoError {
+        source: std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("invalid {what} in serialized graph"),
+        ),
+    }
+}
 
-        let offsets = unsafe { offsets.assume_init_compat() };
-        let targets = unsafe { targets.assume_init_compat() };
+/// Reads `len` values from the input.
+///
+/// The length is read from the input as well and might be corrupt, so the
+/// buffer grows in chunks as the values are read, instead of allocating it
+/// upfront. A length that exceeds the input fails with an unexpected end of
+/// input, after allocating at most about twice the size of the input.
+fn read_slice<T, R>(read: &mut R, len: usize) -> Result<Box<[T]>, Error>
+where
+    T: ToMutByteSlice,
+    R: Read,
+{
+    const CHUNK_BYTES: usize = 1 << 20;
+    let chunk_len = match std::mem::size_of::<T>() {
+        // Zero-sized values do not allocate.
+        0 => usize::MAX,
+        size => usize::max(CHUNK_BYTES / size, 1),
+    };
 
-        Ok(Csr::new(offsets, targets))
+    let mut values = Vec::<T>::with_capacity(usize::min(len, chunk_len));
+    while values.len() < len {
+        let chunk = usize::min(chunk_len, len - values.len());
+        values.reserve(chunk);
+        let spare = &mut values.spare_capacity_mut()[..chunk];
+        let spare = unsafe { std::slice::from_raw_parts_mut(spare.as_mut_ptr() as *mut T, chunk) };
+        read.read_exact(spare.as_mut_byte_slice())?;
+        // SAFETY: the chunk has been initialized by `read_exact`.
+        unsafe { values.set_len(values.len() + chunk) };
     }
+
+    Ok(values.into_boxed_slice())
 }
 
 pub struct NodeValues<NV>(pub(crate) Box<[NV]>);
@@ -321,6 +740,12 @@ impl<NV> NodeValues<NV> {
     }
 }
 
+impl<NV> NodeValues<NV> {
+    fn memory_bytes(&self) -> usize {
+        std::mem::size_of_val(&*self.0)
+    }
+}
+
 impl<NV> FromIterator<NV> for NodeValues<NV> {
     fn from_iter<T: IntoIterator<Item = NV>>(iter: T) -> Self {
         Self(iter.into_iter().collect::<Vec<_>>().into_boxed_slice())
@@ -349,16 +774,64 @@ where
         read.read_exact(meta.as_mut_byte_slice())?;
         let [node_count] = meta;
 
-        let mut node_values = Box::new_uninit_slice_compat(node_count);
-        let node_values_ptr = node_values.as_mut_ptr() as *mut NV;
-        let node_values_slice =
-            unsafe { std::slice::from_raw_parts_mut(node_values_ptr, node_count.index()) };
-        read.read_exact(node_values_slice.as_mut_byte_slice())?;
+        Ok(NodeValues(read_slice(read, node_count)?))
+    }
+}
+
+/// Serializes a graph as a single byte buffer in the binary layout of
+/// [`SerializeGraphOp`].
+#[cfg(feature = "serde")]
+fn serialize_binary<G, S>(graph: &G, serializer: S) -> Result<S::Ok, S::Error>
+where
+    G: for<'a> SerializeGraphOp<&'a mut Vec<u8>>,
+    S: serde::Serializer,
+{
+    let mut bytes = Vec::new();
+    graph
+        .serialize(&mut bytes)
+        .map_err(serde::ser::Error::custom)?;
+    serializer.serialize_bytes(&bytes)
+}
+
+/// Deserializes a graph from a byte buffer in the binary layout of
+/// [`DeserializeGraphOp`].
+///
+/// Formats without native byte buffers, e.g., JSON, represent the buffer as
+/// a sequence of bytes, which is accepted as well.
+#[cfg(feature = "serde")]
+fn deserialize_binary<'de, G, D>(deserializer: D) -> Result<G, D::Error>
+where
+    G: for<'a> DeserializeGraphOp<&'a [u8], G>,
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::{Error as _, SeqAccess, Visitor};
+
+    struct BinaryVisitor<G>(std::marker::PhantomData<G>);
+
+    impl<'de, G> Visitor<'de> for BinaryVisitor<G>
+    where
+        G: for<'a> DeserializeGraphOp<&'a [u8], G>,
+    {
+        type Value = G;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a graph in the binary layout")
+        }
 
-        let offsets = unsafe { node_values.assume_init_compat() };
+        fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<G, E> {
+            G::deserialize(bytes).map_err(E::custom)
+        }
 
-        Ok(NodeValues(offsets))
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<G, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte);
+            }
+            G::deserialize(bytes.as_slice()).map_err(A::Error::custom)
+        }
     }
+
+    deserializer.deserialize_byte_buf(BinaryVisitor(std::marker::PhantomData))
 }
 
 pub struct DirectedCsrGraph<NI: Idx, NV = (), EV = ()> {
@@ -386,6 +859,15 @@ impl<NI: Idx, NV, EV> DirectedCsrGraph<NI, NV, EV> {
 
         g
     }
+    /// Returns the CSR that stores the outgoing neighbor lists.
+    pub fn csr_out(&self) -> &Csr<NI, NI, EV> {
+        &self.csr_out
+    }
+
+    /// Returns the CSR that stores the incoming neighbor lists.
+    pub fn csr_inc(&self) -> &Csr<NI, NI, EV> {
+        &self.csr_inc
+    }
 }
 
 impl<NI, NV, EV> ToUndirectedOp for DirectedCsrGraph<NI, NV, EV>
@@ -511,6 +993,20 @@ impl<NI: Idx, NV, EV> DirectedNeighborsWithValues<NI, EV> for DirectedCsrGraph<N
     }
 }
 
+impl<NI: Idx, NV, EV> DirectedEdgeIds<NI> for DirectedCsrGraph<NI, NV, EV> {
+    fn edge_id_count(&self) -> usize {
+        self.csr_out.edge_count().index()
+    }
+
+    fn out_edge_ids(&self, node: NI) -> Range<usize> {
+        self.csr_out.edge_ids(node)
+    }
+
+    fn out_edge_id(&self, source: NI, target: NI) -> Option<usize> {
+        self.csr_out.edge_id(source, target)
+    }
+}
+
 impl<NI, EV, E> From<(E, CsrLayout)> for DirectedCsrGraph<NI, (), EV>
 where
     NI: Idx,
@@ -518,17 +1014,40 @@ where
     E: Edges<NI = NI, EV = EV>,
 {
     fn from((edge_list, csr_option): (E, CsrLayout)) -> Self {
+        DirectedCsrGraph::from((edge_list, csr_option, CsrStrategy::default()))
+    }
+}
+
+impl<NI, EV, E> From<(E, CsrLayout, CsrStrategy)> for DirectedCsrGraph<NI, (), EV>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+    E: Edges<NI = NI, EV = EV>,
+{
+    fn from((edge_list, csr_option, csr_strategy): (E, CsrLayout, CsrStrategy)) -> Self {
         info!("Creating directed graph");
         let node_count = edge_list.max_node_id() + NI::new(1);
 
         let node_values = NodeValues::new(vec![(); node_count.index()]);
 
         let start = Instant::now();
-        let csr_out = Csr::from((&edge_list, node_count, Direction::Outgoing, csr_option));
+        let csr_out = Csr::from((
+            &edge_list,
+            node_count,
+            Direction::Outgoing,
+            csr_option,
+            csr_strategy,
+        ));
         info!("Created outgoing csr in {:?}.", start.elapsed());
 
         let start = Instant::now();
-        let csr_inc = Csr::from((&edge_list, node_count, Direction::Incoming, csr_option));
+        let csr_inc = Csr::from((
+            &edge_list,
+            node_count,
+            Direction::Incoming,
+            csr_option,
+            csr_strategy,
+        ));
         info!("Created incoming csr in {:?}.", start.elapsed());
 
         DirectedCsrGraph::new(node_values, csr_out, csr_inc)
@@ -595,94 +1114,349 @@ where
     }
 }
 
-impl<W, NI, NV, EV> SerializeGraphOp<W> for DirectedCsrGraph<NI, NV, EV>
+impl<NI, NV, EV> EgoSubgraphOp<NI> for DirectedCsrGraph<NI, NV, EV>
 where
-    W: Write,
-    NI: Idx + ToByteSlice,
-    NV: ToByteSlice,
-    EV: ToByteSlice,
+    NI: Idx + Hash,
+    NV: Clone,
+    EV: Copy + Send + Sync,
 {
-    fn serialize(&self, mut output: W) -> Result<(), Error> {
-        let DirectedCsrGraph {
-            node_values,
-            csr_out,
-            csr_inc,
-        } = self;
-
-        node_values.serialize(&mut output)?;
-        csr_out.serialize(&mut output)?;
-        csr_inc.serialize(&mut output)?;
+    type Subgraph = DirectedCsrGraph<NI, NV, EV>;
 
-        Ok(())
+    fn ego_subgraph(&self, node: NI, radius: usize) -> (Self::Subgraph, Vec<NI>) {
+        let mut mapping = vec![node];
+        mapping.extend(self.out_neighbors_within(node, radius));
+        directed_subgraph(self, mapping)
     }
 }
 
-impl<R, NI, NV, EV> DeserializeGraphOp<R, Self> for DirectedCsrGraph<NI, NV, EV>
+impl<NI, NV, EV> InducedSubgraphOp<NI> for DirectedCsrGraph<NI, NV, EV>
 where
-    R: Read,
-    NI: Idx + ToMutByteSlice,
-    NV: ToMutByteSlice,
-    EV: ToMutByteSlice,
+    NI: Idx + Hash,
+    NV: Clone,
+    EV: Copy + Send + Sync,
 {
-    fn deserialize(mut read: R) -> Result<Self, Error> {
-        let node_values: NodeValues<NV> = NodeValues::deserialize(&mut read)?;
-        let csr_out: Csr<NI, NI, EV> = Csr::deserialize(&mut read)?;
-        let csr_inc: Csr<NI, NI, EV> = Csr::deserialize(&mut read)?;
-        Ok(DirectedCsrGraph::new(node_values, csr_out, csr_inc))
+    type Subgraph = DirectedCsrGraph<NI, NV, EV>;
+
+    fn induced_subgraph<P>(&self, predicate: P) -> (Self::Subgraph, Vec<NI>)
+    where
+        P: Fn(NI) -> bool,
+    {
+        directed_subgraph(self, selected_nodes(self.node_count(), predicate))
     }
 }
 
-impl<NI, EV> TryFrom<(PathBuf, CsrLayout)> for DirectedCsrGraph<NI, EV>
+fn directed_subgraph<NI, NV, EV>(
+    graph: &DirectedCsrGraph<NI, NV, EV>,
+    mapping: Vec<NI>,
+) -> (DirectedCsrGraph<NI, NV, EV>, Vec<NI>)
 where
-    NI: Idx + ToMutByteSlice,
-    EV: ToMutByteSlice,
+    NI: Idx + Hash,
+    NV: Clone,
+    EV: Copy + Send + Sync,
 {
-    type Error = Error;
+    let ids = subgraph_ids(&mapping);
+
+    let mut edges = Vec::new();
+    for (source, original) in mapping.iter().enumerate() {
+        let source = NI::new(source);
+        for target in graph.csr_out.targets_with_values(*original) {
+            if let Some(&mapped) = ids.get(&target.target) {
+                edges.push((source, mapped, target.value));
+            }
+        }
+    }
 
-    fn try_from((path, _): (PathBuf, CsrLayout)) -> Result<Self, Self::Error> {
-        let reader = BufReader::new(File::open(path)?);
-        let graph = DirectedCsrGraph::deserialize(reader)?;
+    let node_values = subgraph_node_values(&graph.node_values, &mapping);
+    let edges = EdgeList::with_max_node_id(edges, NI::new(mapping.len() - 1));
+    let subgraph = DirectedCsrGraph::from((node_values, edges, CsrLayout::Sorted));
 
-        Ok(graph)
-    }
+    (subgraph, mapping)
 }
 
-pub struct UndirectedCsrGraph<NI: Idx, NV = (), EV = ()> {
-    node_values: NodeValues<NV>,
-    csr: Csr<NI, NI, EV>,
-}
+impl<NI, NV, EV> FilterEdgesOp<NI, EV> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+{
+    type Filtered = DirectedCsrGraph<NI, NV, EV>;
 
-impl<NI: Idx, EV> From<Csr<NI, NI, EV>> for UndirectedCsrGraph<NI, (), EV> {
-    fn from(csr: Csr<NI, NI, EV>) -> Self {
-        UndirectedCsrGraph::new(NodeValues::new(vec![(); csr.node_count().index()]), csr)
+    fn filter_edges<P>(&self, predicate: P) -> Self::Filtered
+    where
+        P: Fn(NI, NI, EV) -> bool,
+    {
+        let mut edges = Vec::new();
+        for source in (0..self.node_count().index()).map(NI::new) {
+            for target in self.csr_out.targets_with_values(source) {
+                if predicate(source, target.target, target.value) {
+                    edges.push((source, target.target, target.value));
+                }
+            }
+        }
+
+        let node_values = NodeValues(self.node_values.0.clone());
+        let edges = EdgeList::with_max_node_id(edges, max_node_id(self.node_count()));
+        DirectedCsrGraph::from((node_values, edges, CsrLayout::Sorted))
     }
 }
 
-impl<NI: Idx, NV, EV> UndirectedCsrGraph<NI, NV, EV> {
-    pub fn new(node_values: NodeValues<NV>, csr: Csr<NI, NI, EV>) -> Self {
-        let g = Self { node_values, csr };
-        info!(
-            "Created undirected graph (node_count = {:?}, edge_count = {:?})",
-            g.node_count(),
-            g.edge_count()
-        );
+impl<NI, NV, EV, EV2> MapEdgeValuesOp<NI, EV, EV2> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Sync,
+    EV2: Send,
+{
+    type Mapped = DirectedCsrGraph<NI, NV, EV2>;
 
-        g
-    }
-}
+    fn map_edge_values<F>(&self, f: F) -> Self::Mapped
+    where
+        F: Fn(NI, NI, EV) -> EV2 + Sync,
+    {
+        let csr_out = self.csr_out.map_values(&f);
+        let csr_inc = self
+            .csr_inc
+            .map_values(|target, source, value| f(source, target, value));
 
-impl<NI: Idx, NV, EV> Graph<NI> for UndirectedCsrGraph<NI, NV, EV> {
-    fn node_count(&self) -> NI {
-        self.csr.node_count()
+        DirectedCsrGraph::new(NodeValues(self.node_values.0.clone()), csr_out, csr_inc)
     }
+}
 
-    fn edge_count(&self) -> NI {
-        self.csr.edge_count() / NI::new(2)
+impl<NI, NV, EV> GraphStatsOp for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    EV: Sync,
+{
+    fn stats(&self) -> GraphStats {
+        let node_count = self.node_count().index() as u64;
+        let edge_count = self.edge_count().index() as u64;
+
+        GraphStats {
+            node_count,
+            edge_count,
+            density: GraphStats::density(node_count, edge_count, true),
+            degrees: DegreeStats::from_degrees(self.csr_out.degrees()),
+            in_degrees: Some(DegreeStats::from_degrees(self.csr_inc.degrees())),
+            memory_bytes: self.node_values.memory_bytes()
+                + self.csr_out.memory_bytes()
+                + self.csr_inc.memory_bytes(),
+        }
     }
 }
 
-impl<NI: Idx, NV, EV> NodeValuesTrait<NI, NV> for UndirectedCsrGraph<NI, NV, EV> {
-    fn node_value(&self, node: NI) -> &NV {
+impl<NI, NV, EV> ValidateOp for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    EV: Sync,
+{
+    fn validate(&self, layout: CsrLayout) -> ValidationReport {
+        let start = Instant::now();
+        let mut report = ValidationReport::default();
+        let node_count = self.csr_out.offsets.len().saturating_sub(1);
+
+        if self.node_values.0.len() != node_count {
+            report.extend([Violation::NodeValueCount {
+                expected: node_count,
+                actual: self.node_values.0.len(),
+            }]);
+        }
+
+        let (out, inc) = (&self.csr_out, &self.csr_inc);
+        let out_valid = validate_csr(
+            &out.offsets,
+            &out.targets,
+            node_count,
+            layout,
+            Direction::Outgoing,
+            &mut report,
+        );
+        let inc_valid = validate_csr(
+            &inc.offsets,
+            &inc.targets,
+            node_count,
+            layout,
+            Direction::Incoming,
+            &mut report,
+        );
+
+        if out_valid && inc_valid {
+            let out_targets = sorted_targets(&out.offsets, &out.targets);
+            let inc_targets = sorted_targets(&inc.offsets, &inc.targets);
+            let out = (&out.offsets[..], &out_targets[..]);
+            let inc = (&inc.offsets[..], &inc_targets[..]);
+            report.extend(reverse_violations(out, inc, Direction::Outgoing));
+            report.extend(reverse_violations(inc, out, Direction::Incoming));
+        }
+
+        info!(
+            "Found {} violations in {:?}",
+            report.violation_count,
+            start.elapsed()
+        );
+
+        report
+    }
+}
+
+impl<NI, NV, EV> DegreeQueryOp<NI> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Sync,
+    EV: Sync,
+{
+    fn nodes_with_degree_in<R>(&self, range: R) -> Vec<NI>
+    where
+        R: RangeBounds<NI> + Sync,
+    {
+        nodes_with_degree_in(self.node_count(), range, |node| {
+            self.out_degree(node) + self.in_degree(node)
+        })
+    }
+
+    fn top_degree_nodes(&self, k: usize) -> Vec<NI> {
+        top_degree_nodes(self.node_count(), k, |node| {
+            self.out_degree(node) + self.in_degree(node)
+        })
+    }
+}
+
+impl<NI, NV, EV> RelabelOp<NI> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy,
+{
+    fn relabel(&mut self, permutation: &[NI]) {
+        let original = inverse_permutation(self.node_count(), permutation);
+        self.node_values = subgraph_node_values(&self.node_values, &original);
+        self.csr_out = self.csr_out.relabel(permutation, &original);
+        self.csr_inc = self.csr_inc.relabel(permutation, &original);
+    }
+}
+
+impl<W, NI, NV, EV> SerializeGraphOp<W> for DirectedCsrGraph<NI, NV, EV>
+where
+    W: Write,
+    NI: Idx + ToByteSlice,
+    NV: ToByteSlice,
+    EV: ToByteSlice,
+{
+    fn serialize(&self, mut output: W) -> Result<(), Error> {
+        let DirectedCsrGraph {
+            node_values,
+            csr_out,
+            csr_inc,
+        } = self;
+
+        node_values.serialize(&mut output)?;
+        csr_out.serialize(&mut output)?;
+        csr_inc.serialize(&mut output)?;
+
+        Ok(())
+    }
+}
+
+impl<R, NI, NV, EV> DeserializeGraphOp<R, Self> for DirectedCsrGraph<NI, NV, EV>
+where
+    R: Read,
+    NI: Idx + ToMutByteSlice,
+    NV: ToMutByteSlice,
+    EV: ToMutByteSlice,
+{
+    fn deserialize(mut read: R) -> Result<Self, Error> {
+        let node_values: NodeValues<NV> = NodeValues::deserialize(&mut read)?;
+        let csr_out: Csr<NI, NI, EV> = Csr::deserialize(&mut read)?;
+        let csr_inc: Csr<NI, NI, EV> = Csr::deserialize(&mut read)?;
+        Ok(DirectedCsrGraph::new(node_values, csr_out, csr_inc))
+    }
+}
+
+impl<NI, EV> TryFrom<(PathBuf, CsrLayout)> for DirectedCsrGraph<NI, EV>
+where
+    NI: Idx + ToMutByteSlice,
+    EV: ToMutByteSlice,
+{
+    type Error = Error;
+
+    fn try_from((path, _): (PathBuf, CsrLayout)) -> Result<Self, Self::Error> {
+        let reader = BufReader::new(File::open(path)?);
+        let graph = DirectedCsrGraph::deserialize(reader)?;
+
+        Ok(graph)
+    }
+}
+
+/// Serializes the graph as a byte buffer in the binary layout of
+/// [`SerializeGraphOp`], which depends on the byte order and the pointer
+/// width of the platform.
+#[cfg(feature = "serde")]
+#[cfg_attr(all(feature = "serde", has_doc_cfg), doc(cfg(feature = "serde")))]
+impl<NI, NV, EV> serde::Serialize for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx + ToByteSlice,
+    NV: ToByteSlice,
+    EV: ToByteSlice,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_binary(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(feature = "serde", has_doc_cfg), doc(cfg(feature = "serde")))]
+impl<'de, NI, NV, EV> serde::Deserialize<'de> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx + ToMutByteSlice,
+    NV: ToMutByteSlice,
+    EV: ToMutByteSlice,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_binary(deserializer)
+    }
+}
+
+pub struct UndirectedCsrGraph<NI: Idx, NV = (), EV = ()> {
+    node_values: NodeValues<NV>,
+    csr: Csr<NI, NI, EV>,
+}
+
+impl<NI: Idx, EV> From<Csr<NI, NI, EV>> for UndirectedCsrGraph<NI, (), EV> {
+    fn from(csr: Csr<NI, NI, EV>) -> Self {
+        UndirectedCsrGraph::new(NodeValues::new(vec![(); csr.node_count().index()]), csr)
+    }
+}
+
+impl<NI: Idx, NV, EV> UndirectedCsrGraph<NI, NV, EV> {
+    pub fn new(node_values: NodeValues<NV>, csr: Csr<NI, NI, EV>) -> Self {
+        let g = Self { node_values, csr };
+        info!(
+            "Created undirected graph (node_count = {:?}, edge_count = {:?})",
+            g.node_count(),
+            g.edge_count()
+        );
+
+        g
+    }
+    /// Returns the CSR that stores the neighbor lists.
+    ///
+    /// Every relationship is contained twice, once for each of its end nodes.
+    pub fn csr(&self) -> &Csr<NI, NI, EV> {
+        &self.csr
+    }
+}
+
+impl<NI: Idx, NV, EV> Graph<NI> for UndirectedCsrGraph<NI, NV, EV> {
+    fn node_count(&self) -> NI {
+        self.csr.node_count()
+    }
+
+    fn edge_count(&self) -> NI {
+        self.csr.edge_count() / NI::new(2)
+    }
+}
+
+impl<NI: Idx, NV, EV> NodeValuesTrait<NI, NV> for UndirectedCsrGraph<NI, NV, EV> {
+    fn node_value(&self, node: NI) -> &NV {
         &self.node_values.0[node.index()]
     }
 }
@@ -709,6 +1483,20 @@ impl<NI: Idx, NV, EV> UndirectedNeighborsWithValues<NI, EV> for UndirectedCsrGra
     }
 }
 
+impl<NI: Idx, NV, EV> UndirectedEdgeIds<NI> for UndirectedCsrGraph<NI, NV, EV> {
+    fn edge_id_count(&self) -> usize {
+        self.csr.edge_count().index()
+    }
+
+    fn edge_ids(&self, node: NI) -> Range<usize> {
+        self.csr.edge_ids(node)
+    }
+
+    fn edge_id(&self, source: NI, target: NI) -> Option<usize> {
+        self.csr.edge_id(source, target)
+    }
+}
+
 impl<NI: Idx, NV, EV> SwapCsr<NI, NI, EV> for UndirectedCsrGraph<NI, NV, EV> {
     fn swap_csr(&mut self, mut csr: Csr<NI, NI, EV>) -> &mut Self {
         std::mem::swap(&mut self.csr, &mut csr);
@@ -723,13 +1511,30 @@ where
     E: Edges<NI = NI, EV = EV>,
 {
     fn from((edge_list, csr_option): (E, CsrLayout)) -> Self {
+        UndirectedCsrGraph::from((edge_list, csr_option, CsrStrategy::default()))
+    }
+}
+
+impl<NI, EV, E> From<(E, CsrLayout, CsrStrategy)> for UndirectedCsrGraph<NI, (), EV>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+    E: Edges<NI = NI, EV = EV>,
+{
+    fn from((edge_list, csr_option, csr_strategy): (E, CsrLayout, CsrStrategy)) -> Self {
         info!("Creating undirected graph");
         let node_count = edge_list.max_node_id() + NI::new(1);
 
         let node_values = NodeValues::new(vec![(); node_count.index()]);
 
         let start = Instant::now();
-        let csr = Csr::from((&edge_list, node_count, Direction::Undirected, csr_option));
+        let csr = Csr::from((
+            &edge_list,
+            node_count,
+            Direction::Undirected,
+            csr_option,
+            csr_strategy,
+        ));
         info!("Created csr in {:?}.", start.elapsed());
 
         UndirectedCsrGraph::new(node_values, csr)
@@ -792,6 +1597,227 @@ where
     }
 }
 
+impl<NI, NV, EV> EgoSubgraphOp<NI> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx + Hash,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+{
+    type Subgraph = UndirectedCsrGraph<NI, NV, EV>;
+
+    fn ego_subgraph(&self, node: NI, radius: usize) -> (Self::Subgraph, Vec<NI>) {
+        let mut mapping = vec![node];
+        mapping.extend(self.neighbors_within(node, radius));
+        undirected_subgraph(self, mapping)
+    }
+}
+
+impl<NI, NV, EV> InducedSubgraphOp<NI> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx + Hash,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+{
+    type Subgraph = UndirectedCsrGraph<NI, NV, EV>;
+
+    fn induced_subgraph<P>(&self, predicate: P) -> (Self::Subgraph, Vec<NI>)
+    where
+        P: Fn(NI) -> bool,
+    {
+        undirected_subgraph(self, selected_nodes(self.node_count(), predicate))
+    }
+}
+
+fn undirected_subgraph<NI, NV, EV>(
+    graph: &UndirectedCsrGraph<NI, NV, EV>,
+    mapping: Vec<NI>,
+) -> (UndirectedCsrGraph<NI, NV, EV>, Vec<NI>)
+where
+    NI: Idx + Hash,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+{
+    let ids = subgraph_ids(&mapping);
+
+    let mut edges = Vec::new();
+    for (source, original) in mapping.iter().enumerate() {
+        let source = NI::new(source);
+        // Every relationship is stored at both of its end nodes, so we only
+        // keep it at the smaller one. Self-loops appear twice in the same
+        // neighbor list and are kept at every second occurrence.
+        let mut self_loops = 0;
+        for target in graph.csr.targets_with_values(*original) {
+            if let Some(&mapped) = ids.get(&target.target) {
+                if source == mapped {
+                    self_loops += 1;
+                    if self_loops % 2 == 1 {
+                        continue;
+                    }
+                }
+                if source <= mapped {
+                    edges.push((source, mapped, target.value));
+                }
+            }
+        }
+    }
+
+    let node_values = subgraph_node_values(&graph.node_values, &mapping);
+    let edges = EdgeList::with_max_node_id(edges, NI::new(mapping.len() - 1));
+    let subgraph = UndirectedCsrGraph::from((node_values, edges, CsrLayout::Sorted));
+
+    (subgraph, mapping)
+}
+
+impl<NI, NV, EV> FilterEdgesOp<NI, EV> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+{
+    type Filtered = UndirectedCsrGraph<NI, NV, EV>;
+
+    fn filter_edges<P>(&self, predicate: P) -> Self::Filtered
+    where
+        P: Fn(NI, NI, EV) -> bool,
+    {
+        let mut edges = Vec::new();
+        for source in (0..self.node_count().index()).map(NI::new) {
+            // See `undirected_subgraph` on why self-loops are kept at every
+            // second occurrence.
+            let mut self_loops = 0;
+            for target in self.csr.targets_with_values(source) {
+                if source == target.target {
+                    self_loops += 1;
+                    if self_loops % 2 == 1 {
+                        continue;
+                    }
+                }
+                if source <= target.target && predicate(source, target.target, target.value) {
+                    edges.push((source, target.target, target.value));
+                }
+            }
+        }
+
+        let node_values = NodeValues(self.node_values.0.clone());
+        let edges = EdgeList::with_max_node_id(edges, max_node_id(self.node_count()));
+        UndirectedCsrGraph::from((node_values, edges, CsrLayout::Sorted))
+    }
+}
+
+impl<NI, NV, EV, EV2> MapEdgeValuesOp<NI, EV, EV2> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Sync,
+    EV2: Send,
+{
+    type Mapped = UndirectedCsrGraph<NI, NV, EV2>;
+
+    fn map_edge_values<F>(&self, f: F) -> Self::Mapped
+    where
+        F: Fn(NI, NI, EV) -> EV2 + Sync,
+    {
+        // Both copies of an edge are mapped with the smaller node first.
+        let csr = self.csr.map_values(|node, target, value| {
+            f(NI::min(node, target), NI::max(node, target), value)
+        });
+
+        UndirectedCsrGraph::new(NodeValues(self.node_values.0.clone()), csr)
+    }
+}
+
+impl<NI, NV, EV> GraphStatsOp for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    EV: Sync,
+{
+    fn stats(&self) -> GraphStats {
+        let node_count = self.node_count().index() as u64;
+        let edge_count = self.edge_count().index() as u64;
+
+        GraphStats {
+            node_count,
+            edge_count,
+            density: GraphStats::density(node_count, edge_count, false),
+            degrees: DegreeStats::from_degrees(self.csr.degrees()),
+            in_degrees: None,
+            memory_bytes: self.node_values.memory_bytes() + self.csr.memory_bytes(),
+        }
+    }
+}
+
+impl<NI, NV, EV> ValidateOp for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    EV: Sync,
+{
+    fn validate(&self, layout: CsrLayout) -> ValidationReport {
+        let start = Instant::now();
+        let mut report = ValidationReport::default();
+        let node_count = self.csr.offsets.len().saturating_sub(1);
+
+        if self.node_values.0.len() != node_count {
+            report.extend([Violation::NodeValueCount {
+                expected: node_count,
+                actual: self.node_values.0.len(),
+            }]);
+        }
+
+        let csr = &self.csr;
+        if validate_csr(
+            &csr.offsets,
+            &csr.targets,
+            node_count,
+            layout,
+            Direction::Undirected,
+            &mut report,
+        ) {
+            let targets = sorted_targets(&csr.offsets, &csr.targets);
+            let csr = (&csr.offsets[..], &targets[..]);
+            report.extend(reverse_violations(csr, csr, Direction::Undirected));
+        }
+
+        info!(
+            "Found {} violations in {:?}",
+            report.violation_count,
+            start.elapsed()
+        );
+
+        report
+    }
+}
+
+impl<NI, NV, EV> DegreeQueryOp<NI> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Sync,
+    EV: Sync,
+{
+    fn nodes_with_degree_in<R>(&self, range: R) -> Vec<NI>
+    where
+        R: RangeBounds<NI> + Sync,
+    {
+        nodes_with_degree_in(self.node_count(), range, |node| self.degree(node))
+    }
+
+    fn top_degree_nodes(&self, k: usize) -> Vec<NI> {
+        top_degree_nodes(self.node_count(), k, |node| self.degree(node))
+    }
+}
+
+impl<NI, NV, EV> RelabelOp<NI> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy,
+{
+    fn relabel(&mut self, permutation: &[NI]) {
+        let original = inverse_permutation(self.node_count(), permutation);
+        self.node_values = subgraph_node_values(&self.node_values, &original);
+        self.csr = self.csr.relabel(permutation, &original);
+    }
+}
+
 impl<W, NI, NV, EV> SerializeGraphOp<W> for UndirectedCsrGraph<NI, NV, EV>
 where
     W: Write,
@@ -836,6 +1862,35 @@ where
     }
 }
 
+/// Serializes the graph as a byte buffer in the binary layout of
+/// [`SerializeGraphOp`], which depends on the byte order and the pointer
+/// width of the platform.
+#[cfg(feature = "serde")]
+#[cfg_attr(all(feature = "serde", has_doc_cfg), doc(cfg(feature = "serde")))]
+impl<NI, NV, EV> serde::Serialize for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx + ToByteSlice,
+    NV: ToByteSlice,
+    EV: ToByteSlice,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_binary(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(feature = "serde", has_doc_cfg), doc(cfg(feature = "serde")))]
+impl<'de, NI, NV, EV> serde::Deserialize<'de> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx + ToMutByteSlice,
+    NV: ToMutByteSlice,
+    EV: ToMutByteSlice,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_binary(deserializer)
+    }
+}
+
 fn prefix_sum_atomic<NI: Idx>(degrees: Vec<Atomic<NI>>) -> Vec<Atomic<NI>> {
     let mut last = degrees.last().unwrap().load(Acquire);
     let mut sums = degrees
@@ -951,6 +2006,63 @@ fn to_mut_slices<'targets, NI: Idx, T>(
     target_slices
 }
 
+// Assigns consecutive ids to the ego node and its neighborhood. Returns the
+// mapping from new to original ids and its inverse.
+fn selected_nodes<NI: Idx, P: Fn(NI) -> bool>(node_count: NI, predicate: P) -> Vec<NI> {
+    let mapping = (0..node_count.index())
+        .map(NI::new)
+        .filter(|node| predicate(*node))
+        .collect::<Vec<_>>();
+    assert!(!mapping.is_empty(), "predicate must select at least one node");
+    mapping
+}
+
+/// Returns the largest node id of a graph with the given node count, so that
+/// trailing nodes without edges are kept when rebuilding the graph.
+fn max_node_id<NI: Idx>(node_count: NI) -> NI {
+    NI::new(node_count.index().saturating_sub(1))
+}
+
+/// Maps the original node ids to subgraph node ids.
+fn subgraph_ids<NI: Idx + Hash>(mapping: &[NI]) -> FxHashMap<NI, NI> {
+    mapping
+        .iter()
+        .enumerate()
+        .map(|(new, original)| (*original, NI::new(new)))
+        .collect()
+}
+
+/// Returns the inverse of the given permutation of node ids.
+fn inverse_permutation<NI: Idx>(node_count: NI, permutation: &[NI]) -> Vec<NI> {
+    assert_eq!(
+        permutation.len(),
+        node_count.index(),
+        "permutation must contain one entry per node"
+    );
+
+    let mut original = vec![None; permutation.len()];
+    for (node, new) in permutation.iter().enumerate() {
+        let slot = original
+            .get_mut(new.index())
+            .expect("permutation must only contain node ids");
+        assert!(slot.is_none(), "permutation must not contain duplicates");
+        *slot = Some(NI::new(node));
+    }
+
+    // All slots are set, since there are as many distinct entries as nodes.
+    original.into_iter().flatten().collect()
+}
+
+fn subgraph_node_values<NI: Idx, NV: Clone>(
+    node_values: &NodeValues<NV>,
+    mapping: &[NI],
+) -> NodeValues<NV> {
+    mapping
+        .iter()
+        .map(|node| node_values.0[node.index()].clone())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -1027,6 +2139,43 @@ mod tests {
         assert_eq!(prefix_sum, vec![0, 42, 42, 1379, 1383, 1385, 1385]);
     }
 
+    #[test]
+    fn sort_based_csr_test() {
+        let edges = (0..10_000_usize)
+            .map(|i| (i * 7_919 % 1_009, i * 104_729 % 1_013, ()))
+            .chain([(3, 3, ()), (5, 7, ()), (5, 7, ())])
+            .collect::<Vec<_>>();
+        let edge_list = EdgeList::new(edges);
+        let node_count = edge_list.max_node_id() + 1;
+
+        for direction in [
+            Direction::Outgoing,
+            Direction::Incoming,
+            Direction::Undirected,
+        ] {
+            for (csr_layout, expected_layout) in [
+                (CsrLayout::Unsorted, CsrLayout::Sorted),
+                (CsrLayout::Sorted, CsrLayout::Sorted),
+                (CsrLayout::Deduplicated, CsrLayout::Deduplicated),
+            ] {
+                let expected = Csr::from((&edge_list, node_count, direction, expected_layout));
+
+                for sort_algorithm in [SortAlgorithm::Comparison, SortAlgorithm::Radix] {
+                    let csr = Csr::from((
+                        &edge_list,
+                        node_count,
+                        direction,
+                        csr_layout,
+                        CsrStrategy::SortBased(sort_algorithm),
+                    ));
+
+                    assert_eq!(csr.offsets, expected.offsets);
+                    assert_eq!(csr.targets, expected.targets);
+                }
+            }
+        }
+    }
+
     #[test]
     fn serialize_directed_usize_graph_test() {
         let mut file = tempfile::tempfile().unwrap();
@@ -1066,6 +2215,46 @@ mod tests {
         assert_eq!(g0.in_neighbors(3).as_slice(), g1.in_neighbors(3).as_slice());
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_directed_graph_test() {
+        let g0: DirectedCsrGraph<u32, u64, f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.5), (0, 2, 1.5), (2, 1, 2.5)])
+            .node_values(vec![4, 2, 1])
+            .build();
+
+        let json = serde_json::to_string(&g0).unwrap();
+        let g1: DirectedCsrGraph<u32, u64, f32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(g1.node_count(), 3);
+        assert_eq!(g1.edge_count(), 3);
+        assert_eq!(g1.node_value(0), &4);
+        assert_eq!(
+            g1.out_neighbors_with_values(0).as_slice(),
+            g0.out_neighbors_with_values(0).as_slice()
+        );
+        assert_eq!(
+            g1.in_neighbors_with_values(1).as_slice(),
+            g0.in_neighbors_with_values(1).as_slice()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_undirected_graph_with_invalid_id_type_test() {
+        let g0: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2)])
+            .build();
+
+        let json = serde_json::to_string(&g0).unwrap();
+        let g1: UndirectedCsrGraph<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(g1.neighbors(1).as_slice(), g0.neighbors(1).as_slice());
+
+        let result = serde_json::from_str::<UndirectedCsrGraph<u64>>(&json);
+        assert!(matches!(result, Err(e) if e.to_string().contains("invalid id size")));
+    }
+
     #[test]
     fn serialize_undirected_usize_graph_test() {
         let mut file = tempfile::tempfile().unwrap();
@@ -1176,6 +2365,47 @@ mod tests {
         assert!(matches!(res, _expected));
     }
 
+    #[test]
+    fn deserialize_corrupt_type_name() {
+        // no node values, followed by a type name of bogus length
+        let mut bytes = 0_usize.to_ne_bytes().to_vec();
+        bytes.extend_from_slice(&usize::MAX.to_ne_bytes());
+        bytes.extend_from_slice(&[0xff, 0xfe]);
+
+        let res = UndirectedCsrGraph::<u32>::deserialize(bytes.as_slice());
+
+        assert!(matches!(res, Err(Error::InvalidIdType { .. })));
+    }
+
+    #[test]
+    fn deserialize_corrupt_counts() {
+        let graph: DirectedCsrGraph<u32, u64> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 0)])
+            .node_values(vec![4, 2, 1])
+            .build();
+        let mut bytes = Vec::new();
+        graph.serialize(&mut bytes).unwrap();
+
+        // The node values are followed by the type name and the node count.
+        let type_name = std::any::type_name::<u32>();
+        let node_count = 2 * std::mem::size_of::<usize>() + 3 * 8 + type_name.len();
+        for (position, count) in [
+            // node values
+            (0, usize::MAX.to_ne_bytes().to_vec()),
+            // node count
+            (node_count, u32::MAX.to_ne_bytes().to_vec()),
+            // edge count
+            (node_count + 4, (u32::MAX - 1).to_ne_bytes().to_vec()),
+        ] {
+            let mut bytes = bytes.clone();
+            bytes[position..position + count.len()].copy_from_slice(&count);
+
+            let res = DirectedCsrGraph::<u32, u64>::deserialize(bytes.as_slice());
+
+            assert!(matches!(res, Err(Error::IoError { .. })));
+        }
+    }
+
     #[test]
     fn test_to_undirected() {
         // we need a deterministic order of loading, so we're doing stuff in serial
@@ -1238,4 +2468,308 @@ mod tests {
         assert_eq!(g0.degree(2), 1);
         assert_eq!(g0.degree(3), 0);
     }
+
+    #[test]
+    fn directed_ego_subgraph() {
+        let g: DirectedCsrGraph<u32, u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 0)])
+            .node_values(vec![10, 11, 12, 13, 14])
+            .build();
+
+        let (ego, mapping) = g.ego_subgraph(1, 2);
+
+        assert_eq!(mapping, vec![1, 2, 0, 3]);
+        assert_eq!(ego.node_count(), 4);
+        assert_eq!(ego.edge_count(), 4);
+        assert_eq!(ego.node_value(0), &11);
+        assert_eq!(ego.node_value(3), &13);
+
+        assert_eq!(ego.out_neighbors(0).as_slice(), &[1]);
+        assert_eq!(ego.out_neighbors(1).as_slice(), &[2, 3]);
+        assert_eq!(ego.out_neighbors(2).as_slice(), &[0]);
+        assert_eq!(ego.out_neighbors(3).as_slice(), &[] as &[u32]);
+    }
+
+    #[test]
+    fn directed_induced_subgraph() {
+        let g: DirectedCsrGraph<u32, u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 0)])
+            .node_values(vec![10, 11, 12, 13, 14])
+            .build();
+
+        let (sub, mapping) = g.induced_subgraph(|node| node != 1);
+
+        assert_eq!(mapping, vec![0, 2, 3, 4]);
+        assert_eq!(sub.node_count(), 4);
+        assert_eq!(sub.edge_count(), 4);
+        assert_eq!(sub.node_value(1), &12);
+
+        assert_eq!(sub.out_neighbors(0).as_slice(), &[] as &[u32]);
+        assert_eq!(sub.out_neighbors(1).as_slice(), &[0, 2]);
+        assert_eq!(sub.out_neighbors(2).as_slice(), &[3]);
+        assert_eq!(sub.out_neighbors(3).as_slice(), &[0]);
+        assert_eq!(sub.in_neighbors(0).as_slice(), &[1, 3]);
+    }
+
+    #[test]
+    fn undirected_induced_subgraph() {
+        let g: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![
+                (0, 1, 0.1),
+                (1, 1, 1.1),
+                (1, 2, 1.2),
+                (2, 3, 2.3),
+                (0, 3, 0.3),
+            ])
+            .build();
+
+        let (sub, mapping) = g.induced_subgraph(|node| node != 2);
+
+        assert_eq!(mapping, vec![0, 1, 3]);
+        assert_eq!(sub.edge_count(), 3);
+        assert_eq!(
+            sub.neighbors_with_values(1).as_slice(),
+            &[
+                Target::new(0, 0.1),
+                Target::new(1, 1.1),
+                Target::new(1, 1.1)
+            ]
+        );
+        assert_eq!(
+            sub.neighbors_with_values(2).as_slice(),
+            &[Target::new(0, 0.3)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "predicate must select at least one node")]
+    fn induced_subgraph_without_nodes() {
+        let g: UndirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+        let _ = g.induced_subgraph(|_| false);
+    }
+
+    #[test]
+    fn undirected_ego_subgraph() {
+        let g: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![
+                (0, 1, 0.1),
+                (1, 1, 1.1),
+                (1, 2, 1.2),
+                (2, 3, 2.3),
+                (0, 3, 0.3),
+            ])
+            .build();
+
+        let (ego, mapping) = g.ego_subgraph(1, 1);
+
+        assert_eq!(mapping, vec![1, 0, 2]);
+        assert_eq!(ego.node_count(), 3);
+        assert_eq!(ego.edge_count(), 3);
+
+        assert_eq!(
+            ego.neighbors_with_values(0).as_slice(),
+            &[
+                Target::new(0, 1.1),
+                Target::new(0, 1.1),
+                Target::new(1, 0.1),
+                Target::new(2, 1.2)
+            ]
+        );
+        assert_eq!(
+            ego.neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 0.1)]
+        );
+    }
+
+    #[test]
+    fn directed_relabel() {
+        let mut g: DirectedCsrGraph<u32, u32, f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.1), (0, 2, 0.2), (1, 2, 1.2)])
+            .node_values(vec![10, 11, 12])
+            .build();
+
+        g.relabel(&[2, 0, 1]);
+
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 3);
+        assert_eq!(*g.node_value(0), 11);
+        assert_eq!(*g.node_value(2), 10);
+        assert_eq!(
+            g.out_neighbors_with_values(2).as_slice(),
+            &[Target::new(0, 0.1), Target::new(1, 0.2)]
+        );
+        assert_eq!(
+            g.in_neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 1.2), Target::new(2, 0.2)]
+        );
+    }
+
+    #[test]
+    fn undirected_relabel() {
+        let mut g: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 2)])
+            .build();
+
+        g.relabel(&[1, 2, 0]);
+
+        assert_eq!(g.edge_count(), 3);
+        assert_eq!(g.neighbors(0).as_slice(), &[0, 0, 2]);
+        assert_eq!(g.neighbors(1).as_slice(), &[2]);
+        assert_eq!(g.neighbors(2).as_slice(), &[0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation must not contain duplicates")]
+    fn relabel_with_invalid_permutation() {
+        let mut g: UndirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+        g.relabel(&[0, 0]);
+    }
+
+    #[test]
+    fn undirected_filter_edges() {
+        let g: UndirectedCsrGraph<u32, (), u32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 1), (1, 2, 2), (2, 2, 3), (3, 1, 4)])
+            .build();
+
+        let filtered = g.filter_edges(|_, _, value| value >= 2);
+
+        assert_eq!(filtered.node_count(), 4);
+        assert_eq!(filtered.edge_count(), 3);
+        assert_eq!(filtered.degree(0), 0);
+        assert_eq!(
+            filtered.neighbors_with_values(1).as_slice(),
+            &[Target::new(2, 2), Target::new(3, 4)]
+        );
+        assert_eq!(
+            filtered.neighbors_with_values(2).as_slice(),
+            &[Target::new(1, 2), Target::new(2, 3), Target::new(2, 3)]
+        );
+    }
+
+    #[test]
+    fn directed_filter_edges_keeps_isolated_nodes() {
+        let g: DirectedCsrGraph<u32, (), u32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 1), (1, 2, 2), (2, 3, 3)])
+            .build();
+
+        let filtered = g.filter_edges(|source, _, _| source == 0);
+
+        assert_eq!(filtered.node_count(), 4);
+        assert_eq!(filtered.edge_count(), 1);
+        assert_eq!(filtered.in_degree(3), 0);
+    }
+
+    #[test]
+    fn directed_map_edge_values() {
+        let g: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.5), (0, 2, 4.0), (2, 1, 2.0)])
+            .build();
+
+        let mapped =
+            g.map_edge_values(|source, target, value| (source * 10 + target) as f32 / value);
+
+        assert_eq!(mapped.edge_count(), 3);
+        assert_eq!(
+            mapped.out_neighbors_with_values(0).as_slice(),
+            &[Target::new(1, 2.0), Target::new(2, 0.5)]
+        );
+        assert_eq!(
+            mapped.in_neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 2.0), Target::new(2, 10.5)]
+        );
+    }
+
+    #[test]
+    fn undirected_map_edge_values() {
+        let g: UndirectedCsrGraph<u32, (), u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 1), (2, 1, 2)])
+            .build();
+
+        let mapped = g.map_edge_values(|source, target, value| (source, target, value > 1));
+
+        assert_eq!(
+            mapped.neighbors_with_values(1).as_slice(),
+            &[Target::new(0, (0, 1, false)), Target::new(2, (1, 2, true))]
+        );
+        assert_eq!(
+            mapped.neighbors_with_values(2).as_slice(),
+            &[Target::new(1, (1, 2, true))]
+        );
+    }
+
+    #[test]
+    fn undirected_stats() {
+        let g: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (0, 2), (0, 3)])
+            .build();
+
+        let stats = g.stats();
+
+        assert_eq!(stats.node_count, 4);
+        assert_eq!(stats.edge_count, 3);
+        assert_eq!(stats.density, 0.5);
+        assert_eq!(stats.degrees.min, 1);
+        assert_eq!(stats.degrees.max, 3);
+        assert_eq!(stats.degrees.mean, 1.5);
+        assert_eq!(stats.in_degrees, None);
+        // 5 offsets and 6 targets
+        assert_eq!(stats.memory_bytes, 11 * std::mem::size_of::<u32>());
+    }
+
+    #[test]
+    fn directed_degree_queries() {
+        let g: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (0, 2), (0, 3), (1, 2), (4, 0)])
+            .build();
+
+        assert_eq!(g.nodes_with_degree_in(2..=2), vec![1, 2]);
+        assert_eq!(g.nodes_with_degree_in(..), vec![0, 1, 2, 3, 4]);
+        assert_eq!(g.nodes_with_degree_in(5..), Vec::<u32>::new());
+        assert_eq!(g.top_degree_nodes(3), vec![0, 1, 2]);
+        assert_eq!(g.top_degree_nodes(0), Vec::<u32>::new());
+        assert_eq!(g.top_degree_nodes(10), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn undirected_degree_queries() {
+        // a star around node 0 and a path behind node 5
+        let mut edges = (1..100).map(|n| (0, n)).collect::<Vec<_>>();
+        edges.extend([(5, 100), (100, 101), (101, 102)]);
+        let g: UndirectedCsrGraph<u32> = GraphBuilder::new().edges(edges).build();
+
+        assert_eq!(g.nodes_with_degree_in(2..3), vec![5, 100, 101]);
+        assert_eq!(g.nodes_with_degree_in(10..), vec![0]);
+        assert_eq!(g.top_degree_nodes(4), vec![0, 5, 100, 101]);
+    }
+
+    #[test]
+    fn csr_from_invalid_raw_parts() {
+        assert!(Csr::<u32, u32, ()>::from_raw_parts(vec![], vec![], vec![]).is_err());
+        assert!(Csr::<u32, u32, ()>::from_raw_parts(vec![0, 2, 1], vec![0], vec![()]).is_err());
+        assert!(Csr::<u32, u32, ()>::from_raw_parts(vec![0, 1], vec![1], vec![()]).is_err());
+        assert!(Csr::<u32, u32, ()>::from_raw_parts(vec![0, 1], vec![0], vec![]).is_err());
+    }
+
+    #[test]
+    fn csr_transpose() {
+        let csr = Csr::<u32, u32, u32>::from_raw_parts(
+            vec![0, 2, 3, 3, 4],
+            vec![2, 1, 2, 0],
+            vec![10, 20, 30, 40],
+        )
+        .unwrap();
+
+        let (offsets, targets, values) = csr.transpose().into_raw_parts();
+
+        assert_eq!(offsets, vec![0, 1, 2, 4, 4]);
+        assert_eq!(targets, vec![3, 0, 0, 1]);
+        assert_eq!(values, vec![40, 20, 10, 30]);
+    }
 }
@@ -0,0 +1,402 @@
+//! A directed graph that supports batched edge insertions and deletions.
+//!
+//! CSR graphs are immutable and rebuilding them for every small batch of
+//! updates is expensive. A [`DeltaGraph`] instead buffers updates in a delta
+//! structure next to the CSR base graph. Reads combine the base graph and the
+//! delta. Once the delta grows beyond a configurable fraction of the base
+//! graph, both are merged into a new CSR graph. This amortizes the cost of the
+//! rebuild over many batches, similar to a log-structured merge tree.
+
+use fxhash::{FxHashMap, FxHashSet};
+use log::info;
+use rayon::prelude::*;
+
+use crate::{
+    index::Idx, input::edgelist::EdgeList, time::Instant, CsrLayout, DirectedCsrGraph,
+    DirectedDegrees, DirectedNeighborsWithValues, Graph, Target,
+};
+
+/// The default fraction of pending updates relative to the base edge count
+/// that triggers a merge.
+pub const DEFAULT_MERGE_THRESHOLD: f64 = 0.1;
+
+/// A directed graph that buffers edge insertions and deletions and merges them
+/// lazily into its CSR representation.
+///
+/// Updates are applied via [`DeltaGraph::apply_batch`]. Within a batch,
+/// deletions are applied before additions. Deleting an edge `(u, v)` removes
+/// all parallel edges from `u` to `v`. Adding an edge with a node id larger
+/// than the current maximum node id grows the graph.
+///
+/// The graph implements the same traversal traits as [`DirectedCsrGraph`], so
+/// algorithms can run on it without merging first. Buffered edges are
+/// returned after the edges of the base graph, i.e., neighbor lists are only
+/// sorted or deduplicated according to the [`CsrLayout`] after a merge.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .edges(vec![(0, 1), (0, 2), (1, 2)])
+///     .build();
+///
+/// let mut graph = DeltaGraph::new(graph).with_merge_threshold(1.0);
+///
+/// graph.apply_batch(vec![(2, 3, ()), (3, 0, ())], vec![(0, 2)]);
+///
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 4);
+/// assert_eq!(graph.pending_updates(), 3);
+///
+/// graph.compact();
+///
+/// assert_eq!(graph.pending_updates(), 0);
+/// assert_eq!(graph.out_degree(0), 1);
+/// assert_eq!(graph.in_degree(0), 1);
+/// ```
+pub struct DeltaGraph<NI: Idx, EV = ()> {
+    base: DirectedCsrGraph<NI, (), EV>,
+    layout: CsrLayout,
+    merge_threshold: f64,
+    node_count: usize,
+    out_added: FxHashMap<usize, Vec<Target<NI, EV>>>,
+    inc_added: FxHashMap<usize, Vec<Target<NI, EV>>>,
+    added_count: usize,
+    // (source, target) pairs whose edges in the base graph are deleted
+    deleted: FxHashSet<(usize, usize)>,
+    deleted_count: usize,
+}
+
+impl<NI, EV> DeltaGraph<NI, EV>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+{
+    /// Creates a new delta graph on top of the given base graph.
+    pub fn new(base: DirectedCsrGraph<NI, (), EV>) -> Self {
+        let node_count = base.node_count().index();
+
+        Self {
+            base,
+            layout: CsrLayout::default(),
+            merge_threshold: DEFAULT_MERGE_THRESHOLD,
+            node_count,
+            out_added: FxHashMap::default(),
+            inc_added: FxHashMap::default(),
+            added_count: 0,
+            deleted: FxHashSet::default(),
+            deleted_count: 0,
+        }
+    }
+
+    /// Sets the layout of the CSR graph created by a merge.
+    pub fn with_csr_layout(mut self, layout: CsrLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the fraction of pending updates relative to the edge count of
+    /// the base graph that triggers a merge.
+    ///
+    /// A threshold of `0.0` merges after each batch.
+    pub fn with_merge_threshold(mut self, merge_threshold: f64) -> Self {
+        self.merge_threshold = merge_threshold;
+        self
+    }
+
+    /// Returns the base graph, which does not contain pending updates.
+    pub fn base(&self) -> &DirectedCsrGraph<NI, (), EV> {
+        &self.base
+    }
+
+    /// Returns the number of buffered edge additions and deleted base edges.
+    pub fn pending_updates(&self) -> usize {
+        self.added_count + self.deleted_count
+    }
+
+    /// Applies a batch of edge deletions followed by a batch of edge
+    /// additions.
+    ///
+    /// If the pending updates exceed the merge threshold afterwards, they are
+    /// merged into the base graph. Returns `true` if a merge happened.
+    pub fn apply_batch<A, D>(&mut self, additions: A, deletions: D) -> bool
+    where
+        A: IntoIterator<Item = (NI, NI, EV)>,
+        D: IntoIterator<Item = (NI, NI)>,
+    {
+        for (source, target) in deletions {
+            self.delete(source, target);
+        }
+
+        for (source, target, value) in additions {
+            self.insert(source, target, value);
+        }
+
+        let threshold = self.merge_threshold * self.base.edge_count().index() as f64;
+        if self.pending_updates() as f64 > threshold {
+            self.compact();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Merges all pending updates into the base graph.
+    pub fn compact(&mut self) {
+        if self.pending_updates() == 0 && self.node_count == self.base.node_count().index() {
+            return;
+        }
+
+        let start = Instant::now();
+        let base = &self.base;
+        let deleted = &self.deleted;
+
+        let mut edges = (0..base.node_count().index())
+            .into_par_iter()
+            .flat_map_iter(|source| {
+                base.out_neighbors_with_values(NI::new(source))
+                    .filter(move |t| !deleted.contains(&(source, t.target.index())))
+                    .map(move |t| (NI::new(source), t.target, t.value))
+            })
+            .collect::<Vec<_>>();
+
+        edges.extend(self.out_added.drain().flat_map(|(source, targets)| {
+            targets
+                .into_iter()
+                .map(move |t| (NI::new(source), t.target, t.value))
+        }));
+
+        let edges = EdgeList::with_max_node_id(edges, NI::new(self.node_count - 1));
+        self.base = DirectedCsrGraph::from((edges, self.layout));
+
+        info!(
+            "Merged {} pending updates in {:?}",
+            self.pending_updates(),
+            start.elapsed()
+        );
+
+        self.inc_added.clear();
+        self.added_count = 0;
+        self.deleted.clear();
+        self.deleted_count = 0;
+    }
+
+    /// Merges all pending updates and returns the resulting CSR graph.
+    pub fn into_graph(mut self) -> DirectedCsrGraph<NI, (), EV> {
+        self.compact();
+        self.base
+    }
+
+    fn insert(&mut self, source: NI, target: NI, value: EV) {
+        self.node_count = self
+            .node_count
+            .max(source.index() + 1)
+            .max(target.index() + 1);
+
+        self.out_added
+            .entry(source.index())
+            .or_default()
+            .push(Target::new(target, value));
+        self.inc_added
+            .entry(target.index())
+            .or_default()
+            .push(Target::new(source, value));
+        self.added_count += 1;
+    }
+
+    fn delete(&mut self, source: NI, target: NI) {
+        let (s, t) = (source.index(), target.index());
+
+        if let Some(targets) = self.out_added.get_mut(&s) {
+            let len = targets.len();
+            targets.retain(|other| other.target != target);
+            self.added_count -= len - targets.len();
+        }
+        if let Some(sources) = self.inc_added.get_mut(&t) {
+            sources.retain(|other| other.target != source);
+        }
+
+        if s < self.base.node_count().index() && !self.deleted.contains(&(s, t)) {
+            let count = self
+                .base
+                .out_neighbors_with_values(source)
+                .filter(|other| other.target == target)
+                .count();
+
+            if count > 0 {
+                self.deleted.insert((s, t));
+                self.deleted_count += count;
+            }
+        }
+    }
+}
+
+impl<NI, EV> From<DirectedCsrGraph<NI, (), EV>> for DeltaGraph<NI, EV>
+where
+    NI: Idx,
+    EV: Copy + Send + Sync,
+{
+    fn from(base: DirectedCsrGraph<NI, (), EV>) -> Self {
+        Self::new(base)
+    }
+}
+
+impl<NI: Idx, EV> DeltaGraph<NI, EV> {
+    fn neighbors(&self, node: NI, incoming: bool) -> DeltaNeighbors<'_, NI, EV> {
+        let (base, added) = if incoming {
+            (self.base.csr_inc(), &self.inc_added)
+        } else {
+            (self.base.csr_out(), &self.out_added)
+        };
+
+        let base = if node.index() < self.base.node_count().index() {
+            base.targets_with_values(node)
+        } else {
+            &[]
+        };
+        let added = added.get(&node.index()).map_or(&[][..], Vec::as_slice);
+
+        DeltaNeighbors {
+            node: node.index(),
+            base: base.iter(),
+            added: added.iter(),
+            deleted: &self.deleted,
+            incoming,
+        }
+    }
+}
+
+impl<NI: Idx, EV> Graph<NI> for DeltaGraph<NI, EV> {
+    fn node_count(&self) -> NI {
+        NI::new(self.node_count)
+    }
+
+    fn edge_count(&self) -> NI {
+        NI::new(self.base.edge_count().index() + self.added_count - self.deleted_count)
+    }
+}
+
+impl<NI: Idx, EV> DirectedDegrees<NI> for DeltaGraph<NI, EV> {
+    fn out_degree(&self, node: NI) -> NI {
+        NI::new(self.out_neighbors_with_values(node).count())
+    }
+
+    fn in_degree(&self, node: NI) -> NI {
+        NI::new(self.in_neighbors_with_values(node).count())
+    }
+}
+
+impl<NI: Idx, EV> DirectedNeighborsWithValues<NI, EV> for DeltaGraph<NI, EV> {
+    type NeighborsIterator<'a> = DeltaNeighbors<'a, NI, EV> where EV: 'a;
+
+    fn out_neighbors_with_values(&self, node: NI) -> Self::NeighborsIterator<'_> {
+        self.neighbors(node, false)
+    }
+
+    fn in_neighbors_with_values(&self, node: NI) -> Self::NeighborsIterator<'_> {
+        self.neighbors(node, true)
+    }
+}
+
+/// Iterates the neighbors of a node in a [`DeltaGraph`], skipping deleted
+/// edges of the base graph and including buffered additions.
+pub struct DeltaNeighbors<'a, NI, EV> {
+    node: usize,
+    base: std::slice::Iter<'a, Target<NI, EV>>,
+    added: std::slice::Iter<'a, Target<NI, EV>>,
+    deleted: &'a FxHashSet<(usize, usize)>,
+    incoming: bool,
+}
+
+impl<'a, NI: Idx, EV> Iterator for DeltaNeighbors<'a, NI, EV> {
+    type Item = &'a Target<NI, EV>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for t in self.base.by_ref() {
+            let edge = if self.incoming {
+                (t.target.index(), self.node)
+            } else {
+                (self.node, t.target.index())
+            };
+            if !self.deleted.contains(&edge) {
+                return Some(t);
+            }
+        }
+        self.added.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn targets<'a>(neighbors: impl Iterator<Item = &'a Target<u32, f32>>) -> Vec<(u32, f32)> {
+        let mut targets = neighbors.map(|t| (t.target, t.value)).collect::<Vec<_>>();
+        targets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        targets
+    }
+
+    #[test]
+    fn delta_graph_reads_pending_updates() {
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.5), (0, 1, 0.25), (0, 2, 1.0), (1, 2, 2.0)])
+            .build();
+
+        let mut graph = DeltaGraph::new(graph).with_merge_threshold(10.0);
+
+        let merged = graph.apply_batch(vec![(0, 1, 4.0), (4, 0, 3.0)], vec![(0, 1), (1, 0)]);
+
+        assert!(!merged);
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.pending_updates(), 4);
+        assert_eq!(
+            targets(graph.out_neighbors_with_values(0)),
+            vec![(1, 4.0), (2, 1.0)]
+        );
+        assert_eq!(targets(graph.in_neighbors_with_values(0)), vec![(4, 3.0)]);
+        assert_eq!(targets(graph.in_neighbors_with_values(1)), vec![(0, 4.0)]);
+        assert_eq!(graph.out_degree(3), 0);
+    }
+
+    #[test]
+    fn delta_graph_deletes_pending_additions() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+
+        let mut graph = DeltaGraph::new(graph).with_merge_threshold(10.0);
+
+        graph.apply_batch(vec![(1, 2, ()), (1, 2, ())], vec![]);
+        graph.apply_batch(vec![], vec![(1, 2)]);
+
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.pending_updates(), 0);
+        assert_eq!(graph.in_degree(2), 0);
+        assert_eq!(graph.node_count(), 3);
+    }
+
+    #[test]
+    fn delta_graph_merges_above_threshold() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 2), (2, 3), (3, 0)])
+            .build();
+
+        let mut graph = DeltaGraph::new(graph)
+            .with_csr_layout(CsrLayout::Sorted)
+            .with_merge_threshold(0.5);
+
+        assert!(!graph.apply_batch(vec![(0, 2, ())], vec![]));
+        assert!(graph.apply_batch(vec![(0, 3, ())], vec![(2, 3)]));
+        assert_eq!(graph.pending_updates(), 0);
+
+        let graph = graph.into_graph();
+
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(graph.out_neighbors(0).as_slice(), &[1, 2, 3]);
+        assert_eq!(graph.out_degree(2), 0);
+    }
+}
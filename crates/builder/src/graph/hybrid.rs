@@ -0,0 +1,323 @@
+//! A view that indexes the neighbors of high-degree nodes.
+//!
+//! Membership tests and intersections of neighbor lists are linear in the
+//! degree of the nodes involved, which makes them expensive for the few
+//! nodes with a very high degree in graphs with a power-law degree
+//! distribution, e.g., celebrities in a social network. [`HybridView`] keeps
+//! the CSR neighbor lists for all nodes and additionally indexes the
+//! neighbors of every node whose degree reaches a threshold, so that
+//! membership tests for these nodes take constant time.
+
+use fxhash::{FxHashMap, FxHashSet};
+use rayon::prelude::*;
+
+use crate::{index::Idx, Graph, NodeValues, UndirectedDegrees, UndirectedNeighbors};
+
+/// The neighbors of a single high-degree node.
+#[derive(Debug)]
+enum NeighborIndex {
+    /// One bit per node of the graph, used if the node is adjacent to a
+    /// large fraction of the graph.
+    Bitset(Box<[u64]>),
+    Hash(FxHashSet<usize>),
+}
+
+impl NeighborIndex {
+    fn new<NI: Idx>(node_count: usize, degree: usize, neighbors: impl Iterator<Item = NI>) -> Self {
+        // A bitset needs `node_count / 8` bytes, a hash set roughly
+        // `16 * degree` bytes.
+        if node_count / 128 <= degree {
+            let mut bits = vec![0_u64; (node_count + 63) / 64].into_boxed_slice();
+            for neighbor in neighbors {
+                let neighbor = neighbor.index();
+                bits[neighbor / 64] |= 1 << (neighbor % 64);
+            }
+            NeighborIndex::Bitset(bits)
+        } else {
+            NeighborIndex::Hash(neighbors.map(NI::index).collect())
+        }
+    }
+
+    #[inline]
+    fn contains(&self, node: usize) -> bool {
+        match self {
+            NeighborIndex::Bitset(bits) => bits[node / 64] & (1 << (node % 64)) != 0,
+            NeighborIndex::Hash(set) => set.contains(&node),
+        }
+    }
+}
+
+/// A view of an undirected graph that additionally indexes the neighbors of
+/// all nodes with a degree of at least `threshold`.
+///
+/// The neighbors of an indexed node are stored in a bitset if the node is
+/// adjacent to a large fraction of the graph and in a hash set otherwise.
+/// [`contains_edge`](Self::contains_edge) and
+/// [`common_neighbor_count`](Self::common_neighbor_count) look up neighbors
+/// in the index of the involved node with the higher degree, if it is
+/// indexed, and otherwise fall back to scanning the neighbor lists. The view
+/// implements the same graph traits as the underlying graph, so algorithms
+/// that only traverse neighbor lists run on it unchanged.
+///
+/// Neighbor lists must not contain duplicates for exact counts, i.e., the
+/// graph should be built with [`CsrLayout::Deduplicated`](crate::CsrLayout::Deduplicated).
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Deduplicated)
+///     .edges(vec![(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (3, 4)])
+///     .build();
+///
+/// let view = HybridView::new(&graph, 3);
+///
+/// assert!(view.is_indexed(0));
+/// assert!(!view.is_indexed(1));
+/// assert!(view.contains_edge(2, 0));
+/// assert!(!view.contains_edge(1, 3));
+/// assert_eq!(view.common_neighbor_count(0, 1), 1);
+/// assert_eq!(view.jaccard_similarity(1, 2), 1.0 / 3.0);
+/// ```
+#[derive(Debug)]
+pub struct HybridView<'g, G> {
+    graph: &'g G,
+    threshold: usize,
+    index: FxHashMap<usize, NeighborIndex>,
+}
+
+impl<'g, G> HybridView<'g, G> {
+    /// Creates the view and indexes the neighbors of all nodes with a degree
+    /// of at least `threshold` in parallel.
+    pub fn new<NI>(graph: &'g G, threshold: usize) -> Self
+    where
+        NI: Idx,
+        G: Graph<NI> + UndirectedDegrees<NI> + UndirectedNeighbors<NI> + Sync,
+    {
+        let node_count = graph.node_count().index();
+        let index = (0..node_count)
+            .into_par_iter()
+            .filter_map(|node| {
+                let degree = graph.degree(NI::new(node)).index();
+                (degree >= threshold).then(|| {
+                    let neighbors = graph.neighbors(NI::new(node)).copied();
+                    (node, NeighborIndex::new(node_count, degree, neighbors))
+                })
+            })
+            .collect();
+
+        Self {
+            graph,
+            threshold,
+            index,
+        }
+    }
+
+    /// Returns the underlying graph.
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+
+    /// Returns the degree from which on nodes are indexed.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the number of indexed nodes.
+    pub fn indexed_node_count(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns `true` if the neighbors of the node are indexed.
+    pub fn is_indexed<NI: Idx>(&self, node: NI) -> bool {
+        self.index.contains_key(&node.index())
+    }
+
+    /// Returns `true` if the nodes `u` and `v` are connected.
+    ///
+    /// If either node is indexed, this takes constant time. Otherwise, the
+    /// neighbors of the node with the lower degree are scanned.
+    pub fn contains_edge<NI>(&self, u: NI, v: NI) -> bool
+    where
+        NI: Idx,
+        G: UndirectedDegrees<NI> + UndirectedNeighbors<NI>,
+    {
+        if let Some(index) = self.index.get(&u.index()) {
+            return index.contains(v.index());
+        }
+        if let Some(index) = self.index.get(&v.index()) {
+            return index.contains(u.index());
+        }
+
+        let (small, other) = if self.graph.degree(u) <= self.graph.degree(v) {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        self.graph.neighbors(small).any(|&w| w == other)
+    }
+
+    /// Returns the number of neighbors that `u` and `v` have in common.
+    ///
+    /// The neighbors of the node with the lower degree are looked up in the
+    /// index of the other node, if it is indexed. Otherwise, the neighbor
+    /// lists of both nodes are intersected using a temporary hash set, which
+    /// is bounded by the threshold.
+    pub fn common_neighbor_count<NI>(&self, u: NI, v: NI) -> usize
+    where
+        NI: Idx,
+        G: UndirectedDegrees<NI> + UndirectedNeighbors<NI>,
+    {
+        let (small, large) = if self.graph.degree(u) <= self.graph.degree(v) {
+            (u, v)
+        } else {
+            (v, u)
+        };
+
+        match self.index.get(&large.index()) {
+            Some(index) => self
+                .graph
+                .neighbors(small)
+                .filter(|w| index.contains(w.index()))
+                .count(),
+            None => {
+                let large_neighbors = self
+                    .graph
+                    .neighbors(large)
+                    .map(|w| w.index())
+                    .collect::<FxHashSet<_>>();
+                self.graph
+                    .neighbors(small)
+                    .filter(|w| large_neighbors.contains(&w.index()))
+                    .count()
+            }
+        }
+    }
+
+    /// Returns the Jaccard similarity of the neighborhoods of `u` and `v`,
+    /// i.e., the number of common neighbors divided by the number of nodes
+    /// that are a neighbor of either node, or `0` if neither node has any
+    /// neighbors.
+    pub fn jaccard_similarity<NI>(&self, u: NI, v: NI) -> f64
+    where
+        NI: Idx,
+        G: UndirectedDegrees<NI> + UndirectedNeighbors<NI>,
+    {
+        let common = self.common_neighbor_count(u, v);
+        let union = self.graph.degree(u).index() + self.graph.degree(v).index() - common;
+
+        if union == 0 {
+            0.0
+        } else {
+            common as f64 / union as f64
+        }
+    }
+}
+
+impl<NI: Idx, G: Graph<NI>> Graph<NI> for HybridView<'_, G> {
+    fn node_count(&self) -> NI {
+        self.graph.node_count()
+    }
+
+    fn edge_count(&self) -> NI {
+        self.graph.edge_count()
+    }
+}
+
+impl<NI: Idx, NV, G: NodeValues<NI, NV>> NodeValues<NI, NV> for HybridView<'_, G> {
+    fn node_value(&self, node: NI) -> &NV {
+        self.graph.node_value(node)
+    }
+}
+
+impl<NI: Idx, G: UndirectedDegrees<NI>> UndirectedDegrees<NI> for HybridView<'_, G> {
+    fn degree(&self, node: NI) -> NI {
+        self.graph.degree(node)
+    }
+}
+
+impl<NI: Idx, G: UndirectedNeighbors<NI>> UndirectedNeighbors<NI> for HybridView<'_, G> {
+    type NeighborsIterator<'a> = G::NeighborsIterator<'a>
+    where
+        Self: 'a;
+
+    fn neighbors(&self, node: NI) -> Self::NeighborsIterator<'_> {
+        self.graph.neighbors(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn star_with_triangles() -> UndirectedCsrGraph<u32> {
+        let edges = (1..300)
+            .map(|node| (0, node))
+            .chain((1..300).step_by(2).map(|node| (node, node + 1)))
+            .collect::<Vec<_>>();
+
+        GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(edges)
+            .build()
+    }
+
+    #[test]
+    fn hybrid_view_indexes_high_degree_nodes() {
+        let graph = star_with_triangles();
+        let view = HybridView::new(&graph, 10);
+
+        assert_eq!(view.indexed_node_count(), 1);
+        assert!(view.is_indexed(0));
+        assert!(!view.is_indexed(1));
+        assert_eq!(view.degree(0), 299);
+        assert_eq!(view.neighbors(1).as_slice(), graph.neighbors(1).as_slice());
+    }
+
+    #[test]
+    fn hybrid_view_contains_edge() {
+        let graph = star_with_triangles();
+
+        for threshold in [1, 10, usize::MAX] {
+            let view = HybridView::new(&graph, threshold);
+
+            assert!(view.contains_edge(0, 42));
+            assert!(view.contains_edge(42, 0));
+            assert!(view.contains_edge(41, 42));
+            assert!(!view.contains_edge(42, 43));
+            assert!(!view.contains_edge(0, 0));
+        }
+    }
+
+    #[test]
+    fn hybrid_view_common_neighbors() {
+        let graph = star_with_triangles();
+
+        for threshold in [1, 10, usize::MAX] {
+            let view = HybridView::new(&graph, threshold);
+
+            assert_eq!(view.common_neighbor_count(0, 41), 1);
+            assert_eq!(view.common_neighbor_count(41, 42), 1);
+            assert_eq!(view.common_neighbor_count(41, 43), 1);
+            assert_eq!(view.jaccard_similarity(41, 43), 1.0 / 3.0);
+        }
+    }
+
+    #[test]
+    fn hybrid_view_uses_hash_sets_for_sparse_neighborhoods() {
+        let edges = (1..1000).map(|node| (node - 1, node)).collect::<Vec<_>>();
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Deduplicated)
+            .edges(edges)
+            .build();
+        let view = HybridView::new(&graph, 2);
+
+        assert!(matches!(view.index.get(&1), Some(NeighborIndex::Hash(_))));
+        assert!(view.contains_edge(1, 2));
+        assert!(!view.contains_edge(1, 3));
+        assert_eq!(view.common_neighbor_count(1, 3), 1);
+    }
+}
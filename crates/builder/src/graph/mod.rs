@@ -1,5 +1,9 @@
 pub mod adj_list;
 pub mod csr;
+pub mod delta;
+pub mod hybrid;
+mod sort;
+pub mod view;
 
 /// Represents the target of an edge and its associated value.
 #[derive(Clone, Copy, Debug)]
@@ -0,0 +1,113 @@
+use rayon::prelude::*;
+
+use crate::SharedMut;
+
+const RADIX_BITS: usize = 8;
+const BUCKETS: usize = 1 << RADIX_BITS;
+const MIN_CHUNK_SIZE: usize = 1 << 14;
+
+/// Sorts the items by the integer key that `key` returns for each item
+/// using a parallel, stable least-significant-digit radix sort.
+///
+/// `max_key` must not be smaller than any key. It determines the number of
+/// passes over the items, which is one per byte of `max_key`. Since the
+/// sort is stable, items can be sorted by multiple keys by sorting them by
+/// the least significant key first.
+pub(crate) fn par_radix_sort_by_key<T, K>(items: &mut Vec<T>, max_key: usize, key: K)
+where
+    T: Copy + Send + Sync,
+    K: Fn(&T) -> usize + Sync,
+{
+    let len = items.len();
+    if len <= 1 {
+        return;
+    }
+
+    let key_bits = (usize::BITS - max_key.leading_zeros()) as usize;
+    let passes = (key_bits + RADIX_BITS - 1) / RADIX_BITS;
+    let chunk_size = usize::max(len / (rayon::current_num_threads() * 4), MIN_CHUNK_SIZE);
+
+    let mut buffer = items.clone();
+
+    for pass in 0..passes {
+        let shift = pass * RADIX_BITS;
+        let digit = |item: &T| (key(item) >> shift) & (BUCKETS - 1);
+
+        let mut positions = items
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut counts = [0_usize; BUCKETS];
+                chunk.iter().for_each(|item| counts[digit(item)] += 1);
+                counts
+            })
+            .collect::<Vec<_>>();
+
+        // Items with a smaller digit come first and items with the same
+        // digit are written in chunk order, which keeps the sort stable.
+        let mut position = 0;
+        for digit in 0..BUCKETS {
+            for counts in positions.iter_mut() {
+                let count = counts[digit];
+                counts[digit] = position;
+                position += count;
+            }
+        }
+
+        let buffer_ptr = SharedMut::new(buffer.as_mut_ptr());
+
+        items
+            .par_chunks(chunk_size)
+            .zip(positions.par_iter_mut())
+            .for_each(|(chunk, positions)| {
+                for item in chunk {
+                    let position = &mut positions[digit(item)];
+                    // SAFETY: The positions of all chunks and digits are
+                    // disjoint ranges that partition `0..len`, so every
+                    // position of the buffer is written exactly once.
+                    unsafe {
+                        buffer_ptr.add(*position).write(*item);
+                    }
+                    *position += 1;
+                }
+            });
+
+        std::mem::swap(items, &mut buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radix_sort_by_key() {
+        let mut items = (0..100_000_usize)
+            .map(|i| (i * 7_919 % 65_537, i))
+            .collect::<Vec<_>>();
+        let mut expected = items.clone();
+        expected.sort_by_key(|(key, _)| *key);
+
+        par_radix_sort_by_key(&mut items, 65_536, |(key, _)| *key);
+
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn radix_sort_by_multiple_keys() {
+        let mut items = vec![(2, 1), (0, 3), (2, 0), (1, 1), (0, 0), (1, 1)];
+
+        par_radix_sort_by_key(&mut items, 3, |(_, second)| *second);
+        par_radix_sort_by_key(&mut items, 2, |(first, _)| *first);
+
+        assert_eq!(items, vec![(0, 0), (0, 3), (1, 1), (1, 1), (2, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn radix_sort_with_zero_keys() {
+        let mut items = vec![(0, 2), (0, 1), (0, 0)];
+
+        par_radix_sort_by_key(&mut items, 0, |(key, _)| *key);
+
+        assert_eq!(items, vec![(0, 2), (0, 1), (0, 0)]);
+    }
+}
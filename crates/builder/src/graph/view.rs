@@ -0,0 +1,145 @@
+//! Views that change how an existing graph is traversed without copying it.
+
+use std::iter::Chain;
+
+use crate::{
+    index::Idx, DirectedDegrees, DirectedNeighbors, DirectedNeighborsWithValues, Graph, NodeValues,
+    UndirectedDegrees, UndirectedNeighbors, UndirectedNeighborsWithValues,
+};
+
+/// A view of a directed graph that ignores the direction of its edges.
+///
+/// The neighbors of a node are its outgoing neighbors followed by its
+/// incoming neighbors, so algorithms for undirected graphs can run on a
+/// directed graph without building an undirected copy with
+/// [`ToUndirectedOp::to_undirected`](crate::graph_ops::ToUndirectedOp::to_undirected).
+/// In contrast to the copy, neighbor lists are neither sorted nor
+/// deduplicated, i.e., the edges `(u, v)` and `(v, u)` make `v` a neighbor
+/// of `u` twice, and a self-loop makes a node its own neighbor twice.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .edges(vec![(0, 1), (2, 0)])
+///     .build();
+///
+/// let view = UndirectedView::new(&graph);
+///
+/// assert_eq!(view.degree(0), 2);
+/// assert_eq!(view.neighbors(0).copied().collect::<Vec<_>>(), vec![1, 2]);
+/// ```
+#[derive(Debug)]
+pub struct UndirectedView<'g, G> {
+    graph: &'g G,
+}
+
+impl<'g, G> UndirectedView<'g, G> {
+    pub fn new(graph: &'g G) -> Self {
+        Self { graph }
+    }
+
+    /// Returns the underlying directed graph.
+    pub fn graph(&self) -> &'g G {
+        self.graph
+    }
+}
+
+impl<G> Clone for UndirectedView<'_, G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for UndirectedView<'_, G> {}
+
+impl<NI: Idx, G: Graph<NI>> Graph<NI> for UndirectedView<'_, G> {
+    fn node_count(&self) -> NI {
+        self.graph.node_count()
+    }
+
+    fn edge_count(&self) -> NI {
+        self.graph.edge_count()
+    }
+}
+
+impl<NI: Idx, NV, G: NodeValues<NI, NV>> NodeValues<NI, NV> for UndirectedView<'_, G> {
+    fn node_value(&self, node: NI) -> &NV {
+        self.graph.node_value(node)
+    }
+}
+
+impl<NI: Idx, G: DirectedDegrees<NI>> UndirectedDegrees<NI> for UndirectedView<'_, G> {
+    fn degree(&self, node: NI) -> NI {
+        self.graph.out_degree(node) + self.graph.in_degree(node)
+    }
+}
+
+impl<NI: Idx, G: DirectedNeighbors<NI>> UndirectedNeighbors<NI> for UndirectedView<'_, G> {
+    type NeighborsIterator<'a> = Chain<G::NeighborsIterator<'a>, G::NeighborsIterator<'a>>
+    where
+        Self: 'a;
+
+    fn neighbors(&self, node: NI) -> Self::NeighborsIterator<'_> {
+        self.graph
+            .out_neighbors(node)
+            .chain(self.graph.in_neighbors(node))
+    }
+}
+
+impl<NI: Idx, EV, G: DirectedNeighborsWithValues<NI, EV>> UndirectedNeighborsWithValues<NI, EV>
+    for UndirectedView<'_, G>
+{
+    type NeighborsIterator<'a> = Chain<G::NeighborsIterator<'a>, G::NeighborsIterator<'a>>
+    where
+        Self: 'a,
+        EV: 'a;
+
+    fn neighbors_with_values(&self, node: NI) -> Self::NeighborsIterator<'_> {
+        self.graph
+            .out_neighbors_with_values(node)
+            .chain(self.graph.in_neighbors_with_values(node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn undirected_view_neighbors() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (3, 0), (1, 2)])
+            .build();
+
+        let view = UndirectedView::new(&graph);
+
+        assert_eq!(view.node_count(), 4);
+        assert_eq!(view.edge_count(), 4);
+        assert_eq!(view.degree(0), 3);
+        assert_eq!(
+            view.neighbors(0).copied().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(view.neighbors(2).copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(view.neighbors(3).copied().collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn undirected_view_neighbors_with_values() {
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 0.5), (1, 0, 1.5)])
+            .build();
+
+        let view = UndirectedView::new(&graph);
+
+        assert_eq!(
+            view.neighbors_with_values(0).copied().collect::<Vec<_>>(),
+            vec![Target::new(1, 0.5), Target::new(1, 1.5)]
+        );
+    }
+}
@@ -1,17 +1,23 @@
+use fxhash::FxHashSet;
 use log::info;
 use rayon::prelude::*;
 
 use crate::graph::csr::{prefix_sum, Csr, SwapCsr};
 use crate::graph::Target;
 use crate::index::Idx;
+use crate::stats::GraphStats;
+use crate::validation::ValidationReport;
 use crate::{
     CsrLayout, DirectedDegrees, DirectedNeighborsWithValues, Error, Graph, SharedMut,
     UndirectedDegrees, UndirectedNeighborsWithValues,
 };
 
-use std::ops::Range;
+use crate::time::Instant;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::ops::{Range, RangeBounds};
 use std::sync::Arc;
-use std::time::Instant;
 
 /// Partition the node set based on the degrees of the nodes.
 pub trait DegreePartitionOp<NI: Idx, EV> {
@@ -49,6 +55,63 @@ pub trait InDegreePartitionOp<NI: Idx, EV> {
     fn in_degree_partition(&self, concurrency: usize) -> Vec<Range<NI>>;
 }
 
+/// Query the k-hop neighborhood of a node in an undirected graph.
+pub trait NeighborsWithinOp<NI: Idx, EV> {
+    /// Returns all nodes that are reachable from `node` within at most `k`
+    /// hops, excluding `node` itself.
+    ///
+    /// Nodes are returned in breadth-first order, i.e., all nodes at hop
+    /// distance `1` come before all nodes at hop distance `2` and so on.
+    fn neighbors_within(&self, node: NI, k: usize) -> Vec<NI> {
+        self.neighbors_within_with_distance(node, k)
+            .into_iter()
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    /// Returns all nodes that are reachable from `node` within at most `k`
+    /// hops together with their hop distance to `node`.
+    ///
+    /// `node` itself is not part of the result.
+    fn neighbors_within_with_distance(&self, node: NI, k: usize) -> Vec<(NI, usize)>;
+}
+
+/// Query the k-hop neighborhood of a node following outgoing relationships.
+pub trait OutNeighborsWithinOp<NI: Idx, EV> {
+    /// Returns all nodes that are reachable from `node` via outgoing
+    /// relationships within at most `k` hops, excluding `node` itself.
+    ///
+    /// Nodes are returned in breadth-first order.
+    fn out_neighbors_within(&self, node: NI, k: usize) -> Vec<NI> {
+        self.out_neighbors_within_with_distance(node, k)
+            .into_iter()
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    /// Returns all nodes that are reachable from `node` via outgoing
+    /// relationships within at most `k` hops together with their hop distance.
+    fn out_neighbors_within_with_distance(&self, node: NI, k: usize) -> Vec<(NI, usize)>;
+}
+
+/// Query the k-hop neighborhood of a node following incoming relationships.
+pub trait InNeighborsWithinOp<NI: Idx, EV> {
+    /// Returns all nodes from which `node` can be reached via at most `k`
+    /// relationships, excluding `node` itself.
+    ///
+    /// Nodes are returned in breadth-first order.
+    fn in_neighbors_within(&self, node: NI, k: usize) -> Vec<NI> {
+        self.in_neighbors_within_with_distance(node, k)
+            .into_iter()
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    /// Returns all nodes from which `node` can be reached via at most `k`
+    /// relationships together with their hop distance.
+    fn in_neighbors_within_with_distance(&self, node: NI, k: usize) -> Vec<(NI, usize)>;
+}
+
 /// Call a particular function for each node with its corresponding state in parallel.
 pub trait ForEachNodeParallelOp<NI: Idx> {
     /// For each node calls `node_fn` with the node and its corresponding mutable
@@ -173,13 +236,47 @@ pub trait RelabelByDegreeOp<NI, EV> {
     fn make_degree_ordered(&mut self);
 }
 
+pub trait RelabelOp<NI> {
+    /// Relabels the node ids of the graph according to the given permutation.
+    ///
+    /// Node `u` becomes node `permutation[u]` and keeps its node value. The
+    /// neighbor lists of the relabeled graph are sorted.
+    ///
+    /// Note, that this method creates a new graph with the same space
+    /// requirements as the input graph.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `permutation` is not a permutation of the node ids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let mut graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (1, 2)])
+    ///     .build();
+    ///
+    /// // reverses the node ids
+    /// graph.relabel(&[2, 1, 0]);
+    ///
+    /// assert_eq!(graph.out_neighbors(2).as_slice(), &[1]);
+    /// assert_eq!(graph.out_neighbors(1).as_slice(), &[0]);
+    /// assert_eq!(graph.out_degree(0), 0);
+    /// ```
+    fn relabel(&mut self, permutation: &[NI]);
+}
+
 pub trait ToUndirectedOp {
     type Undirected;
 
     /// Creates a new undirected graph from the edges of an existing graph.
     ///
     /// Note, that this method creates a new graph with the same space
-    /// requirements as the input graph.
+    /// requirements as the input graph. An
+    /// [`UndirectedView`](crate::graph::view::UndirectedView) traverses a
+    /// directed graph as undirected graph without copying it.
     ///
     /// # Example
     ///
@@ -229,6 +326,244 @@ pub trait ToUndirectedOp {
     fn to_undirected(&self, layout: impl Into<Option<CsrLayout>>) -> Self::Undirected;
 }
 
+pub trait EgoSubgraphOp<NI> {
+    type Subgraph;
+
+    /// Extracts the subgraph induced by the `radius`-hop neighborhood of `node`.
+    ///
+    /// The returned subgraph contains `node`, all nodes within `radius` hops
+    /// of it and every relationship of the original graph between any two of
+    /// these nodes. Subgraph nodes are numbered in breadth-first order, i.e.,
+    /// `node` always becomes node `0`. The second element of the returned
+    /// tuple maps subgraph node ids to their ids in the original graph.
+    ///
+    /// For directed graphs, the neighborhood is determined by following
+    /// outgoing relationships. Neighbor lists of the subgraph are sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4), (1, 3)])
+    ///     .build();
+    ///
+    /// let (ego, mapping) = graph.ego_subgraph(2, 1);
+    ///
+    /// assert_eq!(mapping[0], 2);
+    /// assert_eq!(ego.node_count(), 3);
+    /// // (1, 2), (2, 3) and (1, 3)
+    /// assert_eq!(ego.edge_count(), 3);
+    /// ```
+    fn ego_subgraph(&self, node: NI, radius: usize) -> (Self::Subgraph, Vec<NI>);
+}
+
+pub trait InducedSubgraphOp<NI> {
+    type Subgraph;
+
+    /// Extracts the subgraph induced by the nodes that satisfy `predicate`.
+    ///
+    /// The returned subgraph contains every selected node and every
+    /// relationship of the original graph between any two of them. Subgraph
+    /// nodes keep the relative order of their original ids. The second
+    /// element of the returned tuple maps subgraph node ids to their ids in
+    /// the original graph. Neighbor lists of the subgraph are sorted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no node satisfies `predicate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 0), (1, 3)])
+    ///     .build();
+    ///
+    /// // keep all nodes with an out-degree of 1
+    /// let (subgraph, mapping) = graph.induced_subgraph(|node| graph.out_degree(node) == 1);
+    ///
+    /// assert_eq!(mapping, vec![0, 2, 3]);
+    /// // (2, 3) and (3, 0)
+    /// assert_eq!(subgraph.edge_count(), 2);
+    /// ```
+    fn induced_subgraph<P>(&self, predicate: P) -> (Self::Subgraph, Vec<NI>)
+    where
+        P: Fn(NI) -> bool;
+}
+
+pub trait FilterEdgesOp<NI, EV> {
+    type Filtered;
+
+    /// Creates a new graph that only contains the edges for which `predicate`
+    /// returns `true`.
+    ///
+    /// The predicate is called with the source node, the target node and the
+    /// value of each edge. For undirected graphs, it is called once per edge
+    /// with the source node not larger than the target node. All nodes and
+    /// their values are kept, so node ids do not change. Neighbor lists of
+    /// the filtered graph are sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+    ///     .edges_with_values(vec![(0, 1, 0.5), (0, 2, 2.0), (1, 2, 1.5)])
+    ///     .build();
+    ///
+    /// let heavy = graph.filter_edges(|_, _, weight| weight >= 1.0);
+    ///
+    /// assert_eq!(heavy.node_count(), 3);
+    /// assert_eq!(heavy.edge_count(), 2);
+    /// assert_eq!(heavy.out_degree(0), 1);
+    /// ```
+    fn filter_edges<P>(&self, predicate: P) -> Self::Filtered
+    where
+        P: Fn(NI, NI, EV) -> bool;
+}
+
+pub trait MapEdgeValuesOp<NI, EV, EV2> {
+    type Mapped;
+
+    /// Creates a new graph with the same nodes and edges, where the value of
+    /// each edge is replaced by `f(source, target, value)`.
+    ///
+    /// For undirected graphs, `f` is called with the source node not larger
+    /// than the target node. Since each edge is stored more than once, `f`
+    /// may be called multiple times per edge and should return the same
+    /// value for the same arguments. Node values and the order of neighbor
+    /// lists are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+    ///     .edges_with_values(vec![(0, 1, 0.5), (0, 2, 2.0), (1, 2, 1.0)])
+    ///     .build();
+    ///
+    /// // turn similarities into distances
+    /// let distances = graph.map_edge_values(|_, _, similarity| 1.0 / similarity);
+    ///
+    /// assert_eq!(distances.edge_count(), 3);
+    /// assert_eq!(distances.in_neighbors_with_values(1).as_slice(), &[Target::new(0, 2.0)]);
+    /// ```
+    fn map_edge_values<F>(&self, f: F) -> Self::Mapped
+    where
+        F: Fn(NI, NI, EV) -> EV2 + Sync;
+}
+
+/// Select nodes by their degree, e.g., to remove hubs before running
+/// algorithms whose run time grows quadratically with the degree.
+///
+/// For directed graphs, the degree of a node is the sum of its out and in
+/// degree.
+pub trait DegreeQueryOp<NI: Idx> {
+    /// Returns all nodes with a degree in `range` in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (0, 2), (0, 3), (3, 1)])
+    ///     .build();
+    ///
+    /// assert_eq!(graph.nodes_with_degree_in(2..), vec![0, 1, 3]);
+    /// assert_eq!(graph.nodes_with_degree_in(..2), vec![2]);
+    /// ```
+    fn nodes_with_degree_in<R>(&self, range: R) -> Vec<NI>
+    where
+        R: RangeBounds<NI> + Sync;
+
+    /// Returns the `k` nodes with the largest degree, ordered by descending
+    /// degree. Ties are broken in favor of smaller node ids. Returns all
+    /// nodes if the graph has fewer than `k` nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (1, 2), (1, 3), (3, 2), (4, 2)])
+    ///     .build();
+    ///
+    /// assert_eq!(graph.top_degree_nodes(2), vec![1, 2]);
+    /// ```
+    fn top_degree_nodes(&self, k: usize) -> Vec<NI>;
+}
+
+pub trait GraphStatsOp {
+    /// Computes summary statistics of the graph, such as its density, its
+    /// degree distribution and the memory used to store it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .edges(vec![(0, 1), (0, 2), (1, 2), (2, 0)])
+    ///     .build();
+    ///
+    /// let stats = graph.stats();
+    ///
+    /// assert_eq!(stats.node_count, 3);
+    /// assert_eq!(stats.edge_count, 4);
+    /// assert_eq!(stats.degrees.max, 2);
+    /// assert_eq!(stats.in_degrees.unwrap().min, 1);
+    /// ```
+    fn stats(&self) -> GraphStats;
+}
+
+pub trait ValidateOp {
+    /// Checks the invariants of the underlying CSR data structures and
+    /// returns a report of all violations.
+    ///
+    /// Offsets must be non-decreasing, start at `0` and end at the number of
+    /// targets, and all targets must be existing nodes. If the given layout
+    /// claims so, neighbor lists must be sorted or sorted and free of
+    /// duplicates and self-loops. The outgoing and incoming neighbors of
+    /// directed graphs must contain the same edges, and the neighbors of
+    /// undirected graphs must be symmetric.
+    ///
+    /// Validation is useful after loading graphs from third-party binary
+    /// snapshots or creating them from raw parts, which only perform basic
+    /// checks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    /// use graph_builder::graph::csr::Csr;
+    ///
+    /// // The edge (1, 0) is missing in the neighbors of node 0.
+    /// let csr = Csr::<u32, u32, ()>::from_raw_parts(vec![0, 0, 1], vec![0], vec![()]).unwrap();
+    /// let graph = UndirectedCsrGraph::from(csr);
+    ///
+    /// let report = graph.validate(CsrLayout::Sorted);
+    ///
+    /// assert!(!report.is_valid());
+    /// assert_eq!(
+    ///     report.violations,
+    ///     vec![Violation::MissingReverseEdge {
+    ///         direction: Direction::Undirected,
+    ///         source: 1,
+    ///         target: 0
+    ///     }]
+    /// );
+    /// ```
+    fn validate(&self, layout: CsrLayout) -> ValidationReport;
+}
+
 pub trait SerializeGraphOp<W> {
     fn serialize(&self, write: W) -> Result<(), Error>;
 }
@@ -439,9 +774,187 @@ where
     }
 }
 
+impl<NI, EV, U> NeighborsWithinOp<NI, EV> for U
+where
+    NI: Idx + Hash,
+    U: UndirectedNeighborsWithValues<NI, EV>,
+{
+    /// Collects the k-hop neighborhood using a level-synchronous BFS.
+    ///
+    /// Visited nodes are tracked in a hash set instead of a node-sized bitmap,
+    /// which keeps the memory footprint proportional to the neighborhood size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use graph_builder::prelude::*;
+    /// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .csr_layout(CsrLayout::Sorted)
+    ///     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4)])
+    ///     .build();
+    ///
+    /// assert_eq!(graph.neighbors_within(2, 1), vec![1, 3]);
+    /// assert_eq!(graph.neighbors_within(2, 2), vec![1, 3, 0, 4]);
+    /// assert_eq!(
+    ///     graph.neighbors_within_with_distance(0, 2),
+    ///     vec![(1, 1), (2, 2)]
+    /// );
+    /// ```
+    fn neighbors_within_with_distance(&self, node: NI, k: usize) -> Vec<(NI, usize)> {
+        k_hop_neighborhood(node, k, |node| {
+            self.neighbors_with_values(node).map(|t| t.target)
+        })
+    }
+}
+
+impl<NI, EV, D> OutNeighborsWithinOp<NI, EV> for D
+where
+    NI: Idx + Hash,
+    D: DirectedNeighborsWithValues<NI, EV>,
+{
+    /// Collects the outgoing k-hop neighborhood using a level-synchronous BFS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use graph_builder::prelude::*;
+    /// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .csr_layout(CsrLayout::Sorted)
+    ///     .edges(vec![(0, 1), (0, 2), (1, 3), (3, 0)])
+    ///     .build();
+    ///
+    /// assert_eq!(graph.out_neighbors_within(0, 1), vec![1, 2]);
+    /// assert_eq!(graph.out_neighbors_within(0, 2), vec![1, 2, 3]);
+    /// ```
+    fn out_neighbors_within_with_distance(&self, node: NI, k: usize) -> Vec<(NI, usize)> {
+        k_hop_neighborhood(node, k, |node| {
+            self.out_neighbors_with_values(node).map(|t| t.target)
+        })
+    }
+}
+
+impl<NI, EV, D> InNeighborsWithinOp<NI, EV> for D
+where
+    NI: Idx + Hash,
+    D: DirectedNeighborsWithValues<NI, EV>,
+{
+    /// Collects the incoming k-hop neighborhood using a level-synchronous BFS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use graph_builder::prelude::*;
+    /// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+    ///     .csr_layout(CsrLayout::Sorted)
+    ///     .edges(vec![(0, 1), (0, 2), (1, 3), (3, 0)])
+    ///     .build();
+    ///
+    /// assert_eq!(graph.in_neighbors_within(3, 1), vec![1]);
+    /// assert_eq!(graph.in_neighbors_within(3, 3), vec![1, 0]);
+    /// ```
+    fn in_neighbors_within_with_distance(&self, node: NI, k: usize) -> Vec<(NI, usize)> {
+        k_hop_neighborhood(node, k, |node| {
+            self.in_neighbors_with_values(node).map(|t| t.target)
+        })
+    }
+}
+
+// Runs a BFS from `start` that stops after `k` levels. The frontier and the
+// visited set only ever contain nodes of the neighborhood, so the cost of a
+// query does not depend on the size of the graph for small `k`.
+fn k_hop_neighborhood<NI, F, I>(start: NI, k: usize, neighbors: F) -> Vec<(NI, usize)>
+where
+    NI: Idx + Hash,
+    F: Fn(NI) -> I,
+    I: Iterator<Item = NI>,
+{
+    let mut visited = FxHashSet::default();
+    visited.insert(start);
+
+    let mut result = Vec::new();
+    let mut frontier = vec![start];
+    let mut next_frontier = Vec::new();
+
+    for distance in 1..=k {
+        for &node in &frontier {
+            for neighbor in neighbors(node) {
+                if visited.insert(neighbor) {
+                    result.push((neighbor, distance));
+                    next_frontier.push(neighbor);
+                }
+            }
+        }
+
+        if next_frontier.is_empty() {
+            break;
+        }
+
+        std::mem::swap(&mut frontier, &mut next_frontier);
+        next_frontier.clear();
+    }
+
+    result
+}
+
 // Split input slice into a vector of partition.len() disjoint slices such that
 // the slice at index i in the output vector has the same length as the range at
 // index i in the input partition.
+pub(crate) fn nodes_with_degree_in<NI, R, F>(node_count: NI, range: R, degree: F) -> Vec<NI>
+where
+    NI: Idx,
+    R: RangeBounds<NI> + Sync,
+    F: Fn(NI) -> NI + Sync,
+{
+    (0..node_count.index())
+        .into_par_iter()
+        .map(NI::new)
+        .filter(|node| range.contains(&degree(*node)))
+        .collect()
+}
+
+// Each thread keeps the `k` best nodes of its part of the graph in a min-heap,
+// whose root is the node that is replaced first. The heaps are merged pairwise.
+pub(crate) fn top_degree_nodes<NI, F>(node_count: NI, k: usize, degree: F) -> Vec<NI>
+where
+    NI: Idx,
+    F: Fn(NI) -> NI + Sync,
+{
+    type Heap<NI> = BinaryHeap<Reverse<(NI, Reverse<NI>)>>;
+
+    fn push<NI: Idx>(heap: &mut Heap<NI>, k: usize, entry: (NI, Reverse<NI>)) {
+        if heap.len() < k {
+            heap.push(Reverse(entry));
+        } else if heap.peek().is_some_and(|Reverse(worst)| entry > *worst) {
+            heap.pop();
+            heap.push(Reverse(entry));
+        }
+    }
+
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let heap = (0..node_count.index())
+        .into_par_iter()
+        .map(NI::new)
+        .fold(Heap::new, |mut heap, node| {
+            push(&mut heap, k, (degree(node), Reverse(node)));
+            heap
+        })
+        .reduce(Heap::new, |mut left, right| {
+            for Reverse(entry) in right {
+                push(&mut left, k, entry);
+            }
+            left
+        });
+
+    // Ascending order of `Reverse` entries is descending degree order.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((_, Reverse(node)))| node)
+        .collect()
+}
+
 fn split_by_partition<'a, NI: Idx, T>(
     partition: &[Range<NI>],
     slice: &'a mut [T],
@@ -640,7 +1153,9 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        builder::GraphBuilder, graph::csr::UndirectedCsrGraph, graph_ops::unzip_degrees_and_nodes,
+        builder::GraphBuilder,
+        graph::csr::{DirectedCsrGraph, UndirectedCsrGraph},
+        graph_ops::unzip_degrees_and_nodes,
         UndirectedNeighbors,
     };
 
@@ -772,4 +1287,47 @@ mod tests {
         assert_eq!(graph.neighbors(2).as_slice(), &[0, 0, 1, 3]);
         assert_eq!(graph.neighbors(3).as_slice(), &[0, 1, 2]);
     }
+
+    #[test]
+    fn k_hop_neighborhood_zero_hops() {
+        let graph: UndirectedCsrGraph<u32> =
+            GraphBuilder::new().edges(vec![(0, 1), (1, 2)]).build();
+
+        assert!(graph.neighbors_within(1, 0).is_empty());
+    }
+
+    #[test]
+    fn k_hop_neighborhood_with_distance() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 5)])
+            .build();
+
+        assert_eq!(
+            graph.neighbors_within_with_distance(0, 2),
+            vec![(1, 1), (2, 1), (3, 2)]
+        );
+        assert_eq!(
+            graph.neighbors_within_with_distance(0, 10),
+            vec![(1, 1), (2, 1), (3, 2), (4, 3), (5, 4)]
+        );
+    }
+
+    #[test]
+    fn k_hop_neighborhood_directed() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2), (2, 0), (2, 3)])
+            .build();
+
+        assert_eq!(
+            graph.out_neighbors_within_with_distance(0, 2),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(
+            graph.in_neighbors_within_with_distance(0, 2),
+            vec![(2, 1), (1, 2)]
+        );
+        assert!(graph.out_neighbors_within(3, 5).is_empty());
+    }
 }
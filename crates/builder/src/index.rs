@@ -23,8 +23,18 @@ pub trait Idx:
 {
     fn new(idx: usize) -> Self;
 
+    /// Converts the index into an id, or returns `None` if it does not fit
+    /// into the id type.
+    fn try_new(idx: usize) -> Option<Self>;
+
     fn zero() -> Self;
 
+    /// Converts the id into a `usize`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id does not fit into `usize`, e.g., for negative ids or
+    /// `u64` ids beyond `u32::MAX` on 32-bit platforms.
     fn index(self) -> usize;
 
     type RangeIter: Iterator<Item = Self>;
@@ -53,6 +63,11 @@ macro_rules! impl_idx {
                 idx as $TYPE
             }
 
+            #[inline]
+            fn try_new(idx: usize) -> Option<Self> {
+                <$TYPE>::try_from(idx).ok()
+            }
+
             #[inline]
             fn zero() -> Self {
                 0
@@ -60,7 +75,12 @@ macro_rules! impl_idx {
 
             #[inline]
             fn index(self) -> usize {
-                self as usize
+                // The conversion is free for unsigned ids that are not wider
+                // than `usize`, other ids must not be truncated.
+                match usize::try_from(self) {
+                    Ok(index) => index,
+                    Err(_) => panic!("id {self} does not fit into usize"),
+                }
             }
 
             type RangeIter = Range<Self>;
@@ -101,3 +121,45 @@ impl_idx!(i16);
 impl_idx!(i32);
 impl_idx!(i64);
 impl_idx!(isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn u64_index_round_trip() {
+        let idx = u64::new(u32::MAX as usize);
+
+        assert_eq!(idx, u32::MAX as u64);
+        assert_eq!(idx.index(), u32::MAX as usize);
+        assert_eq!((idx + 1).index(), u32::MAX as usize + 1);
+    }
+
+    #[test]
+    fn mixed_id_types() {
+        use crate::prelude::*;
+
+        let small: DirectedCsrGraph<u8> = GraphBuilder::new().edges(vec![(0, 1), (1, 2)]).build();
+        let large: DirectedCsrGraph<u64> = GraphBuilder::new()
+            .edges(vec![(0, 1), (1, 1 << 20)])
+            .build();
+
+        assert_eq!(small.node_count(), 3);
+        assert_eq!(large.node_count(), (1 << 20) + 1);
+        assert_eq!(large.in_neighbors(1 << 20).as_slice(), &[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_index() {
+        (-1_i32).index();
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(target_pointer_width = "32")]
+    fn u64_index_overflow() {
+        (u32::MAX as u64 + 1).index();
+    }
+}
@@ -0,0 +1,177 @@
+//! Reads graphs from files within a tokio runtime.
+//!
+//! Reading a graph is dominated by parsing the input and building the CSR,
+//! both of which would block the worker threads of an async runtime for a
+//! long time. The functions in this module read files via [`tokio::fs`] and
+//! move the CPU-bound phases to the blocking thread pool of the runtime, see
+//! [`GraphBuilder::build_async`].
+
+use std::{convert::TryFrom, path::Path, sync::Arc};
+
+use tokio::{sync::Semaphore, task::JoinSet};
+
+use crate::{
+    builder::GraphBuilder,
+    graph::csr::CsrLayout,
+    index::Idx,
+    input::{InputCapabilities, InputPath},
+    Error,
+};
+
+/// Reads the content of the file at the given path.
+///
+/// With the `object_store` feature, the path may also be the URL of an
+/// object, see [`object_store`](super::object_store).
+pub(crate) async fn read_file_async(path: &Path) -> Result<Vec<u8>, Error> {
+    #[cfg(feature = "object_store")]
+    if let Some(url) = super::object_store::object_url(path) {
+        return super::object_store::read_object(&url).await;
+    }
+
+    Ok(tokio::fs::read(path).await?)
+}
+
+/// Reads the graph from the file at the given path using the given format.
+///
+/// This is a shorthand for building the graph with
+/// [`GraphBuilder::build_async`].
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let path = [env!("CARGO_MANIFEST_DIR"), "resources", "example.el"]
+///     .iter()
+///     .collect::<std::path::PathBuf>();
+///
+/// let graph: UndirectedCsrGraph<u32> =
+///     read_graph_async(path, EdgeListInput::default(), CsrLayout::Sorted)
+///         .await
+///         .expect("loading failed");
+///
+/// assert_eq!(graph.node_count(), 4);
+/// # });
+/// ```
+pub async fn read_graph_async<NI, P, Format, Graph>(
+    path: P,
+    format: Format,
+    csr_layout: CsrLayout,
+) -> Result<Graph, Error>
+where
+    NI: Idx,
+    P: AsRef<Path>,
+    Format: InputCapabilities<NI> + 'static,
+    Format::GraphInput: TryFrom<InputPath<P>> + for<'a> TryFrom<&'a [u8], Error = Error>,
+    Error: From<<Format::GraphInput as TryFrom<InputPath<P>>>::Error>,
+    Graph: TryFrom<(Format::GraphInput, CsrLayout)> + Send + 'static,
+    Error: From<Graph::Error>,
+{
+    GraphBuilder::new()
+        .csr_layout(csr_layout)
+        .file_format(format)
+        .path(path)
+        .build_async()
+        .await
+}
+
+/// Reads a graph from each of the given paths using the given format.
+///
+/// At most `concurrency` graphs are read at the same time, which bounds the
+/// memory that is needed for the file contents and intermediate edge lists.
+/// The graphs are returned in the order of the paths. If reading any of the
+/// graphs fails, the remaining reads are cancelled and the first error is
+/// returned.
+///
+/// # Panics
+///
+/// Panics if `concurrency` is zero or if called outside of a tokio runtime.
+pub async fn read_graphs_async<NI, P, Format, Graph>(
+    paths: impl IntoIterator<Item = P>,
+    format: Format,
+    csr_layout: CsrLayout,
+    concurrency: usize,
+) -> Result<Vec<Graph>, Error>
+where
+    NI: Idx,
+    P: AsRef<Path> + Send + 'static,
+    Format: InputCapabilities<NI> + Clone + Send + 'static,
+    Format::GraphInput: TryFrom<InputPath<P>> + for<'a> TryFrom<&'a [u8], Error = Error>,
+    Error: From<<Format::GraphInput as TryFrom<InputPath<P>>>::Error>,
+    Graph: TryFrom<(Format::GraphInput, CsrLayout)> + Send + 'static,
+    Error: From<Graph::Error>,
+{
+    assert!(concurrency > 0, "concurrency must be at least 1");
+
+    let permits = Arc::new(Semaphore::new(concurrency));
+    let mut tasks = JoinSet::new();
+
+    for (index, path) in paths.into_iter().enumerate() {
+        let permits = Arc::clone(&permits);
+        let format = format.clone();
+        tasks.spawn(async move {
+            let _permit = permits.acquire().await.expect("semaphore is never closed");
+            let graph = read_graph_async::<NI, P, Format, Graph>(path, format, csr_layout).await;
+            (index, graph)
+        });
+    }
+
+    let mut graphs = Vec::with_capacity(tasks.len());
+    while let Some(result) = tasks.join_next().await {
+        let (index, graph) = match result {
+            Ok(result) => result,
+            Err(error) => std::panic::resume_unwind(error.into_panic()),
+        };
+        graphs.push((index, graph?));
+    }
+    graphs.sort_unstable_by_key(|(index, _)| *index);
+
+    Ok(graphs.into_iter().map(|(_, graph)| graph).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn read_graphs_async_keeps_path_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = (1..=3)
+            .map(|edge_count| {
+                let path = dir.path().join(format!("{edge_count}.el"));
+                let edges = (0..edge_count)
+                    .map(|source| format!("{source} {}\n", source + 1))
+                    .collect::<String>();
+                std::fs::write(&path, edges).unwrap();
+                path
+            })
+            .collect::<Vec<_>>();
+
+        let graphs: Vec<DirectedCsrGraph<u32>> =
+            read_graphs_async(paths, EdgeListInput::default(), CsrLayout::Sorted, 2)
+                .await
+                .unwrap();
+
+        let edge_counts = graphs.iter().map(|g| g.edge_count()).collect::<Vec<_>>();
+        assert_eq!(edge_counts, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn read_graphs_async_fails_on_missing_file() {
+        let paths = vec![
+            [env!("CARGO_MANIFEST_DIR"), "resources", "example.el"]
+                .iter()
+                .collect::<PathBuf>(),
+            PathBuf::from("does/not/exist.el"),
+        ];
+
+        let result: Result<Vec<DirectedCsrGraph<u32>>, Error> =
+            read_graphs_async(paths, EdgeListInput::default(), CsrLayout::Sorted, 2).await;
+
+        assert!(matches!(result, Err(Error::IoError { .. })));
+    }
+}
@@ -0,0 +1,646 @@
+//! Reads a graph from a delimited text file, e.g., CSV or TSV.
+//!
+//! In contrast to [`EdgeListInput`](super::EdgeListInput), which expects bare
+//! node ids separated by a single byte, this reader supports header rows,
+//! arbitrary delimiters, comment lines, quoted fields and selecting the
+//! relevant columns by name or position. This allows loading exports of other
+//! systems without any preprocessing.
+
+use std::{borrow::Cow, marker::PhantomData, path::Path};
+
+use log::info;
+use rayon::prelude::*;
+
+use crate::{index::Idx, Error};
+
+use super::{edgelist::EdgeList, node_values::NodeValuesInput, InputCapabilities, ParseValue};
+
+/// Identifies a column of a delimited file.
+///
+/// Columns can either be referenced by their zero-based position or by their
+/// name in the header row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvColumn {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for CsvColumn {
+    fn from(index: usize) -> Self {
+        CsvColumn::Index(index)
+    }
+}
+
+impl From<&str> for CsvColumn {
+    fn from(name: &str) -> Self {
+        CsvColumn::Name(name.to_string())
+    }
+}
+
+impl From<String> for CsvColumn {
+    fn from(name: String) -> Self {
+        CsvColumn::Name(name)
+    }
+}
+
+/// Reads an edge list from a delimited text file.
+///
+/// Each record represents an edge. By default, records are comma separated,
+/// there is no header row, and the source node id, target node id and edge
+/// value are read from the first three columns. If a record does not contain
+/// the value column or the field is empty, the default value of `EV` is used.
+/// Values that cannot be parsed into `EV` fail the record.
+/// Fields can be enclosed in double quotes, which allows them to contain the
+/// delimiter. Within quoted fields, a doubled quote represents a single quote.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+///
+/// use graph_builder::prelude::*;
+///
+/// let path = std::env::temp_dir().join("graph_builder_csv_example.csv");
+/// let mut file = std::fs::File::create(&path).unwrap();
+/// writeln!(file, "# exported relationships").unwrap();
+/// writeln!(file, "type;from;to;cost").unwrap();
+/// writeln!(file, "KNOWS;0;1;0.5").unwrap();
+/// writeln!(file, "LIKES;0;2;1.0").unwrap();
+/// writeln!(file, "KNOWS;1;2;1.5").unwrap();
+///
+/// let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .csv_input(
+///         CsvInput::default()
+///             .delimiter(b';')
+///             .header(true)
+///             .comment(b'#')
+///             .source("from")
+///             .target("to")
+///             .value("cost")
+///             .label("type", "KNOWS"),
+///     )
+///     .path(&path)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_count(), 2);
+/// assert_eq!(graph.out_neighbors_with_values(0).as_slice(), &[Target::new(1, 0.5)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvInput<NI: Idx, EV = ()> {
+    delimiter: u8,
+    header: bool,
+    comment: Option<u8>,
+    source: CsvColumn,
+    target: CsvColumn,
+    value: CsvColumn,
+    label: Option<(CsvColumn, String)>,
+    _idx: PhantomData<(NI, EV)>,
+}
+
+impl<NI: Idx, EV> Default for CsvInput<NI, EV> {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            header: false,
+            comment: None,
+            source: CsvColumn::Index(0),
+            target: CsvColumn::Index(1),
+            value: CsvColumn::Index(2),
+            label: None,
+            _idx: PhantomData,
+        }
+    }
+}
+
+impl<NI: Idx, EV> CsvInput<NI, EV> {
+    /// Sets the byte that separates the fields of a record.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the first record is a header row containing the column
+    /// names. Columns can only be selected by name if there is a header row.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets the byte that starts a comment line. Comment lines are skipped.
+    pub fn comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Sets the column that contains the source node ids.
+    pub fn source(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.source = column.into();
+        self
+    }
+
+    /// Sets the column that contains the target node ids.
+    pub fn target(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.target = column.into();
+        self
+    }
+
+    /// Sets the column that contains the edge values.
+    pub fn value(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.value = column.into();
+        self
+    }
+
+    /// Only reads records whose field in the given column equals `label`.
+    ///
+    /// This is useful to load a single relationship type from an export
+    /// that contains multiple types.
+    pub fn label(mut self, column: impl Into<CsvColumn>, label: impl Into<String>) -> Self {
+        self.label = Some((column.into(), label.into()));
+        self
+    }
+}
+
+impl<NI: Idx, EV> InputCapabilities<NI> for CsvInput<NI, EV> {
+    type GraphInput = EdgeList<NI, EV>;
+}
+
+/// Reads node values from a delimited text file.
+///
+/// Each record contains a node id and a node value. By default, records are
+/// comma separated, there is no header row, and the node id and value are
+/// read from the first two columns. If a record does not contain the value
+/// column or the field is empty, the default value of `NV` is used.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+///
+/// use graph_builder::prelude::*;
+///
+/// let dir = std::env::temp_dir();
+/// let path = dir.join("graph_builder_csv_node_example.csv");
+/// let node_path = dir.join("graph_builder_csv_node_example_nodes.csv");
+/// std::fs::write(&path, "0,1\n1,2\n").unwrap();
+/// std::fs::write(&node_path, "node,age\n2,42\n0,23\n3,7\n").unwrap();
+///
+/// let graph: DirectedCsrGraph<u32, u64> = GraphBuilder::new()
+///     .csv_input(CsvInput::default())
+///     .path(&path)
+///     .node_values_file(CsvNodeInput::default().header(true).value("age"), &node_path)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.node_value(0), &23);
+/// assert_eq!(graph.node_value(1), &0);
+/// assert_eq!(graph.node_value(3), &7);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvNodeInput<NI: Idx, NV> {
+    delimiter: u8,
+    header: bool,
+    comment: Option<u8>,
+    id: CsvColumn,
+    value: CsvColumn,
+    _idx: PhantomData<(NI, NV)>,
+}
+
+impl<NI: Idx, NV> Default for CsvNodeInput<NI, NV> {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            header: false,
+            comment: None,
+            id: CsvColumn::Index(0),
+            value: CsvColumn::Index(1),
+            _idx: PhantomData,
+        }
+    }
+}
+
+impl<NI: Idx, NV> CsvNodeInput<NI, NV> {
+    /// Sets the byte that separates the fields of a record.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the first record is a header row containing the column
+    /// names. Columns can only be selected by name if there is a header row.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets the byte that starts a comment line. Comment lines are skipped.
+    pub fn comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Sets the column that contains the node ids.
+    pub fn id(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.id = column.into();
+        self
+    }
+
+    /// Sets the column that contains the node values.
+    pub fn value(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.value = column.into();
+        self
+    }
+}
+
+impl<NI, NV> CsvNodeInput<NI, NV>
+where
+    NI: Idx,
+    NV: ParseValue + Send + Sync,
+{
+    /// Parses the node ids and values from the given bytes.
+    ///
+    /// Records are parsed in parallel.
+    pub fn parse(&self, bytes: &[u8]) -> Result<Vec<(NI, NV)>, Error> {
+        let start = crate::time::Instant::now();
+
+        let (header, body) = split_header(bytes, self.header, self.comment, self.delimiter);
+        let id_column = position(&self.id, header.as_deref())?;
+        let value_column = position(&self.value, header.as_deref())?;
+
+        let records = body
+            .par_split(|b| *b == b'\n')
+            .map(trim_line_end)
+            .filter(|line| !is_skipped(line, self.comment))
+            .map(|line| {
+                let fields = fields(line, self.delimiter);
+                let id = parse_id::<NI>(&fields, id_column, line)?;
+                let value = parse_value::<NV>(&fields, value_column, line)?;
+                Ok((id, value))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let elapsed = start.elapsed().as_millis() as f64 / 1000_f64;
+
+        info!("Read {} node values in {:.2}s", records.len(), elapsed);
+
+        Ok(records)
+    }
+}
+
+impl<NI, NV> NodeValuesInput<NI, NV> for CsvNodeInput<NI, NV>
+where
+    NI: Idx,
+    NV: ParseValue + Send + Sync,
+{
+    fn read_node_values(&self, path: &Path) -> Result<Vec<(NI, NV)>, Error> {
+        let bytes = super::read_file(path)?;
+        self.parse(&bytes)
+    }
+}
+
+/// Column positions after resolving names against the header row.
+struct Columns<'a> {
+    source: usize,
+    target: usize,
+    value: usize,
+    label: Option<(usize, &'a [u8])>,
+}
+
+impl<NI, EV> CsvInput<NI, EV>
+where
+    NI: Idx,
+    EV: ParseValue + Copy + Send + Sync,
+{
+    /// Reads the edge list from the file at the given path.
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> Result<EdgeList<NI, EV>, Error> {
+        let bytes = super::read_file(path.as_ref())?;
+        self.parse(&bytes)
+    }
+
+    /// Parses the edge list from the given bytes.
+    ///
+    /// Records are parsed in parallel.
+    pub fn parse(&self, bytes: &[u8]) -> Result<EdgeList<NI, EV>, Error> {
+        let start = crate::time::Instant::now();
+
+        let (header, body) = split_header(bytes, self.header, self.comment, self.delimiter);
+        let columns = self.resolve(header.as_deref())?;
+
+        let edges = body
+            .par_split(|b| *b == b'\n')
+            .map(trim_line_end)
+            .filter(|line| !is_skipped(line, self.comment))
+            .map(|line| self.parse_record(line, &columns))
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let elapsed = start.elapsed().as_millis() as f64 / 1000_f64;
+
+        info!("Read {} edges in {:.2}s", edges.len(), elapsed);
+
+        Ok(EdgeList::new(edges))
+    }
+
+    fn resolve<'a>(&'a self, header: Option<&[Cow<'_, [u8]>]>) -> Result<Columns<'a>, Error> {
+        let position = |column: &CsvColumn| position(column, header);
+
+        Ok(Columns {
+            source: position(&self.source)?,
+            target: position(&self.target)?,
+            value: position(&self.value)?,
+            label: match &self.label {
+                Some((column, label)) => Some((position(column)?, label.as_bytes())),
+                None => None,
+            },
+        })
+    }
+
+    fn parse_record(&self, line: &[u8], columns: &Columns) -> Result<Option<(NI, NI, EV)>, Error> {
+        let fields = fields(line, self.delimiter);
+
+        if let Some((column, label)) = columns.label {
+            if fields.get(column).map(|field| field.as_ref()) != Some(label) {
+                return Ok(None);
+            }
+        }
+
+        let source = parse_id::<NI>(&fields, columns.source, line)?;
+        let target = parse_id::<NI>(&fields, columns.target, line)?;
+        let value = parse_value::<EV>(&fields, columns.value, line)?;
+
+        Ok(Some((source, target, value)))
+    }
+}
+
+/// The fields of a record, which are borrowed unless they contain escaped
+/// quotes.
+type Fields<'a> = Vec<Cow<'a, [u8]>>;
+
+/// Splits off the header row, if there is one, and returns its fields
+/// together with the remaining bytes.
+fn split_header(
+    bytes: &[u8],
+    header: bool,
+    comment: Option<u8>,
+    delimiter: u8,
+) -> (Option<Fields<'_>>, &[u8]) {
+    let mut body = bytes;
+    if !header {
+        return (None, body);
+    }
+    loop {
+        let (line, rest) = split_line(body);
+        body = rest;
+        if line.is_empty() && body.is_empty() {
+            return (None, body);
+        }
+        if !is_skipped(line, comment) {
+            return (Some(fields(line, delimiter)), body);
+        }
+    }
+}
+
+fn is_skipped(line: &[u8], comment: Option<u8>) -> bool {
+    line.iter().all(u8::is_ascii_whitespace)
+        || matches!((comment, line.first()), (Some(c), Some(b)) if c == *b)
+}
+
+/// Splits a record into its unquoted fields. Delimiters within double quotes
+/// do not separate fields.
+fn fields(line: &[u8], delimiter: u8) -> Fields<'_> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+
+    for (i, b) in line.iter().enumerate() {
+        if *b == b'"' {
+            // a doubled quote within a quoted field toggles twice
+            quoted = !quoted;
+        } else if *b == delimiter && !quoted {
+            fields.push(unquote(&line[start..i]));
+            start = i + 1;
+        }
+    }
+    fields.push(unquote(&line[start..]));
+
+    fields
+}
+
+fn position(column: &CsvColumn, header: Option<&[Cow<'_, [u8]>]>) -> Result<usize, Error> {
+    match (column, header) {
+        (CsvColumn::Index(index), _) => Ok(*index),
+        (CsvColumn::Name(name), Some(header)) => header
+            .iter()
+            .position(|field| field.as_ref() == name.as_bytes())
+            .ok_or_else(|| Error::InvalidColumn {
+                column: name.clone(),
+                reason: String::from("column does not exist"),
+            }),
+        (CsvColumn::Name(name), None) => Err(Error::InvalidColumn {
+            column: name.clone(),
+            reason: String::from("columns can only be selected by name with a header row"),
+        }),
+    }
+}
+
+fn parse_id<NI: Idx>(fields: &[Cow<'_, [u8]>], column: usize, line: &[u8]) -> Result<NI, Error> {
+    let field = fields
+        .get(column)
+        .ok_or_else(|| invalid(line, &format!("missing column {column}")))?;
+
+    match NI::parse(field) {
+        (id, len) if len > 0 && len == field.len() => Ok(id),
+        _ => Err(invalid(
+            line,
+            &format!("invalid node id in column {column}"),
+        )),
+    }
+}
+
+fn parse_value<V: ParseValue>(
+    fields: &[Cow<'_, [u8]>],
+    column: usize,
+    line: &[u8],
+) -> Result<V, Error> {
+    match fields.get(column) {
+        // values of zero-sized types, e.g., `()`, are not read
+        Some(field) if !field.is_empty() && std::mem::size_of::<V>() > 0 => match V::parse(field) {
+            (value, len) if len == field.len() => Ok(value),
+            _ => Err(invalid(
+                line,
+                &format!(
+                    "invalid value {:?} in column {column}",
+                    String::from_utf8_lossy(field)
+                ),
+            )),
+        },
+        // if the record does not have a value, the default is used
+        _ => Ok(V::default()),
+    }
+}
+
+fn invalid(line: &[u8], reason: &str) -> Error {
+    Error::InvalidRecord {
+        record: String::from_utf8_lossy(line).into_owned(),
+        reason: reason.to_string(),
+    }
+}
+
+fn split_line(bytes: &[u8]) -> (&[u8], &[u8]) {
+    match bytes.iter().position(|b| *b == b'\n') {
+        Some(end) => (trim_line_end(&bytes[..end]), &bytes[end + 1..]),
+        None => (trim_line_end(bytes), &[]),
+    }
+}
+
+fn trim_line_end(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+fn unquote(field: &[u8]) -> Cow<'_, [u8]> {
+    let field = trim(field);
+    match field
+        .strip_prefix(b"\"")
+        .and_then(|f| f.strip_suffix(b"\""))
+    {
+        Some(quoted) if quoted.contains(&b'"') => {
+            let mut unquoted = Vec::with_capacity(quoted.len());
+            let mut bytes = quoted.iter().peekable();
+            while let Some(b) = bytes.next() {
+                unquoted.push(*b);
+                if *b == b'"' {
+                    bytes.next_if_eq(&&b'"');
+                }
+            }
+            Cow::Owned(unquoted)
+        }
+        Some(quoted) => Cow::Borrowed(quoted),
+        None => Cow::Borrowed(field),
+    }
+}
+
+fn trim(field: &[u8]) -> &[u8] {
+    let start = field
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(field.len());
+    let end = field
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |end| end + 1);
+    &field[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::prelude::*;
+
+    fn edges<EV: Copy + Send + Sync>(edge_list: EdgeList<u32, EV>) -> Vec<(u32, u32, EV)> {
+        let mut edges = edge_list.edges().collect::<Vec<_>>();
+        edges.sort_by_key(|(s, t, _)| (*s, *t));
+        edges
+    }
+
+    #[test]
+    fn default_columns() {
+        let bytes = b"0,1\n0,2\r\n\n1,2\n";
+
+        let edge_list = CsvInput::<u32>::default().parse(bytes).unwrap();
+
+        assert_eq!(edges(edge_list), vec![(0, 1, ()), (0, 2, ()), (1, 2, ())]);
+    }
+
+    #[test]
+    fn named_columns_with_quotes() {
+        let bytes = b"\"weight\"\t\"dst\"\t\"src\"\n0.5\t\"1\"\t0\n# comment\n1.5\t2\t1\n";
+
+        let edge_list = CsvInput::<u32, f32>::default()
+            .delimiter(b'\t')
+            .header(true)
+            .comment(b'#')
+            .source("src")
+            .target("dst")
+            .value("weight")
+            .parse(bytes)
+            .unwrap();
+
+        assert_eq!(edges(edge_list), vec![(0, 1, 0.5), (1, 2, 1.5)]);
+    }
+
+    #[test]
+    fn missing_values_use_default() {
+        let bytes = b"0,1,0.5\n1,2\n";
+
+        let edge_list = CsvInput::<u32, f64>::default().parse(bytes).unwrap();
+
+        assert_eq!(edges(edge_list), vec![(0, 1, 0.5), (1, 2, 0.0)]);
+    }
+
+    #[test]
+    fn quoted_delimiters() {
+        let bytes = b"0,1,\"a,b\",0.5\n1,2,\"say \"\"hi\"\", b\",1.5\n2,0,a,2.5\n";
+
+        let input = CsvInput::<u32, f32>::default().value(3);
+
+        let edge_list = input.clone().label(2, "a,b").parse(bytes).unwrap();
+        assert_eq!(edges(edge_list), vec![(0, 1, 0.5)]);
+
+        let edge_list = input.label(2, "say \"hi\", b").parse(bytes).unwrap();
+        assert_eq!(edges(edge_list), vec![(1, 2, 1.5)]);
+    }
+
+    #[test]
+    fn invalid_value() {
+        let bytes = b"0,1,0.5\n1,2,heavy\n";
+
+        let result = CsvInput::<u32, f32>::default().parse(bytes);
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidRecord { record, reason }) if record == "1,2,heavy" && reason.contains("column 2")
+        ));
+
+        // values are not read for unit edge values
+        let edge_list = CsvInput::<u32>::default().parse(bytes).unwrap();
+        assert_eq!(edges(edge_list), vec![(0, 1, ()), (1, 2, ())]);
+    }
+
+    #[test]
+    fn invalid_node_id() {
+        let bytes = b"source,target\n0,1\n";
+
+        let result = CsvInput::<u32>::default().parse(bytes);
+
+        assert!(
+            matches!(result, Err(Error::InvalidRecord { record, .. }) if record == "source,target")
+        );
+    }
+
+    #[test]
+    fn named_column_without_header() {
+        let result = CsvInput::<u32>::default().source("src").parse(b"0,1\n");
+
+        assert!(matches!(result, Err(Error::InvalidColumn { column, .. }) if column == "src"));
+    }
+
+    #[test]
+    fn node_values() {
+        let bytes = b"# features\nid;score;name\n2;1.5;c\n0;0.5;a\n1\n";
+
+        let records = CsvNodeInput::<u32, f32>::default()
+            .delimiter(b';')
+            .header(true)
+            .comment(b'#')
+            .id("id")
+            .value("score")
+            .parse(bytes)
+            .unwrap();
+
+        assert_eq!(records, vec![(2, 1.5), (0, 0.5), (1, 0.0)]);
+    }
+}
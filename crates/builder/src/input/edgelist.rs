@@ -1,6 +1,6 @@
 use atomic::Atomic;
 use log::info;
-use std::{convert::TryFrom, fs::File, marker::PhantomData, path::Path, sync::Arc};
+use std::{convert::TryFrom, marker::PhantomData, path::Path};
 
 use crate::index::Idx;
 
@@ -29,6 +29,37 @@ use super::{InputCapabilities, InputPath, ParseValue};
 /// 1 3
 /// 2 0
 /// ```
+///
+/// # Shards
+///
+/// Instead of a single file, the path may refer to multiple shards of an edge
+/// list, e.g., the output of a Spark or Hadoop job. The shards are read in
+/// parallel and merged into a single graph. The path may be
+///
+/// * a directory, which refers to all files in the directory except for
+///   hidden files and files starting with `_`, such as `_SUCCESS`, or
+/// * a pattern in the file name, e.g., `edges/part-*`, where `*` matches any
+///   sequence of characters and `?` matches a single character.
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let dir = std::env::temp_dir().join("graph_builder_shards_example");
+/// std::fs::create_dir_all(&dir).unwrap();
+/// std::fs::write(dir.join("part-00000"), "0 1\n0 2\n").unwrap();
+/// std::fs::write(dir.join("part-00001"), "1 2\n2 3\n").unwrap();
+/// std::fs::write(dir.join("_SUCCESS"), "").unwrap();
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .file_format(EdgeListInput::default())
+///     .path(dir.join("part-*"))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.edge_count(), 4);
+/// ```
+#[derive(Clone, Copy)]
 pub struct EdgeListInput<NI: Idx, EV = ()> {
     _idx: PhantomData<(NI, EV)>,
 }
@@ -137,6 +168,193 @@ impl<NI: Idx, EV: Copy + Send + Sync> Edges for EdgeList<NI, EV> {
     }
 }
 
+/// Defines how self-loops, i.e., edges in the form of `(u, u)`, are handled
+/// when building a graph.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SelfLoops {
+    /// Self-loops are kept without inspecting the edges.
+    #[default]
+    Keep,
+    /// Self-loops are removed and counted in [`EdgeReport::dropped_self_loops`].
+    Drop,
+    /// Self-loops are kept and counted in [`EdgeReport::self_loops`].
+    Count,
+}
+
+/// Defines how parallel edges, i.e., multiple edges between the same pair of
+/// nodes, are handled when building a graph.
+///
+/// For undirected graphs, `(u, v)` and `(v, u)` are parallel edges.
+#[derive(Default)]
+pub enum ParallelEdges<EV = ()> {
+    /// Parallel edges are kept, i.e., the graph is a multigraph.
+    #[default]
+    Keep,
+    /// Only the first of the parallel edges in input order is kept.
+    Deduplicate,
+    /// Parallel edges are merged into a single edge, whose value is computed
+    /// by folding the edge values in input order with the given function.
+    Aggregate(fn(EV, EV) -> EV),
+}
+
+impl<EV> Clone for ParallelEdges<EV> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<EV> Copy for ParallelEdges<EV> {}
+
+impl<EV> std::fmt::Debug for ParallelEdges<EV> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParallelEdges::Keep => f.write_str("Keep"),
+            ParallelEdges::Deduplicate => f.write_str("Deduplicate"),
+            ParallelEdges::Aggregate(_) => f.write_str("Aggregate"),
+        }
+    }
+}
+
+/// Reports the edges that were counted or removed by the [`SelfLoops`] and
+/// [`ParallelEdges`] policies while building a graph.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeReport {
+    /// The number of self-loops in the input. Only counted if self-loops are
+    /// not kept with [`SelfLoops::Keep`].
+    pub self_loops: usize,
+    /// The number of removed self-loops.
+    pub dropped_self_loops: usize,
+    /// The number of parallel edges that were removed or merged into another
+    /// edge.
+    pub dropped_parallel_edges: usize,
+}
+
+impl<NI: Idx, EV: Copy + Send + Sync> EdgeList<NI, EV> {
+    /// Sets the node count of the resulting graph, which allows to represent
+    /// nodes without edges that have a larger id than all nodes with edges.
+    ///
+    /// Returns an error if `node_count` is not larger than the largest node
+    /// id of the edges.
+    pub(crate) fn with_node_count(self, node_count: usize) -> Result<Self, Error> {
+        let max_node_id = self.max_node_id();
+        if node_count <= max_node_id.index() {
+            return Err(Error::InvalidNodeCount {
+                node_count,
+                max_node_id: max_node_id.index(),
+            });
+        }
+
+        Ok(Self {
+            list: self.list,
+            max_node_id: Some(NI::new(node_count - 1)),
+        })
+    }
+
+    /// Extends the node count of the resulting graph to include all node ids
+    /// of the given node file, see [`read_max_node_id`].
+    pub(crate) fn with_node_file<P: AsRef<Path>>(self, path: P) -> Result<Self, Error> {
+        match read_max_node_id::<NI, _>(path)? {
+            Some(id) if id > self.max_node_id() => self.with_node_count(id.index() + 1),
+            _ => Ok(self),
+        }
+    }
+
+    /// Applies the given policies to the edges and returns the remaining
+    /// edges together with a report of what was counted or removed.
+    ///
+    /// The node count is not affected, i.e., nodes whose edges are all
+    /// removed remain in the graph without edges.
+    pub(crate) fn apply_edge_policies(
+        self,
+        directed: bool,
+        self_loops: SelfLoops,
+        parallel_edges: ParallelEdges<EV>,
+    ) -> (Self, EdgeReport) {
+        let start = crate::time::Instant::now();
+        let max_node_id = self.max_node_id();
+        let mut edges = self.list.into_vec();
+        let mut report = EdgeReport::default();
+
+        if self_loops != SelfLoops::Keep {
+            report.self_loops = edges.par_iter().filter(|(s, t, _)| s == t).count();
+        }
+        if self_loops == SelfLoops::Drop {
+            edges.retain(|(s, t, _)| s != t);
+            report.dropped_self_loops = report.self_loops;
+        }
+
+        let key = |(s, t, _): &(NI, NI, EV)| {
+            if directed {
+                (*s, *t)
+            } else {
+                (NI::min(*s, *t), NI::max(*s, *t))
+            }
+        };
+        let edge_count = edges.len();
+        match parallel_edges {
+            ParallelEdges::Keep => {}
+            ParallelEdges::Deduplicate => {
+                // The sort is stable, so the first edge in input order is kept.
+                edges.par_sort_by_key(key);
+                edges.dedup_by_key(|edge| key(edge));
+            }
+            ParallelEdges::Aggregate(aggregate) => {
+                edges.par_sort_by_key(key);
+                edges.dedup_by(|next, kept| {
+                    let parallel = key(next) == key(kept);
+                    if parallel {
+                        kept.2 = aggregate(kept.2, next.2);
+                    }
+                    parallel
+                });
+            }
+        }
+        report.dropped_parallel_edges = edge_count - edges.len();
+
+        info!(
+            "Applied edge policies in {:?}: {:?}",
+            start.elapsed(),
+            report
+        );
+
+        (Self::with_max_node_id(edges, max_node_id), report)
+    }
+}
+
+/// Reads a node file and returns its largest node id, or `None` if the file
+/// does not contain any node ids.
+///
+/// A node file contains a node id per line and is used to declare nodes that
+/// are not part of any edge. Everything following the id on a line, e.g., a
+/// label, is ignored, as are empty lines.
+pub(crate) fn read_max_node_id<NI: Idx, P: AsRef<Path>>(path: P) -> Result<Option<NI>, Error> {
+    let bytes = std::fs::read(path)?;
+
+    let mut max_node_id = None;
+    for line in bytes.split(|b| *b == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            continue;
+        }
+        let (id, id_bytes) = NI::parse(line);
+        if id_bytes == 0 {
+            return Err(Error::InvalidRecord {
+                record: String::from_utf8_lossy(line).into_owned(),
+                reason: "expected a node id".to_string(),
+            });
+        }
+        max_node_id = max_node_id.max(Some(id));
+    }
+
+    Ok(max_node_id)
+}
+
 pub(crate) struct EdgeIterator<NI: Idx, I: IntoIterator<Item = (NI, NI)>>(pub I);
 
 impl<NI, I> From<EdgeIterator<NI, I>> for EdgeList<NI, ()>
@@ -171,9 +389,37 @@ where
     type Error = Error;
 
     fn try_from(path: InputPath<P>) -> Result<Self, Self::Error> {
-        let file = File::open(path.0.as_ref())?;
-        let mmap = unsafe { memmap2::MmapOptions::new().populate().map(&file)? };
-        EdgeList::try_from(mmap.as_ref())
+        let shards = super::shards::shard_paths(path.0.as_ref())?;
+
+        if let [path] = shards.as_slice() {
+            let bytes = super::read_file(path)?;
+            return EdgeList::try_from(&bytes[..]);
+        }
+
+        let start = crate::time::Instant::now();
+
+        // Shards are parsed in parallel and concatenated in shard order.
+        let lists = shards
+            .par_iter()
+            .map(|path| {
+                let bytes = super::read_file(path)?;
+                EdgeList::<NI, EV>::try_from(&bytes[..])
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut edges = Vec::with_capacity(lists.iter().map(|list| list.list.len()).sum());
+        for list in lists {
+            edges.extend(list.list.into_vec());
+        }
+
+        info!(
+            "Read {} edges from {} shards in {:?}",
+            edges.len(),
+            shards.len(),
+            start.elapsed()
+        );
+
+        Ok(EdgeList::new(edges))
     }
 }
 
@@ -185,7 +431,7 @@ where
     type Error = Error;
 
     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        let start = std::time::Instant::now();
+        let start = crate::time::Instant::now();
 
         let page_size = page_size::get();
         let cpu_count = num_cpus::get_physical();
@@ -197,58 +443,58 @@ where
             page_size, cpu_count, chunk_size
         );
 
-        let all_edges = Arc::new(Mutex::new(Vec::new()));
+        let all_edges = Mutex::new(Vec::new());
 
         let new_line_bytes = new_line_bytes(bytes);
 
-        std::thread::scope(|s| {
-            for start in (0..bytes.len()).step_by(chunk_size) {
-                let all_edges = Arc::clone(&all_edges);
-                s.spawn(move || {
-                    let mut end = usize::min(start + chunk_size, bytes.len());
-                    while end <= bytes.len() && bytes[end - 1] != b'\n' {
-                        end += 1;
+        // Chunks are parsed on the rayon thread pool, which falls back to the
+        // current thread on targets without threads, e.g., wasm32.
+        (0..bytes.len())
+            .into_par_iter()
+            .step_by(chunk_size)
+            .for_each(|start| {
+                let mut end = usize::min(start + chunk_size, bytes.len());
+                while end <= bytes.len() && bytes[end - 1] != b'\n' {
+                    end += 1;
+                }
+
+                let mut start = start;
+                if start != 0 {
+                    while bytes[start - 1] != b'\n' {
+                        start += 1;
                     }
-
-                    let mut start = start;
-                    if start != 0 {
-                        while bytes[start - 1] != b'\n' {
-                            start += 1;
+                }
+
+                let mut edges = Vec::new();
+                let mut chunk = &bytes[start..end];
+                while !chunk.is_empty() {
+                    let (source, source_bytes) = NI::parse(chunk);
+                    chunk = &chunk[source_bytes + 1..];
+
+                    let (target, target_bytes) = NI::parse(chunk);
+                    chunk = &chunk[target_bytes..];
+
+                    let value = match chunk.strip_prefix(b" ") {
+                        Some(value_chunk) => {
+                            let (value, value_bytes) = EV::parse(value_chunk);
+                            chunk = &value_chunk[value_bytes + new_line_bytes..];
+                            value
                         }
-                    }
+                        None => {
+                            chunk = &chunk[new_line_bytes..];
+                            // if the input does not have a value, the default for EV is used
+                            EV::parse(&[]).0
+                        }
+                    };
 
-                    let mut edges = Vec::new();
-                    let mut chunk = &bytes[start..end];
-                    while !chunk.is_empty() {
-                        let (source, source_bytes) = NI::parse(chunk);
-                        chunk = &chunk[source_bytes + 1..];
-
-                        let (target, target_bytes) = NI::parse(chunk);
-                        chunk = &chunk[target_bytes..];
-
-                        let value = match chunk.strip_prefix(b" ") {
-                            Some(value_chunk) => {
-                                let (value, value_bytes) = EV::parse(value_chunk);
-                                chunk = &value_chunk[value_bytes + new_line_bytes..];
-                                value
-                            }
-                            None => {
-                                chunk = &chunk[new_line_bytes..];
-                                // if the input does not have a value, the default for EV is used
-                                EV::parse(&[]).0
-                            }
-                        };
-
-                        edges.push((source, target, value));
-                    }
+                    edges.push((source, target, value));
+                }
 
-                    let mut all_edges = all_edges.lock();
-                    all_edges.append(&mut edges);
-                });
-            }
-        });
+                let mut all_edges = all_edges.lock();
+                all_edges.append(&mut edges);
+            });
 
-        let edges = Arc::try_unwrap(all_edges).unwrap().into_inner();
+        let edges = all_edges.into_inner();
 
         let elapsed = start.elapsed().as_millis() as f64 / 1000_f64;
 
@@ -344,4 +590,63 @@ mod tests {
 
         assert_eq!(3, edge_list.max_node_id());
     }
+
+    #[test]
+    fn edge_list_self_loop_policies() {
+        let edges = || EdgeList::<u32, ()>::new(vec![(0, 0, ()), (0, 1, ()), (3, 3, ())]);
+
+        let (edge_list, report) =
+            edges().apply_edge_policies(true, SelfLoops::Keep, ParallelEdges::Keep);
+        assert_eq!(edge_list.list.len(), 3);
+        assert_eq!(report, EdgeReport::default());
+
+        let (edge_list, report) =
+            edges().apply_edge_policies(true, SelfLoops::Count, ParallelEdges::Keep);
+        assert_eq!(edge_list.list.len(), 3);
+        assert_eq!(report.self_loops, 2);
+        assert_eq!(report.dropped_self_loops, 0);
+
+        let (edge_list, report) =
+            edges().apply_edge_policies(true, SelfLoops::Drop, ParallelEdges::Keep);
+        assert_eq!(edge_list.list.into_vec(), vec![(0, 1, ())]);
+        assert_eq!(report.self_loops, 2);
+        assert_eq!(report.dropped_self_loops, 2);
+    }
+
+    #[test]
+    fn edge_list_parallel_edge_policies() {
+        let edges = || EdgeList::<u32, u32>::new(vec![(1, 0, 1), (0, 1, 2), (0, 1, 3), (1, 2, 4)]);
+
+        let (edge_list, report) =
+            edges().apply_edge_policies(true, SelfLoops::Keep, ParallelEdges::Deduplicate);
+        assert_eq!(
+            edge_list.list.into_vec(),
+            vec![(0, 1, 2), (1, 0, 1), (1, 2, 4)]
+        );
+        assert_eq!(report.dropped_parallel_edges, 1);
+
+        let (edge_list, report) =
+            edges().apply_edge_policies(false, SelfLoops::Keep, ParallelEdges::Deduplicate);
+        assert_eq!(edge_list.list.into_vec(), vec![(1, 0, 1), (1, 2, 4)]);
+        assert_eq!(report.dropped_parallel_edges, 2);
+
+        // Values are folded in input order.
+        let (edge_list, report) = edges().apply_edge_policies(
+            false,
+            SelfLoops::Keep,
+            ParallelEdges::Aggregate(|a, b| 10 * a + b),
+        );
+        assert_eq!(edge_list.list.into_vec(), vec![(1, 0, 123), (1, 2, 4)]);
+        assert_eq!(report.dropped_parallel_edges, 2);
+    }
+
+    #[test]
+    fn edge_list_policies_keep_node_count() {
+        let edge_list = EdgeList::<u32, ()>::new(vec![(0, 1, ()), (4, 4, ())]);
+
+        let (edge_list, _) =
+            edge_list.apply_edge_policies(true, SelfLoops::Drop, ParallelEdges::Keep);
+
+        assert_eq!(edge_list.max_node_id(), 4);
+    }
 }
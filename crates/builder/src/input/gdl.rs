@@ -1,6 +1,9 @@
+use std::{convert::TryFrom, marker::PhantomData, path::Path};
+
 use crate::graph::csr::{CsrLayout, DirectedCsrGraph, NodeValues, UndirectedCsrGraph};
 use crate::index::Idx;
-use crate::input::EdgeList;
+use crate::input::{EdgeList, InputCapabilities, InputPath};
+use crate::Error;
 
 use gdl::CypherValue;
 
@@ -13,77 +16,259 @@ use std::fmt::Write;
 #[cfg(feature = "dotgraph")]
 use std::hash::Hash;
 
-/// A wrapper around [`gdl::CypherValue`] to allow custom From implementations.
-pub struct MyCypherValue<'a>(&'a CypherValue);
+/// Reads a graph from a file containing a [GDL](https://crates.io/crates/gdl)
+/// string.
+///
+/// This allows reading GDL files via [`GraphBuilder::file_format`](crate::GraphBuilder::file_format)
+/// like any other input format. Node properties are used as node values and
+/// relationship properties as edge values. If a node or relationship has
+/// multiple properties, the one with the smallest key is used, see
+/// [`MyCypherValue`] for the supported value types. With a node value type of
+/// `Vec<String>`, the labels of each node are used as its node value instead.
+/// Node ids are assigned by the GDL parser in order of appearance.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let path = std::env::temp_dir().join("graph_builder_gdl_example.gdl");
+/// std::fs::write(&path, "(a {v: 13}), (b {v: 37}), (a)-[{w: 0.5}]->(b)").unwrap();
+///
+/// let graph: DirectedCsrGraph<u32, u64, f32> = GraphBuilder::new()
+///     .file_format(GdlInput::default())
+///     .path(&path)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 2);
+/// assert_eq!(graph.node_value(1), &37);
+/// assert_eq!(graph.out_neighbors_with_values(0).as_slice(), &[Target::new(1, 0.5)]);
+///
+/// std::fs::write(&path, "(a:Person:Admin), (b:Person), (a)-->(b)").unwrap();
+///
+/// let graph: DirectedCsrGraph<u32, Vec<String>> = GraphBuilder::new()
+///     .file_format(GdlInput::default())
+///     .path(&path)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_value(0), &["Admin", "Person"]);
+/// assert_eq!(graph.node_value(1), &["Person"]);
+/// ```
+pub struct GdlInput<NI: Idx> {
+    _idx: PhantomData<NI>,
+}
+
+impl<NI: Idx> Default for GdlInput<NI> {
+    fn default() -> Self {
+        Self { _idx: PhantomData }
+    }
+}
+
+impl<NI: Idx> InputCapabilities<NI> for GdlInput<NI> {
+    type GraphInput = Gdl<NI>;
+}
+
+impl<NI: Idx> GdlInput<NI> {
+    /// Reads and parses the GDL file at the given path.
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> Result<Gdl<NI>, Error> {
+        Gdl::try_from(InputPath(path))
+    }
+}
+
+/// A parsed GDL graph.
+pub struct Gdl<NI: Idx> {
+    pub graph: gdl::Graph,
+    _idx: PhantomData<NI>,
+}
+
+impl<NI: Idx> Gdl<NI> {
+    /// Returns the sorted labels of each node, indexed by node id.
+    pub fn node_labels(&self) -> Vec<Vec<String>> {
+        let mut labels = vec![Vec::new(); self.graph.node_count()];
+
+        self.graph.nodes().for_each(|n| {
+            labels[n.id()] = sorted_labels(n);
+        });
+
+        labels
+    }
+}
+
+impl<NI: Idx> From<gdl::Graph> for Gdl<NI> {
+    fn from(graph: gdl::Graph) -> Self {
+        Self {
+            graph,
+            _idx: PhantomData,
+        }
+    }
+}
+
+impl<NI, P> TryFrom<InputPath<P>> for Gdl<NI>
+where
+    P: AsRef<Path>,
+    NI: Idx,
+{
+    type Error = Error;
+
+    fn try_from(path: InputPath<P>) -> Result<Self, Self::Error> {
+        let input = std::fs::read_to_string(path.0.as_ref())?;
+        let graph = input.parse::<gdl::Graph>()?;
+        Ok(Self::from(graph))
+    }
+}
+
+/// The value of a GDL node or relationship, which is converted into a node or
+/// edge value.
+///
+/// Numeric types are read from a property. Floating point types also accept
+/// integral properties, e.g., `{weight: 2}`. Integer types fail to convert if
+/// the property is not an integer or does not fit into the type. Nodes and
+/// relationships without properties get the default value. `Vec<String>`
+/// consists of the sorted labels of a node and `()` ignores the value.
+pub struct MyCypherValue<'a> {
+    property: Option<&'a CypherValue>,
+    labels: Vec<&'a str>,
+}
+
+impl<'a> MyCypherValue<'a> {
+    fn invalid(&self, expected: &str) -> Error {
+        Error::InvalidRecord {
+            record: format!("{:?}", self.property),
+            reason: format!("expected {expected} value"),
+        }
+    }
+}
+
+impl<'a> TryFrom<MyCypherValue<'a>> for () {
+    type Error = Error;
+
+    fn try_from(_: MyCypherValue) -> Result<Self, Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<MyCypherValue<'a>> for Vec<String> {
+    type Error = Error;
 
-impl<'a> From<MyCypherValue<'a>> for () {
-    fn from(_: MyCypherValue) -> Self {}
+    fn try_from(cv: MyCypherValue) -> Result<Self, Self::Error> {
+        Ok(cv.labels.into_iter().map(String::from).collect())
+    }
 }
 
-macro_rules! impl_from_cypher_value {
-    ($enum:path, $ty:ty) => {
-        impl<'a> ::std::convert::From<$crate::input::gdl::MyCypherValue<'a>> for $ty {
-            fn from(cv: $crate::input::gdl::MyCypherValue) -> Self {
-                if let $enum(f) = cv.0 {
-                    *f as $ty
-                } else {
-                    panic!("expected {} value", stringify!($ty))
+macro_rules! impl_try_from_cypher_value {
+    ($ty:ty) => {
+        impl<'a> ::std::convert::TryFrom<$crate::input::gdl::MyCypherValue<'a>> for $ty {
+            type Error = $crate::Error;
+
+            fn try_from(cv: $crate::input::gdl::MyCypherValue) -> Result<Self, Self::Error> {
+                match cv.property {
+                    Some(CypherValue::Integer(i)) => {
+                        <$ty>::try_from(*i).map_err(|_| cv.invalid(stringify!($ty)))
+                    }
+                    Some(_) => Err(cv.invalid(stringify!($ty))),
+                    None => Ok(<$ty>::default()),
                 }
             }
         }
     };
 }
 
-impl_from_cypher_value!(CypherValue::Float, f32);
-impl_from_cypher_value!(CypherValue::Float, f64);
-impl_from_cypher_value!(CypherValue::Integer, i32);
-impl_from_cypher_value!(CypherValue::Integer, i64);
-impl_from_cypher_value!(CypherValue::Integer, isize);
-impl_from_cypher_value!(CypherValue::Integer, u32);
-impl_from_cypher_value!(CypherValue::Integer, u64);
-impl_from_cypher_value!(CypherValue::Integer, usize);
+macro_rules! impl_float_try_from_cypher_value {
+    ($ty:ty) => {
+        impl<'a> ::std::convert::TryFrom<$crate::input::gdl::MyCypherValue<'a>> for $ty {
+            type Error = $crate::Error;
+
+            fn try_from(cv: $crate::input::gdl::MyCypherValue) -> Result<Self, Self::Error> {
+                match cv.property {
+                    Some(CypherValue::Float(f)) => Ok(*f as $ty),
+                    // allows writing integral weights, e.g., `{weight: 2}`
+                    Some(CypherValue::Integer(i)) => Ok(*i as $ty),
+                    Some(_) => Err(cv.invalid(stringify!($ty))),
+                    None => Ok(<$ty>::default()),
+                }
+            }
+        }
+    };
+}
+
+impl_float_try_from_cypher_value!(f32);
+impl_float_try_from_cypher_value!(f64);
+impl_try_from_cypher_value!(i32);
+impl_try_from_cypher_value!(i64);
+impl_try_from_cypher_value!(isize);
+impl_try_from_cypher_value!(u32);
+impl_try_from_cypher_value!(u64);
+impl_try_from_cypher_value!(usize);
+
+/// The relationship property that is preferably used as edge value.
+const WEIGHT_PROPERTY: &str = "weight";
+
+fn sorted_labels(node: &gdl::graph::Node) -> Vec<String> {
+    let mut labels = node.labels().map(String::from).collect::<Vec<_>>();
+    labels.sort();
+    labels
+}
 
-impl<'gdl, NI, EV> From<&'gdl gdl::Graph> for EdgeList<NI, EV>
+/// Creates an edge list from the relationships of a GDL graph.
+///
+/// The edge value is read from the `weight` property of a relationship, e.g.,
+/// `(a)-[{weight: 2.0}]->(b)`. If there is no such property, the property
+/// with the smallest key is used. Relationships without properties get the
+/// default edge value.
+impl<'gdl, NI, EV> TryFrom<&'gdl gdl::Graph> for EdgeList<NI, EV>
 where
     NI: Idx,
-    EV: From<MyCypherValue<'gdl>> + Default + Send + Sync,
+    EV: TryFrom<MyCypherValue<'gdl>, Error = Error> + Send + Sync,
 {
-    fn from(gdl_graph: &'gdl gdl::Graph) -> Self {
+    type Error = Error;
+
+    fn try_from(gdl_graph: &'gdl gdl::Graph) -> Result<Self, Self::Error> {
         let edges = gdl_graph
             .relationships()
             .map(|r| {
                 let source = gdl_graph.get_node(r.source()).unwrap().id();
                 let target = gdl_graph.get_node(r.target()).unwrap().id();
 
-                let value = if let Some(k) = r.property_keys().next() {
-                    EV::from(MyCypherValue(r.property_value(k).unwrap()))
-                } else {
-                    EV::default()
-                };
+                let property = r
+                    .property_value(WEIGHT_PROPERTY)
+                    .or_else(|| r.property_keys().min().and_then(|k| r.property_value(k)));
+                let value = EV::try_from(MyCypherValue {
+                    property,
+                    labels: Vec::new(),
+                })?;
 
-                (NI::new(source), NI::new(target), value)
+                Ok((NI::new(source), NI::new(target), value))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, Error>>()?;
 
-        EdgeList::new(edges)
+        Ok(EdgeList::new(edges))
     }
 }
 
-impl<'gdl, NV> From<&'gdl gdl::Graph> for NodeValues<NV>
+/// Creates the node values of a GDL graph.
+///
+/// The node value is read from the property with the smallest key, or, for
+/// `Vec<String>`, from the labels of a node.
+impl<'gdl, NV> TryFrom<&'gdl gdl::Graph> for NodeValues<NV>
 where
-    NV: From<MyCypherValue<'gdl>> + Default + Send + Sync,
+    NV: TryFrom<MyCypherValue<'gdl>, Error = Error> + Default + Send + Sync,
 {
-    fn from(gdl_graph: &'gdl gdl::Graph) -> Self {
+    type Error = Error;
+
+    fn try_from(gdl_graph: &'gdl gdl::Graph) -> Result<Self, Self::Error> {
         let mut node_values = Vec::with_capacity(gdl_graph.node_count());
         node_values.resize_with(gdl_graph.node_count(), || NV::default());
 
-        gdl_graph.nodes().for_each(|n| {
-            if let Some(k) = n.property_keys().next() {
-                node_values[n.id()] = NV::from(MyCypherValue(n.property_value(k).unwrap()));
-            }
-        });
+        for n in gdl_graph.nodes() {
+            let mut labels = n.labels().map(String::as_str).collect::<Vec<_>>();
+            labels.sort_unstable();
+            let property = n.property_keys().min().and_then(|k| n.property_value(k));
+            node_values[n.id()] = NV::try_from(MyCypherValue { property, labels })?;
+        }
 
-        NodeValues::new(node_values)
+        Ok(NodeValues::new(node_values))
     }
 }
 
@@ -155,54 +340,215 @@ where
     }
 }
 
-impl<'a, NI, NV, EV> From<(&'a gdl::Graph, CsrLayout)> for DirectedCsrGraph<NI, NV, EV>
+impl<'a, NI, NV, EV> TryFrom<(&'a gdl::Graph, CsrLayout)> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: TryFrom<MyCypherValue<'a>, Error = Error> + Default + Send + Sync,
+    EV: TryFrom<MyCypherValue<'a>, Error = Error> + Copy + Send + Sync,
+{
+    type Error = Error;
+
+    fn try_from((gdl_graph, csr_layout): (&'a gdl::Graph, CsrLayout)) -> Result<Self, Error> {
+        let node_values = NodeValues::try_from(gdl_graph)?;
+        let edge_list = EdgeList::try_from(gdl_graph)?;
+        Ok(DirectedCsrGraph::from((node_values, edge_list, csr_layout)))
+    }
+}
+
+impl<NI, NV, EV> TryFrom<(gdl::Graph, CsrLayout)> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    for<'a> NV: TryFrom<MyCypherValue<'a>, Error = Error> + Default + Send + Sync,
+    for<'a> EV: TryFrom<MyCypherValue<'a>, Error = Error> + Copy + Send + Sync,
+{
+    type Error = Error;
+
+    fn try_from((gdl_graph, csr_layout): (gdl::Graph, CsrLayout)) -> Result<Self, Error> {
+        DirectedCsrGraph::try_from((&gdl_graph, csr_layout))
+    }
+}
+
+impl<'a, NI, NV, EV> TryFrom<(&'a gdl::Graph, CsrLayout)> for UndirectedCsrGraph<NI, NV, EV>
 where
     NI: Idx,
-    NV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
-    EV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
+    NV: TryFrom<MyCypherValue<'a>, Error = Error> + Default + Send + Sync,
+    EV: TryFrom<MyCypherValue<'a>, Error = Error> + Copy + Send + Sync,
 {
-    fn from((gdl_graph, csr_layout): (&'a gdl::Graph, CsrLayout)) -> Self {
-        let node_values = NodeValues::from(gdl_graph);
-        let edge_list = EdgeList::from(gdl_graph);
-        DirectedCsrGraph::from((node_values, edge_list, csr_layout))
+    type Error = Error;
+
+    fn try_from((gdl_graph, csr_layout): (&'a gdl::Graph, CsrLayout)) -> Result<Self, Error> {
+        let node_values = NodeValues::try_from(gdl_graph)?;
+        let edge_list = EdgeList::try_from(gdl_graph)?;
+        Ok(UndirectedCsrGraph::from((
+            node_values,
+            edge_list,
+            csr_layout,
+        )))
     }
 }
 
-impl<NI, NV, EV> From<(gdl::Graph, CsrLayout)> for DirectedCsrGraph<NI, NV, EV>
+impl<NI, NV, EV> TryFrom<(gdl::Graph, CsrLayout)> for UndirectedCsrGraph<NI, NV, EV>
 where
     NI: Idx,
-    for<'a> NV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
-    for<'a> EV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
+    for<'a> NV: TryFrom<MyCypherValue<'a>, Error = Error> + Default + Send + Sync,
+    for<'a> EV: TryFrom<MyCypherValue<'a>, Error = Error> + Copy + Send + Sync,
 {
-    fn from((gdl_graph, csr_layout): (gdl::Graph, CsrLayout)) -> Self {
-        let node_values = NodeValues::from(&gdl_graph);
-        let edge_list = EdgeList::from(&gdl_graph);
-        DirectedCsrGraph::from((node_values, edge_list, csr_layout))
+    type Error = Error;
+
+    fn try_from((gdl_graph, csr_layout): (gdl::Graph, CsrLayout)) -> Result<Self, Error> {
+        UndirectedCsrGraph::try_from((&gdl_graph, csr_layout))
     }
 }
 
-impl<'a, NI, NV, EV> From<(&'a gdl::Graph, CsrLayout)> for UndirectedCsrGraph<NI, NV, EV>
+impl<NI, NV, EV> TryFrom<(Gdl<NI>, CsrLayout)> for DirectedCsrGraph<NI, NV, EV>
 where
     NI: Idx,
-    NV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
-    EV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
+    for<'a> NV: TryFrom<MyCypherValue<'a>, Error = Error> + Default + Send + Sync,
+    for<'a> EV: TryFrom<MyCypherValue<'a>, Error = Error> + Copy + Send + Sync,
 {
-    fn from((gdl_graph, csr_layout): (&'a gdl::Graph, CsrLayout)) -> Self {
-        let node_values = NodeValues::from(gdl_graph);
-        let edge_list = EdgeList::from(gdl_graph);
-        UndirectedCsrGraph::from((node_values, edge_list, csr_layout))
+    type Error = Error;
+
+    fn try_from((input, csr_layout): (Gdl<NI>, CsrLayout)) -> Result<Self, Error> {
+        DirectedCsrGraph::try_from((input.graph, csr_layout))
     }
 }
 
-impl<NI, NV, EV> From<(gdl::Graph, CsrLayout)> for UndirectedCsrGraph<NI, NV, EV>
+impl<NI, NV, EV> TryFrom<(Gdl<NI>, CsrLayout)> for UndirectedCsrGraph<NI, NV, EV>
 where
     NI: Idx,
-    for<'a> NV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
-    for<'a> EV: From<MyCypherValue<'a>> + Default + Copy + Send + Sync,
+    for<'a> NV: TryFrom<MyCypherValue<'a>, Error = Error> + Default + Send + Sync,
+    for<'a> EV: TryFrom<MyCypherValue<'a>, Error = Error> + Copy + Send + Sync,
 {
-    fn from((gdl_graph, csr_layout): (gdl::Graph, CsrLayout)) -> Self {
-        let node_values = NodeValues::from(&gdl_graph);
-        let edge_list = EdgeList::from(&gdl_graph);
-        UndirectedCsrGraph::from((node_values, edge_list, csr_layout))
+    type Error = Error;
+
+    fn try_from((input, csr_layout): (Gdl<NI>, CsrLayout)) -> Result<Self, Error> {
+        UndirectedCsrGraph::try_from((input.graph, csr_layout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::NodeValues as _;
+
+    #[test]
+    fn gdl_input_from_file() {
+        let path = std::env::temp_dir().join("graph_builder_gdl_input_test.gdl");
+        std::fs::write(
+            &path,
+            "(a:A:B), (b:C), (c), (a)-[{w: 4.2}]->(b), (a)-[{w: 1.3}]->(c)",
+        )
+        .unwrap();
+
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .file_format(GdlInput::default())
+            .path(&path)
+            .build()
+            .unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(
+            graph.neighbors_with_values(0).as_slice(),
+            &[Target::new(1, 4.2), Target::new(2, 1.3)]
+        );
+
+        let gdl = GdlInput::<u32>::default().read(&path).unwrap();
+
+        assert_eq!(
+            gdl.node_labels(),
+            vec![
+                vec![String::from("A"), String::from("B")],
+                vec![String::from("C")],
+                vec![]
+            ]
+        );
+    }
+
+    #[test]
+    fn gdl_weight_property() {
+        let graph: DirectedCsrGraph<u32, (), f64> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .gdl_str::<u32, _>(
+                "(a)-[{cost: 1.0, weight: 2.5}]->(b), (a)-[{weight: 3}]->(c), (b)-[{id: 7}]->(c), (c)-->(a)",
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            graph.out_neighbors_with_values(0).as_slice(),
+            &[Target::new(1, 2.5), Target::new(2, 3.0)]
+        );
+        assert_eq!(
+            graph.out_neighbors_with_values(1).as_slice(),
+            &[Target::new(2, 7.0)]
+        );
+        assert_eq!(
+            graph.out_neighbors_with_values(2).as_slice(),
+            &[Target::new(0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn gdl_smallest_property_key() {
+        let graph: DirectedCsrGraph<u32, u64, u64> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .gdl_str::<u32, _>("(a {z: 1, b: 2, m: 3})-[{z: 4, c: 5}]->(b {x: 6})")
+            .build()
+            .unwrap();
+
+        assert_eq!(graph.node_value(0), &2);
+        assert_eq!(graph.node_value(1), &6);
+        assert_eq!(
+            graph.out_neighbors_with_values(0).as_slice(),
+            &[Target::new(1, 5)]
+        );
+    }
+
+    #[test]
+    fn gdl_node_labels() {
+        let graph: UndirectedCsrGraph<u32, Vec<String>> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .gdl_str::<u32, _>("(a:C:A:B {v: 1})-->(b), (c:D)")
+            .build()
+            .unwrap();
+
+        assert_eq!(graph.node_value(0), &["A", "B", "C"]);
+        assert!(graph.node_value(1).is_empty());
+        assert_eq!(graph.node_value(2), &["D"]);
+    }
+
+    #[test]
+    fn gdl_invalid_values() {
+        assert!(matches!(
+            GraphBuilder::new()
+                .gdl_str::<u32, _>("(a)-[{weight: 'heavy'}]->(b)")
+                .build::<DirectedCsrGraph<u32, (), f32>>(),
+            Err(Error::InvalidRecord { .. })
+        ));
+        assert!(matches!(
+            GraphBuilder::new()
+                .gdl_str::<u32, _>("(a {v: 2.5})-->(b)")
+                .build::<DirectedCsrGraph<u32, u64>>(),
+            Err(Error::InvalidRecord { .. })
+        ));
+        assert!(matches!(
+            GraphBuilder::new()
+                .gdl_str::<u32, _>("(a {v: -1})-->(b)")
+                .build::<DirectedCsrGraph<u32, u32>>(),
+            Err(Error::InvalidRecord { .. })
+        ));
+    }
+
+    #[test]
+    fn gdl_input_invalid_file() {
+        let path = std::env::temp_dir().join("graph_builder_gdl_input_invalid.gdl");
+        std::fs::write(&path, "(a)-->(").unwrap();
+
+        assert!(matches!(
+            GdlInput::<u32>::default().read(&path),
+            Err(Error::GdlError { .. })
+        ));
     }
 }
@@ -1,9 +1,10 @@
 use log::info;
-use std::{fs::File, marker::PhantomData, path::Path};
+use std::{marker::PhantomData, path::Path};
 
 use crate::prelude::*;
 use rayon::prelude::*;
 
+#[derive(Clone, Copy)]
 pub struct Graph500Input<NI> {
     _phantom: PhantomData<NI>,
 }
@@ -53,9 +54,8 @@ where
     type Error = Error;
 
     fn try_from(path: InputPath<P>) -> Result<Self, Self::Error> {
-        let file = File::open(path.0.as_ref())?;
-        let mmap = unsafe { memmap2::MmapOptions::new().populate().map(&file)? };
-        Graph500::try_from(mmap.as_ref())
+        let bytes = super::read_file(path.0.as_ref())?;
+        Graph500::try_from(&bytes[..])
     }
 }
 
@@ -66,22 +66,19 @@ where
     type Error = Error;
 
     fn try_from(map: &[u8]) -> Result<Self, Self::Error> {
-        let start = std::time::Instant::now();
+        let start = crate::time::Instant::now();
 
         let file_size = map.len();
         let edge_count = map.len() / std::mem::size_of::<PackedEdge>();
         let node_count = edge_count / 16;
 
-        let map = map.as_ptr();
-        assert_eq!(map as usize % std::mem::align_of::<PackedEdge>(), 0);
-
-        let edges = unsafe { std::slice::from_raw_parts(map as *const PackedEdge, edge_count) };
-
         let mut all_edges = Vec::with_capacity(edge_count);
 
-        edges
-            .par_iter()
-            .map(|edge| {
+        // The bytes are not necessarily aligned for `PackedEdge`, e.g., if the
+        // file has been read into a `Vec<u8>`, so edges are read bytewise.
+        map.par_chunks_exact(std::mem::size_of::<PackedEdge>())
+            .map(|bytes| {
+                let edge = PackedEdge::from_le_bytes(bytes);
                 let source =
                     usize::try_from(edge.source()).expect("Could not read source id as usize");
                 let target =
@@ -106,21 +103,215 @@ where
     }
 }
 
+/// Reads a Graph500 file without copying its edges into memory.
+///
+/// In contrast to [`Graph500Input`], which converts the edges of the file
+/// into an edge list, the file is memory-mapped and reinterpreted as a slice
+/// of [`PackedEdge`]s, which are unpacked while the graph is built. This
+/// roughly halves the peak memory during graph construction.
+///
+/// Graph500 files are little-endian. Since the content is not converted,
+/// reading a file fails on big-endian targets. Reading also fails if a node
+/// id is not smaller than the node count that is implied by the edge count,
+/// which is usually the case for files with a different byte order.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let path = [env!("CARGO_MANIFEST_DIR"), "resources", "scale_8.graph500"]
+///     .iter()
+///     .collect::<std::path::PathBuf>();
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .file_format(Graph500MmapInput::default())
+///     .path(path)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 256);
+/// assert_eq!(graph.edge_count(), 4096);
+/// ```
+#[cfg(feature = "mmap")]
+#[derive(Clone, Copy)]
+pub struct Graph500MmapInput<NI> {
+    _phantom: PhantomData<NI>,
+}
+
+#[cfg(feature = "mmap")]
+impl<NI> Default for Graph500MmapInput<NI> {
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<NI: Idx> InputCapabilities<NI> for Graph500MmapInput<NI> {
+    type GraphInput = MappedGraph500<NI>;
+}
+
+/// The edges of a memory-mapped Graph500 file, see [`Graph500MmapInput`].
+#[cfg(feature = "mmap")]
+pub struct MappedGraph500<NI> {
+    map: memmap2::Mmap,
+    node_count: usize,
+    _phantom: PhantomData<NI>,
+}
+
+#[cfg(feature = "mmap")]
+impl<NI: Idx> MappedGraph500<NI> {
+    fn packed_edges(&self) -> &[PackedEdge] {
+        let edge_count = self.map.len() / std::mem::size_of::<PackedEdge>();
+        // The map is page-aligned and its length has been validated.
+        unsafe { std::slice::from_raw_parts(self.map.as_ptr() as *const PackedEdge, edge_count) }
+    }
+
+    fn unpack(edge: &PackedEdge) -> (NI, NI, ()) {
+        (
+            NI::new(edge.source() as usize),
+            NI::new(edge.target() as usize),
+            (),
+        )
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<NI: Idx> Edges for MappedGraph500<NI> {
+    type NI = NI;
+
+    type EV = ();
+
+    type EdgeIter<'a> = rayon::iter::Map<rayon::slice::Iter<'a, PackedEdge>, fn(&PackedEdge) -> (NI, NI, ())>
+        where
+            Self: 'a;
+
+    fn edges(&self) -> Self::EdgeIter<'_> {
+        self.packed_edges()
+            .par_iter()
+            .map(Self::unpack as fn(&PackedEdge) -> (NI, NI, ()))
+    }
+
+    fn max_node_id(&self) -> Self::NI {
+        NI::new(self.node_count - 1)
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.packed_edges().len()
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<NI, P> TryFrom<InputPath<P>> for MappedGraph500<NI>
+where
+    P: AsRef<Path>,
+    NI: Idx,
+{
+    type Error = Error;
+
+    fn try_from(path: InputPath<P>) -> Result<Self, Self::Error> {
+        if cfg!(target_endian = "big") {
+            return Err(Error::UnsupportedGraph {
+                reason: String::from(
+                    "memory-mapped Graph500 files can only be read on little-endian targets",
+                ),
+            });
+        }
+
+        let file = std::fs::File::open(path.0.as_ref())?;
+        let map = unsafe { memmap2::MmapOptions::new().populate().map(&file)? };
+
+        if map.len() % std::mem::size_of::<PackedEdge>() != 0 {
+            return Err(Error::InvalidRecord {
+                record: format!("{} bytes", map.len()),
+                reason: format!(
+                    "file size must be a multiple of the edge size of {} bytes",
+                    std::mem::size_of::<PackedEdge>()
+                ),
+            });
+        }
+
+        let edge_count = map.len() / std::mem::size_of::<PackedEdge>();
+        let node_count = usize::max(1, edge_count / 16);
+
+        let graph = MappedGraph500 {
+            map,
+            node_count,
+            _phantom: PhantomData,
+        };
+
+        let invalid = graph.packed_edges().par_iter().find_any(|edge| {
+            edge.source() >= node_count as u64 || edge.target() >= node_count as u64
+        });
+
+        if let Some(edge) = invalid {
+            return Err(Error::InvalidRecord {
+                record: format!("({}, {})", edge.source(), edge.target()),
+                reason: format!(
+                    "node ids must be smaller than the node count {node_count}, \
+                     the file may not be little-endian"
+                ),
+            });
+        }
+
+        Ok(graph)
+    }
+}
+
+/// An edge of a Graph500 file, which packs two 48-bit node ids into 12 bytes.
 // see https://github.com/graph500/graph500/blob/f89d643ce4aaae9a823d310c6ab2dd10e3d2982c/generator/graph_generator.h#L29-L33
 #[derive(Default, Copy, Clone, Debug)]
 #[repr(C)]
-struct PackedEdge {
+pub struct PackedEdge {
     v0_low: u32,
     v1_low: u32,
     high: u32,
 }
 
 impl PackedEdge {
-    fn source(&self) -> u64 {
+    /// Reads an edge from its 12 little-endian bytes in a Graph500 file.
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        let word = |i: usize| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap());
+        Self {
+            v0_low: word(0),
+            v1_low: word(1),
+            high: word(2),
+        }
+    }
+
+    pub fn source(&self) -> u64 {
         self.v0_low as u64 | (self.high as u64 & 0xFFFF) << 32
     }
 
-    fn target(&self) -> u64 {
+    pub fn target(&self) -> u64 {
         self.v1_low as u64 | (self.high as u64 >> 16) << 32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unaligned_bytes() {
+        let path = [env!("CARGO_MANIFEST_DIR"), "resources", "scale_8.graph500"]
+            .iter()
+            .collect::<std::path::PathBuf>();
+        let bytes = std::fs::read(path).unwrap();
+        let mut unaligned = vec![0_u8];
+        unaligned.extend_from_slice(&bytes);
+
+        let aligned = Graph500::<u64>::try_from(&bytes[..]).unwrap();
+        let unaligned = Graph500::<u64>::try_from(&unaligned[1..]).unwrap();
+
+        assert_eq!(unaligned.len(), 4096);
+        assert_eq!(unaligned.max_node_id(), aligned.max_node_id());
+        assert_eq!(
+            unaligned.edges().collect::<Vec<_>>(),
+            aligned.edges().collect::<Vec<_>>()
+        );
+    }
+}
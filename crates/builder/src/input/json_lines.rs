@@ -0,0 +1,431 @@
+//! Reads a graph from files in the JSON Lines format.
+//!
+//! Each line of an edge file is a JSON object representing a single edge,
+//! e.g., `{"source": 0, "target": 1, "weight": 0.5}`. Optionally, a node file
+//! provides node values. Each of its lines is a JSON object of the form
+//! `{"id": 0, "labels": ["Person"], "properties": {"age": 42}}`. The node
+//! value is either read from a property or, see
+//! [`JsonLinesInput::node_labels`], consists of the labels of the node.
+//!
+//! Edge and node values are deserialized using [`serde`], which means that
+//! any JSON value that can be deserialized into the value type is supported.
+
+use std::{marker::PhantomData, path::Path};
+
+use log::info;
+use rayon::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{Map, Value};
+
+use crate::{graph::csr::NodeValues, index::Idx, Error};
+
+use super::{edgelist::EdgeList, InputCapabilities};
+
+/// Used by the JSON Lines input to deserialize node and edge values.
+///
+/// The unit type `()` represents the absence of values. Fields mapped to a
+/// value of that type are ignored.
+pub trait JsonValue: DeserializeOwned + Default {
+    /// `false`, if the type carries no value and fields can be skipped.
+    const HAS_VALUE: bool = true;
+}
+
+impl JsonValue for () {
+    const HAS_VALUE: bool = false;
+}
+
+macro_rules! impl_json_value {
+    ($($ty:ty),+ $(,)?) => {
+        $(impl JsonValue for $ty {})+
+    };
+}
+
+impl_json_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, bool, String);
+
+impl JsonValue for Vec<String> {}
+
+/// A single line of a JSON Lines node file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct JsonNode {
+    /// The id of the node.
+    pub id: u64,
+    /// The labels of the node, if any.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// The properties of the node, if any.
+    #[serde(default)]
+    pub properties: Map<String, Value>,
+}
+
+/// Reads an edge list and optionally node values from JSON Lines files.
+///
+/// By default, the source node id, target node id and edge value are read
+/// from the `"source"`, `"target"` and `"weight"` fields of each edge object.
+/// Node values are read from the `"value"` property of each node object. If
+/// an object does not contain the value field, the default value is used.
+///
+/// Node ids in the node file must be smaller than the node count of the edges
+/// plus the number of nodes in the node file, so that the node values do not
+/// need to be allocated for arbitrarily large ids.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+///
+/// use graph_builder::prelude::*;
+///
+/// let edges = std::env::temp_dir().join("graph_builder_jsonl_example_edges.jsonl");
+/// let mut file = std::fs::File::create(&edges).unwrap();
+/// writeln!(file, r#"{{"from": 0, "to": 1, "cost": 0.5}}"#).unwrap();
+/// writeln!(file, r#"{{"from": 0, "to": 2, "cost": 1.0}}"#).unwrap();
+/// writeln!(file, r#"{{"from": 1, "to": 2}}"#).unwrap();
+///
+/// let nodes = std::env::temp_dir().join("graph_builder_jsonl_example_nodes.jsonl");
+/// let mut file = std::fs::File::create(&nodes).unwrap();
+/// writeln!(file, r#"{{"id": 0, "labels": ["Person"], "properties": {{"age": 42}}}}"#).unwrap();
+/// writeln!(file, r#"{{"id": 3, "labels": ["Person"], "properties": {{"age": 23}}}}"#).unwrap();
+///
+/// let graph: DirectedCsrGraph<u32, u32, f32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .jsonl_input(
+///         JsonLinesInput::default()
+///             .source("from")
+///             .target("to")
+///             .value("cost")
+///             .node_value("age"),
+///     )
+///     .path(&edges)
+///     .node_path(&nodes)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 4);
+/// assert_eq!(graph.node_value(3), &23);
+/// assert_eq!(graph.out_neighbors_with_values(1).as_slice(), &[Target::new(2, 0.0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonLinesInput<NI: Idx, NV = (), EV = ()> {
+    source: String,
+    target: String,
+    value: String,
+    node_value: String,
+    node_labels: bool,
+    _idx: PhantomData<(NI, NV, EV)>,
+}
+
+impl<NI: Idx, NV, EV> Default for JsonLinesInput<NI, NV, EV> {
+    fn default() -> Self {
+        Self {
+            source: String::from("source"),
+            target: String::from("target"),
+            value: String::from("weight"),
+            node_value: String::from("value"),
+            node_labels: false,
+            _idx: PhantomData,
+        }
+    }
+}
+
+impl<NI: Idx, NV, EV> JsonLinesInput<NI, NV, EV> {
+    /// Sets the field that contains the source node ids.
+    pub fn source(mut self, field: impl Into<String>) -> Self {
+        self.source = field.into();
+        self
+    }
+
+    /// Sets the field that contains the target node ids.
+    pub fn target(mut self, field: impl Into<String>) -> Self {
+        self.target = field.into();
+        self
+    }
+
+    /// Sets the field that contains the edge values.
+    pub fn value(mut self, field: impl Into<String>) -> Self {
+        self.value = field.into();
+        self
+    }
+
+    /// Sets the node property that contains the node values.
+    pub fn node_value(mut self, property: impl Into<String>) -> Self {
+        self.node_value = property.into();
+        self.node_labels = false;
+        self
+    }
+
+    /// Uses the labels of each node as its node value instead of a property,
+    /// e.g., for a node value type of `Vec<String>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use graph_builder::prelude::*;
+    ///
+    /// let input = JsonLinesInput::<u32, Vec<String>>::default().node_labels();
+    /// let nodes = input
+    ///     .parse_nodes(br#"{"id": 1, "labels": ["Person", "Admin"]}"#)
+    ///     .unwrap();
+    /// let node_values = input.node_values(nodes, 2).unwrap();
+    ///
+    /// let edges = input.parse(br#"{"source": 0, "target": 1}"#).unwrap();
+    /// let graph: DirectedCsrGraph<u32, Vec<String>> =
+    ///     DirectedCsrGraph::from((node_values, edges, CsrLayout::Sorted));
+    ///
+    /// assert_eq!(graph.node_value(1), &["Person", "Admin"]);
+    /// assert!(graph.node_value(0).is_empty());
+    /// ```
+    pub fn node_labels(mut self) -> Self {
+        self.node_labels = true;
+        self
+    }
+}
+
+impl<NI: Idx, NV, EV> InputCapabilities<NI> for JsonLinesInput<NI, NV, EV> {
+    type GraphInput = EdgeList<NI, EV>;
+}
+
+impl<NI, NV, EV> JsonLinesInput<NI, NV, EV>
+where
+    NI: Idx,
+    NV: JsonValue + Send + Sync,
+    EV: JsonValue + Copy + Send + Sync,
+{
+    /// Reads the edge list from the file at the given path.
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> Result<EdgeList<NI, EV>, Error> {
+        let bytes = super::read_file(path.as_ref())?;
+        self.parse(&bytes)
+    }
+
+    /// Parses the edge list from the given bytes.
+    ///
+    /// Lines are parsed in parallel, empty lines are skipped.
+    pub fn parse(&self, bytes: &[u8]) -> Result<EdgeList<NI, EV>, Error> {
+        let start = crate::time::Instant::now();
+
+        let edges = lines(bytes)
+            .map(|line| {
+                let record = serde_json::from_slice::<Map<String, Value>>(line)
+                    .map_err(|e| invalid(line, &e.to_string()))?;
+
+                let source = node_id::<NI>(&record, &self.source, line)?;
+                let target = node_id::<NI>(&record, &self.target, line)?;
+                let value = value::<EV>(&record, &self.value, line)?;
+
+                Ok((source, target, value))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let elapsed = start.elapsed().as_millis() as f64 / 1000_f64;
+
+        info!("Read {} edges in {:.2}s", edges.len(), elapsed);
+
+        Ok(EdgeList::new(edges))
+    }
+
+    /// Reads all nodes from the node file at the given path.
+    pub fn read_nodes<P: AsRef<Path>>(&self, path: P) -> Result<Vec<JsonNode>, Error> {
+        let bytes = super::read_file(path.as_ref())?;
+        self.parse_nodes(&bytes)
+    }
+
+    /// Parses all nodes from the given bytes.
+    pub fn parse_nodes(&self, bytes: &[u8]) -> Result<Vec<JsonNode>, Error> {
+        lines(bytes)
+            .map(|line| {
+                serde_json::from_slice::<JsonNode>(line).map_err(|e| invalid(line, &e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Creates the node values for a graph with at least `node_count` nodes.
+    ///
+    /// Nodes that are not contained in the given nodes get the default value.
+    /// Node ids must be smaller than `node_count` plus the number of nodes.
+    pub fn node_values(
+        &self,
+        nodes: Vec<JsonNode>,
+        node_count: usize,
+    ) -> Result<NodeValues<NV>, Error> {
+        let max_node_count = node_count.saturating_add(nodes.len());
+        let node_count =
+            nodes.iter().try_fold(node_count, |node_count, node| {
+                match usize::try_from(node.id) {
+                    Ok(id) if id < max_node_count => Ok(usize::max(node_count, id + 1)),
+                    _ => Err(Error::InvalidRecord {
+                        record: format!("{node:?}"),
+                        reason: format!("node id must be smaller than {max_node_count}"),
+                    }),
+                }
+            })?;
+
+        let mut values = Vec::with_capacity(node_count);
+        values.resize_with(node_count, NV::default);
+
+        for node in nodes {
+            let id = node.id as usize;
+            values[id] = if self.node_labels {
+                NV::deserialize(Value::from(node.labels))
+                    .map_err(|e| invalid(&[], &format!("labels of node {id}: {e}")))?
+            } else {
+                value::<NV>(&node.properties, &self.node_value, &[])?
+            };
+        }
+
+        Ok(NodeValues::new(values))
+    }
+}
+
+fn lines(bytes: &[u8]) -> impl ParallelIterator<Item = &[u8]> {
+    bytes
+        .par_split(|b| *b == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+}
+
+fn node_id<NI: Idx>(record: &Map<String, Value>, field: &str, line: &[u8]) -> Result<NI, Error> {
+    let id = record
+        .get(field)
+        .ok_or_else(|| invalid(line, &format!("missing field {field}")))?
+        .as_u64()
+        .ok_or_else(|| invalid(line, &format!("invalid node id in field {field}")))?;
+
+    usize::try_from(id)
+        .ok()
+        .and_then(NI::try_new)
+        .ok_or_else(|| {
+            invalid(
+                line,
+                &format!("node id {id} in field {field} does not fit into the node id type"),
+            )
+        })
+}
+
+fn value<V: JsonValue>(record: &Map<String, Value>, field: &str, line: &[u8]) -> Result<V, Error> {
+    match record.get(field) {
+        Some(value) if V::HAS_VALUE && !value.is_null() => {
+            V::deserialize(value).map_err(|e| invalid(line, &format!("field {field}: {e}")))
+        }
+        // if the record does not have a value, the default is used
+        _ => Ok(V::default()),
+    }
+}
+
+fn invalid(line: &[u8], reason: &str) -> Error {
+    Error::InvalidRecord {
+        record: String::from_utf8_lossy(line).into_owned(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn jsonl_edges() {
+        let bytes = b"{\"source\": 0, \"target\": 1, \"weight\": 0.5}\n\n{\"target\": 2, \"source\": 1}\r\n";
+
+        let edges = JsonLinesInput::<u32, (), f64>::default()
+            .parse(&bytes[..])
+            .unwrap();
+        let graph: DirectedCsrGraph<u32, (), f64> =
+            DirectedCsrGraph::from((edges, CsrLayout::Sorted));
+
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(
+            graph.out_neighbors_with_values(0).as_slice(),
+            &[Target::new(1, 0.5)]
+        );
+        assert_eq!(
+            graph.out_neighbors_with_values(1).as_slice(),
+            &[Target::new(2, 0.0)]
+        );
+    }
+
+    #[test]
+    fn jsonl_edges_ignore_values_for_unit() {
+        let bytes = b"{\"source\": 0, \"target\": 1, \"weight\": \"heavy\"}";
+
+        let edges = JsonLinesInput::<u32>::default().parse(&bytes[..]).unwrap();
+
+        assert_eq!(edges.max_node_id(), 1);
+    }
+
+    #[test]
+    fn jsonl_invalid_node_id() {
+        let bytes = b"{\"source\": -1, \"target\": 1}";
+
+        assert!(matches!(
+            JsonLinesInput::<u32>::default().parse(&bytes[..]),
+            Err(Error::InvalidRecord { .. })
+        ));
+    }
+
+    #[test]
+    fn jsonl_overflowing_node_id() {
+        let bytes = b"{\"source\": 256, \"target\": 1}";
+
+        assert!(matches!(
+            JsonLinesInput::<u8>::default().parse(&bytes[..]),
+            Err(Error::InvalidRecord { .. })
+        ));
+    }
+
+    #[test]
+    fn jsonl_node_ids_are_bounded() {
+        let bytes = b"{\"id\": 3}\n{\"id\": 18446744073709551615}";
+
+        let input = JsonLinesInput::<u32, String>::default();
+        let nodes = input.parse_nodes(&bytes[..]).unwrap();
+
+        assert!(matches!(
+            input.node_values(nodes, 2),
+            Err(Error::InvalidRecord { .. })
+        ));
+    }
+
+    #[test]
+    fn jsonl_node_labels() {
+        let bytes = b"{\"id\": 1, \"labels\": [\"A\", \"B\"], \"properties\": {\"name\": \"bob\"}}";
+
+        let input = JsonLinesInput::<u32, Vec<String>>::default().node_labels();
+        let nodes = input.parse_nodes(&bytes[..]).unwrap();
+        let node_values = input.node_values(nodes, 2).unwrap();
+
+        assert_eq!(
+            node_values.0.as_ref(),
+            &[vec![], vec![String::from("A"), String::from("B")]]
+        );
+
+        let input = JsonLinesInput::<u32, u64>::default().node_labels();
+        let nodes = input.parse_nodes(&bytes[..]).unwrap();
+
+        assert!(matches!(
+            input.node_values(nodes, 2),
+            Err(Error::InvalidRecord { .. })
+        ));
+    }
+
+    #[test]
+    fn jsonl_node_values() {
+        let bytes = b"{\"id\": 2, \"labels\": [\"A\", \"B\"], \"properties\": {\"name\": \"bob\"}}\n{\"id\": 0}";
+
+        let input = JsonLinesInput::<u32, String>::default().node_value("name");
+        let nodes = input.parse_nodes(&bytes[..]).unwrap();
+
+        assert_eq!(nodes[0].labels, vec![String::from("A"), String::from("B")]);
+
+        let node_values = input.node_values(nodes, 4).unwrap();
+
+        assert_eq!(
+            node_values.0.as_ref(),
+            &[
+                String::new(),
+                String::new(),
+                String::from("bob"),
+                String::new()
+            ]
+        );
+    }
+}
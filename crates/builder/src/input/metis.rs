@@ -0,0 +1,237 @@
+use log::info;
+use std::{convert::TryFrom, marker::PhantomData, path::Path};
+
+use crate::prelude::*;
+
+/// Reads an undirected graph from a file in the METIS adjacency format.
+///
+/// The first non-comment line is a header of the form `n m [fmt [ncon]]`,
+/// where `n` is the number of nodes and `m` the number of undirected edges.
+/// It is followed by one line per node, which lists the 1-based ids of its
+/// neighbors. Depending on `fmt`, each line starts with a node size and
+/// `ncon` node weights, and each neighbor id is followed by an edge weight.
+/// Lines starting with `%` are comments.
+///
+/// Edge weights are parsed into the edge value type, node sizes and node
+/// weights are skipped. Since every edge is listed by both of its end nodes,
+/// only the occurrence at the smaller node id is kept.
+///
+/// See the [METIS manual](https://github.com/KarypisLab/METIS/blob/master/manual/manual.pdf)
+/// for details.
+///
+/// # Example
+///
+/// ```ignore
+/// > cat my_graph.graph
+/// % triangle with a pendant node
+/// 4 4
+/// 2 3
+/// 1 3
+/// 1 2 4
+/// 3
+/// ```
+#[derive(Clone, Copy)]
+pub struct MetisInput<NI: Idx, EV = ()> {
+    _idx: PhantomData<(NI, EV)>,
+}
+
+impl<NI: Idx, EV> Default for MetisInput<NI, EV> {
+    fn default() -> Self {
+        Self { _idx: PhantomData }
+    }
+}
+
+impl<NI: Idx, EV> InputCapabilities<NI> for MetisInput<NI, EV> {
+    type GraphInput = Metis<NI, EV>;
+}
+
+pub struct Metis<NI: Idx, EV>(pub EdgeList<NI, EV>);
+
+impl<NI: Idx, EV: Copy + Send + Sync> Edges for Metis<NI, EV> {
+    type NI = NI;
+
+    type EV = EV;
+
+    type EdgeIter<'a> = rayon::iter::Copied<rayon::slice::Iter<'a, (Self::NI, Self::NI, Self::EV)>>
+        where
+            Self: 'a;
+
+    fn edges(&self) -> Self::EdgeIter<'_> {
+        self.0.edges()
+    }
+
+    fn max_node_id(&self) -> Self::NI {
+        self.0.max_node_id()
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<NI, P, EV> TryFrom<InputPath<P>> for Metis<NI, EV>
+where
+    P: AsRef<Path>,
+    NI: Idx,
+    EV: ParseValue + Send + Sync,
+{
+    type Error = Error;
+
+    fn try_from(path: InputPath<P>) -> Result<Self, Self::Error> {
+        let bytes = super::read_file(path.0.as_ref())?;
+        Metis::try_from(&bytes[..])
+    }
+}
+
+impl<NI, EV> TryFrom<&[u8]> for Metis<NI, EV>
+where
+    NI: Idx,
+    EV: ParseValue + Send + Sync,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let start = crate::time::Instant::now();
+
+        let text = std::str::from_utf8(bytes).map_err(|e| Error::InvalidRecord {
+            record: String::new(),
+            reason: e.to_string(),
+        })?;
+
+        let mut lines = text
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('%'));
+
+        let header = lines.next().ok_or_else(|| invalid("", "missing header"))?;
+        let header_fields = header
+            .split_whitespace()
+            .map(|field| {
+                field
+                    .parse::<usize>()
+                    .map_err(|_| invalid(header, "invalid header"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (node_count, edge_count) = match header_fields.as_slice() {
+            [n, m, ..] => (*n, *m),
+            _ => return Err(invalid(header, "header must contain node and edge count")),
+        };
+        let fmt = header_fields.get(2).copied().unwrap_or(0);
+        let has_node_sizes = fmt / 100 % 10 == 1;
+        let has_node_weights = fmt / 10 % 10 == 1;
+        let has_edge_weights = fmt % 10 == 1;
+        let node_weight_count = if has_node_weights {
+            header_fields.get(3).copied().unwrap_or(1)
+        } else {
+            0
+        };
+        let skip = has_node_sizes as usize + node_weight_count;
+
+        let mut edges = Vec::with_capacity(edge_count);
+
+        for source in 0..node_count {
+            // Trailing nodes without neighbors may be omitted.
+            let line = lines.next().unwrap_or_default();
+            let mut fields = line.split_whitespace().skip(skip);
+
+            while let Some(field) = fields.next() {
+                let target = field
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|target| target.checked_sub(1))
+                    .filter(|target| *target < node_count)
+                    .ok_or_else(|| invalid(line, "invalid neighbor id"))?;
+
+                let value = if has_edge_weights {
+                    let weight = fields
+                        .next()
+                        .ok_or_else(|| invalid(line, "missing edge weight"))?;
+                    EV::parse(weight.as_bytes()).0
+                } else {
+                    EV::parse(&[]).0
+                };
+
+                if source <= target {
+                    edges.push((NI::new(source), NI::new(target), value));
+                }
+            }
+        }
+
+        if edges.len() != edge_count {
+            return Err(invalid(
+                header,
+                &format!("expected {edge_count} edges, found {}", edges.len()),
+            ));
+        }
+
+        let elapsed = start.elapsed().as_millis() as f64 / 1000_f64;
+
+        info!("Read {} edges in {:.2}s", edges.len(), elapsed);
+
+        let max_node_id = NI::new(node_count.saturating_sub(1));
+        Ok(Self(EdgeList::with_max_node_id(edges, max_node_id)))
+    }
+}
+
+fn invalid(record: &str, reason: &str) -> Error {
+    Error::InvalidRecord {
+        record: record.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn metis_without_weights() {
+        let bytes = b"% comment\n4 4\n2 3\n1 3\n1 2 4\n3\n";
+
+        let metis = Metis::<u32, ()>::try_from(&bytes[..]).unwrap();
+        let graph: UndirectedCsrGraph<u32> = UndirectedCsrGraph::from((metis, CsrLayout::Sorted));
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.neighbors(2).as_slice(), &[0, 1, 3]);
+    }
+
+    #[test]
+    fn metis_with_node_and_edge_weights() {
+        let bytes = b"3 2 011\n5 2 7\n1 1 7 3 9\n2 2 9\n";
+
+        let metis = Metis::<u32, f32>::try_from(&bytes[..]).unwrap();
+        let graph: UndirectedCsrGraph<u32, (), f32> =
+            UndirectedCsrGraph::from((metis, CsrLayout::Sorted));
+
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(
+            graph.neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 7.0), Target::new(2, 9.0)]
+        );
+    }
+
+    #[test]
+    fn metis_isolated_nodes() {
+        let bytes = b"3 1\n2\n1\n";
+
+        let metis = Metis::<u32, ()>::try_from(&bytes[..]).unwrap();
+        let graph: UndirectedCsrGraph<u32> = UndirectedCsrGraph::from((metis, CsrLayout::Sorted));
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.degree(2), 0);
+    }
+
+    #[test]
+    fn metis_edge_count_mismatch() {
+        let bytes = b"2 2\n2\n1\n";
+
+        assert!(matches!(
+            Metis::<u32, ()>::try_from(&bytes[..]),
+            Err(Error::InvalidRecord { .. })
+        ));
+    }
+}
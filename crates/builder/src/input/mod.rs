@@ -1,4 +1,8 @@
+#[cfg(feature = "tokio")]
+#[cfg_attr(all(feature = "tokio", has_doc_cfg), doc(cfg(feature = "tokio")))]
+pub mod async_read;
 pub mod binary;
+pub mod csv_file;
 #[cfg(feature = "dotgraph")]
 #[cfg_attr(all(feature = "dotgraph", has_doc_cfg), doc(cfg(feature = "dotgraph")))]
 pub mod dotgraph;
@@ -7,17 +11,70 @@ pub mod edgelist;
 #[cfg_attr(all(feature = "gdl", has_doc_cfg), doc(cfg(feature = "gdl")))]
 pub mod gdl;
 pub mod graph500;
+#[cfg(feature = "jsonl")]
+#[cfg_attr(all(feature = "jsonl", has_doc_cfg), doc(cfg(feature = "jsonl")))]
+pub mod json_lines;
+pub mod metis;
+pub mod node_values;
+#[cfg(feature = "object_store")]
+#[cfg_attr(
+    all(feature = "object_store", has_doc_cfg),
+    doc(cfg(feature = "object_store"))
+)]
+pub mod object_store;
+#[cfg(feature = "parquet")]
+#[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+pub mod parquet_file;
+#[cfg(feature = "arrow")]
+#[cfg_attr(all(feature = "arrow", has_doc_cfg), doc(cfg(feature = "arrow")))]
+pub mod record_batch;
+mod shards;
 
+#[cfg(feature = "gdl")]
+pub use self::gdl::Gdl;
+#[cfg(feature = "gdl")]
+pub use self::gdl::GdlInput;
+#[cfg(feature = "tokio")]
+pub use async_read::read_graph_async;
+#[cfg(feature = "tokio")]
+pub use async_read::read_graphs_async;
 pub use binary::BinaryInput;
+pub use csv_file::CsvColumn;
+pub use csv_file::CsvInput;
+pub use csv_file::CsvNodeInput;
 #[cfg(feature = "dotgraph")]
 pub use dotgraph::DotGraph;
 #[cfg(feature = "dotgraph")]
 pub use dotgraph::DotGraphInput;
 pub use edgelist::EdgeList;
 pub use edgelist::EdgeListInput;
+pub use edgelist::EdgeReport;
 pub use edgelist::Edges;
+pub use edgelist::ParallelEdges;
+pub use edgelist::SelfLoops;
 pub use graph500::Graph500;
 pub use graph500::Graph500Input;
+#[cfg(feature = "mmap")]
+pub use graph500::Graph500MmapInput;
+#[cfg(feature = "mmap")]
+pub use graph500::MappedGraph500;
+#[cfg(feature = "jsonl")]
+pub use json_lines::JsonLinesInput;
+#[cfg(feature = "jsonl")]
+pub use json_lines::JsonNode;
+#[cfg(feature = "jsonl")]
+pub use json_lines::JsonValue;
+pub use metis::Metis;
+pub use metis::MetisInput;
+pub use node_values::NodeValuesInput;
+#[cfg(feature = "parquet")]
+pub use parquet_file::ParquetInput;
+#[cfg(feature = "parquet")]
+pub use parquet_file::ParquetNodeInput;
+#[cfg(feature = "arrow")]
+pub use record_batch::ArrowEdgeList;
+#[cfg(feature = "arrow")]
+pub use record_batch::ArrowInput;
 
 use crate::index::Idx;
 
@@ -27,7 +84,12 @@ pub trait InputCapabilities<NI: Idx> {
     type GraphInput;
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Direction {
     Outgoing,
     Incoming,
@@ -54,7 +116,8 @@ pub trait ParseValue: Default + Sized {
     /// # Return
     ///
     /// Returns a tuple containing two entries. The first is the parsed value,
-    /// the second is the index of the byte right after the parsed value.
+    /// the second is the index of the byte right after the parsed value. If
+    /// the slice does not start with a value, the index is `0`.
     fn parse(bytes: &[u8]) -> (Self, usize);
 }
 
@@ -102,6 +165,22 @@ impl_parse_value!(
 
 impl_parse_value!(parse_float, f32, f64);
 
-fn parse_float<T: fast_float::FastFloat>(bytes: &[u8]) -> (T, usize) {
-    fast_float::parse_partial(bytes).unwrap()
+fn parse_float<T: fast_float::FastFloat + Default>(bytes: &[u8]) -> (T, usize) {
+    // like for integers, no bytes are consumed if there is no number
+    fast_float::parse_partial(bytes).unwrap_or_default()
+}
+
+/// Reads the content of the file at the given path.
+///
+/// If the `mmap` feature is enabled, the file is memory-mapped. Otherwise,
+/// e.g., on targets without memory mapping, the file is read into memory.
+#[cfg(feature = "mmap")]
+pub(crate) fn read_file(path: &std::path::Path) -> std::io::Result<memmap2::Mmap> {
+    let file = std::fs::File::open(path)?;
+    unsafe { memmap2::MmapOptions::new().populate().map(&file) }
+}
+
+#[cfg(not(feature = "mmap"))]
+pub(crate) fn read_file(path: &std::path::Path) -> std::io::Result<Vec<u8>> {
+    std::fs::read(path)
 }
@@ -0,0 +1,40 @@
+//! Reads node values from files that contain a node id and a value per
+//! record, e.g., node features that are exported separately from the edges.
+//!
+//! Node values are joined to the graph by node id while the graph is built,
+//! so that nodes without a record get the default value and the node count
+//! is extended to include every node id of the file.
+
+use std::path::Path;
+
+use crate::{graph::csr::NodeValues, index::Idx, Error};
+
+/// A file format that contains a node id and a node value per record.
+pub trait NodeValuesInput<NI: Idx, NV> {
+    /// Reads the node id and node value of every record of the file at the
+    /// given path.
+    fn read_node_values(&self, path: &Path) -> Result<Vec<(NI, NV)>, Error>;
+}
+
+/// Creates the node values for a graph with at least `node_count` nodes.
+///
+/// Nodes without a record get the default value. If a node id appears in
+/// multiple records, the last record wins.
+pub(crate) fn join_node_values<NI, NV>(node_count: usize, records: Vec<(NI, NV)>) -> NodeValues<NV>
+where
+    NI: Idx,
+    NV: Default,
+{
+    let node_count = records
+        .iter()
+        .map(|(id, _)| id.index() + 1)
+        .fold(node_count, usize::max);
+
+    let mut values = Vec::with_capacity(node_count);
+    values.resize_with(node_count, NV::default);
+    for (id, value) in records {
+        values[id.index()] = value;
+    }
+
+    NodeValues::new(values)
+}
@@ -0,0 +1,83 @@
+//! Reads inputs from object stores, such as Amazon S3 or Google Cloud
+//! Storage, instead of the local file system.
+//!
+//! An input path refers to an object if it is a URL with one of the schemes
+//! `s3`, `gs`, `http` or `https`, e.g., `s3://bucket/graphs/edges.el.gz`.
+//! Credentials and other options of the store are read from environment
+//! variables, e.g., `AWS_ACCESS_KEY_ID`, `AWS_REGION` or
+//! `GOOGLE_SERVICE_ACCOUNT`, using the configuration keys of the
+//! [`object_store`](https://docs.rs/object_store) crate.
+//!
+//! Objects with a `.gz` or `.zst` extension are decompressed while they are
+//! downloaded.
+//!
+//! Objects are only supported by the async readers, e.g.,
+//! [`GraphBuilder::build_async`](crate::GraphBuilder::build_async) and
+//! [`read_graph_async`](crate::input::read_graph_async).
+
+use std::path::Path;
+
+use async_compression::tokio::bufread::{GzipDecoder, ZstdDecoder};
+use futures::TryStreamExt;
+use object_store::ObjectStore;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio_util::io::StreamReader;
+use url::Url;
+
+use crate::Error;
+
+const SCHEMES: [&str; 4] = ["s3", "gs", "http", "https"];
+
+/// Returns the URL of the object that the given path refers to, or `None`
+/// if the path refers to a local file.
+pub fn object_url(path: &Path) -> Option<Url> {
+    let url = Url::parse(path.to_str()?).ok()?;
+    SCHEMES.contains(&url.scheme()).then_some(url)
+}
+
+/// Downloads the object at the given URL and decompresses it, if its name
+/// ends with `.gz` or `.zst`.
+pub async fn read_object(url: &Url) -> Result<Vec<u8>, Error> {
+    // Configuration keys are the lowercase names of the environment
+    // variables, unrelated variables are ignored by the store.
+    let options = std::env::vars().map(|(key, value)| (key.to_ascii_lowercase(), value));
+    let (store, path) = object_store::parse_url_opts(url, options).map_err(std::io::Error::from)?;
+
+    let stream = store
+        .get(&path)
+        .await
+        .map_err(std::io::Error::from)?
+        .into_stream()
+        .map_err(std::io::Error::from);
+    let reader = StreamReader::new(stream);
+
+    let bytes = match path.extension() {
+        Some("gz") => read_to_end(GzipDecoder::new(reader)).await?,
+        Some("zst") => read_to_end(ZstdDecoder::new(reader)).await?,
+        _ => read_to_end(reader).await?,
+    };
+
+    Ok(bytes)
+}
+
+async fn read_to_end(reader: impl AsyncRead) -> std::io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    tokio::pin!(reader);
+    reader.read_to_end(&mut bytes).await?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn object_urls() {
+        assert!(object_url(Path::new("s3://bucket/edges.el")).is_some());
+        assert!(object_url(Path::new("gs://bucket/part-00000.gz")).is_some());
+        assert!(object_url(Path::new("https://example.com/edges.el")).is_some());
+        assert!(object_url(Path::new("/data/edges.el")).is_none());
+        assert!(object_url(Path::new("edges.el")).is_none());
+        assert!(object_url(Path::new("file:///data/edges.el")).is_none());
+    }
+}
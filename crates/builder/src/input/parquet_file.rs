@@ -0,0 +1,362 @@
+//! Reads a graph from a Parquet file.
+//!
+//! Each row of the file represents an edge. Only the configured source,
+//! target and value columns are read. Row groups are decoded in parallel and
+//! the resulting record batches are used as an [`ArrowEdgeList`].
+
+use std::{fs::File, marker::PhantomData, path::Path};
+
+use arrow_array::RecordBatch;
+use arrow_schema::ArrowError;
+use parquet::arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ProjectionMask};
+use rayon::prelude::*;
+
+use crate::{index::Idx, Error};
+
+use super::{
+    node_values::NodeValuesInput,
+    record_batch::{node_column, ArrowEdgeList, ArrowIdx, ArrowInput, ArrowValue},
+    InputCapabilities,
+};
+
+/// Reads an edge list from a Parquet file.
+///
+/// By default, node ids are read from the columns `source` and `target` and
+/// edge values from the column `weight`. The node id columns may have any
+/// integer type. If their type matches the node id type, the decoded buffers
+/// are used without copying.
+///
+/// # Example
+///
+/// ```
+/// use std::{fs::File, sync::Arc};
+///
+/// use arrow_array::{RecordBatch, UInt32Array};
+/// use graph_builder::prelude::*;
+/// use parquet::arrow::ArrowWriter;
+///
+/// let batch = RecordBatch::try_from_iter(vec![
+///     ("from", Arc::new(UInt32Array::from(vec![0, 0, 1])) as _),
+///     ("to", Arc::new(UInt32Array::from(vec![1, 2, 2])) as _),
+/// ])
+/// .unwrap();
+///
+/// let path = std::env::temp_dir().join("graph_builder_parquet_example.parquet");
+/// let mut writer = ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.close().unwrap();
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .parquet_input(ParquetInput::default().source("from").target("to"))
+///     .path(&path)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_count(), 3);
+/// ```
+pub struct ParquetInput<NI: Idx, EV = ()> {
+    columns: ArrowInput<NI, EV>,
+    batch_size: Option<usize>,
+}
+
+impl<NI: Idx, EV> Default for ParquetInput<NI, EV> {
+    fn default() -> Self {
+        Self {
+            columns: ArrowInput::default(),
+            batch_size: None,
+        }
+    }
+}
+
+impl<NI: Idx, EV> ParquetInput<NI, EV> {
+    /// Sets the name of the column that contains the source node ids.
+    pub fn source(mut self, column: impl Into<String>) -> Self {
+        self.columns = self.columns.source(column);
+        self
+    }
+
+    /// Sets the name of the column that contains the target node ids.
+    pub fn target(mut self, column: impl Into<String>) -> Self {
+        self.columns = self.columns.target(column);
+        self
+    }
+
+    /// Sets the name of the column that contains the edge values.
+    pub fn value(mut self, column: impl Into<String>) -> Self {
+        self.columns = self.columns.value(column);
+        self
+    }
+
+    /// Sets the maximum number of rows per decoded record batch.
+    ///
+    /// If not set, the default of the Parquet reader is used.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+}
+
+impl<NI: ArrowIdx, EV: ArrowValue> InputCapabilities<NI> for ParquetInput<NI, EV> {
+    type GraphInput = ArrowEdgeList<NI, EV>;
+}
+
+impl<NI: ArrowIdx, EV: ArrowValue> ParquetInput<NI, EV> {
+    /// Reads the edge list from the Parquet file at the given path.
+    ///
+    /// Each row group is read by a separate rayon task.
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> Result<ArrowEdgeList<NI, EV>, Error> {
+        let batches = read_batches(path.as_ref(), &self.columns.columns(), self.batch_size)?;
+        ArrowEdgeList::try_new(&self.columns, batches)
+    }
+}
+
+/// Reads node values from a Parquet file.
+///
+/// By default, node ids are read from the column `id` and node values from
+/// the column `value`. The node id column may have any integer type. Nodes
+/// without a row get the default value of `NV`.
+///
+/// # Example
+///
+/// ```
+/// use std::{fs::File, sync::Arc};
+///
+/// use arrow_array::{Float32Array, RecordBatch, UInt32Array};
+/// use graph_builder::prelude::*;
+/// use parquet::arrow::ArrowWriter;
+///
+/// let batch = RecordBatch::try_from_iter(vec![
+///     ("node", Arc::new(UInt32Array::from(vec![0, 2])) as _),
+///     ("score", Arc::new(Float32Array::from(vec![0.5, 1.5])) as _),
+/// ])
+/// .unwrap();
+///
+/// let path = std::env::temp_dir().join("graph_builder_parquet_node_example.parquet");
+/// let mut writer = ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.close().unwrap();
+///
+/// let edge_path = std::env::temp_dir().join("graph_builder_parquet_node_example.el");
+/// std::fs::write(&edge_path, "0 1\n1 2\n").unwrap();
+///
+/// let graph: DirectedCsrGraph<u32, f32> = GraphBuilder::new()
+///     .file_format(EdgeListInput::default())
+///     .path(&edge_path)
+///     .node_values_file(ParquetNodeInput::default().id("node").value("score"), &path)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_value(0), &0.5);
+/// assert_eq!(graph.node_value(1), &0.0);
+/// assert_eq!(graph.node_value(2), &1.5);
+/// ```
+pub struct ParquetNodeInput<NI: Idx, NV> {
+    id: String,
+    value: String,
+    batch_size: Option<usize>,
+    _idx: PhantomData<(NI, NV)>,
+}
+
+impl<NI: Idx, NV> Default for ParquetNodeInput<NI, NV> {
+    fn default() -> Self {
+        Self {
+            id: String::from("id"),
+            value: String::from("value"),
+            batch_size: None,
+            _idx: PhantomData,
+        }
+    }
+}
+
+impl<NI: Idx, NV> ParquetNodeInput<NI, NV> {
+    /// Sets the name of the column that contains the node ids.
+    pub fn id(mut self, column: impl Into<String>) -> Self {
+        self.id = column.into();
+        self
+    }
+
+    /// Sets the name of the column that contains the node values.
+    pub fn value(mut self, column: impl Into<String>) -> Self {
+        self.value = column.into();
+        self
+    }
+
+    /// Sets the maximum number of rows per decoded record batch.
+    ///
+    /// If not set, the default of the Parquet reader is used.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+}
+
+impl<NI: ArrowIdx, NV: ArrowValue> NodeValuesInput<NI, NV> for ParquetNodeInput<NI, NV> {
+    fn read_node_values(&self, path: &Path) -> Result<Vec<(NI, NV)>, Error> {
+        let batches = read_batches(path, &[&self.id, &self.value], self.batch_size)?;
+
+        let mut records = Vec::new();
+        for batch in batches {
+            let ids = node_column::<NI>(&batch, &self.id)?;
+            let values = NV::column(&batch, &self.value)?;
+            records.extend(
+                ids.values()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| (*id, NV::value(&values, i))),
+            );
+        }
+
+        Ok(records)
+    }
+}
+
+/// Reads the given columns of the Parquet file at the given path.
+///
+/// Each row group is read by a separate rayon task.
+fn read_batches(
+    path: &Path,
+    columns: &[&str],
+    batch_size: Option<usize>,
+) -> Result<Vec<RecordBatch>, Error> {
+    let row_groups = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?
+        .metadata()
+        .num_row_groups();
+
+    let batches = (0..row_groups)
+        .into_par_iter()
+        .map(|row_group| read_row_group(path, row_group, columns, batch_size))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(batches.into_iter().flatten().collect())
+}
+
+fn read_row_group(
+    path: &Path,
+    row_group: usize,
+    columns: &[&str],
+    batch_size: Option<usize>,
+) -> Result<Vec<RecordBatch>, Error> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+    // Missing columns are reported when reading the columns from the batches.
+    let roots = columns
+        .iter()
+        .filter_map(|column| builder.schema().index_of(column).ok())
+        .collect::<Vec<_>>();
+    let projection = ProjectionMask::roots(builder.parquet_schema(), roots);
+
+    let mut builder = builder
+        .with_row_groups(vec![row_group])
+        .with_projection(projection);
+
+    if let Some(batch_size) = batch_size {
+        builder = builder.with_batch_size(batch_size);
+    }
+
+    let batches = builder.build()?.collect::<Result<Vec<_>, ArrowError>>()?;
+
+    Ok(batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, path::Path, sync::Arc};
+
+    use arrow_array::{Float32Array, Int64Array, RecordBatch};
+    use parquet::{arrow::ArrowWriter, file::properties::WriterProperties};
+
+    use crate::prelude::*;
+
+    fn write_parquet(path: &Path, max_row_group_size: usize) {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "source",
+                Arc::new(Int64Array::from(vec![0, 0, 1, 2, 3])) as _,
+            ),
+            (
+                "target",
+                Arc::new(Int64Array::from(vec![1, 2, 2, 3, 0])) as _,
+            ),
+            (
+                "label",
+                Arc::new(Int64Array::from(vec![7, 7, 7, 7, 7])) as _,
+            ),
+            (
+                "weight",
+                Arc::new(Float32Array::from(vec![0.1, 0.2, 1.2, 2.3, 3.0])) as _,
+            ),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(max_row_group_size)
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    #[test]
+    fn multiple_row_groups() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("edges.parquet");
+        write_parquet(&path, 2);
+
+        let edges = ParquetInput::<u32, f32>::default()
+            .batch_size(1)
+            .read(&path)
+            .unwrap();
+        assert_eq!(edges.len(), 5);
+
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .parquet_input(ParquetInput::default())
+            .path(&path)
+            .build()
+            .unwrap();
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(
+            graph.out_neighbors_with_values(0).as_slice(),
+            &[Target::new(1, 0.1), Target::new(2, 0.2)]
+        );
+        assert_eq!(
+            graph.in_neighbors_with_values(0).as_slice(),
+            &[Target::new(3, 3.0)]
+        );
+    }
+
+    #[test]
+    fn node_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("edges.parquet");
+        write_parquet(&path, 2);
+
+        let records = ParquetNodeInput::<u32, f32>::default()
+            .id("target")
+            .value("weight")
+            .read_node_values(&path)
+            .unwrap();
+
+        assert_eq!(
+            records,
+            vec![(1, 0.1), (2, 0.2), (2, 1.2), (3, 2.3), (0, 3.0)]
+        );
+    }
+
+    #[test]
+    fn missing_column() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("edges.parquet");
+        write_parquet(&path, 1024);
+
+        let result: Result<UndirectedCsrGraph<u64>, Error> = GraphBuilder::new()
+            .parquet_input(ParquetInput::default().target("dst"))
+            .path(&path)
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidColumn { column, .. }) if column == "dst"));
+    }
+}
@@ -0,0 +1,504 @@
+//! Reads a graph from Arrow record batches.
+//!
+//! Each row of a record batch represents an edge. Source and target node ids
+//! are read from two integer columns and edge values from an optional third
+//! column. If the columns already have the Arrow type that corresponds to the
+//! node id type, e.g., `UInt32` for `u32`, the underlying buffers are used as
+//! they are and no intermediate edge list is materialized. Columns of other
+//! integer types are converted once while creating the edge list.
+
+use std::marker::PhantomData;
+
+use arrow_array::{
+    cast::AsArray,
+    types::{
+        ArrowPrimitiveType, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+        UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+    Array, ArrayRef, ArrowNativeTypeOp, PrimitiveArray, RecordBatch,
+};
+use arrow_schema::DataType;
+use rayon::prelude::*;
+
+use crate::{index::Idx, Error};
+
+use super::{edgelist::Edges, InputCapabilities};
+
+/// Node id types that have a corresponding Arrow primitive type.
+pub trait ArrowIdx: Idx + ArrowNativeTypeOp {
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+macro_rules! impl_arrow_idx {
+    ($($ty:ty => $arrow_ty:ty),+ $(,)?) => {
+        $(
+            impl ArrowIdx for $ty {
+                type ArrowType = $arrow_ty;
+            }
+        )+
+    };
+}
+
+impl_arrow_idx!(
+    u8 => UInt8Type,
+    u16 => UInt16Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+    i8 => Int8Type,
+    i16 => Int16Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+);
+
+/// Edge value types that can be read from an Arrow column.
+///
+/// The unit type `()` is used for graphs without edge values, in which case no
+/// value column is read.
+pub trait ArrowValue: Copy + Send + Sync + Sized {
+    type Column: Send + Sync;
+
+    /// Whether values are read from a column.
+    const HAS_COLUMN: bool = true;
+
+    fn column(batch: &RecordBatch, name: &str) -> Result<Self::Column, Error>;
+
+    fn value(column: &Self::Column, index: usize) -> Self;
+}
+
+impl ArrowValue for () {
+    type Column = ();
+
+    const HAS_COLUMN: bool = false;
+
+    fn column(_: &RecordBatch, _: &str) -> Result<Self::Column, Error> {
+        Ok(())
+    }
+
+    fn value(_: &Self::Column, _: usize) -> Self {}
+}
+
+macro_rules! impl_arrow_value {
+    ($($ty:ty => $arrow_ty:ty),+ $(,)?) => {
+        $(
+            impl ArrowValue for $ty {
+                type Column = PrimitiveArray<$arrow_ty>;
+
+                fn column(batch: &RecordBatch, name: &str) -> Result<Self::Column, Error> {
+                    let column = non_null_column(batch, name)?;
+                    column
+                        .as_primitive_opt::<$arrow_ty>()
+                        .cloned()
+                        .ok_or_else(|| invalid_type(name, &<$arrow_ty>::DATA_TYPE, column.data_type()))
+                }
+
+                fn value(column: &Self::Column, index: usize) -> Self {
+                    column.value(index)
+                }
+            }
+        )+
+    };
+}
+
+impl_arrow_value!(
+    f32 => Float32Type,
+    f64 => Float64Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+);
+
+/// Configures which columns of a record batch are used to build a graph.
+///
+/// By default, node ids are read from the columns `source` and `target` and
+/// edge values from the column `weight`.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use arrow_array::{Float32Array, RecordBatch, UInt32Array};
+/// use graph_builder::prelude::*;
+///
+/// let batch = RecordBatch::try_from_iter(vec![
+///     ("src", Arc::new(UInt32Array::from(vec![0, 0, 1])) as _),
+///     ("dst", Arc::new(UInt32Array::from(vec![1, 2, 2])) as _),
+///     ("weight", Arc::new(Float32Array::from(vec![0.5, 1.0, 1.5])) as _),
+/// ])
+/// .unwrap();
+///
+/// let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .arrow_input(ArrowInput::default().source("src").target("dst"))
+///     .record_batches(vec![batch])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_count(), 3);
+/// assert_eq!(graph.out_neighbors_with_values(0).as_slice(), &[Target::new(1, 0.5), Target::new(2, 1.0)]);
+/// ```
+pub struct ArrowInput<NI: Idx, EV = ()> {
+    source: String,
+    target: String,
+    value: String,
+    _idx: PhantomData<(NI, EV)>,
+}
+
+impl<NI: Idx, EV> Default for ArrowInput<NI, EV> {
+    fn default() -> Self {
+        Self {
+            source: String::from("source"),
+            target: String::from("target"),
+            value: String::from("weight"),
+            _idx: PhantomData,
+        }
+    }
+}
+
+impl<NI: Idx, EV> ArrowInput<NI, EV> {
+    /// Sets the name of the column that contains the source node ids.
+    pub fn source(mut self, column: impl Into<String>) -> Self {
+        self.source = column.into();
+        self
+    }
+
+    /// Sets the name of the column that contains the target node ids.
+    pub fn target(mut self, column: impl Into<String>) -> Self {
+        self.target = column.into();
+        self
+    }
+
+    /// Sets the name of the column that contains the edge values.
+    pub fn value(mut self, column: impl Into<String>) -> Self {
+        self.value = column.into();
+        self
+    }
+}
+
+impl<NI: ArrowIdx, EV: ArrowValue> ArrowInput<NI, EV> {
+    /// Returns the names of all columns that are read.
+    pub fn columns(&self) -> Vec<&str> {
+        let mut columns = vec![self.source.as_str(), self.target.as_str()];
+        if EV::HAS_COLUMN {
+            columns.push(self.value.as_str());
+        }
+        columns
+    }
+}
+
+impl<NI: ArrowIdx, EV: ArrowValue> InputCapabilities<NI> for ArrowInput<NI, EV> {
+    type GraphInput = ArrowEdgeList<NI, EV>;
+}
+
+/// An edge list that is backed by the columns of Arrow record batches.
+pub struct ArrowEdgeList<NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    batches: Vec<ArrowEdgeBatch<NI, EV>>,
+}
+
+struct ArrowEdgeBatch<NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    sources: PrimitiveArray<NI::ArrowType>,
+    targets: PrimitiveArray<NI::ArrowType>,
+    values: EV::Column,
+}
+
+impl<NI, EV> ArrowEdgeList<NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    /// Creates an edge list from the given record batches using the columns
+    /// configured by `input`.
+    pub fn try_new<I>(input: &ArrowInput<NI, EV>, batches: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = RecordBatch>,
+    {
+        let batches = batches
+            .into_iter()
+            .map(|batch| {
+                Ok(ArrowEdgeBatch {
+                    sources: node_column::<NI>(&batch, &input.source)?,
+                    targets: node_column::<NI>(&batch, &input.target)?,
+                    values: EV::column(&batch, &input.value)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Self { batches })
+    }
+}
+
+impl<NI, EV> Edges for ArrowEdgeList<NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    type NI = NI;
+
+    type EV = EV;
+
+    type EdgeIter<'a>
+        = ArrowEdgesIter<'a, NI, EV>
+    where
+        Self: 'a;
+
+    fn edges(&self) -> Self::EdgeIter<'_> {
+        ArrowEdgesIter {
+            batches: &self.batches,
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.batches.iter().map(|b| b.sources.len()).sum()
+    }
+}
+
+pub struct ArrowEdgesIter<'a, NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    batches: &'a [ArrowEdgeBatch<NI, EV>],
+}
+
+impl<'a, NI, EV> ParallelIterator for ArrowEdgesIter<'a, NI, EV>
+where
+    NI: ArrowIdx,
+    EV: ArrowValue,
+{
+    type Item = (NI, NI, EV);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        self.batches
+            .par_iter()
+            .flat_map(|batch| {
+                let sources = batch.sources.values();
+                let targets = batch.targets.values();
+                (0..sources.len())
+                    .into_par_iter()
+                    .map(move |i| (sources[i], targets[i], EV::value(&batch.values, i)))
+            })
+            .drive_unindexed(consumer)
+    }
+}
+
+fn non_null_column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a ArrayRef, Error> {
+    let column = batch
+        .column_by_name(name)
+        .ok_or_else(|| Error::InvalidColumn {
+            column: name.to_string(),
+            reason: String::from("column does not exist"),
+        })?;
+
+    if column.null_count() > 0 {
+        return Err(Error::InvalidColumn {
+            column: name.to_string(),
+            reason: String::from("column must not contain null values"),
+        });
+    }
+
+    Ok(column)
+}
+
+fn invalid_type(name: &str, expected: &DataType, actual: &DataType) -> Error {
+    Error::InvalidColumn {
+        column: name.to_string(),
+        reason: format!("expected data type {expected}, got {actual}"),
+    }
+}
+
+pub(crate) fn node_column<NI: ArrowIdx>(
+    batch: &RecordBatch,
+    name: &str,
+) -> Result<PrimitiveArray<NI::ArrowType>, Error> {
+    let column = non_null_column(batch, name)?;
+
+    // Zero-copy: the column already has the node id type.
+    if let Some(array) = column.as_primitive_opt::<NI::ArrowType>() {
+        // Only signed node id types can contain negative ids.
+        if array.values().iter().any(|&id| id < NI::zero()) {
+            return Err(negative_ids(name));
+        }
+        return Ok(array.clone());
+    }
+
+    macro_rules! convert {
+        ($($arrow_ty:ty),+) => {
+            match column.data_type() {
+                $(
+                    dt if dt == &<$arrow_ty>::DATA_TYPE => convert_ids::<NI, $arrow_ty, _>(
+                        name,
+                        column.as_primitive::<$arrow_ty>(),
+                        |v| usize::try_from(v).ok(),
+                    ),
+                )+
+                dt => Err(invalid_type(name, &NI::ArrowType::DATA_TYPE, dt)),
+            }
+        };
+    }
+
+    convert!(
+        UInt8Type, UInt16Type, UInt32Type, UInt64Type, Int8Type, Int16Type, Int32Type, Int64Type
+    )
+}
+
+fn convert_ids<NI, T, F>(
+    name: &str,
+    array: &PrimitiveArray<T>,
+    to_usize: F,
+) -> Result<PrimitiveArray<NI::ArrowType>, Error>
+where
+    NI: ArrowIdx,
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> Option<usize>,
+{
+    let ids = array
+        .values()
+        .iter()
+        .map(|&v| {
+            let id = to_usize(v).ok_or_else(|| negative_ids(name))?;
+            NI::from_usize(id).ok_or_else(|| Error::InvalidColumn {
+                column: name.to_string(),
+                reason: format!("node id {id} does not fit into the node id type"),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(PrimitiveArray::from_iter_values(ids))
+}
+
+fn negative_ids(name: &str) -> Error {
+    Error::InvalidColumn {
+        column: name.to_string(),
+        reason: String::from("node ids must not be negative"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Float64Array, Int64Array, RecordBatch, UInt32Array};
+
+    use crate::prelude::*;
+
+    fn batch(sources: Vec<i64>, targets: Vec<i64>, weights: Vec<f64>) -> RecordBatch {
+        RecordBatch::try_from_iter(vec![
+            ("source", Arc::new(Int64Array::from(sources)) as _),
+            ("target", Arc::new(Int64Array::from(targets)) as _),
+            ("weight", Arc::new(Float64Array::from(weights)) as _),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn multiple_batches() {
+        let batches = vec![
+            batch(vec![0, 0], vec![1, 2], vec![0.1, 0.2]),
+            batch(vec![1, 2], vec![2, 3], vec![1.2, 2.3]),
+        ];
+
+        let edges = ArrowEdgeList::<i64, f64>::try_new(&ArrowInput::default(), batches).unwrap();
+        assert_eq!(edges.len(), 4);
+
+        let graph: DirectedCsrGraph<i64, (), f64> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .arrow_input(ArrowInput::default())
+            .record_batches(vec![
+                batch(vec![0, 0], vec![1, 2], vec![0.1, 0.2]),
+                batch(vec![1, 2], vec![2, 3], vec![1.2, 2.3]),
+            ])
+            .build()
+            .unwrap();
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(
+            graph.in_neighbors_with_values(2).as_slice(),
+            &[Target::new(0, 0.2), Target::new(1, 1.2)]
+        );
+    }
+
+    #[test]
+    fn converted_node_ids() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .arrow_input(ArrowInput::default())
+            .record_batches(vec![batch(vec![0, 1], vec![1, 2], vec![0.0, 0.0])])
+            .build()
+            .unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.out_neighbors(1).as_slice(), &[2]);
+    }
+
+    #[test]
+    fn negative_node_ids() {
+        let result: Result<DirectedCsrGraph<u32>, Error> = GraphBuilder::new()
+            .arrow_input(ArrowInput::default())
+            .record_batches(vec![batch(vec![0, -1], vec![1, 2], vec![0.0, 0.0])])
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidColumn { column, .. }) if column == "source"));
+
+        // The node id type matches the column type, which skips the conversion.
+        let result: Result<DirectedCsrGraph<i64>, Error> = GraphBuilder::new()
+            .arrow_input(ArrowInput::default())
+            .record_batches(vec![batch(vec![0, 1], vec![1, -2], vec![0.0, 0.0])])
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidColumn { column, .. }) if column == "target"));
+    }
+
+    #[test]
+    fn overflowing_node_ids() {
+        let result: Result<DirectedCsrGraph<u8>, Error> = GraphBuilder::new()
+            .arrow_input(ArrowInput::default())
+            .record_batches(vec![batch(vec![0, 256], vec![1, 2], vec![0.0, 0.0])])
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidColumn { column, .. }) if column == "source"));
+    }
+
+    #[test]
+    fn missing_column() {
+        let result: Result<DirectedCsrGraph<u32, (), f32>, Error> = GraphBuilder::new()
+            .arrow_input(ArrowInput::default().value("cost"))
+            .record_batches(vec![batch(vec![0], vec![1], vec![0.0])])
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidColumn { column, .. }) if column == "cost"));
+    }
+
+    #[test]
+    fn null_values() {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "source",
+                Arc::new(UInt32Array::from(vec![Some(0), None])) as _,
+            ),
+            ("target", Arc::new(UInt32Array::from(vec![1, 2])) as _),
+        ])
+        .unwrap();
+
+        let result: Result<DirectedCsrGraph<u32>, Error> = GraphBuilder::new()
+            .arrow_input(ArrowInput::default())
+            .record_batches(vec![batch])
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidColumn { column, .. }) if column == "source"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Resolves input paths that refer to multiple files, e.g., the shards
+//! (`part-00000`, `part-00001`, ...) that distributed jobs write into an
+//! output directory next to marker files such as `_SUCCESS`. Shards are
+//! returned in lexicographic order of their file names.
+
+use std::{
+    io::{Error as IoError, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
+
+/// Returns the files that the given path refers to.
+pub(crate) fn shard_paths(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    if path.is_dir() {
+        return files_in(path, |name| !name.starts_with(['.', '_']));
+    }
+
+    match path.file_name().and_then(|name| name.to_str()) {
+        Some(pattern) if pattern.contains(['*', '?']) => {
+            let dir = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+            let shards = files_in(dir, |name| matches(pattern.as_bytes(), name.as_bytes()))?;
+            if shards.is_empty() {
+                return Err(IoError::new(
+                    ErrorKind::NotFound,
+                    format!("no files match {}", path.display()),
+                )
+                .into());
+            }
+            Ok(shards)
+        }
+        _ => Ok(vec![path.to_path_buf()]),
+    }
+}
+
+fn files_in(dir: &Path, mut filter: impl FnMut(&str) -> bool) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        if entry.file_name().to_str().is_some_and(&mut filter) {
+            files.push(entry.path());
+        }
+    }
+
+    files.sort_unstable();
+
+    Ok(files)
+}
+
+/// Matches a file name against a pattern with `*` and `?` wildcards.
+fn matches(pattern: &[u8], name: &[u8]) -> bool {
+    match (pattern.split_first(), name.split_first()) {
+        (None, None) => true,
+        (Some((b'*', rest)), _) => {
+            matches(rest, name) || (!name.is_empty() && matches(pattern, &name[1..]))
+        }
+        (Some((b'?', rest)), Some((_, name_rest))) => matches(rest, name_rest),
+        (Some((p, rest)), Some((n, name_rest))) if p == n => matches(rest, name_rest),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcards() {
+        assert!(matches(b"part-*", b"part-00000"));
+        assert!(matches(b"part-*", b"part-"));
+        assert!(matches(b"*.el", b"edges.el"));
+        assert!(matches(b"part-0000?", b"part-00001"));
+        assert!(!matches(b"part-0000?", b"part-000010"));
+        assert!(!matches(b"part-*", b"_SUCCESS"));
+        assert!(!matches(b"*.el", b"edges.el.crc"));
+    }
+
+    #[test]
+    fn directory_skips_hidden_and_marker_files() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["part-00001", "part-00000", "_SUCCESS", ".part-00000.crc"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+
+        let shards = shard_paths(dir.path()).unwrap();
+
+        assert_eq!(
+            shards,
+            vec![dir.path().join("part-00000"), dir.path().join("part-00001")]
+        );
+    }
+
+    #[test]
+    fn pattern_without_matches() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let result = shard_paths(&dir.path().join("part-*"));
+
+        assert!(matches!(result, Err(Error::IoError { .. })));
+    }
+}
@@ -0,0 +1,10 @@
+//! Conversions from and to data structures of other graph and matrix crates.
+//!
+//! Each integration lives behind a feature flag of the same name.
+
+#[cfg(feature = "petgraph")]
+#[cfg_attr(all(feature = "petgraph", has_doc_cfg), doc(cfg(feature = "petgraph")))]
+pub mod petgraph;
+#[cfg(feature = "sprs")]
+#[cfg_attr(all(feature = "sprs", has_doc_cfg), doc(cfg(feature = "sprs")))]
+pub mod sprs;
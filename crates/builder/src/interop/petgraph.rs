@@ -0,0 +1,582 @@
+//! Conversions between [`petgraph`] graphs and the CSR graph types.
+//!
+//! A [`petgraph::Graph`] can be turned into a [`DirectedCsrGraph`] or an
+//! [`UndirectedCsrGraph`]. Node weights become node values and edge weights
+//! become edge values. The node ids of the resulting graph are the indices of
+//! the petgraph nodes.
+//!
+//! In addition, both CSR graph types implement the [`petgraph::visit`] traits
+//! that are required to run petgraph's traversals and algorithms, e.g.,
+//! [`petgraph::visit::Bfs`] or [`petgraph::algo::dijkstra`], directly on the
+//! CSR representation. Visited nodes are tracked in
+//! a hash set, since petgraph's bit set based maps require its own index type.
+//!
+//! Edge ids are pairs of source and target ids. For undirected graphs, the
+//! smaller node id comes first, so that a relationship has the same id in
+//! both directions. Parallel relationships share their id. Every undirected
+//! self-loop is returned once per node, although the CSR stores it twice.
+//!
+//! # Example
+//!
+//! ```
+//! use graph_builder::prelude::*;
+//!
+//! let mut pg = petgraph::Graph::<(), f32>::new();
+//! let a = pg.add_node(());
+//! let b = pg.add_node(());
+//! let c = pg.add_node(());
+//! pg.add_edge(a, b, 0.5);
+//! pg.add_edge(b, c, 1.5);
+//!
+//! let graph: DirectedCsrGraph<u32, (), f32> = DirectedCsrGraph::from(&pg);
+//!
+//! assert_eq!(graph.node_count(), 3);
+//! assert_eq!(graph.edge_count(), 2);
+//! assert_eq!(graph.out_neighbors_with_values(1).as_slice(), &[Target::new(2, 1.5)]);
+//!
+//! let mut bfs = petgraph::visit::Bfs::new(&graph, 0);
+//! let mut visited = vec![];
+//! while let Some(node) = bfs.next(&graph) {
+//!     visited.push(node);
+//! }
+//! assert_eq!(visited, vec![0, 1, 2]);
+//! ```
+
+use petgraph::{
+    graph::IndexType,
+    visit::{
+        Data, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoNeighbors,
+        IntoNeighborsDirected, IntoNodeIdentifiers, NodeCompactIndexable, NodeCount, NodeIndexable,
+        Visitable,
+    },
+    EdgeType,
+};
+
+use crate::{
+    graph::csr::{Csr, NodeValues},
+    index::Idx,
+    CsrLayout, DirectedCsrGraph, DirectedNeighborsWithValues, Graph, Target, UndirectedCsrGraph,
+    UndirectedNeighborsWithValues,
+};
+
+use crate::input::EdgeList;
+
+use std::{collections::HashSet, hash::Hash};
+
+impl<NI, N, E, Ty, Ix> From<&petgraph::Graph<N, E, Ty, Ix>> for EdgeList<NI, E>
+where
+    NI: Idx,
+    E: Copy + Sync,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: &petgraph::Graph<N, E, Ty, Ix>) -> Self {
+        let edges = graph
+            .raw_edges()
+            .iter()
+            .map(|edge| {
+                (
+                    NI::new(edge.source().index()),
+                    NI::new(edge.target().index()),
+                    edge.weight,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Isolated nodes at the end of the id space must not get lost. An edge
+        // list always spans at least one node, graphs without nodes are
+        // converted by the graph conversions below.
+        let max_node_id = NI::new(graph.node_count().saturating_sub(1));
+
+        EdgeList::with_max_node_id(edges, max_node_id)
+    }
+}
+
+impl<N, E, Ty, Ix> From<&petgraph::Graph<N, E, Ty, Ix>> for NodeValues<N>
+where
+    N: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: &petgraph::Graph<N, E, Ty, Ix>) -> Self {
+        graph.raw_nodes().iter().map(|n| n.weight.clone()).collect()
+    }
+}
+
+impl<NI, NV, EV, Ty, Ix> From<(&petgraph::Graph<NV, EV, Ty, Ix>, CsrLayout)>
+    for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from((graph, csr_layout): (&petgraph::Graph<NV, EV, Ty, Ix>, CsrLayout)) -> Self {
+        let node_values = NodeValues::from(graph);
+        if graph.node_count() == 0 {
+            return DirectedCsrGraph::new(node_values, empty_csr(), empty_csr());
+        }
+        let edge_list = EdgeList::from(graph);
+        DirectedCsrGraph::from((node_values, edge_list, csr_layout))
+    }
+}
+
+impl<NI, NV, EV, Ty, Ix> From<&petgraph::Graph<NV, EV, Ty, Ix>> for DirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: &petgraph::Graph<NV, EV, Ty, Ix>) -> Self {
+        DirectedCsrGraph::from((graph, CsrLayout::default()))
+    }
+}
+
+impl<NI, NV, EV, Ty, Ix> From<(&petgraph::Graph<NV, EV, Ty, Ix>, CsrLayout)>
+    for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from((graph, csr_layout): (&petgraph::Graph<NV, EV, Ty, Ix>, CsrLayout)) -> Self {
+        let node_values = NodeValues::from(graph);
+        if graph.node_count() == 0 {
+            return UndirectedCsrGraph::new(node_values, empty_csr());
+        }
+        let edge_list = EdgeList::from(graph);
+        UndirectedCsrGraph::from((node_values, edge_list, csr_layout))
+    }
+}
+
+impl<NI, NV, EV, Ty, Ix> From<&petgraph::Graph<NV, EV, Ty, Ix>> for UndirectedCsrGraph<NI, NV, EV>
+where
+    NI: Idx,
+    NV: Clone,
+    EV: Copy + Send + Sync,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: &petgraph::Graph<NV, EV, Ty, Ix>) -> Self {
+        UndirectedCsrGraph::from((graph, CsrLayout::default()))
+    }
+}
+
+/// Returns the CSR of a graph without nodes.
+fn empty_csr<NI: Idx, EV>() -> Csr<NI, NI, EV> {
+    Csr::new(Box::new([NI::zero()]), Box::new([]))
+}
+
+type TargetIds<'a, NI, EV> =
+    std::iter::Map<std::slice::Iter<'a, Target<NI, EV>>, fn(&Target<NI, EV>) -> NI>;
+
+fn target_id<NI: Copy, EV>(target: &Target<NI, EV>) -> NI {
+    target.target
+}
+
+/// A relationship of a CSR graph as seen by petgraph.
+#[derive(Debug)]
+pub struct CsrEdgeReference<'a, NI, EV> {
+    source: NI,
+    target: &'a Target<NI, EV>,
+    undirected: bool,
+}
+
+impl<NI: Copy, EV> Clone for CsrEdgeReference<'_, NI, EV> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<NI: Copy, EV> Copy for CsrEdgeReference<'_, NI, EV> {}
+
+impl<NI: Idx, EV> EdgeRef for CsrEdgeReference<'_, NI, EV> {
+    type NodeId = NI;
+    type EdgeId = (NI, NI);
+    type Weight = EV;
+
+    fn source(&self) -> NI {
+        self.source
+    }
+
+    fn target(&self) -> NI {
+        self.target.target
+    }
+
+    fn weight(&self) -> &EV {
+        &self.target.value
+    }
+
+    fn id(&self) -> (NI, NI) {
+        let (source, target) = (self.source, self.target.target);
+        if self.undirected && target < source {
+            (target, source)
+        } else {
+            (source, target)
+        }
+    }
+}
+
+/// Iterates the relationships of a single node.
+pub struct CsrEdges<'a, NI, EV> {
+    source: NI,
+    targets: std::slice::Iter<'a, Target<NI, EV>>,
+    undirected: bool,
+    // An undirected self-loop is stored twice, only the first copy is
+    // returned.
+    skip_self_loop: bool,
+}
+
+impl<'a, NI: Idx, EV> CsrEdges<'a, NI, EV> {
+    fn new(source: NI, targets: std::slice::Iter<'a, Target<NI, EV>>, undirected: bool) -> Self {
+        Self {
+            source,
+            targets,
+            undirected,
+            skip_self_loop: false,
+        }
+    }
+}
+
+impl<'a, NI: Idx, EV> Iterator for CsrEdges<'a, NI, EV> {
+    type Item = CsrEdgeReference<'a, NI, EV>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let target = self.targets.next()?;
+            if self.undirected && target.target == self.source {
+                self.skip_self_loop = !self.skip_self_loop;
+                if !self.skip_self_loop {
+                    continue;
+                }
+            }
+            return Some(CsrEdgeReference {
+                source: self.source,
+                target,
+                undirected: self.undirected,
+            });
+        }
+    }
+}
+
+/// Iterates all relationships of a graph.
+///
+/// For undirected graphs, every relationship is only returned once, i.e., in
+/// the direction from the smaller to the larger node id.
+pub struct CsrEdgeReferences<'a, G, NI, EV> {
+    graph: &'a G,
+    targets: fn(&'a G, NI) -> std::slice::Iter<'a, Target<NI, EV>>,
+    undirected: bool,
+    next_node: NI,
+    current: CsrEdges<'a, NI, EV>,
+}
+
+impl<'a, G: Graph<NI>, NI: Idx, EV> CsrEdgeReferences<'a, G, NI, EV> {
+    fn new(
+        graph: &'a G,
+        targets: fn(&'a G, NI) -> std::slice::Iter<'a, Target<NI, EV>>,
+        undirected: bool,
+    ) -> Self {
+        Self {
+            graph,
+            targets,
+            undirected,
+            next_node: NI::zero(),
+            current: CsrEdges::new(NI::zero(), [].iter(), undirected),
+        }
+    }
+}
+
+impl<'a, G: Graph<NI>, NI: Idx, EV> Iterator for CsrEdgeReferences<'a, G, NI, EV> {
+    type Item = CsrEdgeReference<'a, NI, EV>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.current.next() {
+                Some(edge) if self.undirected && edge.target() < edge.source() => continue,
+                Some(edge) => return Some(edge),
+                None if self.next_node == self.graph.node_count() => return None,
+                None => {
+                    self.current = CsrEdges::new(
+                        self.next_node,
+                        (self.targets)(self.graph, self.next_node),
+                        self.undirected,
+                    );
+                    self.next_node += NI::new(1);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_visit_common {
+    ($graph:ident, $edge_type:ty) => {
+        impl<NI: Idx, NV, EV> GraphBase for $graph<NI, NV, EV> {
+            type EdgeId = (NI, NI);
+            type NodeId = NI;
+        }
+
+        impl<NI: Idx, NV, EV> Data for $graph<NI, NV, EV> {
+            type NodeWeight = NV;
+            type EdgeWeight = EV;
+        }
+
+        impl<NI: Idx, NV, EV> GraphProp for $graph<NI, NV, EV> {
+            type EdgeType = $edge_type;
+        }
+
+        impl<NI: Idx, NV, EV> NodeCount for $graph<NI, NV, EV> {
+            fn node_count(&self) -> usize {
+                Graph::node_count(self).index()
+            }
+        }
+
+        impl<NI: Idx, NV, EV> NodeIndexable for $graph<NI, NV, EV> {
+            fn node_bound(&self) -> usize {
+                Graph::node_count(self).index()
+            }
+
+            fn to_index(&self, node: NI) -> usize {
+                node.index()
+            }
+
+            fn from_index(&self, index: usize) -> NI {
+                NI::new(index)
+            }
+        }
+
+        impl<NI: Idx, NV, EV> NodeCompactIndexable for $graph<NI, NV, EV> {}
+
+        impl<NI: Idx + Hash, NV, EV> Visitable for $graph<NI, NV, EV> {
+            type Map = HashSet<NI>;
+
+            fn visit_map(&self) -> Self::Map {
+                HashSet::new()
+            }
+
+            fn reset_map(&self, map: &mut Self::Map) {
+                map.clear();
+            }
+        }
+
+        impl<'a, NI: Idx, NV, EV> IntoNodeIdentifiers for &'a $graph<NI, NV, EV> {
+            type NodeIdentifiers = std::iter::Map<std::ops::Range<usize>, fn(usize) -> NI>;
+
+            fn node_identifiers(self) -> Self::NodeIdentifiers {
+                (0..Graph::node_count(self).index()).map(NI::new)
+            }
+        }
+    };
+}
+
+impl_visit_common!(DirectedCsrGraph, petgraph::Directed);
+impl_visit_common!(UndirectedCsrGraph, petgraph::Undirected);
+
+impl<'a, NI: Idx, NV, EV> IntoNeighbors for &'a DirectedCsrGraph<NI, NV, EV> {
+    type Neighbors = TargetIds<'a, NI, EV>;
+
+    fn neighbors(self, node: NI) -> Self::Neighbors {
+        self.out_neighbors_with_values(node).map(target_id)
+    }
+}
+
+impl<'a, NI: Idx, NV, EV> IntoEdgeReferences for &'a DirectedCsrGraph<NI, NV, EV> {
+    type EdgeRef = CsrEdgeReference<'a, NI, EV>;
+    type EdgeReferences = CsrEdgeReferences<'a, DirectedCsrGraph<NI, NV, EV>, NI, EV>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        CsrEdgeReferences::new(self, |g, node| g.out_neighbors_with_values(node), false)
+    }
+}
+
+impl<'a, NI: Idx, NV, EV> IntoEdges for &'a DirectedCsrGraph<NI, NV, EV> {
+    type Edges = CsrEdges<'a, NI, EV>;
+
+    fn edges(self, node: NI) -> Self::Edges {
+        CsrEdges::new(node, self.out_neighbors_with_values(node), false)
+    }
+}
+
+impl<'a, NI: Idx, NV, EV> IntoNeighborsDirected for &'a DirectedCsrGraph<NI, NV, EV> {
+    type NeighborsDirected = TargetIds<'a, NI, EV>;
+
+    fn neighbors_directed(self, node: NI, dir: petgraph::Direction) -> Self::NeighborsDirected {
+        match dir {
+            petgraph::Direction::Outgoing => self.out_neighbors_with_values(node).map(target_id),
+            petgraph::Direction::Incoming => self.in_neighbors_with_values(node).map(target_id),
+        }
+    }
+}
+
+impl<'a, NI: Idx, NV, EV> IntoNeighbors for &'a UndirectedCsrGraph<NI, NV, EV> {
+    type Neighbors = TargetIds<'a, NI, EV>;
+
+    fn neighbors(self, node: NI) -> Self::Neighbors {
+        self.neighbors_with_values(node).map(target_id)
+    }
+}
+
+impl<'a, NI: Idx, NV, EV> IntoEdgeReferences for &'a UndirectedCsrGraph<NI, NV, EV> {
+    type EdgeRef = CsrEdgeReference<'a, NI, EV>;
+    type EdgeReferences = CsrEdgeReferences<'a, UndirectedCsrGraph<NI, NV, EV>, NI, EV>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        CsrEdgeReferences::new(self, |g, node| g.neighbors_with_values(node), true)
+    }
+}
+
+impl<'a, NI: Idx, NV, EV> IntoEdges for &'a UndirectedCsrGraph<NI, NV, EV> {
+    type Edges = CsrEdges<'a, NI, EV>;
+
+    fn edges(self, node: NI) -> Self::Edges {
+        CsrEdges::new(node, self.neighbors_with_values(node), true)
+    }
+}
+
+impl<'a, NI: Idx, NV, EV> IntoNeighborsDirected for &'a UndirectedCsrGraph<NI, NV, EV> {
+    type NeighborsDirected = TargetIds<'a, NI, EV>;
+
+    fn neighbors_directed(self, node: NI, _: petgraph::Direction) -> Self::NeighborsDirected {
+        self.neighbors_with_values(node).map(target_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::visit::{Dfs, EdgeRef, IntoEdgeReferences, IntoEdges, Topo};
+
+    use super::CsrEdgeReference;
+    use crate::prelude::*;
+
+    #[test]
+    fn directed_from_petgraph() {
+        let mut pg = petgraph::Graph::<u32, f32>::new();
+        let a = pg.add_node(42);
+        let b = pg.add_node(43);
+        let c = pg.add_node(44);
+        let _ = pg.add_node(45);
+        pg.add_edge(a, b, 0.1);
+        pg.add_edge(a, c, 0.2);
+        pg.add_edge(c, b, 0.3);
+
+        let graph: DirectedCsrGraph<usize, u32, f32> =
+            DirectedCsrGraph::from((&pg, CsrLayout::Sorted));
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.node_value(3), &45);
+        assert_eq!(
+            graph.out_neighbors_with_values(0).as_slice(),
+            &[Target::new(1, 0.1), Target::new(2, 0.2)]
+        );
+        assert_eq!(
+            graph.in_neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 0.1), Target::new(2, 0.3)]
+        );
+    }
+
+    #[test]
+    fn undirected_from_petgraph() {
+        let mut pg = petgraph::Graph::<(), (), petgraph::Undirected>::new_undirected();
+        let a = pg.add_node(());
+        let b = pg.add_node(());
+        let c = pg.add_node(());
+        pg.add_edge(a, b, ());
+        pg.add_edge(b, c, ());
+
+        let graph: UndirectedCsrGraph<u32> = UndirectedCsrGraph::from((&pg, CsrLayout::Sorted));
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.neighbors(1).as_slice(), &[0, 2]);
+    }
+
+    #[test]
+    fn empty_from_petgraph() {
+        let pg = petgraph::Graph::<(), f32>::new();
+
+        let directed: DirectedCsrGraph<u32, (), f32> = DirectedCsrGraph::from(&pg);
+        assert_eq!(directed.node_count(), 0);
+        assert_eq!(directed.edge_count(), 0);
+
+        let undirected: UndirectedCsrGraph<u32, (), f32> = UndirectedCsrGraph::from(&pg);
+        assert_eq!(undirected.node_count(), 0);
+        assert_eq!(undirected.edge_count(), 0);
+    }
+
+    #[test]
+    fn visit_directed_csr() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (1, 3), (2, 3)])
+            .build();
+
+        let mut topo = Topo::new(&graph);
+        let mut order = vec![];
+        while let Some(node) = topo.next(&graph) {
+            order.push(node);
+        }
+
+        assert_eq!(order.len(), 4);
+        assert_eq!(order[0], 0);
+        assert_eq!(order[3], 3);
+    }
+
+    #[test]
+    fn dijkstra_on_directed_csr() {
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 1.0), (0, 2, 4.0), (1, 2, 1.5), (2, 3, 1.0)])
+            .build();
+
+        let distances = petgraph::algo::dijkstra(&graph, 0, None, |e| *e.weight());
+
+        assert_eq!(distances[&2], 2.5);
+        assert_eq!(distances[&3], 3.5);
+    }
+
+    #[test]
+    fn visit_undirected_csr() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (2, 1), (3, 4)])
+            .build();
+
+        let mut dfs = Dfs::new(&graph, 2);
+        let mut reachable = vec![];
+        while let Some(node) = dfs.next(&graph) {
+            reachable.push(node);
+        }
+        reachable.sort_unstable();
+
+        assert_eq!(reachable, vec![0, 1, 2]);
+        assert_eq!(petgraph::algo::connected_components(&graph), 2);
+    }
+
+    #[test]
+    fn undirected_edge_ids() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(1, 1, 0.5), (1, 0, 1.0), (2, 1, 2.0)])
+            .build();
+
+        let ids = |edges: &mut dyn Iterator<Item = CsrEdgeReference<'_, u32, f32>>| {
+            edges.map(|e| (e.id(), *e.weight())).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(&mut graph.edge_references()),
+            vec![((0, 1), 1.0), ((1, 1), 0.5), ((1, 2), 2.0)]
+        );
+        // The self-loop is returned once and ids match across directions.
+        assert_eq!(
+            ids(&mut graph.edges(1)),
+            vec![((0, 1), 1.0), ((1, 1), 0.5), ((1, 2), 2.0)]
+        );
+        assert_eq!(ids(&mut graph.edges(2)), vec![((1, 2), 2.0)]);
+    }
+}
@@ -0,0 +1,255 @@
+//! Conversions between the CSR graph types and [`sprs`] sparse matrices.
+//!
+//! A graph with `n` nodes corresponds to a square `n x n` adjacency matrix in
+//! which the entry at `(u, v)` is the value of the relationship from `u` to
+//! `v`. For directed graphs, rows represent outgoing neighbor lists. For
+//! undirected graphs, the matrix is symmetric.
+//!
+//! Sparse matrices cannot contain more than one entry per position, so
+//! converting a graph with parallel relationships fails. Graphs built with
+//! [`CsrLayout::Deduplicated`] can always be converted.
+//!
+//! # Example
+//!
+//! ```
+//! use graph_builder::prelude::*;
+//! use sprs::CsMat;
+//!
+//! let graph: DirectedCsrGraph<u32, (), f64> = GraphBuilder::new()
+//!     .edges_with_values(vec![(0, 1, 0.5), (0, 2, 1.0), (2, 1, 1.5)])
+//!     .build();
+//!
+//! let matrix = CsMat::try_from(&graph).unwrap();
+//! assert_eq!(matrix.get(2, 1), Some(&1.5));
+//!
+//! let squared = &matrix * &matrix;
+//! assert_eq!(squared.get(0, 1), Some(&1.5));
+//!
+//! let graph: DirectedCsrGraph<u32, (), f64> = DirectedCsrGraph::try_from(&matrix).unwrap();
+//! assert_eq!(graph.in_degree(1), 2);
+//! ```
+
+use std::convert::TryFrom;
+
+use sprs::{CsMatI, SpIndex};
+
+use crate::{
+    graph::csr::{Csr, NodeValues},
+    index::Idx,
+    DirectedCsrGraph, Error, UndirectedCsrGraph,
+};
+
+impl<NI, NV, EV> TryFrom<&DirectedCsrGraph<NI, NV, EV>> for CsMatI<EV, usize>
+where
+    NI: Idx,
+    EV: Copy,
+{
+    type Error = Error;
+
+    fn try_from(graph: &DirectedCsrGraph<NI, NV, EV>) -> Result<Self, Self::Error> {
+        csr_to_matrix(graph.csr_out())
+    }
+}
+
+impl<NI, NV, EV> TryFrom<&UndirectedCsrGraph<NI, NV, EV>> for CsMatI<EV, usize>
+where
+    NI: Idx,
+    EV: Copy,
+{
+    type Error = Error;
+
+    fn try_from(graph: &UndirectedCsrGraph<NI, NV, EV>) -> Result<Self, Self::Error> {
+        csr_to_matrix(graph.csr())
+    }
+}
+
+impl<NI, EV, I, Iptr> TryFrom<&CsMatI<EV, I, Iptr>> for DirectedCsrGraph<NI, (), EV>
+where
+    NI: Idx,
+    EV: Copy + Default,
+    I: SpIndex,
+    Iptr: SpIndex,
+{
+    type Error = Error;
+
+    /// Creates a directed graph from an adjacency matrix, where rows represent
+    /// the outgoing neighbor lists of nodes.
+    fn try_from(matrix: &CsMatI<EV, I, Iptr>) -> Result<Self, Self::Error> {
+        let csr_out = matrix_to_csr(&matrix.to_csr())?;
+        let csr_inc = csr_out.transpose();
+        let node_values = NodeValues::new(vec![(); matrix.rows()]);
+
+        Ok(DirectedCsrGraph::new(node_values, csr_out, csr_inc))
+    }
+}
+
+impl<NI, EV, I, Iptr> TryFrom<&CsMatI<EV, I, Iptr>> for UndirectedCsrGraph<NI, (), EV>
+where
+    NI: Idx,
+    EV: Copy + Default,
+    I: SpIndex,
+    Iptr: SpIndex,
+{
+    type Error = Error;
+
+    /// Creates an undirected graph from a symmetric adjacency matrix.
+    ///
+    /// The rows of the matrix are used as neighbor lists as they are, i.e.,
+    /// the caller is responsible for the matrix being symmetric.
+    fn try_from(matrix: &CsMatI<EV, I, Iptr>) -> Result<Self, Self::Error> {
+        let csr = matrix_to_csr(&matrix.to_csr())?;
+
+        Ok(UndirectedCsrGraph::from(csr))
+    }
+}
+
+fn csr_to_matrix<NI, EV>(csr: &Csr<NI, NI, EV>) -> Result<CsMatI<EV, usize>, Error>
+where
+    NI: Idx,
+    EV: Copy,
+{
+    let node_count = csr.offsets().len() - 1;
+    let offsets = csr.offsets().iter().map(|o| o.index()).collect::<Vec<_>>();
+
+    let mut targets = csr.targets_with_values_raw().to_vec();
+    for range in offsets.windows(2) {
+        targets[range[0]..range[1]].sort_unstable();
+    }
+
+    let indices = targets.iter().map(|t| t.target.index()).collect::<Vec<_>>();
+    let values = targets.iter().map(|t| t.value).collect::<Vec<_>>();
+
+    CsMatI::try_new((node_count, node_count), offsets, indices, values).map_err(|(_, _, _, e)| {
+        Error::InvalidCsr {
+            reason: e.to_string(),
+        }
+    })
+}
+
+fn matrix_to_csr<NI, EV, I, Iptr>(matrix: &CsMatI<EV, I, Iptr>) -> Result<Csr<NI, NI, EV>, Error>
+where
+    NI: Idx,
+    EV: Copy,
+    I: SpIndex,
+    Iptr: SpIndex,
+{
+    if matrix.rows() != matrix.cols() {
+        return Err(Error::InvalidCsr {
+            reason: format!(
+                "adjacency matrix must be square, got {} x {}",
+                matrix.rows(),
+                matrix.cols()
+            ),
+        });
+    }
+
+    let mut offsets = Vec::with_capacity(matrix.rows() + 1);
+    let mut targets = Vec::with_capacity(matrix.nnz());
+    let mut values = Vec::with_capacity(matrix.nnz());
+
+    offsets.push(NI::zero());
+    for row in matrix.outer_iterator() {
+        for (col, value) in row.iter() {
+            targets.push(NI::new(col));
+            values.push(*value);
+        }
+        offsets.push(NI::new(targets.len()));
+    }
+
+    Csr::from_raw_parts(offsets, targets, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use sprs::{CsMat, TriMat};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn directed_to_matrix() {
+        let graph: DirectedCsrGraph<usize, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 2, 0.2), (0, 1, 0.1), (1, 2, 1.2), (3, 0, 3.0)])
+            .build();
+
+        let matrix = CsMat::try_from(&graph).unwrap();
+
+        assert_eq!(matrix.shape(), (4, 4));
+        assert_eq!(matrix.nnz(), 4);
+        assert_eq!(matrix.get(0, 1), Some(&0.1));
+        assert_eq!(matrix.get(0, 2), Some(&0.2));
+        assert_eq!(matrix.get(3, 0), Some(&3.0));
+        assert_eq!(matrix.get(2, 1), None);
+    }
+
+    #[test]
+    fn undirected_to_matrix() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 0.1), (1, 2, 1.2)])
+            .build();
+
+        let matrix = CsMat::try_from(&graph).unwrap();
+
+        assert_eq!(matrix.nnz(), 4);
+        assert_eq!(matrix.get(0, 1), Some(&0.1));
+        assert_eq!(matrix.get(1, 0), Some(&0.1));
+        assert_eq!(matrix.get(2, 1), Some(&1.2));
+    }
+
+    #[test]
+    fn parallel_edges_to_matrix() {
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .edges_with_values(vec![(0, 1, 0.1), (0, 1, 0.2)])
+            .build();
+
+        assert!(matches!(
+            CsMat::try_from(&graph),
+            Err(Error::InvalidCsr { .. })
+        ));
+    }
+
+    #[test]
+    fn directed_from_matrix() {
+        let mut triplets = TriMat::new((3, 3));
+        triplets.add_triplet(0, 1, 1.0);
+        triplets.add_triplet(2, 1, 2.0);
+        triplets.add_triplet(2, 0, 3.0);
+        let matrix: CsMat<f64> = triplets.to_csc();
+
+        let graph: DirectedCsrGraph<u32, (), f64> = DirectedCsrGraph::try_from(&matrix).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(
+            graph.out_neighbors_with_values(2).as_slice(),
+            &[Target::new(0, 3.0), Target::new(1, 2.0)]
+        );
+        assert_eq!(
+            graph.in_neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 1.0), Target::new(2, 2.0)]
+        );
+    }
+
+    #[test]
+    fn undirected_from_matrix() {
+        let mut triplets = TriMat::new((3, 3));
+        triplets.add_triplet(0, 1, 1.0);
+        triplets.add_triplet(1, 0, 1.0);
+        let matrix: CsMat<f32> = triplets.to_csr();
+
+        let graph: UndirectedCsrGraph<u32, (), f32> =
+            UndirectedCsrGraph::try_from(&matrix).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.degree(2), 0);
+    }
+
+    #[test]
+    fn non_square_matrix() {
+        let matrix: CsMat<f32> = TriMat::new((2, 3)).to_csr();
+
+        assert!(DirectedCsrGraph::<u32, (), f32>::try_from(&matrix).is_err());
+    }
+}
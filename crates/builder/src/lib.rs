@@ -1,5 +1,4 @@
 #![cfg_attr(has_maybe_uninit_write_slice, feature(maybe_uninit_write_slice))]
-#![cfg_attr(has_new_uninit, feature(new_uninit))]
 #![cfg_attr(has_doc_cfg, feature(doc_cfg))]
 #![cfg_attr(has_slice_partition_dedup, feature(slice_partition_dedup))]
 
@@ -249,14 +248,40 @@
 //! assert_eq!(graph.edge_count(), 6);
 //! assert_eq!(graph.out_neighbors(1).as_slice(), &[2, 3, 0]);
 //! ```
+//!
+//! # WebAssembly
+//!
+//! The library compiles to `wasm32-unknown-unknown` when the default `mmap`
+//! feature is disabled:
+//!
+//! ```toml
+//! graph_builder = { version = "0.4", default-features = false }
+//! ```
+//!
+//! Without the `mmap` feature, input files are read into memory instead of
+//! being memory-mapped. Since the target has neither threads nor a file
+//! system, graphs need to be built from in-memory inputs, e.g., via
+//! [`GraphBuilder::edges`] or by parsing the bytes of a file with
+//! `EdgeList::try_from(bytes)`. Parallel code runs on the current thread as
+//! the rayon thread pool falls back to it if no threads can be spawned, which
+//! is why `rayon` remains a required dependency. Tests that spawn threads
+//! directly are not compiled for the target.
 
 pub mod builder;
 mod compat;
+pub mod diff;
+pub mod edge_balanced;
 pub mod graph;
 pub mod graph_ops;
 pub mod index;
 pub mod input;
+pub mod interop;
+pub mod output;
 pub mod prelude;
+pub mod properties;
+pub mod stats;
+pub mod time;
+pub mod validation;
 
 pub use crate::builder::GraphBuilder;
 pub use crate::graph::adj_list::DirectedALGraph;
@@ -264,8 +289,12 @@ pub use crate::graph::adj_list::UndirectedALGraph;
 pub use crate::graph::csr::CsrLayout;
 pub use crate::graph::csr::DirectedCsrGraph;
 pub use crate::graph::csr::UndirectedCsrGraph;
+pub use crate::graph::delta::DeltaGraph;
+pub use crate::graph::hybrid::HybridView;
+pub use crate::graph::view::UndirectedView;
 
 use std::convert::Infallible;
+use std::ops::Range;
 
 use crate::graph::Target;
 use crate::index::Idx;
@@ -290,15 +319,46 @@ pub enum Error {
         #[from]
         source: gdl::graph::GraphHandlerError,
     },
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(all(feature = "arrow", has_doc_cfg), doc(cfg(feature = "arrow")))]
+    #[error("error while reading record batches")]
+    ArrowError {
+        #[from]
+        source: arrow_schema::ArrowError,
+    },
+    #[cfg(feature = "parquet")]
+    #[cfg_attr(all(feature = "parquet", has_doc_cfg), doc(cfg(feature = "parquet")))]
+    #[error("error while reading Parquet input")]
+    ParquetError {
+        #[from]
+        source: parquet::errors::ParquetError,
+    },
     #[error("invalid partitioning")]
     InvalidPartitioning,
     #[error("number of node values must be the same as node count")]
     InvalidNodeValues,
+    #[error("node count {node_count} must be larger than the largest node id {max_node_id}")]
+    InvalidNodeCount {
+        node_count: usize,
+        max_node_id: usize,
+    },
     #[error("invalid id size, expected {expected:?} bytes, got {actual:?} bytes")]
     InvalidIdType { expected: String, actual: String },
 
     #[error("node {node:?} does not exist in the graph")]
     MissingNode { node: String },
+
+    #[error("invalid csr: {reason}")]
+    InvalidCsr { reason: String },
+
+    #[error("invalid column {column:?}: {reason}")]
+    InvalidColumn { column: String, reason: String },
+
+    #[error("invalid record {record:?}: {reason}")]
+    InvalidRecord { record: String, reason: String },
+
+    #[error("unsupported graph: {reason}")]
+    UnsupportedGraph { reason: String },
 }
 
 impl From<Infallible> for Error {
@@ -411,6 +471,45 @@ pub trait DirectedNeighborsWithValues<NI: Idx, EV> {
     fn in_neighbors_with_values(&self, node: NI) -> Self::NeighborsIterator<'_>;
 }
 
+/// Identifies the outgoing edges of a directed graph.
+///
+/// The id of an edge is its position in the outgoing adjacency list of the
+/// graph, i.e., the `i`-th outgoing neighbor of a node is connected via the
+/// edge with id `out_edge_ids(node).start + i`. Ids are consecutive, start at
+/// `0` and do not change for the lifetime of the graph, which makes them
+/// suitable as keys for per-edge values, see [`EdgeValues`](crate::properties::EdgeValues).
+pub trait DirectedEdgeIds<NI: Idx> {
+    /// Returns the number of edge ids, which equals the number of edges.
+    fn edge_id_count(&self) -> usize;
+
+    /// Returns the ids of the outgoing edges of the given node in the order
+    /// of [`out_neighbors`](DirectedNeighbors::out_neighbors).
+    fn out_edge_ids(&self, node: NI) -> Range<usize>;
+
+    /// Returns the id of the first edge from `source` to `target` or `None`
+    /// if there is no such edge. Takes time linear in the out-degree of
+    /// `source`.
+    fn out_edge_id(&self, source: NI, target: NI) -> Option<usize>;
+}
+
+/// Identifies the edges of an undirected graph.
+///
+/// The id of an edge is its position in the adjacency list of the graph.
+/// Every undirected edge `(u, v)` is stored in the neighbors of both `u` and
+/// `v` and therefore has two ids, one per direction.
+pub trait UndirectedEdgeIds<NI: Idx> {
+    /// Returns the number of edge ids, which is twice the number of edges.
+    fn edge_id_count(&self) -> usize;
+
+    /// Returns the ids of the edges of the given node in the order of
+    /// [`neighbors`](UndirectedNeighbors::neighbors).
+    fn edge_ids(&self, node: NI) -> Range<usize>;
+
+    /// Returns the id of the first edge from `source` to `target` or `None`
+    /// if there is no such edge. Takes time linear in the degree of `source`.
+    fn edge_id(&self, source: NI, target: NI) -> Option<usize>;
+}
+
 /// Allows adding new edges to a graph.
 pub trait EdgeMutation<NI: Idx> {
     /// Adds a new edge between the given source and target node.
@@ -0,0 +1,271 @@
+use std::io::Write;
+
+use crate::{index::Idx, Error};
+
+use super::{OutputFormat, OutputGraph, WriteValue};
+
+/// Writes a graph in the [DOT](https://graphviz.org/doc/info/lang.html)
+/// language of Graphviz.
+///
+/// By default, node values and edge values are written as labels. Node labels
+/// can also be set explicitly and nodes can be colored by a category, e.g., a
+/// community id computed by an algorithm. The format is intended for small
+/// graphs, e.g., for debugging or figures.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .edges(vec![(0, 1), (1, 2), (3, 4)])
+///     .build();
+///
+/// let communities = vec![0, 0, 0, 1, 1];
+///
+/// let output = DotOutput::default()
+///     .node_labels(vec!["Alice", "Bob", "Carol", "Dave", "Eve"])
+///     .node_colors(&communities);
+///
+/// let mut dot = Vec::new();
+/// output.write(&graph, &mut dot).unwrap();
+/// let dot = String::from_utf8(dot).unwrap();
+///
+/// assert!(dot.contains(r#"3 [label="Dave", style=filled, colorscheme=set312, fillcolor=2];"#));
+/// assert!(dot.contains("3 -- 4;"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DotOutput {
+    node_values: bool,
+    edge_values: bool,
+    node_labels: Option<Vec<String>>,
+    node_colors: Option<Vec<usize>>,
+}
+
+impl Default for DotOutput {
+    fn default() -> Self {
+        Self {
+            node_values: true,
+            edge_values: true,
+            node_labels: None,
+            node_colors: None,
+        }
+    }
+}
+
+/// Number of colors in the Graphviz `set312` color scheme.
+const COLOR_COUNT: usize = 12;
+
+impl DotOutput {
+    /// Sets whether node values are written as node labels.
+    ///
+    /// Explicit labels set via [`DotOutput::node_labels`] take precedence.
+    pub fn node_values(mut self, node_values: bool) -> Self {
+        self.node_values = node_values;
+        self
+    }
+
+    /// Sets whether edge values are written as edge labels.
+    pub fn edge_values(mut self, edge_values: bool) -> Self {
+        self.edge_values = edge_values;
+        self
+    }
+
+    /// Sets a label for each node.
+    ///
+    /// The number of labels must match the node count of the written graph.
+    pub fn node_labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.node_labels = Some(labels.into_iter().map(|l| l.to_string()).collect());
+        self
+    }
+
+    /// Colors each node by the given category, e.g., a community id.
+    ///
+    /// Nodes of the same category get the same fill color. Colors are taken
+    /// from the Graphviz `set312` color scheme, i.e., categories that are 12
+    /// apart share a color. The number of categories must match the node
+    /// count of the written graph.
+    pub fn node_colors<C: Idx>(mut self, categories: &[C]) -> Self {
+        self.node_colors = Some(categories.iter().map(|c| c.index()).collect());
+        self
+    }
+
+    fn check_len<T>(values: &Option<Vec<T>>, node_count: usize) -> Result<(), Error> {
+        match values {
+            Some(values) if values.len() != node_count => Err(Error::InvalidNodeValues),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_node<NV, W>(&self, writer: &mut W, node: usize, value: &NV) -> std::io::Result<()>
+    where
+        NV: WriteValue,
+        W: Write,
+    {
+        let mut attributes = Vec::new();
+
+        if let Some(labels) = &self.node_labels {
+            attributes.push(format!("label=\"{}\"", escape(&labels[node])));
+        } else if self.node_values && NV::TYPE_NAME.is_some() {
+            attributes.push(format!("label=\"{}\"", escape(&to_string(value)?)));
+        }
+
+        if let Some(colors) = &self.node_colors {
+            attributes.push(format!(
+                "style=filled, colorscheme=set312, fillcolor={}",
+                colors[node] % COLOR_COUNT + 1
+            ));
+        }
+
+        write!(writer, "  {node}")?;
+        write_attributes(writer, &attributes)
+    }
+
+    fn write_edge<EV, W>(
+        &self,
+        writer: &mut W,
+        source: usize,
+        edge_op: &str,
+        target: usize,
+        value: &EV,
+    ) -> std::io::Result<()>
+    where
+        EV: WriteValue,
+        W: Write,
+    {
+        let mut attributes = Vec::new();
+
+        if self.edge_values && EV::TYPE_NAME.is_some() {
+            attributes.push(format!("label=\"{}\"", escape(&to_string(value)?)));
+        }
+
+        write!(writer, "  {source} {edge_op} {target}")?;
+        write_attributes(writer, &attributes)
+    }
+}
+
+impl<NI, NV, EV> OutputFormat<NI, NV, EV> for DotOutput
+where
+    NI: Idx,
+    NV: WriteValue,
+    EV: WriteValue,
+{
+    fn write<G, W>(&self, graph: &G, writer: &mut W) -> Result<(), Error>
+    where
+        G: OutputGraph<NI, NV, EV>,
+        W: Write,
+    {
+        let node_count = graph.node_count().index();
+        Self::check_len(&self.node_labels, node_count)?;
+        Self::check_len(&self.node_colors, node_count)?;
+
+        let (graph_type, edge_op) = if graph.is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        writeln!(writer, "{graph_type} {{")?;
+
+        for node in 0..node_count {
+            self.write_node(writer, node, graph.node_value(NI::new(node)))?;
+        }
+
+        let mut result = Ok(());
+        graph.for_each_edge(|source, target, value| {
+            if result.is_ok() {
+                result = self.write_edge(writer, source.index(), edge_op, target.index(), value);
+            }
+        });
+        result?;
+
+        writeln!(writer, "}}")?;
+
+        Ok(())
+    }
+}
+
+fn write_attributes<W: Write>(writer: &mut W, attributes: &[String]) -> std::io::Result<()> {
+    if attributes.is_empty() {
+        writeln!(writer, ";")
+    } else {
+        writeln!(writer, " [{}];", attributes.join(", "))
+    }
+}
+
+fn to_string<V: WriteValue>(value: &V) -> std::io::Result<String> {
+    let mut bytes = Vec::new();
+    value.write_value(&mut bytes)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn dot_undirected() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(1, 0, 0.5), (1, 2, 1.5)])
+            .build();
+
+        let mut bytes = Vec::new();
+        DotOutput::default().write(&graph, &mut bytes).unwrap();
+
+        let expected = "graph {
+  0;
+  1;
+  2;
+  0 -- 1 [label=\"0.5\"];
+  1 -- 2 [label=\"1.5\"];
+}
+";
+        assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn dot_labels_and_colors() {
+        let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.5), (1, 0, 1.5)])
+            .build();
+
+        let output = DotOutput::default()
+            .edge_values(false)
+            .node_labels(vec!["say \"hi\"", "b"])
+            .node_colors(&[13_u32, 1]);
+
+        let mut bytes = Vec::new();
+        output.write(&graph, &mut bytes).unwrap();
+
+        let expected = r#"digraph {
+  0 [label="say \"hi\"", style=filled, colorscheme=set312, fillcolor=2];
+  1 [label="b", style=filled, colorscheme=set312, fillcolor=2];
+  0 -> 1;
+  1 -> 0;
+}
+"#;
+        assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn dot_invalid_node_colors() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+
+        let result = DotOutput::default()
+            .node_colors(&[0_u32])
+            .write(&graph, &mut Vec::new());
+
+        assert!(matches!(result, Err(Error::InvalidNodeValues)));
+    }
+}
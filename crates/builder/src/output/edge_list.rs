@@ -0,0 +1,84 @@
+use std::io::Write;
+
+use crate::{index::Idx, Error};
+
+use super::{OutputFormat, OutputGraph, WriteValue};
+
+/// Writes a graph as an edge list with one edge per line.
+///
+/// Source and target node ids are separated by a whitespace. If the graph has
+/// edge values, the value follows the target node id, separated by another
+/// whitespace. The output can be read via
+/// [`EdgeListInput`](crate::input::EdgeListInput). Node values are not
+/// written.
+///
+/// For undirected graphs, every edge is written once.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EdgeListOutput;
+
+impl<NI, NV, EV> OutputFormat<NI, NV, EV> for EdgeListOutput
+where
+    NI: Idx,
+    EV: WriteValue,
+{
+    fn write<G, W>(&self, graph: &G, writer: &mut W) -> Result<(), Error>
+    where
+        G: OutputGraph<NI, NV, EV>,
+        W: Write,
+    {
+        let mut result = Ok(());
+
+        graph.for_each_edge(|source, target, value| {
+            if result.is_ok() {
+                result = write_edge(writer, source, target, value);
+            }
+        });
+
+        Ok(result?)
+    }
+}
+
+fn write_edge<NI, EV, W>(writer: &mut W, source: NI, target: NI, value: &EV) -> std::io::Result<()>
+where
+    NI: Idx,
+    EV: WriteValue,
+    W: Write,
+{
+    write!(writer, "{} {}", source.index(), target.index())?;
+    if EV::TYPE_NAME.is_some() {
+        writer.write_all(b" ")?;
+        value.write_value(writer)?;
+    }
+    writer.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn directed_edge_list() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (2, 1)])
+            .build();
+
+        let mut bytes = Vec::new();
+        EdgeListOutput.write(&graph, &mut bytes).unwrap();
+
+        assert_eq!(String::from_utf8(bytes).unwrap(), "0 1\n0 2\n2 1\n");
+    }
+
+    #[test]
+    fn undirected_edge_list_with_values() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(1, 0, 0.5), (1, 2, 1.5)])
+            .build();
+
+        let mut bytes = Vec::new();
+        EdgeListOutput.write(&graph, &mut bytes).unwrap();
+
+        assert_eq!(String::from_utf8(bytes).unwrap(), "0 1 0.5\n1 2 1.5\n");
+    }
+}
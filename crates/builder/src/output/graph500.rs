@@ -0,0 +1,92 @@
+use std::io::Write;
+
+use crate::{index::Idx, Error};
+
+use super::{OutputFormat, OutputGraph};
+
+/// Writes a graph in the binary edge format of the Graph500 reference
+/// implementation.
+///
+/// Every edge is stored as a packed 12 byte record containing two 48 bit node
+/// ids in native byte order. Node and edge values are not written. The output
+/// can be read via [`Graph500Input`](crate::input::Graph500Input).
+///
+/// Note that the Graph500 format does not store the node count. When reading
+/// the file, the node count is derived from the number of edges assuming the
+/// default edge factor of 16.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Graph500Output;
+
+const MAX_ID: u64 = (1 << 48) - 1;
+
+impl<NI, NV, EV> OutputFormat<NI, NV, EV> for Graph500Output
+where
+    NI: Idx,
+{
+    fn write<G, W>(&self, graph: &G, writer: &mut W) -> Result<(), Error>
+    where
+        G: OutputGraph<NI, NV, EV>,
+        W: Write,
+    {
+        if graph.node_count().index() as u64 > MAX_ID {
+            return Err(Error::InvalidIdType {
+                expected: String::from("6"),
+                actual: std::mem::size_of::<NI>().to_string(),
+            });
+        }
+
+        let mut result = Ok(());
+
+        graph.for_each_edge(|source, target, _| {
+            if result.is_ok() {
+                result = writer.write_all(&pack(source.index() as u64, target.index() as u64));
+            }
+        });
+
+        Ok(result?)
+    }
+}
+
+// see `PackedEdge` in `crate::input::graph500`
+fn pack(source: u64, target: u64) -> [u8; 12] {
+    let high = ((source >> 32) & 0xFFFF) as u32 | (((target >> 32) & 0xFFFF) as u32) << 16;
+
+    let mut bytes = [0; 12];
+    bytes[0..4].copy_from_slice(&(source as u32).to_ne_bytes());
+    bytes[4..8].copy_from_slice(&(target as u32).to_ne_bytes());
+    bytes[8..12].copy_from_slice(&high.to_ne_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pack;
+    use crate::prelude::*;
+
+    #[test]
+    fn pack_high_bits() {
+        let bytes = pack(1 << 33 | 5, 1 << 40 | 7);
+
+        let word =
+            |i: usize| u32::from_ne_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+
+        assert_eq!(word(0), 5);
+        assert_eq!(word(4), 7);
+        assert_eq!(word(8), 2 | 256 << 16);
+    }
+
+    #[test]
+    fn graph500_edges() {
+        let graph: UndirectedCsrGraph<u64> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (2, 1)])
+            .build();
+
+        let mut bytes = Vec::new();
+        Graph500Output.write(&graph, &mut bytes).unwrap();
+
+        assert_eq!(bytes.len(), 3 * 12);
+        assert_eq!(&bytes[0..12], &pack(0, 1));
+        assert_eq!(&bytes[24..36], &pack(1, 2));
+    }
+}
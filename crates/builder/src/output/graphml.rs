@@ -0,0 +1,144 @@
+use std::io::Write;
+
+use crate::{index::Idx, Error};
+
+use super::{OutputFormat, OutputGraph, WriteValue};
+
+/// Writes a graph in the XML-based [GraphML](http://graphml.graphdrawing.org/)
+/// format.
+///
+/// Node ids are written as `n{id}`. Node values are stored as node data with
+/// key `value` and edge values as edge data with key `weight`. Data keys are
+/// only declared if the corresponding value type is not `()`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GraphMlOutput;
+
+impl<NI, NV, EV> OutputFormat<NI, NV, EV> for GraphMlOutput
+where
+    NI: Idx,
+    NV: WriteValue,
+    EV: WriteValue,
+{
+    fn write<G, W>(&self, graph: &G, writer: &mut W) -> Result<(), Error>
+    where
+        G: OutputGraph<NI, NV, EV>,
+        W: Write,
+    {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        if let Some(type_name) = NV::TYPE_NAME {
+            writeln!(
+                writer,
+                r#"  <key id="value" for="node" attr.name="value" attr.type="{type_name}"/>"#
+            )?;
+        }
+        if let Some(type_name) = EV::TYPE_NAME {
+            writeln!(
+                writer,
+                r#"  <key id="weight" for="edge" attr.name="weight" attr.type="{type_name}"/>"#
+            )?;
+        }
+
+        let edge_default = if graph.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        writeln!(writer, r#"  <graph id="G" edgedefault="{edge_default}">"#)?;
+
+        for node in 0..graph.node_count().index() {
+            if NV::TYPE_NAME.is_some() {
+                write!(writer, r#"    <node id="n{node}"><data key="value">"#)?;
+                graph.node_value(NI::new(node)).write_value(writer)?;
+                writeln!(writer, "</data></node>")?;
+            } else {
+                writeln!(writer, r#"    <node id="n{node}"/>"#)?;
+            }
+        }
+
+        let mut result = Ok(());
+        graph.for_each_edge(|source, target, value| {
+            if result.is_ok() {
+                result = write_edge(writer, source.index(), target.index(), value);
+            }
+        });
+        result?;
+
+        writeln!(writer, "  </graph>")?;
+        writeln!(writer, "</graphml>")?;
+
+        Ok(())
+    }
+}
+
+fn write_edge<EV, W>(
+    writer: &mut W,
+    source: usize,
+    target: usize,
+    value: &EV,
+) -> std::io::Result<()>
+where
+    EV: WriteValue,
+    W: Write,
+{
+    if EV::TYPE_NAME.is_some() {
+        write!(
+            writer,
+            r#"    <edge source="n{source}" target="n{target}"><data key="weight">"#
+        )?;
+        value.write_value(writer)?;
+        writeln!(writer, "</data></edge>")
+    } else {
+        writeln!(
+            writer,
+            r#"    <edge source="n{source}" target="n{target}"/>"#
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn graphml_with_values() {
+        let graph: DirectedCsrGraph<u32, u64, f64> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 0.5)])
+            .node_values(vec![42, 1337])
+            .build();
+
+        let mut bytes = Vec::new();
+        GraphMlOutput.write(&graph, &mut bytes).unwrap();
+
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="value" for="node" attr.name="value" attr.type="long"/>
+  <key id="weight" for="edge" attr.name="weight" attr.type="double"/>
+  <graph id="G" edgedefault="directed">
+    <node id="n0"><data key="value">42</data></node>
+    <node id="n1"><data key="value">1337</data></node>
+    <edge source="n0" target="n1"><data key="weight">0.5</data></edge>
+  </graph>
+</graphml>
+"#;
+
+        assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn graphml_without_values() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(1, 0)]).build();
+
+        let mut bytes = Vec::new();
+        GraphMlOutput.write(&graph, &mut bytes).unwrap();
+        let graphml = String::from_utf8(bytes).unwrap();
+
+        assert!(!graphml.contains("<key"));
+        assert!(graphml.contains(r#"edgedefault="undirected""#));
+        assert!(graphml.contains(r#"<edge source="n0" target="n1"/>"#));
+    }
+}
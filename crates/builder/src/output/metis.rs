@@ -0,0 +1,117 @@
+use std::io::Write;
+
+use crate::{index::Idx, Error};
+
+use super::{OutputFormat, OutputGraph, WriteValue};
+
+/// Writes an undirected graph in the METIS adjacency format.
+///
+/// The output starts with a header `n m [fmt]` followed by one line per node
+/// listing the 1-based ids of its neighbors. If the graph has edge values, the
+/// format flag `001` is set and each neighbor id is followed by the value of
+/// the connecting edge. METIS expects positive integer weights. Node values
+/// and self-loops are not written. The output can be read via
+/// [`MetisInput`](crate::input::MetisInput).
+///
+/// Directed graphs cannot be written, since METIS only supports undirected
+/// graphs. Convert them first, e.g., via
+/// [`ToUndirectedOp`](crate::graph_ops::ToUndirectedOp).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MetisOutput;
+
+impl<NI, NV, EV> OutputFormat<NI, NV, EV> for MetisOutput
+where
+    NI: Idx,
+    EV: WriteValue + Copy,
+{
+    fn write<G, W>(&self, graph: &G, writer: &mut W) -> Result<(), Error>
+    where
+        G: OutputGraph<NI, NV, EV>,
+        W: Write,
+    {
+        if graph.is_directed() {
+            return Err(Error::UnsupportedGraph {
+                reason: String::from("the METIS format only supports undirected graphs"),
+            });
+        }
+
+        let node_count = graph.node_count().index();
+        let mut adjacency = vec![Vec::new(); node_count];
+        let mut edge_count = 0;
+
+        graph.for_each_edge(|source, target, value| {
+            if source != target {
+                adjacency[source.index()].push((target.index(), *value));
+                adjacency[target.index()].push((source.index(), *value));
+                edge_count += 1;
+            }
+        });
+
+        write!(writer, "{node_count} {edge_count}")?;
+        if EV::TYPE_NAME.is_some() {
+            write!(writer, " 001")?;
+        }
+        writeln!(writer)?;
+
+        for mut neighbors in adjacency {
+            neighbors.sort_unstable_by_key(|(target, _)| *target);
+            for (i, (target, value)) in neighbors.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b" ")?;
+                }
+                write!(writer, "{}", target + 1)?;
+                if EV::TYPE_NAME.is_some() {
+                    writer.write_all(b" ")?;
+                    value.write_value(writer)?;
+                }
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn metis_round_trip() {
+        let graph: UndirectedCsrGraph<u32, (), u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(0, 1, 7), (1, 2, 9), (3, 3, 1)])
+            .build();
+
+        let mut bytes = Vec::new();
+        MetisOutput.write(&graph, &mut bytes).unwrap();
+
+        assert_eq!(
+            String::from_utf8(bytes.clone()).unwrap(),
+            "4 2 001\n2 7\n1 7 3 9\n2 9\n\n"
+        );
+
+        let metis = Metis::<u32, u32>::try_from(bytes.as_slice()).unwrap();
+        let read: UndirectedCsrGraph<u32, (), u32> =
+            UndirectedCsrGraph::from((metis, CsrLayout::Sorted));
+
+        assert_eq!(read.node_count(), 4);
+        assert_eq!(read.edge_count(), 2);
+        assert_eq!(
+            read.neighbors_with_values(1).as_slice(),
+            &[Target::new(0, 7), Target::new(2, 9)]
+        );
+    }
+
+    #[test]
+    fn metis_directed_graph() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+
+        assert!(matches!(
+            MetisOutput.write(&graph, &mut Vec::new()),
+            Err(Error::UnsupportedGraph { .. })
+        ));
+    }
+}
@@ -0,0 +1,237 @@
+//! Writes graphs to files in various formats.
+//!
+//! Output formats are the counterpart to the input formats in
+//! [`crate::input`]. A graph is written via [`write_graph`], which takes the
+//! graph, a path and the output format:
+//!
+//! ```
+//! use graph_builder::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+//!     .csr_layout(CsrLayout::Sorted)
+//!     .edges_with_values(vec![(0, 1, 0.5), (0, 2, 1.0), (1, 2, 1.5)])
+//!     .build();
+//!
+//! let path = std::env::temp_dir().join("graph_builder_output_example.el");
+//! write_graph(&graph, &path, EdgeListOutput).unwrap();
+//!
+//! let read: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+//!     .csr_layout(CsrLayout::Sorted)
+//!     .file_format(EdgeListInput::default())
+//!     .path(&path)
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(read.edge_count(), 3);
+//! assert_eq!(read.out_neighbors_with_values(0).as_slice(), graph.out_neighbors_with_values(0).as_slice());
+//! ```
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    index::Idx, DirectedALGraph, DirectedCsrGraph, DirectedNeighborsWithValues, Error, Graph,
+    NodeValues, UndirectedALGraph, UndirectedCsrGraph, UndirectedNeighborsWithValues,
+};
+
+pub mod dot;
+pub mod edge_list;
+pub mod graph500;
+pub mod graphml;
+pub mod metis;
+pub mod neo4j;
+
+pub use dot::DotOutput;
+pub use edge_list::EdgeListOutput;
+pub use graph500::Graph500Output;
+pub use graphml::GraphMlOutput;
+pub use metis::MetisOutput;
+pub use neo4j::Neo4jOutput;
+
+/// Writes the given graph to a file at the given path using the given format.
+///
+/// Existing files are overwritten.
+pub fn write_graph<NI, NV, EV, G, P, Format>(
+    graph: &G,
+    path: P,
+    format: Format,
+) -> Result<(), Error>
+where
+    NI: Idx,
+    G: OutputGraph<NI, NV, EV>,
+    P: AsRef<Path>,
+    Format: OutputFormat<NI, NV, EV>,
+{
+    let mut writer = BufWriter::new(File::create(path)?);
+    format.write(graph, &mut writer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// An output format that can write graphs with node values of type `NV` and
+/// edge values of type `EV`.
+pub trait OutputFormat<NI: Idx, NV, EV> {
+    /// Writes the given graph to the given writer.
+    fn write<G, W>(&self, graph: &G, writer: &mut W) -> Result<(), Error>
+    where
+        G: OutputGraph<NI, NV, EV>,
+        W: Write;
+}
+
+/// A graph that can be written by an [`OutputFormat`].
+pub trait OutputGraph<NI: Idx, NV, EV>: Graph<NI> + NodeValues<NI, NV> {
+    /// Returns `true`, if the edges of the graph are directed.
+    fn is_directed(&self) -> bool;
+
+    /// Calls the given function for every edge of the graph.
+    ///
+    /// For directed graphs, edges are visited in outgoing direction. For
+    /// undirected graphs, every edge is visited once with the smaller node id
+    /// as source.
+    fn for_each_edge<F>(&self, f: F)
+    where
+        F: FnMut(NI, NI, &EV);
+}
+
+macro_rules! impl_directed_output_graph {
+    ($graph:ident) => {
+        impl<NI, NV, EV> OutputGraph<NI, NV, EV> for $graph<NI, NV, EV>
+        where
+            NI: Idx,
+            NV: Send + Sync,
+            EV: Send + Sync,
+        {
+            fn is_directed(&self) -> bool {
+                true
+            }
+
+            fn for_each_edge<F>(&self, mut f: F)
+            where
+                F: FnMut(NI, NI, &EV),
+            {
+                for source in 0..self.node_count().index() {
+                    let source = NI::new(source);
+                    for t in self.out_neighbors_with_values(source) {
+                        f(source, t.target, &t.value);
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_undirected_output_graph {
+    ($graph:ident) => {
+        impl<NI, NV, EV> OutputGraph<NI, NV, EV> for $graph<NI, NV, EV>
+        where
+            NI: Idx,
+            NV: Send + Sync,
+            EV: Send + Sync,
+        {
+            fn is_directed(&self) -> bool {
+                false
+            }
+
+            fn for_each_edge<F>(&self, mut f: F)
+            where
+                F: FnMut(NI, NI, &EV),
+            {
+                for source in 0..self.node_count().index() {
+                    let source = NI::new(source);
+                    // Self-loops are stored twice in the neighbor list.
+                    let mut self_loop = false;
+                    for t in self.neighbors_with_values(source) {
+                        if t.target == source {
+                            self_loop = !self_loop;
+                            if !self_loop {
+                                continue;
+                            }
+                        } else if t.target < source {
+                            continue;
+                        }
+                        f(source, t.target, &t.value);
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_directed_output_graph!(DirectedCsrGraph);
+impl_directed_output_graph!(DirectedALGraph);
+impl_undirected_output_graph!(UndirectedCsrGraph);
+impl_undirected_output_graph!(UndirectedALGraph);
+
+/// Used by output formats to write node or edge values.
+///
+/// The unit type `()` represents the absence of values. Formats skip writing
+/// values of that type.
+pub trait WriteValue {
+    /// The name of the type as used by typed formats such as GraphML, or
+    /// `None` if the type carries no value.
+    const TYPE_NAME: Option<&'static str>;
+
+    /// Writes the textual representation of the value.
+    fn write_value<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+impl WriteValue for () {
+    const TYPE_NAME: Option<&'static str> = None;
+
+    fn write_value<W: Write>(&self, _writer: &mut W) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+macro_rules! impl_write_value {
+    ($type_name:literal, $($ty:ty),+ $(,)?) => {
+        $(
+            impl WriteValue for $ty {
+                const TYPE_NAME: Option<&'static str> = Some($type_name);
+
+                fn write_value<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write!(writer, "{}", self)
+                }
+            }
+        )+
+    };
+}
+
+impl_write_value!("int", u8, u16, i8, i16, i32);
+impl_write_value!("long", u32, u64, usize, i64, isize);
+impl_write_value!("float", f32);
+impl_write_value!("double", f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn edges<G: OutputGraph<u32, (), f32>>(graph: &G) -> Vec<(u32, u32, f32)> {
+        let mut edges = Vec::new();
+        graph.for_each_edge(|s, t, v| edges.push((s, t, *v)));
+        edges
+    }
+
+    #[test]
+    fn undirected_edges_are_visited_once() {
+        let graph: UndirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(1, 0, 0.1), (1, 2, 1.2), (2, 2, 2.2)])
+            .build();
+
+        assert_eq!(edges(&graph), vec![(0, 1, 0.1), (1, 2, 1.2), (2, 2, 2.2)]);
+    }
+
+    #[test]
+    fn directed_edges() {
+        let graph: DirectedALGraph<u32, (), f32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges_with_values(vec![(1, 0, 0.1), (1, 2, 1.2), (2, 2, 2.2)])
+            .build();
+
+        assert_eq!(edges(&graph), vec![(1, 0, 0.1), (1, 2, 1.2), (2, 2, 2.2)]);
+    }
+}
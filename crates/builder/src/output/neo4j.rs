@@ -0,0 +1,312 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use crate::{index::Idx, Error};
+
+use super::{OutputGraph, WriteValue};
+
+/// Writes a graph as CSV files that can be imported into
+/// [Neo4j](https://neo4j.com) using `neo4j-admin database import`.
+///
+/// The graph is written as a node file and a relationship file. The node file
+/// contains the node id, a label, the node value and any number of additional
+/// node properties, e.g., results computed by an algorithm. The relationship
+/// file contains the source and target node ids, a relationship type and the
+/// edge value. Values of type `()` are not written. Undirected edges are
+/// written once, with the smaller node id as source.
+///
+/// The files can be imported via
+///
+/// ```text
+/// neo4j-admin database import full --nodes=nodes.csv --relationships=relationships.csv
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .edges_with_values(vec![(0, 1, 0.5), (1, 2, 1.5)])
+///     .build();
+///
+/// let scores = vec![0.25_f64, 0.5, 0.25];
+///
+/// let output = Neo4jOutput::default()
+///     .label("Page")
+///     .relationship_type("LINKS_TO")
+///     .node_property("score", &scores);
+///
+/// let mut nodes = Vec::new();
+/// let mut relationships = Vec::new();
+/// output.write_nodes(&graph, &mut nodes).unwrap();
+/// output.write_relationships(&graph, &mut relationships).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(nodes).unwrap(),
+///     "id:ID,:LABEL,score:double\n0,Page,0.25\n1,Page,0.5\n2,Page,0.25\n"
+/// );
+/// assert_eq!(
+///     String::from_utf8(relationships).unwrap(),
+///     ":START_ID,:END_ID,:TYPE,weight:float\n0,1,LINKS_TO,0.5\n1,2,LINKS_TO,1.5\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Neo4jOutput {
+    label: String,
+    relationship_type: String,
+    node_value: String,
+    edge_value: String,
+    node_properties: Vec<NodeProperty>,
+}
+
+#[derive(Debug, Clone)]
+struct NodeProperty {
+    name: String,
+    type_name: &'static str,
+    values: Vec<String>,
+}
+
+impl Default for Neo4jOutput {
+    fn default() -> Self {
+        Self {
+            label: String::from("Node"),
+            relationship_type: String::from("REL"),
+            node_value: String::from("value"),
+            edge_value: String::from("weight"),
+            node_properties: Vec::new(),
+        }
+    }
+}
+
+impl Neo4jOutput {
+    /// Sets the label of all nodes.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Sets the type of all relationships.
+    pub fn relationship_type(mut self, relationship_type: impl Into<String>) -> Self {
+        self.relationship_type = relationship_type.into();
+        self
+    }
+
+    /// Sets the name of the property that stores the node values.
+    pub fn node_value(mut self, name: impl Into<String>) -> Self {
+        self.node_value = name.into();
+        self
+    }
+
+    /// Sets the name of the property that stores the edge values.
+    pub fn edge_value(mut self, name: impl Into<String>) -> Self {
+        self.edge_value = name.into();
+        self
+    }
+
+    /// Adds a node property with one value per node, e.g., the result of an
+    /// algorithm.
+    ///
+    /// The number of values must match the node count of the written graph.
+    pub fn node_property<V>(mut self, name: impl Into<String>, values: &[V]) -> Self
+    where
+        V: WriteValue + ToString,
+    {
+        if let Some(type_name) = V::TYPE_NAME {
+            self.node_properties.push(NodeProperty {
+                name: name.into(),
+                type_name,
+                values: values.iter().map(|v| v.to_string()).collect(),
+            });
+        }
+        self
+    }
+
+    /// Writes the node file and the relationship file to the given paths.
+    ///
+    /// Existing files are overwritten.
+    pub fn write_files<NI, NV, EV, G, P1, P2>(
+        &self,
+        graph: &G,
+        nodes_path: P1,
+        relationships_path: P2,
+    ) -> Result<(), Error>
+    where
+        NI: Idx,
+        NV: WriteValue,
+        EV: WriteValue,
+        G: OutputGraph<NI, NV, EV>,
+        P1: AsRef<Path>,
+        P2: AsRef<Path>,
+    {
+        let mut writer = BufWriter::new(File::create(nodes_path)?);
+        self.write_nodes(graph, &mut writer)?;
+        writer.flush()?;
+
+        let mut writer = BufWriter::new(File::create(relationships_path)?);
+        self.write_relationships(graph, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes the nodes of the given graph including a header row.
+    pub fn write_nodes<NI, NV, EV, G, W>(&self, graph: &G, writer: &mut W) -> Result<(), Error>
+    where
+        NI: Idx,
+        NV: WriteValue,
+        G: OutputGraph<NI, NV, EV>,
+        W: Write,
+    {
+        let node_count = graph.node_count().index();
+        if self
+            .node_properties
+            .iter()
+            .any(|property| property.values.len() != node_count)
+        {
+            return Err(Error::InvalidNodeValues);
+        }
+
+        write!(writer, "id:ID,:LABEL")?;
+        if let Some(type_name) = NV::TYPE_NAME {
+            write!(writer, ",{}:{type_name}", quote(&self.node_value))?;
+        }
+        for property in &self.node_properties {
+            write!(writer, ",{}:{}", quote(&property.name), property.type_name)?;
+        }
+        writeln!(writer)?;
+
+        let label = quote(&self.label);
+        for node in 0..node_count {
+            write!(writer, "{node},{label}")?;
+            if NV::TYPE_NAME.is_some() {
+                write!(writer, ",")?;
+                graph.node_value(NI::new(node)).write_value(writer)?;
+            }
+            for property in &self.node_properties {
+                write!(writer, ",{}", property.values[node])?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the edges of the given graph including a header row.
+    pub fn write_relationships<NI, NV, EV, G, W>(
+        &self,
+        graph: &G,
+        writer: &mut W,
+    ) -> Result<(), Error>
+    where
+        NI: Idx,
+        EV: WriteValue,
+        G: OutputGraph<NI, NV, EV>,
+        W: Write,
+    {
+        write!(writer, ":START_ID,:END_ID,:TYPE")?;
+        if let Some(type_name) = EV::TYPE_NAME {
+            write!(writer, ",{}:{type_name}", quote(&self.edge_value))?;
+        }
+        writeln!(writer)?;
+
+        let relationship_type = quote(&self.relationship_type);
+        let mut result = Ok(());
+        graph.for_each_edge(|source, target, value| {
+            if result.is_ok() {
+                result = write_relationship(
+                    writer,
+                    source.index(),
+                    target.index(),
+                    &relationship_type,
+                    value,
+                );
+            }
+        });
+        result?;
+
+        Ok(())
+    }
+}
+
+fn write_relationship<EV, W>(
+    writer: &mut W,
+    source: usize,
+    target: usize,
+    relationship_type: &str,
+    value: &EV,
+) -> std::io::Result<()>
+where
+    EV: WriteValue,
+    W: Write,
+{
+    write!(writer, "{source},{target},{relationship_type}")?;
+    if EV::TYPE_NAME.is_some() {
+        write!(writer, ",")?;
+        value.write_value(writer)?;
+    }
+    writeln!(writer)
+}
+
+/// Quotes a field if it contains a delimiter, a quote or a line break.
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn neo4j_undirected_with_node_values() {
+        let graph: UndirectedCsrGraph<u32, u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(1, 0), (1, 2)])
+            .node_values(vec![7, 8, 9])
+            .build();
+
+        let output = Neo4jOutput::default()
+            .label("Person, Employee")
+            .node_value("age")
+            .node_property("community", &[0_u64, 0, 1]);
+
+        let mut nodes = Vec::new();
+        let mut relationships = Vec::new();
+        output.write_nodes(&graph, &mut nodes).unwrap();
+        output
+            .write_relationships(&graph, &mut relationships)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(nodes).unwrap(),
+            "id:ID,:LABEL,age:long,community:long\n\
+             0,\"Person, Employee\",7,0\n\
+             1,\"Person, Employee\",8,0\n\
+             2,\"Person, Employee\",9,1\n"
+        );
+        assert_eq!(
+            String::from_utf8(relationships).unwrap(),
+            ":START_ID,:END_ID,:TYPE\n0,1,REL\n1,2,REL\n"
+        );
+    }
+
+    #[test]
+    fn neo4j_property_length_mismatch() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new().edges(vec![(0, 1)]).build();
+
+        let output = Neo4jOutput::default().node_property("score", &[1.0_f64]);
+
+        assert!(matches!(
+            output.write_nodes(&graph, &mut Vec::new()),
+            Err(Error::InvalidNodeValues)
+        ));
+    }
+}
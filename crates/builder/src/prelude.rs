@@ -1,34 +1,79 @@
 pub use crate::builder::GraphBuilder;
 
+pub use crate::diff::diff;
+pub use crate::diff::diff_undirected;
+pub use crate::diff::GraphChange;
+
+pub use crate::edge_balanced::edge_balanced_partition;
+
 pub use crate::graph::csr::CsrLayout;
+pub use crate::graph::csr::CsrStrategy;
 pub use crate::graph::csr::DirectedCsrGraph;
+pub use crate::graph::csr::SortAlgorithm;
 pub use crate::graph::csr::UndirectedCsrGraph;
+pub use crate::graph::delta::DeltaGraph;
+pub use crate::graph::hybrid::HybridView;
+pub use crate::graph::view::UndirectedView;
 pub use crate::graph::Target;
 
 pub use crate::graph::adj_list::DirectedALGraph;
 pub use crate::graph::adj_list::UndirectedALGraph;
 
 pub use crate::graph_ops::DegreePartitionOp;
+pub use crate::graph_ops::DegreeQueryOp;
 pub use crate::graph_ops::DeserializeGraphOp;
+pub use crate::graph_ops::EgoSubgraphOp;
+pub use crate::graph_ops::FilterEdgesOp;
 pub use crate::graph_ops::ForEachNodeParallelByPartitionOp;
 pub use crate::graph_ops::ForEachNodeParallelOp;
+pub use crate::graph_ops::GraphStatsOp;
 pub use crate::graph_ops::InDegreePartitionOp;
+pub use crate::graph_ops::InNeighborsWithinOp;
+pub use crate::graph_ops::InducedSubgraphOp;
+pub use crate::graph_ops::MapEdgeValuesOp;
+pub use crate::graph_ops::NeighborsWithinOp;
 pub use crate::graph_ops::OutDegreePartitionOp;
+pub use crate::graph_ops::OutNeighborsWithinOp;
 pub use crate::graph_ops::RelabelByDegreeOp;
+pub use crate::graph_ops::RelabelOp;
 pub use crate::graph_ops::SerializeGraphOp;
 pub use crate::graph_ops::ToUndirectedOp;
+pub use crate::graph_ops::ValidateOp;
 
 pub use crate::index::Idx;
 pub use atomic::Atomic;
 
 pub use crate::input::*;
 
+pub use crate::output::write_graph;
+pub use crate::output::DotOutput;
+pub use crate::output::EdgeListOutput;
+pub use crate::output::Graph500Output;
+pub use crate::output::GraphMlOutput;
+pub use crate::output::MetisOutput;
+pub use crate::output::Neo4jOutput;
+pub use crate::output::OutputFormat;
+pub use crate::output::OutputGraph;
+pub use crate::output::WriteValue;
+
+pub use crate::properties::AtomicValue;
+pub use crate::properties::EdgeValues;
+pub use crate::properties::SharedNodeValues;
+
+pub use crate::stats::DegreeStats;
+pub use crate::stats::GraphStats;
+
+pub use crate::validation::ValidationReport;
+pub use crate::validation::Violation;
+
 pub use crate::DirectedDegrees;
+pub use crate::DirectedEdgeIds;
 pub use crate::DirectedNeighbors;
 pub use crate::DirectedNeighborsWithValues;
 pub use crate::Graph;
 pub use crate::NodeValues;
 pub use crate::UndirectedDegrees;
+pub use crate::UndirectedEdgeIds;
 pub use crate::UndirectedNeighbors;
 pub use crate::UndirectedNeighborsWithValues;
 
@@ -0,0 +1,599 @@
+//! Property arrays that store a value per node or per edge.
+//!
+//! Parallel graph algorithms typically maintain a value per node, e.g., a
+//! distance, a rank or a component id, that is updated concurrently by
+//! multiple threads. [`SharedNodeValues`] stores such values in atomics and
+//! provides accessors with explicit memory orderings, so that user-defined
+//! algorithms do not need to resort to `unsafe` code.
+//!
+//! Values per edge, e.g., a flow or a centrality score, are stored in
+//! [`EdgeValues`], which is keyed by the edge ids of
+//! [`DirectedEdgeIds`] and [`UndirectedEdgeIds`]. Unlike edge values that are
+//! part of the graph, they can be computed and updated after the graph has
+//! been built.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::atomic::Ordering;
+//!
+//! use graph_builder::prelude::*;
+//! use rayon::prelude::*;
+//!
+//! let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+//!     .edges(vec![(0, 1), (0, 2), (1, 2), (2, 0)])
+//!     .build();
+//!
+//! // Count the in-degree of every node in parallel.
+//! let degrees = SharedNodeValues::<u64>::new(graph.node_count().index(), 0);
+//!
+//! (0..graph.node_count()).into_par_iter().for_each(|node| {
+//!     for target in graph.out_neighbors(node) {
+//!         degrees.fetch_add(*target, 1, Ordering::Relaxed);
+//!     }
+//! });
+//!
+//! assert_eq!(degrees.into_vec(), vec![1, 1, 2]);
+//! ```
+
+use std::sync::atomic::{AtomicI32, AtomicI64, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    index::Idx, DirectedEdgeIds, DirectedNeighbors, UndirectedEdgeIds, UndirectedNeighbors,
+};
+
+/// A value type that can be stored in [`SharedNodeValues`].
+///
+/// Integer types are backed by the corresponding standard library atomic.
+/// Floating point types are stored as their bit representation and updated
+/// using compare-and-swap loops.
+pub trait AtomicValue: Copy + Send + Sync + 'static {
+    /// The atomic type storing a single value.
+    type Atomic: Send + Sync;
+
+    fn new_atomic(value: Self) -> Self::Atomic;
+
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    fn store(atomic: &Self::Atomic, value: Self, order: Ordering);
+
+    fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+
+    fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+
+    fn fetch_min(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+
+    fn fetch_max(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+
+    fn into_inner(atomic: Self::Atomic) -> Self;
+}
+
+macro_rules! impl_atomic_value_int {
+    ($ty:ty, $atomic:ty) => {
+        impl AtomicValue for $ty {
+            type Atomic = $atomic;
+
+            #[inline]
+            fn new_atomic(value: Self) -> Self::Atomic {
+                <$atomic>::new(value)
+            }
+
+            #[inline]
+            fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                atomic.load(order)
+            }
+
+            #[inline]
+            fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                atomic.store(value, order)
+            }
+
+            #[inline]
+            fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                atomic.swap(value, order)
+            }
+
+            #[inline]
+            fn compare_exchange(
+                atomic: &Self::Atomic,
+                current: Self,
+                new: Self,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<Self, Self> {
+                atomic.compare_exchange(current, new, success, failure)
+            }
+
+            #[inline]
+            fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                atomic.fetch_add(value, order)
+            }
+
+            #[inline]
+            fn fetch_min(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                atomic.fetch_min(value, order)
+            }
+
+            #[inline]
+            fn fetch_max(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                atomic.fetch_max(value, order)
+            }
+
+            #[inline]
+            fn into_inner(atomic: Self::Atomic) -> Self {
+                atomic.into_inner()
+            }
+        }
+    };
+}
+
+impl_atomic_value_int!(u32, AtomicU32);
+impl_atomic_value_int!(u64, AtomicU64);
+impl_atomic_value_int!(usize, AtomicUsize);
+impl_atomic_value_int!(i32, AtomicI32);
+impl_atomic_value_int!(i64, AtomicI64);
+
+macro_rules! impl_atomic_value_float {
+    ($ty:ty, $atomic:ty) => {
+        impl AtomicValue for $ty {
+            type Atomic = $atomic;
+
+            #[inline]
+            fn new_atomic(value: Self) -> Self::Atomic {
+                <$atomic>::new(value.to_bits())
+            }
+
+            #[inline]
+            fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                <$ty>::from_bits(atomic.load(order))
+            }
+
+            #[inline]
+            fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                atomic.store(value.to_bits(), order)
+            }
+
+            #[inline]
+            fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                <$ty>::from_bits(atomic.swap(value.to_bits(), order))
+            }
+
+            #[inline]
+            fn compare_exchange(
+                atomic: &Self::Atomic,
+                current: Self,
+                new: Self,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<Self, Self> {
+                atomic
+                    .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+                    .map(<$ty>::from_bits)
+                    .map_err(<$ty>::from_bits)
+            }
+
+            #[inline]
+            fn fetch_add(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                update_float(atomic, order, |current| Some(current + value))
+            }
+
+            #[inline]
+            fn fetch_min(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                update_float(atomic, order, |current| (value < current).then_some(value))
+            }
+
+            #[inline]
+            fn fetch_max(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                update_float(atomic, order, |current| (value > current).then_some(value))
+            }
+
+            #[inline]
+            fn into_inner(atomic: Self::Atomic) -> Self {
+                <$ty>::from_bits(atomic.into_inner())
+            }
+        }
+    };
+}
+
+impl_atomic_value_float!(f32, AtomicU32);
+impl_atomic_value_float!(f64, AtomicU64);
+
+/// Applies `f` to the current value until the update succeeds or `f` returns
+/// `None`. Returns the previous value.
+#[inline]
+fn update_float<T, F>(atomic: &T::Atomic, order: Ordering, mut f: F) -> T
+where
+    T: AtomicValue,
+    F: FnMut(T) -> Option<T>,
+{
+    let mut current = T::load(atomic, Ordering::Relaxed);
+    while let Some(new) = f(current) {
+        match T::compare_exchange(atomic, current, new, order, Ordering::Relaxed) {
+            Ok(previous) => return previous,
+            Err(actual) => current = actual,
+        }
+    }
+    current
+}
+
+/// A value per node that can be read and updated concurrently.
+///
+/// All accessors take the node id and an explicit [`Ordering`]. Use
+/// [`Ordering::Relaxed`] if the values are only combined, e.g., summed up,
+/// and the result is read after all threads have finished, and
+/// [`Ordering::AcqRel`] or [`Ordering::SeqCst`] if threads synchronize via
+/// the values.
+pub struct SharedNodeValues<T: AtomicValue> {
+    values: Box<[T::Atomic]>,
+}
+
+impl<T: AtomicValue> SharedNodeValues<T> {
+    /// Creates a value array for `node_count` nodes with the given initial
+    /// value.
+    pub fn new(node_count: usize, value: T) -> Self {
+        let mut values = Vec::with_capacity(node_count);
+        values.resize_with(node_count, || T::new_atomic(value));
+        Self {
+            values: values.into_boxed_slice(),
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Loads the value of the given node.
+    #[inline]
+    pub fn load<NI: Idx>(&self, node: NI, order: Ordering) -> T {
+        T::load(&self.values[node.index()], order)
+    }
+
+    /// Stores the value of the given node.
+    #[inline]
+    pub fn store<NI: Idx>(&self, node: NI, value: T, order: Ordering) {
+        T::store(&self.values[node.index()], value, order)
+    }
+
+    /// Replaces the value of the given node and returns the previous value.
+    #[inline]
+    pub fn swap<NI: Idx>(&self, node: NI, value: T, order: Ordering) -> T {
+        T::swap(&self.values[node.index()], value, order)
+    }
+
+    /// Stores `new` if the value of the given node equals `current`.
+    ///
+    /// Returns the previous value, wrapped in `Ok` if the value was updated
+    /// and in `Err` otherwise.
+    #[inline]
+    pub fn compare_exchange<NI: Idx>(
+        &self,
+        node: NI,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        T::compare_exchange(&self.values[node.index()], current, new, success, failure)
+    }
+
+    /// Adds to the value of the given node and returns the previous value.
+    #[inline]
+    pub fn fetch_add<NI: Idx>(&self, node: NI, value: T, order: Ordering) -> T {
+        T::fetch_add(&self.values[node.index()], value, order)
+    }
+
+    /// Sets the value of the given node to the minimum of the current and the
+    /// given value and returns the previous value.
+    #[inline]
+    pub fn fetch_min<NI: Idx>(&self, node: NI, value: T, order: Ordering) -> T {
+        T::fetch_min(&self.values[node.index()], value, order)
+    }
+
+    /// Sets the value of the given node to the maximum of the current and the
+    /// given value and returns the previous value.
+    #[inline]
+    pub fn fetch_max<NI: Idx>(&self, node: NI, value: T, order: Ordering) -> T {
+        T::fetch_max(&self.values[node.index()], value, order)
+    }
+
+    /// Returns a copy of all values.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.values
+            .iter()
+            .map(|value| T::load(value, Ordering::Relaxed))
+            .collect()
+    }
+
+    /// Consumes the array and returns the values.
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+            .into_vec()
+            .into_iter()
+            .map(T::into_inner)
+            .collect()
+    }
+}
+
+impl<T: AtomicValue> From<Vec<T>> for SharedNodeValues<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self {
+            values: values.into_iter().map(T::new_atomic).collect(),
+        }
+    }
+}
+
+impl<T: AtomicValue> FromIterator<T> for SharedNodeValues<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().map(T::new_atomic).collect(),
+        }
+    }
+}
+
+/// A value per edge of a graph, keyed by edge id.
+///
+/// The values are indexed by the ids of [`DirectedEdgeIds`] or
+/// [`UndirectedEdgeIds`]. Since undirected edges have one id per direction,
+/// both directions of an undirected edge can carry different values.
+///
+/// # Example
+///
+/// ```
+/// use graph_builder::prelude::*;
+///
+/// let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+///     .csr_layout(CsrLayout::Sorted)
+///     .edges(vec![(0, 1), (0, 2), (1, 2)])
+///     .build();
+///
+/// let mut flow = EdgeValues::new(graph.edge_id_count(), 0.0);
+/// flow[graph.out_edge_id(0, 2).unwrap()] = 4.2;
+///
+/// assert_eq!(
+///     flow.out_neighbors_with_values(&graph, 0).collect::<Vec<_>>(),
+///     vec![(1, &0.0), (2, &4.2)]
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeValues<T> {
+    values: Box<[T]>,
+}
+
+impl<T> EdgeValues<T> {
+    /// Creates a value array for `edge_id_count` edge ids with the given
+    /// initial value.
+    pub fn new(edge_id_count: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            values: vec![value; edge_id_count].into_boxed_slice(),
+        }
+    }
+
+    /// Returns the number of edge ids.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no edge ids.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the values of all edges in the order of their ids.
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the values of all edges in the order of their ids.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Returns the values of the outgoing edges of the given node.
+    pub fn out_values<NI, G>(&self, graph: &G, node: NI) -> &[T]
+    where
+        NI: Idx,
+        G: DirectedEdgeIds<NI>,
+    {
+        &self.values[graph.out_edge_ids(node)]
+    }
+
+    /// Returns the values of the edges of the given node.
+    pub fn values<NI, G>(&self, graph: &G, node: NI) -> &[T]
+    where
+        NI: Idx,
+        G: UndirectedEdgeIds<NI>,
+    {
+        &self.values[graph.edge_ids(node)]
+    }
+
+    /// Returns an iterator of all nodes which are connected in outgoing
+    /// direction to the given node, together with the value of the
+    /// connecting edge.
+    pub fn out_neighbors_with_values<'a, NI, G>(
+        &'a self,
+        graph: &'a G,
+        node: NI,
+    ) -> impl Iterator<Item = (NI, &'a T)> + 'a
+    where
+        NI: Idx,
+        G: DirectedEdgeIds<NI> + DirectedNeighbors<NI>,
+    {
+        graph
+            .out_neighbors(node)
+            .copied()
+            .zip(self.out_values(graph, node))
+    }
+
+    /// Returns an iterator of all nodes connected to the given node,
+    /// together with the value of the connecting edge.
+    pub fn neighbors_with_values<'a, NI, G>(
+        &'a self,
+        graph: &'a G,
+        node: NI,
+    ) -> impl Iterator<Item = (NI, &'a T)> + 'a
+    where
+        NI: Idx,
+        G: UndirectedEdgeIds<NI> + UndirectedNeighbors<NI>,
+    {
+        graph.neighbors(node).copied().zip(self.values(graph, node))
+    }
+
+    /// Consumes the array and returns the values.
+    pub fn into_vec(self) -> Vec<T> {
+        self.values.into_vec()
+    }
+}
+
+impl<T> Index<usize> for EdgeValues<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, edge_id: usize) -> &Self::Output {
+        &self.values[edge_id]
+    }
+}
+
+impl<T> IndexMut<usize> for EdgeValues<T> {
+    #[inline]
+    fn index_mut(&mut self, edge_id: usize) -> &mut Self::Output {
+        &mut self.values[edge_id]
+    }
+}
+
+impl<T> From<Vec<T>> for EdgeValues<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self {
+            values: values.into_boxed_slice(),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for EdgeValues<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering::{AcqRel, Acquire, Relaxed};
+
+    use rayon::prelude::*;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn parallel_fetch_add() {
+        let counts = SharedNodeValues::<u64>::new(4, 0);
+        let sums = SharedNodeValues::<f64>::new(4, 0.0);
+
+        (0..10_000_u32).into_par_iter().for_each(|i| {
+            counts.fetch_add(i % 4, 1, Relaxed);
+            sums.fetch_add(i % 4, 0.5, Relaxed);
+        });
+
+        assert_eq!(counts.into_vec(), vec![2500; 4]);
+        assert_eq!(sums.into_vec(), vec![1250.0; 4]);
+    }
+
+    #[test]
+    fn float_min_max() {
+        let values = SharedNodeValues::from(vec![f32::INFINITY, 0.0]);
+
+        assert_eq!(values.fetch_min(0_u32, 4.2, AcqRel), f32::INFINITY);
+        assert_eq!(values.fetch_min(0_u32, 13.37, AcqRel), 4.2);
+        assert_eq!(values.fetch_max(1_u32, -1.0, AcqRel), 0.0);
+        assert_eq!(values.to_vec(), vec![4.2, 0.0]);
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let values = (0..3).collect::<SharedNodeValues<i64>>();
+
+        assert_eq!(
+            values.compare_exchange(1_u32, 1, 42, AcqRel, Acquire),
+            Ok(1)
+        );
+        assert_eq!(
+            values.compare_exchange(2_u32, 1, 42, AcqRel, Acquire),
+            Err(2)
+        );
+        assert_eq!(values.load(1_u32, Acquire), 42);
+    }
+
+    #[test]
+    fn directed_edge_values() {
+        let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (0, 2), (1, 2), (2, 0), (2, 1)])
+            .build();
+
+        assert_eq!(graph.edge_id_count(), 5);
+        assert_eq!(graph.out_edge_ids(2), 3..5);
+        assert_eq!(graph.out_edge_id(2, 1), Some(4));
+        assert_eq!(graph.out_edge_id(1, 0), None);
+
+        // Store the source node per edge.
+        let mut sources = EdgeValues::new(graph.edge_id_count(), u32::MAX);
+        for node in 0..graph.node_count() {
+            for edge_id in graph.out_edge_ids(node) {
+                sources[edge_id] = node;
+            }
+        }
+
+        assert_eq!(sources.as_slice(), &[0, 0, 1, 2, 2]);
+        assert_eq!(sources.out_values(&graph, 2), &[2, 2]);
+        assert_eq!(
+            sources
+                .out_neighbors_with_values(&graph, 0)
+                .collect::<Vec<_>>(),
+            vec![(1, &0), (2, &0)]
+        );
+    }
+
+    #[test]
+    fn undirected_edge_values() {
+        let graph: UndirectedCsrGraph<u32> = GraphBuilder::new()
+            .csr_layout(CsrLayout::Sorted)
+            .edges(vec![(0, 1), (1, 2)])
+            .build();
+
+        assert_eq!(graph.edge_id_count(), 4);
+        assert_eq!(graph.edge_ids(1), 1..3);
+
+        let mut values = (0..graph.edge_id_count())
+            .map(|_| 0)
+            .collect::<EdgeValues<_>>();
+        values[graph.edge_id(1, 2).unwrap()] = 42;
+
+        assert_eq!(values[graph.edge_id(2, 1).unwrap()], 0);
+        assert_eq!(
+            values.neighbors_with_values(&graph, 1).collect::<Vec<_>>(),
+            vec![(0, &0), (2, &42)]
+        );
+        assert_eq!(values.into_vec(), vec![0, 0, 42, 0]);
+    }
+}
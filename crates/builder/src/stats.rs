@@ -0,0 +1,120 @@
+//! Summary statistics of graphs.
+//!
+//! Statistics are computed by [`GraphStatsOp::stats`](crate::graph_ops::GraphStatsOp)
+//! and describe the size of a graph, its degree distribution and the memory
+//! that is used to store its topology and node values.
+
+use rayon::prelude::*;
+
+/// Summary statistics of the degree distribution of a graph.
+///
+/// Percentiles are computed using the nearest-rank method, i.e., `p90` is the
+/// smallest degree such that at least 90% of all nodes have that degree or a
+/// smaller one.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DegreeStats {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+}
+
+impl DegreeStats {
+    /// Computes the statistics of the given degrees, one per node.
+    ///
+    /// Returns all zeros if `degrees` is empty.
+    pub fn from_degrees(mut degrees: Vec<u64>) -> Self {
+        if degrees.is_empty() {
+            return Self::default();
+        }
+
+        degrees.par_sort_unstable();
+
+        let percentile = |p: usize| {
+            let rank = (p * degrees.len() + 99) / 100;
+            degrees[rank.saturating_sub(1)]
+        };
+
+        Self {
+            min: degrees[0],
+            max: degrees[degrees.len() - 1],
+            mean: degrees.par_iter().sum::<u64>() as f64 / degrees.len() as f64,
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+        }
+    }
+}
+
+/// Summary statistics of a graph.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphStats {
+    pub node_count: u64,
+    pub edge_count: u64,
+    /// The number of edges divided by the number of edges in a complete
+    /// graph without self-loops of the same size.
+    pub density: f64,
+    /// The distribution of out-degrees for directed graphs and of degrees
+    /// for undirected graphs.
+    pub degrees: DegreeStats,
+    /// The distribution of in-degrees for directed graphs.
+    pub in_degrees: Option<DegreeStats>,
+    /// The number of bytes used by the neighbor lists and node values.
+    pub memory_bytes: usize,
+}
+
+impl GraphStats {
+    pub(crate) fn density(node_count: u64, edge_count: u64, directed: bool) -> f64 {
+        if node_count < 2 {
+            return 0.0;
+        }
+        let possible_edges = (node_count * (node_count - 1)) as f64;
+        if directed {
+            edge_count as f64 / possible_edges
+        } else {
+            2.0 * edge_count as f64 / possible_edges
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn degree_stats() {
+        let stats = DegreeStats::from_degrees((1..=100).rev().collect());
+
+        assert_eq!(stats.min, 1);
+        assert_eq!(stats.max, 100);
+        assert_eq!(stats.mean, 50.5);
+        assert_eq!(stats.p50, 50);
+        assert_eq!(stats.p90, 90);
+        assert_eq!(stats.p99, 99);
+    }
+
+    #[test]
+    fn degree_stats_single_node() {
+        let stats = DegreeStats::from_degrees(vec![3]);
+
+        assert_eq!(stats.min, 3);
+        assert_eq!(stats.p50, 3);
+        assert_eq!(stats.p99, 3);
+    }
+
+    #[test]
+    fn degree_stats_empty() {
+        assert_eq!(DegreeStats::from_degrees(vec![]), DegreeStats::default());
+    }
+
+    #[test]
+    fn density() {
+        assert_eq!(GraphStats::density(4, 6, true), 0.5);
+        assert_eq!(GraphStats::density(4, 6, false), 1.0);
+        assert_eq!(GraphStats::density(1, 1, true), 0.0);
+    }
+}
@@ -0,0 +1,36 @@
+//! Measures the durations that are logged while building graphs and running
+//! algorithms.
+//!
+//! [`std::time::Instant::now`] panics on `wasm32-unknown-unknown`, since the
+//! target has no clock. On that target, [`Instant`] is a stand-in that always
+//! reports a zero duration. On all other targets, it is a re-export of
+//! [`std::time::Instant`].
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use std::time::Instant;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub use self::wasm::Instant;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod wasm {
+    use std::time::Duration;
+
+    /// A point in time on a target without a clock.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Instant;
+
+    impl Instant {
+        pub fn now() -> Self {
+            Instant
+        }
+
+        pub fn elapsed(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        pub fn duration_since(&self, _earlier: Instant) -> Duration {
+            Duration::ZERO
+        }
+    }
+}
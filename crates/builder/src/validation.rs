@@ -0,0 +1,427 @@
+//! Integrity checks of graph topologies.
+//!
+//! Validation is performed by [`ValidateOp::validate`](crate::graph_ops::ValidateOp)
+//! and checks the invariants of the underlying CSR data structures, which is
+//! useful for graphs that were loaded from third-party binary snapshots or
+//! created from raw parts.
+
+use rayon::prelude::*;
+
+use crate::{graph::csr::CsrLayout, index::Idx, input::Direction, Target};
+
+/// A violated invariant of a CSR data structure.
+///
+/// The `direction` of a violation identifies the CSR in which it was found,
+/// i.e., the outgoing or incoming CSR of a directed graph or the single CSR
+/// of an undirected graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Violation {
+    /// The offsets do not contain `node_count + 1` entries.
+    OffsetCount {
+        direction: Direction,
+        expected: usize,
+        actual: usize,
+    },
+    /// The first offset is not `0`.
+    FirstOffset { direction: Direction, offset: u64 },
+    /// The offset of `node + 1` is smaller than the offset of `node`.
+    DecreasingOffset { direction: Direction, node: u64 },
+    /// The last offset is not equal to the number of targets.
+    LastOffset {
+        direction: Direction,
+        offset: u64,
+        target_count: usize,
+    },
+    /// A target is not smaller than the node count.
+    TargetOutOfBounds {
+        direction: Direction,
+        source: u64,
+        target: u64,
+    },
+    /// The neighbors of a node are not sorted, although the layout claims so.
+    UnsortedTargets { direction: Direction, node: u64 },
+    /// A target appears multiple times in a neighbor list of a deduplicated
+    /// layout.
+    DuplicateTarget {
+        direction: Direction,
+        source: u64,
+        target: u64,
+    },
+    /// A deduplicated layout contains a self-loop.
+    SelfLoop { direction: Direction, node: u64 },
+    /// The edge `(source, target)` has no matching reverse entry. For
+    /// directed graphs, the edge is stored in the CSR of the given
+    /// `direction`, but not in the other one. For undirected graphs, `source`
+    /// is missing in the neighbors of `target`.
+    MissingReverseEdge {
+        direction: Direction,
+        source: u64,
+        target: u64,
+    },
+    /// The number of node values differs from the node count.
+    NodeValueCount { expected: usize, actual: usize },
+}
+
+/// The result of validating a graph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationReport {
+    /// The number of checked edges, i.e., entries in all target arrays.
+    pub checked_edges: u64,
+    /// The total number of violations found.
+    pub violation_count: usize,
+    /// The first [`ValidationReport::MAX_VIOLATIONS`] violations.
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    /// The maximum number of violations that are kept in a report, so that
+    /// validating a badly corrupted graph does not exhaust memory.
+    pub const MAX_VIOLATIONS: usize = 100;
+
+    /// Returns `true`, if no invariant is violated.
+    pub fn is_valid(&self) -> bool {
+        self.violation_count == 0
+    }
+
+    pub(crate) fn extend<I: IntoIterator<Item = Violation>>(&mut self, violations: I) {
+        for violation in violations {
+            self.violation_count += 1;
+            if self.violations.len() < Self::MAX_VIOLATIONS {
+                self.violations.push(violation);
+            }
+        }
+    }
+}
+
+/// Checks the offsets and targets of a single CSR and returns `true`, if the
+/// neighbor lists can be accessed safely and only contain existing nodes.
+pub(crate) fn validate_csr<NI: Idx, EV: Sync>(
+    offsets: &[NI],
+    targets: &[Target<NI, EV>],
+    node_count: usize,
+    layout: CsrLayout,
+    direction: Direction,
+    report: &mut ValidationReport,
+) -> bool {
+    report.checked_edges += targets.len() as u64;
+
+    if offsets.len() != node_count + 1 {
+        report.extend([Violation::OffsetCount {
+            direction,
+            expected: node_count + 1,
+            actual: offsets.len(),
+        }]);
+        return false;
+    }
+    if offsets[0] != NI::zero() {
+        report.extend([Violation::FirstOffset {
+            direction,
+            offset: offsets[0].index() as u64,
+        }]);
+    }
+    let decreasing = (0..node_count)
+        .into_par_iter()
+        .filter(|&node| offsets[node] > offsets[node + 1])
+        .map(|node| Violation::DecreasingOffset {
+            direction,
+            node: node as u64,
+        })
+        .collect::<Vec<_>>();
+    let valid = offsets[0] == NI::zero() && decreasing.is_empty();
+    report.extend(decreasing);
+    if offsets[node_count].index() != targets.len() {
+        report.extend([Violation::LastOffset {
+            direction,
+            offset: offsets[node_count].index() as u64,
+            target_count: targets.len(),
+        }]);
+        return false;
+    }
+    if !valid {
+        return false;
+    }
+
+    let neighbors = |node: usize| &targets[offsets[node].index()..offsets[node + 1].index()];
+    let violations = (0..node_count)
+        .into_par_iter()
+        .flat_map_iter(|node| {
+            let neighbors = neighbors(node);
+            let mut violations = neighbors
+                .iter()
+                .filter(|t| t.target.index() >= node_count)
+                .map(|t| Violation::TargetOutOfBounds {
+                    direction,
+                    source: node as u64,
+                    target: t.target.index() as u64,
+                })
+                .collect::<Vec<_>>();
+
+            if !matches!(layout, CsrLayout::Unsorted)
+                && neighbors.windows(2).any(|w| w[0].target > w[1].target)
+            {
+                violations.push(Violation::UnsortedTargets {
+                    direction,
+                    node: node as u64,
+                });
+            }
+            if matches!(layout, CsrLayout::Deduplicated) {
+                violations.extend(
+                    neighbors
+                        .windows(2)
+                        .filter(|w| w[0].target == w[1].target)
+                        .map(|w| Violation::DuplicateTarget {
+                            direction,
+                            source: node as u64,
+                            target: w[0].target.index() as u64,
+                        }),
+                );
+                if neighbors.iter().any(|t| t.target.index() == node) {
+                    violations.push(Violation::SelfLoop {
+                        direction,
+                        node: node as u64,
+                    });
+                }
+            }
+
+            violations
+        })
+        .collect::<Vec<_>>();
+    let valid = violations
+        .iter()
+        .all(|violation| !matches!(violation, Violation::TargetOutOfBounds { .. }));
+    report.extend(violations);
+
+    valid
+}
+
+/// Returns the targets of a valid CSR with each neighbor list sorted.
+pub(crate) fn sorted_targets<NI: Idx, EV: Sync>(
+    offsets: &[NI],
+    targets: &[Target<NI, EV>],
+) -> Vec<NI> {
+    let mut sorted = targets.par_iter().map(|t| t.target).collect::<Vec<_>>();
+    let mut lists = Vec::with_capacity(offsets.len().saturating_sub(1));
+    let mut rest = sorted.as_mut_slice();
+    for w in offsets.windows(2) {
+        let (list, tail) = rest.split_at_mut(w[1].index() - w[0].index());
+        lists.push(list);
+        rest = tail;
+    }
+    lists.into_par_iter().for_each(|list| list.sort_unstable());
+    sorted
+}
+
+/// Checks that every edge in the `forward` CSR has a matching reverse entry
+/// in the `backward` CSR. Both are given as valid offsets and sorted targets.
+///
+/// An edge `(u, v)` that appears `k` times in the neighbors of `u` must
+/// appear at least `k` times as `u` in the `backward` neighbors of `v`. For
+/// undirected graphs, both CSRs are the same and self-loops are skipped.
+pub(crate) fn reverse_violations<NI: Idx>(
+    forward: (&[NI], &[NI]),
+    backward: (&[NI], &[NI]),
+    direction: Direction,
+) -> Vec<Violation> {
+    fn neighbors<'a, NI: Idx>((offsets, targets): (&[NI], &'a [NI]), node: usize) -> &'a [NI] {
+        &targets[offsets[node].index()..offsets[node + 1].index()]
+    }
+    let count = |list: &[NI], node: NI| {
+        list.partition_point(|&t| t <= node) - list.partition_point(|&t| t < node)
+    };
+    let skip_self_loops = matches!(direction, Direction::Undirected);
+
+    (0..forward.0.len() - 1)
+        .into_par_iter()
+        .flat_map_iter(|source| {
+            let list = neighbors(forward, source);
+            let source = NI::new(source);
+            list.iter()
+                .enumerate()
+                // Check each distinct target once, i.e., at its first entry.
+                .filter(move |&(i, &target)| {
+                    (i == 0 || list[i - 1] != target) && !(skip_self_loops && target == source)
+                })
+                .filter(move |&(_, &target)| {
+                    count(list, target) > count(neighbors(backward, target.index()), source)
+                })
+                .map(move |(_, &target)| {
+                    let (source, target) = match direction {
+                        Direction::Incoming => (target, source),
+                        _ => (source, target),
+                    };
+                    Violation::MissingReverseEdge {
+                        direction,
+                        source: source.index() as u64,
+                        target: target.index() as u64,
+                    }
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graph::csr::{Csr, NodeValues},
+        prelude::*,
+    };
+
+    fn csr(offsets: Vec<u32>, targets: Vec<u32>) -> Csr<u32, u32, ()> {
+        let values = vec![(); targets.len()];
+        Csr::new(
+            offsets.into_boxed_slice(),
+            targets
+                .into_iter()
+                .zip(values)
+                .map(|(t, v)| Target::new(t, v))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn valid_graphs() {
+        let edges = vec![(0, 1), (0, 2), (2, 1), (2, 2), (3, 0), (0, 1)];
+
+        for layout in [
+            CsrLayout::Unsorted,
+            CsrLayout::Sorted,
+            CsrLayout::Deduplicated,
+        ] {
+            let directed: DirectedCsrGraph<u32> = GraphBuilder::new()
+                .csr_layout(layout)
+                .edges(edges.clone())
+                .build();
+            let report = directed.validate(layout);
+            assert!(report.is_valid(), "{layout:?}: {report:?}");
+            assert_eq!(report.checked_edges, 2 * directed.edge_count() as u64);
+
+            let undirected: UndirectedCsrGraph<u32> = GraphBuilder::new()
+                .csr_layout(layout)
+                .edges(edges.clone())
+                .build();
+            let report = undirected.validate(layout);
+            assert!(report.is_valid(), "{layout:?}: {report:?}");
+        }
+    }
+
+    #[test]
+    fn invalid_offsets() {
+        let graph =
+            UndirectedCsrGraph::new(NodeValues::new(vec![(); 3]), csr(vec![1, 0, 2], vec![1, 0]));
+
+        let report = graph.validate(CsrLayout::Unsorted);
+
+        assert_eq!(
+            report.violations,
+            vec![
+                Violation::NodeValueCount {
+                    expected: 2,
+                    actual: 3
+                },
+                Violation::FirstOffset {
+                    direction: Direction::Undirected,
+                    offset: 1
+                },
+                Violation::DecreasingOffset {
+                    direction: Direction::Undirected,
+                    node: 0
+                },
+            ]
+        );
+        assert_eq!(report.violation_count, 3);
+    }
+
+    #[test]
+    fn invalid_targets() {
+        let graph = UndirectedCsrGraph::from(csr(vec![0, 3, 4, 5], vec![2, 1, 1, 0, 7]));
+
+        let report = graph.validate(CsrLayout::Sorted);
+
+        assert_eq!(
+            report.violations,
+            vec![
+                Violation::UnsortedTargets {
+                    direction: Direction::Undirected,
+                    node: 0
+                },
+                Violation::TargetOutOfBounds {
+                    direction: Direction::Undirected,
+                    source: 2,
+                    target: 7
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicates_and_self_loops() {
+        let graph = UndirectedCsrGraph::from(csr(vec![0, 3, 5], vec![0, 1, 1, 0, 0]));
+
+        let report = graph.validate(CsrLayout::Deduplicated);
+        assert_eq!(
+            report.violations,
+            vec![
+                Violation::DuplicateTarget {
+                    direction: Direction::Undirected,
+                    source: 0,
+                    target: 1
+                },
+                Violation::SelfLoop {
+                    direction: Direction::Undirected,
+                    node: 0
+                },
+                Violation::DuplicateTarget {
+                    direction: Direction::Undirected,
+                    source: 1,
+                    target: 0
+                },
+            ]
+        );
+
+        assert!(graph.validate(CsrLayout::Sorted).is_valid());
+    }
+
+    #[test]
+    fn mismatched_directions() {
+        // Outgoing: 0 -> 1, 0 -> 2, incoming: 1 <- 0, 1 <- 2
+        let graph: DirectedCsrGraph<u32> = DirectedCsrGraph::new(
+            NodeValues::new(vec![(); 3]),
+            csr(vec![0, 2, 2, 2], vec![1, 2]),
+            csr(vec![0, 0, 2, 2], vec![0, 2]),
+        );
+
+        let report = graph.validate(CsrLayout::Sorted);
+
+        assert_eq!(
+            report.violations,
+            vec![
+                Violation::MissingReverseEdge {
+                    direction: Direction::Outgoing,
+                    source: 0,
+                    target: 2
+                },
+                Violation::MissingReverseEdge {
+                    direction: Direction::Incoming,
+                    source: 2,
+                    target: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn report_is_truncated() {
+        let node_count = 2 * ValidationReport::MAX_VIOLATIONS as u32;
+        let offsets = (0..=node_count).collect::<Vec<_>>();
+        let targets = (0..node_count).map(|n| (n + 1) % node_count).collect();
+        let graph = UndirectedCsrGraph::from(csr(offsets, targets));
+
+        let report = graph.validate(CsrLayout::Sorted);
+
+        assert_eq!(report.violation_count, node_count as usize);
+        assert_eq!(report.violations.len(), ValidationReport::MAX_VIOLATIONS);
+    }
+}
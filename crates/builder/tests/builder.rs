@@ -110,6 +110,35 @@ fn directed_usize_graph_from_edge_list_with_values_and_node_values() {
     assert_eq!(*g.node_value(4), 2);
 }
 
+#[test]
+fn graph_from_edges_with_node_count() {
+    let graph: DirectedCsrGraph<u32, (), f32> = GraphBuilder::new()
+        .edges_with_values(vec![(0, 1, 0.5), (1, 2, 1.5)])
+        .node_count(5)
+        .build();
+
+    assert_eq!(graph.node_count(), 5);
+    assert_eq!(graph.in_degree(4), 0);
+
+    let graph: UndirectedCsrGraph<u32, u32> = GraphBuilder::new()
+        .edges(vec![(0, 1), (1, 2)])
+        .node_count(4)
+        .node_values(vec![0, 1, 2, 3])
+        .build();
+
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.node_value(3), &3);
+}
+
+#[test]
+#[should_panic(expected = "node count 2 must be larger than the largest node id 2")]
+fn graph_from_edges_with_too_small_node_count() {
+    let _: DirectedCsrGraph<u32> = GraphBuilder::new()
+        .edges(vec![(0, 1), (1, 2)])
+        .node_count(2)
+        .build();
+}
+
 #[test]
 fn directed_u32_graph_from_edge_list() {
     assert_directed_graph::<u32, ()>(
@@ -120,6 +149,40 @@ fn directed_u32_graph_from_edge_list() {
     );
 }
 
+#[test]
+fn directed_u32_graph_from_edge_list_with_csr_strategy() {
+    for csr_strategy in [
+        CsrStrategy::CountingSort,
+        CsrStrategy::SortBased(SortAlgorithm::Comparison),
+        CsrStrategy::SortBased(SortAlgorithm::Radix),
+    ] {
+        assert_directed_graph::<u32, ()>(
+            GraphBuilder::new()
+                .csr_layout(CsrLayout::Sorted)
+                .edges([(0, 1), (0, 2), (1, 2), (1, 3), (2, 4), (3, 4)])
+                .csr_strategy(csr_strategy)
+                .build(),
+        );
+    }
+}
+
+#[test]
+fn undirected_u32_graph_from_edge_list_file_with_csr_strategy() {
+    let path = [env!("CARGO_MANIFEST_DIR"), "resources", "test.el"]
+        .iter()
+        .collect::<PathBuf>();
+
+    let graph = GraphBuilder::new()
+        .csr_layout(CsrLayout::Sorted)
+        .file_format(EdgeListInput::default())
+        .path(path)
+        .csr_strategy(CsrStrategy::SortBased(SortAlgorithm::Radix))
+        .build()
+        .expect("loading failed");
+
+    assert_undirected_graph::<u32, ()>(graph);
+}
+
 #[test]
 fn directed_usize_graph_from_edge_list_with_values() {
     let graph: DirectedCsrGraph<usize, (), f32> = GraphBuilder::new()
@@ -367,6 +430,134 @@ fn directed_u32_graph_from_edge_list_file() {
     assert_directed_graph::<u32, ()>(graph);
 }
 
+#[test]
+fn directed_u32_graph_from_edge_list_shards() {
+    let path = [env!("CARGO_MANIFEST_DIR"), "resources", "test_shards"]
+        .iter()
+        .collect::<PathBuf>();
+
+    let graph = GraphBuilder::new()
+        .csr_layout(CsrLayout::Sorted)
+        .file_format(EdgeListInput::default())
+        .path(path)
+        .build()
+        .expect("loading failed");
+
+    assert_directed_graph::<u32, ()>(graph);
+}
+
+#[test]
+fn directed_graph_from_edge_list_file_and_node_file() {
+    let resources = [env!("CARGO_MANIFEST_DIR"), "resources"]
+        .iter()
+        .collect::<PathBuf>();
+
+    let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+        .file_format(EdgeListInput::default())
+        .path(resources.join("example.el"))
+        .node_path(resources.join("example.nodes"))
+        .build()
+        .expect("loading failed");
+
+    assert_eq!(graph.node_count(), 7);
+    assert_eq!(graph.edge_count(), 5);
+    assert_eq!(graph.out_degree(6), 0);
+
+    let graph: DirectedCsrGraph<u32> = GraphBuilder::new()
+        .file_format(EdgeListInput::default())
+        .path(resources.join("example.el"))
+        .node_path(resources.join("example.nodes"))
+        .node_count(10)
+        .build()
+        .expect("loading failed");
+
+    assert_eq!(graph.node_count(), 10);
+}
+
+#[test]
+fn edge_list_file_with_too_small_node_count() {
+    let path = [env!("CARGO_MANIFEST_DIR"), "resources", "example.el"]
+        .iter()
+        .collect::<PathBuf>();
+
+    let result: Result<DirectedCsrGraph<u32>, _> = GraphBuilder::new()
+        .file_format(EdgeListInput::default())
+        .path(path)
+        .node_count(3)
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(Error::InvalidNodeCount {
+            node_count: 3,
+            max_node_id: 3
+        })
+    ));
+}
+
+#[test]
+fn directed_graph_from_edge_list_file_and_node_values_file() {
+    let path = [env!("CARGO_MANIFEST_DIR"), "resources", "example.el"]
+        .iter()
+        .collect::<PathBuf>();
+    let node_values_path = [
+        env!("CARGO_MANIFEST_DIR"),
+        "resources",
+        "example.node_values.csv",
+    ]
+    .iter()
+    .collect::<PathBuf>();
+
+    let graph: DirectedCsrGraph<u32, f32> = GraphBuilder::new()
+        .file_format(EdgeListInput::default())
+        .path(path)
+        .node_values_file(
+            CsvNodeInput::default().header(true).value("rank"),
+            node_values_path,
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(graph.node_count(), 6);
+    assert_eq!(graph.out_degree(5), 0);
+    assert_eq!(graph.node_value(0), &0.5);
+    assert_eq!(graph.node_value(1), &0.0);
+    assert_eq!(graph.node_value(3), &1.5);
+    assert_eq!(graph.node_value(5), &2.5);
+}
+
+#[test]
+fn node_values_file_with_too_small_node_count() {
+    let path = [env!("CARGO_MANIFEST_DIR"), "resources", "example.el"]
+        .iter()
+        .collect::<PathBuf>();
+    let node_values_path = [
+        env!("CARGO_MANIFEST_DIR"),
+        "resources",
+        "example.node_values.csv",
+    ]
+    .iter()
+    .collect::<PathBuf>();
+
+    let result: Result<DirectedCsrGraph<u32, f32>, _> = GraphBuilder::new()
+        .file_format(EdgeListInput::default())
+        .path(path)
+        .node_count(5)
+        .node_values_file(
+            CsvNodeInput::default().header(true).value("rank"),
+            node_values_path,
+        )
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(Error::InvalidNodeCount {
+            node_count: 5,
+            max_node_id: 5
+        })
+    ));
+}
+
 #[test]
 #[cfg(feature = "dotgraph")]
 fn directed_u32_graph_from_dot_graph_file() {
@@ -468,6 +659,45 @@ fn directed_u64_graph_from_graph_500_file() {
     );
 }
 
+#[test]
+fn directed_u64_graph_from_mapped_graph_500_file() {
+    let path = [env!("CARGO_MANIFEST_DIR"), "resources", "scale_8.graph500"]
+        .iter()
+        .collect::<PathBuf>();
+
+    let graph: DirectedCsrGraph<u64> = GraphBuilder::new()
+        .csr_layout(CsrLayout::Sorted)
+        .file_format(Graph500MmapInput::default())
+        .path(path)
+        .build()
+        .expect("loading failed");
+
+    assert_eq!(graph.node_count(), 256);
+    assert_eq!(graph.edge_count(), 4096);
+
+    assert_eq!(graph.out_neighbors(0).as_slice(), &[37, 157]);
+    assert_eq!(
+        graph.in_neighbors(0).as_slice(),
+        &[12, 26, 50, 50, 52, 82, 82, 82, 106, 109, 172, 186, 250, 250]
+    );
+}
+
+#[test]
+fn mapped_graph_500_file_with_big_endian_ids() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    // 16 edges (0, 1), written with big-endian ids
+    for _ in 0..16 {
+        std::io::Write::write_all(&mut file, &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0]).unwrap();
+    }
+
+    let result: Result<DirectedCsrGraph<u64>, _> = GraphBuilder::new()
+        .file_format(Graph500MmapInput::default())
+        .path(file.path())
+        .build();
+
+    assert!(matches!(result, Err(Error::InvalidRecord { .. })));
+}
+
 #[test]
 fn undirected_u64_graph_from_graph_500_file() {
     let path = [env!("CARGO_MANIFEST_DIR"), "resources", "scale_8.graph500"]
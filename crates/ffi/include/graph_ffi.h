@@ -0,0 +1,126 @@
+/*
+ * C API of the graph crate.
+ *
+ * Graphs are opaque handles that are created by graph_create or graph_load
+ * and released by graph_free. Node ids are uint32_t. Functions that can fail
+ * return a graph_status; graph_last_error returns the message of the last
+ * failed call on the calling thread.
+ *
+ * A graph may be read from multiple threads concurrently, as long as no
+ * thread calls graph_set_node_values or graph_free at the same time.
+ */
+
+#ifndef GRAPH_FFI_H
+#define GRAPH_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum graph_status {
+    GRAPH_OK = 0,
+    GRAPH_ERROR_NULL_POINTER = 1,
+    GRAPH_ERROR_INVALID_ARGUMENT = 2,
+    GRAPH_ERROR_IO = 3,
+    GRAPH_ERROR_UNSUPPORTED = 4,
+    /* The graph passed to the failed call must only be released. */
+    GRAPH_ERROR_PANIC = 5,
+} graph_status;
+
+/* Neighbor lists are sorted and may contain duplicate targets. */
+#define GRAPH_LAYOUT_SORTED 0u
+/* Neighbor lists are not in any particular order. */
+#define GRAPH_LAYOUT_UNSORTED 1u
+/* Neighbor lists are sorted without duplicate targets and self-loops. */
+#define GRAPH_LAYOUT_DEDUPLICATED 2u
+
+/* A text file with one "source target" pair per line. */
+#define GRAPH_FORMAT_EDGE_LIST 0u
+/* A file in the binary layout that is written by the graph crate. */
+#define GRAPH_FORMAT_BINARY 1u
+
+typedef struct GraphHandle graph_t;
+
+typedef struct graph_page_rank_config {
+    size_t max_iterations;
+    double tolerance;
+    float damping_factor;
+} graph_page_rank_config;
+
+/* Returns the message of the last failed call on this thread, or NULL. */
+const char *graph_last_error(void);
+
+/*
+ * Creates a graph from the edges (sources[i], targets[i]). If node_count is
+ * 0, it is derived from the largest node id of the edges.
+ */
+graph_status graph_create(const uint32_t *sources, const uint32_t *targets,
+                          size_t edge_count, size_t node_count, bool directed,
+                          uint32_t layout, graph_t **graph);
+
+/* Loads a graph from a file. layout is ignored for binary files. */
+graph_status graph_load(const char *path, uint32_t format, bool directed,
+                        uint32_t layout, graph_t **graph);
+
+/* Releases a graph. Passing NULL is a no-op. */
+void graph_free(graph_t *graph);
+
+bool graph_is_directed(const graph_t *graph);
+
+size_t graph_node_count(const graph_t *graph);
+
+size_t graph_edge_count(const graph_t *graph);
+
+/* For undirected graphs, both degrees are the number of edges of the node. */
+graph_status graph_out_degree(const graph_t *graph, uint32_t node,
+                              size_t *degree);
+
+graph_status graph_in_degree(const graph_t *graph, uint32_t node,
+                             size_t *degree);
+
+/*
+ * The neighbors are owned by the graph and remain valid until it is
+ * released. For undirected graphs, both functions return all neighbors.
+ */
+graph_status graph_out_neighbors(const graph_t *graph, uint32_t node,
+                                 const uint32_t **neighbors, size_t *len);
+
+graph_status graph_in_neighbors(const graph_t *graph, uint32_t node,
+                                const uint32_t **neighbors, size_t *len);
+
+/* Replaces the float property of every node. len must be the node count. */
+graph_status graph_set_node_values(graph_t *graph, const float *values,
+                                   size_t len);
+
+graph_status graph_node_value(const graph_t *graph, uint32_t node,
+                              float *value);
+
+graph_page_rank_config graph_page_rank_config_default(void);
+
+/*
+ * Computes PageRank for a directed graph. config may be NULL for the default
+ * parameters, iterations may be NULL. len must be the node count.
+ */
+graph_status graph_page_rank(const graph_t *graph,
+                             const graph_page_rank_config *config,
+                             float *scores, size_t len, size_t *iterations);
+
+/* Writes the weakly connected component per node. len must be the node count. */
+graph_status graph_wcc(const graph_t *graph, uint32_t *components, size_t len);
+
+/*
+ * Counts triangles. Directed graphs are counted without direction, parallel
+ * edges and self-loops, undirected graphs need GRAPH_LAYOUT_DEDUPLICATED for
+ * exact counts.
+ */
+graph_status graph_triangle_count(const graph_t *graph, uint64_t *triangles);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GRAPH_FFI_H */
@@ -0,0 +1,117 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    ffi::{c_char, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+use graph::prelude::Error;
+
+/// The result of a call that can fail.
+///
+/// On failure, a message describing the error is available via
+/// [`graph_last_error`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphStatus {
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// An argument was out of range, e.g., a node id or a buffer length.
+    InvalidArgument = 2,
+    /// A file could not be read or parsed.
+    Io = 3,
+    /// The operation is not supported for the kind of graph, e.g., PageRank
+    /// on an undirected graph.
+    Unsupported = 4,
+    /// The call panicked. The graph that was passed to the call must not be
+    /// used anymore, except for releasing it.
+    Panic = 5,
+}
+
+pub(crate) struct FfiError {
+    status: GraphStatus,
+    message: String,
+}
+
+impl FfiError {
+    pub(crate) fn new(status: GraphStatus, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn null_pointer(argument: &str) -> Self {
+        Self::new(
+            GraphStatus::NullPointer,
+            format!("{argument} must not be null"),
+        )
+    }
+
+    pub(crate) fn invalid_argument(message: impl Into<String>) -> Self {
+        Self::new(GraphStatus::InvalidArgument, message)
+    }
+}
+
+impl From<Error> for FfiError {
+    fn from(error: Error) -> Self {
+        let status = match error {
+            Error::IoError { .. } => GraphStatus::Io,
+            _ => GraphStatus::InvalidArgument,
+        };
+        Self::new(status, error.to_string())
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Runs the body of an exported function, records its error, if any, and
+/// turns panics into [`GraphStatus::Panic`], since unwinding into C is
+/// undefined behavior.
+pub(crate) fn run<F>(f: F) -> GraphStatus
+where
+    F: FnOnce() -> Result<(), FfiError>,
+{
+    let result = catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(FfiError::new(GraphStatus::Panic, panic_message(payload))));
+
+    match result {
+        Ok(()) => GraphStatus::Ok,
+        Err(error) => {
+            // Interior NUL bytes would truncate the message in C, so they are
+            // dropped instead.
+            let message = CString::new(error.message.replace('\0', ""))
+                .expect("message does not contain NUL bytes");
+            LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+            error.status
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => String::from("unknown panic"),
+        },
+    }
+}
+
+/// Returns the message of the last failed call on the calling thread, or
+/// null if no call has failed yet.
+///
+/// The message is owned by the library and remains valid until the next
+/// failed call on the same thread.
+#[no_mangle]
+pub extern "C" fn graph_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
@@ -0,0 +1,754 @@
+//! A C API for the graph crate, which allows C, C++ or Go services to embed
+//! the graph algorithms without going through the Arrow Flight server.
+//!
+//! Graphs are passed across the boundary as opaque [`GraphHandle`] pointers,
+//! which are created by [`graph_create`] or [`graph_load`] and released by
+//! [`graph_free`]. Node ids are `uint32_t`. Each graph carries one `float`
+//! property per node, which defaults to `0`.
+//!
+//! Functions that can fail return a [`GraphStatus`]. The message of the last
+//! failed call on the calling thread is available via [`graph_last_error`].
+//! Results are written into buffers that are owned by the caller.
+//!
+//! The C declarations are in `include/graph_ffi.h`.
+
+use std::{
+    ffi::{c_char, CStr},
+    path::PathBuf,
+    slice,
+};
+
+use graph::prelude::*;
+
+mod error;
+
+pub use error::{graph_last_error, GraphStatus};
+
+use error::{run, FfiError};
+
+/// Neighbor lists are sorted and may contain duplicate targets.
+pub const GRAPH_LAYOUT_SORTED: u32 = 0;
+/// Neighbor lists are not in any particular order.
+pub const GRAPH_LAYOUT_UNSORTED: u32 = 1;
+/// Neighbor lists are sorted and neither contain duplicate targets nor
+/// self-loops.
+pub const GRAPH_LAYOUT_DEDUPLICATED: u32 = 2;
+
+/// A text file with one `source target` pair per line.
+pub const GRAPH_FORMAT_EDGE_LIST: u32 = 0;
+/// A file in the binary layout that is written by the graph crate.
+pub const GRAPH_FORMAT_BINARY: u32 = 1;
+
+/// An opaque graph.
+pub struct GraphHandle {
+    topology: Topology,
+    node_values: Vec<f32>,
+}
+
+enum Topology {
+    Directed(DirectedCsrGraph<u32>),
+    Undirected(UndirectedCsrGraph<u32>),
+}
+
+impl GraphHandle {
+    fn new(topology: Topology) -> *mut GraphHandle {
+        let node_count = match &topology {
+            Topology::Directed(graph) => graph.node_count(),
+            Topology::Undirected(graph) => graph.node_count(),
+        };
+        let handle = GraphHandle {
+            topology,
+            node_values: vec![0.0; node_count as usize],
+        };
+        Box::into_raw(Box::new(handle))
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_values.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        match &self.topology {
+            Topology::Directed(graph) => graph.edge_count() as usize,
+            Topology::Undirected(graph) => graph.edge_count() as usize,
+        }
+    }
+
+    fn check_node(&self, node: u32) -> Result<(), FfiError> {
+        if node as usize >= self.node_count() {
+            return Err(FfiError::invalid_argument(format!(
+                "node {node} does not exist in a graph with {} nodes",
+                self.node_count()
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_len(&self, argument: &str, len: usize) -> Result<(), FfiError> {
+        if len != self.node_count() {
+            return Err(FfiError::invalid_argument(format!(
+                "{argument} must have one entry per node ({}), got {len}",
+                self.node_count()
+            )));
+        }
+        Ok(())
+    }
+
+    fn directed(&self, operation: &str) -> Result<&DirectedCsrGraph<u32>, FfiError> {
+        match &self.topology {
+            Topology::Directed(graph) => Ok(graph),
+            Topology::Undirected(_) => Err(FfiError::new(
+                GraphStatus::Unsupported,
+                format!("{operation} requires a directed graph"),
+            )),
+        }
+    }
+}
+
+fn csr_layout(layout: u32) -> Result<CsrLayout, FfiError> {
+    match layout {
+        GRAPH_LAYOUT_SORTED => Ok(CsrLayout::Sorted),
+        GRAPH_LAYOUT_UNSORTED => Ok(CsrLayout::Unsorted),
+        GRAPH_LAYOUT_DEDUPLICATED => Ok(CsrLayout::Deduplicated),
+        _ => Err(FfiError::invalid_argument(format!(
+            "unknown layout {layout}"
+        ))),
+    }
+}
+
+unsafe fn handle<'a>(graph: *const GraphHandle) -> Result<&'a GraphHandle, FfiError> {
+    graph
+        .as_ref()
+        .ok_or_else(|| FfiError::null_pointer("graph"))
+}
+
+unsafe fn input<'a, T>(data: *const T, len: usize, argument: &str) -> Result<&'a [T], FfiError> {
+    match (data.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(FfiError::null_pointer(argument)),
+        (false, _) => Ok(slice::from_raw_parts(data, len)),
+    }
+}
+
+unsafe fn output<'a, T>(data: *mut T, len: usize, argument: &str) -> Result<&'a mut [T], FfiError> {
+    match (data.is_null(), len) {
+        (_, 0) => Ok(&mut []),
+        (true, _) => Err(FfiError::null_pointer(argument)),
+        (false, _) => Ok(slice::from_raw_parts_mut(data, len)),
+    }
+}
+
+unsafe fn write<T>(target: *mut T, value: T, argument: &str) -> Result<(), FfiError> {
+    match target.as_mut() {
+        Some(target) => {
+            *target = value;
+            Ok(())
+        }
+        None => Err(FfiError::null_pointer(argument)),
+    }
+}
+
+/// Creates a graph from the edges `(sources[i], targets[i])`.
+///
+/// If `node_count` is `0`, the graph contains the nodes up to the largest
+/// node id of the edges. Otherwise, it must be larger than every node id
+/// and nodes without edges are isolated nodes of the graph.
+///
+/// # Safety
+///
+/// `sources` and `targets` must point to `edge_count` node ids each and
+/// `graph` must point to writable memory for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn graph_create(
+    sources: *const u32,
+    targets: *const u32,
+    edge_count: usize,
+    node_count: usize,
+    directed: bool,
+    layout: u32,
+    graph: *mut *mut GraphHandle,
+) -> GraphStatus {
+    run(|| {
+        let sources = input(sources, edge_count, "sources")?;
+        let targets = input(targets, edge_count, "targets")?;
+        let layout = csr_layout(layout)?;
+        if graph.is_null() {
+            return Err(FfiError::null_pointer("graph"));
+        }
+
+        let min_node_count = sources
+            .iter()
+            .chain(targets)
+            .max()
+            .map_or(0, |max_node_id| *max_node_id as usize + 1);
+        if node_count != 0 && node_count < min_node_count {
+            return Err(FfiError::invalid_argument(format!(
+                "node count {node_count} must be larger than the largest node id {}",
+                min_node_count - 1
+            )));
+        }
+        let node_count = node_count.max(min_node_count).max(1);
+
+        let edges = sources.iter().copied().zip(targets.iter().copied());
+        let builder = GraphBuilder::new()
+            .csr_layout(layout)
+            .edges(edges)
+            .node_count(node_count);
+        let topology = if directed {
+            Topology::Directed(builder.build())
+        } else {
+            Topology::Undirected(builder.build())
+        };
+
+        write(graph, GraphHandle::new(topology), "graph")
+    })
+}
+
+/// Loads a graph from the file at the given path, which is either in
+/// [`GRAPH_FORMAT_EDGE_LIST`] or [`GRAPH_FORMAT_BINARY`].
+///
+/// Binary files contain the layout of the written graph, so `layout` is
+/// ignored for them, and `directed` must match the written graph.
+///
+/// # Safety
+///
+/// `path` must be a NUL-terminated string and `graph` must point to
+/// writable memory for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn graph_load(
+    path: *const c_char,
+    format: u32,
+    directed: bool,
+    layout: u32,
+    graph: *mut *mut GraphHandle,
+) -> GraphStatus {
+    run(|| {
+        if path.is_null() {
+            return Err(FfiError::null_pointer("path"));
+        }
+        let path = CStr::from_ptr(path)
+            .to_str()
+            .map(PathBuf::from)
+            .map_err(|e| FfiError::invalid_argument(format!("path is not valid UTF-8: {e}")))?;
+        let layout = csr_layout(layout)?;
+        if graph.is_null() {
+            return Err(FfiError::null_pointer("graph"));
+        }
+
+        let topology = match (format, directed) {
+            (GRAPH_FORMAT_EDGE_LIST, true) => Topology::Directed(
+                GraphBuilder::new()
+                    .csr_layout(layout)
+                    .file_format(EdgeListInput::default())
+                    .path(path)
+                    .build()?,
+            ),
+            (GRAPH_FORMAT_EDGE_LIST, false) => Topology::Undirected(
+                GraphBuilder::new()
+                    .csr_layout(layout)
+                    .file_format(EdgeListInput::default())
+                    .path(path)
+                    .build()?,
+            ),
+            (GRAPH_FORMAT_BINARY, true) => Topology::Directed(
+                GraphBuilder::new()
+                    .file_format(BinaryInput::<u32>::default())
+                    .path(path)
+                    .build()?,
+            ),
+            (GRAPH_FORMAT_BINARY, false) => Topology::Undirected(
+                GraphBuilder::new()
+                    .file_format(BinaryInput::<u32>::default())
+                    .path(path)
+                    .build()?,
+            ),
+            _ => {
+                return Err(FfiError::invalid_argument(format!(
+                    "unknown format {format}"
+                )))
+            }
+        };
+
+        write(graph, GraphHandle::new(topology), "graph")
+    })
+}
+
+/// Releases a graph. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `graph` must have been created by this library and must not be used
+/// after this call.
+#[no_mangle]
+pub unsafe extern "C" fn graph_free(graph: *mut GraphHandle) {
+    if !graph.is_null() {
+        drop(Box::from_raw(graph));
+    }
+}
+
+/// Returns whether the graph is directed, or `false` if `graph` is null.
+///
+/// # Safety
+///
+/// `graph` must be null or a valid graph.
+#[no_mangle]
+pub unsafe extern "C" fn graph_is_directed(graph: *const GraphHandle) -> bool {
+    matches!(
+        graph.as_ref(),
+        Some(GraphHandle {
+            topology: Topology::Directed(_),
+            ..
+        })
+    )
+}
+
+/// Returns the number of nodes, or `0` if `graph` is null.
+///
+/// # Safety
+///
+/// `graph` must be null or a valid graph.
+#[no_mangle]
+pub unsafe extern "C" fn graph_node_count(graph: *const GraphHandle) -> usize {
+    graph.as_ref().map_or(0, GraphHandle::node_count)
+}
+
+/// Returns the number of edges, or `0` if `graph` is null.
+///
+/// # Safety
+///
+/// `graph` must be null or a valid graph.
+#[no_mangle]
+pub unsafe extern "C" fn graph_edge_count(graph: *const GraphHandle) -> usize {
+    graph.as_ref().map_or(0, GraphHandle::edge_count)
+}
+
+/// Writes the number of outgoing edges of `node`, or of all its edges for
+/// undirected graphs, to `degree`.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph and `degree` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn graph_out_degree(
+    graph: *const GraphHandle,
+    node: u32,
+    degree: *mut usize,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        graph.check_node(node)?;
+        let value = match &graph.topology {
+            Topology::Directed(g) => g.out_degree(node),
+            Topology::Undirected(g) => g.degree(node),
+        };
+        write(degree, value as usize, "degree")
+    })
+}
+
+/// Writes the number of incoming edges of `node`, or of all its edges for
+/// undirected graphs, to `degree`.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph and `degree` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn graph_in_degree(
+    graph: *const GraphHandle,
+    node: u32,
+    degree: *mut usize,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        graph.check_node(node)?;
+        let value = match &graph.topology {
+            Topology::Directed(g) => g.in_degree(node),
+            Topology::Undirected(g) => g.degree(node),
+        };
+        write(degree, value as usize, "degree")
+    })
+}
+
+/// Points `neighbors` to the outgoing neighbors of `node`, or to all its
+/// neighbors for undirected graphs, and writes their number to `len`.
+///
+/// The neighbors are owned by the graph and remain valid until it is
+/// released.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph, `neighbors` and `len` must point to
+/// writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn graph_out_neighbors(
+    graph: *const GraphHandle,
+    node: u32,
+    neighbors: *mut *const u32,
+    len: *mut usize,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        graph.check_node(node)?;
+        let targets = match &graph.topology {
+            Topology::Directed(g) => g.out_neighbors(node).as_slice(),
+            Topology::Undirected(g) => g.neighbors(node).as_slice(),
+        };
+        write(neighbors, targets.as_ptr(), "neighbors")?;
+        write(len, targets.len(), "len")
+    })
+}
+
+/// Points `neighbors` to the incoming neighbors of `node`, or to all its
+/// neighbors for undirected graphs, and writes their number to `len`.
+///
+/// The neighbors are owned by the graph and remain valid until it is
+/// released.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph, `neighbors` and `len` must point to
+/// writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn graph_in_neighbors(
+    graph: *const GraphHandle,
+    node: u32,
+    neighbors: *mut *const u32,
+    len: *mut usize,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        graph.check_node(node)?;
+        let targets = match &graph.topology {
+            Topology::Directed(g) => g.in_neighbors(node).as_slice(),
+            Topology::Undirected(g) => g.neighbors(node).as_slice(),
+        };
+        write(neighbors, targets.as_ptr(), "neighbors")?;
+        write(len, targets.len(), "len")
+    })
+}
+
+/// Replaces the node property of every node. `len` must be the node count.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph that is not used concurrently and `values`
+/// must point to `len` values.
+#[no_mangle]
+pub unsafe extern "C" fn graph_set_node_values(
+    graph: *mut GraphHandle,
+    values: *const f32,
+    len: usize,
+) -> GraphStatus {
+    run(|| {
+        let graph = graph
+            .as_mut()
+            .ok_or_else(|| FfiError::null_pointer("graph"))?;
+        graph.check_len("values", len)?;
+        let values = input(values, len, "values")?;
+        graph.node_values.copy_from_slice(values);
+        Ok(())
+    })
+}
+
+/// Writes the node property of `node` to `value`.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph and `value` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn graph_node_value(
+    graph: *const GraphHandle,
+    node: u32,
+    value: *mut f32,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        graph.check_node(node)?;
+        write(value, graph.node_values[node as usize], "value")
+    })
+}
+
+/// The parameters of [`graph_page_rank`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct GraphPageRankConfig {
+    pub max_iterations: usize,
+    pub tolerance: f64,
+    pub damping_factor: f32,
+}
+
+impl From<GraphPageRankConfig> for PageRankConfig {
+    fn from(config: GraphPageRankConfig) -> Self {
+        PageRankConfig::new(
+            config.max_iterations,
+            config.tolerance,
+            config.damping_factor,
+        )
+    }
+}
+
+/// Returns the default PageRank parameters.
+#[no_mangle]
+pub extern "C" fn graph_page_rank_config_default() -> GraphPageRankConfig {
+    GraphPageRankConfig {
+        max_iterations: PageRankConfig::DEFAULT_MAX_ITERATIONS,
+        tolerance: PageRankConfig::DEFAULT_TOLERANCE,
+        damping_factor: PageRankConfig::DEFAULT_DAMPING_FACTOR,
+    }
+}
+
+/// Computes the PageRank of every node of a directed graph and writes it to
+/// `scores`, which must have one entry per node. If `config` is null, the
+/// default parameters are used. If `iterations` is not null, the number of
+/// executed iterations is written to it.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph, `config` must be null or valid, `scores`
+/// must point to `len` writable values and `iterations` must be null or
+/// point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn graph_page_rank(
+    graph: *const GraphHandle,
+    config: *const GraphPageRankConfig,
+    scores: *mut f32,
+    len: usize,
+    iterations: *mut usize,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        let directed = graph.directed("PageRank")?;
+        graph.check_len("scores", len)?;
+        let scores = output(scores, len, "scores")?;
+        let config = config
+            .as_ref()
+            .map_or_else(PageRankConfig::default, |config| (*config).into());
+
+        let (result, ran_iterations, _) = page_rank(directed, config);
+
+        scores.copy_from_slice(&result);
+        if !iterations.is_null() {
+            *iterations = ran_iterations;
+        }
+        Ok(())
+    })
+}
+
+/// Computes the weakly connected components of the graph and writes the
+/// component id of every node to `components`, which must have one entry
+/// per node. Nodes in the same component share a component id.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph and `components` must point to `len`
+/// writable values.
+#[no_mangle]
+pub unsafe extern "C" fn graph_wcc(
+    graph: *const GraphHandle,
+    components: *mut u32,
+    len: usize,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        graph.check_len("components", len)?;
+        let components = output(components, len, "components")?;
+
+        let result = match &graph.topology {
+            Topology::Directed(g) => wcc_afforest(g, WccConfig::default()).to_vec(),
+            Topology::Undirected(g) => wcc_undirected(g, WccConfig::default()).to_vec(),
+        };
+
+        components.copy_from_slice(&result);
+        Ok(())
+    })
+}
+
+/// Counts the triangles of the graph and writes their number to
+/// `triangles`.
+///
+/// Directed graphs are counted as undirected graphs without parallel edges
+/// and self-loops. Undirected graphs are counted as they are, so they need
+/// to be created with [`GRAPH_LAYOUT_DEDUPLICATED`] for exact counts.
+///
+/// # Safety
+///
+/// `graph` must be a valid graph and `triangles` must point to writable
+/// memory.
+#[no_mangle]
+pub unsafe extern "C" fn graph_triangle_count(
+    graph: *const GraphHandle,
+    triangles: *mut u64,
+) -> GraphStatus {
+    run(|| {
+        let graph = handle(graph)?;
+        if triangles.is_null() {
+            return Err(FfiError::null_pointer("triangles"));
+        }
+
+        let count = match &graph.topology {
+            Topology::Directed(g) => {
+                global_triangle_count(&g.to_undirected(CsrLayout::Deduplicated))
+            }
+            Topology::Undirected(g) => global_triangle_count(g),
+        };
+
+        write(triangles, count, "triangles")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, ptr};
+
+    use super::*;
+
+    fn create(directed: bool) -> *mut GraphHandle {
+        let sources = [0, 0, 1, 2, 3];
+        let targets = [1, 2, 2, 0, 4];
+        let mut graph = ptr::null_mut();
+        let status = unsafe {
+            graph_create(
+                sources.as_ptr(),
+                targets.as_ptr(),
+                sources.len(),
+                6,
+                directed,
+                GRAPH_LAYOUT_DEDUPLICATED,
+                &mut graph,
+            )
+        };
+        assert_eq!(status, GraphStatus::Ok);
+        graph
+    }
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(graph_last_error()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn topology() {
+        let graph = create(true);
+
+        unsafe {
+            assert!(graph_is_directed(graph));
+            assert_eq!(graph_node_count(graph), 6);
+            assert_eq!(graph_edge_count(graph), 5);
+
+            let mut degree = 0;
+            assert_eq!(graph_in_degree(graph, 2, &mut degree), GraphStatus::Ok);
+            assert_eq!(degree, 2);
+
+            let mut neighbors = ptr::null();
+            let mut len = 0;
+            let status = graph_out_neighbors(graph, 0, &mut neighbors, &mut len);
+            assert_eq!(status, GraphStatus::Ok);
+            assert_eq!(slice::from_raw_parts(neighbors, len), &[1, 2]);
+
+            let status = graph_out_neighbors(graph, 6, &mut neighbors, &mut len);
+            assert_eq!(status, GraphStatus::InvalidArgument);
+            assert!(last_error().contains("node 6 does not exist"));
+
+            graph_free(graph);
+        }
+    }
+
+    #[test]
+    fn node_values() {
+        let graph = create(false);
+
+        unsafe {
+            let mut value = 1.0;
+            assert_eq!(graph_node_value(graph, 5, &mut value), GraphStatus::Ok);
+            assert_eq!(value, 0.0);
+
+            let values = [0.5, 1.5, 2.5, 3.5, 4.5, 5.5];
+            let status = graph_set_node_values(graph, values.as_ptr(), values.len());
+            assert_eq!(status, GraphStatus::Ok);
+            assert_eq!(graph_node_value(graph, 5, &mut value), GraphStatus::Ok);
+            assert_eq!(value, 5.5);
+
+            let status = graph_set_node_values(graph, values.as_ptr(), 2);
+            assert_eq!(status, GraphStatus::InvalidArgument);
+
+            graph_free(graph);
+        }
+    }
+
+    #[test]
+    fn algorithms() {
+        let graph = create(true);
+
+        unsafe {
+            let mut scores = [0.0; 6];
+            let mut iterations = 0;
+            let status = graph_page_rank(
+                graph,
+                ptr::null(),
+                scores.as_mut_ptr(),
+                scores.len(),
+                &mut iterations,
+            );
+            assert_eq!(status, GraphStatus::Ok);
+            assert!(iterations > 0);
+            assert!(scores[2] > scores[5]);
+
+            let mut components = [0; 6];
+            let status = graph_wcc(graph, components.as_mut_ptr(), components.len());
+            assert_eq!(status, GraphStatus::Ok);
+            assert_eq!(components[0], components[2]);
+            assert_eq!(components[3], components[4]);
+            assert_ne!(components[0], components[3]);
+            assert_ne!(components[0], components[5]);
+
+            let mut triangles = 0;
+            assert_eq!(graph_triangle_count(graph, &mut triangles), GraphStatus::Ok);
+            assert_eq!(triangles, 1);
+
+            graph_free(graph);
+        }
+
+        let graph = create(false);
+        unsafe {
+            let mut scores = [0.0; 6];
+            let status = graph_page_rank(
+                graph,
+                ptr::null(),
+                scores.as_mut_ptr(),
+                scores.len(),
+                ptr::null_mut(),
+            );
+            assert_eq!(status, GraphStatus::Unsupported);
+            graph_free(graph);
+        }
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let sources = [0, 7];
+        let targets = [1, 2];
+        let mut graph = ptr::null_mut();
+
+        unsafe {
+            let status = graph_create(
+                sources.as_ptr(),
+                targets.as_ptr(),
+                2,
+                4,
+                true,
+                GRAPH_LAYOUT_SORTED,
+                &mut graph,
+            );
+            assert_eq!(status, GraphStatus::InvalidArgument);
+            assert!(graph.is_null());
+
+            let status = graph_create(ptr::null(), targets.as_ptr(), 2, 0, true, 0, &mut graph);
+            assert_eq!(status, GraphStatus::NullPointer);
+            assert_eq!(last_error(), "sources must not be null");
+
+            let path = CString::new("does/not/exist.el").unwrap();
+            let status = graph_load(path.as_ptr(), GRAPH_FORMAT_EDGE_LIST, true, 0, &mut graph);
+            assert_eq!(status, GraphStatus::Io);
+        }
+    }
+}
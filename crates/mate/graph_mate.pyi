@@ -1,4 +1,4 @@
-from typing import Optional, overload
+from typing import Any, Callable, Iterable, Iterator, Literal, Optional, Sequence, Union, overload
 
 import numpy as np
 import numpy.typing as npt
@@ -43,6 +43,47 @@ class FileFormat:
     """
     EdgeList: FileFormat
 
+LayoutLike = Union[Layout, Literal["sorted", "unsorted", "deduplicated"]]
+"""
+A `Layout` or its case-insensitive name.
+"""
+
+ProgressCallback = Callable[[str, int, Optional[int]], Any]
+"""
+Called as `progress(task, completed, total)` while an algorithm is running,
+where `total` is `None` if the number of steps is not known upfront.
+
+The callback is invoked after each batch of work, e.g., an iteration. If it
+raises an exception, it is not called again and the exception is raised once
+the algorithm has finished.
+"""
+
+def reset_logging() -> None:
+    """
+    Apply changes to the Python logging configuration, e.g., new log levels,
+    to the messages that are logged while running algorithms.
+
+    Loggers and their levels are cached when they are first used, so changes
+    made after that are only picked up once this function has been called.
+    """
+
+def load(
+    path: str,
+    *,
+    file_format: Optional[FileFormat] = None,
+    layout: LayoutLike = Layout.Unsorted,
+    directed: bool = True,
+    weighted: bool = False,
+) -> Union[DiGraph, Graph, WeightedDiGraph, WeightedGraph]:
+    """
+    Load a graph from a file, similar to the `GraphBuilder` in Rust.
+
+    The type of the returned graph depends on the `directed` and `weighted`
+    options. Weighted graphs can only be loaded from edge lists, which is
+    also the default file format for them, unweighted graphs default to the
+    Graph500 format.
+    """
+
 class DiGraph:
     """
     A directed graph using 32 bits for node ids.
@@ -50,17 +91,66 @@ class DiGraph:
 
     @staticmethod
     def load(
-        path: str, layout: Layout = Layout.Unsorted, file_format=FileFormat.Graph500
+        path: str, layout: LayoutLike = Layout.Unsorted, file_format=FileFormat.Graph500
     ) -> DiGraph:
         """Load a graph from the provided format."""
     @staticmethod
     def from_numpy(
-        np: npt.NDArray[np.uint32], layout: Layout = Layout.Unsorted
+        np: npt.NDArray[np.uint32], layout: LayoutLike = Layout.Unsorted
     ) -> DiGraph:
         """Convert a numpy 2d-array into a graph."""
     @staticmethod
-    def from_pandas(df: pd.DataFrame, layout: Layout = Layout.Unsorted) -> DiGraph:
-        """Convert a pandas dataframe into a graph."""
+    def from_pandas(
+        df: pd.DataFrame,
+        layout: LayoutLike = Layout.Unsorted,
+        *,
+        source: Optional[str] = None,
+        target: Optional[str] = None,
+    ) -> DiGraph:
+        """
+        Convert a pandas dataframe into a graph.
+
+        The `source` and `target` columns contain the node ids of each edge.
+        If they are not given, the first two columns of the dataframe are used.
+        """
+    @staticmethod
+    def from_arrays(
+        sources: npt.ArrayLike,
+        targets: npt.ArrayLike,
+        layout: LayoutLike = Layout.Unsorted,
+    ) -> DiGraph:
+        """
+        Create a graph from two arrays containing the source and target ids
+        of each edge.
+
+        The arrays are converted to `uint32` numpy arrays, which does not copy
+        the data if they already have that type.
+        """
+    @staticmethod
+    def from_scipy_csr(mat: Any, layout: LayoutLike = Layout.Unsorted) -> DiGraph:
+        """
+        Create a graph from a `scipy.sparse` CSR matrix, where row `i` contains
+        the outgoing edges of node `i`. The values of the matrix are ignored.
+        """
+    @staticmethod
+    def from_networkx(graph: Any, layout: LayoutLike = Layout.Unsorted) -> DiGraph:
+        """
+        Convert a NetworkX graph into a graph.
+
+        Nodes are relabeled to consecutive ids in the order of `graph.nodes`,
+        the original labels are available as `node_labels`.
+
+        Edges of an undirected NetworkX graph are added in both directions.
+        """
+    @property
+    def node_labels(self) -> Optional[list[Any]]:
+        """
+        The node labels, where node `i` has the label at position `i`, e.g.,
+        the original nodes if the graph was created from NetworkX or node
+        types. Can be set to any sequence with one label per node.
+        """
+    @node_labels.setter
+    def node_labels(self, node_labels: Optional[Sequence[Any]]) -> None: ...
     def node_count(self) -> int:
         """Returns the number of nodes in the graph."""
     def edge_count(self) -> int:
@@ -69,6 +159,105 @@ class DiGraph:
         """Returns the number of edges where the given node is a source node."""
     def in_degree(self, node: int) -> int:
         """Returns the number of edges where the given node is a target node."""
+    def stats(self) -> dict[str, Union[int, float]]:
+        """
+        Returns summary statistics of this graph as a dict, i.e., the node
+        and edge counts, the density, the minimum, maximum, mean and 50th, 90th
+        and 99th percentile of the out-degrees and in-degrees, and the number of bytes used
+        to store the graph.
+        """
+    def __repr__(self) -> str:
+        """Returns a summary of the graph based on `stats`."""
+    def to_bytes(self) -> bytes:
+        """
+        Returns a binary snapshot of this graph, which can be turned back into
+        a graph with `from_bytes`.
+
+        `node_labels` are not part of the snapshot.
+        """
+    @staticmethod
+    def from_bytes(data: bytes) -> DiGraph:
+        """
+        Create a graph from a binary snapshot created by `to_bytes`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def save(self, path: str) -> None:
+        """
+        Write a binary snapshot of this graph to the given file, which can be
+        loaded much faster than the original input with `load_binary`.
+
+        `node_labels` are not part of the snapshot.
+        """
+    @staticmethod
+    def load_binary(path: str) -> DiGraph:
+        """
+        Load a graph from a binary snapshot file written by `save`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def __reduce__(self) -> tuple[Any, tuple[bytes], Optional[list[Any]]]:
+        """
+        Returns the state for `pickle`, which is the binary snapshot of the
+        graph together with its node labels.
+        """
+    def subgraph(self, nodes: npt.ArrayLike) -> tuple[DiGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by the given nodes, i.e., the nodes and
+        all edges between them, together with a numpy array that contains the
+        original id of each subgraph node.
+
+        Subgraph nodes keep the relative order of their original ids.
+        """
+    def sample(
+        self,
+        fraction: float = 0.1,
+        *,
+        method: Literal["node", "edge", "random_walk", "forest_fire"] = "random_walk",
+        seed: int = 42,
+        restart_probability: float = 0.15,
+        burn_probability: float = 0.7,
+    ) -> tuple[DiGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns a sample of this graph, i.e., the subgraph induced by a
+        `fraction` of its nodes, together with a numpy array that contains the
+        original id of each subgraph node.
+
+        `method` is one of `"node"` and `"edge"`, which select nodes or the
+        end nodes of edges uniformly at random, `"random_walk"`, which selects
+        the nodes visited by random walks along outgoing edges that return to
+        their start node with `restart_probability`, or `"forest_fire"`, which
+        spreads a fire from random nodes that ignites about
+        `burn_probability / (1 - burn_probability)` neighbors per node.
+        """
+    def ego(self, node: int, radius: int = 1) -> tuple[DiGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by all nodes that can be reached from
+        `node` by following at most `radius` outgoing edges, together with a numpy
+        array that contains the original id of each subgraph node.
+
+        `node` always becomes node `0` of the subgraph.
+        """
+    def label(self, node: int) -> Any:
+        """Returns the label of the given node."""
+    def nodes_with_label(self, label: Any) -> npt.NDArray[np.uint32]:
+        """
+        Returns all nodes with a label equal to the given label as a numpy
+        array in ascending order.
+        """
+    def label_counts(self) -> dict[Any, int]:
+        """
+        Returns a dict that maps each label to the number of nodes with that
+        label.
+        """
+    def subgraph_with_labels(self, labels: Iterable[Any]) -> tuple[DiGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by all nodes with one of the given labels,
+        together with a numpy array that contains the original id of each
+        subgraph node.
+        """
     def out_neighbors(self, node: int) -> npt.NDArray[np.uint32]:
         """
         Returns all nodes which are connected in outgoing direction to the given node,
@@ -99,19 +288,186 @@ class DiGraph:
 
         This function returns a copy of the data as a Python list.
         """
-    def to_undirected(self, layout: Layout = None) -> Graph:
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns all edges of this graph as a pandas dataframe with the
+        columns `src` and `dst`.
+        """
+    def to_networkx(self, node_attributes: Optional[dict[str, Any]] = None) -> Any:
+        """
+        Convert this graph into a `networkx.DiGraph`, using the original node
+        labels if there are any.
+
+        `node_attributes` maps attribute names to sequences with one value per
+        node, e.g., algorithm results, which are copied onto the nodes.
+        """
+    def to_undirected(self, layout: LayoutLike = None) -> Graph:
         """
         Convert this graph into an undirected graph.
         The new graph is unrelated to this graph and does not share any data.
         """
     def page_rank(
-        self, *, max_iterations: int, tolerance: float, damping_factor: float
+        self,
+        *,
+        max_iterations: int,
+        tolerance: float,
+        damping_factor: float,
+        initial_scores: Optional[npt.ArrayLike] = None,
+        progress: Optional[ProgressCallback] = None,
     ) -> PageRankResult:
-        """Run Page Rank on this graph."""
+        """
+        Run Page Rank on this graph.
+
+        If given, the computation starts from `initial_scores` instead of the
+        uniform distribution, e.g., the scores of a previous run on a slightly
+        changed graph, which needs fewer iterations to converge.
+
+        If given, `progress` is called as `progress(task, completed, total)`
+        after each iteration.
+        """
     def wcc(
-        self, *, chunk_size: int, neighbor_rounds: int, sampling_size: int
+        self,
+        *,
+        chunk_size: int,
+        neighbor_rounds: int,
+        sampling_size: int,
+        initial_components: Optional[npt.ArrayLike] = None,
     ) -> WccResult:
-        """Run Weakly Connected Components on this graph."""
+        """
+        Run Weakly Connected Components on this graph.
+
+        If given, the computation starts from `initial_components`, e.g., the
+        components of a previous run on this graph before edges were added.
+        Nodes that share an initial component must be connected.
+        """
+    def sssp(self, start_node: int, delta: float) -> SsspResult:
+        """
+        Run Single Source Shortest Path on this graph using delta-stepping.
+
+        Every edge has a weight of `1`, so distances are the number of hops
+        from the start node.
+        """
+    def shortest_path(
+        self, source_node: int, target_node: int
+    ) -> Optional[tuple[float, npt.NDArray[np.uint32]]]:
+        """
+        Compute a path with the fewest edges from `source_node` to
+        `target_node`, following outgoing edges, using a bidirectional
+        breadth-first search.
+
+        Returns the number of edges on the path and the nodes on it, including
+        both end nodes, or `None` if `target_node` is not reachable. Only the
+        nodes around the path are visited, which is much faster than
+        computing all distances for a single pair of nodes.
+        """
+    def landmark_index(
+        self,
+        landmark_count: int = 16,
+        *,
+        selection: Literal["farthest", "random"] = "farthest",
+        seed: int = 42,
+    ) -> LandmarkIndex:
+        """
+        Build a landmark index, which bounds the distance between any two
+        nodes, following outgoing edges, in time independent of the graph size.
+
+        `selection` is either `"farthest"`, which chooses each landmark as the
+        node that is farthest from the previous landmarks, or `"random"`.
+        """
+    def hyperball(
+        self, *, precision: int = 8, max_iterations: int = 1000
+    ) -> HyperBallResult:
+        """
+        Run HyperBall, which estimates the neighborhood function and the
+        closeness and harmonic centrality of each node, following outgoing
+        edges, with HyperLogLog counters of `2^precision` registers per node.
+        """
+    def reachability_index(
+        self, label_count: int = 3, *, seed: int = 42
+    ) -> ReachabilityIndex:
+        """
+        Build a reachability index, which answers whether a node can reach
+        another node by following outgoing edges, mostly in constant time.
+
+        More intervals per strongly connected component (`label_count`)
+        answer more queries without searching the graph.
+        """
+    def descendant_counts(
+        self, *, exact_threshold: int = 16384, precision: int = 8
+    ) -> npt.NDArray[np.float64]:
+        """
+        Count the nodes that each node can reach by following outgoing edges,
+        excluding the node itself.
+
+        The counts are exact if the graph has at most `exact_threshold`
+        strongly connected components and estimated with HyperLogLog counters
+        of `2^precision` registers otherwise.
+        """
+    def random_walks(
+        self,
+        *,
+        walk_length: int = 80,
+        walks_per_node: int = 10,
+        return_factor: float = 1.0,
+        in_out_factor: float = 1.0,
+        seed: int = 42,
+    ) -> list[npt.NDArray[np.uint32]]:
+        """
+        Compute `walks_per_node` random walks starting at each node, which
+        follow outgoing edges.
+
+        Returns a list of `uint32` numpy arrays, where the first `node_count`
+        walks are the first walks of each node in node order. Walks contain
+        at most `walk_length` nodes and end early at nodes without neighbors.
+        `return_factor` and `in_out_factor` are the node2vec parameters `p`
+        and `q` that bias the choice of the next node.
+        """
+    def fast_rp(
+        self,
+        *,
+        embedding_dimension: int = 128,
+        iterations: int = 3,
+        normalization_strength: float = 0.0,
+        seed: int = 42,
+        progress: Optional[ProgressCallback] = None,
+    ) -> npt.NDArray[np.float32]:
+        """
+        Compute FastRP node embeddings, which are propagated along
+        outgoing edges.
+
+        Returns a `float32` numpy array of shape `(node_count,
+        embedding_dimension)`, where row `i` is the embedding of node `i`.
+
+        If given, `progress` is called as `progress(task, completed, total)`
+        after each iteration.
+        """
+    def node2vec(
+        self,
+        *,
+        embedding_dimension: int = 128,
+        walk_length: int = 80,
+        walks_per_node: int = 10,
+        return_factor: float = 1.0,
+        in_out_factor: float = 1.0,
+        window_size: int = 10,
+        negative_samples: int = 5,
+        iterations: int = 1,
+        learning_rate: float = 0.025,
+        seed: int = 42,
+        progress: Optional[ProgressCallback] = None,
+    ) -> npt.NDArray[np.float32]:
+        """
+        Compute Node2Vec node embeddings by training a skip-gram model on
+        random walks, which follow outgoing edges.
+
+        Returns a `float32` numpy array of shape `(node_count,
+        embedding_dimension)`, where row `i` is the embedding of node `i`.
+        The training runs on a single thread, so that the result only depends
+        on the graph and the `seed`.
+
+        If given, `progress` is called as `progress(task, completed, total)`
+        with the number of walks that have been trained on.
+        """
 
 class Graph:
     """
@@ -120,23 +476,197 @@ class Graph:
 
     @staticmethod
     def load(
-        path: str, layout: Layout = Layout.Unsorted, file_format=FileFormat.Graph500
+        path: str, layout: LayoutLike = Layout.Unsorted, file_format=FileFormat.Graph500
     ) -> Graph:
         """Load a graph from the provided format"""
     @staticmethod
     def from_numpy(
-        np: npt.NDArray[np.uint32], layout: Layout = Layout.Unsorted
+        np: npt.NDArray[np.uint32], layout: LayoutLike = Layout.Unsorted
     ) -> Graph:
         """Convert a numpy 2d-array into a graph."""
     @staticmethod
-    def from_pandas(df: pd.DataFrame, layout: Layout = Layout.Unsorted) -> Graph:
-        """Convert a pandas dataframe into a graph."""
+    def from_pandas(
+        df: pd.DataFrame,
+        layout: LayoutLike = Layout.Unsorted,
+        *,
+        source: Optional[str] = None,
+        target: Optional[str] = None,
+    ) -> Graph:
+        """
+        Convert a pandas dataframe into a graph.
+
+        The `source` and `target` columns contain the node ids of each edge.
+        If they are not given, the first two columns of the dataframe are used.
+        """
+    @staticmethod
+    def from_arrays(
+        sources: npt.ArrayLike,
+        targets: npt.ArrayLike,
+        layout: LayoutLike = Layout.Unsorted,
+    ) -> Graph:
+        """
+        Create a graph from two arrays containing the source and target ids
+        of each edge.
+
+        The arrays are converted to `uint32` numpy arrays, which does not copy
+        the data if they already have that type.
+        """
+    @staticmethod
+    def from_scipy_csr(mat: Any, layout: LayoutLike = Layout.Unsorted) -> Graph:
+        """
+        Create a graph from a `scipy.sparse` CSR matrix, where row `i` contains
+        the edges of node `i`. The values of the matrix are ignored.
+
+        Every stored entry becomes an edge, so for a symmetric matrix only its
+        upper triangle, e.g., `scipy.sparse.triu(m, format="csr")`, should be
+        passed to avoid creating each edge twice.
+        """
+    @staticmethod
+    def from_networkx(graph: Any, layout: LayoutLike = Layout.Unsorted) -> Graph:
+        """
+        Convert a NetworkX graph into a graph.
+
+        Nodes are relabeled to consecutive ids in the order of `graph.nodes`,
+        the original labels are available as `node_labels`.
+
+        Edges of a directed NetworkX graph become undirected edges, so edges
+        in both directions between two nodes are added twice.
+        """
+    @property
+    def node_labels(self) -> Optional[list[Any]]:
+        """
+        The node labels, where node `i` has the label at position `i`, e.g.,
+        the original nodes if the graph was created from NetworkX or node
+        types. Can be set to any sequence with one label per node.
+        """
+    @node_labels.setter
+    def node_labels(self, node_labels: Optional[Sequence[Any]]) -> None: ...
     def node_count(self) -> int:
         """Returns the number of nodes in the graph."""
     def edge_count(self) -> int:
         """Returns the number of edges in the graph."""
     def degree(self, node: int) -> int:
         """Returns the number of edges connected to the given node."""
+    def stats(self) -> dict[str, Union[int, float]]:
+        """
+        Returns summary statistics of this graph as a dict, i.e., the node
+        and edge counts, the density, the minimum, maximum, mean and 50th, 90th
+        and 99th percentile of the degrees, and the number of bytes used
+        to store the graph.
+        """
+    def __repr__(self) -> str:
+        """Returns a summary of the graph based on `stats`."""
+    def to_bytes(self) -> bytes:
+        """
+        Returns a binary snapshot of this graph, which can be turned back into
+        a graph with `from_bytes`.
+
+        `node_labels` are not part of the snapshot.
+        """
+    @staticmethod
+    def from_bytes(data: bytes) -> Graph:
+        """
+        Create a graph from a binary snapshot created by `to_bytes`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def save(self, path: str) -> None:
+        """
+        Write a binary snapshot of this graph to the given file, which can be
+        loaded much faster than the original input with `load_binary`.
+
+        `node_labels` are not part of the snapshot.
+        """
+    @staticmethod
+    def load_binary(path: str) -> Graph:
+        """
+        Load a graph from a binary snapshot file written by `save`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def __reduce__(self) -> tuple[Any, tuple[bytes], Optional[list[Any]]]:
+        """
+        Returns the state for `pickle`, which is the binary snapshot of the
+        graph together with its node labels.
+        """
+    def subgraph(self, nodes: npt.ArrayLike) -> tuple[Graph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by the given nodes, i.e., the nodes and
+        all edges between them, together with a numpy array that contains the
+        original id of each subgraph node.
+
+        Subgraph nodes keep the relative order of their original ids.
+        """
+    def sample(
+        self,
+        fraction: float = 0.1,
+        *,
+        method: Literal["node", "edge", "random_walk", "forest_fire"] = "random_walk",
+        seed: int = 42,
+        restart_probability: float = 0.15,
+        burn_probability: float = 0.7,
+    ) -> tuple[Graph, npt.NDArray[np.uint32]]:
+        """
+        Returns a sample of this graph, i.e., the subgraph induced by a
+        `fraction` of its nodes, together with a numpy array that contains the
+        original id of each subgraph node.
+
+        `method` is one of `"node"` and `"edge"`, which select nodes or the
+        end nodes of edges uniformly at random, `"random_walk"`, which selects
+        the nodes visited by random walks that return to their start node
+        with `restart_probability`, or `"forest_fire"`, which spreads a fire
+        from random nodes that ignites about
+        `burn_probability / (1 - burn_probability)` neighbors per node.
+        """
+    def ego(self, node: int, radius: int = 1) -> tuple[Graph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by all nodes that can be reached from
+        `node` by following at most `radius` edges, together with a numpy
+        array that contains the original id of each subgraph node.
+
+        `node` always becomes node `0` of the subgraph.
+        """
+    def project(
+        self,
+        nodes: npt.ArrayLike,
+        *,
+        weighting: Literal["count", "jaccard", "newman"] = "count",
+        min_count: int = 1,
+        max_degree: Optional[int] = None,
+    ) -> tuple[WeightedGraph, npt.NDArray[np.uint32]]:
+        """
+        Projects this bipartite graph onto one of its sides, given as the
+        array `nodes`, and returns the weighted one-mode graph together with
+        a numpy array that contains the original id of each of its nodes.
+
+        Two nodes are connected if they share at least `min_count` neighbors
+        on the other side. `weighting` is one of `"count"`, the number of
+        shared neighbors, `"jaccard"`, that number divided by the number of
+        neighbors of either node, or `"newman"`, which sums `1 / (d - 1)`
+        over the shared neighbors with degree `d`. Neighbors with a degree
+        above `max_degree` are ignored, which keeps hubs from dominating the
+        run time.
+        """
+    def label(self, node: int) -> Any:
+        """Returns the label of the given node."""
+    def nodes_with_label(self, label: Any) -> npt.NDArray[np.uint32]:
+        """
+        Returns all nodes with a label equal to the given label as a numpy
+        array in ascending order.
+        """
+    def label_counts(self) -> dict[Any, int]:
+        """
+        Returns a dict that maps each label to the number of nodes with that
+        label.
+        """
+    def subgraph_with_labels(self, labels: Iterable[Any]) -> tuple[Graph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by all nodes with one of the given labels,
+        together with a numpy array that contains the original id of each
+        subgraph node.
+        """
     def neighbors(self, node: int) -> npt.NDArray[np.uint32]:
         """
         Returns all nodes connected to the given node.
@@ -150,6 +680,21 @@ class Graph:
 
         This function returns a copy of the data as a Python list.
         """
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns all edges of this graph as a pandas dataframe with the
+        columns `src` and `dst`.
+
+        Every edge is contained once, with `src` not larger than `dst`.
+        """
+    def to_networkx(self, node_attributes: Optional[dict[str, Any]] = None) -> Any:
+        """
+        Convert this graph into a `networkx.Graph`, using the original node
+        labels if there are any.
+
+        `node_attributes` maps attribute names to sequences with one value per
+        node, e.g., algorithm results, which are copied onto the nodes.
+        """
     def make_degree_ordered(self):
         """
         Converts this graph by relabeling the node ids based on their degree.
@@ -160,31 +705,1032 @@ class Graph:
 
         This modifies the graph in-place.
         The operation can only be done when there are no `neighbors` referenced somewhere.
+        Graphs with `node_labels` cannot be reordered, as the labels would no
+        longer match the node ids.
         """
-    def global_triangle_count(self) -> TriangleCountResult:
+    def triangle_count(self) -> TriangleCountResult:
         """Count the number of global triangles of this graph."""
+    def global_triangle_count(self) -> TriangleCountResult:
+        """
+        Count the number of global triangles of this graph.
 
-class PageRankResult:
-    def scores(self) -> npt.NDArray[np.float32]:
-        pass
-    @property
-    def ran_iterations(self) -> int:
-        pass
-    @property
-    def error(self) -> float:
-        pass
+        This is an alias for `triangle_count`.
+        """
+    def louvain(
+        self,
+        *,
+        max_iterations: int = 10,
+        tolerance: float = 1e-4,
+        seed: int = 42,
+        progress: Optional[ProgressCallback] = None,
+    ) -> CommunityResult:
+        """
+        Detect communities of this graph using the Louvain method.
+
+        `max_iterations` limits the local moving passes per level, the
+        computation stops once a level improves the modularity by less than
+        `tolerance`. The `seed` determines the order in which nodes are visited.
+
+        If given, `progress` is called as `progress(task, completed, None)`
+        after each level.
+        """
+    def label_propagation(
+        self,
+        *,
+        max_iterations: int = 10,
+        tolerance: float = 0.0,
+        seed: int = 42,
+        progress: Optional[ProgressCallback] = None,
+    ) -> CommunityResult:
+        """
+        Detect communities of this graph using label propagation.
+
+        The computation stops after `max_iterations` iterations or once the
+        fraction of nodes that changed their community in an iteration is at
+        most `tolerance`. The `seed` is used to break ties between labels.
+
+        If given, `progress` is called as `progress(task, completed, total)`
+        after each iteration.
+        """
+    def shortest_path(
+        self, source_node: int, target_node: int
+    ) -> Optional[tuple[float, npt.NDArray[np.uint32]]]:
+        """
+        Compute a path with the fewest edges from `source_node` to
+        `target_node` using a bidirectional breadth-first search.
+
+        Returns the number of edges on the path and the nodes on it, including
+        both end nodes, or `None` if `target_node` is not reachable. Only the
+        nodes around the path are visited, which is much faster than
+        computing all distances for a single pair of nodes.
+        """
+    def landmark_index(
+        self,
+        landmark_count: int = 16,
+        *,
+        selection: Literal["farthest", "random"] = "farthest",
+        seed: int = 42,
+    ) -> LandmarkIndex:
+        """
+        Build a landmark index, which bounds the distance between any two
+        nodes in time independent of the graph size.
+
+        `selection` is either `"farthest"`, which chooses each landmark as the
+        node that is farthest from the previous landmarks, or `"random"`.
+        """
+    def hyperball(
+        self, *, precision: int = 8, max_iterations: int = 1000
+    ) -> HyperBallResult:
+        """
+        Run HyperBall, which estimates the neighborhood function and the
+        closeness and harmonic centrality of each node with HyperLogLog
+        counters of `2^precision` registers per node.
+        """
+    def random_walks(
+        self,
+        *,
+        walk_length: int = 80,
+        walks_per_node: int = 10,
+        return_factor: float = 1.0,
+        in_out_factor: float = 1.0,
+        seed: int = 42,
+    ) -> list[npt.NDArray[np.uint32]]:
+        """
+        Compute `walks_per_node` random walks starting at each node, which
+        follow the edges of this graph.
+
+        Returns a list of `uint32` numpy arrays, where the first `node_count`
+        walks are the first walks of each node in node order. Walks contain
+        at most `walk_length` nodes and end early at nodes without neighbors.
+        `return_factor` and `in_out_factor` are the node2vec parameters `p`
+        and `q` that bias the choice of the next node.
+        """
+    def fast_rp(
+        self,
+        *,
+        embedding_dimension: int = 128,
+        iterations: int = 3,
+        normalization_strength: float = 0.0,
+        seed: int = 42,
+        progress: Optional[ProgressCallback] = None,
+    ) -> npt.NDArray[np.float32]:
+        """
+        Compute FastRP node embeddings, which are propagated along
+        the edges of this graph.
+
+        Returns a `float32` numpy array of shape `(node_count,
+        embedding_dimension)`, where row `i` is the embedding of node `i`.
+
+        If given, `progress` is called as `progress(task, completed, total)`
+        after each iteration.
+        """
+    def node2vec(
+        self,
+        *,
+        embedding_dimension: int = 128,
+        walk_length: int = 80,
+        walks_per_node: int = 10,
+        return_factor: float = 1.0,
+        in_out_factor: float = 1.0,
+        window_size: int = 10,
+        negative_samples: int = 5,
+        iterations: int = 1,
+        learning_rate: float = 0.025,
+        seed: int = 42,
+        progress: Optional[ProgressCallback] = None,
+    ) -> npt.NDArray[np.float32]:
+        """
+        Compute Node2Vec node embeddings by training a skip-gram model on
+        random walks, which follow the edges of this graph.
+
+        Returns a `float32` numpy array of shape `(node_count,
+        embedding_dimension)`, where row `i` is the embedding of node `i`.
+        The training runs on a single thread, so that the result only depends
+        on the graph and the `seed`.
+
+        If given, `progress` is called as `progress(task, completed, total)`
+        with the number of walks that have been trained on.
+        """
+
+class WeightedDiGraph:
+    """
+    A directed graph using 32 bits for node ids and 32 bit floats as edge weights.
+    """
+
+    @staticmethod
+    def load(path: str, layout: LayoutLike = Layout.Unsorted) -> WeightedDiGraph:
+        """
+        Load a graph from an edge list, where each line represents an edge in
+        the form of `<source_id> <target_id> <weight>`.
+        """
+    @staticmethod
+    def from_arrays(
+        sources: npt.ArrayLike,
+        targets: npt.ArrayLike,
+        weights: npt.ArrayLike,
+        layout: LayoutLike = Layout.Unsorted,
+    ) -> WeightedDiGraph:
+        """
+        Create a graph from arrays of source ids, target ids and edge weights.
+
+        The arrays are converted to `uint32` and `float32` numpy arrays, which
+        does not copy the data if they already have those types.
+        """
+    @staticmethod
+    def from_pandas(
+        df: pd.DataFrame,
+        layout: LayoutLike = Layout.Unsorted,
+        *,
+        source: Optional[str] = None,
+        target: Optional[str] = None,
+        weight: Optional[str] = None,
+    ) -> WeightedDiGraph:
+        """
+        Convert a pandas dataframe into a graph.
+
+        The `source`, `target` and `weight` columns contain the node ids and
+        weight of each edge. If they are not given, the first three columns of
+        the dataframe are used.
+        """
+    @staticmethod
+    def from_scipy_csr(mat: Any, layout: LayoutLike = Layout.Unsorted) -> WeightedDiGraph:
+        """
+        Create a graph from a `scipy.sparse` CSR matrix, where row `i` contains
+        the outgoing edges of node `i` and the matrix values are the edge weights.
+        """
+    @staticmethod
+    def from_networkx(
+        graph: Any, layout: LayoutLike = Layout.Unsorted, weight: str = "weight"
+    ) -> WeightedDiGraph:
+        """
+        Convert a NetworkX graph into a graph.
+
+        Nodes are relabeled to consecutive ids in the order of `graph.nodes`,
+        the original labels are available as `node_labels`. Edge weights are
+        read from the `weight` attribute and default to `1.0`.
+
+        Edges of an undirected NetworkX graph are added in both directions.
+        """
     @property
-    def micros(self) -> int:
-        pass
+    def node_labels(self) -> Optional[list[Any]]:
+        """
+        The node labels, where node `i` has the label at position `i`, e.g.,
+        the original nodes if the graph was created from NetworkX or node
+        types. Can be set to any sequence with one label per node.
+        """
+    @node_labels.setter
+    def node_labels(self, node_labels: Optional[Sequence[Any]]) -> None: ...
+    def node_count(self) -> int:
+        """Returns the number of nodes in the graph."""
+    def edge_count(self) -> int:
+        """Returns the number of edges in the graph."""
+    def out_degree(self, node: int) -> int:
+        """Returns the number of edges where the given node is a source node."""
+    def in_degree(self, node: int) -> int:
+        """Returns the number of edges where the given node is a target node."""
+    def stats(self) -> dict[str, Union[int, float]]:
+        """
+        Returns summary statistics of this graph as a dict, i.e., the node
+        and edge counts, the density, the minimum, maximum, mean and 50th, 90th
+        and 99th percentile of the out-degrees and in-degrees, and the number of bytes used
+        to store the graph.
+        """
     def __repr__(self) -> str:
-        pass
+        """Returns a summary of the graph based on `stats`."""
+    def to_bytes(self) -> bytes:
+        """
+        Returns a binary snapshot of this graph, which can be turned back into
+        a graph with `from_bytes`.
 
-class WccResult:
-    def components(self) -> npt.NDArray[np.uint32]:
-        pass
-    @property
-    def micros(self) -> int:
-        pass
+        `node_labels` are not part of the snapshot.
+        """
+    @staticmethod
+    def from_bytes(data: bytes) -> WeightedDiGraph:
+        """
+        Create a graph from a binary snapshot created by `to_bytes`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def save(self, path: str) -> None:
+        """
+        Write a binary snapshot of this graph to the given file, which can be
+        loaded much faster than the original input with `load_binary`.
+
+        `node_labels` are not part of the snapshot.
+        """
+    @staticmethod
+    def load_binary(path: str) -> WeightedDiGraph:
+        """
+        Load a graph from a binary snapshot file written by `save`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def __reduce__(self) -> tuple[Any, tuple[bytes], Optional[list[Any]]]:
+        """
+        Returns the state for `pickle`, which is the binary snapshot of the
+        graph together with its node labels.
+        """
+    def subgraph(self, nodes: npt.ArrayLike) -> tuple[WeightedDiGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by the given nodes, i.e., the nodes and
+        all edges between them, together with a numpy array that contains the
+        original id of each subgraph node.
+
+        Subgraph nodes keep the relative order of their original ids.
+        """
+    def ego(self, node: int, radius: int = 1) -> tuple[WeightedDiGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by all nodes that can be reached from
+        `node` by following at most `radius` outgoing edges, together with a numpy
+        array that contains the original id of each subgraph node.
+
+        `node` always becomes node `0` of the subgraph.
+        """
+    def label(self, node: int) -> Any:
+        """Returns the label of the given node."""
+    def nodes_with_label(self, label: Any) -> npt.NDArray[np.uint32]:
+        """
+        Returns all nodes with a label equal to the given label as a numpy
+        array in ascending order.
+        """
+    def label_counts(self) -> dict[Any, int]:
+        """
+        Returns a dict that maps each label to the number of nodes with that
+        label.
+        """
+    def subgraph_with_labels(self, labels: Iterable[Any]) -> tuple[WeightedDiGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by all nodes with one of the given labels,
+        together with a numpy array that contains the original id of each
+        subgraph node.
+        """
+    def filter(
+        self, *, min_weight: Optional[float] = None, max_weight: Optional[float] = None
+    ) -> WeightedDiGraph:
+        """
+        Returns a graph that only contains the edges with a weight of at least
+        `min_weight` and at most `max_weight`.
+
+        All nodes are kept, so node ids are the same as in this graph.
+        """
+    def transform_weights(
+        self, transform: Literal["inverse", "log", "negative_log", "complement"]
+    ) -> WeightedDiGraph:
+        """
+        Returns a graph with the same edges, where each weight `w` is
+        replaced according to `transform`, which is one of `"inverse"`
+        (`1 / w`), `"log"` (`ln(w)`), `"negative_log"` (`-ln(w)`) or
+        `"complement"` (`1 - w`).
+
+        This turns similarities into distances before running shortest path
+        algorithms, e.g., `"negative_log"` for probabilities and
+        `"complement"` for cosine similarities.
+        """
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns all edges of this graph as a pandas dataframe with the
+        columns `src`, `dst` and `weight`.
+        """
+    def to_networkx(self, node_attributes: Optional[dict[str, Any]] = None) -> Any:
+        """
+        Convert this graph into a `networkx.DiGraph`, using the original node
+        labels if there are any.
+
+        `node_attributes` maps attribute names to sequences with one value per
+        node, e.g., algorithm results, which are copied onto the nodes.
+        """
+    def sssp(self, start_node: int, delta: float) -> SsspResult:
+        """Run Single Source Shortest Path on this graph using delta-stepping."""
+    def dijkstra(self, start_node: int) -> ShortestPathResult:
+        """
+        Compute the shortest paths from `start_node` to all other nodes using
+        Dijkstra's algorithm, following outgoing edges.
+
+        Unlike `sssp`, the result also contains the shortest paths, which are
+        returned by `path_to`. Edge weights must not be negative.
+        """
+    def astar(
+        self, start_node: int, target_node: int, heuristic: Optional[npt.ArrayLike] = None
+    ) -> ShortestPathResult:
+        """
+        Compute the shortest path from `start_node` to `target_node` using A*,
+        following outgoing edges.
+
+        `heuristic` contains an estimate of the remaining distance of each
+        node to `target_node`, e.g., the straight-line distance, which must
+        never overestimate the actual distance. Without it, the search stops
+        as soon as `target_node` is reached, but is otherwise the same as
+        `dijkstra`. Only the distances of nodes settled before the target are
+        final.
+        """
+    def shortest_path(
+        self, source_node: int, target_node: int
+    ) -> Optional[tuple[float, npt.NDArray[np.uint32]]]:
+        """
+        Compute a shortest path from `source_node` to `target_node`,
+        following outgoing edges, using a bidirectional Dijkstra search.
+
+        Returns the distance and the nodes on the path, including both end
+        nodes, or `None` if `target_node` is not reachable. Only the nodes
+        around the path are visited, which is much faster than `dijkstra` for
+        a single pair of nodes. Edge weights must not be negative, which is
+        not checked to keep queries independent of the graph size.
+        """
+    def landmark_index(
+        self,
+        landmark_count: int = 16,
+        *,
+        selection: Literal["farthest", "random"] = "farthest",
+        seed: int = 42,
+    ) -> LandmarkIndex:
+        """
+        Build a landmark index, which bounds the distance between any two
+        nodes, following outgoing edges, in time independent of the graph size.
+
+        `selection` is either `"farthest"`, which chooses each landmark as the
+        node that is farthest from the previous landmarks, or `"random"`.
+        """
+    def reachability_index(
+        self, label_count: int = 3, *, seed: int = 42
+    ) -> ReachabilityIndex:
+        """
+        Build a reachability index, which answers whether a node can reach
+        another node by following outgoing edges, mostly in constant time.
+
+        More intervals per strongly connected component (`label_count`)
+        answer more queries without searching the graph.
+        """
+    def descendant_counts(
+        self, *, exact_threshold: int = 16384, precision: int = 8
+    ) -> npt.NDArray[np.float64]:
+        """
+        Count the nodes that each node can reach by following outgoing edges,
+        excluding the node itself.
+
+        The counts are exact if the graph has at most `exact_threshold`
+        strongly connected components and estimated with HyperLogLog counters
+        of `2^precision` registers otherwise.
+        """
+
+class WeightedGraph:
+    """
+    An undirected graph using 32 bits for node ids and 32 bit floats as edge weights.
+    """
+
+    @staticmethod
+    def load(path: str, layout: LayoutLike = Layout.Unsorted) -> WeightedGraph:
+        """
+        Load a graph from an edge list, where each line represents an edge in
+        the form of `<source_id> <target_id> <weight>`.
+        """
+    @staticmethod
+    def from_arrays(
+        sources: npt.ArrayLike,
+        targets: npt.ArrayLike,
+        weights: npt.ArrayLike,
+        layout: LayoutLike = Layout.Unsorted,
+    ) -> WeightedGraph:
+        """
+        Create a graph from arrays of source ids, target ids and edge weights.
+
+        The arrays are converted to `uint32` and `float32` numpy arrays, which
+        does not copy the data if they already have those types.
+        """
+    def node_count(self) -> int:
+        """Returns the number of nodes in the graph."""
+    def edge_count(self) -> int:
+        """Returns the number of edges in the graph."""
+    def degree(self, node: int) -> int:
+        """Returns the number of edges connected to the given node."""
+    def stats(self) -> dict[str, Union[int, float]]:
+        """
+        Returns summary statistics of this graph as a dict, i.e., the node
+        and edge counts, the density, the minimum, maximum, mean and 50th, 90th
+        and 99th percentile of the degrees, and the number of bytes used
+        to store the graph.
+        """
+    def __repr__(self) -> str:
+        """Returns a summary of the graph based on `stats`."""
+    def to_bytes(self) -> bytes:
+        """
+        Returns a binary snapshot of this graph, which can be turned back into
+        a graph with `from_bytes`.
+        """
+    @staticmethod
+    def from_bytes(data: bytes) -> WeightedGraph:
+        """
+        Create a graph from a binary snapshot created by `to_bytes`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def save(self, path: str) -> None:
+        """
+        Write a binary snapshot of this graph to the given file, which can be
+        loaded much faster than the original input with `load_binary`.
+        """
+    @staticmethod
+    def load_binary(path: str) -> WeightedGraph:
+        """
+        Load a graph from a binary snapshot file written by `save`.
+
+        Only use snapshots from trusted sources, the data is not validated
+        beyond its header.
+        """
+    def __reduce__(self) -> tuple[Any, tuple[bytes]]:
+        """
+        Returns the state for `pickle`, which is the binary snapshot of the
+        graph.
+        """
+    def subgraph(self, nodes: npt.ArrayLike) -> tuple[WeightedGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by the given nodes, i.e., the nodes and
+        all edges between them, together with a numpy array that contains the
+        original id of each subgraph node.
+
+        Subgraph nodes keep the relative order of their original ids.
+        """
+    def ego(self, node: int, radius: int = 1) -> tuple[WeightedGraph, npt.NDArray[np.uint32]]:
+        """
+        Returns the subgraph induced by all nodes that can be reached from
+        `node` by following at most `radius` edges, together with a numpy
+        array that contains the original id of each subgraph node.
+
+        `node` always becomes node `0` of the subgraph.
+        """
+    def filter(
+        self, *, min_weight: Optional[float] = None, max_weight: Optional[float] = None
+    ) -> WeightedGraph:
+        """
+        Returns a graph that only contains the edges with a weight of at least
+        `min_weight` and at most `max_weight`.
+
+        All nodes are kept, so node ids are the same as in this graph.
+        """
+    def transform_weights(
+        self, transform: Literal["inverse", "log", "negative_log", "complement"]
+    ) -> WeightedGraph:
+        """
+        Returns a graph with the same edges, where each weight `w` is
+        replaced according to `transform`, which is one of `"inverse"`
+        (`1 / w`), `"log"` (`ln(w)`), `"negative_log"` (`-ln(w)`) or
+        `"complement"` (`1 - w`).
+
+        This turns similarities into distances before running shortest path
+        algorithms, e.g., `"negative_log"` for probabilities and
+        `"complement"` for cosine similarities.
+        """
+    def dijkstra(self, start_node: int) -> ShortestPathResult:
+        """
+        Compute the shortest paths from `start_node` to all other nodes using
+        Dijkstra's algorithm, following edges.
+
+        The result contains the distances and the shortest paths, which are
+        returned by `path_to`. Edge weights must not be negative.
+        """
+    def astar(
+        self, start_node: int, target_node: int, heuristic: Optional[npt.ArrayLike] = None
+    ) -> ShortestPathResult:
+        """
+        Compute the shortest path from `start_node` to `target_node` using A*,
+        following edges.
+
+        `heuristic` contains an estimate of the remaining distance of each
+        node to `target_node`, e.g., the straight-line distance, which must
+        never overestimate the actual distance. Without it, the search stops
+        as soon as `target_node` is reached, but is otherwise the same as
+        `dijkstra`. Only the distances of nodes settled before the target are
+        final.
+        """
+    def shortest_path(
+        self, source_node: int, target_node: int
+    ) -> Optional[tuple[float, npt.NDArray[np.uint32]]]:
+        """
+        Compute a shortest path from `source_node` to `target_node`,
+        following edges, using a bidirectional Dijkstra search.
+
+        Returns the distance and the nodes on the path, including both end
+        nodes, or `None` if `target_node` is not reachable. Only the nodes
+        around the path are visited, which is much faster than `dijkstra` for
+        a single pair of nodes. Edge weights must not be negative, which is
+        not checked to keep queries independent of the graph size.
+        """
+    def landmark_index(
+        self,
+        landmark_count: int = 16,
+        *,
+        selection: Literal["farthest", "random"] = "farthest",
+        seed: int = 42,
+    ) -> LandmarkIndex:
+        """
+        Build a landmark index, which bounds the distance between any two
+        nodes in time independent of the graph size.
+
+        `selection` is either `"farthest"`, which chooses each landmark as the
+        node that is farthest from the previous landmarks, or `"random"`.
+        """
+
+AnyGraph = Union[DiGraph, Graph, WeightedDiGraph, WeightedGraph]
+
+class Catalog:
+    """
+    A collection of named graphs, e.g., to manage several datasets in a
+    long-lived notebook kernel.
+
+    Graphs are kept alive as long as they are in the catalog or referenced
+    elsewhere, so dropping a graph only frees its memory once there are no
+    other references to it.
+    """
+
+    def __init__(self) -> None: ...
+    def load(
+        self,
+        name: str,
+        path: str,
+        *,
+        file_format: Optional[FileFormat] = None,
+        layout: LayoutLike = Layout.Unsorted,
+        directed: bool = True,
+        weighted: bool = False,
+        replace: bool = False,
+    ) -> AnyGraph:
+        """
+        Load a graph from a file and add it under the given name, see `load`
+        for the options.
+        """
+    def add(self, name: str, graph: AnyGraph, *, replace: bool = False) -> None:
+        """
+        Add a graph under the given name.
+
+        Adding a graph under an existing name fails, unless `replace` is set.
+        """
+    def get(self, name: str) -> AnyGraph:
+        """Returns the graph with the given name."""
+    def drop(self, name: str) -> AnyGraph:
+        """Removes the graph with the given name from the catalog and returns it."""
+    def clear(self) -> None:
+        """Removes all graphs from the catalog."""
+    def names(self) -> list[str]:
+        """Returns the names of all graphs in ascending order."""
+    def list(self) -> list[dict[str, Any]]:
+        """
+        Returns a list with one dict per graph in ascending order of their
+        names, containing the `name`, the `type`, the `node_count`, the
+        `edge_count` and the `memory_bytes` of the graph.
+        """
+    def memory_bytes(self) -> int:
+        """Returns the number of bytes used by all graphs in the catalog."""
+    def run(self, name: str, algorithm: str, *args: Any, **kwargs: Any) -> Any:
+        """
+        Run an algorithm on the graph with the given name.
+
+        The algorithm is the name of a graph method, e.g., `"page_rank"`, and
+        is called with the remaining arguments.
+        """
+    def __getitem__(self, name: str) -> AnyGraph:
+        pass
+    def __setitem__(self, name: str, graph: AnyGraph) -> None:
+        pass
+    def __delitem__(self, name: str) -> None:
+        pass
+    def __contains__(self, name: str) -> bool:
+        pass
+    def __len__(self) -> int:
+        pass
+    def __iter__(self) -> Iterator[str]:
+        pass
+    def __repr__(self) -> str:
+        pass
+
+class _Generate:
+    """
+    Random graph generators, e.g., to benchmark algorithms without shipping
+    datasets.
+
+    The generators return a `DiGraph` if `directed` is set, otherwise a
+    `Graph`. The same seed always generates the same graph.
+    """
+
+    def rmat(
+        self,
+        scale: int = 20,
+        edge_factor: int = 16,
+        *,
+        a: float = 0.57,
+        b: float = 0.19,
+        c: float = 0.19,
+        seed: int = 42,
+        directed: bool = True,
+        layout: LayoutLike = Layout.Unsorted,
+    ) -> Union[DiGraph, Graph]:
+        """
+        Generate an R-MAT graph with `2^scale` nodes and `edge_factor * 2^scale`
+        edges, as used by the Graph500 benchmark.
+
+        `a`, `b` and `c` are the probabilities of choosing the upper left, upper
+        right and lower left quadrant of the adjacency matrix for an edge. The
+        graph may contain duplicate edges and self-loops, unless it is created
+        with the deduplicated layout.
+        """
+    def erdos_renyi(
+        self,
+        node_count: int,
+        edge_probability: float,
+        *,
+        seed: int = 42,
+        directed: bool = True,
+        layout: LayoutLike = Layout.Unsorted,
+    ) -> Union[DiGraph, Graph]:
+        """
+        Generate an Erdős–Rényi graph, which contains each possible edge between
+        two distinct nodes with the given probability.
+
+        Directed graphs may contain both `(u, v)` and `(v, u)`, undirected graphs
+        contain each edge at most once.
+        """
+    def barabasi_albert(
+        self,
+        node_count: int,
+        edges_per_node: int = 4,
+        *,
+        seed: int = 42,
+        directed: bool = True,
+        layout: LayoutLike = Layout.Unsorted,
+    ) -> Union[DiGraph, Graph]:
+        """
+        Generate a Barabási–Albert graph, in which each new node is connected to
+        `edges_per_node` existing nodes, chosen with a probability proportional
+        to their degree.
+
+        For directed graphs, the edges point from the new nodes to the existing
+        ones.
+        """
+
+generate: _Generate
+
+ResultSummary = dict[str, float]
+"""
+The keys `p50`, `p90` and `p99` contain percentiles with a relative error of
+at most 0.1%, `max` and `mean` contain the exact maximum and mean.
+"""
+
+class PageRankResult:
+    def scores(self) -> npt.NDArray[np.float32]:
+        pass
+    @property
+    def ran_iterations(self) -> int:
+        pass
+    @property
+    def error(self) -> float:
+        pass
+    @property
+    def micros(self) -> int:
+        pass
+    def __array__(self, dtype: Optional[npt.DTypeLike] = None) -> npt.NDArray[np.float32]:
+        """
+        Returns a read-only view of the result without copying it, unless a
+        different `dtype` is requested.
+        """
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns the score of each node as a pandas dataframe indexed by `node_id`.
+        """
+    def top_k(self, k: int) -> tuple[npt.NDArray[np.uint32], npt.NDArray[np.float32]]:
+        """
+        Returns the `k` highest-scoring nodes and their scores, ordered by
+        descending score, without sorting all scores.
+        """
+    def summary(self) -> Optional[ResultSummary]:
+        """
+        Returns the percentiles, the maximum and the mean of the scores as a
+        dict with the keys `p50`, `p90`, `p99`, `max` and `mean`.
+        """
+    def __len__(self) -> int:
+        pass
+    @overload
+    def __getitem__(self, index: int) -> float:
+        pass
+    @overload
+    def __getitem__(self, index: slice) -> npt.NDArray[np.float32]:
+        """Returns a read-only view of the sliced values without copying them."""
+    def __iter__(self) -> Iterator[float]:
+        pass
+    def __repr__(self) -> str:
+        pass
+
+class WccResult:
+    def components(self) -> npt.NDArray[np.uint32]:
+        pass
+    def size_summary(self) -> Optional[ResultSummary]:
+        """
+        Returns the percentiles, the maximum and the mean of the component
+        sizes as a dict with the keys `p50`, `p90`, `p99`, `max` and `mean`.
+        """
+    @property
+    def micros(self) -> int:
+        pass
+    def __array__(self, dtype: Optional[npt.DTypeLike] = None) -> npt.NDArray[np.uint32]:
+        """
+        Returns a read-only view of the result without copying it, unless a
+        different `dtype` is requested.
+        """
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns the component id of each node as a pandas dataframe indexed by
+        `node_id`.
+        """
+    def __len__(self) -> int:
+        pass
+    @overload
+    def __getitem__(self, index: int) -> int:
+        pass
+    @overload
+    def __getitem__(self, index: slice) -> npt.NDArray[np.uint32]:
+        """Returns a read-only view of the sliced values without copying them."""
+    def __iter__(self) -> Iterator[int]:
+        pass
+    def __repr__(self) -> str:
+        pass
+
+class CommunityResult:
+    def communities(self) -> npt.NDArray[np.uint32]:
+        """
+        Returns the community id of each node.
+
+        Community ids are consecutive, starting at `0`.
+        """
+    def community_of(self, node: int) -> int:
+        """Returns the community id of the given node."""
+    def community_sizes(self) -> npt.NDArray[np.uint32]:
+        """Returns the number of nodes in each community, indexed by community id."""
+    def size_summary(self) -> Optional[ResultSummary]:
+        """
+        Returns the percentiles, the maximum and the mean of the community
+        sizes as a dict with the keys `p50`, `p90`, `p99`, `max` and `mean`.
+        """
+    @property
+    def community_count(self) -> int:
+        """The number of communities."""
+    @property
+    def modularity(self) -> float:
+        """The modularity of the communities."""
+    @property
+    def ran_iterations(self) -> int:
+        """
+        The number of iterations for label propagation, or the number of
+        levels for Louvain.
+        """
+    @property
+    def micros(self) -> int:
+        pass
+    def __array__(self, dtype: Optional[npt.DTypeLike] = None) -> npt.NDArray[np.uint32]:
+        """
+        Returns a read-only view of the result without copying it, unless a
+        different `dtype` is requested.
+        """
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns the community id of each node as a pandas dataframe indexed by
+        `node_id`.
+        """
+    def __len__(self) -> int:
+        pass
+    @overload
+    def __getitem__(self, index: int) -> int:
+        pass
+    @overload
+    def __getitem__(self, index: slice) -> npt.NDArray[np.uint32]:
+        """Returns a read-only view of the sliced values without copying them."""
+    def __iter__(self) -> Iterator[int]:
+        pass
+    def __repr__(self) -> str:
+        pass
+
+class SsspResult:
+    def distances(self) -> npt.NDArray[np.float32]:
+        """
+        The distance of each node to the start node, unreachable nodes have
+        an infinite distance.
+        """
+    @property
+    def micros(self) -> int:
+        pass
+    def __array__(self, dtype: Optional[npt.DTypeLike] = None) -> npt.NDArray[np.float32]:
+        """
+        Returns a read-only view of the result without copying it, unless a
+        different `dtype` is requested.
+        """
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns the distance of each node to the start node as a pandas
+        dataframe indexed by `node_id`.
+        """
+    def __len__(self) -> int:
+        pass
+    @overload
+    def __getitem__(self, index: int) -> float:
+        pass
+    @overload
+    def __getitem__(self, index: slice) -> npt.NDArray[np.float32]:
+        """Returns a read-only view of the sliced values without copying them."""
+    def __iter__(self) -> Iterator[float]:
+        pass
+    def __repr__(self) -> str:
+        pass
+
+class ShortestPathResult:
+    @property
+    def start_node(self) -> int:
+        pass
+    @property
+    def micros(self) -> int:
+        pass
+    def distances(self) -> npt.NDArray[np.float32]:
+        """
+        The distance of each node to the start node, nodes without a path
+        have an infinite distance.
+        """
+    def predecessors(self) -> npt.NDArray[np.uint32]:
+        """
+        The predecessor of each node on its shortest path. The start node and
+        nodes without a path are their own predecessors.
+        """
+    def distance_to(self, target: int) -> float:
+        """Returns the distance of the given node to the start node."""
+    def path_to(self, target: int) -> Optional[list[int]]:
+        """
+        Returns the nodes on the shortest path from the start node to the
+        given node, including both, or `None` if there is no path.
+        """
+    def __array__(self, dtype: Optional[npt.DTypeLike] = None) -> npt.NDArray[np.float32]:
+        """
+        Returns a read-only view of the result without copying it, unless a
+        different `dtype` is requested.
+        """
+    def to_pandas(self) -> pd.DataFrame:
+        """
+        Returns the distance of each node to the start node as a pandas
+        dataframe indexed by `node_id`.
+        """
+    def __len__(self) -> int:
+        pass
+    @overload
+    def __getitem__(self, index: int) -> float:
+        pass
+    @overload
+    def __getitem__(self, index: slice) -> npt.NDArray[np.float32]:
+        """Returns a read-only view of the sliced values without copying them."""
+    def __iter__(self) -> Iterator[float]:
+        pass
+    def __repr__(self) -> str:
+        pass
+
+class LandmarkIndex:
+    """
+    The distances from and to a set of landmark nodes, which bound the
+    distance between any two nodes.
+    """
+
+    node_count: int
+    micros: int
+
+    def landmarks(self) -> npt.NDArray[np.uint32]:
+        """The landmark nodes in the order in which they were chosen."""
+    def lower_bound(self, source_node: int, target_node: int) -> float:
+        """
+        A lower bound for the distance from `source_node` to `target_node`,
+        which is infinite if the landmarks prove that there is no path.
+        """
+    def upper_bound(self, source_node: int, target_node: int) -> float:
+        """
+        An upper bound for the distance from `source_node` to `target_node`,
+        which is the length of the shortest path through a landmark.
+        """
+    def lower_bounds(
+        self, sources: npt.ArrayLike, targets: npt.ArrayLike
+    ) -> npt.NDArray[np.float32]:
+        """
+        The lower bounds for each pair of nodes from the arrays `sources` and
+        `targets`, computed in parallel.
+        """
+    def upper_bounds(
+        self, sources: npt.ArrayLike, targets: npt.ArrayLike
+    ) -> npt.NDArray[np.float32]:
+        """
+        The upper bounds for each pair of nodes from the arrays `sources` and
+        `targets`, computed in parallel.
+        """
+    def heuristic(self, target_node: int) -> npt.NDArray[np.float32]:
+        """
+        The lower bound for the distance of each node to `target_node`, which
+        can be passed as the `heuristic` of `astar`.
+        """
+    def __repr__(self) -> str:
+        pass
+
+class HyperBallResult:
+    """
+    Estimates of the neighborhood function and of the closeness and harmonic
+    centrality of each node.
+    """
+
+    micros: int
+
+    @property
+    def iterations(self) -> int:
+        """
+        The number of iterations, which is one more than the radius at which
+        all balls were complete, unless `max_iterations` was reached.
+        """
+    def neighborhood_function(self) -> npt.NDArray[np.float64]:
+        """
+        The estimated number of pairs of nodes within distance `t` at index
+        `t`, including pairs of a node with itself.
+        """
+    def closeness(self) -> npt.NDArray[np.float64]:
+        """
+        The estimated number of reachable nodes divided by the sum of their
+        distances for each node, or zero if no other node is reachable.
+        """
+    def harmonic(self) -> npt.NDArray[np.float64]:
+        """The estimated sum of the inverse distances to all reachable nodes."""
+    def effective_diameter(self, quantile: float = 0.9) -> float:
+        """
+        The smallest distance, interpolated between integers, within which
+        the fraction `quantile` of all connected pairs of nodes lie.
+        """
+    def __repr__(self) -> str:
+        pass
+
+class ReachabilityIndex:
+    """
+    The strongly connected components of a directed graph, labeled with
+    intervals that answer whether a node can reach another node.
+    """
+
+    node_count: int
+    micros: int
+
+    @property
+    def component_count(self) -> int:
+        """The number of strongly connected components."""
+    def components(self) -> npt.NDArray[np.uint32]:
+        """
+        The strongly connected component of each node. Components are
+        numbered in topological order, i.e., edges never point from a
+        component to one with a lower number.
+        """
+    def can_reach(self, source_node: int, target_node: int) -> bool:
+        """Whether there is a path from `source_node` to `target_node`."""
+    def can_reach_many(
+        self, sources: npt.ArrayLike, targets: npt.ArrayLike
+    ) -> npt.NDArray[np.bool_]:
+        """
+        Whether there is a path for each pair of nodes from the arrays
+        `sources` and `targets`, computed in parallel.
+        """
     def __repr__(self) -> str:
         pass
 
@@ -0,0 +1,169 @@
+use crate::{
+    graphs::{SharedSlice, SharedSliceIter},
+    progress::PyProgress,
+};
+use graph::prelude::{
+    label_propagation_with_progress, louvain_with_progress, modularity as graph_modularity,
+    Graph as GraphTrait, LabelPropagationConfig, LouvainConfig, ResultSummary, UndirectedNeighbors,
+};
+use numpy::PyArray1;
+use pyo3::prelude::*;
+use std::time::{Duration, Instant};
+
+pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<CommunityResult>()?;
+    Ok(())
+}
+
+pub(crate) fn louvain<G>(
+    py: Python<'_>,
+    graph: &G,
+    config: LouvainConfig,
+    progress: PyProgress,
+) -> PyResult<CommunityResult>
+where
+    G: GraphTrait<u32> + UndirectedNeighbors<u32> + Sync,
+{
+    let result = py.allow_threads(|| {
+        let start = Instant::now();
+        let result = louvain_with_progress(graph, config, &progress);
+        let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
+        CommunityResult::new(result.communities, result.modularity, result.levels, micros)
+    });
+    progress.finish()?;
+    Ok(result)
+}
+
+pub(crate) fn label_propagation<G>(
+    py: Python<'_>,
+    graph: &G,
+    config: LabelPropagationConfig,
+    progress: PyProgress,
+) -> PyResult<CommunityResult>
+where
+    G: GraphTrait<u32> + UndirectedNeighbors<u32> + Sync,
+{
+    let result = py.allow_threads(|| {
+        let start = Instant::now();
+        let (communities, ran_iterations) =
+            label_propagation_with_progress(graph, config, &progress);
+        let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
+        let modularity = graph_modularity(graph, &communities);
+        CommunityResult::new(communities, modularity, ran_iterations, micros)
+    });
+    progress.finish()?;
+    Ok(result)
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct CommunityResult {
+    communities: SharedSlice,
+    community_sizes: SharedSlice,
+    /// The modularity of the communities.
+    #[pyo3(get)]
+    modularity: f64,
+    /// The number of iterations for label propagation, or the number of
+    /// levels for Louvain.
+    #[pyo3(get)]
+    ran_iterations: usize,
+    #[pyo3(get)]
+    micros: u64,
+}
+
+impl CommunityResult {
+    fn new(communities: Vec<u32>, modularity: f64, ran_iterations: usize, micros: u64) -> Self {
+        let community_count = communities.iter().map(|c| *c as usize + 1).max();
+        let mut community_sizes = vec![0_u32; community_count.unwrap_or(0)];
+        for community in &communities {
+            community_sizes[*community as usize] += 1;
+        }
+        Self {
+            communities: SharedSlice::from_vec(communities),
+            community_sizes: SharedSlice::from_vec(community_sizes),
+            modularity,
+            ran_iterations,
+            micros,
+        }
+    }
+}
+
+impl std::fmt::Debug for CommunityResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommunityResult")
+            .field(
+                "communities",
+                &format!("[... {} values]", self.communities.len()),
+            )
+            .field("community_count", &self.community_sizes.len())
+            .field("modularity", &self.modularity)
+            .field("ran_iterations", &self.ran_iterations)
+            .field("took", &Duration::from_micros(self.micros))
+            .finish()
+    }
+}
+
+#[pymethods]
+impl CommunityResult {
+    /// Returns the community id of each node.
+    ///
+    /// Community ids are consecutive, starting at `0`.
+    pub fn communities<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<u32>> {
+        self.communities.clone().into_numpy(py)
+    }
+
+    /// Returns the community id of the given node.
+    pub fn community_of(&self, node: u32) -> PyResult<u32> {
+        self.communities.item(node as isize)
+    }
+
+    /// Returns the number of nodes in each community, indexed by community id.
+    pub fn community_sizes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<u32>> {
+        self.community_sizes.clone().into_numpy(py)
+    }
+
+    /// Returns the percentiles, the maximum and the mean of the community
+    /// sizes as a dict with the keys `p50`, `p90`, `p99`, `max` and `mean`.
+    pub fn size_summary(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let sizes = self.community_sizes.as_slice::<u32>();
+        let summary = py.allow_threads(|| {
+            let sizes = sizes.iter().map(|&size| size as u64).collect::<Vec<_>>();
+            ResultSummary::from_counts(&sizes)
+        });
+        crate::summary::into_dict(py, summary)
+    }
+
+    /// The number of communities.
+    #[getter]
+    pub fn community_count(&self) -> usize {
+        self.community_sizes.len()
+    }
+
+    #[args(dtype = "None")]
+    fn __array__(&self, py: Python<'_>, dtype: Option<&PyAny>) -> PyResult<PyObject> {
+        self.communities.clone().into_array::<u32>(py, dtype)
+    }
+
+    /// Returns the community id of each node as a pandas dataframe indexed by
+    /// `node_id`.
+    fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let communities = self.communities.clone().into_numpy::<u32>(py)?;
+        crate::pandas::node_frame(py, "community", communities)
+    }
+
+    fn __len__(&self) -> usize {
+        self.communities.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: &PyAny) -> PyResult<PyObject> {
+        self.communities.get(py, index)
+    }
+
+    fn __iter__(&self) -> SharedSliceIter {
+        self.communities.iter()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
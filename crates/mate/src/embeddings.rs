@@ -0,0 +1,80 @@
+use crate::progress::PyProgress;
+use graph::prelude::{
+    fast_rp_with_progress, node2vec_with_progress, random_walks as graph_random_walks, Embeddings,
+    FastRpConfig, Graph as GraphTrait, Node2VecConfig, RandomWalkConfig, WalkNeighbors,
+};
+use numpy::{IntoPyArray, PyArray2};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyList};
+
+pub(crate) fn random_walks<'py, G>(
+    py: Python<'py>,
+    graph: &G,
+    config: RandomWalkConfig,
+) -> PyResult<&'py PyList>
+where
+    G: GraphTrait<u32> + WalkNeighbors<u32> + Sync,
+{
+    check_walk_factors(config.return_factor, config.in_out_factor)?;
+    let walks = py.allow_threads(move || graph_random_walks(graph, config));
+    let arrays = PyList::empty(py);
+    for walk in walks {
+        arrays.append(walk.into_pyarray(py))?;
+    }
+    Ok(arrays)
+}
+
+pub(crate) fn fast_rp<'py, G>(
+    py: Python<'py>,
+    graph: &G,
+    config: FastRpConfig,
+    progress: PyProgress,
+) -> PyResult<&'py PyArray2<f32>>
+where
+    G: GraphTrait<u32> + WalkNeighbors<u32> + Sync,
+{
+    check_dimension(config.embedding_dimension)?;
+    let embeddings = py.allow_threads(|| fast_rp_with_progress(graph, config, &progress));
+    progress.finish()?;
+    into_matrix(py, embeddings)
+}
+
+pub(crate) fn node2vec<'py, G>(
+    py: Python<'py>,
+    graph: &G,
+    config: Node2VecConfig,
+    progress: PyProgress,
+) -> PyResult<&'py PyArray2<f32>>
+where
+    G: GraphTrait<u32> + WalkNeighbors<u32> + Sync,
+{
+    check_dimension(config.embedding_dimension)?;
+    check_walk_factors(config.return_factor, config.in_out_factor)?;
+    let embeddings = py.allow_threads(|| node2vec_with_progress(graph, config, &progress));
+    progress.finish()?;
+    into_matrix(py, embeddings)
+}
+
+/// Converts the embeddings into a `(node_count, dimension)` matrix without
+/// copying the values.
+fn into_matrix<'py>(py: Python<'py>, embeddings: Embeddings) -> PyResult<&'py PyArray2<f32>> {
+    let shape = [embeddings.node_count(), embeddings.dimension()];
+    embeddings.into_vec().into_pyarray(py).reshape(shape)
+}
+
+fn check_dimension(embedding_dimension: usize) -> PyResult<()> {
+    if embedding_dimension == 0 {
+        return Err(PyValueError::new_err(
+            "embedding_dimension must be positive",
+        ));
+    }
+    Ok(())
+}
+
+fn check_walk_factors(return_factor: f64, in_out_factor: f64) -> PyResult<()> {
+    if !(return_factor > 0.0 && in_out_factor > 0.0) {
+        return Err(PyValueError::new_err(
+            "return_factor and in_out_factor must be positive",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,391 @@
+use super::{time, Layout, PyGraph};
+use ::graph::prelude::{
+    CsrLayout, DirectedCsrGraph, DirectedNeighbors, DirectedNeighborsWithValues,
+    Graph as GraphTrait, GraphBuilder, UndirectedCsrGraph, UndirectedNeighbors,
+};
+use numpy::{Element, PyArray1, PyReadonlyArray1};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+};
+use std::cmp::Ordering;
+
+/// A graph that can be created from a vector of edges.
+///
+/// The graph contains at least `node_count` nodes, additional nodes are
+/// added if the edges reference larger node ids.
+pub(super) trait FromEdgeVec<EV>: Sized {
+    fn from_edge_vec(edges: Vec<(u32, u32, EV)>, node_count: usize, layout: CsrLayout) -> Self;
+}
+
+macro_rules! impl_from_edge_vec {
+    ($graph:ty, $ev:ty) => {
+        impl FromEdgeVec<$ev> for $graph {
+            fn from_edge_vec(
+                edges: Vec<(u32, u32, $ev)>,
+                node_count: usize,
+                layout: CsrLayout,
+            ) -> Self {
+                GraphBuilder::new()
+                    .csr_layout(layout)
+                    .edges_with_values(edges)
+                    .node_values(std::iter::repeat(()).take(node_count))
+                    .build()
+            }
+        }
+    };
+}
+
+impl_from_edge_vec!(DirectedCsrGraph<u32>, ());
+impl_from_edge_vec!(UndirectedCsrGraph<u32>, ());
+impl_from_edge_vec!(DirectedCsrGraph<u32, (), f32>, f32);
+impl_from_edge_vec!(UndirectedCsrGraph<u32, (), f32>, f32);
+
+/// pymethods
+impl<G> PyGraph<u32, G> {
+    /// Create a graph from two arrays of equal length, containing the source
+    /// and target ids of each edge.
+    pub(super) fn from_arrays(
+        py: Python<'_>,
+        sources: &PyAny,
+        targets: &PyAny,
+        layout: Option<Layout>,
+    ) -> PyResult<Self>
+    where
+        G: FromEdgeVec<()> + Send,
+    {
+        let sources = contiguous_array::<u32>(py, sources, "sources")?;
+        let targets = contiguous_array::<u32>(py, targets, "targets")?;
+        let edges = zip_edges(sources.as_slice()?, targets.as_slice()?, |_| ())?;
+        Self::from_edge_vec(py, edges, 0, layout)
+    }
+
+    /// Create a graph from three arrays of equal length, containing the source
+    /// ids, target ids and weights of each edge.
+    pub(super) fn from_weighted_arrays(
+        py: Python<'_>,
+        sources: &PyAny,
+        targets: &PyAny,
+        weights: &PyAny,
+        layout: Option<Layout>,
+    ) -> PyResult<Self>
+    where
+        G: FromEdgeVec<f32> + Send,
+    {
+        let sources = contiguous_array::<u32>(py, sources, "sources")?;
+        let targets = contiguous_array::<u32>(py, targets, "targets")?;
+        let weights = contiguous_array::<f32>(py, weights, "weights")?;
+        let weights = weights.as_slice()?;
+        if weights.len() != sources.len() {
+            return Err(PyValueError::new_err(format!(
+                "weights has {} values, but there are {} edges",
+                weights.len(),
+                sources.len()
+            )));
+        }
+        let edges = zip_edges(sources.as_slice()?, targets.as_slice()?, |edge| {
+            weights[edge]
+        })?;
+        Self::from_edge_vec(py, edges, 0, layout)
+    }
+
+    /// Create a graph from a `scipy.sparse` CSR matrix, where row `i` contains
+    /// the outgoing edges of node `i`. The values of the matrix are ignored.
+    pub(super) fn from_scipy_csr(
+        py: Python<'_>,
+        matrix: &PyAny,
+        layout: Option<Layout>,
+    ) -> PyResult<Self>
+    where
+        G: FromEdgeVec<()> + Send,
+    {
+        let matrix = CsrMatrix::extract(py, matrix)?;
+        let edges = matrix.edges(|_| ())?;
+        Self::from_edge_vec(py, edges, matrix.node_count, layout)
+    }
+
+    /// Create a graph from a `scipy.sparse` CSR matrix, where row `i` contains
+    /// the outgoing edges of node `i` and the values of the matrix are used as
+    /// edge weights.
+    pub(super) fn from_weighted_scipy_csr(
+        py: Python<'_>,
+        matrix: &PyAny,
+        layout: Option<Layout>,
+    ) -> PyResult<Self>
+    where
+        G: FromEdgeVec<f32> + Send,
+    {
+        let data = contiguous_array::<f32>(py, matrix.getattr("data")?, "data")?;
+        let data = data.as_slice()?;
+        let matrix = CsrMatrix::extract(py, matrix)?;
+        if data.len() != matrix.indices.len() {
+            return Err(PyValueError::new_err(format!(
+                "data has {} values, but the matrix stores {} entries",
+                data.len(),
+                matrix.indices.len()
+            )));
+        }
+        let edges = matrix.edges(|position| data[position])?;
+        Self::from_edge_vec(py, edges, matrix.node_count, layout)
+    }
+
+    /// Create a graph from the `source` and `target` columns of a pandas
+    /// dataframe, defaulting to the first two columns.
+    pub(super) fn from_pandas(
+        py: Python<'_>,
+        data: &PyAny,
+        source: Option<&str>,
+        target: Option<&str>,
+        layout: Option<Layout>,
+    ) -> PyResult<Self>
+    where
+        G: FromEdgeVec<()> + Send,
+    {
+        let sources = column(data, source, 0)?;
+        let targets = column(data, target, 1)?;
+        Self::from_arrays(py, sources, targets, layout)
+    }
+
+    /// Create a graph from the `source`, `target` and `weight` columns of a
+    /// pandas dataframe, defaulting to the first three columns.
+    pub(super) fn from_weighted_pandas(
+        py: Python<'_>,
+        data: &PyAny,
+        source: Option<&str>,
+        target: Option<&str>,
+        weight: Option<&str>,
+        layout: Option<Layout>,
+    ) -> PyResult<Self>
+    where
+        G: FromEdgeVec<f32> + Send,
+    {
+        let sources = column(data, source, 0)?;
+        let targets = column(data, target, 1)?;
+        let weights = column(data, weight, 2)?;
+        Self::from_weighted_arrays(py, sources, targets, weights, layout)
+    }
+
+    pub(super) fn from_edge_vec<EV: Send>(
+        py: Python<'_>,
+        edges: Vec<(u32, u32, EV)>,
+        node_count: usize,
+        layout: Option<Layout>,
+    ) -> PyResult<Self>
+    where
+        G: FromEdgeVec<EV> + Send,
+    {
+        let max_node_id = edges
+            .iter()
+            .map(|&(source, target, _)| source.max(target) as usize + 1)
+            .max()
+            .unwrap_or_default();
+        let node_count = node_count.max(max_node_id);
+        if node_count == 0 {
+            return Err(PyValueError::new_err(
+                "Cannot create a graph without any nodes",
+            ));
+        }
+
+        let layout = layout.map(CsrLayout::from).unwrap_or_default();
+        let (graph, took) =
+            py.allow_threads(move || time(move || G::from_edge_vec(edges, node_count, layout)));
+        Ok(Self::new(took, graph))
+    }
+}
+
+impl<G> PyGraph<u32, G>
+where
+    G: GraphTrait<u32> + Sync,
+{
+    /// Returns the source and target ids of all edges in the graph.
+    pub(super) fn edge_arrays(&self, py: Python<'_>) -> (Vec<u32>, Vec<u32>)
+    where
+        G: DirectedNeighbors<u32>,
+    {
+        let g = self.g();
+        py.allow_threads(move || {
+            let edge_count = g.edge_count() as usize;
+            let mut sources = Vec::with_capacity(edge_count);
+            let mut targets = Vec::with_capacity(edge_count);
+            for source in 0..g.node_count() {
+                for &target in g.out_neighbors(source) {
+                    sources.push(source);
+                    targets.push(target);
+                }
+            }
+            (sources, targets)
+        })
+    }
+
+    /// Returns the source ids, target ids and weights of all edges in the graph.
+    pub(super) fn weighted_edge_arrays(&self, py: Python<'_>) -> (Vec<u32>, Vec<u32>, Vec<f32>)
+    where
+        G: DirectedNeighborsWithValues<u32, f32>,
+    {
+        let g = self.g();
+        py.allow_threads(move || {
+            let edge_count = g.edge_count() as usize;
+            let mut sources = Vec::with_capacity(edge_count);
+            let mut targets = Vec::with_capacity(edge_count);
+            let mut weights = Vec::with_capacity(edge_count);
+            for source in 0..g.node_count() {
+                for target in g.out_neighbors_with_values(source) {
+                    sources.push(source);
+                    targets.push(target.target);
+                    weights.push(target.value);
+                }
+            }
+            (sources, targets, weights)
+        })
+    }
+
+    /// Returns the ids of both nodes of all edges in the graph, where the
+    /// source id is never larger than the target id.
+    pub(super) fn undirected_edge_arrays(&self, py: Python<'_>) -> (Vec<u32>, Vec<u32>)
+    where
+        G: UndirectedNeighbors<u32>,
+    {
+        let g = self.g();
+        py.allow_threads(move || {
+            let edge_count = g.edge_count() as usize;
+            let mut sources = Vec::with_capacity(edge_count);
+            let mut targets = Vec::with_capacity(edge_count);
+            for source in 0..g.node_count() {
+                // Every edge is stored for both of its nodes, which means that
+                // self-loops appear twice in the neighbors of their node.
+                let mut self_loops = 0;
+                for &target in g.neighbors(source) {
+                    let keep = match target.cmp(&source) {
+                        Ordering::Greater => true,
+                        Ordering::Equal => {
+                            self_loops += 1;
+                            self_loops % 2 == 1
+                        }
+                        Ordering::Less => false,
+                    };
+                    if keep {
+                        sources.push(source);
+                        targets.push(target);
+                    }
+                }
+            }
+            (sources, targets)
+        })
+    }
+}
+
+/// Converts an array-like object into a contiguous numpy array of the given
+/// element type. The data is only copied if it is not already in that form.
+pub(crate) fn contiguous_array<'py, T: Element>(
+    py: Python<'py>,
+    array: &'py PyAny,
+    name: &str,
+) -> PyResult<PyReadonlyArray1<'py, T>> {
+    let array = py
+        .import("numpy")?
+        .call_method1("ascontiguousarray", (array, T::get_dtype(py)))?;
+    let array = array
+        .downcast::<PyArray1<T>>()
+        .map_err(|_| PyTypeError::new_err(format!("{name} must be a 1-dimensional array")))?;
+    Ok(array.readonly())
+}
+
+/// Returns the values of a dataframe column as a numpy array. The column is
+/// selected by its name or, if no name is given, by its position.
+fn column<'py>(data: &'py PyAny, name: Option<&str>, position: usize) -> PyResult<&'py PyAny> {
+    let column = match name {
+        Some(name) => data.get_item(name)?,
+        None => {
+            let columns = data.getattr("columns")?;
+            if columns.len()? <= position {
+                return Err(PyTypeError::new_err(format!(
+                    "Can only create a graph from a dataframe with at least {} columns",
+                    position + 1
+                )));
+            }
+            data.get_item(columns.get_item(position)?)?
+        }
+    };
+    column.call_method0("to_numpy")
+}
+
+fn zip_edges<EV>(
+    sources: &[u32],
+    targets: &[u32],
+    value: impl Fn(usize) -> EV,
+) -> PyResult<Vec<(u32, u32, EV)>> {
+    if sources.len() != targets.len() {
+        return Err(PyValueError::new_err(format!(
+            "sources and targets must have the same length, got {} and {}",
+            sources.len(),
+            targets.len()
+        )));
+    }
+    Ok(sources
+        .iter()
+        .zip(targets)
+        .enumerate()
+        .map(|(edge, (&source, &target))| (source, target, value(edge)))
+        .collect())
+}
+
+/// The index arrays of a `scipy.sparse.csr_matrix`.
+struct CsrMatrix<'py> {
+    indptr: PyReadonlyArray1<'py, u64>,
+    indices: PyReadonlyArray1<'py, u32>,
+    node_count: usize,
+}
+
+impl<'py> CsrMatrix<'py> {
+    fn extract(py: Python<'py>, matrix: &'py PyAny) -> PyResult<Self> {
+        let format = matrix
+            .getattr("format")
+            .and_then(|format| format.extract::<&str>())
+            .ok();
+        if format != Some("csr") {
+            return Err(PyTypeError::new_err(
+                "Expected a scipy.sparse matrix in CSR format, consider calling `tocsr()` first",
+            ));
+        }
+
+        let (rows, columns) = matrix.getattr("shape")?.extract::<(usize, usize)>()?;
+        let indptr = contiguous_array::<u64>(py, matrix.getattr("indptr")?, "indptr")?;
+        let indices = contiguous_array::<u32>(py, matrix.getattr("indices")?, "indices")?;
+
+        if indptr.len() != rows + 1 {
+            return Err(PyValueError::new_err(format!(
+                "indptr must have {} entries for a matrix with {} rows",
+                rows + 1,
+                rows
+            )));
+        }
+
+        Ok(Self {
+            indptr,
+            indices,
+            node_count: rows.max(columns),
+        })
+    }
+
+    /// Returns one edge for each stored entry of the matrix. `value` is called
+    /// with the position of the entry within the `data` array.
+    fn edges<EV>(&self, value: impl Fn(usize) -> EV) -> PyResult<Vec<(u32, u32, EV)>> {
+        let indptr = self.indptr.as_slice()?;
+        let indices = self.indices.as_slice()?;
+
+        let mut edges = Vec::with_capacity(indices.len());
+        for (source, row) in indptr.windows(2).enumerate() {
+            let (start, end) = (row[0] as usize, row[1] as usize);
+            if start > end || end > indices.len() {
+                return Err(PyValueError::new_err(format!(
+                    "indptr contains an invalid range for row {source}"
+                )));
+            }
+            for (position, &target) in indices[start..end].iter().enumerate() {
+                edges.push((source as u32, target, value(start + position)));
+            }
+        }
+
+        Ok(edges)
+    }
+}
@@ -0,0 +1,94 @@
+use super::{DiGraph, FileFormat, Graph, Layout, WeightedDiGraph, WeightedGraph};
+use ::graph::prelude::CsrLayout;
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+};
+use std::path::PathBuf;
+
+pub(crate) fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(load, m)?)?;
+    Ok(())
+}
+
+/// A `Layout` or its case-insensitive name, i.e., `"sorted"`, `"unsorted"`
+/// or `"deduplicated"`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LayoutArg(Layout);
+
+impl<'source> FromPyObject<'source> for LayoutArg {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        if let Ok(layout) = ob.extract::<Layout>() {
+            return Ok(Self(layout));
+        }
+        let name = ob
+            .extract::<&str>()
+            .map_err(|_| PyTypeError::new_err("layout must be a Layout or the name of a layout"))?;
+        let layout = match name.to_ascii_lowercase().as_str() {
+            "sorted" => Layout::Sorted,
+            "unsorted" => Layout::Unsorted,
+            "deduplicated" => Layout::Deduplicated,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                "unknown layout {name:?}, expected one of 'sorted', 'unsorted' or 'deduplicated'"
+            )))
+            }
+        };
+        Ok(Self(layout))
+    }
+}
+
+impl From<LayoutArg> for Layout {
+    fn from(layout: LayoutArg) -> Self {
+        layout.0
+    }
+}
+
+impl From<LayoutArg> for CsrLayout {
+    fn from(layout: LayoutArg) -> Self {
+        layout.0.into()
+    }
+}
+
+/// Load a graph from a file, similar to the `GraphBuilder` in Rust.
+///
+/// The type of the returned graph depends on the `directed` and `weighted`
+/// options. Weighted graphs can only be loaded from edge lists, which is
+/// also the default file format for them, unweighted graphs default to the
+/// Graph500 format.
+#[pyfunction]
+#[args(
+    "*",
+    file_format = "None",
+    layout = "None",
+    directed = "true",
+    weighted = "false"
+)]
+pub fn load(
+    py: Python<'_>,
+    path: PathBuf,
+    file_format: Option<FileFormat>,
+    layout: Option<LayoutArg>,
+    directed: bool,
+    weighted: bool,
+) -> PyResult<PyObject> {
+    if !weighted {
+        let file_format = file_format.unwrap_or(FileFormat::Graph500);
+        return if directed {
+            Ok(DiGraph::load(py, path, layout, file_format)?.into_py(py))
+        } else {
+            Ok(Graph::load(py, path, layout, file_format)?.into_py(py))
+        };
+    }
+
+    if let Some(FileFormat::Graph500) = file_format {
+        return Err(PyValueError::new_err(
+            "Graph500 files do not contain edge weights, use an edge list instead",
+        ));
+    }
+    if directed {
+        Ok(WeightedDiGraph::load(py, path, layout)?.into_py(py))
+    } else {
+        Ok(WeightedGraph::load(py, path, layout)?.into_py(py))
+    }
+}
@@ -0,0 +1,223 @@
+use super::{builder::LayoutArg, DiGraph, FileFormat, Graph, WeightedDiGraph, WeightedGraph};
+use pyo3::{
+    exceptions::{PyKeyError, PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyDict, PyList, PyTuple},
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+pub(crate) fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<Catalog>()?;
+    Ok(())
+}
+
+/// The graph methods that can be run by name via `Catalog.run`.
+const ALGORITHMS: [&str; 12] = [
+    "page_rank",
+    "wcc",
+    "sssp",
+    "dijkstra",
+    "astar",
+    "triangle_count",
+    "louvain",
+    "label_propagation",
+    "random_walks",
+    "fast_rp",
+    "node2vec",
+    "stats",
+];
+
+/// A collection of named graphs, e.g., to manage several datasets in a
+/// long-lived notebook kernel.
+///
+/// Graphs are kept alive as long as they are in the catalog or referenced
+/// elsewhere, so dropping a graph only frees its memory once there are no
+/// other references to it.
+#[pyclass]
+#[derive(Default)]
+pub struct Catalog {
+    graphs: BTreeMap<String, PyObject>,
+}
+
+#[pymethods]
+impl Catalog {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a graph from a file and add it under the given name, see `load`
+    /// for the options.
+    #[allow(clippy::too_many_arguments)]
+    #[args(
+        "*",
+        file_format = "None",
+        layout = "None",
+        directed = "true",
+        weighted = "false",
+        replace = "false"
+    )]
+    pub fn load(
+        &mut self,
+        py: Python<'_>,
+        name: String,
+        path: PathBuf,
+        file_format: Option<FileFormat>,
+        layout: Option<LayoutArg>,
+        directed: bool,
+        weighted: bool,
+        replace: bool,
+    ) -> PyResult<PyObject> {
+        self.check_name(&name, replace)?;
+        let graph = super::builder::load(py, path, file_format, layout, directed, weighted)?;
+        self.graphs.insert(name, graph.clone_ref(py));
+        Ok(graph)
+    }
+
+    /// Add a graph under the given name.
+    ///
+    /// Adding a graph under an existing name fails, unless `replace` is set.
+    #[args("*", replace = "false")]
+    pub fn add(&mut self, name: String, graph: &PyAny, replace: bool) -> PyResult<()> {
+        if !is_graph(graph)? {
+            return Err(PyTypeError::new_err(format!(
+                "Expected a graph, got {}",
+                graph.get_type().name()?
+            )));
+        }
+        self.check_name(&name, replace)?;
+        self.graphs.insert(name, graph.into());
+        Ok(())
+    }
+
+    /// Returns the graph with the given name.
+    pub fn get(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        self.graphs
+            .get(name)
+            .map(|graph| graph.clone_ref(py))
+            .ok_or_else(|| missing_graph(name))
+    }
+
+    /// Removes the graph with the given name from the catalog and returns it.
+    pub fn drop(&mut self, name: &str) -> PyResult<PyObject> {
+        self.graphs.remove(name).ok_or_else(|| missing_graph(name))
+    }
+
+    /// Removes all graphs from the catalog.
+    pub fn clear(&mut self) {
+        self.graphs.clear();
+    }
+
+    /// Returns the names of all graphs in ascending order.
+    pub fn names(&self) -> Vec<String> {
+        self.graphs.keys().cloned().collect()
+    }
+
+    /// Returns a list with one dict per graph in ascending order of their
+    /// names, containing the `name`, the `type`, the `node_count`, the
+    /// `edge_count` and the `memory_bytes` of the graph.
+    pub fn list<'py>(&self, py: Python<'py>) -> PyResult<&'py PyList> {
+        let list = PyList::empty(py);
+        for (name, graph) in &self.graphs {
+            let graph = graph.as_ref(py);
+            let stats = graph.call_method0("stats")?;
+            let info = PyDict::new(py);
+            info.set_item("name", name)?;
+            info.set_item("type", graph.get_type().name()?)?;
+            for key in ["node_count", "edge_count", "memory_bytes"] {
+                info.set_item(key, stats.get_item(key)?)?;
+            }
+            list.append(info)?;
+        }
+        Ok(list)
+    }
+
+    /// Returns the number of bytes used by all graphs in the catalog.
+    pub fn memory_bytes(&self, py: Python<'_>) -> PyResult<usize> {
+        self.graphs.values().try_fold(0, |total, graph| {
+            let stats = graph.as_ref(py).call_method0("stats")?;
+            Ok(total + stats.get_item("memory_bytes")?.extract::<usize>()?)
+        })
+    }
+
+    /// Run an algorithm on the graph with the given name.
+    ///
+    /// The algorithm is the name of a graph method, e.g., `"page_rank"`, and
+    /// is called with the remaining arguments.
+    #[args(args = "*", kwargs = "**")]
+    pub fn run(
+        &self,
+        py: Python<'_>,
+        name: &str,
+        algorithm: &str,
+        args: &PyTuple,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<PyObject> {
+        let graph = self.get(py, name)?;
+        let graph = graph.as_ref(py);
+        if !ALGORITHMS.contains(&algorithm) {
+            return Err(PyValueError::new_err(format!(
+                "Unknown algorithm {algorithm:?}, expected one of {ALGORITHMS:?}"
+            )));
+        }
+        if !graph.hasattr(algorithm)? {
+            return Err(PyValueError::new_err(format!(
+                "{} does not support {algorithm}",
+                graph.get_type().name()?
+            )));
+        }
+        Ok(graph.call_method(algorithm, args, kwargs)?.into())
+    }
+
+    pub fn __getitem__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        self.get(py, name)
+    }
+
+    pub fn __setitem__(&mut self, name: String, graph: &PyAny) -> PyResult<()> {
+        self.add(name, graph, true)
+    }
+
+    pub fn __delitem__(&mut self, name: &str) -> PyResult<()> {
+        self.drop(name).map(|_| ())
+    }
+
+    pub fn __contains__(&self, name: &str) -> bool {
+        self.graphs.contains_key(name)
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.graphs.len()
+    }
+
+    pub fn __iter__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(PyList::new(py, self.graphs.keys())
+            .call_method0("__iter__")?
+            .into())
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Catalog({:?})", self.names())
+    }
+}
+
+impl Catalog {
+    fn check_name(&self, name: &str, replace: bool) -> PyResult<()> {
+        if !replace && self.graphs.contains_key(name) {
+            return Err(PyValueError::new_err(format!(
+                "A graph named {name:?} already exists, use `replace=True` to replace it"
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn is_graph(graph: &PyAny) -> PyResult<bool> {
+    Ok(graph.is_instance_of::<DiGraph>()?
+        || graph.is_instance_of::<Graph>()?
+        || graph.is_instance_of::<WeightedDiGraph>()?
+        || graph.is_instance_of::<WeightedGraph>()?)
+}
+
+fn missing_graph(name: &str) -> PyErr {
+    PyKeyError::new_err(format!("No graph named {name:?}"))
+}
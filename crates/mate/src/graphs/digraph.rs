@@ -1,12 +1,28 @@
-use super::{FileFormat, Graph, Layout, PyGraph};
-use crate::{page_rank::PageRankResult, wcc::WccResult};
+use super::{
+    arrays::contiguous_array,
+    labels,
+    networkx::{self, Nodes},
+    persistence::GraphKind,
+    subgraph, FileFormat, Graph, Layout, LayoutArg, PyGraph,
+};
+use crate::{
+    hyperball::HyperBallResult, landmarks::LandmarkIndex, page_rank::PageRankResult,
+    progress::PyProgress, reachability::ReachabilityIndex, sssp::SsspResult, wcc::WccResult,
+};
 use graph::{
     page_rank::PageRankConfig,
-    prelude::{CsrLayout, DirectedCsrGraph},
+    prelude::{
+        CsrLayout, DeltaSteppingConfig, DescendantsConfig, DirectedCsrGraph, FastRpConfig,
+        HyperBallConfig, LandmarkConfig, Node2VecConfig, RandomWalkConfig, ReachabilityConfig,
+        SamplingConfig,
+    },
     wcc::WccConfig,
 };
-use numpy::{PyArray1, PyArray2};
-use pyo3::{prelude::*, types::PyList};
+use numpy::{IntoPyArray, PyArray1, PyArray2};
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyDict, PyList},
+};
 use std::path::PathBuf;
 
 pub(crate) fn register(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -19,11 +35,36 @@ pub struct DiGraph {
     inner: PyGraph<u32, DirectedCsrGraph<u32>>,
     #[pyo3(get)]
     load_micros: u64,
+    /// The node labels, where node `i` has the label at position `i`, e.g.,
+    /// the original nodes if the graph was created from NetworkX or node
+    /// types. Can be set to any sequence with one label per node.
+    #[pyo3(get)]
+    node_labels: Option<Py<PyList>>,
 }
 
 impl DiGraph {
     fn new(load_micros: u64, inner: PyGraph<u32, DirectedCsrGraph<u32>>) -> Self {
-        Self { inner, load_micros }
+        Self {
+            inner,
+            load_micros,
+            node_labels: None,
+        }
+    }
+
+    fn with_labels(mut self, node_labels: Py<PyList>) -> Self {
+        self.node_labels = Some(node_labels);
+        self
+    }
+
+    /// Creates a graph from the given edges, e.g., of a generated graph.
+    pub(super) fn from_edge_vec(
+        py: Python<'_>,
+        edges: Vec<(u32, u32, ())>,
+        node_count: usize,
+        layout: Option<Layout>,
+    ) -> PyResult<Self> {
+        let g = PyGraph::from_edge_vec(py, edges, node_count, layout)?;
+        Ok(Self::new(g.load_micros, g))
     }
 }
 
@@ -36,29 +77,87 @@ impl DiGraph {
     pub fn load(
         py: Python<'_>,
         path: PathBuf,
-        layout: Option<Layout>,
+        layout: Option<LayoutArg>,
         file_format: FileFormat,
     ) -> PyResult<Self> {
-        let g = PyGraph::load_file(py, path, layout, file_format)?;
+        let g = PyGraph::load_file(py, path, layout.map(Layout::from), file_format)?;
         Ok(Self::new(g.load_micros, g))
     }
 
     /// Convert a numpy 2d-array into a graph.
     #[staticmethod]
     #[args(layout = "None")]
-    pub fn from_numpy(np: &PyArray2<u32>, layout: Option<Layout>) -> PyResult<Self> {
-        let g = PyGraph::from_numpy(np, layout)?;
+    pub fn from_numpy(np: &PyArray2<u32>, layout: Option<LayoutArg>) -> PyResult<Self> {
+        let g = PyGraph::from_numpy(np, layout.map(Layout::from))?;
         Ok(Self::new(g.load_micros, g))
     }
 
     /// Convert a pandas dataframe into a graph.
+    ///
+    /// The `source` and `target` columns contain the node ids of each edge.
+    /// If they are not given, the first two columns of the dataframe are used.
+    #[staticmethod]
+    #[args(layout = "None", "*", source = "None", target = "None")]
+    pub fn from_pandas(
+        py: Python<'_>,
+        data: &PyAny,
+        layout: Option<LayoutArg>,
+        source: Option<&str>,
+        target: Option<&str>,
+    ) -> PyResult<Self> {
+        let g = PyGraph::from_pandas(py, data, source, target, layout.map(Layout::from))?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Create a graph from two arrays containing the source and target ids
+    /// of each edge.
+    ///
+    /// The arrays are converted to `uint32` numpy arrays, which does not copy
+    /// the data if they already have that type.
     #[staticmethod]
     #[args(layout = "None")]
-    pub fn from_pandas(py: Python<'_>, data: PyObject, layout: Option<Layout>) -> PyResult<Self> {
-        let g = PyGraph::from_pandas(py, data, layout)?;
+    pub fn from_arrays(
+        py: Python<'_>,
+        sources: &PyAny,
+        targets: &PyAny,
+        layout: Option<LayoutArg>,
+    ) -> PyResult<Self> {
+        let g = PyGraph::from_arrays(py, sources, targets, layout.map(Layout::from))?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Create a graph from a `scipy.sparse` CSR matrix, where row `i` contains
+    /// the outgoing edges of node `i`. The values of the matrix are ignored.
+    #[staticmethod]
+    #[args(layout = "None")]
+    pub fn from_scipy_csr(
+        py: Python<'_>,
+        matrix: &PyAny,
+        layout: Option<LayoutArg>,
+    ) -> PyResult<Self> {
+        let g = PyGraph::from_scipy_csr(py, matrix, layout.map(Layout::from))?;
         Ok(Self::new(g.load_micros, g))
     }
 
+    /// Convert a NetworkX graph into a graph.
+    ///
+    /// Nodes are relabeled to consecutive ids in the order of `graph.nodes`,
+    /// the original labels are available as `node_labels`.
+    ///
+    /// Edges of an undirected NetworkX graph are added in both directions.
+    #[staticmethod]
+    #[args(layout = "None")]
+    pub fn from_networkx(
+        py: Python<'_>,
+        graph: &PyAny,
+        layout: Option<LayoutArg>,
+    ) -> PyResult<Self> {
+        let nodes = Nodes::read(py, graph)?;
+        let edges = nodes.edges(graph, true)?;
+        let g = PyGraph::from_edge_vec(py, edges, nodes.count(), layout.map(Layout::from))?;
+        Ok(Self::new(g.load_micros, g).with_labels(nodes.into_labels()))
+    }
+
     /// Returns the number of nodes in the graph.
     pub fn node_count(&self) -> u32 {
         self.inner.node_count()
@@ -113,22 +212,243 @@ impl DiGraph {
         self.inner.copy_in_neighbors(py, node)
     }
 
+    /// Returns all edges of this graph as a pandas dataframe with the
+    /// columns `src` and `dst`.
+    pub fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let (sources, targets) = self.inner.edge_arrays(py);
+        crate::pandas::edge_frame(py, sources, targets, None)
+    }
+
+    /// Convert this graph into a `networkx.DiGraph`, using the original node
+    /// labels if there are any.
+    ///
+    /// `node_attributes` maps attribute names to sequences with one value per
+    /// node, e.g., algorithm results, which are copied onto the nodes.
+    #[args(node_attributes = "None")]
+    pub fn to_networkx(
+        &self,
+        py: Python<'_>,
+        node_attributes: Option<&PyDict>,
+    ) -> PyResult<PyObject> {
+        let (sources, targets) = self.inner.edge_arrays(py);
+        networkx::to_networkx(
+            py,
+            "DiGraph",
+            self.node_labels.as_ref(),
+            self.node_count(),
+            sources,
+            targets,
+            None,
+            node_attributes,
+        )
+    }
+
+    /// Returns summary statistics of this graph as a dict, i.e., the node
+    /// and edge counts, the density, the minimum, maximum, mean and 50th, 90th
+    /// and 99th percentile of the out-degrees and in-degrees, and the number of bytes used
+    /// to store the graph.
+    pub fn stats<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        self.inner.stats(py)
+    }
+
     pub fn __repr__(&self) -> String {
         self.inner.__repr__()
     }
 
+    /// Returns a binary snapshot of this graph, which can be turned back into
+    /// a graph with `from_bytes`.
+    ///
+    /// `node_labels` are not part of the snapshot.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        self.inner.to_bytes(py, GraphKind::DiGraph)
+    }
+
+    /// Create a graph from a binary snapshot created by `to_bytes`.
+    ///
+    /// Only use snapshots from trusted sources, the data is not validated
+    /// beyond its header.
+    #[staticmethod]
+    pub fn from_bytes(py: Python<'_>, data: &[u8]) -> PyResult<Self> {
+        let g = PyGraph::from_bytes(py, data, GraphKind::DiGraph)?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Write a binary snapshot of this graph to the given file, which can be
+    /// loaded much faster than the original input with `load_binary`.
+    ///
+    /// `node_labels` are not part of the snapshot.
+    pub fn save(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        self.inner.save(py, path, GraphKind::DiGraph)
+    }
+
+    /// Load a graph from a binary snapshot file written by `save`.
+    ///
+    /// Only use snapshots from trusted sources, the data is not validated
+    /// beyond its header.
+    #[staticmethod]
+    pub fn load_binary(py: Python<'_>, path: PathBuf) -> PyResult<Self> {
+        let g = PyGraph::load_binary(py, path, GraphKind::DiGraph)?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Returns the state for `pickle`, which is the binary snapshot of the
+    /// graph together with its node labels.
+    pub fn __reduce__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(PyObject, (&'py PyBytes,), Option<Py<PyList>>)> {
+        let from_bytes = py.get_type::<Self>().getattr("from_bytes")?.into();
+        Ok((from_bytes, (self.to_bytes(py)?,), self.__getstate__()))
+    }
+
+    /// Returns the node labels, which `pickle` stores next to the binary
+    /// snapshot.
+    pub fn __getstate__(&self) -> Option<Py<PyList>> {
+        self.node_labels.clone()
+    }
+
+    /// Restores the node labels after unpickling.
+    pub fn __setstate__(&mut self, node_labels: Option<Py<PyList>>) {
+        self.node_labels = node_labels;
+    }
+
+    #[setter]
+    pub fn set_node_labels(&mut self, py: Python<'_>, node_labels: Option<&PyAny>) -> PyResult<()> {
+        self.node_labels = labels::validate_labels(py, node_labels, self.node_count())?;
+        Ok(())
+    }
+
+    /// Returns the subgraph induced by the given nodes, i.e., the nodes and
+    /// all edges between them, together with a numpy array that contains the
+    /// original id of each subgraph node.
+    ///
+    /// Subgraph nodes keep the relative order of their original ids.
+    pub fn subgraph<'py>(
+        &self,
+        py: Python<'py>,
+        nodes: &PyAny,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let (g, mapping) = self.inner.induced_subgraph(py, nodes)?;
+        let mut subgraph = Self::new(g.load_micros, g);
+        subgraph.node_labels = subgraph::subgraph_labels(py, self.node_labels.as_ref(), &mapping)?;
+        Ok((subgraph, mapping.into_pyarray(py)))
+    }
+
+    /// Returns a sample of this graph, i.e., the subgraph induced by a
+    /// `fraction` of its nodes, together with a numpy array that contains the
+    /// original id of each subgraph node.
+    ///
+    /// `method` is one of `"node"` and `"edge"`, which select nodes or the
+    /// end nodes of edges uniformly at random, `"random_walk"`, which selects
+    /// the nodes visited by random walks along outgoing edges that return to
+    /// their start node with `restart_probability`, or `"forest_fire"`, which
+    /// spreads a fire from random nodes that ignites about
+    /// `burn_probability / (1 - burn_probability)` neighbors per node.
+    #[args(
+        fraction = "SamplingConfig::DEFAULT_FRACTION",
+        "*",
+        method = "\"random_walk\"",
+        seed = "SamplingConfig::DEFAULT_SEED",
+        restart_probability = "SamplingConfig::DEFAULT_RESTART_PROBABILITY",
+        burn_probability = "SamplingConfig::DEFAULT_BURN_PROBABILITY"
+    )]
+    pub fn sample<'py>(
+        &self,
+        py: Python<'py>,
+        fraction: f64,
+        method: &str,
+        seed: u64,
+        restart_probability: f64,
+        burn_probability: f64,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let nodes = self.inner.sample_nodes(
+            py,
+            fraction,
+            method,
+            seed,
+            restart_probability,
+            burn_probability,
+        )?;
+        self.subgraph(py, nodes.into_pyarray(py))
+    }
+
+    /// Returns the subgraph induced by all nodes that can be reached from
+    /// `node` by following at most `radius` outgoing edges, together with a numpy
+    /// array that contains the original id of each subgraph node.
+    ///
+    /// `node` always becomes node `0` of the subgraph.
+    #[args(radius = "1")]
+    pub fn ego<'py>(
+        &self,
+        py: Python<'py>,
+        node: u32,
+        radius: usize,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let (g, mapping) = self.inner.ego_subgraph(py, node, radius)?;
+        let mut subgraph = Self::new(g.load_micros, g);
+        subgraph.node_labels = subgraph::subgraph_labels(py, self.node_labels.as_ref(), &mapping)?;
+        Ok((subgraph, mapping.into_pyarray(py)))
+    }
+
+    /// Returns the label of the given node.
+    pub fn label(&self, py: Python<'_>, node: u32) -> PyResult<PyObject> {
+        labels::label(py, self.node_labels.as_ref(), node)
+    }
+
+    /// Returns all nodes with a label equal to the given label as a numpy
+    /// array in ascending order.
+    pub fn nodes_with_label<'py>(
+        &self,
+        py: Python<'py>,
+        label: &PyAny,
+    ) -> PyResult<&'py PyArray1<u32>> {
+        let nodes = labels::nodes_with_label(py, self.node_labels.as_ref(), label)?;
+        Ok(nodes.into_pyarray(py))
+    }
+
+    /// Returns a dict that maps each label to the number of nodes with that
+    /// label.
+    pub fn label_counts<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        labels::label_counts(py, self.node_labels.as_ref())
+    }
+
+    /// Returns the subgraph induced by all nodes with one of the given labels,
+    /// together with a numpy array that contains the original id of each
+    /// subgraph node.
+    pub fn subgraph_with_labels<'py>(
+        &self,
+        py: Python<'py>,
+        labels: &PyAny,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let nodes = labels::nodes_with_any_label(py, self.node_labels.as_ref(), labels)?;
+        self.subgraph(py, nodes.into_pyarray(py))
+    }
+
     #[args(layout = "None")]
-    pub fn to_undirected(&self, layout: Option<Layout>) -> Graph {
+    pub fn to_undirected(&self, layout: Option<LayoutArg>) -> Graph {
         let g = self.inner.to_undirected(layout.map(CsrLayout::from));
-        Graph::new(g.load_micros, g)
+        let graph = Graph::new(g.load_micros, g);
+        match &self.node_labels {
+            Some(labels) => graph.with_labels(labels.clone()),
+            None => graph,
+        }
     }
 
     /// Run Page Rank on this graph.
+    ///
+    /// If given, the computation starts from `initial_scores` instead of the
+    /// uniform distribution, e.g., the scores of a previous run on a slightly
+    /// changed graph, which needs fewer iterations to converge.
+    ///
+    /// If given, `progress` is called as `progress(task, completed, total)`
+    /// after each iteration.
     #[args(
         "*",
         max_iterations = "PageRankConfig::DEFAULT_MAX_ITERATIONS",
         tolerance = "PageRankConfig::DEFAULT_TOLERANCE",
-        damping_factor = "PageRankConfig::DEFAULT_DAMPING_FACTOR"
+        damping_factor = "PageRankConfig::DEFAULT_DAMPING_FACTOR",
+        initial_scores = "None",
+        progress = "None"
     )]
     pub fn page_rank(
         &self,
@@ -136,17 +456,33 @@ impl DiGraph {
         max_iterations: usize,
         tolerance: f64,
         damping_factor: f32,
-    ) -> PageRankResult {
+        initial_scores: Option<&PyAny>,
+        progress: Option<PyObject>,
+    ) -> PyResult<PageRankResult> {
         let config = PageRankConfig::new(max_iterations, tolerance, damping_factor);
-        crate::page_rank::page_rank(py, self.inner.g(), config)
+        let initial_scores = match initial_scores {
+            Some(scores) => Some(
+                contiguous_array::<f32>(py, scores, "initial_scores")?
+                    .as_slice()?
+                    .to_vec(),
+            ),
+            None => None,
+        };
+        let progress = PyProgress::new(py, progress)?;
+        crate::page_rank::page_rank(py, self.inner.g(), config, initial_scores, progress)
     }
 
     /// Run Weakly Connected Compontents on this graph.
+    ///
+    /// If given, the computation starts from `initial_components`, e.g., the
+    /// components of a previous run on this graph before edges were added.
+    /// Nodes that share an initial component must be connected.
     #[args(
         "*",
         chunk_size = "WccConfig::DEFAULT_CHUNK_SIZE",
         neighbor_rounds = "WccConfig::DEFAULT_NEIGHBOR_ROUNDS",
-        sampling_size = "WccConfig::DEFAULT_SAMPLING_SIZE"
+        sampling_size = "WccConfig::DEFAULT_SAMPLING_SIZE",
+        initial_components = "None"
     )]
     pub fn wcc(
         &self,
@@ -154,9 +490,252 @@ impl DiGraph {
         chunk_size: usize,
         neighbor_rounds: usize,
         sampling_size: usize,
-    ) -> WccResult {
+        initial_components: Option<&PyAny>,
+    ) -> PyResult<WccResult> {
         let config = WccConfig::new(chunk_size, neighbor_rounds, sampling_size);
-        WccResult::new(crate::wcc::wcc(py, self.inner.g(), config))
+        let initial_components = match initial_components {
+            Some(components) => Some(
+                contiguous_array::<u32>(py, components, "initial_components")?
+                    .as_slice()?
+                    .to_vec(),
+            ),
+            None => None,
+        };
+        let result = crate::wcc::wcc(py, self.inner.g(), config, initial_components)?;
+        Ok(WccResult::new(result))
+    }
+
+    /// Run Single Source Shortest Path on this graph using delta-stepping.
+    ///
+    /// Every edge has a weight of `1`, so distances are the number of hops
+    /// from the start node.
+    pub fn sssp(&self, py: Python<'_>, start_node: u32, delta: f32) -> PyResult<SsspResult> {
+        let config = DeltaSteppingConfig::new(start_node as usize, delta);
+        crate::sssp::sssp(py, self.inner.g(), config)
+    }
+
+    /// Compute a path with the fewest edges from `source_node` to
+    /// `target_node`, following outgoing edges, using a bidirectional
+    /// breadth-first search.
+    ///
+    /// Returns the number of edges on the path and the nodes on it, including
+    /// both end nodes, or `None` if `target_node` is not reachable. Only the
+    /// nodes around the path are visited, which is much faster than
+    /// computing all distances for a single pair of nodes.
+    pub fn shortest_path<'py>(
+        &self,
+        py: Python<'py>,
+        source_node: u32,
+        target_node: u32,
+    ) -> PyResult<Option<(f32, &'py PyArray1<u32>)>> {
+        crate::sssp::pair_path(py, self.inner.g(), source_node, target_node)
+    }
+
+    /// Build a landmark index, which bounds the distance between any two
+    /// nodes, following outgoing edges, in time independent of the graph size.
+    ///
+    /// `selection` is either `"farthest"`, which chooses each landmark as the
+    /// node that is farthest from the previous landmarks, or `"random"`.
+    #[args(
+        landmark_count = "LandmarkConfig::DEFAULT_LANDMARK_COUNT",
+        "*",
+        selection = "\"farthest\"",
+        seed = "LandmarkConfig::DEFAULT_SEED"
+    )]
+    pub fn landmark_index(
+        &self,
+        py: Python<'_>,
+        landmark_count: usize,
+        selection: &str,
+        seed: u64,
+    ) -> PyResult<LandmarkIndex> {
+        let config = crate::landmarks::config(landmark_count, selection, seed)?;
+        Ok(crate::landmarks::landmark_index(py, self.inner.g(), config))
+    }
+
+    /// Run HyperBall, which estimates the neighborhood function and the
+    /// closeness and harmonic centrality of each node, following outgoing
+    /// edges, with HyperLogLog counters of `2^precision` registers per node.
+    #[args(
+        "*",
+        precision = "HyperBallConfig::DEFAULT_PRECISION",
+        max_iterations = "HyperBallConfig::DEFAULT_MAX_ITERATIONS"
+    )]
+    pub fn hyperball(
+        &self,
+        py: Python<'_>,
+        precision: u8,
+        max_iterations: usize,
+    ) -> PyResult<HyperBallResult> {
+        let config = HyperBallConfig::new(precision, max_iterations);
+        crate::hyperball::hyperball(py, self.inner.g(), config)
+    }
+
+    /// Build a reachability index, which answers whether a node can reach
+    /// another node by following outgoing edges, mostly in constant time.
+    ///
+    /// More intervals per strongly connected component (`label_count`)
+    /// answer more queries without searching the graph.
+    #[args(
+        label_count = "ReachabilityConfig::DEFAULT_LABEL_COUNT",
+        "*",
+        seed = "ReachabilityConfig::DEFAULT_SEED"
+    )]
+    pub fn reachability_index(
+        &self,
+        py: Python<'_>,
+        label_count: usize,
+        seed: u64,
+    ) -> ReachabilityIndex {
+        let config = ReachabilityConfig::new(label_count, seed);
+        crate::reachability::reachability_index(py, self.inner.g(), config)
+    }
+
+    /// Count the nodes that each node can reach by following outgoing edges,
+    /// excluding the node itself.
+    ///
+    /// The counts are exact if the graph has at most `exact_threshold`
+    /// strongly connected components and estimated with HyperLogLog counters
+    /// of `2^precision` registers otherwise.
+    #[args(
+        "*",
+        exact_threshold = "DescendantsConfig::DEFAULT_EXACT_THRESHOLD",
+        precision = "DescendantsConfig::DEFAULT_PRECISION"
+    )]
+    pub fn descendant_counts<'py>(
+        &self,
+        py: Python<'py>,
+        exact_threshold: usize,
+        precision: u8,
+    ) -> PyResult<&'py PyArray1<f64>> {
+        let config = DescendantsConfig::new(exact_threshold, precision);
+        crate::reachability::descendant_counts(py, self.inner.g(), config)
+    }
+
+    /// Compute `walks_per_node` random walks starting at each node, which
+    /// follow outgoing edges.
+    ///
+    /// Returns a list of `uint32` numpy arrays, where the first `node_count`
+    /// walks are the first walks of each node in node order. Walks contain
+    /// at most `walk_length` nodes and end early at nodes without neighbors.
+    /// `return_factor` and `in_out_factor` are the node2vec parameters `p`
+    /// and `q` that bias the choice of the next node.
+    #[args(
+        "*",
+        walk_length = "RandomWalkConfig::DEFAULT_WALK_LENGTH",
+        walks_per_node = "RandomWalkConfig::DEFAULT_WALKS_PER_NODE",
+        return_factor = "RandomWalkConfig::DEFAULT_RETURN_FACTOR",
+        in_out_factor = "RandomWalkConfig::DEFAULT_IN_OUT_FACTOR",
+        seed = "RandomWalkConfig::DEFAULT_SEED"
+    )]
+    pub fn random_walks<'py>(
+        &self,
+        py: Python<'py>,
+        walk_length: usize,
+        walks_per_node: usize,
+        return_factor: f64,
+        in_out_factor: f64,
+        seed: u64,
+    ) -> PyResult<&'py PyList> {
+        let config = RandomWalkConfig {
+            walk_length,
+            walks_per_node,
+            return_factor,
+            in_out_factor,
+            seed,
+        };
+        crate::embeddings::random_walks(py, self.inner.g(), config)
+    }
+
+    /// Compute FastRP node embeddings, which are propagated along
+    /// outgoing edges.
+    ///
+    /// Returns a `float32` numpy array of shape `(node_count,
+    /// embedding_dimension)`, where row `i` is the embedding of node `i`.
+    ///
+    /// If given, `progress` is called as `progress(task, completed, total)`
+    /// after each iteration.
+    #[args(
+        "*",
+        embedding_dimension = "FastRpConfig::DEFAULT_EMBEDDING_DIMENSION",
+        iterations = "FastRpConfig::DEFAULT_ITERATIONS",
+        normalization_strength = "FastRpConfig::DEFAULT_NORMALIZATION_STRENGTH",
+        seed = "FastRpConfig::DEFAULT_SEED",
+        progress = "None"
+    )]
+    pub fn fast_rp<'py>(
+        &self,
+        py: Python<'py>,
+        embedding_dimension: usize,
+        iterations: usize,
+        normalization_strength: f32,
+        seed: u64,
+        progress: Option<PyObject>,
+    ) -> PyResult<&'py PyArray2<f32>> {
+        let config = FastRpConfig {
+            embedding_dimension,
+            iterations,
+            normalization_strength,
+            seed,
+        };
+        let progress = PyProgress::new(py, progress)?;
+        crate::embeddings::fast_rp(py, self.inner.g(), config, progress)
+    }
+
+    /// Compute Node2Vec node embeddings by training a skip-gram model on
+    /// random walks, which follow outgoing edges.
+    ///
+    /// Returns a `float32` numpy array of shape `(node_count,
+    /// embedding_dimension)`, where row `i` is the embedding of node `i`.
+    /// The training runs on a single thread, so that the result only depends
+    /// on the graph and the `seed`.
+    ///
+    /// If given, `progress` is called as `progress(task, completed, total)`
+    /// with the number of walks that have been trained on.
+    #[allow(clippy::too_many_arguments)]
+    #[args(
+        "*",
+        embedding_dimension = "Node2VecConfig::DEFAULT_EMBEDDING_DIMENSION",
+        walk_length = "Node2VecConfig::DEFAULT_WALK_LENGTH",
+        walks_per_node = "Node2VecConfig::DEFAULT_WALKS_PER_NODE",
+        return_factor = "Node2VecConfig::DEFAULT_RETURN_FACTOR",
+        in_out_factor = "Node2VecConfig::DEFAULT_IN_OUT_FACTOR",
+        window_size = "Node2VecConfig::DEFAULT_WINDOW_SIZE",
+        negative_samples = "Node2VecConfig::DEFAULT_NEGATIVE_SAMPLES",
+        iterations = "Node2VecConfig::DEFAULT_ITERATIONS",
+        learning_rate = "Node2VecConfig::DEFAULT_LEARNING_RATE",
+        seed = "Node2VecConfig::DEFAULT_SEED",
+        progress = "None"
+    )]
+    pub fn node2vec<'py>(
+        &self,
+        py: Python<'py>,
+        embedding_dimension: usize,
+        walk_length: usize,
+        walks_per_node: usize,
+        return_factor: f64,
+        in_out_factor: f64,
+        window_size: usize,
+        negative_samples: usize,
+        iterations: usize,
+        learning_rate: f32,
+        seed: u64,
+        progress: Option<PyObject>,
+    ) -> PyResult<&'py PyArray2<f32>> {
+        let config = Node2VecConfig {
+            embedding_dimension,
+            walk_length,
+            walks_per_node,
+            return_factor,
+            in_out_factor,
+            window_size,
+            negative_samples,
+            iterations,
+            learning_rate,
+            seed,
+        };
+        let progress = PyProgress::new(py, progress)?;
+        crate::embeddings::node2vec(py, self.inner.g(), config, progress)
     }
 }
 
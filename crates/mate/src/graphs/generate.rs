@@ -0,0 +1,166 @@
+//! Random graph generators, exposed as the `graph_mate.generate` module.
+
+use super::{builder::LayoutArg, DiGraph, Graph, Layout};
+use graph::prelude::{
+    barabasi_albert as generate_barabasi_albert, erdos_renyi as generate_erdos_renyi,
+    rmat as generate_rmat, BarabasiAlbertConfig, ErdosRenyiConfig, RmatConfig,
+};
+use pyo3::{exceptions::PyValueError, prelude::*, wrap_pyfunction};
+
+pub(crate) fn register(py: Python, m: &PyModule) -> PyResult<()> {
+    let generate = PyModule::new(py, "generate")?;
+    generate.add_function(wrap_pyfunction!(rmat, generate)?)?;
+    generate.add_function(wrap_pyfunction!(erdos_renyi, generate)?)?;
+    generate.add_function(wrap_pyfunction!(barabasi_albert, generate)?)?;
+    m.add_submodule(generate)?;
+    Ok(())
+}
+
+/// Generate an R-MAT graph with `2^scale` nodes and `edge_factor * 2^scale`
+/// edges, as used by the Graph500 benchmark.
+///
+/// `a`, `b` and `c` are the probabilities of choosing the upper left, upper
+/// right and lower left quadrant of the adjacency matrix for an edge. The
+/// graph may contain duplicate edges and self-loops, unless it is created
+/// with the deduplicated layout.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+#[args(
+    scale = "RmatConfig::DEFAULT_SCALE",
+    edge_factor = "RmatConfig::DEFAULT_EDGE_FACTOR",
+    "*",
+    a = "RmatConfig::DEFAULT_A",
+    b = "RmatConfig::DEFAULT_B",
+    c = "RmatConfig::DEFAULT_C",
+    seed = "RmatConfig::DEFAULT_SEED",
+    directed = "true",
+    layout = "None"
+)]
+pub fn rmat(
+    py: Python<'_>,
+    scale: u32,
+    edge_factor: usize,
+    a: f64,
+    b: f64,
+    c: f64,
+    seed: u64,
+    directed: bool,
+    layout: Option<LayoutArg>,
+) -> PyResult<PyObject> {
+    if scale > 31 {
+        return Err(PyValueError::new_err(format!(
+            "scale must be at most 31, got {scale}"
+        )));
+    }
+    if [a, b, c].iter().any(|p| !(0.0..=1.0).contains(p)) || a + b + c > 1.0 {
+        return Err(PyValueError::new_err(format!(
+            "a, b and c must not be negative and sum up to at most 1, got {a}, {b} and {c}"
+        )));
+    }
+
+    let config = RmatConfig {
+        scale,
+        edge_factor,
+        a,
+        b,
+        c,
+        seed,
+    };
+    let edges = py.allow_threads(|| with_unit_values(generate_rmat(config)));
+    into_graph(py, edges, config.node_count(), directed, layout)
+}
+
+/// Generate an Erdős–Rényi graph, which contains each possible edge between
+/// two distinct nodes with the given probability.
+///
+/// Directed graphs may contain both `(u, v)` and `(v, u)`, undirected graphs
+/// contain each edge at most once.
+#[pyfunction]
+#[args(
+    "*",
+    seed = "ErdosRenyiConfig::DEFAULT_SEED",
+    directed = "true",
+    layout = "None"
+)]
+pub fn erdos_renyi(
+    py: Python<'_>,
+    node_count: u32,
+    edge_probability: f64,
+    seed: u64,
+    directed: bool,
+    layout: Option<LayoutArg>,
+) -> PyResult<PyObject> {
+    if !(0.0..=1.0).contains(&edge_probability) {
+        return Err(PyValueError::new_err(format!(
+            "edge_probability must be between 0 and 1, got {edge_probability}"
+        )));
+    }
+
+    let config = ErdosRenyiConfig {
+        node_count: node_count as usize,
+        edge_probability,
+        directed,
+        seed,
+    };
+    let edges = py.allow_threads(|| with_unit_values(generate_erdos_renyi(config)));
+    into_graph(py, edges, config.node_count, directed, layout)
+}
+
+/// Generate a Barabási–Albert graph, in which each new node is connected to
+/// `edges_per_node` existing nodes, chosen with a probability proportional
+/// to their degree.
+///
+/// For directed graphs, the edges point from the new nodes to the existing
+/// ones.
+#[pyfunction]
+#[args(
+    edges_per_node = "BarabasiAlbertConfig::DEFAULT_EDGES_PER_NODE",
+    "*",
+    seed = "BarabasiAlbertConfig::DEFAULT_SEED",
+    directed = "true",
+    layout = "None"
+)]
+pub fn barabasi_albert(
+    py: Python<'_>,
+    node_count: u32,
+    edges_per_node: usize,
+    seed: u64,
+    directed: bool,
+    layout: Option<LayoutArg>,
+) -> PyResult<PyObject> {
+    if edges_per_node == 0 || edges_per_node >= node_count as usize {
+        return Err(PyValueError::new_err(format!(
+            "edges_per_node must be at least 1 and less than node_count ({node_count}), got {edges_per_node}"
+        )));
+    }
+
+    let config = BarabasiAlbertConfig {
+        node_count: node_count as usize,
+        edges_per_node,
+        seed,
+    };
+    let edges = py.allow_threads(|| with_unit_values(generate_barabasi_albert(config)));
+    into_graph(py, edges, config.node_count, directed, layout)
+}
+
+fn with_unit_values(edges: Vec<(u32, u32)>) -> Vec<(u32, u32, ())> {
+    edges
+        .into_iter()
+        .map(|(source, target)| (source, target, ()))
+        .collect()
+}
+
+fn into_graph(
+    py: Python<'_>,
+    edges: Vec<(u32, u32, ())>,
+    node_count: usize,
+    directed: bool,
+    layout: Option<LayoutArg>,
+) -> PyResult<PyObject> {
+    let layout = layout.map(Layout::from);
+    if directed {
+        Ok(DiGraph::from_edge_vec(py, edges, node_count, layout)?.into_py(py))
+    } else {
+        Ok(Graph::from_edge_vec(py, edges, node_count, layout)?.into_py(py))
+    }
+}
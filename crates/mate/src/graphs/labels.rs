@@ -0,0 +1,99 @@
+//! Queries on node labels.
+//!
+//! Labels are kept as a Python list with one entry per node, so they can be
+//! any Python object, e.g., the original NetworkX node or a node type.
+//! Labels are compared via `==` and must be hashable to be counted or to be
+//! used for selecting subgraphs.
+
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError},
+    prelude::*,
+    types::{PyDict, PyList, PySet},
+};
+
+/// Converts the given sequence into node labels of a graph with
+/// `node_count` nodes.
+pub(super) fn validate_labels(
+    py: Python<'_>,
+    labels: Option<&PyAny>,
+    node_count: u32,
+) -> PyResult<Option<Py<PyList>>> {
+    let Some(labels) = labels else {
+        return Ok(None);
+    };
+    let labels = PyList::new(py, labels.iter()?.collect::<PyResult<Vec<_>>>()?);
+    if labels.len() != node_count as usize {
+        return Err(PyValueError::new_err(format!(
+            "Expected {node_count} node labels, got {}",
+            labels.len()
+        )));
+    }
+    Ok(Some(labels.into()))
+}
+
+/// Returns the label of the given node.
+pub(super) fn label(py: Python<'_>, labels: Option<&Py<PyList>>, node: u32) -> PyResult<PyObject> {
+    let labels = require_labels(py, labels)?;
+    labels
+        .get_item(node as usize)
+        .map(Into::into)
+        .map_err(|_| PyIndexError::new_err(format!("node {node} is not contained in the graph")))
+}
+
+/// Returns all nodes, in ascending order, that have a label equal to the
+/// given label.
+pub(super) fn nodes_with_label(
+    py: Python<'_>,
+    labels: Option<&Py<PyList>>,
+    label: &PyAny,
+) -> PyResult<Vec<u32>> {
+    let labels = require_labels(py, labels)?;
+    let mut nodes = Vec::new();
+    for (node, node_label) in labels.iter().enumerate() {
+        if node_label.eq(label)? {
+            nodes.push(node as u32);
+        }
+    }
+    Ok(nodes)
+}
+
+/// Returns all nodes, in ascending order, that have one of the given labels.
+pub(super) fn nodes_with_any_label(
+    py: Python<'_>,
+    labels: Option<&Py<PyList>>,
+    selected: &PyAny,
+) -> PyResult<Vec<u32>> {
+    let labels = require_labels(py, labels)?;
+    let selected = PySet::new(py, &selected.iter()?.collect::<PyResult<Vec<_>>>()?)?;
+    let mut nodes = Vec::new();
+    for (node, node_label) in labels.iter().enumerate() {
+        if selected.contains(node_label)? {
+            nodes.push(node as u32);
+        }
+    }
+    Ok(nodes)
+}
+
+/// Counts the nodes per label.
+pub(super) fn label_counts<'py>(
+    py: Python<'py>,
+    labels: Option<&Py<PyList>>,
+) -> PyResult<&'py PyDict> {
+    let labels = require_labels(py, labels)?;
+    let counts = PyDict::new(py);
+    for label in labels {
+        let count = match counts.get_item(label) {
+            Some(count) => count.extract::<usize>()? + 1,
+            None => 1,
+        };
+        counts.set_item(label, count)?;
+    }
+    Ok(counts)
+}
+
+fn require_labels<'py>(py: Python<'py>, labels: Option<&Py<PyList>>) -> PyResult<&'py PyList> {
+    match labels {
+        Some(labels) => Ok(labels.as_ref(py)),
+        None => Err(PyValueError::new_err("The graph has no node labels")),
+    }
+}
@@ -1,8 +1,8 @@
 use crate::GraphError as GraphErrorWrapper;
 use ::graph::prelude::{
-    CsrLayout, DirectedDegrees, DirectedNeighbors, EdgeList, EdgeListInput, Edges,
-    Error as GraphError, Graph as GraphTrait, Graph500, Graph500Input, GraphBuilder, Idx,
-    InputCapabilities, InputPath, RelabelByDegreeOp, ToUndirectedOp, UndirectedDegrees,
+    CsrLayout, DegreeStats, DirectedDegrees, DirectedNeighbors, EdgeList, EdgeListInput, Edges,
+    Error as GraphError, Graph as GraphTrait, Graph500, Graph500Input, GraphBuilder, GraphStatsOp,
+    Idx, InputCapabilities, InputPath, RelabelByDegreeOp, ToUndirectedOp, UndirectedDegrees,
     UndirectedNeighbors,
 };
 use numpy::{
@@ -12,7 +12,7 @@ use numpy::{
 use pyo3::{
     exceptions::{PyTypeError, PyValueError},
     prelude::*,
-    types::PyList,
+    types::{PyDict, PyList},
 };
 use std::{
     fmt::Debug,
@@ -22,12 +22,27 @@ use std::{
     time::{Duration, Instant},
 };
 
+mod arrays;
+mod builder;
+mod catalog;
 mod digraph;
+mod generate;
 mod graph;
+mod labels;
+mod networkx;
+mod persistence;
 mod shared_slice;
+mod subgraph;
+mod weighted_digraph;
+mod weighted_graph;
 
+use self::builder::LayoutArg;
+use self::digraph::DiGraph;
+pub(crate) use self::arrays::contiguous_array;
 pub(crate) use self::graph::Graph;
-pub(crate) use self::shared_slice::{NumpyType, SharedSlice};
+pub(crate) use self::shared_slice::{NumpyType, SharedSlice, SharedSliceIter};
+use self::weighted_digraph::WeightedDiGraph;
+use self::weighted_graph::WeightedGraph;
 
 pub(crate) fn register(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Layout>()?;
@@ -35,6 +50,11 @@ pub(crate) fn register(py: Python, m: &PyModule) -> PyResult<()> {
 
     digraph::register(py, m)?;
     graph::register(py, m)?;
+    weighted_digraph::register(py, m)?;
+    weighted_graph::register(py, m)?;
+    builder::register(py, m)?;
+    catalog::register(py, m)?;
+    generate::register(py, m)?;
 
     Ok(())
 }
@@ -177,17 +197,6 @@ where
         Ok(Self::from_edge_list(el, layout))
     }
 
-    fn from_pandas(py: Python<'_>, data: PyObject, layout: Option<Layout>) -> PyResult<Self>
-    where
-        NI: Element,
-        for<'a> G: From<(ArrayEdgeList<'a, NI>, CsrLayout)>,
-    {
-        let to_numpy = data.getattr(py, "to_numpy")?;
-        let np = to_numpy.call0(py)?;
-        let np = unsafe { PyArray2::from_owned_ptr(py, np.into_ptr()) };
-        Self::from_numpy(np, layout)
-    }
-
     /// Load a graph from an edge list
     fn from_edge_list<E>(edge_list: E, layout: Option<Layout>) -> Self
     where
@@ -276,7 +285,10 @@ where
         Ok(())
     }
 
-    fn __repr__(&self) -> String {
+    fn __repr__(&self) -> String
+    where
+        G: GraphStatsOp,
+    {
         format!("{self:?}")
     }
 }
@@ -372,16 +384,76 @@ where
     }
 }
 
-impl<NI: Idx, G: GraphTrait<NI>> std::fmt::Debug for PyGraph<NI, G> {
+impl<NI, G> PyGraph<NI, G>
+where
+    G: GraphStatsOp + Sync,
+{
+    /// Returns summary statistics of the graph as a dict.
+    ///
+    /// Degree statistics use out-degrees for directed graphs, which also
+    /// contain the same statistics for their in-degrees.
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let g = self.g();
+        let stats = py.allow_threads(move || g.stats());
+
+        let dict = PyDict::new(py);
+        dict.set_item("node_count", stats.node_count)?;
+        dict.set_item("edge_count", stats.edge_count)?;
+        dict.set_item("density", stats.density)?;
+        set_degree_stats(dict, "degree", &stats.degrees)?;
+        if let Some(in_degrees) = &stats.in_degrees {
+            set_degree_stats(dict, "in_degree", in_degrees)?;
+        }
+        dict.set_item("memory_bytes", stats.memory_bytes)?;
+        Ok(dict)
+    }
+}
+
+fn set_degree_stats(dict: &PyDict, prefix: &str, stats: &DegreeStats) -> PyResult<()> {
+    dict.set_item(format!("{prefix}_min"), stats.min)?;
+    dict.set_item(format!("{prefix}_max"), stats.max)?;
+    dict.set_item(format!("{prefix}_mean"), stats.mean)?;
+    dict.set_item(format!("{prefix}_p50"), stats.p50)?;
+    dict.set_item(format!("{prefix}_p90"), stats.p90)?;
+    dict.set_item(format!("{prefix}_p99"), stats.p99)?;
+    Ok(())
+}
+
+impl<NI: Idx, G: GraphTrait<NI> + GraphStatsOp> std::fmt::Debug for PyGraph<NI, G> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stats = self.g.stats();
         f.debug_struct("Graph")
             .field("node_count", &self.g.node_count())
             .field("edge_count", &self.g.edge_count())
+            .field("density", &format_args!("{:.6}", stats.density))
+            .field("mean_degree", &format_args!("{:.2}", stats.degrees.mean))
+            .field("max_degree", &stats.degrees.max)
+            .field("memory", &format_args!("{}", Bytes(stats.memory_bytes)))
             .field("load_took", &Duration::from_micros(self.load_micros))
             .finish()
     }
 }
 
+/// Formats a number of bytes using binary prefixes, e.g., `1.50 KiB`.
+struct Bytes(usize);
+
+impl std::fmt::Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+        let mut value = self.0 as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            write!(f, "{} {}", self.0, UNITS[0])
+        } else {
+            write!(f, "{value:.2} {}", UNITS[unit])
+        }
+    }
+}
+
 impl<NI, G> Drop for PyGraph<NI, G> {
     fn drop(&mut self) {
         match Arc::strong_count(&self.g) {
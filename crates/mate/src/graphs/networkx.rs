@@ -0,0 +1,173 @@
+//! Conversions from and to NetworkX graphs.
+//!
+//! NetworkX is an optional dependency that is only imported when one of the
+//! conversions is used. Node labels can be any hashable Python object, they
+//! are relabeled to consecutive ids in the order of `graph.nodes` and kept as
+//! a list, so that the labels can be restored when converting back.
+//!
+//! Every node and edge is converted into Python objects, so the conversions
+//! are meant for graphs of a size that NetworkX can handle comfortably.
+
+use pyo3::{
+    exceptions::{PyImportError, PyKeyError, PyValueError},
+    prelude::*,
+    types::{PyDict, PyList, PyTuple},
+};
+
+fn import_networkx(py: Python<'_>) -> PyResult<&PyModule> {
+    py.import("networkx").map_err(|_| {
+        PyImportError::new_err(
+            "networkx is required for this conversion, install it with `pip install graph_mate[networkx]`",
+        )
+    })
+}
+
+/// The nodes of a NetworkX graph and their new ids.
+pub(super) struct Nodes<'py> {
+    labels: &'py PyList,
+    ids: &'py PyDict,
+}
+
+impl<'py> Nodes<'py> {
+    pub(super) fn read(py: Python<'py>, graph: &'py PyAny) -> PyResult<Self> {
+        let labels = PyList::empty(py);
+        let ids = PyDict::new(py);
+        for node in graph.getattr("nodes")?.iter()? {
+            let node = node?;
+            ids.set_item(node, labels.len())?;
+            labels.append(node)?;
+        }
+        Ok(Self { labels, ids })
+    }
+
+    pub(super) fn count(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub(super) fn into_labels(self) -> Py<PyList> {
+        self.labels.into()
+    }
+
+    fn id(&self, label: &PyAny) -> PyResult<u32> {
+        match self.ids.get_item(label) {
+            Some(id) => id.extract(),
+            None => Err(PyKeyError::new_err(label.to_object(label.py()))),
+        }
+    }
+
+    /// Reads the edges of the graph as pairs of node ids.
+    ///
+    /// If `directed` is set and the NetworkX graph is undirected, every edge
+    /// is returned in both directions, as `networkx.DiGraph(graph)` would do.
+    pub(super) fn edges(&self, graph: &PyAny, directed: bool) -> PyResult<Vec<(u32, u32, ())>> {
+        self.weighted_edges(graph, directed, None)
+            .map(|edges| edges.into_iter().map(|(s, t, _)| (s, t, ())).collect())
+    }
+
+    /// Reads the edges of the graph together with their `weight` attribute.
+    /// Edges without that attribute have a weight of `1.0`.
+    pub(super) fn weighted_edges(
+        &self,
+        graph: &PyAny,
+        directed: bool,
+        weight: Option<&str>,
+    ) -> PyResult<Vec<(u32, u32, f32)>> {
+        let py = graph.py();
+        let reverse = directed && !graph.call_method0("is_directed")?.is_true()?;
+
+        let kwargs = PyDict::new(py);
+        match weight {
+            Some(weight) => kwargs.set_item("data", weight)?,
+            None => kwargs.set_item("data", false)?,
+        }
+        kwargs.set_item("default", 1.0)?;
+        let edges = graph.getattr("edges")?.call((), Some(kwargs))?;
+
+        let mut result = Vec::with_capacity(edges.len()?);
+        for edge in edges.iter()? {
+            let edge = edge?.downcast::<PyTuple>()?;
+            let source = self.id(edge.get_item(0)?)?;
+            let target = self.id(edge.get_item(1)?)?;
+            let value = match weight {
+                Some(_) => edge.get_item(2)?.extract::<f32>()?,
+                None => 1.0,
+            };
+            result.push((source, target, value));
+            if reverse && source != target {
+                result.push((target, source, value));
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Creates a NetworkX graph of the given class, e.g., `DiGraph`.
+///
+/// Node `i` is added with the label at position `i` of `labels`, or with its
+/// id if there are no labels. Each entry of `node_attributes` maps an
+/// attribute name to a sequence with one value per node, such as an
+/// algorithm result.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn to_networkx(
+    py: Python<'_>,
+    class: &str,
+    labels: Option<&Py<PyList>>,
+    node_count: u32,
+    sources: Vec<u32>,
+    targets: Vec<u32>,
+    weights: Option<Vec<f32>>,
+    node_attributes: Option<&PyDict>,
+) -> PyResult<PyObject> {
+    let networkx = import_networkx(py)?;
+    let graph = networkx.getattr(class)?.call0()?;
+
+    let labels = match labels {
+        Some(labels) => labels.as_ref(py),
+        None => PyList::new(py, 0..node_count),
+    };
+    let label = |id: u32| labels.get_item(id as usize);
+
+    graph.call_method1("add_nodes_from", (labels,))?;
+
+    let edges = PyList::empty(py);
+    for (edge, (&source, &target)) in sources.iter().zip(&targets).enumerate() {
+        let edge = match &weights {
+            Some(weights) => PyTuple::new(
+                py,
+                [
+                    label(source)?,
+                    label(target)?,
+                    weights[edge].into_py(py).into_ref(py),
+                ],
+            ),
+            None => PyTuple::new(py, [label(source)?, label(target)?]),
+        };
+        edges.append(edge)?;
+    }
+    match weights {
+        Some(_) => graph.call_method1("add_weighted_edges_from", (edges,))?,
+        None => graph.call_method1("add_edges_from", (edges,))?,
+    };
+
+    if let Some(node_attributes) = node_attributes {
+        let numpy = py.import("numpy")?;
+        for (name, values) in node_attributes {
+            let values = numpy.call_method1("asarray", (values,))?;
+            let values = values.call_method0("tolist")?.downcast::<PyList>()?;
+            if values.len() != labels.len() {
+                return Err(PyValueError::new_err(format!(
+                    "node attribute {name} has {} values, but the graph has {} nodes",
+                    values.len(),
+                    labels.len()
+                )));
+            }
+            let attributes = PyDict::new(py);
+            for (label, value) in labels.iter().zip(values) {
+                attributes.set_item(label, value)?;
+            }
+            networkx.call_method1("set_node_attributes", (graph, attributes, name))?;
+        }
+    }
+
+    Ok(graph.into_py(py))
+}
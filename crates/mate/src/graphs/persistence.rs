@@ -0,0 +1,178 @@
+use super::{time, PyGraph};
+use crate::GraphError as GraphErrorWrapper;
+use ::graph::prelude::{DeserializeGraphOp, Error as GraphError, SerializeGraphOp};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
+    path::PathBuf,
+};
+
+/// Identifies binary snapshots written by `graph_mate`, followed by a
+/// format version.
+const MAGIC: &[u8; 8] = b"gmate\0\0\x01";
+
+/// The graph class that a binary snapshot has been written from.
+///
+/// The CSR snapshot itself does not tell directed and undirected or weighted
+/// and unweighted graphs apart, so the kind is stored in front of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub(super) enum GraphKind {
+    DiGraph = 0,
+    Graph = 1,
+    WeightedDiGraph = 2,
+    WeightedGraph = 3,
+}
+
+impl GraphKind {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::DiGraph),
+            1 => Some(Self::Graph),
+            2 => Some(Self::WeightedDiGraph),
+            3 => Some(Self::WeightedGraph),
+            _ => None,
+        }
+    }
+}
+
+impl<G> PyGraph<u32, G>
+where
+    G: Send + Sync,
+{
+    /// Serializes the graph into a binary snapshot.
+    pub(super) fn to_bytes<'py>(&self, py: Python<'py>, kind: GraphKind) -> PyResult<&'py PyBytes>
+    where
+        G: for<'w> SerializeGraphOp<&'w mut Vec<u8>>,
+    {
+        let g = self.g();
+        let bytes = py
+            .allow_threads(move || {
+                let mut bytes = Vec::new();
+                write_snapshot(g, kind, &mut bytes).map(|()| bytes)
+            })
+            .map_err(|e| SnapshotError::from(e).into_py_err())?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Creates a graph from a binary snapshot created by `to_bytes`.
+    pub(super) fn from_bytes<'b>(py: Python<'_>, bytes: &'b [u8], kind: GraphKind) -> PyResult<Self>
+    where
+        G: for<'r> DeserializeGraphOp<&'r mut &'b [u8], G>,
+    {
+        let (graph, took) = py.allow_threads(move || {
+            time(move || {
+                let mut read = bytes;
+                let graph = read_snapshot::<_, G>(kind, &mut read)?;
+                if !read.is_empty() {
+                    return Err(SnapshotError::TrailingData);
+                }
+                Ok(graph)
+            })
+        });
+        Ok(Self::new(took, graph.map_err(SnapshotError::into_py_err)?))
+    }
+
+    /// Writes a binary snapshot of the graph to the given file.
+    pub(super) fn save(&self, py: Python<'_>, path: PathBuf, kind: GraphKind) -> PyResult<()>
+    where
+        G: for<'w> SerializeGraphOp<&'w mut BufWriter<File>>,
+    {
+        let g = self.g();
+        py.allow_threads(move || {
+            let mut file = BufWriter::new(File::create(path).map_err(GraphError::from)?);
+            write_snapshot(g, kind, &mut file)?;
+            file.flush().map_err(GraphError::from)?;
+            Ok(())
+        })
+        .map_err(SnapshotError::into_py_err)
+    }
+
+    /// Reads a graph from a binary snapshot file written by `save`.
+    pub(super) fn load_binary(py: Python<'_>, path: PathBuf, kind: GraphKind) -> PyResult<Self>
+    where
+        G: for<'r> DeserializeGraphOp<&'r mut BufReader<File>, G>,
+    {
+        let (graph, took) = py.allow_threads(move || {
+            time(move || {
+                let mut file = BufReader::new(File::open(path).map_err(GraphError::from)?);
+                read_snapshot::<_, G>(kind, &mut file)
+            })
+        });
+        Ok(Self::new(took, graph.map_err(SnapshotError::into_py_err)?))
+    }
+}
+
+fn write_snapshot<G, W>(g: &G, kind: GraphKind, write: &mut W) -> Result<(), GraphError>
+where
+    W: Write,
+    G: for<'w> SerializeGraphOp<&'w mut W>,
+{
+    write.write_all(MAGIC)?;
+    write.write_all(&[kind as u8])?;
+    g.serialize(write)
+}
+
+fn read_snapshot<R, G>(kind: GraphKind, read: &mut R) -> Result<G, SnapshotError>
+where
+    R: Read,
+    G: for<'r> DeserializeGraphOp<&'r mut R, G>,
+{
+    let mut header = [0_u8; MAGIC.len() + 1];
+    read.read_exact(&mut header)
+        .map_err(|_| SnapshotError::InvalidHeader)?;
+    if &header[..MAGIC.len()] != MAGIC {
+        return Err(SnapshotError::InvalidHeader);
+    }
+    match GraphKind::from_byte(header[MAGIC.len()]) {
+        Some(actual) if actual == kind => {}
+        Some(actual) => {
+            return Err(SnapshotError::WrongKind {
+                expected: kind,
+                actual,
+            })
+        }
+        None => return Err(SnapshotError::InvalidHeader),
+    }
+    Ok(G::deserialize(read)?)
+}
+
+enum SnapshotError {
+    InvalidHeader,
+    WrongKind {
+        expected: GraphKind,
+        actual: GraphKind,
+    },
+    TrailingData,
+    Graph(GraphError),
+}
+
+impl From<GraphError> for SnapshotError {
+    fn from(error: GraphError) -> Self {
+        Self::Graph(error)
+    }
+}
+
+impl SnapshotError {
+    fn into_py_err(self) -> PyErr {
+        match self {
+            Self::InvalidHeader => {
+                PyValueError::new_err("The data is not a binary graph_mate snapshot")
+            }
+            Self::WrongKind { expected, actual } => PyValueError::new_err(format!(
+                "The snapshot contains a {actual:?}, but a {expected:?} was expected"
+            )),
+            Self::TrailingData => {
+                PyValueError::new_err("The snapshot contains data after the graph")
+            }
+            Self::Graph(GraphError::IoError { source })
+                if source.kind() == ErrorKind::UnexpectedEof =>
+            {
+                PyValueError::new_err("The snapshot is truncated")
+            }
+            Self::Graph(GraphError::IoError { source }) => source.into(),
+            Self::Graph(error) => GraphErrorWrapper(error).into(),
+        }
+    }
+}
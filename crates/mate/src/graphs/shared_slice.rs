@@ -3,7 +3,11 @@ use numpy::{
     npyffi::{types::NPY_TYPES, NpyTypes, NPY_ARRAY_DEFAULT, NPY_ARRAY_WRITEABLE},
     PyArray, PyArray1, PY_ARRAY_API,
 };
-use pyo3::{prelude::*, types::PyCapsule};
+use pyo3::{
+    exceptions::PyIndexError,
+    prelude::*,
+    types::{PyCapsule, PySlice},
+};
 use std::{ffi::CString, fmt::Debug, os::raw::c_void, sync::Arc};
 
 pub trait NumpyType {
@@ -106,6 +110,91 @@ impl SharedSlice {
         self.len
     }
 
+    /// Returns a copy of the value at the given index, which may be negative
+    /// to index from the end, as in Python sequences.
+    pub fn item<T: NumpyType + Copy>(&self, index: isize) -> PyResult<T> {
+        assert_eq!(
+            T::NP_TYPE,
+            self.np_tpe,
+            "The shared slice is the wrong type"
+        );
+        let len = self.len as isize;
+        let index = if index < 0 { index + len } else { index };
+        if !(0..len).contains(&index) {
+            return Err(PyIndexError::new_err("index out of range"));
+        }
+        // SAFETY: the index is in bounds and the data is kept alive by the owner
+        Ok(unsafe { *self.data.0.cast::<T>().offset(index) })
+    }
+
+    /// Returns the data as a slice of the given type.
+    pub fn as_slice<T: NumpyType>(&self) -> &[T] {
+        assert_eq!(
+            T::NP_TYPE,
+            self.np_tpe,
+            "The shared slice is the wrong type"
+        );
+        // SAFETY: the data is kept alive by the owner and has the given type
+        unsafe { std::slice::from_raw_parts(self.data.0.cast::<T>(), self.len) }
+    }
+
+    /// Returns the value at the given index as a Python object.
+    fn item_object(&self, py: Python<'_>, index: isize) -> PyResult<PyObject> {
+        match self.np_tpe {
+            tpe if tpe == u32::NP_TYPE => Ok(self.item::<u32>(index)?.into_py(py)),
+            tpe if tpe == u64::NP_TYPE => Ok(self.item::<u64>(index)?.into_py(py)),
+            tpe if tpe == f32::NP_TYPE => Ok(self.item::<f32>(index)?.into_py(py)),
+            tpe if tpe == f64::NP_TYPE => Ok(self.item::<f64>(index)?.into_py(py)),
+            tpe => unreachable!("shared slices are not created for {tpe:?}"),
+        }
+    }
+
+    /// Implements `__getitem__` for types that wrap this slice.
+    ///
+    /// Integer indices return a single value and may be negative. Slices,
+    /// including those with a negative step, return a read-only numpy view
+    /// of the data without copying it.
+    pub fn get(&self, py: Python<'_>, index: &PyAny) -> PyResult<PyObject> {
+        if let Ok(slice) = index.downcast::<PySlice>() {
+            let array = self.clone().into_numpy_object(py)?;
+            return Ok(array.get_item(slice)?.into_py(py));
+        }
+        self.item_object(py, index.extract()?)
+    }
+
+    /// Implements `__iter__` for types that wrap this slice.
+    pub fn iter(&self) -> SharedSliceIter {
+        SharedSliceIter {
+            slice: self.clone(),
+            position: 0,
+        }
+    }
+
+    fn into_numpy_object(self, py: Python<'_>) -> PyResult<&PyAny> {
+        match self.np_tpe {
+            tpe if tpe == u32::NP_TYPE => Ok(self.into_numpy::<u32>(py)?.as_ref()),
+            tpe if tpe == u64::NP_TYPE => Ok(self.into_numpy::<u64>(py)?.as_ref()),
+            tpe if tpe == f32::NP_TYPE => Ok(self.into_numpy::<f32>(py)?.as_ref()),
+            tpe if tpe == f64::NP_TYPE => Ok(self.into_numpy::<f64>(py)?.as_ref()),
+            tpe => unreachable!("shared slices are not created for {tpe:?}"),
+        }
+    }
+
+    /// Implements `__array__` for types that wrap this slice, so that
+    /// `np.asarray` returns a view of the data without copying it, unless a
+    /// different `dtype` is requested.
+    pub fn into_array<T: NumpyType>(
+        self,
+        py: Python<'_>,
+        dtype: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let array = self.into_numpy::<T>(py)?;
+        match dtype {
+            Some(dtype) => Ok(array.call_method1("astype", (dtype,))?.into_py(py)),
+            None => Ok(array.into_py(py)),
+        }
+    }
+
     pub fn into_numpy<NI: NumpyType>(mut self, py: Python<'_>) -> PyResult<&PyArray1<NI>> {
         assert_eq!(
             NI::NP_TYPE,
@@ -195,6 +284,33 @@ impl Drop for SharedSlice {
     }
 }
 
+/// Iterates over the values of a shared slice as Python objects.
+#[pyclass]
+pub struct SharedSliceIter {
+    slice: SharedSlice,
+    position: usize,
+}
+
+#[pymethods]
+impl SharedSliceIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        if self.position == self.slice.len() {
+            return Ok(None);
+        }
+        let item = self.slice.item_object(py, self.position as isize)?;
+        self.position += 1;
+        Ok(Some(item))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.slice.len() - self.position
+    }
+}
+
 struct SharedConst(*const ());
 
 impl SharedConst {
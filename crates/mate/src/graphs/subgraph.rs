@@ -0,0 +1,226 @@
+use super::{arrays::contiguous_array, time, PyGraph};
+use ::graph::prelude::{
+    project_bipartite, sample_nodes, threshold_weights, transform_weights, BidirectionalNeighbors,
+    EgoSubgraphOp, FilterEdgesOp, Graph as GraphTrait, InducedSubgraphOp, MapEdgeValuesOp,
+    ProjectionConfig, ProjectionWeighting, SamplingConfig, SamplingMethod, UndirectedCsrGraph,
+    UndirectedDegrees, UndirectedNeighbors, WeightTransform,
+};
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError},
+    prelude::*,
+    types::PyList,
+};
+
+impl<G> PyGraph<u32, G>
+where
+    G: GraphTrait<u32> + Send + Sync,
+{
+    /// Creates the subgraph induced by the given node ids and returns it
+    /// together with the original id of each subgraph node.
+    pub(super) fn induced_subgraph(
+        &self,
+        py: Python<'_>,
+        nodes: &PyAny,
+    ) -> PyResult<(Self, Vec<u32>)>
+    where
+        G: InducedSubgraphOp<u32, Subgraph = G>,
+    {
+        let nodes = contiguous_array::<u32>(py, nodes, "nodes")?;
+        let selected = self.selection(nodes.as_slice()?)?;
+        if !selected.contains(&true) {
+            return Err(PyValueError::new_err(
+                "Cannot create a subgraph without any nodes",
+            ));
+        }
+
+        let g = self.g();
+        let ((subgraph, mapping), took) = py.allow_threads(move || {
+            time(move || g.induced_subgraph(|node| selected[node as usize]))
+        });
+        Ok((Self::new(took, subgraph), mapping))
+    }
+
+    /// Creates the subgraph induced by the `radius`-hop neighborhood of the
+    /// given node and returns it together with the original id of each
+    /// subgraph node.
+    pub(super) fn ego_subgraph(
+        &self,
+        py: Python<'_>,
+        node: u32,
+        radius: usize,
+    ) -> PyResult<(Self, Vec<u32>)>
+    where
+        G: EgoSubgraphOp<u32, Subgraph = G>,
+    {
+        if node >= self.g().node_count() {
+            return Err(missing_node(node));
+        }
+
+        let g = self.g();
+        let ((subgraph, mapping), took) =
+            py.allow_threads(move || time(move || g.ego_subgraph(node, radius)));
+        Ok((Self::new(took, subgraph), mapping))
+    }
+
+    /// Selects the nodes of a sample of the graph in ascending order, which
+    /// can be passed to `induced_subgraph`.
+    pub(super) fn sample_nodes(
+        &self,
+        py: Python<'_>,
+        fraction: f64,
+        method: &str,
+        seed: u64,
+        restart_probability: f64,
+        burn_probability: f64,
+    ) -> PyResult<Vec<u32>>
+    where
+        G: BidirectionalNeighbors<u32>,
+    {
+        let method = match method.to_ascii_lowercase().as_str() {
+            "node" => SamplingMethod::Node,
+            "edge" => SamplingMethod::Edge,
+            "random_walk" => SamplingMethod::RandomWalk,
+            "forest_fire" => SamplingMethod::ForestFire,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown method {method:?}, expected one of 'node', 'edge', 'random_walk' or 'forest_fire'"
+                )))
+            }
+        };
+        if !(fraction > 0.0 && fraction <= 1.0) {
+            return Err(PyValueError::new_err(format!(
+                "fraction must be greater than 0 and at most 1, got {fraction}"
+            )));
+        }
+        for (name, probability) in [
+            ("restart_probability", restart_probability),
+            ("burn_probability", burn_probability),
+        ] {
+            if !(0.0..1.0).contains(&probability) {
+                return Err(PyValueError::new_err(format!(
+                    "{name} must be at least 0 and less than 1, got {probability}"
+                )));
+            }
+        }
+        if self.g().node_count() == 0 {
+            return Err(PyValueError::new_err(
+                "Cannot sample a graph without any nodes",
+            ));
+        }
+
+        let config = SamplingConfig {
+            restart_probability,
+            burn_probability,
+            ..SamplingConfig::new(method, fraction, seed)
+        };
+        let g = self.g();
+        Ok(py.allow_threads(move || sample_nodes(g, config)))
+    }
+
+    /// Creates a graph with the same nodes that only contains the edges
+    /// with a weight between `min_weight` and `max_weight`, both inclusive.
+    pub(super) fn filter_by_weight(
+        &self,
+        py: Python<'_>,
+        min_weight: Option<f32>,
+        max_weight: Option<f32>,
+    ) -> Self
+    where
+        G: FilterEdgesOp<u32, f32, Filtered = G>,
+    {
+        let g = self.g();
+        let (filtered, took) =
+            py.allow_threads(move || time(move || threshold_weights(g, min_weight, max_weight)));
+        Self::new(took, filtered)
+    }
+
+    /// Creates a graph with the same edges, where each weight is transformed
+    /// by the transformation with the given name.
+    pub(super) fn transform_weights(&self, py: Python<'_>, transform: &str) -> PyResult<Self>
+    where
+        G: MapEdgeValuesOp<u32, f32, f32, Mapped = G>,
+    {
+        let transform = match transform.to_ascii_lowercase().as_str() {
+            "inverse" => WeightTransform::Inverse,
+            "log" => WeightTransform::Log,
+            "negative_log" => WeightTransform::NegativeLog,
+            "complement" => WeightTransform::Complement,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown transform {transform:?}, expected one of 'inverse', 'log', 'negative_log' or 'complement'"
+                )))
+            }
+        };
+
+        let g = self.g();
+        let (transformed, took) =
+            py.allow_threads(move || time(move || transform_weights(g, transform)));
+        Ok(Self::new(took, transformed))
+    }
+
+    /// Projects a bipartite graph onto the given nodes, which connects two
+    /// of them if they share a neighbor, and returns the projection together
+    /// with the original id of each of its nodes.
+    pub(super) fn project(
+        &self,
+        py: Python<'_>,
+        nodes: &PyAny,
+        weighting: &str,
+        min_count: usize,
+        max_degree: Option<usize>,
+    ) -> PyResult<(PyGraph<u32, UndirectedCsrGraph<u32, (), f32>>, Vec<u32>)>
+    where
+        G: UndirectedDegrees<u32> + UndirectedNeighbors<u32>,
+    {
+        let weighting = match weighting.to_ascii_lowercase().as_str() {
+            "count" => ProjectionWeighting::Count,
+            "jaccard" => ProjectionWeighting::Jaccard,
+            "newman" => ProjectionWeighting::Newman,
+            _ => return Err(PyValueError::new_err(format!(
+                "unknown weighting {weighting:?}, expected one of 'count', 'jaccard' or 'newman'"
+            ))),
+        };
+        let nodes = contiguous_array::<u32>(py, nodes, "nodes")?;
+        let selected = self.selection(nodes.as_slice()?)?;
+
+        let config = ProjectionConfig::new(weighting, min_count, max_degree);
+        let g = self.g();
+        let ((projection, mapping), took) = py.allow_threads(move || {
+            time(move || project_bipartite(g, |node| selected[node as usize], config))
+        });
+        Ok((PyGraph::new(took, projection), mapping))
+    }
+
+    fn selection(&self, nodes: &[u32]) -> PyResult<Vec<bool>> {
+        let mut selected = vec![false; self.g().node_count() as usize];
+        for &node in nodes {
+            match selected.get_mut(node as usize) {
+                Some(selected) => *selected = true,
+                None => return Err(missing_node(node)),
+            }
+        }
+        Ok(selected)
+    }
+}
+
+/// Selects the node labels of a subgraph, where subgraph node `i` has the
+/// label of the original node `mapping[i]`.
+pub(super) fn subgraph_labels(
+    py: Python<'_>,
+    labels: Option<&Py<PyList>>,
+    mapping: &[u32],
+) -> PyResult<Option<Py<PyList>>> {
+    let Some(labels) = labels else {
+        return Ok(None);
+    };
+    let labels = labels.as_ref(py);
+    let selected = PyList::empty(py);
+    for node in mapping {
+        selected.append(labels.get_item(*node as usize)?)?;
+    }
+    Ok(Some(selected.into()))
+}
+
+fn missing_node(node: u32) -> PyErr {
+    PyIndexError::new_err(format!("node {node} is not contained in the graph"))
+}
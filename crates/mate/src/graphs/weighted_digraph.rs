@@ -0,0 +1,510 @@
+use super::{
+    arrays::contiguous_array,
+    labels,
+    networkx::{self, Nodes},
+    persistence::GraphKind,
+    subgraph, Layout, LayoutArg, PyGraph,
+};
+use crate::{
+    landmarks::LandmarkIndex,
+    reachability::ReachabilityIndex,
+    sssp::{ShortestPathResult, SsspResult},
+};
+use graph::prelude::{
+    DeltaSteppingConfig, DescendantsConfig, DirectedCsrGraph, EdgeListInput, LandmarkConfig,
+    ReachabilityConfig,
+};
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyDict, PyList},
+};
+use std::path::PathBuf;
+
+pub(crate) fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WeightedDiGraph>()?;
+    Ok(())
+}
+
+/// A directed graph using 32 bits for node ids and 32 bit floats as edge weights.
+#[pyclass]
+pub struct WeightedDiGraph {
+    inner: PyGraph<u32, DirectedCsrGraph<u32, (), f32>>,
+    #[pyo3(get)]
+    load_micros: u64,
+    /// The node labels, where node `i` has the label at position `i`, e.g.,
+    /// the original nodes if the graph was created from NetworkX or node
+    /// types. Can be set to any sequence with one label per node.
+    #[pyo3(get)]
+    node_labels: Option<Py<PyList>>,
+}
+
+impl WeightedDiGraph {
+    fn new(load_micros: u64, inner: PyGraph<u32, DirectedCsrGraph<u32, (), f32>>) -> Self {
+        Self {
+            inner,
+            load_micros,
+            node_labels: None,
+        }
+    }
+
+    fn with_labels(mut self, node_labels: Py<PyList>) -> Self {
+        self.node_labels = Some(node_labels);
+        self
+    }
+}
+
+#[pymethods]
+impl WeightedDiGraph {
+    /// Load a graph from an edge list, where each line represents an edge in
+    /// the form of `<source_id> <target_id> <weight>`.
+    #[staticmethod]
+    #[args(layout = "None")]
+    pub fn load(py: Python<'_>, path: PathBuf, layout: Option<LayoutArg>) -> PyResult<Self> {
+        let g = PyGraph::load_file_input(
+            py,
+            path,
+            layout.map(Layout::from),
+            EdgeListInput::<u32, f32>::default(),
+        )?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Create a graph from arrays of source ids, target ids and edge weights.
+    ///
+    /// The arrays are converted to `uint32` and `float32` numpy arrays, which
+    /// does not copy the data if they already have those types.
+    #[staticmethod]
+    #[args(layout = "None")]
+    pub fn from_arrays(
+        py: Python<'_>,
+        sources: &PyAny,
+        targets: &PyAny,
+        weights: &PyAny,
+        layout: Option<LayoutArg>,
+    ) -> PyResult<Self> {
+        let g =
+            PyGraph::from_weighted_arrays(py, sources, targets, weights, layout.map(Layout::from))?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Convert a pandas dataframe into a graph.
+    ///
+    /// The `source`, `target` and `weight` columns contain the node ids and
+    /// weight of each edge. If they are not given, the first three columns of
+    /// the dataframe are used.
+    #[staticmethod]
+    #[args(
+        layout = "None",
+        "*",
+        source = "None",
+        target = "None",
+        weight = "None"
+    )]
+    pub fn from_pandas(
+        py: Python<'_>,
+        data: &PyAny,
+        layout: Option<LayoutArg>,
+        source: Option<&str>,
+        target: Option<&str>,
+        weight: Option<&str>,
+    ) -> PyResult<Self> {
+        let g = PyGraph::from_weighted_pandas(
+            py,
+            data,
+            source,
+            target,
+            weight,
+            layout.map(Layout::from),
+        )?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Create a graph from a `scipy.sparse` CSR matrix, where row `i` contains
+    /// the outgoing edges of node `i` and the matrix values are the edge weights.
+    #[staticmethod]
+    #[args(layout = "None")]
+    pub fn from_scipy_csr(
+        py: Python<'_>,
+        matrix: &PyAny,
+        layout: Option<LayoutArg>,
+    ) -> PyResult<Self> {
+        let g = PyGraph::from_weighted_scipy_csr(py, matrix, layout.map(Layout::from))?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Convert a NetworkX graph into a graph.
+    ///
+    /// Nodes are relabeled to consecutive ids in the order of `graph.nodes`,
+    /// the original labels are available as `node_labels`. Edge weights are
+    /// read from the `weight` attribute and default to `1.0`.
+    ///
+    /// Edges of an undirected NetworkX graph are added in both directions.
+    #[staticmethod]
+    #[args(layout = "None", weight = "\"weight\"")]
+    pub fn from_networkx(
+        py: Python<'_>,
+        graph: &PyAny,
+        layout: Option<LayoutArg>,
+        weight: &str,
+    ) -> PyResult<Self> {
+        let nodes = Nodes::read(py, graph)?;
+        let edges = nodes.weighted_edges(graph, true, Some(weight))?;
+        let g = PyGraph::from_edge_vec(py, edges, nodes.count(), layout.map(Layout::from))?;
+        Ok(Self::new(g.load_micros, g).with_labels(nodes.into_labels()))
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> u32 {
+        self.inner.node_count()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_count(&self) -> u32 {
+        self.inner.edge_count()
+    }
+
+    /// Returns the number of edges where the given node is a source node.
+    pub fn out_degree(&self, node: u32) -> u32 {
+        self.inner.out_degree(node)
+    }
+
+    /// Returns the number of edges where the given node is a target node.
+    pub fn in_degree(&self, node: u32) -> u32 {
+        self.inner.in_degree(node)
+    }
+
+    /// Returns all edges of this graph as a pandas dataframe with the
+    /// columns `src`, `dst` and `weight`.
+    pub fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let (sources, targets, weights) = self.inner.weighted_edge_arrays(py);
+        crate::pandas::edge_frame(py, sources, targets, Some(weights))
+    }
+
+    /// Convert this graph into a `networkx.DiGraph`, using the original node
+    /// labels if there are any.
+    ///
+    /// `node_attributes` maps attribute names to sequences with one value per
+    /// node, e.g., algorithm results, which are copied onto the nodes.
+    #[args(node_attributes = "None")]
+    pub fn to_networkx(
+        &self,
+        py: Python<'_>,
+        node_attributes: Option<&PyDict>,
+    ) -> PyResult<PyObject> {
+        let (sources, targets, weights) = self.inner.weighted_edge_arrays(py);
+        networkx::to_networkx(
+            py,
+            "DiGraph",
+            self.node_labels.as_ref(),
+            self.node_count(),
+            sources,
+            targets,
+            Some(weights),
+            node_attributes,
+        )
+    }
+
+    /// Returns summary statistics of this graph as a dict, i.e., the node
+    /// and edge counts, the density, the minimum, maximum, mean and 50th, 90th
+    /// and 99th percentile of the out-degrees and in-degrees, and the number of bytes used
+    /// to store the graph.
+    pub fn stats<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        self.inner.stats(py)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.inner.__repr__()
+    }
+
+    /// Returns a binary snapshot of this graph, which can be turned back into
+    /// a graph with `from_bytes`.
+    ///
+    /// `node_labels` are not part of the snapshot.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        self.inner.to_bytes(py, GraphKind::WeightedDiGraph)
+    }
+
+    /// Create a graph from a binary snapshot created by `to_bytes`.
+    ///
+    /// Only use snapshots from trusted sources, the data is not validated
+    /// beyond its header.
+    #[staticmethod]
+    pub fn from_bytes(py: Python<'_>, data: &[u8]) -> PyResult<Self> {
+        let g = PyGraph::from_bytes(py, data, GraphKind::WeightedDiGraph)?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Write a binary snapshot of this graph to the given file, which can be
+    /// loaded much faster than the original input with `load_binary`.
+    ///
+    /// `node_labels` are not part of the snapshot.
+    pub fn save(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        self.inner.save(py, path, GraphKind::WeightedDiGraph)
+    }
+
+    /// Load a graph from a binary snapshot file written by `save`.
+    ///
+    /// Only use snapshots from trusted sources, the data is not validated
+    /// beyond its header.
+    #[staticmethod]
+    pub fn load_binary(py: Python<'_>, path: PathBuf) -> PyResult<Self> {
+        let g = PyGraph::load_binary(py, path, GraphKind::WeightedDiGraph)?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Returns the state for `pickle`, which is the binary snapshot of the
+    /// graph together with its node labels.
+    pub fn __reduce__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(PyObject, (&'py PyBytes,), Option<Py<PyList>>)> {
+        let from_bytes = py.get_type::<Self>().getattr("from_bytes")?.into();
+        Ok((from_bytes, (self.to_bytes(py)?,), self.__getstate__()))
+    }
+
+    /// Returns the node labels, which `pickle` stores next to the binary
+    /// snapshot.
+    pub fn __getstate__(&self) -> Option<Py<PyList>> {
+        self.node_labels.clone()
+    }
+
+    /// Restores the node labels after unpickling.
+    pub fn __setstate__(&mut self, node_labels: Option<Py<PyList>>) {
+        self.node_labels = node_labels;
+    }
+
+    #[setter]
+    pub fn set_node_labels(&mut self, py: Python<'_>, node_labels: Option<&PyAny>) -> PyResult<()> {
+        self.node_labels = labels::validate_labels(py, node_labels, self.node_count())?;
+        Ok(())
+    }
+
+    /// Returns the subgraph induced by the given nodes, i.e., the nodes and
+    /// all edges between them, together with a numpy array that contains the
+    /// original id of each subgraph node.
+    ///
+    /// Subgraph nodes keep the relative order of their original ids.
+    pub fn subgraph<'py>(
+        &self,
+        py: Python<'py>,
+        nodes: &PyAny,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let (g, mapping) = self.inner.induced_subgraph(py, nodes)?;
+        let mut subgraph = Self::new(g.load_micros, g);
+        subgraph.node_labels = subgraph::subgraph_labels(py, self.node_labels.as_ref(), &mapping)?;
+        Ok((subgraph, mapping.into_pyarray(py)))
+    }
+
+    /// Returns the subgraph induced by all nodes that can be reached from
+    /// `node` by following at most `radius` outgoing edges, together with a numpy
+    /// array that contains the original id of each subgraph node.
+    ///
+    /// `node` always becomes node `0` of the subgraph.
+    #[args(radius = "1")]
+    pub fn ego<'py>(
+        &self,
+        py: Python<'py>,
+        node: u32,
+        radius: usize,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let (g, mapping) = self.inner.ego_subgraph(py, node, radius)?;
+        let mut subgraph = Self::new(g.load_micros, g);
+        subgraph.node_labels = subgraph::subgraph_labels(py, self.node_labels.as_ref(), &mapping)?;
+        Ok((subgraph, mapping.into_pyarray(py)))
+    }
+
+    /// Returns the label of the given node.
+    pub fn label(&self, py: Python<'_>, node: u32) -> PyResult<PyObject> {
+        labels::label(py, self.node_labels.as_ref(), node)
+    }
+
+    /// Returns all nodes with a label equal to the given label as a numpy
+    /// array in ascending order.
+    pub fn nodes_with_label<'py>(
+        &self,
+        py: Python<'py>,
+        label: &PyAny,
+    ) -> PyResult<&'py PyArray1<u32>> {
+        let nodes = labels::nodes_with_label(py, self.node_labels.as_ref(), label)?;
+        Ok(nodes.into_pyarray(py))
+    }
+
+    /// Returns a dict that maps each label to the number of nodes with that
+    /// label.
+    pub fn label_counts<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        labels::label_counts(py, self.node_labels.as_ref())
+    }
+
+    /// Returns the subgraph induced by all nodes with one of the given labels,
+    /// together with a numpy array that contains the original id of each
+    /// subgraph node.
+    pub fn subgraph_with_labels<'py>(
+        &self,
+        py: Python<'py>,
+        labels: &PyAny,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let nodes = labels::nodes_with_any_label(py, self.node_labels.as_ref(), labels)?;
+        self.subgraph(py, nodes.into_pyarray(py))
+    }
+
+    /// Returns a graph that only contains the edges with a weight of at least
+    /// `min_weight` and at most `max_weight`.
+    ///
+    /// All nodes are kept, so node ids are the same as in this graph.
+    #[args("*", min_weight = "None", max_weight = "None")]
+    pub fn filter(&self, py: Python<'_>, min_weight: Option<f32>, max_weight: Option<f32>) -> Self {
+        let g = self.inner.filter_by_weight(py, min_weight, max_weight);
+        let mut filtered = Self::new(g.load_micros, g);
+        filtered.node_labels = self.node_labels.as_ref().map(|labels| labels.clone_ref(py));
+        filtered
+    }
+
+    /// Returns a graph with the same edges, where each weight `w` is
+    /// replaced according to `transform`, which is one of `"inverse"`
+    /// (`1 / w`), `"log"` (`ln(w)`), `"negative_log"` (`-ln(w)`) or
+    /// `"complement"` (`1 - w`).
+    ///
+    /// This turns similarities into distances before running shortest path
+    /// algorithms, e.g., `"negative_log"` for probabilities and
+    /// `"complement"` for cosine similarities.
+    pub fn transform_weights(&self, py: Python<'_>, transform: &str) -> PyResult<Self> {
+        let g = self.inner.transform_weights(py, transform)?;
+        let mut transformed = Self::new(g.load_micros, g);
+        transformed.node_labels = self.node_labels.as_ref().map(|labels| labels.clone_ref(py));
+        Ok(transformed)
+    }
+
+    /// Run Single Source Shortest Path on this graph using delta-stepping.
+    pub fn sssp(&self, py: Python<'_>, start_node: u32, delta: f32) -> PyResult<SsspResult> {
+        let config = DeltaSteppingConfig::new(start_node as usize, delta);
+        crate::sssp::weighted_sssp(py, self.inner.g(), config)
+    }
+
+    /// Compute the shortest paths from `start_node` to all other nodes using
+    /// Dijkstra's algorithm, following outgoing edges.
+    ///
+    /// Unlike `sssp`, the result also contains the shortest paths, which are
+    /// returned by `path_to`. Edge weights must not be negative.
+    pub fn dijkstra(&self, py: Python<'_>, start_node: u32) -> PyResult<ShortestPathResult> {
+        crate::sssp::shortest_paths(py, self.inner.g(), start_node, None, None)
+    }
+
+    /// Compute the shortest path from `start_node` to `target_node` using A*,
+    /// following outgoing edges.
+    ///
+    /// `heuristic` contains an estimate of the remaining distance of each
+    /// node to `target_node`, e.g., the straight-line distance, which must
+    /// never overestimate the actual distance. Without it, the search stops
+    /// as soon as `target_node` is reached, but is otherwise the same as
+    /// `dijkstra`. Only the distances of nodes settled before the target are
+    /// final.
+    #[args(heuristic = "None")]
+    pub fn astar(
+        &self,
+        py: Python<'_>,
+        start_node: u32,
+        target_node: u32,
+        heuristic: Option<&PyAny>,
+    ) -> PyResult<ShortestPathResult> {
+        let heuristic = match heuristic {
+            Some(heuristic) => Some(
+                contiguous_array::<f32>(py, heuristic, "heuristic")?
+                    .as_slice()?
+                    .to_vec(),
+            ),
+            None => None,
+        };
+        crate::sssp::shortest_paths(py, self.inner.g(), start_node, Some(target_node), heuristic)
+    }
+
+    /// Compute a shortest path from `source_node` to `target_node`,
+    /// following edges, using a bidirectional Dijkstra search.
+    ///
+    /// Returns the distance and the nodes on the path, including both end
+    /// nodes, or `None` if `target_node` is not reachable. Only the nodes
+    /// around the path are visited, which is much faster than `dijkstra` for
+    /// a single pair of nodes. Edge weights must not be negative, which is
+    /// not checked to keep queries independent of the graph size.
+    pub fn shortest_path<'py>(
+        &self,
+        py: Python<'py>,
+        source_node: u32,
+        target_node: u32,
+    ) -> PyResult<Option<(f32, &'py PyArray1<u32>)>> {
+        crate::sssp::weighted_pair_path(py, self.inner.g(), source_node, target_node)
+    }
+
+    /// Build a landmark index, which bounds the distance between any two
+    /// nodes, following outgoing edges, in time independent of the graph size.
+    ///
+    /// `selection` is either `"farthest"`, which chooses each landmark as the
+    /// node that is farthest from the previous landmarks, or `"random"`.
+    #[args(
+        landmark_count = "LandmarkConfig::DEFAULT_LANDMARK_COUNT",
+        "*",
+        selection = "\"farthest\"",
+        seed = "LandmarkConfig::DEFAULT_SEED"
+    )]
+    pub fn landmark_index(
+        &self,
+        py: Python<'_>,
+        landmark_count: usize,
+        selection: &str,
+        seed: u64,
+    ) -> PyResult<LandmarkIndex> {
+        let config = crate::landmarks::config(landmark_count, selection, seed)?;
+        Ok(crate::landmarks::weighted_landmark_index(
+            py,
+            self.inner.g(),
+            config,
+        ))
+    }
+
+    /// Build a reachability index, which answers whether a node can reach
+    /// another node by following outgoing edges, mostly in constant time.
+    ///
+    /// More intervals per strongly connected component (`label_count`)
+    /// answer more queries without searching the graph.
+    #[args(
+        label_count = "ReachabilityConfig::DEFAULT_LABEL_COUNT",
+        "*",
+        seed = "ReachabilityConfig::DEFAULT_SEED"
+    )]
+    pub fn reachability_index(
+        &self,
+        py: Python<'_>,
+        label_count: usize,
+        seed: u64,
+    ) -> ReachabilityIndex {
+        let config = ReachabilityConfig::new(label_count, seed);
+        crate::reachability::reachability_index(py, self.inner.g(), config)
+    }
+
+    /// Count the nodes that each node can reach by following outgoing edges,
+    /// excluding the node itself.
+    ///
+    /// The counts are exact if the graph has at most `exact_threshold`
+    /// strongly connected components and estimated with HyperLogLog counters
+    /// of `2^precision` registers otherwise.
+    #[args(
+        "*",
+        exact_threshold = "DescendantsConfig::DEFAULT_EXACT_THRESHOLD",
+        precision = "DescendantsConfig::DEFAULT_PRECISION"
+    )]
+    pub fn descendant_counts<'py>(
+        &self,
+        py: Python<'py>,
+        exact_threshold: usize,
+        precision: u8,
+    ) -> PyResult<&'py PyArray1<f64>> {
+        let config = DescendantsConfig::new(exact_threshold, precision);
+        crate::reachability::descendant_counts(py, self.inner.g(), config)
+    }
+}
+
+impl std::fmt::Debug for WeightedDiGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
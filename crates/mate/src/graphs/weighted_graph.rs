@@ -0,0 +1,271 @@
+use super::{arrays::contiguous_array, persistence::GraphKind, Layout, LayoutArg, PyGraph};
+use crate::{landmarks::LandmarkIndex, sssp::ShortestPathResult};
+use graph::prelude::{EdgeListInput, LandmarkConfig, UndirectedCsrGraph};
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
+use std::path::PathBuf;
+
+pub(crate) fn register(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<WeightedGraph>()?;
+    Ok(())
+}
+
+/// An undirected graph using 32 bits for node ids and 32 bit floats as edge weights.
+#[pyclass]
+pub struct WeightedGraph {
+    inner: PyGraph<u32, UndirectedCsrGraph<u32, (), f32>>,
+    #[pyo3(get)]
+    load_micros: u64,
+}
+
+impl WeightedGraph {
+    pub(super) fn new(
+        load_micros: u64,
+        inner: PyGraph<u32, UndirectedCsrGraph<u32, (), f32>>,
+    ) -> Self {
+        Self { inner, load_micros }
+    }
+}
+
+#[pymethods]
+impl WeightedGraph {
+    /// Load a graph from an edge list, where each line represents an edge in
+    /// the form of `<source_id> <target_id> <weight>`.
+    #[staticmethod]
+    #[args(layout = "None")]
+    pub fn load(py: Python<'_>, path: PathBuf, layout: Option<LayoutArg>) -> PyResult<Self> {
+        let g = PyGraph::load_file_input(
+            py,
+            path,
+            layout.map(Layout::from),
+            EdgeListInput::<u32, f32>::default(),
+        )?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Create a graph from arrays of source ids, target ids and edge weights.
+    ///
+    /// The arrays are converted to `uint32` and `float32` numpy arrays, which
+    /// does not copy the data if they already have those types.
+    #[staticmethod]
+    #[args(layout = "None")]
+    pub fn from_arrays(
+        py: Python<'_>,
+        sources: &PyAny,
+        targets: &PyAny,
+        weights: &PyAny,
+        layout: Option<LayoutArg>,
+    ) -> PyResult<Self> {
+        let g =
+            PyGraph::from_weighted_arrays(py, sources, targets, weights, layout.map(Layout::from))?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> u32 {
+        self.inner.node_count()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_count(&self) -> u32 {
+        self.inner.edge_count()
+    }
+
+    /// Returns the number of edges connected to the given node.
+    pub fn degree(&self, node: u32) -> u32 {
+        self.inner.degree(node)
+    }
+
+    /// Returns summary statistics of this graph as a dict, i.e., the node
+    /// and edge counts, the density, the minimum, maximum, mean and 50th, 90th
+    /// and 99th percentile of the degrees, and the number of bytes used
+    /// to store the graph.
+    pub fn stats<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        self.inner.stats(py)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.inner.__repr__()
+    }
+
+    /// Returns a binary snapshot of this graph, which can be turned back into
+    /// a graph with `from_bytes`.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        self.inner.to_bytes(py, GraphKind::WeightedGraph)
+    }
+
+    /// Create a graph from a binary snapshot created by `to_bytes`.
+    ///
+    /// Only use snapshots from trusted sources, the data is not validated
+    /// beyond its header.
+    #[staticmethod]
+    pub fn from_bytes(py: Python<'_>, data: &[u8]) -> PyResult<Self> {
+        let g = PyGraph::from_bytes(py, data, GraphKind::WeightedGraph)?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Write a binary snapshot of this graph to the given file, which can be
+    /// loaded much faster than the original input with `load_binary`.
+    pub fn save(&self, py: Python<'_>, path: PathBuf) -> PyResult<()> {
+        self.inner.save(py, path, GraphKind::WeightedGraph)
+    }
+
+    /// Load a graph from a binary snapshot file written by `save`.
+    ///
+    /// Only use snapshots from trusted sources, the data is not validated
+    /// beyond its header.
+    #[staticmethod]
+    pub fn load_binary(py: Python<'_>, path: PathBuf) -> PyResult<Self> {
+        let g = PyGraph::load_binary(py, path, GraphKind::WeightedGraph)?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Returns the state for `pickle`, which is the binary snapshot of the
+    /// graph.
+    pub fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<(PyObject, (&'py PyBytes,))> {
+        let from_bytes = py.get_type::<Self>().getattr("from_bytes")?.into();
+        Ok((from_bytes, (self.to_bytes(py)?,)))
+    }
+
+    /// Returns the subgraph induced by the given nodes, i.e., the nodes and
+    /// all edges between them, together with a numpy array that contains the
+    /// original id of each subgraph node.
+    ///
+    /// Subgraph nodes keep the relative order of their original ids.
+    pub fn subgraph<'py>(
+        &self,
+        py: Python<'py>,
+        nodes: &PyAny,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let (g, mapping) = self.inner.induced_subgraph(py, nodes)?;
+        Ok((Self::new(g.load_micros, g), mapping.into_pyarray(py)))
+    }
+
+    /// Returns the subgraph induced by all nodes that can be reached from
+    /// `node` by following at most `radius` edges, together with a numpy
+    /// array that contains the original id of each subgraph node.
+    ///
+    /// `node` always becomes node `0` of the subgraph.
+    #[args(radius = "1")]
+    pub fn ego<'py>(
+        &self,
+        py: Python<'py>,
+        node: u32,
+        radius: usize,
+    ) -> PyResult<(Self, &'py PyArray1<u32>)> {
+        let (g, mapping) = self.inner.ego_subgraph(py, node, radius)?;
+        Ok((Self::new(g.load_micros, g), mapping.into_pyarray(py)))
+    }
+
+    /// Returns a graph that only contains the edges with a weight of at least
+    /// `min_weight` and at most `max_weight`.
+    ///
+    /// All nodes are kept, so node ids are the same as in this graph.
+    #[args("*", min_weight = "None", max_weight = "None")]
+    pub fn filter(&self, py: Python<'_>, min_weight: Option<f32>, max_weight: Option<f32>) -> Self {
+        let g = self.inner.filter_by_weight(py, min_weight, max_weight);
+        Self::new(g.load_micros, g)
+    }
+
+    /// Returns a graph with the same edges, where each weight `w` is
+    /// replaced according to `transform`, which is one of `"inverse"`
+    /// (`1 / w`), `"log"` (`ln(w)`), `"negative_log"` (`-ln(w)`) or
+    /// `"complement"` (`1 - w`).
+    ///
+    /// This turns similarities into distances before running shortest path
+    /// algorithms, e.g., `"negative_log"` for probabilities and
+    /// `"complement"` for cosine similarities.
+    pub fn transform_weights(&self, py: Python<'_>, transform: &str) -> PyResult<Self> {
+        let g = self.inner.transform_weights(py, transform)?;
+        Ok(Self::new(g.load_micros, g))
+    }
+
+    /// Compute the shortest paths from `start_node` to all other nodes using
+    /// Dijkstra's algorithm, following edges.
+    ///
+    /// The result contains the distances and the shortest paths, which are
+    /// returned by `path_to`. Edge weights must not be negative.
+    pub fn dijkstra(&self, py: Python<'_>, start_node: u32) -> PyResult<ShortestPathResult> {
+        crate::sssp::shortest_paths(py, self.inner.g(), start_node, None, None)
+    }
+
+    /// Compute the shortest path from `start_node` to `target_node` using A*,
+    /// following edges.
+    ///
+    /// `heuristic` contains an estimate of the remaining distance of each
+    /// node to `target_node`, e.g., the straight-line distance, which must
+    /// never overestimate the actual distance. Without it, the search stops
+    /// as soon as `target_node` is reached, but is otherwise the same as
+    /// `dijkstra`. Only the distances of nodes settled before the target are
+    /// final.
+    #[args(heuristic = "None")]
+    pub fn astar(
+        &self,
+        py: Python<'_>,
+        start_node: u32,
+        target_node: u32,
+        heuristic: Option<&PyAny>,
+    ) -> PyResult<ShortestPathResult> {
+        let heuristic = match heuristic {
+            Some(heuristic) => Some(
+                contiguous_array::<f32>(py, heuristic, "heuristic")?
+                    .as_slice()?
+                    .to_vec(),
+            ),
+            None => None,
+        };
+        crate::sssp::shortest_paths(py, self.inner.g(), start_node, Some(target_node), heuristic)
+    }
+
+    /// Compute a shortest path from `source_node` to `target_node`,
+    /// following edges, using a bidirectional Dijkstra search.
+    ///
+    /// Returns the distance and the nodes on the path, including both end
+    /// nodes, or `None` if `target_node` is not reachable. Only the nodes
+    /// around the path are visited, which is much faster than `dijkstra` for
+    /// a single pair of nodes. Edge weights must not be negative, which is
+    /// not checked to keep queries independent of the graph size.
+    pub fn shortest_path<'py>(
+        &self,
+        py: Python<'py>,
+        source_node: u32,
+        target_node: u32,
+    ) -> PyResult<Option<(f32, &'py PyArray1<u32>)>> {
+        crate::sssp::weighted_pair_path(py, self.inner.g(), source_node, target_node)
+    }
+
+    /// Build a landmark index, which bounds the distance between any two
+    /// nodes in time independent of the graph size.
+    ///
+    /// `selection` is either `"farthest"`, which chooses each landmark as the
+    /// node that is farthest from the previous landmarks, or `"random"`.
+    #[args(
+        landmark_count = "LandmarkConfig::DEFAULT_LANDMARK_COUNT",
+        "*",
+        selection = "\"farthest\"",
+        seed = "LandmarkConfig::DEFAULT_SEED"
+    )]
+    pub fn landmark_index(
+        &self,
+        py: Python<'_>,
+        landmark_count: usize,
+        selection: &str,
+        seed: u64,
+    ) -> PyResult<LandmarkIndex> {
+        let config = crate::landmarks::config(landmark_count, selection, seed)?;
+        Ok(crate::landmarks::weighted_landmark_index(
+            py,
+            self.inner.g(),
+            config,
+        ))
+    }
+}
+
+impl std::fmt::Debug for WeightedGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
@@ -0,0 +1,97 @@
+use graph::prelude::{
+    hyperball as run, BidirectionalNeighbors, Graph as GraphTrait, HyperBallConfig,
+    HyperBallResult as Result,
+};
+use numpy::PyArray1;
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::time::{Duration, Instant};
+
+pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<HyperBallResult>()?;
+    Ok(())
+}
+
+pub(crate) fn hyperball<G>(
+    py: Python<'_>,
+    graph: &G,
+    config: HyperBallConfig,
+) -> PyResult<HyperBallResult>
+where
+    G: GraphTrait<u32> + BidirectionalNeighbors<u32> + Sync,
+{
+    if !(4..=16).contains(&config.precision) {
+        return Err(PyValueError::new_err(format!(
+            "precision must be between 4 and 16, got {}",
+            config.precision
+        )));
+    }
+
+    Ok(py.allow_threads(|| {
+        let start = Instant::now();
+        let result = run(graph, config);
+        let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
+        HyperBallResult { result, micros }
+    }))
+}
+
+/// Estimates of the neighborhood function and of the closeness and harmonic
+/// centrality of each node.
+#[pyclass]
+pub struct HyperBallResult {
+    result: Result,
+    #[pyo3(get)]
+    micros: u64,
+}
+
+impl std::fmt::Debug for HyperBallResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HyperBallResult")
+            .field("iterations", &self.result.iterations)
+            .field("effective_diameter", &self.result.effective_diameter(0.9))
+            .field("took", &Duration::from_micros(self.micros))
+            .finish()
+    }
+}
+
+#[pymethods]
+impl HyperBallResult {
+    /// The number of iterations, which is one more than the radius at which
+    /// all balls were complete, unless `max_iterations` was reached.
+    #[getter]
+    pub fn iterations(&self) -> usize {
+        self.result.iterations
+    }
+
+    /// The estimated number of pairs of nodes within distance `t` at index
+    /// `t`, including pairs of a node with itself.
+    pub fn neighborhood_function<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        PyArray1::from_slice(py, &self.result.neighborhood_function)
+    }
+
+    /// The estimated number of reachable nodes divided by the sum of their
+    /// distances for each node, or zero if no other node is reachable.
+    pub fn closeness<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        PyArray1::from_slice(py, &self.result.closeness)
+    }
+
+    /// The estimated sum of the inverse distances to all reachable nodes.
+    pub fn harmonic<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
+        PyArray1::from_slice(py, &self.result.harmonic)
+    }
+
+    /// The smallest distance, interpolated between integers, within which
+    /// the fraction `quantile` of all connected pairs of nodes lie.
+    #[args(quantile = "0.9")]
+    pub fn effective_diameter(&self, quantile: f64) -> PyResult<f64> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PyValueError::new_err(format!(
+                "quantile must be between 0 and 1, got {quantile}"
+            )));
+        }
+        Ok(self.result.effective_diameter(quantile))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -0,0 +1,194 @@
+use crate::graphs::contiguous_array;
+use graph::prelude::{
+    BidirectionalNeighbors, Graph as GraphTrait, LandmarkConfig, LandmarkIndex as Index,
+    LandmarkSelection, WeightedBackwardNeighbors,
+};
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use rayon::prelude::*;
+use std::time::{Duration, Instant};
+
+pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<LandmarkIndex>()?;
+    Ok(())
+}
+
+pub(crate) fn config(
+    landmark_count: usize,
+    selection: &str,
+    seed: u64,
+) -> PyResult<LandmarkConfig> {
+    let selection = match selection.to_ascii_lowercase().as_str() {
+        "farthest" => LandmarkSelection::Farthest,
+        "random" => LandmarkSelection::Random,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "unknown selection {selection:?}, expected one of 'farthest' or 'random'"
+            )))
+        }
+    };
+    Ok(LandmarkConfig::new(landmark_count, selection, seed))
+}
+
+pub(crate) fn weighted_landmark_index<G>(
+    py: Python<'_>,
+    graph: &G,
+    config: LandmarkConfig,
+) -> LandmarkIndex
+where
+    G: GraphTrait<u32> + WeightedBackwardNeighbors<u32> + Sync,
+{
+    py.allow_threads(|| {
+        let start = Instant::now();
+        let index = Index::weighted(graph, config);
+        LandmarkIndex::new(index, graph.node_count(), start)
+    })
+}
+
+pub(crate) fn landmark_index<G>(py: Python<'_>, graph: &G, config: LandmarkConfig) -> LandmarkIndex
+where
+    G: GraphTrait<u32> + BidirectionalNeighbors<u32> + Sync,
+{
+    py.allow_threads(|| {
+        let start = Instant::now();
+        let index = Index::unweighted(graph, config);
+        LandmarkIndex::new(index, graph.node_count(), start)
+    })
+}
+
+/// The distances from and to a set of landmark nodes, which bound the
+/// distance between any two nodes.
+#[pyclass]
+pub struct LandmarkIndex {
+    index: Index<u32>,
+    #[pyo3(get)]
+    node_count: u32,
+    #[pyo3(get)]
+    micros: u64,
+}
+
+impl LandmarkIndex {
+    fn new(index: Index<u32>, node_count: u32, start: Instant) -> Self {
+        let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
+        Self {
+            index,
+            node_count,
+            micros,
+        }
+    }
+
+    fn validate(&self, nodes: &[u32]) -> PyResult<()> {
+        match nodes.iter().find(|&&node| node >= self.node_count) {
+            Some(node) => Err(PyValueError::new_err(format!(
+                "node {node} is not contained in the graph"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Applies `bound` to each pair of nodes from `sources` and `targets`.
+    fn bounds<'py>(
+        &self,
+        py: Python<'py>,
+        sources: &PyAny,
+        targets: &PyAny,
+        bound: fn(&Index<u32>, u32, u32) -> f32,
+    ) -> PyResult<&'py PyArray1<f32>> {
+        let sources = contiguous_array::<u32>(py, sources, "sources")?;
+        let targets = contiguous_array::<u32>(py, targets, "targets")?;
+        let (sources, targets) = (sources.as_slice()?, targets.as_slice()?);
+        if sources.len() != targets.len() {
+            return Err(PyValueError::new_err(format!(
+                "sources and targets must have the same length, got {} and {}",
+                sources.len(),
+                targets.len()
+            )));
+        }
+        self.validate(sources)?;
+        self.validate(targets)?;
+
+        let bounds = py.allow_threads(|| {
+            sources
+                .par_iter()
+                .zip(targets.par_iter())
+                .map(|(&source, &target)| bound(&self.index, source, target))
+                .collect::<Vec<_>>()
+        });
+        Ok(bounds.into_pyarray(py))
+    }
+}
+
+impl std::fmt::Debug for LandmarkIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LandmarkIndex")
+            .field("landmarks", &self.index.landmarks().len())
+            .field("node_count", &self.node_count)
+            .field("took", &Duration::from_micros(self.micros))
+            .finish()
+    }
+}
+
+#[pymethods]
+impl LandmarkIndex {
+    /// The landmark nodes in the order in which they were chosen.
+    pub fn landmarks<'py>(&self, py: Python<'py>) -> &'py PyArray1<u32> {
+        PyArray1::from_slice(py, self.index.landmarks())
+    }
+
+    /// A lower bound for the distance from `source_node` to `target_node`,
+    /// which is infinite if the landmarks prove that there is no path.
+    pub fn lower_bound(&self, source_node: u32, target_node: u32) -> PyResult<f32> {
+        self.validate(&[source_node, target_node])?;
+        Ok(self.index.lower_bound(source_node, target_node))
+    }
+
+    /// An upper bound for the distance from `source_node` to `target_node`,
+    /// which is the length of the shortest path through a landmark.
+    pub fn upper_bound(&self, source_node: u32, target_node: u32) -> PyResult<f32> {
+        self.validate(&[source_node, target_node])?;
+        Ok(self.index.upper_bound(source_node, target_node))
+    }
+
+    /// The lower bounds for each pair of nodes from the arrays `sources` and
+    /// `targets`, computed in parallel.
+    pub fn lower_bounds<'py>(
+        &self,
+        py: Python<'py>,
+        sources: &PyAny,
+        targets: &PyAny,
+    ) -> PyResult<&'py PyArray1<f32>> {
+        self.bounds(py, sources, targets, Index::lower_bound)
+    }
+
+    /// The upper bounds for each pair of nodes from the arrays `sources` and
+    /// `targets`, computed in parallel.
+    pub fn upper_bounds<'py>(
+        &self,
+        py: Python<'py>,
+        sources: &PyAny,
+        targets: &PyAny,
+    ) -> PyResult<&'py PyArray1<f32>> {
+        self.bounds(py, sources, targets, Index::upper_bound)
+    }
+
+    /// The lower bound for the distance of each node to `target_node`, which
+    /// can be passed as the `heuristic` of `astar`.
+    pub fn heuristic<'py>(
+        &self,
+        py: Python<'py>,
+        target_node: u32,
+    ) -> PyResult<&'py PyArray1<f32>> {
+        self.validate(&[target_node])?;
+        let heuristic = py.allow_threads(|| {
+            (0..self.node_count)
+                .into_par_iter()
+                .map(|node| self.index.lower_bound(node, target_node))
+                .collect::<Vec<_>>()
+        });
+        Ok(heuristic.into_pyarray(py))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -3,13 +3,23 @@
 use ::graph::prelude::Error as GError;
 use pyo3::{
     exceptions::PyValueError,
-    prelude::{pymodule, IntoPy, PyErr, PyModule, PyObject, PyResult, Python},
-    PyErrArguments,
+    prelude::{pyfunction, pymodule, IntoPy, PyErr, PyModule, PyObject, PyResult, Python},
+    wrap_pyfunction, PyErrArguments,
 };
-use pyo3_log::{Caching, Logger};
+use pyo3_log::{Caching, Logger, ResetHandle};
+use std::sync::OnceLock;
 
+mod community;
+mod embeddings;
 mod graphs;
+mod hyperball;
+mod landmarks;
 mod page_rank;
+mod pandas;
+mod progress;
+mod reachability;
+mod sssp;
+mod summary;
 mod triangle_count;
 mod wcc;
 
@@ -27,17 +37,38 @@ impl From<GraphError> for PyErr {
     }
 }
 
+static LOG_RESET: OnceLock<ResetHandle> = OnceLock::new();
+
+/// Apply changes to the Python logging configuration, e.g., new log levels,
+/// to the messages that are logged while running algorithms.
+///
+/// Loggers and their levels are cached when they are first used, so changes
+/// made after that are only picked up once this function has been called.
+#[pyfunction]
+fn reset_logging() {
+    if let Some(handle) = LOG_RESET.get() {
+        handle.reset();
+    }
+}
+
 /// Python API for the graph crate
 #[pymodule]
 fn graph_mate(py: Python, m: &PyModule) -> PyResult<()> {
-    Logger::new(py, Caching::LoggersAndLevels)?
+    let handle = Logger::new(py, Caching::LoggersAndLevels)?
         .install()
         .unwrap();
+    let _ = LOG_RESET.set(handle);
 
     graphs::register(py, m)?;
     page_rank::register(py, m)?;
     wcc::register(py, m)?;
+    sssp::register(py, m)?;
     triangle_count::register(py, m)?;
+    community::register(py, m)?;
+    landmarks::register(py, m)?;
+    hyperball::register(py, m)?;
+    reachability::register(py, m)?;
+    m.add_function(wrap_pyfunction!(reset_logging, m)?)?;
 
     Ok(())
 }
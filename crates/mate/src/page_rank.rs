@@ -1,10 +1,13 @@
-use crate::graphs::SharedSlice;
+use crate::{
+    graphs::{SharedSlice, SharedSliceIter},
+    progress::PyProgress,
+};
 use graph::prelude::{
-    page_rank as graph_page_rank, DirectedDegrees, DirectedNeighbors, Graph as GraphTrait, Idx,
-    PageRankConfig,
+    page_rank_from_scores_with_progress, page_rank_with_progress, top_k, DirectedDegrees,
+    DirectedNeighbors, Graph as GraphTrait, Idx, PageRankConfig, ResultSummary,
 };
-use numpy::PyArray1;
-use pyo3::prelude::*;
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{exceptions::PyValueError, prelude::*};
 use std::time::{Duration, Instant};
 
 pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
@@ -12,23 +15,51 @@ pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     Ok(())
 }
 
-pub(crate) fn page_rank<NI, G, C>(py: Python<'_>, graph: &G, config: C) -> PageRankResult
+pub(crate) fn page_rank<NI, G, C>(
+    py: Python<'_>,
+    graph: &G,
+    config: C,
+    initial_scores: Option<Vec<f32>>,
+    progress: PyProgress,
+) -> PyResult<PageRankResult>
 where
     NI: Idx,
     G: GraphTrait<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
     C: Into<Option<PageRankConfig>> + Send,
 {
-    py.allow_threads(move || inner_page_rank(graph, config))
+    if let Some(initial_scores) = &initial_scores {
+        let node_count = graph.node_count().index();
+        if initial_scores.len() != node_count {
+            return Err(PyValueError::new_err(format!(
+                "initial_scores must contain one score per node ({node_count}), got {}",
+                initial_scores.len()
+            )));
+        }
+    }
+
+    let result = py.allow_threads(|| inner_page_rank(graph, config, initial_scores, &progress));
+    progress.finish()?;
+    Ok(result)
 }
 
-fn inner_page_rank<NI, G>(graph: &G, config: impl Into<Option<PageRankConfig>>) -> PageRankResult
+fn inner_page_rank<NI, G>(
+    graph: &G,
+    config: impl Into<Option<PageRankConfig>>,
+    initial_scores: Option<Vec<f32>>,
+    progress: &PyProgress,
+) -> PageRankResult
 where
     NI: Idx,
     G: GraphTrait<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
 {
     let config = config.into().unwrap_or_default();
     let start = Instant::now();
-    let (scores, ran_iterations, error) = graph_page_rank(graph, config);
+    let (scores, ran_iterations, error) = match initial_scores {
+        Some(initial_scores) => {
+            page_rank_from_scores_with_progress(graph, config, initial_scores, progress)
+        }
+        None => page_rank_with_progress(graph, config, progress),
+    };
     let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
     let scores = SharedSlice::from_vec(scores);
     PageRankResult {
@@ -68,6 +99,50 @@ impl PageRankResult {
         self.scores.clone().into_numpy(py)
     }
 
+    /// Returns the `k` highest-scoring nodes and their scores, ordered by
+    /// descending score, without sorting all scores.
+    pub fn top_k<'py>(
+        &self,
+        py: Python<'py>,
+        k: usize,
+    ) -> (&'py PyArray1<u32>, &'py PyArray1<f32>) {
+        let scores = self.scores.as_slice::<f32>();
+        let (nodes, scores): (Vec<u32>, Vec<f32>) =
+            py.allow_threads(|| top_k::<u32, _>(scores, k).into_iter().unzip());
+        (nodes.into_pyarray(py), scores.into_pyarray(py))
+    }
+
+    /// Returns the percentiles, the maximum and the mean of the scores as a
+    /// dict with the keys `p50`, `p90`, `p99`, `max` and `mean`.
+    pub fn summary(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let scores = self.scores.as_slice::<f32>();
+        let summary = py.allow_threads(|| ResultSummary::from_scores(scores));
+        crate::summary::into_dict(py, summary)
+    }
+
+    #[args(dtype = "None")]
+    fn __array__(&self, py: Python<'_>, dtype: Option<&PyAny>) -> PyResult<PyObject> {
+        self.scores.clone().into_array::<f32>(py, dtype)
+    }
+
+    /// Returns the score of each node as a pandas dataframe indexed by `node_id`.
+    fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let scores = self.scores.clone().into_numpy::<f32>(py)?;
+        crate::pandas::node_frame(py, "score", scores)
+    }
+
+    fn __len__(&self) -> usize {
+        self.scores.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: &PyAny) -> PyResult<PyObject> {
+        self.scores.get(py, index)
+    }
+
+    fn __iter__(&self) -> SharedSliceIter {
+        self.scores.iter()
+    }
+
     fn __repr__(&self) -> String {
         format!("{self:?}")
     }
@@ -0,0 +1,39 @@
+use numpy::IntoPyArray;
+use pyo3::{prelude::*, types::PyDict};
+
+/// Creates a dataframe with one row per edge and the columns `src`, `dst`
+/// and, if weights are given, `weight`.
+pub(crate) fn edge_frame(
+    py: Python<'_>,
+    sources: Vec<u32>,
+    targets: Vec<u32>,
+    weights: Option<Vec<f32>>,
+) -> PyResult<PyObject> {
+    let columns = PyDict::new(py);
+    columns.set_item("src", sources.into_pyarray(py))?;
+    columns.set_item("dst", targets.into_pyarray(py))?;
+    if let Some(weights) = weights {
+        columns.set_item("weight", weights.into_pyarray(py))?;
+    }
+    Ok(data_frame(py, columns, false)?.into_py(py))
+}
+
+/// Creates a dataframe with one row per node, indexed by `node_id`, and a
+/// single column containing the given values.
+///
+/// The values are copied, so that the dataframe can be modified even if they
+/// are a read-only view of an algorithm result.
+pub(crate) fn node_frame(py: Python<'_>, column: &str, values: &PyAny) -> PyResult<PyObject> {
+    let columns = PyDict::new(py);
+    columns.set_item(column, values)?;
+    let frame = data_frame(py, columns, true)?;
+    Ok(frame.call_method1("rename_axis", ("node_id",))?.into_py(py))
+}
+
+fn data_frame<'py>(py: Python<'py>, columns: &PyDict, copy: bool) -> PyResult<&'py PyAny> {
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("copy", copy)?;
+    py.import("pandas")?
+        .getattr("DataFrame")?
+        .call((columns,), Some(kwargs))
+}
@@ -0,0 +1,58 @@
+use graph::prelude::ProgressTracker;
+use pyo3::{exceptions::PyTypeError, prelude::*};
+use std::sync::Mutex;
+
+/// Forwards progress updates of an algorithm to an optional Python callable.
+///
+/// The callable is invoked as `progress(task, completed, total)`, where
+/// `total` is `None` if the number of steps is not known upfront. Algorithms
+/// run without the GIL, so it is reacquired for every update. Updates are
+/// only sent after a batch of work, e.g., an iteration, to keep that cheap.
+///
+/// If the callable raises an exception, no further updates are sent and the
+/// exception is raised once the algorithm has finished.
+pub(crate) struct PyProgress {
+    callback: Option<PyObject>,
+    error: Mutex<Option<PyErr>>,
+}
+
+impl PyProgress {
+    pub(crate) fn new(py: Python<'_>, callback: Option<PyObject>) -> PyResult<Self> {
+        if let Some(callback) = &callback {
+            if !callback.as_ref(py).is_callable() {
+                return Err(PyTypeError::new_err(
+                    "progress must be a callable that accepts (task, completed, total)",
+                ));
+            }
+        }
+        Ok(Self {
+            callback,
+            error: Mutex::new(None),
+        })
+    }
+
+    /// Raises the exception of the callable, if there was any.
+    pub(crate) fn finish(self) -> PyResult<()> {
+        match self.error.into_inner().unwrap() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ProgressTracker for PyProgress {
+    fn progress(&self, task: &str, completed: usize, total: Option<usize>) {
+        let Some(callback) = &self.callback else {
+            return;
+        };
+        let mut error = self.error.lock().unwrap();
+        if error.is_some() {
+            return;
+        }
+        Python::with_gil(|py| {
+            if let Err(e) = callback.call1(py, (task, completed, total)) {
+                *error = Some(e);
+            }
+        });
+    }
+}
@@ -0,0 +1,141 @@
+use crate::graphs::contiguous_array;
+use graph::prelude::{
+    descendant_counts as counts, DescendantsConfig, DirectedNeighbors, Graph as GraphTrait,
+    ReachabilityConfig, ReachabilityIndex as Index,
+};
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use rayon::prelude::*;
+use std::time::{Duration, Instant};
+
+pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<ReachabilityIndex>()?;
+    Ok(())
+}
+
+pub(crate) fn reachability_index<G>(
+    py: Python<'_>,
+    graph: &G,
+    config: ReachabilityConfig,
+) -> ReachabilityIndex
+where
+    G: GraphTrait<u32> + DirectedNeighbors<u32> + Sync,
+{
+    py.allow_threads(|| {
+        let start = Instant::now();
+        let index = Index::new(graph, config);
+        let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
+        ReachabilityIndex {
+            index,
+            node_count: graph.node_count(),
+            micros,
+        }
+    })
+}
+
+pub(crate) fn descendant_counts<'py, G>(
+    py: Python<'py>,
+    graph: &G,
+    config: DescendantsConfig,
+) -> PyResult<&'py PyArray1<f64>>
+where
+    G: GraphTrait<u32> + DirectedNeighbors<u32> + Sync,
+{
+    if !(4..=16).contains(&config.precision) {
+        return Err(PyValueError::new_err(format!(
+            "precision must be between 4 and 16, got {}",
+            config.precision
+        )));
+    }
+    let result = py.allow_threads(|| counts(graph, config));
+    Ok(result.counts.into_pyarray(py))
+}
+
+/// The strongly connected components of a directed graph, labeled with
+/// intervals that answer whether a node can reach another node.
+#[pyclass]
+pub struct ReachabilityIndex {
+    index: Index<u32>,
+    #[pyo3(get)]
+    node_count: u32,
+    #[pyo3(get)]
+    micros: u64,
+}
+
+impl ReachabilityIndex {
+    fn validate(&self, nodes: &[u32]) -> PyResult<()> {
+        match nodes.iter().find(|&&node| node >= self.node_count) {
+            Some(node) => Err(PyValueError::new_err(format!(
+                "node {node} is not contained in the graph"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for ReachabilityIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReachabilityIndex")
+            .field("component_count", &self.index.component_count())
+            .field("node_count", &self.node_count)
+            .field("took", &Duration::from_micros(self.micros))
+            .finish()
+    }
+}
+
+#[pymethods]
+impl ReachabilityIndex {
+    /// The number of strongly connected components.
+    #[getter]
+    pub fn component_count(&self) -> usize {
+        self.index.component_count()
+    }
+
+    /// The strongly connected component of each node. Components are
+    /// numbered in topological order, i.e., edges never point from a
+    /// component to one with a lower number.
+    pub fn components<'py>(&self, py: Python<'py>) -> &'py PyArray1<u32> {
+        PyArray1::from_slice(py, self.index.components())
+    }
+
+    /// Whether there is a path from `source_node` to `target_node`.
+    pub fn can_reach(&self, source_node: u32, target_node: u32) -> PyResult<bool> {
+        self.validate(&[source_node, target_node])?;
+        Ok(self.index.can_reach(source_node, target_node))
+    }
+
+    /// Whether there is a path for each pair of nodes from the arrays
+    /// `sources` and `targets`, computed in parallel.
+    pub fn can_reach_many<'py>(
+        &self,
+        py: Python<'py>,
+        sources: &PyAny,
+        targets: &PyAny,
+    ) -> PyResult<&'py PyArray1<bool>> {
+        let sources = contiguous_array::<u32>(py, sources, "sources")?;
+        let targets = contiguous_array::<u32>(py, targets, "targets")?;
+        let (sources, targets) = (sources.as_slice()?, targets.as_slice()?);
+        if sources.len() != targets.len() {
+            return Err(PyValueError::new_err(format!(
+                "sources and targets must have the same length, got {} and {}",
+                sources.len(),
+                targets.len()
+            )));
+        }
+        self.validate(sources)?;
+        self.validate(targets)?;
+
+        let reachable = py.allow_threads(|| {
+            sources
+                .par_iter()
+                .zip(targets.par_iter())
+                .map(|(&source, &target)| self.index.can_reach(source, target))
+                .collect::<Vec<_>>()
+        });
+        Ok(reachable.into_pyarray(py))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -0,0 +1,373 @@
+use crate::graphs::{SharedSlice, SharedSliceIter};
+use graph::prelude::{
+    astar, bidirectional_bfs, bidirectional_dijkstra, delta_stepping, dijkstra,
+    BidirectionalNeighbors, CsrLayout, DeltaSteppingConfig, DirectedCsrGraph, DirectedNeighbors,
+    DirectedNeighborsWithValues, Graph as GraphTrait, GraphBuilder, PairPath, ShortestPaths,
+    WeightedBackwardNeighbors, WeightedNeighbors,
+};
+use numpy::{IntoPyArray, PyArray1};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::{
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+
+pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<SsspResult>()?;
+    m.add_class::<ShortestPathResult>()?;
+    Ok(())
+}
+
+pub(crate) fn sssp<G>(
+    py: Python<'_>,
+    graph: &G,
+    config: DeltaSteppingConfig,
+) -> PyResult<SsspResult>
+where
+    G: GraphTrait<u32> + DirectedNeighbors<u32> + Sync,
+{
+    validate(graph, &config)?;
+    Ok(py.allow_threads(move || {
+        let start = Instant::now();
+        let weighted = unit_weights(graph);
+        inner_sssp(&weighted, config, start)
+    }))
+}
+
+pub(crate) fn weighted_sssp<G>(
+    py: Python<'_>,
+    graph: &G,
+    config: DeltaSteppingConfig,
+) -> PyResult<SsspResult>
+where
+    G: GraphTrait<u32> + DirectedNeighborsWithValues<u32, f32> + Sync,
+{
+    validate(graph, &config)?;
+    Ok(py.allow_threads(move || inner_sssp(graph, config, Instant::now())))
+}
+
+fn validate<G>(graph: &G, config: &DeltaSteppingConfig) -> PyResult<()>
+where
+    G: GraphTrait<u32>,
+{
+    if config.start_node >= graph.node_count() as usize {
+        return Err(PyValueError::new_err(format!(
+            "start node {} is not contained in the graph",
+            config.start_node
+        )));
+    }
+    if config.delta.is_nan() || config.delta <= 0.0 {
+        return Err(PyValueError::new_err("delta must be positive"));
+    }
+    Ok(())
+}
+
+/// Delta-stepping requires edge weights, every edge has a weight of 1.
+fn unit_weights<G>(graph: &G) -> DirectedCsrGraph<u32, (), f32>
+where
+    G: GraphTrait<u32> + DirectedNeighbors<u32>,
+{
+    let node_count = graph.node_count();
+    let edges = (0..node_count)
+        .flat_map(|source| {
+            graph
+                .out_neighbors(source)
+                .map(move |&target| (source, target, 1.0_f32))
+        })
+        .collect::<Vec<_>>();
+    GraphBuilder::new()
+        .csr_layout(CsrLayout::Sorted)
+        .edges_with_values(edges)
+        .node_values(std::iter::repeat(()).take(node_count as usize))
+        .build()
+}
+
+fn inner_sssp<G>(graph: &G, config: DeltaSteppingConfig, start: Instant) -> SsspResult
+where
+    G: GraphTrait<u32> + DirectedNeighborsWithValues<u32, f32> + Sync,
+{
+    let distances = delta_stepping(graph, config)
+        .into_iter()
+        .map(|distance| distance.load(Ordering::Relaxed))
+        // Unreachable nodes are reported as infinitely far away.
+        .map(|distance| {
+            if distance == f32::MAX {
+                f32::INFINITY
+            } else {
+                distance
+            }
+        })
+        .collect::<Vec<_>>();
+    let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
+    let distances = SharedSlice::from_vec(distances);
+    SsspResult { distances, micros }
+}
+
+/// Runs Dijkstra's algorithm or, if a target node is given, A* guided by
+/// the given per-node estimates of the remaining distance.
+pub(crate) fn shortest_paths<G>(
+    py: Python<'_>,
+    graph: &G,
+    start_node: u32,
+    target_node: Option<u32>,
+    heuristic: Option<Vec<f32>>,
+) -> PyResult<ShortestPathResult>
+where
+    G: GraphTrait<u32> + WeightedNeighbors<u32> + Sync,
+{
+    let node_count = graph.node_count();
+    validate_nodes(graph, std::iter::once(start_node).chain(target_node))?;
+    if let Some(heuristic) = &heuristic {
+        if heuristic.len() != node_count as usize {
+            return Err(PyValueError::new_err(format!(
+                "Expected a heuristic with {node_count} values, got {}",
+                heuristic.len()
+            )));
+        }
+        if heuristic.iter().any(|estimate| estimate.is_nan()) {
+            return Err(PyValueError::new_err("The heuristic must not contain NaN"));
+        }
+    }
+
+    py.allow_threads(move || {
+        let start = Instant::now();
+        if has_invalid_weight(graph) {
+            return Err(PyValueError::new_err(
+                "Shortest paths require non-negative edge weights",
+            ));
+        }
+        let paths = match target_node {
+            Some(target_node) => match &heuristic {
+                Some(heuristic) => astar(graph, start_node, target_node, |node| {
+                    heuristic[node as usize]
+                }),
+                None => astar(graph, start_node, target_node, |_| 0.0),
+            },
+            None => dijkstra(graph, start_node),
+        };
+        Ok(ShortestPathResult::new(paths, start))
+    })
+}
+
+/// Runs a bidirectional breadth-first search and returns the number of edges
+/// and the nodes on a shortest path, or `None` if there is no path.
+pub(crate) fn pair_path<'py, G>(
+    py: Python<'py>,
+    graph: &G,
+    source_node: u32,
+    target_node: u32,
+) -> PyResult<Option<(f32, &'py PyArray1<u32>)>>
+where
+    G: GraphTrait<u32> + BidirectionalNeighbors<u32> + Sync,
+{
+    validate_nodes(graph, [source_node, target_node])?;
+    let path = py.allow_threads(|| bidirectional_bfs(graph, source_node, target_node));
+    Ok(path.map(|path| into_py_path(py, path)))
+}
+
+/// Runs a bidirectional Dijkstra search and returns the distance and the
+/// nodes on a shortest path, or `None` if there is no path.
+pub(crate) fn weighted_pair_path<'py, G>(
+    py: Python<'py>,
+    graph: &G,
+    source_node: u32,
+    target_node: u32,
+) -> PyResult<Option<(f32, &'py PyArray1<u32>)>>
+where
+    G: GraphTrait<u32> + WeightedBackwardNeighbors<u32> + Sync,
+{
+    validate_nodes(graph, [source_node, target_node])?;
+    let path = py.allow_threads(|| bidirectional_dijkstra(graph, source_node, target_node));
+    Ok(path.map(|path| into_py_path(py, path)))
+}
+
+fn into_py_path(py: Python<'_>, path: PairPath<u32>) -> (f32, &PyArray1<u32>) {
+    (path.distance, path.path.into_pyarray(py))
+}
+
+fn validate_nodes<G>(graph: &G, nodes: impl IntoIterator<Item = u32>) -> PyResult<()>
+where
+    G: GraphTrait<u32>,
+{
+    let node_count = graph.node_count();
+    for node in nodes {
+        if node >= node_count {
+            return Err(PyValueError::new_err(format!(
+                "node {node} is not contained in the graph"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn has_invalid_weight<G>(graph: &G) -> bool
+where
+    G: GraphTrait<u32> + WeightedNeighbors<u32>,
+{
+    (0..graph.node_count()).any(|node| {
+        graph
+            .weighted_neighbors(node)
+            .iter()
+            .any(|target| target.value < 0.0 || target.value.is_nan())
+    })
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct SsspResult {
+    distances: SharedSlice,
+    #[pyo3(get)]
+    micros: u64,
+}
+
+impl std::fmt::Debug for SsspResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SsspResult")
+            .field(
+                "distances",
+                &format!("[... {} values]", self.distances.len()),
+            )
+            .field("took", &Duration::from_micros(self.micros))
+            .finish()
+    }
+}
+
+#[pymethods]
+impl SsspResult {
+    /// The distance of each node to the start node, unreachable nodes have
+    /// an infinite distance.
+    pub fn distances<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<f32>> {
+        self.distances.clone().into_numpy(py)
+    }
+
+    #[args(dtype = "None")]
+    fn __array__(&self, py: Python<'_>, dtype: Option<&PyAny>) -> PyResult<PyObject> {
+        self.distances.clone().into_array::<f32>(py, dtype)
+    }
+
+    /// Returns the distance of each node to the start node as a pandas
+    /// dataframe indexed by `node_id`.
+    fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let distances = self.distances.clone().into_numpy::<f32>(py)?;
+        crate::pandas::node_frame(py, "distance", distances)
+    }
+
+    fn __len__(&self) -> usize {
+        self.distances.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: &PyAny) -> PyResult<PyObject> {
+        self.distances.get(py, index)
+    }
+
+    fn __iter__(&self) -> SharedSliceIter {
+        self.distances.iter()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+/// The distances of all nodes to a start node and their shortest paths.
+#[pyclass]
+#[derive(Clone)]
+pub struct ShortestPathResult {
+    distances: SharedSlice,
+    predecessors: SharedSlice,
+    #[pyo3(get)]
+    start_node: u32,
+    #[pyo3(get)]
+    micros: u64,
+}
+
+impl ShortestPathResult {
+    fn new(paths: ShortestPaths<u32>, start: Instant) -> Self {
+        let start_node = paths.start_node();
+        let (distances, predecessors) = paths.into_parts();
+        let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
+        Self {
+            distances: SharedSlice::from_vec(distances),
+            predecessors: SharedSlice::from_vec(predecessors),
+            start_node,
+            micros,
+        }
+    }
+}
+
+impl std::fmt::Debug for ShortestPathResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShortestPathResult")
+            .field("start_node", &self.start_node)
+            .field(
+                "distances",
+                &format!("[... {} values]", self.distances.len()),
+            )
+            .field("took", &Duration::from_micros(self.micros))
+            .finish()
+    }
+}
+
+#[pymethods]
+impl ShortestPathResult {
+    /// The distance of each node to the start node, nodes without a path
+    /// have an infinite distance.
+    pub fn distances<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<f32>> {
+        self.distances.clone().into_numpy(py)
+    }
+
+    /// The predecessor of each node on its shortest path. The start node and
+    /// nodes without a path are their own predecessors.
+    pub fn predecessors<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<u32>> {
+        self.predecessors.clone().into_numpy(py)
+    }
+
+    /// Returns the distance of the given node to the start node.
+    pub fn distance_to(&self, target: u32) -> PyResult<f32> {
+        self.distances.item(target as isize)
+    }
+
+    /// Returns the nodes on the shortest path from the start node to the
+    /// given node, including both, or `None` if there is no path.
+    pub fn path_to(&self, target: u32) -> PyResult<Option<Vec<u32>>> {
+        if self.distance_to(target)?.is_infinite() {
+            return Ok(None);
+        }
+        let mut path = vec![target];
+        let mut node = target;
+        while node != self.start_node {
+            node = self.predecessors.item(node as isize)?;
+            path.push(node);
+        }
+        path.reverse();
+        Ok(Some(path))
+    }
+
+    #[args(dtype = "None")]
+    fn __array__(&self, py: Python<'_>, dtype: Option<&PyAny>) -> PyResult<PyObject> {
+        self.distances.clone().into_array::<f32>(py, dtype)
+    }
+
+    /// Returns the distance of each node to the start node as a pandas
+    /// dataframe indexed by `node_id`.
+    fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let distances = self.distances.clone().into_numpy::<f32>(py)?;
+        crate::pandas::node_frame(py, "distance", distances)
+    }
+
+    fn __len__(&self) -> usize {
+        self.distances.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: &PyAny) -> PyResult<PyObject> {
+        self.distances.get(py, index)
+    }
+
+    fn __iter__(&self) -> SharedSliceIter {
+        self.distances.iter()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -0,0 +1,17 @@
+use graph::prelude::ResultSummary;
+use pyo3::{prelude::*, types::PyDict};
+
+/// Converts the summary into a dict with the keys `p50`, `p90`, `p99`, `max`
+/// and `mean`, or `None` if there is nothing to summarize.
+pub(crate) fn into_dict(py: Python<'_>, summary: Option<ResultSummary>) -> PyResult<PyObject> {
+    let Some(summary) = summary else {
+        return Ok(py.None());
+    };
+    let dict = PyDict::new(py);
+    dict.set_item("p50", summary.p50)?;
+    dict.set_item("p90", summary.p90)?;
+    dict.set_item("p99", summary.p99)?;
+    dict.set_item("max", summary.max)?;
+    dict.set_item("mean", summary.mean)?;
+    Ok(dict.into_py(py))
+}
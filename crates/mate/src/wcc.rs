@@ -1,10 +1,10 @@
-use crate::graphs::{NumpyType, SharedSlice};
+use crate::graphs::{NumpyType, SharedSlice, SharedSliceIter};
 use graph::prelude::{
-    wcc_afforest as graph_wcc, Components, DirectedDegrees, DirectedNeighbors, Graph as GraphTrait,
-    Idx, WccConfig,
+    wcc_afforest as graph_wcc, wcc_afforest_from_components, Components, DirectedDegrees,
+    DirectedNeighbors, Graph as GraphTrait, Idx, ResultSummary, WccConfig,
 };
 use numpy::PyArray1;
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::time::{Duration, Instant};
@@ -14,23 +14,55 @@ pub(crate) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     Ok(())
 }
 
-pub(crate) fn wcc<NI, G, C>(py: Python<'_>, graph: &G, config: C) -> WccRes<NI>
+pub(crate) fn wcc<NI, G, C>(
+    py: Python<'_>,
+    graph: &G,
+    config: C,
+    initial_components: Option<Vec<NI>>,
+) -> PyResult<WccRes<NI>>
 where
     NI: Idx + Hash + NumpyType,
     G: GraphTrait<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
     C: Into<Option<WccConfig>> + Send,
 {
-    py.allow_threads(move || inner_wcc(graph, config))
+    if let Some(initial_components) = &initial_components {
+        let node_count = graph.node_count().index();
+        if initial_components.len() != node_count {
+            return Err(PyValueError::new_err(format!(
+                "initial_components must contain one component per node ({node_count}), got {}",
+                initial_components.len()
+            )));
+        }
+        if let Some(component) = initial_components
+            .iter()
+            .find(|component| component.index() >= node_count)
+        {
+            return Err(PyValueError::new_err(format!(
+                "initial_components must be less than the node count ({node_count}), got {component:?}"
+            )));
+        }
+    }
+
+    Ok(py.allow_threads(move || inner_wcc(graph, config, initial_components)))
 }
 
-fn inner_wcc<NI, G>(graph: &G, config: impl Into<Option<WccConfig>>) -> WccRes<NI>
+fn inner_wcc<NI, G>(
+    graph: &G,
+    config: impl Into<Option<WccConfig>>,
+    initial_components: Option<Vec<NI>>,
+) -> WccRes<NI>
 where
     NI: Idx + Hash + NumpyType,
     G: GraphTrait<NI> + DirectedDegrees<NI> + DirectedNeighbors<NI> + Sync,
 {
     let config = config.into().unwrap_or_default();
     let start = Instant::now();
-    let components = graph_wcc(graph, config).to_vec();
+    let components = match initial_components {
+        Some(initial_components) => {
+            wcc_afforest_from_components(graph, config, &initial_components).to_vec()
+        }
+        None => graph_wcc(graph, config).to_vec(),
+    };
     let micros = start.elapsed().as_micros().min(u64::MAX as _) as _;
     let components = SharedSlice::from_vec(components);
     WccRes {
@@ -81,6 +113,38 @@ impl WccResult {
         self.components.clone().into_numpy(py)
     }
 
+    /// Returns the percentiles, the maximum and the mean of the component
+    /// sizes as a dict with the keys `p50`, `p90`, `p99`, `max` and `mean`.
+    pub fn size_summary(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let components = self.components.as_slice::<u32>();
+        let summary = py.allow_threads(|| ResultSummary::from_communities(components));
+        crate::summary::into_dict(py, summary)
+    }
+
+    #[args(dtype = "None")]
+    fn __array__(&self, py: Python<'_>, dtype: Option<&PyAny>) -> PyResult<PyObject> {
+        self.components.clone().into_array::<u32>(py, dtype)
+    }
+
+    /// Returns the component id of each node as a pandas dataframe indexed by
+    /// `node_id`.
+    fn to_pandas(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let components = self.components.clone().into_numpy::<u32>(py)?;
+        crate::pandas::node_frame(py, "component", components)
+    }
+
+    fn __len__(&self) -> usize {
+        self.components.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: &PyAny) -> PyResult<PyObject> {
+        self.components.get(py, index)
+    }
+
+    fn __iter__(&self) -> SharedSliceIter {
+        self.components.iter()
+    }
+
     fn __repr__(&self) -> String {
         format!("{self:?}")
     }
@@ -0,0 +1,90 @@
+import numpy as np
+import pytest
+
+from graph_mate import Catalog, DiGraph, Graph, WeightedGraph
+
+FILE = "../builder/resources/scale_8.graph500"
+
+
+def test_load_and_list():
+    catalog = Catalog()
+
+    g = catalog.load("g", FILE)
+    ug = catalog.load("ug", FILE, directed=False)
+
+    assert isinstance(g, DiGraph)
+    assert isinstance(ug, Graph)
+    assert catalog.names() == ["g", "ug"]
+
+    infos = catalog.list()
+    assert [info["name"] for info in infos] == ["g", "ug"]
+    assert [info["type"] for info in infos] == ["DiGraph", "Graph"]
+    assert infos[0]["node_count"] == g.node_count()
+    assert infos[0]["edge_count"] == g.edge_count()
+    assert catalog.memory_bytes() == sum(info["memory_bytes"] for info in infos)
+
+
+def test_add_and_drop(g: DiGraph):
+    catalog = Catalog()
+    catalog.add("g", g)
+
+    assert "g" in catalog
+    assert len(catalog) == 1
+    assert catalog["g"] is g
+    assert list(catalog) == ["g"]
+
+    assert catalog.drop("g") is g
+    assert "g" not in catalog
+    with pytest.raises(KeyError):
+        catalog.get("g")
+
+
+def test_add_existing_name(g: DiGraph, ug: Graph):
+    catalog = Catalog()
+    catalog.add("g", g)
+
+    with pytest.raises(ValueError, match="already exists"):
+        catalog.add("g", ug)
+
+    catalog.add("g", ug, replace=True)
+    assert catalog["g"] is ug
+
+    catalog["g"] = g
+    assert catalog["g"] is g
+
+
+def test_add_rejects_non_graphs():
+    with pytest.raises(TypeError):
+        Catalog().add("g", [(0, 1)])
+
+
+def test_run(g: DiGraph, ug: Graph):
+    catalog = Catalog()
+    catalog["g"] = g
+    catalog["ug"] = ug
+
+    assert catalog.run("ug", "triangle_count").triangles == ug.triangle_count().triangles
+    result = catalog.run("g", "page_rank", max_iterations=3, tolerance=0.0, damping_factor=0.85)
+    assert result.ran_iterations == 3
+    assert catalog.run("g", "sssp", 0, 1.0).distances()[0] == 0.0
+
+
+def test_run_unsupported_algorithm():
+    catalog = Catalog()
+    catalog["w"] = WeightedGraph.from_arrays(np.array([0]), np.array([1]), np.array([1.0]))
+
+    with pytest.raises(ValueError, match="does not support"):
+        catalog.run("w", "page_rank")
+    with pytest.raises(ValueError, match="Unknown algorithm"):
+        catalog.run("w", "save", "graph.bin")
+
+
+def test_clear_and_del(g: DiGraph):
+    catalog = Catalog()
+    catalog["a"] = g
+    catalog["b"] = g
+
+    del catalog["a"]
+    assert catalog.names() == ["b"]
+    catalog.clear()
+    assert len(catalog) == 0
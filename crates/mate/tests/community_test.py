@@ -0,0 +1,67 @@
+import numpy as np
+import pytest
+
+from graph_mate import Graph
+
+
+@pytest.fixture
+def cliques() -> Graph:
+    """Two 4-cliques connected by a single edge"""
+    edges = [(u, v) for u in range(4) for v in range(u + 1, 4)]
+    edges += [(u + 4, v + 4) for (u, v) in edges]
+    edges.append((3, 4))
+    sources, targets = zip(*edges)
+    return Graph.from_arrays(np.array(sources), np.array(targets))
+
+
+@pytest.mark.parametrize("algorithm", ["louvain", "label_propagation"])
+def test_two_cliques(cliques: Graph, algorithm: str):
+    result = getattr(cliques, algorithm)()
+
+    assert result.community_count == 2
+    assert result.community_sizes().tolist() == [4, 4]
+    assert result.communities().tolist() == [0, 0, 0, 0, 1, 1, 1, 1]
+    assert result.community_of(5) == 1
+    assert result.modularity == pytest.approx(2 * (12 / 26 - 0.25))
+
+
+def test_louvain(ug: Graph):
+    result = ug.louvain(max_iterations=5, tolerance=1e-3, seed=7)
+
+    assert result.micros > 0
+    assert len(result) == ug.node_count()
+    assert result.community_sizes().sum() == ug.node_count()
+    assert 0.0 < result.modularity < 1.0
+    assert list(result) == result.communities().tolist()
+
+
+def test_label_propagation(ug: Graph):
+    config = {"max_iterations": 5, "tolerance": 0.01, "seed": 7}
+    result = ug.label_propagation(**config)
+
+    assert result.ran_iterations <= 5
+    assert np.asarray(result).max() < result.community_count
+    assert result.to_pandas()["community"].tolist() == list(result)
+    assert result.communities().tolist() == ug.label_propagation(**config).communities().tolist()
+
+
+def test_community_size_summary(ug: Graph):
+    result = ug.louvain(seed=7)
+    sizes = result.community_sizes()
+
+    summary = result.size_summary()
+
+    assert summary["max"] == sizes.max()
+    assert summary["mean"] == pytest.approx(sizes.mean())
+
+
+def test_community_of_out_of_range(ug: Graph):
+    result = ug.louvain()
+
+    with pytest.raises(IndexError):
+        result.community_of(ug.node_count())
+
+
+def test_config_must_be_kwargs(ug: Graph):
+    with pytest.raises(TypeError):
+        ug.louvain(10, 1e-4, 42)
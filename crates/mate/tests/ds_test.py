@@ -1,7 +1,8 @@
 import numpy as np
 import pandas as pd
+import pytest
 
-from graph_mate import DiGraph, Graph
+from graph_mate import DiGraph, Graph, Layout, WeightedDiGraph
 
 
 def test_numpy_graph():
@@ -60,3 +61,67 @@ def test_pandas_digraph():
 
     assert np.array_equal(g.in_neighbors(1), np.array([0, 4], dtype=np.uint32))
     assert np.array_equal(g.in_neighbors(3), np.array([2], dtype=np.uint32))
+
+
+def test_pandas_named_columns():
+    df = pd.DataFrame({"weight": [0.5, 1.5, 2.5], "dst": [1, 3, 1], "src": [0, 2, 4]})
+    g = DiGraph.from_pandas(df, source="src", target="dst")
+
+    assert g.node_count() == 5
+    assert g.edge_count() == 3
+    assert np.array_equal(g.in_neighbors(1), np.array([0, 4], dtype=np.uint32))
+
+
+def test_pandas_missing_column():
+    df = pd.DataFrame({"src": [0, 2, 4], "dst": [1, 3, 1]})
+
+    with pytest.raises(KeyError):
+        DiGraph.from_pandas(df, source="source")
+
+
+def test_pandas_weighted_digraph():
+    df = pd.DataFrame({"src": [0, 0, 1], "dst": [1, 2, 2], "cost": [1.0, 4.0, 2.0]})
+    g = WeightedDiGraph.from_pandas(df, source="src", target="dst", weight="cost")
+
+    assert g.edge_count() == 3
+    assert g.sssp(0, 1.0).distances().tolist() == [0.0, 1.0, 3.0]
+
+
+def test_digraph_to_pandas():
+    df = pd.DataFrame({"src": [0, 2, 4], "dst": [1, 3, 1]})
+    edges = DiGraph.from_pandas(df, Layout.Sorted).to_pandas()
+
+    assert list(edges.columns) == ["src", "dst"]
+    assert edges.sort_values("src").values.tolist() == df.values.tolist()
+
+
+def test_graph_to_pandas():
+    df = pd.DataFrame({"src": [0, 3, 4, 2], "dst": [1, 2, 1, 2]})
+    edges = Graph.from_pandas(df, Layout.Sorted).to_pandas()
+
+    assert edges.values.tolist() == [[0, 1], [1, 4], [2, 2], [2, 3]]
+
+
+def test_weighted_digraph_to_pandas():
+    df = pd.DataFrame({"src": [0, 0, 1], "dst": [1, 2, 2], "weight": [1.0, 4.0, 2.0]})
+    edges = WeightedDiGraph.from_pandas(df, Layout.Sorted).to_pandas()
+
+    assert list(edges.columns) == ["src", "dst", "weight"]
+    assert edges.values.tolist() == df.values.tolist()
+
+
+def test_results_to_pandas(g: DiGraph):
+    scores = g.page_rank().to_pandas()
+    assert scores.index.name == "node_id"
+    assert list(scores.columns) == ["score"]
+    assert len(scores) == g.node_count()
+
+    components = g.wcc().to_pandas()
+    assert list(components.columns) == ["component"]
+
+    distances = g.sssp(0, 1.0).to_pandas()
+    assert list(distances.columns) == ["distance"]
+    assert distances.loc[0, "distance"] == 0.0
+
+    # results are copied and can be modified
+    distances["distance"] += 1.0
@@ -0,0 +1,63 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph
+
+
+def test_random_walks(g: DiGraph):
+    walks = g.random_walks(walk_length=5, walks_per_node=2, seed=7)
+
+    assert len(walks) == 2 * g.node_count()
+    for i, walk in enumerate(walks):
+        assert walk.dtype == np.uint32
+        assert 1 <= len(walk) <= 5
+        assert walk[0] == i % g.node_count()
+        for u, v in zip(walk, walk[1:]):
+            assert v in g.out_neighbors(u)
+
+
+def test_random_walks_are_reproducible(ug: Graph):
+    config = {"walk_length": 10, "return_factor": 2.0, "in_out_factor": 0.5}
+
+    walks = ug.random_walks(seed=7, **config)
+    same = ug.random_walks(seed=7, **config)
+    other = ug.random_walks(seed=8, **config)
+
+    assert all(np.array_equal(a, b) for a, b in zip(walks, same))
+    assert not all(np.array_equal(a, b) for a, b in zip(walks, other))
+
+
+def test_random_walks_invalid_factor(ug: Graph):
+    with pytest.raises(ValueError):
+        ug.random_walks(return_factor=0.0)
+
+
+@pytest.mark.parametrize("graph", ["g", "ug"])
+def test_fast_rp(graph: str, request: pytest.FixtureRequest):
+    graph = request.getfixturevalue(graph)
+
+    embeddings = graph.fast_rp(embedding_dimension=16, seed=7)
+
+    assert embeddings.dtype == np.float32
+    assert embeddings.shape == (graph.node_count(), 16)
+    np.testing.assert_array_equal(embeddings, graph.fast_rp(embedding_dimension=16, seed=7))
+
+
+@pytest.mark.parametrize("graph", ["g", "ug"])
+def test_node2vec(graph: str, request: pytest.FixtureRequest):
+    graph = request.getfixturevalue(graph)
+    config = {"embedding_dimension": 8, "walk_length": 10, "walks_per_node": 2, "window_size": 3}
+
+    embeddings = graph.node2vec(seed=7, **config)
+
+    assert embeddings.dtype == np.float32
+    assert embeddings.shape == (graph.node_count(), 8)
+    np.testing.assert_array_equal(embeddings, graph.node2vec(seed=7, **config))
+    assert not np.array_equal(embeddings, graph.node2vec(seed=8, **config))
+
+
+def test_invalid_embedding_dimension(ug: Graph):
+    with pytest.raises(ValueError):
+        ug.fast_rp(embedding_dimension=0)
+    with pytest.raises(ValueError):
+        ug.node2vec(embedding_dimension=0)
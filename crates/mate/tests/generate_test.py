@@ -0,0 +1,60 @@
+import pytest
+
+from graph_mate import DiGraph, Graph, generate
+
+
+def test_rmat():
+    g = generate.rmat(scale=8, edge_factor=4)
+
+    assert isinstance(g, DiGraph)
+    assert g.node_count() == 256
+    assert g.edge_count() == 1024
+
+
+def test_rmat_is_reproducible():
+    g1 = generate.rmat(scale=6, edge_factor=4, seed=7, layout="sorted")
+    g2 = generate.rmat(scale=6, edge_factor=4, seed=7, layout="sorted")
+
+    for node in range(g1.node_count()):
+        assert g1.out_neighbors(node).tolist() == g2.out_neighbors(node).tolist()
+
+
+def test_rmat_undirected():
+    g = generate.rmat(scale=6, edge_factor=4, directed=False, layout="deduplicated")
+
+    assert isinstance(g, Graph)
+    assert g.node_count() == 64
+
+
+def test_rmat_validates_probabilities():
+    with pytest.raises(ValueError):
+        generate.rmat(scale=6, a=0.5, b=0.5, c=0.5)
+
+
+def test_erdos_renyi():
+    g = generate.erdos_renyi(10, 1.0)
+
+    assert isinstance(g, DiGraph)
+    assert g.edge_count() == 90
+
+
+def test_erdos_renyi_undirected():
+    g = generate.erdos_renyi(10, 1.0, directed=False)
+
+    assert isinstance(g, Graph)
+    assert g.edge_count() == 45
+    assert generate.erdos_renyi(10, 0.0).edge_count() == 0
+
+    with pytest.raises(ValueError):
+        generate.erdos_renyi(10, 1.5)
+
+
+def test_barabasi_albert():
+    g = generate.barabasi_albert(100, edges_per_node=3, seed=1)
+
+    assert g.node_count() == 100
+    assert g.edge_count() == 97 * 3
+    assert all(g.out_degree(node) == 3 for node in range(3, 100))
+
+    with pytest.raises(ValueError):
+        generate.barabasi_albert(3, edges_per_node=3)
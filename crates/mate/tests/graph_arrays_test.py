@@ -0,0 +1,100 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph, Layout, WeightedDiGraph
+
+SOURCES = np.array([0, 0, 1, 1, 2], dtype=np.uint32)
+TARGETS = np.array([1, 2, 2, 3, 3], dtype=np.uint32)
+WEIGHTS = np.array([1.0, 4.0, 2.0, 6.0, 1.0], dtype=np.float32)
+
+
+def test_digraph_from_arrays():
+    g = DiGraph.from_arrays(SOURCES, TARGETS, Layout.Sorted)
+
+    assert g.node_count() == 4
+    assert g.edge_count() == 5
+    assert g.out_neighbors(0).tolist() == [1, 2]
+    assert g.in_neighbors(3).tolist() == [1, 2]
+
+
+def test_graph_from_arrays():
+    g = Graph.from_arrays(SOURCES, TARGETS, Layout.Sorted)
+
+    assert g.node_count() == 4
+    assert g.edge_count() == 5
+    assert g.neighbors(2).tolist() == [0, 1, 3]
+
+
+def test_from_arrays_converts_dtype():
+    g = DiGraph.from_arrays([0, 0, 1], np.array([1, 2, 2], dtype=np.int64))
+
+    assert g.node_count() == 3
+    assert g.edge_count() == 3
+
+
+def test_from_arrays_length_mismatch():
+    with pytest.raises(ValueError):
+        DiGraph.from_arrays(SOURCES, TARGETS[:-1])
+
+
+def test_from_arrays_requires_1d():
+    with pytest.raises(TypeError):
+        DiGraph.from_arrays(np.stack([SOURCES, TARGETS]), TARGETS)
+
+
+def test_weighted_from_arrays():
+    g = WeightedDiGraph.from_arrays(SOURCES, TARGETS, WEIGHTS)
+
+    assert g.node_count() == 4
+    assert g.edge_count() == 5
+    assert g.out_degree(1) == 2
+    assert g.in_degree(3) == 2
+    assert g.sssp(0, 1.0).distances().tolist() == [0.0, 1.0, 3.0, 4.0]
+
+
+def test_weighted_from_arrays_length_mismatch():
+    with pytest.raises(ValueError):
+        WeightedDiGraph.from_arrays(SOURCES, TARGETS, WEIGHTS[:-1])
+
+
+def test_digraph_from_scipy_csr():
+    sparse = pytest.importorskip("scipy.sparse")
+    # node 4 has no edges, but is part of the matrix
+    mat = sparse.csr_matrix((WEIGHTS, (SOURCES, TARGETS)), shape=(5, 5))
+
+    g = DiGraph.from_scipy_csr(mat, Layout.Sorted)
+
+    assert g.node_count() == 5
+    assert g.edge_count() == 5
+    assert g.out_neighbors(1).tolist() == [2, 3]
+    assert g.out_degree(4) == 0
+
+
+def test_graph_from_scipy_csr():
+    sparse = pytest.importorskip("scipy.sparse")
+    mat = sparse.csr_matrix((WEIGHTS, (SOURCES, TARGETS)), shape=(4, 4))
+    symmetric = (mat + mat.T).tocsr()
+
+    g = Graph.from_scipy_csr(sparse.triu(symmetric, format="csr"), Layout.Sorted)
+
+    assert g.node_count() == 4
+    assert g.edge_count() == 5
+    assert g.neighbors(2).tolist() == [0, 1, 3]
+
+
+def test_weighted_from_scipy_csr():
+    sparse = pytest.importorskip("scipy.sparse")
+    mat = sparse.csr_matrix((WEIGHTS, (SOURCES, TARGETS)), shape=(4, 4))
+
+    g = WeightedDiGraph.from_scipy_csr(mat)
+
+    assert g.edge_count() == 5
+    assert g.sssp(0, 1.0).distances().tolist() == [0.0, 1.0, 3.0, 4.0]
+
+
+def test_from_scipy_requires_csr():
+    sparse = pytest.importorskip("scipy.sparse")
+    mat = sparse.coo_matrix((WEIGHTS, (SOURCES, TARGETS)), shape=(4, 4))
+
+    with pytest.raises(TypeError):
+        DiGraph.from_scipy_csr(mat)
@@ -0,0 +1,46 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph
+
+# A path 0 - 1 - 2 - 3 - 4.
+SOURCES = np.array([0, 1, 2, 3])
+TARGETS = np.array([1, 2, 3, 4])
+
+
+def test_hyperball_path():
+    g = Graph.from_arrays(SOURCES, TARGETS)
+
+    result = g.hyperball(precision=12)
+
+    assert result.iterations == 5
+    assert np.allclose(result.neighborhood_function(), [5, 13, 19, 23, 25], atol=0.5)
+    assert np.argmax(result.harmonic()) == 2
+    assert np.argmax(result.closeness()) == 2
+    assert 2.0 < result.effective_diameter() < 4.0
+
+
+def test_hyperball_directed():
+    g = DiGraph.from_arrays(SOURCES, TARGETS)
+
+    result = g.hyperball()
+
+    assert result.harmonic()[4] == 0.0
+    assert result.closeness()[4] == 0.0
+    assert result.harmonic()[0] > result.harmonic()[3]
+
+
+def test_hyperball_max_iterations(g: DiGraph):
+    result = g.hyperball(precision=6, max_iterations=2)
+
+    assert result.iterations == 2
+    assert len(result.neighborhood_function()) <= 3
+    assert len(result.harmonic()) == g.node_count()
+
+
+def test_hyperball_invalid_arguments(ug: Graph):
+    with pytest.raises(ValueError):
+        ug.hyperball(precision=3)
+
+    with pytest.raises(ValueError):
+        ug.hyperball(max_iterations=1).effective_diameter(1.5)
@@ -0,0 +1,77 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph, WeightedDiGraph
+
+
+def labeled_graph() -> Graph:
+    g = Graph.from_arrays(np.array([0, 0, 1, 2]), np.array([1, 2, 3, 3]))
+    g.node_labels = ["person", "person", "city", "city"]
+    return g
+
+
+def test_label():
+    g = labeled_graph()
+
+    assert g.label(0) == "person"
+    assert g.label(3) == "city"
+    with pytest.raises(IndexError):
+        g.label(4)
+
+
+def test_nodes_with_label():
+    g = labeled_graph()
+
+    assert g.nodes_with_label("city").tolist() == [2, 3]
+    assert g.nodes_with_label("country").tolist() == []
+
+
+def test_label_counts():
+    g = labeled_graph()
+
+    assert g.label_counts() == {"person": 2, "city": 2}
+
+
+def test_subgraph_with_labels():
+    g = labeled_graph()
+
+    sub, original_ids = g.subgraph_with_labels(["city"])
+
+    assert original_ids.tolist() == [2, 3]
+    assert sub.edge_count() == 1
+    assert sub.node_labels == ["city", "city"]
+
+
+def test_directed_subgraph_with_labels():
+    g = DiGraph.from_arrays(np.array([0, 1, 2]), np.array([1, 2, 0]))
+    g.node_labels = ("a", "b", "a")
+
+    sub, original_ids = g.subgraph_with_labels({"a"})
+
+    assert original_ids.tolist() == [0, 2]
+    assert sub.out_neighbors(1).tolist() == [0]
+
+
+def test_weighted_labels():
+    g = WeightedDiGraph.from_arrays(np.array([0, 1]), np.array([1, 2]), np.array([0.5, 1.5]))
+    g.node_labels = [1, 2, 1]
+
+    assert g.nodes_with_label(1).tolist() == [0, 2]
+    assert g.label_counts() == {1: 2, 2: 1}
+
+
+def test_node_labels_length_is_validated():
+    g = labeled_graph()
+
+    with pytest.raises(ValueError):
+        g.node_labels = ["person"]
+
+    g.node_labels = None
+    assert g.node_labels is None
+
+
+def test_label_queries_without_labels(ug: Graph):
+    with pytest.raises(ValueError, match="no node labels"):
+        ug.label(0)
+    with pytest.raises(ValueError, match="no node labels"):
+        ug.subgraph_with_labels(["a"])
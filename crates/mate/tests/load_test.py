@@ -0,0 +1,58 @@
+import numpy as np
+import pytest
+
+import graph_mate as gm
+
+EL_FILE = "../builder/resources/test.el"
+WEL_FILE = "../builder/resources/test.wel"
+GRAPH500_FILE = "../builder/resources/scale_8.graph500"
+
+
+def test_load_defaults():
+    g = gm.load(GRAPH500_FILE)
+
+    assert isinstance(g, gm.DiGraph)
+    assert g.node_count() == 1 << 8
+
+
+def test_load_undirected_edge_list():
+    g = gm.load(
+        EL_FILE, file_format=gm.FileFormat.EdgeList, layout="sorted", directed=False
+    )
+
+    assert isinstance(g, gm.Graph)
+    assert g.edge_count() == 6
+    assert np.array_equal(g.neighbors(2), [0, 1, 4])
+
+
+def test_load_weighted():
+    g = gm.load(WEL_FILE, layout="Deduplicated", weighted=True)
+
+    assert isinstance(g, gm.WeightedDiGraph)
+    assert g.edge_count() == 6
+    assert g.sssp(0, 0.1).distances().tolist() == pytest.approx(
+        [0.0, 0.1, 0.2, 0.5, 0.7]
+    )
+
+
+def test_load_weighted_undirected():
+    g = gm.load(WEL_FILE, layout=gm.Layout.Sorted, directed=False, weighted=True)
+
+    assert isinstance(g, gm.WeightedGraph)
+    assert g.node_count() == 5
+    assert g.degree(2) == 3
+
+
+def test_load_weighted_graph500():
+    with pytest.raises(ValueError):
+        gm.load(GRAPH500_FILE, file_format=gm.FileFormat.Graph500, weighted=True)
+
+
+def test_layout_names():
+    el = np.array([[0, 1], [0, 1], [1, 0]], dtype=np.uint32)
+
+    assert gm.DiGraph.from_numpy(el, "deduplicated").edge_count() == 2
+    assert gm.DiGraph.from_numpy(el, layout="SORTED").edge_count() == 3
+
+    with pytest.raises(ValueError):
+        gm.DiGraph.from_numpy(el, "shuffled")
@@ -0,0 +1,104 @@
+import pytest
+
+from graph_mate import DiGraph, Graph, Layout, WeightedDiGraph
+
+nx = pytest.importorskip("networkx")
+
+
+def test_digraph_from_networkx():
+    nx_g = nx.DiGraph([("a", "b"), ("b", "c"), ("a", "c")])
+    nx_g.add_node("d")
+
+    g = DiGraph.from_networkx(nx_g, Layout.Sorted)
+
+    assert g.node_count() == 4
+    assert g.edge_count() == 3
+    assert g.node_labels == ["a", "b", "c", "d"]
+    assert g.out_neighbors(0).tolist() == [1, 2]
+    assert g.out_degree(3) == 0
+
+
+def test_digraph_from_undirected_networkx():
+    g = DiGraph.from_networkx(nx.Graph([(0, 1), (1, 2)]), Layout.Sorted)
+
+    assert g.edge_count() == 4
+    assert g.out_neighbors(1).tolist() == [0, 2]
+
+
+def test_graph_from_networkx():
+    g = Graph.from_networkx(nx.karate_club_graph())
+
+    assert g.node_count() == 34
+    assert g.edge_count() == 78
+    assert g.triangle_count().triangles == 45
+
+
+def test_weighted_digraph_from_networkx():
+    nx_g = nx.DiGraph()
+    nx_g.add_edge("a", "b", cost=1.0)
+    nx_g.add_edge("b", "c", cost=2.0)
+    nx_g.add_edge("a", "c")
+
+    g = WeightedDiGraph.from_networkx(nx_g, weight="cost")
+
+    # the edge without a cost has a weight of 1.0
+    assert g.sssp(0, 1.0).distances().tolist() == [0.0, 1.0, 1.0]
+
+
+def test_to_networkx_round_trip():
+    nx_g = nx.DiGraph([("a", "b"), ("b", "c"), ("a", "c")])
+
+    back = DiGraph.from_networkx(nx_g).to_networkx()
+
+    assert isinstance(back, nx.DiGraph)
+    assert sorted(back.edges) == sorted(nx_g.edges)
+
+
+def test_graph_to_networkx():
+    g = Graph.from_networkx(nx.Graph([(0, 1), (1, 2), (2, 2)]))
+
+    back = g.to_networkx()
+
+    assert isinstance(back, nx.Graph)
+    assert back.number_of_edges() == 3
+
+
+def test_to_networkx_without_labels(g: DiGraph):
+    back = g.to_networkx(node_attributes={"rank": g.page_rank()})
+
+    assert back.number_of_nodes() == g.node_count()
+    assert back.number_of_edges() == g.edge_count()
+    assert back.nodes[0]["rank"] == pytest.approx(float(g.page_rank()[0]))
+
+
+def test_weighted_to_networkx():
+    nx_g = nx.DiGraph()
+    nx_g.add_edge("a", "b", weight=0.5)
+
+    back = WeightedDiGraph.from_networkx(nx_g).to_networkx()
+
+    assert back["a"]["b"]["weight"] == 0.5
+
+
+def test_node_attributes_length():
+    g = DiGraph.from_networkx(nx.DiGraph([(0, 1)]))
+
+    with pytest.raises(ValueError):
+        g.to_networkx(node_attributes={"rank": [1.0, 2.0, 3.0]})
+
+
+def test_labeled_graph_cannot_be_reordered():
+    g = Graph.from_networkx(nx.Graph([("a", "b")]))
+
+    with pytest.raises(ValueError):
+        g.make_degree_ordered()
+
+
+def test_subgraph_keeps_labels():
+    g = DiGraph.from_networkx(nx.DiGraph([("a", "b"), ("b", "c"), ("c", "d")]))
+
+    sub, _ = g.subgraph([1, 3])
+    assert sub.node_labels == ["b", "d"]
+
+    ego, _ = g.ego(2)
+    assert ego.node_labels == ["c", "d"]
@@ -0,0 +1,53 @@
+import numpy as np
+import pandas as pd
+
+from graph_mate import DiGraph
+
+
+def test_page_rank_array(g: DiGraph):
+    pr = g.page_rank()
+    scores = np.asarray(pr)
+
+    assert scores.dtype == np.float32
+    assert scores.base is not None
+    assert not scores.flags.writeable
+    assert np.shares_memory(scores, pr.scores())
+
+
+def test_wcc_array(g: DiGraph):
+    wcc = g.wcc()
+    components = np.asarray(wcc)
+
+    assert components.dtype == np.uint32
+    assert np.shares_memory(components, wcc.components())
+
+
+def test_sssp_array(g: DiGraph):
+    sssp = g.sssp(0, 1.0)
+    distances = np.asarray(sssp)
+
+    assert distances.dtype == np.float32
+    assert np.shares_memory(distances, sssp.distances())
+
+
+def test_array_dtype(g: DiGraph):
+    wcc = g.wcc()
+    components = np.asarray(wcc, dtype=np.int64)
+
+    assert components.dtype == np.int64
+    assert components.tolist() == wcc.components().tolist()
+
+
+def test_result_keeps_data_alive(g: DiGraph):
+    scores = np.asarray(g.page_rank())
+
+    assert len(scores) == 1 << 8
+    assert np.all(scores > 0.0)
+
+
+def test_pandas_series(g: DiGraph):
+    pr = g.page_rank()
+    series = pd.Series(np.asarray(pr))
+
+    assert len(series) == 1 << 8
+    assert series.dtype == np.float32
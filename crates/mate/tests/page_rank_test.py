@@ -1,3 +1,4 @@
+import numpy as np
 import pytest
 
 from graph_mate import DiGraph
@@ -16,6 +17,71 @@ def test_page_rank(g: DiGraph):
         assert score > 0.0
 
 
+def test_pr_sequence(g: DiGraph):
+    pr = g.page_rank()
+    scores = pr.scores()
+
+    assert len(pr) == len(scores)
+    assert pr[0] == scores[0]
+    assert pr[-1] == scores[-1]
+    with pytest.raises(IndexError):
+        pr[len(pr)]
+
+
+
+def test_pr_iter(g: DiGraph):
+    pr = g.page_rank()
+
+    assert list(pr) == pr.scores().tolist()
+    assert sum(1 for _ in pr) == len(pr)
+
+
+def test_pr_slices(g: DiGraph):
+    pr = g.page_rank()
+    scores = pr.scores()
+
+    assert np.array_equal(pr[10:20], scores[10:20])
+    assert np.array_equal(pr[::-1], scores[::-1])
+    assert np.array_equal(pr[-5::-3], scores[-5::-3])
+    assert np.shares_memory(pr[::2], scores)
+    assert not pr[1:3].flags.writeable
+
+
+def test_pr_top_k(g: DiGraph):
+    pr = g.page_rank()
+    scores = pr.scores()
+
+    nodes, top_scores = pr.top_k(10)
+
+    assert len(nodes) == 10
+    assert top_scores.tolist() == sorted(scores, reverse=True)[:10]
+    assert np.array_equal(scores[nodes], top_scores)
+    assert len(pr.top_k(1000)[0]) == len(pr)
+
+
+def test_pr_summary(g: DiGraph):
+    pr = g.page_rank()
+    scores = pr.scores()
+
+    summary = pr.summary()
+
+    assert set(summary) == {"p50", "p90", "p99", "max", "mean"}
+    assert summary["max"] == pytest.approx(scores.max())
+    assert summary["mean"] == pytest.approx(scores.mean(), rel=1e-5)
+    assert scores.min() <= summary["p50"]
+    assert summary["p50"] <= summary["p90"] <= summary["p99"] <= summary["max"]
+
+def test_pr_initial_scores(g: DiGraph):
+    pr = g.page_rank(max_iterations=100, tolerance=1e-6)
+    warm = g.page_rank(max_iterations=100, tolerance=1e-6, initial_scores=pr)
+
+    assert warm.ran_iterations < pr.ran_iterations
+    assert np.allclose(warm.scores(), pr.scores(), atol=1e-5)
+
+    with pytest.raises(ValueError):
+        g.page_rank(initial_scores=np.ones(3, dtype=np.float32))
+
+
 def test_pr_max_iterations(g: DiGraph):
     pr = g.page_rank(max_iterations=1)
     assert pr.ran_iterations == 1
@@ -0,0 +1,78 @@
+import copy
+import pickle
+
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph, WeightedDiGraph, WeightedGraph
+
+
+def test_pickle_digraph(g: DiGraph):
+    loaded = pickle.loads(pickle.dumps(g))
+
+    assert loaded.node_count() == g.node_count()
+    assert loaded.edge_count() == g.edge_count()
+    for node in range(g.node_count()):
+        assert loaded.out_neighbors(node).tolist() == g.out_neighbors(node).tolist()
+        assert loaded.in_neighbors(node).tolist() == g.in_neighbors(node).tolist()
+
+
+def test_pickle_graph(ug: Graph):
+    loaded = pickle.loads(pickle.dumps(ug))
+
+    assert loaded.node_count() == ug.node_count()
+    assert loaded.edge_count() == ug.edge_count()
+    assert loaded.neighbors(42).tolist() == ug.neighbors(42).tolist()
+    assert loaded.node_labels is None
+
+
+def test_pickle_keeps_labels():
+    g = DiGraph.from_arrays(np.array([0, 1]), np.array([1, 2]))
+    g.__setstate__(["a", "b", "c"])
+
+    loaded = copy.deepcopy(g)
+
+    assert loaded.node_labels == ["a", "b", "c"]
+
+
+@pytest.mark.parametrize("cls", [WeightedDiGraph, WeightedGraph])
+def test_pickle_weighted(cls):
+    g = cls.from_arrays(np.array([0, 1, 2]), np.array([1, 2, 0]), np.array([0.5, 1.5, 2.5]))
+
+    loaded = pickle.loads(pickle.dumps(g))
+
+    assert loaded.edge_count() == 3
+    assert loaded.stats() == g.stats()
+
+
+def test_save_and_load_binary(g: DiGraph, tmp_path):
+    path = tmp_path / "graph.bin"
+
+    g.save(str(path))
+    loaded = DiGraph.load_binary(str(path))
+
+    assert loaded.node_count() == g.node_count()
+    assert loaded.edge_count() == g.edge_count()
+    assert loaded.to_pandas().equals(g.to_pandas())
+
+
+def test_load_binary_wrong_kind(g: DiGraph, tmp_path):
+    path = tmp_path / "graph.bin"
+    g.save(str(path))
+
+    with pytest.raises(ValueError, match="DiGraph"):
+        Graph.load_binary(str(path))
+
+
+def test_from_bytes_invalid_data(ug: Graph):
+    with pytest.raises(ValueError):
+        Graph.from_bytes(b"not a graph")
+    with pytest.raises(ValueError):
+        Graph.from_bytes(ug.to_bytes()[:-4])
+    with pytest.raises(ValueError):
+        Graph.from_bytes(ug.to_bytes() + b"\0")
+
+
+def test_load_binary_missing_file(tmp_path):
+    with pytest.raises(FileNotFoundError):
+        Graph.load_binary(str(tmp_path / "missing.bin"))
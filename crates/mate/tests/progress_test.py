@@ -0,0 +1,72 @@
+import logging
+
+import pytest
+
+import graph_mate as gm
+from graph_mate import DiGraph, Graph
+
+
+def test_page_rank_progress(g: DiGraph):
+    updates = []
+
+    result = g.page_rank(
+        max_iterations=5,
+        tolerance=0.0,
+        damping_factor=0.85,
+        progress=lambda *update: updates.append(update),
+    )
+
+    assert result.ran_iterations == 5
+    assert updates == [("page_rank", i, 5) for i in range(1, 6)]
+
+
+def test_louvain_progress(ug: Graph):
+    updates = []
+
+    result = ug.louvain(progress=lambda *update: updates.append(update))
+
+    assert len(updates) == result.ran_iterations
+    assert all(task == "louvain" and total is None for task, _, total in updates)
+
+
+def test_node2vec_progress(ug: Graph):
+    updates = []
+
+    ug.node2vec(
+        embedding_dimension=4,
+        walk_length=5,
+        walks_per_node=1,
+        progress=lambda *update: updates.append(update),
+    )
+
+    assert updates[-1] == ("node2vec", ug.node_count(), ug.node_count())
+    completed = [update[1] for update in updates]
+    assert completed == sorted(completed)
+
+
+def test_progress_exception_is_raised(ug: Graph):
+    calls = []
+
+    def progress(task, completed, total):
+        calls.append(completed)
+        raise KeyboardInterrupt
+
+    with pytest.raises(KeyboardInterrupt):
+        ug.label_propagation(max_iterations=5, progress=progress)
+
+    assert calls == [1]
+
+
+def test_progress_must_be_callable(ug: Graph):
+    with pytest.raises(TypeError):
+        ug.fast_rp(progress=42)
+
+
+def test_reset_logging(ug: Graph, caplog: pytest.LogCaptureFixture):
+    logging.getLogger().setLevel(logging.INFO)
+    gm.reset_logging()
+
+    with caplog.at_level(logging.INFO):
+        ug.label_propagation()
+
+    assert any("label propagation" in record.getMessage() for record in caplog.records)
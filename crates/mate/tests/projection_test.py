@@ -0,0 +1,55 @@
+import numpy as np
+import pytest
+
+from graph_mate import Graph, WeightedGraph
+
+
+@pytest.fixture
+def ratings() -> Graph:
+    # users 0 to 3 and items 4 to 6, item 6 is rated by every user
+    return Graph.from_arrays(
+        np.array([0, 1, 1, 2, 0, 1, 2, 3]),
+        np.array([4, 4, 5, 5, 6, 6, 6, 6]),
+    )
+
+
+def test_project(ratings: Graph):
+    users, original_ids = ratings.project(np.arange(4))
+
+    assert isinstance(users, WeightedGraph)
+    assert original_ids.tolist() == [0, 1, 2, 3]
+    assert users.edge_count() == 6
+    # users 0 and 1 as well as 1 and 2 share two items
+    assert users.filter(min_weight=2.0).edge_count() == 2
+
+
+def test_project_other_side(ratings: Graph):
+    items, original_ids = ratings.project([4, 5, 6])
+
+    assert original_ids.tolist() == [4, 5, 6]
+    assert items.edge_count() == 3
+    assert items.degree(2) == 2
+
+
+def test_weightings(ratings: Graph):
+    jaccard, _ = ratings.project([0, 1, 2, 3], weighting="jaccard")
+    newman, _ = ratings.project([0, 1, 2, 3], weighting="newman")
+
+    assert jaccard.filter(min_weight=0.6).edge_count() == 2
+    assert newman.filter(max_weight=0.5).edge_count() == 4
+
+
+def test_pruning(ratings: Graph):
+    users, _ = ratings.project([0, 1, 2, 3], max_degree=3)
+    assert users.edge_count() == 2
+    assert users.degree(3) == 0
+
+    users, _ = ratings.project([0, 1, 2, 3], min_count=2)
+    assert users.edge_count() == 2
+
+
+def test_invalid_arguments(ratings: Graph):
+    with pytest.raises(ValueError, match="unknown weighting"):
+        ratings.project([0, 1], weighting="cosine")
+    with pytest.raises(IndexError):
+        ratings.project([0, 7])
@@ -0,0 +1,89 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, WeightedDiGraph
+
+# Nodes 0, 1 and 2 form a cycle, as do 3 and 4.
+SOURCES = np.array([0, 1, 2, 2, 3, 4, 5, 6])
+TARGETS = np.array([1, 2, 0, 3, 4, 3, 0, 4])
+
+
+def reachable(g: DiGraph, source: int) -> set[int]:
+    seen = {source}
+    stack = [source]
+    while stack:
+        for target in g.out_neighbors(stack.pop()).tolist():
+            if target not in seen:
+                seen.add(target)
+                stack.append(target)
+    return seen
+
+
+def test_reachability_index():
+    g = DiGraph.from_arrays(SOURCES, TARGETS)
+
+    index = g.reachability_index()
+
+    assert index.node_count == 7
+    assert index.component_count == 4
+    components = index.components()
+    assert components[0] == components[1] == components[2]
+    assert components[3] == components[4]
+    assert components[5] < components[0] < components[3]
+
+    for source in range(g.node_count()):
+        expected = reachable(g, source)
+        for target in range(g.node_count()):
+            assert index.can_reach(source, target) == (target in expected)
+
+
+def test_reachability_index_many(g: DiGraph):
+    index = g.reachability_index(2, seed=7)
+    sources = np.arange(g.node_count(), dtype=np.uint32)
+    targets = sources[::-1].copy()
+
+    result = index.can_reach_many(sources, targets)
+
+    assert result.dtype == np.bool_
+    assert result.tolist() == [index.can_reach(s, t) for s, t in zip(sources, targets)]
+
+
+def test_reachability_index_weighted():
+    g = WeightedDiGraph.from_arrays(SOURCES, TARGETS, np.ones(len(SOURCES)))
+
+    index = g.reachability_index()
+
+    assert index.can_reach(6, 3)
+    assert not index.can_reach(6, 0)
+
+
+def test_reachability_index_invalid_arguments():
+    index = DiGraph.from_arrays(SOURCES, TARGETS).reachability_index()
+
+    with pytest.raises(ValueError):
+        index.can_reach(0, 7)
+    with pytest.raises(ValueError):
+        index.can_reach_many(np.array([0, 1]), np.array([2]))
+
+
+def test_descendant_counts():
+    g = DiGraph.from_arrays(SOURCES, TARGETS)
+
+    counts = g.descendant_counts()
+
+    assert counts.dtype == np.float64
+    assert counts.tolist() == [len(reachable(g, node)) - 1 for node in range(g.node_count())]
+
+
+def test_descendant_counts_approximate(g: DiGraph):
+    exact = g.descendant_counts()
+    approximate = g.descendant_counts(exact_threshold=0, precision=12)
+
+    assert np.abs(approximate - exact).sum() / exact.sum() < 0.1
+
+
+def test_descendant_counts_invalid_precision():
+    g = WeightedDiGraph.from_arrays(SOURCES, TARGETS, np.ones(len(SOURCES)))
+
+    with pytest.raises(ValueError):
+        g.descendant_counts(precision=20)
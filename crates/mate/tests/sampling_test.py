@@ -0,0 +1,51 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph
+
+METHODS = ["node", "edge", "random_walk", "forest_fire"]
+
+
+@pytest.mark.parametrize("method", METHODS)
+def test_sample(g: DiGraph, method: str):
+    sample, original_ids = g.sample(0.25, method=method, seed=3)
+
+    assert sample.node_count() == round(0.25 * g.node_count())
+    assert len(original_ids) == sample.node_count()
+    assert np.all(np.diff(original_ids) > 0)
+
+    for node in range(sample.node_count()):
+        original = set(g.out_neighbors(original_ids[node]).tolist())
+        for target in sample.out_neighbors(node).tolist():
+            assert original_ids[target] in original
+
+
+@pytest.mark.parametrize("method", METHODS)
+def test_sample_is_reproducible(ug: Graph, method: str):
+    _, a = ug.sample(method=method, seed=1)
+    _, b = ug.sample(method=method, seed=1)
+    _, c = ug.sample(method=method, seed=2)
+
+    assert np.array_equal(a, b)
+    assert not np.array_equal(a, c)
+
+
+def test_sample_keeps_labels():
+    g = DiGraph.from_arrays(np.array([0, 1, 2]), np.array([1, 2, 3]))
+    g.node_labels = ["a", "b", "c", "d"]
+
+    sample, original_ids = g.sample(1.0, method="node")
+
+    assert original_ids.tolist() == [0, 1, 2, 3]
+    assert [sample.label(node) for node in range(4)] == ["a", "b", "c", "d"]
+
+
+def test_sample_invalid_arguments(ug: Graph):
+    with pytest.raises(ValueError):
+        ug.sample(0.0)
+    with pytest.raises(ValueError):
+        ug.sample(1.5)
+    with pytest.raises(ValueError):
+        ug.sample(method="snowball")
+    with pytest.raises(ValueError):
+        ug.sample(method="random_walk", restart_probability=1.0)
@@ -0,0 +1,167 @@
+import math
+
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph, WeightedDiGraph, WeightedGraph
+
+SOURCES = np.array([0, 0, 1, 1, 2, 3, 4])
+TARGETS = np.array([1, 2, 2, 3, 4, 5, 3])
+WEIGHTS = np.array([4.0, 2.0, 5.0, 10.0, 3.0, 11.0, 4.0])
+
+
+@pytest.fixture
+def wg() -> WeightedDiGraph:
+    return WeightedDiGraph.from_arrays(SOURCES, TARGETS, WEIGHTS)
+
+
+def test_dijkstra(wg: WeightedDiGraph):
+    result = wg.dijkstra(0)
+
+    assert result.start_node == 0
+    assert result.distances().tolist() == [0.0, 4.0, 2.0, 9.0, 5.0, 20.0]
+    assert result.distance_to(3) == 9.0
+    assert result.path_to(5) == [0, 2, 4, 3, 5]
+    assert result.path_to(0) == [0]
+    assert result.predecessors().tolist() == [0, 0, 0, 4, 2, 3]
+
+
+def test_dijkstra_unreachable(wg: WeightedDiGraph):
+    result = wg.dijkstra(3)
+
+    assert math.isinf(result.distance_to(0))
+    assert result.path_to(0) is None
+    assert result.path_to(5) == [3, 5]
+
+
+def test_dijkstra_undirected():
+    g = WeightedGraph.from_arrays(np.array([0, 1, 0]), np.array([1, 2, 2]), np.array([1.0, 1.0, 5.0]))
+
+    result = g.dijkstra(2)
+
+    assert list(result) == [2.0, 1.0, 0.0]
+    assert result.path_to(0) == [2, 1, 0]
+
+
+def test_astar(wg: WeightedDiGraph):
+    heuristic = [20.0, 16.0, 18.0, 11.0, 15.0, 0.0]
+
+    result = wg.astar(0, 5, heuristic=heuristic)
+
+    assert result.distance_to(5) == 20.0
+    assert result.path_to(5) == [0, 2, 4, 3, 5]
+
+
+def test_astar_without_heuristic(wg: WeightedDiGraph):
+    result = wg.astar(0, 2)
+
+    assert result.path_to(2) == [0, 2]
+    assert result.path_to(5) is None
+
+
+def test_invalid_arguments(wg: WeightedDiGraph):
+    with pytest.raises(ValueError):
+        wg.dijkstra(6)
+    with pytest.raises(ValueError):
+        wg.astar(0, 6)
+    with pytest.raises(ValueError):
+        wg.astar(0, 5, heuristic=[0.0])
+
+
+def test_negative_weights():
+    g = WeightedDiGraph.from_arrays(np.array([0]), np.array([1]), np.array([-1.0]))
+
+    with pytest.raises(ValueError, match="non-negative"):
+        g.dijkstra(0)
+
+
+def test_shortest_path(wg: WeightedDiGraph):
+    distance, path = wg.shortest_path(0, 5)
+
+    assert distance == 20.0
+    assert path.tolist() == [0, 2, 4, 3, 5]
+    assert path.dtype == np.uint32
+    assert wg.shortest_path(3, 0) is None
+    assert wg.shortest_path(2, 2)[1].tolist() == [2]
+    with pytest.raises(ValueError):
+        wg.shortest_path(0, 6)
+
+
+def test_shortest_path_undirected():
+    g = WeightedGraph.from_arrays(np.array([0, 1, 0]), np.array([1, 2, 2]), np.array([1.0, 1.0, 5.0]))
+
+    distance, path = g.shortest_path(2, 0)
+
+    assert distance == 2.0
+    assert path.tolist() == [2, 1, 0]
+
+
+def test_shortest_path_unweighted():
+    g = DiGraph.from_arrays(SOURCES, TARGETS)
+
+    hops, path = g.shortest_path(0, 5)
+
+    assert hops == 3.0
+    assert path.tolist() == [0, 1, 3, 5]
+    assert g.shortest_path(5, 0) is None
+
+    ug = Graph.from_arrays(SOURCES, TARGETS)
+    assert ug.shortest_path(5, 0)[0] == 3.0
+
+
+def test_shortest_path_matches_dijkstra(wg: WeightedDiGraph):
+    for source in range(wg.node_count()):
+        result = wg.dijkstra(source)
+        for target in range(wg.node_count()):
+            path = wg.shortest_path(source, target)
+            if math.isinf(result.distance_to(target)):
+                assert path is None
+            else:
+                assert path[0] == result.distance_to(target)
+
+
+def test_landmark_index(wg: WeightedDiGraph):
+    index = wg.landmark_index(2, seed=3)
+
+    assert len(index.landmarks()) == 2
+    for source in range(wg.node_count()):
+        distances = wg.dijkstra(source).distances()
+        for target in range(wg.node_count()):
+            assert index.lower_bound(source, target) <= distances[target]
+            assert index.upper_bound(source, target) >= distances[target]
+
+
+def test_landmark_index_vectorized(wg: WeightedDiGraph):
+    index = wg.landmark_index(3, selection="random")
+    sources = np.array([0, 1, 2, 3])
+    targets = np.array([5, 3, 3, 0])
+
+    lower = index.lower_bounds(sources, targets)
+    upper = index.upper_bounds(sources, targets)
+
+    assert lower.tolist() == [index.lower_bound(s, t) for s, t in zip(sources, targets)]
+    assert upper.tolist() == [index.upper_bound(s, t) for s, t in zip(sources, targets)]
+    assert math.isinf(lower[3])
+
+    result = wg.astar(0, 5, heuristic=index.heuristic(5))
+    assert result.path_to(5) == [0, 2, 4, 3, 5]
+
+
+def test_landmark_index_unweighted():
+    g = Graph.from_arrays(SOURCES, TARGETS)
+
+    index = g.landmark_index(g.node_count())
+
+    assert index.lower_bound(0, 5) == 3.0
+    assert index.upper_bound(0, 5) == 3.0
+
+
+def test_landmark_index_invalid_arguments(wg: WeightedDiGraph):
+    with pytest.raises(ValueError):
+        wg.landmark_index(selection="central")
+
+    index = wg.landmark_index()
+    with pytest.raises(ValueError):
+        index.lower_bound(0, 6)
+    with pytest.raises(ValueError):
+        index.upper_bounds(np.array([0, 1]), np.array([2]))
@@ -0,0 +1,52 @@
+import math
+
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph
+
+
+def test_sssp(g: DiGraph):
+    sssp = g.sssp(0, 1.0)
+
+    assert sssp.micros > 0
+
+    distances = sssp.distances()
+
+    assert len(distances) == 1 << 8
+    assert distances[0] == 0.0
+    for distance in distances:
+        assert distance >= 0.0
+
+
+def test_sssp_hops():
+    g = DiGraph.from_numpy(
+        np.array(
+            [
+                # (a)-->(b)-->(c)-->(d), (a)-->(c)
+                [0, 1],
+                [1, 2],
+                [2, 3],
+                [0, 2],
+            ],
+            dtype=np.uint32,
+        )
+    )
+
+    sssp = g.sssp(0, 1.0)
+
+    assert len(sssp) == 4
+    assert list(sssp) == [0.0, 1.0, 1.0, 2.0]
+    assert sssp[-1] == 2.0
+
+    assert math.isinf(g.sssp(3, 1.0)[0])
+
+
+def test_sssp_invalid_start_node(g: DiGraph):
+    with pytest.raises(ValueError):
+        g.sssp(1 << 8, 1.0)
+
+
+def test_sssp_invalid_delta(g: DiGraph):
+    with pytest.raises(ValueError):
+        g.sssp(0, 0.0)
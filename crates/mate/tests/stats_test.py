@@ -0,0 +1,52 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph, WeightedDiGraph
+
+
+def test_stats(g: DiGraph):
+    stats = g.stats()
+
+    assert stats["node_count"] == g.node_count()
+    assert stats["edge_count"] == g.edge_count()
+    assert stats["density"] == pytest.approx(g.edge_count() / (256 * 255))
+
+    out_degrees = [g.out_degree(n) for n in range(g.node_count())]
+    assert stats["degree_min"] == min(out_degrees)
+    assert stats["degree_max"] == max(out_degrees)
+    assert stats["degree_mean"] == pytest.approx(np.mean(out_degrees))
+    assert stats["degree_min"] <= stats["degree_p50"] <= stats["degree_p90"]
+    assert stats["degree_p90"] <= stats["degree_p99"] <= stats["degree_max"]
+
+    in_degrees = [g.in_degree(n) for n in range(g.node_count())]
+    assert stats["in_degree_max"] == max(in_degrees)
+
+    # two neighbor lists with 4 bytes per target
+    assert stats["memory_bytes"] >= 2 * 4 * g.edge_count()
+
+
+def test_undirected_stats(ug: Graph):
+    stats = ug.stats()
+
+    assert stats["node_count"] == ug.node_count()
+    assert stats["degree_max"] == max(ug.degree(n) for n in range(ug.node_count()))
+    assert "in_degree_max" not in stats
+
+
+def test_weighted_stats():
+    g = WeightedDiGraph.from_arrays([0, 0, 1], [1, 2, 2], [1.0, 2.0, 3.0])
+    stats = g.stats()
+
+    assert stats["node_count"] == 3
+    assert stats["edge_count"] == 3
+    assert stats["density"] == 0.5
+    assert stats["degree_p50"] == 1
+
+
+def test_repr(g: DiGraph):
+    repr_ = repr(g)
+
+    assert repr_.startswith("Graph {")
+    assert "node_count: 256" in repr_
+    assert "density:" in repr_
+    assert "memory:" in repr_
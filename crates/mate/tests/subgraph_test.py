@@ -0,0 +1,120 @@
+import numpy as np
+import pytest
+
+from graph_mate import DiGraph, Graph, Layout, WeightedDiGraph, WeightedGraph
+
+
+@pytest.fixture
+def path() -> DiGraph:
+    """0 -> 1 -> 2 -> 3 -> 4 and 1 -> 3"""
+    return DiGraph.from_arrays([0, 1, 2, 3, 1], [1, 2, 3, 4, 3], Layout.Sorted)
+
+
+def test_subgraph(path: DiGraph):
+    sub, mapping = path.subgraph([3, 1, 2])
+
+    assert mapping.tolist() == [1, 2, 3]
+    assert sub.node_count() == 3
+    # (1, 2), (2, 3) and (1, 3)
+    assert sub.edge_count() == 3
+    assert sub.out_neighbors(0).tolist() == [1, 2]
+
+
+def test_subgraph_of_undirected_graph(ug: Graph):
+    nodes = np.arange(0, ug.node_count(), 2, dtype=np.uint32)
+    sub, mapping = ug.subgraph(nodes)
+
+    assert np.array_equal(mapping, nodes)
+    for node in range(sub.node_count()):
+        for neighbor in sub.neighbors(node):
+            assert mapping[neighbor] in ug.neighbors(mapping[node])
+
+
+def test_subgraph_with_unknown_node(path: DiGraph):
+    with pytest.raises(IndexError):
+        path.subgraph([1, 42])
+
+
+def test_empty_subgraph(path: DiGraph):
+    with pytest.raises(ValueError):
+        path.subgraph([])
+
+
+def test_ego(path: DiGraph):
+    ego, mapping = path.ego(1)
+
+    assert mapping[0] == 1
+    assert sorted(mapping.tolist()) == [1, 2, 3]
+    assert ego.out_degree(0) == 2
+
+    ego, mapping = path.ego(1, radius=2)
+
+    assert sorted(mapping.tolist()) == [1, 2, 3, 4]
+
+
+def test_ego_of_unknown_node(path: DiGraph):
+    with pytest.raises(IndexError):
+        path.ego(5)
+
+
+def test_weighted_subgraph_keeps_weights():
+    g = WeightedDiGraph.from_arrays([0, 1, 2], [1, 2, 0], [1.0, 2.0, 4.0])
+    sub, mapping = g.subgraph([1, 2])
+
+    assert mapping.tolist() == [1, 2]
+    assert sub.to_pandas()["weight"].tolist() == [2.0]
+
+
+def test_filter():
+    g = WeightedDiGraph.from_arrays([0, 1, 2, 3], [1, 2, 3, 0], [0.5, 1.0, 2.0, 3.0])
+
+    heavy = g.filter(min_weight=1.0)
+    assert heavy.node_count() == 4
+    assert heavy.edge_count() == 3
+    assert heavy.out_degree(0) == 0
+
+    medium = g.filter(min_weight=1.0, max_weight=2.0)
+    assert medium.to_pandas()["weight"].tolist() == [1.0, 2.0]
+
+
+def test_filter_undirected():
+    g = WeightedGraph.from_arrays([0, 1, 2], [1, 2, 2], [0.5, 1.0, 2.0])
+
+    filtered = g.filter(max_weight=1.0)
+
+    assert filtered.node_count() == 3
+    assert filtered.edge_count() == 2
+    assert filtered.degree(0) == 1
+
+
+def test_filter_is_keyword_only():
+    g = WeightedGraph.from_arrays([0], [1], [1.0])
+
+    with pytest.raises(TypeError):
+        g.filter(1.0)
+
+
+def test_transform_weights():
+    g = WeightedDiGraph.from_arrays([0, 1, 2], [1, 2, 0], [0.5, 1.0, 0.25])
+
+    assert g.transform_weights("inverse").to_pandas()["weight"].tolist() == [2.0, 1.0, 4.0]
+    assert g.transform_weights("complement").to_pandas()["weight"].tolist() == [0.5, 0.0, 0.75]
+    assert np.allclose(
+        g.transform_weights("negative_log").to_pandas()["weight"], -np.log([0.5, 1.0, 0.25])
+    )
+    assert g.to_pandas()["weight"].tolist() == [0.5, 1.0, 0.25]
+
+
+def test_transform_weights_undirected():
+    g = WeightedGraph.from_arrays([0, 1], [1, 2], [2.0, 4.0])
+
+    distances = g.transform_weights("inverse").dijkstra(0).distances()
+
+    assert distances.tolist() == [0.0, 0.5, 0.75]
+
+
+def test_transform_weights_invalid():
+    g = WeightedGraph.from_arrays([0], [1], [1.0])
+
+    with pytest.raises(ValueError):
+        g.transform_weights("sqrt")
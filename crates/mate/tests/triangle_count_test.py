@@ -9,6 +9,10 @@ def test_triangle_count(ug: Graph):
     assert tc.micros > 0
 
 
+def test_triangle_count_alias(ug: Graph):
+    assert ug.triangle_count().triangles == ug.global_triangle_count().triangles
+
+
 def test_tc_two_components():
     ug = Graph.from_numpy(
         np.array(
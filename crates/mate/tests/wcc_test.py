@@ -1,3 +1,4 @@
+import numpy as np
 import pytest
 
 from graph_mate import DiGraph
@@ -16,6 +17,49 @@ def test_wcc(g: DiGraph):
         assert component < g.node_count()
 
 
+def test_wcc_sequence(g: DiGraph):
+    wcc = g.wcc()
+
+    assert len(wcc) == 1 << 8
+    assert list(wcc) == list(wcc.components())
+
+
+
+def test_wcc_slices(g: DiGraph):
+    wcc = g.wcc()
+    components = wcc.components()
+
+    assert wcc[5:1:-1].tolist() == components[5:1:-1].tolist()
+    assert isinstance(wcc[0], int)
+    with pytest.raises(TypeError):
+        wcc[1.5]
+
 def test_config_must_be_kwargs(g: DiGraph):
     with pytest.raises(TypeError):
         g.wcc(42, 1.0, 0.1)
+
+
+def test_wcc_size_summary(g: DiGraph):
+    wcc = g.wcc()
+    sizes = np.unique(wcc.components(), return_counts=True)[1]
+
+    summary = wcc.size_summary()
+
+    assert summary["max"] == sizes.max()
+    assert summary["mean"] == pytest.approx(sizes.mean())
+    assert summary["p50"] <= summary["p90"] <= summary["p99"] <= summary["max"]
+
+
+def test_wcc_initial_components(g: DiGraph):
+    components = g.wcc().components()
+
+    warm = g.wcc(initial_components=components).components()
+    assert np.array_equal(warm, components)
+
+    singletons = np.arange(g.node_count(), dtype=np.uint32)
+    assert np.array_equal(g.wcc(initial_components=singletons).components(), components)
+
+    with pytest.raises(ValueError):
+        g.wcc(initial_components=np.zeros(3, dtype=np.uint32))
+    with pytest.raises(ValueError):
+        g.wcc(initial_components=np.full(g.node_count(), g.node_count(), dtype=np.uint32))
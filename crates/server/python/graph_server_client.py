@@ -0,0 +1,530 @@
+"""A thin Python client for the graph server.
+
+Wraps the Flight actions of the server in typed methods, so that the server
+can be driven from scripts and notebooks without encoding actions by hand.
+Action results are returned as dictionaries, graph data and algorithm
+results as ``pyarrow.Table``.
+
+```python
+from graph_server_client import GraphServerClient
+
+client = GraphServerClient("localhost", 50051)
+client.create_from_edges("g", [(0, 1), (1, 2), (2, 0)])
+ranks = client.page_rank("g").to_pandas()
+```
+"""
+
+from __future__ import annotations
+
+import json
+from typing import Any, Dict, Iterable, List, Literal, Optional, Tuple, Union
+
+import pyarrow as pa
+import pyarrow.flight as flight
+
+CsrLayout = Literal["Sorted", "Unsorted", "Deduplicated"]
+Orientation = Literal["Directed", "Undirected"]
+FileFormat = Literal["EdgeList", "EdgeListWeighted", "Graph500"]
+ComputeMode = Literal["mutate", "stream"]
+Normalization = Literal["min_max", "l1", "l2", "standard", "log", "rank"]
+Comparison = Literal["<", "<=", ">", ">=", "==", "!="]
+
+Edge = Union[Tuple[int, int], Tuple[int, int, float]]
+Algorithm = Union[str, Dict[str, Any]]
+
+
+class GraphServerError(Exception):
+    """Raised if the server rejects an action."""
+
+
+class GraphServerClient:
+    """Runs actions against a graph server via Arrow Flight.
+
+    Given a ``token``, every request carries it as bearer token. With
+    ``tls`` set, the connection is encrypted and verified against the
+    optional ``root_certificates`` in PEM format.
+    """
+
+    def __init__(
+        self,
+        host: str = "localhost",
+        port: int = 50051,
+        *,
+        token: Optional[str] = None,
+        tls: bool = False,
+        root_certificates: Optional[bytes] = None,
+    ):
+        if tls:
+            location = flight.Location.for_grpc_tls(host, port)
+        else:
+            location = flight.Location.for_grpc_tcp(host, port)
+
+        kwargs = {}
+        if root_certificates is not None:
+            kwargs["tls_root_certs"] = root_certificates
+        self._client = flight.FlightClient(location, **kwargs)
+
+        headers = []
+        if token is not None:
+            headers.append((b"authorization", f"Bearer {token}".encode("utf-8")))
+        self._options = flight.FlightCallOptions(headers=headers)
+
+    def close(self) -> None:
+        self._client.close()
+
+    def __enter__(self) -> GraphServerClient:
+        return self
+
+    def __exit__(self, *exc_info) -> None:
+        self.close()
+
+    # Graph management
+
+    def create_from_file(
+        self,
+        graph_name: str,
+        path: str,
+        file_format: FileFormat = "EdgeList",
+        *,
+        csr_layout: CsrLayout = "Sorted",
+        orientation: Orientation = "Directed",
+    ) -> Dict[str, Any]:
+        """Creates a graph from a file on the server."""
+        return self.action(
+            "create",
+            {
+                "graph_name": graph_name,
+                "file_format": file_format,
+                "path": path,
+                "csr_layout": csr_layout,
+                "orientation": orientation,
+            },
+        )
+
+    def create_from_gdl(
+        self,
+        graph_name: str,
+        gdl: str,
+        *,
+        weighted: bool = False,
+        csr_layout: CsrLayout = "Sorted",
+        orientation: Orientation = "Directed",
+    ) -> Dict[str, Any]:
+        """Creates a graph from a GDL string, e.g., ``(a)-->(b)``."""
+        return self.action(
+            "create",
+            {
+                "graph_name": graph_name,
+                "gdl": gdl,
+                "weighted": weighted,
+                "csr_layout": csr_layout,
+                "orientation": orientation,
+            },
+        )
+
+    def create_from_edges(
+        self,
+        graph_name: str,
+        edges: Iterable[Edge],
+        *,
+        csr_layout: CsrLayout = "Sorted",
+        orientation: Orientation = "Directed",
+    ) -> Dict[str, Any]:
+        """Creates a graph from ``(source, target)`` or
+        ``(source, target, weight)`` tuples sent in the action body.
+
+        Use ``create_from_table`` for larger graphs.
+        """
+        return self.action(
+            "create",
+            {
+                "graph_name": graph_name,
+                "edges": [list(edge) for edge in edges],
+                "csr_layout": csr_layout,
+                "orientation": orientation,
+            },
+        )
+
+    def create_from_table(
+        self,
+        graph_name: str,
+        edges: pa.Table,
+        *,
+        csr_layout: CsrLayout = "Sorted",
+        orientation: Orientation = "Directed",
+    ) -> Dict[str, Any]:
+        """Creates a graph by uploading a table with a ``source`` and a
+        ``target`` column and an optional ``weight`` column.
+        """
+        weighted = "weight" in edges.column_names
+        columns = ["source", "target"] + (["weight"] if weighted else [])
+        edges = edges.select(columns).cast(
+            pa.schema(
+                [
+                    pa.field("source", pa.int64(), nullable=False),
+                    pa.field("target", pa.int64(), nullable=False),
+                ]
+                + ([pa.field("weight", pa.float32(), nullable=False)] if weighted else [])
+            )
+        )
+        command = {
+            "graph_name": graph_name,
+            "edge_count": edges.num_rows,
+            "csr_layout": csr_layout,
+            "orientation": orientation,
+            "weighted": weighted,
+        }
+        return self._put(command, edges)
+
+    def put_node_properties(
+        self, graph_name: str, properties: pa.Table, *, property_key: Optional[str] = None
+    ) -> Dict[str, Any]:
+        """Stores each value column of the table as node property.
+
+        The table either has a ``node_id`` column or one row per node in
+        node id order. A single value column can be renamed via
+        ``property_key``.
+        """
+        command: Dict[str, Any] = {"graph_name": graph_name}
+        if property_key is not None:
+            command["property_key"] = property_key
+        return self._put(command, properties)
+
+    def list_graphs(
+        self, namespace: Optional[str] = None, *, detail: bool = False
+    ) -> List[Dict[str, Any]]:
+        """Lists all graphs or the graphs of a namespace."""
+        body: Dict[str, Any] = {"detail": detail}
+        if namespace is not None:
+            body["namespace"] = namespace
+        return self.action("list", body)["graph_infos"]
+
+    def remove(self, graph_name: str) -> Dict[str, Any]:
+        """Removes a graph and its properties."""
+        return self.action("remove", {"graph_name": graph_name})
+
+    def remove_namespace(self, namespace: str) -> Dict[str, Any]:
+        """Removes all graphs of a namespace and their properties."""
+        return self.action("remove_namespace", {"namespace": namespace})
+
+    def to_undirected(self, graph_name: str, *, csr_layout: CsrLayout = "Sorted") -> Dict[str, Any]:
+        """Converts a directed graph into an undirected graph in place."""
+        return self.action("to_undirected", {"graph_name": graph_name, "csr_layout": csr_layout})
+
+    def stats(
+        self, graph_name: str, *, component_property_key: Optional[str] = None
+    ) -> Dict[str, Any]:
+        """Returns the degree distribution, density and, given a component
+        property, the number of components of the graph.
+        """
+        body: Dict[str, Any] = {"graph_name": graph_name}
+        if component_property_key is not None:
+            body["component_property_key"] = component_property_key
+        return self.action("stats", body)
+
+    def metrics(self) -> str:
+        """Returns the server metrics in the Prometheus text format."""
+        return self._action_bytes("metrics", None).decode("utf-8")
+
+    # Algorithms
+
+    def compute(
+        self,
+        graph_name: str,
+        algorithm: Algorithm,
+        *,
+        property_key: str = "",
+        mode: ComputeMode = "mutate",
+        timeout_millis: Optional[int] = None,
+        normalization: Optional[Normalization] = None,
+        summary: bool = False,
+    ) -> Dict[str, Any]:
+        """Runs an algorithm, e.g., ``{"PageRank": {...}}`` or
+        ``"TriangleCount"``, and returns the raw action result.
+
+        In ``mutate`` mode the result contains a ``property_id``, in
+        ``stream`` mode a ``ticket``; both can be passed to ``fetch``.
+        Scores of PageRank, Betweenness and DegreeCentrality and distances of
+        SSSP and BFS can be post-processed via ``normalization``. With
+        ``summary`` set, the result of these algorithms, WCC, Louvain and
+        LabelPropagation contains percentiles of the scores or community sizes.
+        """
+        body: Dict[str, Any] = {
+            "graph_name": graph_name,
+            "algorithm": algorithm,
+            "property_key": property_key,
+            "mode": mode,
+        }
+        if timeout_millis is not None:
+            body["timeout_millis"] = timeout_millis
+        if normalization is not None:
+            body["normalization"] = normalization
+        if summary:
+            body["summary"] = True
+        return self.action("compute", body)
+
+    def page_rank(
+        self,
+        graph_name: str,
+        *,
+        max_iterations: int = 20,
+        tolerance: float = 1e-4,
+        damping_factor: float = 0.85,
+        normalization: Optional[Normalization] = None,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes PageRank and returns the score of each node.
+
+        The scores are stored as ``property_key`` if given and streamed once
+        otherwise.
+        """
+        config = {
+            "max_iterations": max_iterations,
+            "tolerance": tolerance,
+            "damping_factor": damping_factor,
+        }
+        return self._compute_table(
+            graph_name, {"PageRank": config}, property_key, normalization
+        )
+
+    def wcc(
+        self,
+        graph_name: str,
+        *,
+        chunk_size: int = 16384,
+        neighbor_rounds: int = 2,
+        sampling_size: int = 1024,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the weakly connected component of each node."""
+        config = {
+            "chunk_size": chunk_size,
+            "neighbor_rounds": neighbor_rounds,
+            "sampling_size": sampling_size,
+        }
+        return self._compute_table(graph_name, {"Wcc": config}, property_key)
+
+    def sssp(
+        self,
+        graph_name: str,
+        start_node: int,
+        *,
+        delta: float = 1.0,
+        normalization: Optional[Normalization] = None,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the distance of each node from ``start_node``."""
+        config = {"start_node": start_node, "delta": delta}
+        return self._compute_table(graph_name, {"Sssp": config}, property_key, normalization)
+
+    def partition(
+        self,
+        graph_name: str,
+        *,
+        partitions: int = 2,
+        imbalance: float = 0.03,
+        coarsen_to: int = 20,
+        refinement_rounds: int = 8,
+        seed: int = 42,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the partition of each node."""
+        config = {
+            "partitions": partitions,
+            "imbalance": imbalance,
+            "coarsen_to": coarsen_to,
+            "refinement_rounds": refinement_rounds,
+            "seed": seed,
+        }
+        return self._compute_table(graph_name, {"Partition": config}, property_key)
+
+    def louvain(
+        self,
+        graph_name: str,
+        *,
+        max_iterations: int = 10,
+        tolerance: float = 1e-4,
+        seed: int = 42,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the Louvain community of each node of an undirected graph."""
+        config = {"max_iterations": max_iterations, "tolerance": tolerance, "seed": seed}
+        return self._compute_table(graph_name, {"Louvain": config}, property_key)
+
+    def label_propagation(
+        self,
+        graph_name: str,
+        *,
+        max_iterations: int = 10,
+        tolerance: float = 0.0,
+        seed: int = 42,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the label propagation community of each node of an
+        undirected graph."""
+        config = {"max_iterations": max_iterations, "tolerance": tolerance, "seed": seed}
+        return self._compute_table(graph_name, {"LabelPropagation": config}, property_key)
+
+    def betweenness(
+        self,
+        graph_name: str,
+        *,
+        sample_size: Optional[int] = None,
+        seed: int = 42,
+        normalization: Optional[Normalization] = None,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the betweenness of each node of an unweighted graph,
+        estimated from ``sample_size`` source nodes if given."""
+        config: Dict[str, Any] = {"seed": seed}
+        if sample_size is not None:
+            config["sample_size"] = sample_size
+        return self._compute_table(
+            graph_name, {"Betweenness": config}, property_key, normalization
+        )
+
+    def k_core(self, graph_name: str, *, property_key: Optional[str] = None) -> pa.Table:
+        """Computes the core number of each node of an undirected graph."""
+        return self._compute_table(graph_name, "KCore", property_key)
+
+    def bfs(
+        self,
+        graph_name: str,
+        start_node: int,
+        *,
+        max_depth: Optional[int] = None,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the number of hops from ``start_node`` to each node,
+        which is infinite for unreachable nodes."""
+        config: Dict[str, Any] = {"start_node": start_node}
+        if max_depth is not None:
+            config["max_depth"] = max_depth
+        return self._compute_table(graph_name, {"Bfs": config}, property_key)
+
+    def degree_centrality(
+        self,
+        graph_name: str,
+        *,
+        normalization: Optional[Normalization] = None,
+        property_key: Optional[str] = None,
+    ) -> pa.Table:
+        """Computes the degree of each node, i.e., the out-degree in directed
+        graphs."""
+        return self._compute_table(graph_name, "DegreeCentrality", property_key, normalization)
+
+    def triangle_count(self, graph_name: str) -> int:
+        """Counts the triangles of an undirected graph."""
+        return self.compute(graph_name, "TriangleCount")["triangle_count"]
+
+    # Background jobs
+
+    def submit(self, graph_name: str, algorithm: Algorithm, *, property_key: str = "") -> int:
+        """Runs an algorithm in the background and returns its job id."""
+        body = {
+            "graph_name": graph_name,
+            "algorithm": algorithm,
+            "property_key": property_key,
+        }
+        return self.action("submit", body)["job_id"]
+
+    def status(self, job_id: int) -> Dict[str, Any]:
+        return self.action("status", {"job_id": job_id})
+
+    def result(self, job_id: int) -> Dict[str, Any]:
+        return self.action("result", {"job_id": job_id})
+
+    def cancel(self, *, job_id: Optional[int] = None, graph_name: Optional[str] = None) -> Any:
+        """Cancels a single job or all jobs and computations on a graph."""
+        if (job_id is None) == (graph_name is None):
+            raise ValueError("Expected either a job_id or a graph_name")
+        if job_id is not None:
+            return self.action("cancel", {"job_id": job_id})
+        return self.action("cancel", {"graph_name": graph_name})
+
+    # Streaming
+
+    def edges(self, graph_name: str) -> pa.Table:
+        """Streams the edges of a graph."""
+        return self._get({"graph_name": graph_name})
+
+    def node_property(
+        self,
+        graph_name: str,
+        property_key: str,
+        *,
+        node_range: Optional[Tuple[int, Optional[int]]] = None,
+        filter: Optional[Tuple[Comparison, float]] = None,
+    ) -> pa.Table:
+        """Streams a stored node property.
+
+        With a ``(start, end)`` node range or an ``(op, value)`` filter, only
+        the matching rows are streamed, prefixed with their node id.
+        """
+        ticket: Dict[str, Any] = {"graph_name": graph_name, "property_key": property_key}
+        if node_range is not None:
+            start, end = node_range
+            ticket["node_range"] = {"start": start, "end": end}
+        if filter is not None:
+            op, value = filter
+            ticket["filter"] = {"op": op, "value": value}
+        return self._get(ticket)
+
+    def fetch(self, result: Dict[str, Any]) -> pa.Table:
+        """Streams the data referenced by the result of ``compute``."""
+        if "ticket" in result:
+            return self._get(result["ticket"])
+        return self._get(result["property_id"])
+
+    # Raw actions
+
+    def action(self, action_type: str, body: Optional[Any] = None) -> Any:
+        """Runs an action and returns its decoded JSON result."""
+        return json.loads(self._action_bytes(action_type, body))
+
+    def _action_bytes(self, action_type: str, body: Optional[Any]) -> bytes:
+        payload = b"" if body is None else json.dumps(body).encode("utf-8")
+        try:
+            results = self._client.do_action(flight.Action(action_type, payload), self._options)
+            return next(iter(results)).body.to_pybytes()
+        except flight.FlightError as e:
+            raise GraphServerError(f"{action_type} failed: {e}") from e
+
+    def _compute_table(
+        self,
+        graph_name: str,
+        algorithm: Algorithm,
+        property_key: Optional[str],
+        normalization: Optional[Normalization] = None,
+    ) -> pa.Table:
+        if property_key is None:
+            result = self.compute(
+                graph_name, algorithm, mode="stream", normalization=normalization
+            )
+        else:
+            result = self.compute(
+                graph_name, algorithm, property_key=property_key, normalization=normalization
+            )
+        return self.fetch(result)
+
+    def _get(self, ticket: Dict[str, Any]) -> pa.Table:
+        try:
+            reader = self._client.do_get(
+                flight.Ticket(json.dumps(ticket).encode("utf-8")), self._options
+            )
+            return reader.read_all()
+        except flight.FlightError as e:
+            raise GraphServerError(f"do_get failed: {e}") from e
+
+    def _put(self, command: Dict[str, Any], table: pa.Table) -> Dict[str, Any]:
+        descriptor = flight.FlightDescriptor.for_command(json.dumps(command).encode("utf-8"))
+        try:
+            writer, reader = self._client.do_put(descriptor, table.schema, self._options)
+            writer.write_table(table)
+            writer.done_writing()
+            metadata = reader.read()
+            writer.close()
+        except flight.FlightError as e:
+            raise GraphServerError(f"do_put failed: {e}") from e
+        return json.loads(metadata.to_pybytes()) if metadata is not None else {}
+
@@ -1,22 +1,38 @@
-use arrow_flight::{flight_descriptor::DescriptorType, Action, ActionType, FlightDescriptor};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow_flight::{
+    flight_descriptor::DescriptorType, Action, ActionType, FlightDescriptor, Ticket,
+};
 use serde::{Deserialize, Serialize};
 use tonic::Status;
 
-use crate::{catalog::PropertyId, server::FlightResult};
+use crate::{
+    catalog::{PropertyFilter, PropertyId, StreamTicket, TopologyTicket},
+    server::FlightResult,
+};
 use graph::prelude::*;
 
 #[derive(Debug)]
 pub enum FlightAction {
-    Create(CreateGraphFromFileConfig),
-    List,
+    Create(CreateGraphConfig),
+    List(ListConfig),
     Remove(RemoveGraphConfig),
+    RemoveNamespace(NamespaceConfig),
     Compute(ComputeConfig),
     ToRelabeled(ToRelabeledConfig),
     ToUndirected(ToUndirectedConfig),
+    Project(ProjectConfig),
+    Export(ExportConfig),
+    Stats(StatsConfig),
+    Submit(ComputeConfig),
+    Status(JobConfig),
+    Result(JobConfig),
+    Cancel(CancelConfig),
+    Metrics,
+    Pipeline(PipelineConfig),
 }
 
 impl FlightAction {
-    pub fn action_types() -> [ActionType; 6] {
+    pub fn action_types() -> [ActionType; 16] {
         [
             ActionType {
                 r#type: "create".into(),
@@ -24,11 +40,15 @@ impl FlightAction {
             },
             ActionType {
                 r#type: "list".into(),
-                description: "List all graphs.".into(),
+                description: "List all graphs or the graphs of a namespace.".into(),
             },
             ActionType {
                 r#type: "remove".into(),
-                description: "Remove a graph.".into(),
+                description: "Remove a graph and its properties.".into(),
+            },
+            ActionType {
+                r#type: "remove_namespace".into(),
+                description: "Remove all graphs of a namespace and their properties.".into(),
             },
             ActionType {
                 r#type: "compute".into(),
@@ -36,12 +56,49 @@ impl FlightAction {
             },
             ActionType {
                 r#type: "to_relabeled".into(),
-                description: "Relabels the node ids of a graph in degree-descending order".into(),
+                description: "Relabels the node ids of a graph using an ordering strategy".into(),
             },
             ActionType {
                 r#type: "to_undirected".into(),
                 description: "Converts a directed graph to an undirected graph".into(),
             },
+            ActionType {
+                r#type: "project".into(),
+                description: "Create a new graph from a filtered subset of the nodes of a graph."
+                    .into(),
+            },
+            ActionType {
+                r#type: "export".into(),
+                description: "Write a graph and its properties to server-side files.".into(),
+            },
+            ActionType {
+                r#type: "stats".into(),
+                description: "Get the degree distribution and other statistics of a graph.".into(),
+            },
+            ActionType {
+                r#type: "submit".into(),
+                description: "Submit a graph algorithm to run in the background.".into(),
+            },
+            ActionType {
+                r#type: "status".into(),
+                description: "Get the status of a submitted job.".into(),
+            },
+            ActionType {
+                r#type: "result".into(),
+                description: "Get the result of a finished job.".into(),
+            },
+            ActionType {
+                r#type: "cancel".into(),
+                description: "Cancel a running job or all computations on a graph.".into(),
+            },
+            ActionType {
+                r#type: "metrics".into(),
+                description: "Get server metrics in the Prometheus text format.".into(),
+            },
+            ActionType {
+                r#type: "pipeline".into(),
+                description: "Run a sequence of actions and report the result of each step.".into(),
+            },
         ]
     }
 }
@@ -56,11 +113,18 @@ impl TryFrom<Action> for FlightAction {
                 let create_action = action.try_into()?;
                 Ok(FlightAction::Create(create_action))
             }
-            "list" => Ok(FlightAction::List),
+            "list" => {
+                let list_action = action.try_into()?;
+                Ok(FlightAction::List(list_action))
+            }
             "remove" => {
                 let remove_action = action.try_into()?;
                 Ok(FlightAction::Remove(remove_action))
             }
+            "remove_namespace" => {
+                let remove_namespace_action = action.try_into()?;
+                Ok(FlightAction::RemoveNamespace(remove_namespace_action))
+            }
             "compute" => {
                 let compute_action = action.try_into()?;
                 Ok(FlightAction::Compute(compute_action))
@@ -73,6 +137,39 @@ impl TryFrom<Action> for FlightAction {
                 let to_undirected_action = action.try_into()?;
                 Ok(FlightAction::ToUndirected(to_undirected_action))
             }
+            "project" => {
+                let project_action = action.try_into()?;
+                Ok(FlightAction::Project(project_action))
+            }
+            "export" => {
+                let export_action = action.try_into()?;
+                Ok(FlightAction::Export(export_action))
+            }
+            "stats" => {
+                let stats_action = action.try_into()?;
+                Ok(FlightAction::Stats(stats_action))
+            }
+            "submit" => {
+                let submit_action = action.try_into()?;
+                Ok(FlightAction::Submit(submit_action))
+            }
+            "status" => {
+                let status_action = action.try_into()?;
+                Ok(FlightAction::Status(status_action))
+            }
+            "result" => {
+                let result_action = action.try_into()?;
+                Ok(FlightAction::Result(result_action))
+            }
+            "cancel" => {
+                let cancel_action = action.try_into()?;
+                Ok(FlightAction::Cancel(cancel_action))
+            }
+            "metrics" => Ok(FlightAction::Metrics),
+            "pipeline" => {
+                let pipeline_action = action.try_into()?;
+                Ok(FlightAction::Pipeline(pipeline_action))
+            }
             _ => Err(Status::invalid_argument(format!(
                 "Unknown action type: {action_type}"
             ))),
@@ -80,7 +177,7 @@ impl TryFrom<Action> for FlightAction {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone, Copy)]
 pub enum FileFormat {
     EdgeList,
     EdgeListWeighted,
@@ -95,7 +192,7 @@ pub enum CsrLayoutRef {
     Deduplicated,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
 pub enum Orientation {
     Directed,
     Undirected,
@@ -111,6 +208,7 @@ impl Default for Orientation {
 pub struct CreateGraphFromFileConfig {
     pub graph_name: String,
     pub file_format: FileFormat,
+    /// A local path or the URL of an object, e.g., `s3://bucket/edges.el.gz`.
     pub path: String,
     #[serde(with = "CsrLayoutRef")]
     #[serde(default)]
@@ -119,18 +217,27 @@ pub struct CreateGraphFromFileConfig {
     pub orientation: Orientation,
 }
 
-impl TryFrom<Action> for CreateGraphFromFileConfig {
-    type Error = Status;
-
-    fn try_from(action: Action) -> Result<Self, Self::Error> {
-        serde_json::from_slice::<CreateGraphFromFileConfig>(&action.body).map_err(from_json_error)
-    }
+/// Creates a graph from a GDL string carried in the action body.
+#[derive(Deserialize, Debug)]
+pub struct CreateGraphFromGdlConfig {
+    pub graph_name: String,
+    pub gdl: String,
+    #[serde(with = "CsrLayoutRef")]
+    #[serde(default)]
+    pub csr_layout: CsrLayout,
+    #[serde(default)]
+    pub orientation: Orientation,
+    /// Reads edge weights from the `weight` property of each relationship.
+    #[serde(default)]
+    pub weighted: bool,
 }
 
+/// Creates a graph from an edge array carried in the action body, e.g.,
+/// `[[0, 1], [1, 2]]` or, for weighted graphs, `[[0, 1, 0.5], [1, 2, 1.5]]`.
 #[derive(Deserialize, Debug)]
-pub struct CreateGraphCommand {
+pub struct CreateGraphFromEdgesConfig {
     pub graph_name: String,
-    pub edge_count: i64,
+    pub edges: InlineEdges,
     #[serde(with = "CsrLayoutRef")]
     #[serde(default)]
     pub csr_layout: CsrLayout,
@@ -138,7 +245,98 @@ pub struct CreateGraphCommand {
     pub orientation: Orientation,
 }
 
-impl TryFrom<FlightDescriptor> for CreateGraphCommand {
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum InlineEdges {
+    Unweighted(Vec<(u64, u64)>),
+    Weighted(Vec<(u64, u64, f32)>),
+}
+
+impl InlineEdges {
+    pub fn len(&self) -> usize {
+        match self {
+            InlineEdges::Unweighted(edges) => edges.len(),
+            InlineEdges::Weighted(edges) => edges.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        matches!(self, InlineEdges::Weighted(_))
+    }
+
+    /// Returns the node count of the graph, which is the largest node id
+    /// plus one.
+    pub fn node_count(&self) -> Result<u64, Status> {
+        let max_node_id = match self {
+            InlineEdges::Unweighted(edges) => edges.iter().map(|(s, t)| u64::max(*s, *t)).max(),
+            InlineEdges::Weighted(edges) => edges.iter().map(|(s, t, _)| u64::max(*s, *t)).max(),
+        }
+        .unwrap_or_default();
+
+        max_node_id
+            .checked_add(1)
+            .filter(|node_count| usize::try_from(*node_count).is_ok())
+            .ok_or_else(|| Status::invalid_argument(format!("Node id {max_node_id} is too large")))
+    }
+}
+
+/// The source of a graph created via the `create` action.
+///
+/// Bodies with a `gdl` field create the graph from a GDL string, bodies with
+/// an `edges` field from an inline edge array and all other bodies from a
+/// server-side file.
+#[derive(Debug)]
+pub enum CreateGraphConfig {
+    FromFile(CreateGraphFromFileConfig),
+    FromGdl(CreateGraphFromGdlConfig),
+    FromEdges(CreateGraphFromEdgesConfig),
+}
+
+impl TryFrom<Action> for CreateGraphConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        let body =
+            serde_json::from_slice::<serde_json::Value>(&action.body).map_err(from_json_error)?;
+        let config = if body.get("gdl").is_some() {
+            CreateGraphConfig::FromGdl(serde_json::from_value(body).map_err(from_json_error)?)
+        } else if body.get("edges").is_some() {
+            let config: CreateGraphFromEdgesConfig =
+                serde_json::from_value(body).map_err(from_json_error)?;
+            if config.edges.is_empty() {
+                return Err(Status::invalid_argument("Expected at least one edge"));
+            }
+            CreateGraphConfig::FromEdges(config)
+        } else {
+            CreateGraphConfig::FromFile(serde_json::from_value(body).map_err(from_json_error)?)
+        };
+        Ok(config)
+    }
+}
+
+/// The command sent as flight descriptor of a `do_put` request.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum PutCommand {
+    CreateGraph(CreateGraphCommand),
+    NodeProperties(NodePropertiesCommand),
+}
+
+impl PutCommand {
+    /// Returns the schema of the record batches accepted by this command.
+    pub fn schema(&self) -> Schema {
+        match self {
+            PutCommand::CreateGraph(command) => command.schema(),
+            PutCommand::NodeProperties(command) => command.schema(),
+        }
+    }
+}
+
+impl TryFrom<FlightDescriptor> for PutCommand {
     type Error = Status;
 
     fn try_from(descriptor: FlightDescriptor) -> Result<Self, Self::Error> {
@@ -157,6 +355,118 @@ impl TryFrom<FlightDescriptor> for CreateGraphCommand {
     }
 }
 
+/// Selects the edges of a graph or one of its properties via a path
+/// descriptor, i.e., `[graph_name]` or `[graph_name, property_key]`.
+#[derive(Debug)]
+pub enum FlightPath {
+    Edges(String),
+    Property(PropertyId),
+}
+
+impl FlightPath {
+    pub fn descriptor(&self) -> FlightDescriptor {
+        match self {
+            FlightPath::Edges(graph_name) => FlightDescriptor::new_path(vec![graph_name.clone()]),
+            FlightPath::Property(property_id) => FlightDescriptor::new_path(vec![
+                property_id.graph_name.clone(),
+                property_id.property_key.clone(),
+            ]),
+        }
+    }
+
+    /// Returns the `do_get` ticket that streams the selected data.
+    pub fn ticket(&self) -> Result<Ticket, Status> {
+        let ticket = match self {
+            FlightPath::Edges(graph_name) => serde_json::to_vec(&TopologyTicket {
+                graph_name: graph_name.clone(),
+            }),
+            FlightPath::Property(property_id) => serde_json::to_vec(property_id),
+        }
+        .map_err(from_json_error)?;
+        Ok(Ticket::new(ticket))
+    }
+}
+
+impl TryFrom<&FlightDescriptor> for FlightPath {
+    type Error = Status;
+
+    fn try_from(descriptor: &FlightDescriptor) -> Result<Self, Self::Error> {
+        match descriptor.path.as_slice() {
+            [graph_name] => Ok(FlightPath::Edges(graph_name.clone())),
+            [graph_name, property_key] => Ok(FlightPath::Property(PropertyId::new(
+                graph_name.clone(),
+                property_key.clone(),
+            ))),
+            path => Err(Status::invalid_argument(format!(
+                "Expected [graph_name] or [graph_name, property_key], got {path:?}"
+            ))),
+        }
+    }
+}
+
+/// Creates a graph from a stream of edge batches.
+///
+/// Each batch contains a `source` and a `target` column of type `Int64` and,
+/// if `weighted` is set, a `weight` column of type `Float32` or `Float64`.
+#[derive(Deserialize, Debug)]
+pub struct CreateGraphCommand {
+    pub graph_name: String,
+    pub edge_count: i64,
+    #[serde(with = "CsrLayoutRef")]
+    #[serde(default)]
+    pub csr_layout: CsrLayout,
+    #[serde(default)]
+    pub orientation: Orientation,
+    #[serde(default)]
+    pub weighted: bool,
+}
+
+impl CreateGraphCommand {
+    /// Returns the schema of the accepted edge batches.
+    pub fn schema(&self) -> Schema {
+        let mut fields = vec![
+            Field::new("source", DataType::Int64, false),
+            Field::new("target", DataType::Int64, false),
+        ];
+        if self.weighted {
+            fields.push(Field::new("weight", DataType::Float32, false));
+        }
+        Schema::new(fields)
+    }
+}
+
+/// Attaches node properties to an existing graph from a stream of batches.
+///
+/// Each batch contains an optional `node_id` column of any integer type
+/// followed by one or more value columns of any type, e.g., embeddings as
+/// fixed size lists or labels as strings. Each value column is stored as a
+/// node property keyed by its column name, unless a `property_key` is given
+/// for a single value column.
+///
+/// With a `node_id` column, each node may occur at most once and nodes
+/// without a value are set to null. Without it, the batches contain exactly
+/// one row per node in node id order.
+#[derive(Deserialize, Debug)]
+pub struct NodePropertiesCommand {
+    pub graph_name: String,
+    #[serde(default)]
+    pub property_key: Option<String>,
+}
+
+impl NodePropertiesCommand {
+    /// Returns an example schema of the accepted node property batches.
+    pub fn schema(&self) -> Schema {
+        Schema::new(vec![
+            Field::new("node_id", DataType::Int64, false),
+            Field::new(
+                self.property_key.as_deref().unwrap_or("value"),
+                DataType::Float64,
+                true,
+            ),
+        ])
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CreateActionResult {
     node_count: u64,
@@ -175,17 +485,114 @@ impl CreateActionResult {
 }
 
 #[derive(Serialize, Debug)]
-pub struct ListActionResult {
-    graph_infos: Vec<GraphInfo>,
+pub struct NodePropertiesResult {
+    property_ids: Vec<PropertyId>,
+    row_count: u64,
+    put_millis: u128,
+}
+
+impl NodePropertiesResult {
+    pub fn new(property_ids: Vec<PropertyId>, row_count: u64, put_millis: u128) -> Self {
+        Self {
+            property_ids,
+            row_count,
+            put_millis,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct ListConfig {
+    /// Lists only the graphs of this namespace.
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// Includes memory usage, layout, creation time and properties of each
+    /// graph, see [`GraphDetails`].
+    #[serde(default)]
+    pub detail: bool,
+}
+
+impl TryFrom<Action> for ListConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        // The config is optional, so that all graphs can be listed without a body.
+        if action.body.is_empty() {
+            return Ok(Self::default());
+        }
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct ListActionResult<T> {
+    graph_infos: Vec<T>,
 }
 
-impl ListActionResult {
-    pub fn new(graph_infos: Vec<GraphInfo>) -> Self {
+impl<T> ListActionResult<T> {
+    pub fn new(graph_infos: Vec<T>) -> Self {
         Self { graph_infos }
     }
 }
 
+/// The info of a graph as returned by a detailed `list` action.
 #[derive(Serialize, Debug)]
+pub struct GraphDetails {
+    #[serde(flatten)]
+    info: GraphInfo,
+    orientation: Orientation,
+    /// Only known for graphs that are loaded, since it is derived from the
+    /// adjacency lists.
+    csr_layout: Option<&'static str>,
+    loaded: bool,
+    /// Estimated for graphs that are not loaded.
+    size_in_bytes: u64,
+    /// Milliseconds since the Unix epoch.
+    created_at: u64,
+    properties: Vec<PropertyInfo>,
+}
+
+impl GraphDetails {
+    pub fn new(
+        info: GraphInfo,
+        csr_layout: Option<&'static str>,
+        loaded: bool,
+        size_in_bytes: u64,
+        created_at: u64,
+        properties: Vec<PropertyInfo>,
+    ) -> Self {
+        Self {
+            orientation: info.orientation(),
+            info,
+            csr_layout,
+            loaded,
+            size_in_bytes,
+            created_at,
+            properties,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PropertyInfo {
+    property_key: String,
+    data_type: String,
+}
+
+impl PropertyInfo {
+    pub fn new(property_key: String, data_type: String) -> Self {
+        Self {
+            property_key,
+            data_type,
+        }
+    }
+
+    pub fn property_key(&self) -> &str {
+        &self.property_key
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GraphInfo {
     graph_name: String,
     graph_type: String,
@@ -202,6 +609,34 @@ impl GraphInfo {
             edge_count,
         }
     }
+
+    pub fn graph_name(&self) -> &str {
+        &self.graph_name
+    }
+
+    pub fn graph_type(&self) -> &str {
+        &self.graph_type
+    }
+
+    pub fn node_count(&self) -> u64 {
+        self.node_count
+    }
+
+    pub fn edge_count(&self) -> u64 {
+        self.edge_count
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        self.graph_type.ends_with("+weighted")
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        if self.graph_type.starts_with("undirected") {
+            Orientation::Undirected
+        } else {
+            Orientation::Directed
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -217,9 +652,225 @@ impl TryFrom<Action> for RemoveGraphConfig {
     }
 }
 
+#[derive(Serialize, Debug)]
+pub struct RemoveActionResult {
+    #[serde(flatten)]
+    graph_info: GraphInfo,
+    removed_properties: usize,
+    reclaimed_bytes: u64,
+}
+
+impl RemoveActionResult {
+    pub fn new(graph_info: GraphInfo, removed_properties: usize, reclaimed_bytes: u64) -> Self {
+        Self {
+            graph_info,
+            removed_properties,
+            reclaimed_bytes,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NamespaceConfig {
+    pub namespace: String,
+}
+
+impl TryFrom<Action> for NamespaceConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct RemoveNamespaceResult {
+    namespace: String,
+    removed_graphs: Vec<RemoveActionResult>,
+    reclaimed_bytes: u64,
+}
+
+impl RemoveNamespaceResult {
+    pub fn new(namespace: String, removed_graphs: Vec<RemoveActionResult>) -> Self {
+        let reclaimed_bytes = removed_graphs
+            .iter()
+            .map(|result| result.reclaimed_bytes)
+            .sum();
+        Self {
+            namespace,
+            removed_graphs,
+            reclaimed_bytes,
+        }
+    }
+}
+
+/// A sequence of actions that is executed in order until a step fails, e.g.,
+///
+/// ```json
+/// {
+///     "steps": [
+///         { "type": "create", "body": { "graph_name": "g", ... } },
+///         { "type": "compute", "body": { "graph_name": "g", ... } },
+///         { "type": "export", "body": { "graph_name": "g", ... } }
+///     ]
+/// }
+/// ```
+#[derive(Deserialize, Debug)]
+pub struct PipelineConfig {
+    pub steps: Vec<PipelineStep>,
+}
+
+impl TryFrom<Action> for PipelineConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PipelineStep {
+    #[serde(rename = "type")]
+    pub action_type: String,
+    /// The JSON body of the action, omitted for actions without a body.
+    #[serde(default)]
+    pub body: serde_json::Value,
+}
+
+impl PipelineStep {
+    pub fn into_action(self) -> Result<Action, Status> {
+        let body = if self.body.is_null() {
+            Vec::new()
+        } else {
+            serde_json::to_vec(&self.body).map_err(from_json_error)?
+        };
+        Ok(Action {
+            r#type: self.action_type,
+            body: body.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StepState {
+    Completed,
+    Failed,
+    Skipped,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StepResult {
+    action_type: String,
+    state: StepState,
+    /// The result of a completed step, which is kept as text if it is not
+    /// valid JSON.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl StepResult {
+    pub fn new(action_type: String, result: FlightResult<arrow_flight::Result>) -> Self {
+        match result {
+            Ok(result) => {
+                let result = serde_json::from_slice(&result.body).unwrap_or_else(|_| {
+                    serde_json::Value::String(String::from_utf8_lossy(&result.body).into())
+                });
+                Self {
+                    action_type,
+                    state: StepState::Completed,
+                    result: Some(result),
+                    error: None,
+                }
+            }
+            Err(status) => Self {
+                action_type,
+                state: StepState::Failed,
+                result: None,
+                error: Some(status.message().to_string()),
+            },
+        }
+    }
+
+    pub fn skipped(action_type: String) -> Self {
+        Self {
+            action_type,
+            state: StepState::Skipped,
+            result: None,
+            error: None,
+        }
+    }
+
+    pub fn state(&self) -> StepState {
+        self.state
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PipelineResult {
+    succeeded: bool,
+    steps: Vec<StepResult>,
+    pipeline_millis: u128,
+}
+
+impl PipelineResult {
+    pub fn new(steps: Vec<StepResult>, pipeline_millis: u128) -> Self {
+        let succeeded = steps
+            .iter()
+            .all(|step| step.state() == StepState::Completed);
+        Self {
+            succeeded,
+            steps,
+            pipeline_millis,
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct ToRelabeledConfig {
     pub graph_name: String,
+    #[serde(default)]
+    pub ordering: RelabelOrdering,
+    /// The key of the property that maps the relabeled node ids to the
+    /// original ids.
+    #[serde(default = "default_mapping_property_key")]
+    pub mapping_property_key: String,
+}
+
+fn default_mapping_property_key() -> String {
+    "relabel_mapping".to_string()
+}
+
+/// Determines the new node ids of a relabeled graph.
+///
+/// Directed graphs are ordered by out-degree. Breadth-first orderings follow
+/// relationships of directed graphs in both directions.
+#[derive(Deserialize, Debug)]
+pub enum RelabelOrdering {
+    /// Orders nodes by descending degree.
+    Degree,
+    /// Orders nodes in breadth-first order, starting at the smallest node id
+    /// of each component.
+    Bfs,
+    /// Orders nodes in reverse Cuthill-McKee order, which reduces the
+    /// bandwidth of the adjacency matrix.
+    Rcm,
+    /// Orders nodes randomly.
+    Random {
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    /// Assigns node `u` the new id stored for `u` in the given node property,
+    /// e.g., uploaded via `do_put`.
+    Custom { property_key: String },
+}
+
+impl Default for RelabelOrdering {
+    fn default() -> Self {
+        Self::Degree
+    }
 }
 
 impl TryFrom<Action> for ToRelabeledConfig {
@@ -233,6 +884,8 @@ impl TryFrom<Action> for ToRelabeledConfig {
 #[derive(Serialize, Debug)]
 pub struct ToRelabeledResult {
     pub to_relabeled_millis: u128,
+    /// The property that maps the relabeled node ids to the original ids.
+    pub mapping: PropertyId,
 }
 
 #[derive(Deserialize, Debug)]
@@ -251,6 +904,245 @@ impl TryFrom<Action> for ToUndirectedConfig {
     }
 }
 
+/// Creates a new graph from the subgraph induced by all nodes that satisfy
+/// every given filter.
+///
+/// Graphs in the catalog carry neither node labels nor relationship types,
+/// so nodes are selected by their degree and their stored properties.
+#[derive(Deserialize, Debug)]
+pub struct ProjectConfig {
+    pub graph_name: String,
+    pub projected_graph_name: String,
+    /// Keeps nodes whose (out-)degree is at least this value.
+    #[serde(default)]
+    pub min_degree: Option<u64>,
+    /// Keeps nodes whose (out-)degree is at most this value.
+    #[serde(default)]
+    pub max_degree: Option<u64>,
+    /// Keeps nodes whose property value matches the filter.
+    #[serde(default)]
+    pub node_filter: Option<NodePropertyFilter>,
+}
+
+impl TryFrom<Action> for ProjectConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
+/// Compares a stored node property against a constant, e.g.,
+/// `{"property_key": "rank", "op": ">", "value": 0.5}`.
+#[derive(Deserialize, Debug)]
+pub struct NodePropertyFilter {
+    pub property_key: String,
+    #[serde(flatten)]
+    pub filter: PropertyFilter,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ProjectResult {
+    node_count: u64,
+    edge_count: u64,
+    project_millis: u128,
+    /// The property that maps the projected node ids to the original ids.
+    mapping: PropertyId,
+}
+
+impl ProjectResult {
+    pub fn new(
+        node_count: u64,
+        edge_count: u64,
+        project_millis: u128,
+        mapping: PropertyId,
+    ) -> Self {
+        Self {
+            node_count,
+            edge_count,
+            project_millis,
+            mapping,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub enum ExportFormat {
+    EdgeList,
+    Parquet,
+    Binary,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ExportConfig {
+    pub graph_name: String,
+    /// The path of the graph file, relative to the export directory of the
+    /// server.
+    ///
+    /// Properties are written next to it, to `<path>.<property_key>.parquet`,
+    /// where all characters of the property key except for ASCII letters,
+    /// digits, `-` and `_` are replaced by `_`.
+    pub path: String,
+    pub format: ExportFormat,
+    #[serde(default)]
+    pub property_keys: Vec<String>,
+}
+
+impl TryFrom<Action> for ExportConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct ExportedFile {
+    path: String,
+    size_bytes: u64,
+}
+
+impl ExportedFile {
+    pub fn new(path: String, size_bytes: u64) -> Self {
+        Self { path, size_bytes }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct ExportResult {
+    files: Vec<ExportedFile>,
+    export_millis: u128,
+}
+
+impl ExportResult {
+    pub fn new(files: Vec<ExportedFile>, export_millis: u128) -> Self {
+        Self {
+            files,
+            export_millis,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StatsConfig {
+    pub graph_name: String,
+    /// The key of a stored component property, e.g., computed by `Wcc`, to
+    /// count the components of the graph.
+    #[serde(default)]
+    pub component_property_key: Option<String>,
+    #[serde(default)]
+    pub format: StatsFormat,
+}
+
+impl TryFrom<Action> for StatsConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
+/// Determines how the degree histogram is returned to the client.
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsFormat {
+    /// Returns the histogram as part of the JSON result.
+    Json,
+    /// Returns a ticket to fetch the histogram once via `do_get` as record
+    /// batches with a `degree` and a `count` column.
+    Arrow,
+}
+
+impl Default for StatsFormat {
+    fn default() -> Self {
+        Self::Json
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct DegreeCount {
+    degree: u64,
+    count: u64,
+}
+
+impl DegreeCount {
+    pub fn new(degree: u64, count: u64) -> Self {
+        Self { degree, count }
+    }
+}
+
+/// Summary statistics of a graph.
+///
+/// Degrees are out-degrees for directed graphs.
+#[derive(Serialize, Debug)]
+pub struct GraphStats {
+    node_count: u64,
+    edge_count: u64,
+    density: f64,
+    min_degree: u64,
+    max_degree: u64,
+    mean_degree: f64,
+    /// Only set for the `json` format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    degree_histogram: Option<Vec<DegreeCount>>,
+    /// Only set for the `arrow` format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    histogram_ticket: Option<StreamTicket>,
+    /// Only set if a component property is given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component_count: Option<u64>,
+    stats_millis: u128,
+}
+
+impl GraphStats {
+    pub fn new(
+        node_count: u64,
+        edge_count: u64,
+        density: f64,
+        degree_histogram: &[DegreeCount],
+        stats_millis: u128,
+    ) -> Self {
+        let min_degree = degree_histogram.first().map_or(0, |d| d.degree);
+        let max_degree = degree_histogram.last().map_or(0, |d| d.degree);
+        let mean_degree = if node_count == 0 {
+            0.0
+        } else {
+            degree_histogram
+                .iter()
+                .map(|d| d.degree as f64 * d.count as f64)
+                .sum::<f64>()
+                / node_count as f64
+        };
+        Self {
+            node_count,
+            edge_count,
+            density,
+            min_degree,
+            max_degree,
+            mean_degree,
+            degree_histogram: None,
+            histogram_ticket: None,
+            component_count: None,
+            stats_millis,
+        }
+    }
+
+    pub fn with_degree_histogram(mut self, degree_histogram: Vec<DegreeCount>) -> Self {
+        self.degree_histogram = Some(degree_histogram);
+        self
+    }
+
+    pub fn with_histogram_ticket(mut self, histogram_ticket: StreamTicket) -> Self {
+        self.histogram_ticket = Some(histogram_ticket);
+        self
+    }
+
+    pub fn with_component_count(mut self, component_count: u64) -> Self {
+        self.component_count = Some(component_count);
+        self
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct ToUndirectedResult {
     pub to_undirected_millis: u128,
@@ -262,13 +1154,113 @@ pub enum Algorithm {
     TriangleCount,
     Sssp(DeltaSteppingConfig),
     Wcc(WccConfig),
+    Partition(PartitionConfig),
+    Louvain(LouvainConfig),
+    LabelPropagation(LabelPropagationConfig),
+    Betweenness(BetweennessConfig),
+    KCore,
+    Bfs(BfsConfig),
+    DegreeCentrality,
+}
+
+impl Algorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::PageRank(_) => "page_rank",
+            Algorithm::TriangleCount => "triangle_count",
+            Algorithm::Sssp(_) => "sssp",
+            Algorithm::Wcc(_) => "wcc",
+            Algorithm::Partition(_) => "partition",
+            Algorithm::Louvain(_) => "louvain",
+            Algorithm::LabelPropagation(_) => "label_propagation",
+            Algorithm::Betweenness(_) => "betweenness",
+            Algorithm::KCore => "k_core",
+            Algorithm::Bfs(_) => "bfs",
+            Algorithm::DegreeCentrality => "degree_centrality",
+        }
+    }
+
+    /// Returns whether the algorithm computes a floating point score per node,
+    /// which can be normalized.
+    pub fn has_scores(&self) -> bool {
+        matches!(
+            self,
+            Algorithm::PageRank(_)
+                | Algorithm::Sssp(_)
+                | Algorithm::Betweenness(_)
+                | Algorithm::Bfs(_)
+                | Algorithm::DegreeCentrality
+        )
+    }
+
+    /// Returns whether the result can be summarized by a `ResultSummary`.
+    pub fn has_summary(&self) -> bool {
+        self.has_scores()
+            || matches!(
+                self,
+                Algorithm::Wcc(_) | Algorithm::Louvain(_) | Algorithm::LabelPropagation(_)
+            )
+    }
+}
+
+/// Computes the number of hops from the start node to every node, following
+/// the outgoing edges of directed graphs.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct BfsConfig {
+    pub start_node: u64,
+    /// Stops the traversal after the given number of hops, nodes that are
+    /// further away are treated as unreachable.
+    #[serde(default)]
+    pub max_depth: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ComputeConfig {
     pub graph_name: String,
     pub algorithm: Algorithm,
+    /// The key of the stored property, only required in `mutate` mode.
+    #[serde(default)]
     pub property_key: String,
+    #[serde(default)]
+    pub mode: ComputeMode,
+    /// Fails the computation if it does not finish within this duration.
+    #[serde(default)]
+    pub timeout_millis: Option<u64>,
+    #[serde(flatten)]
+    pub post_processing: PostProcessing,
+}
+
+/// Optional post-processing of algorithm results.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
+pub struct PostProcessing {
+    /// Post-processes the scores of score-valued algorithms, i.e., page rank,
+    /// betweenness and degree centrality scores as well as sssp and bfs
+    /// distances, before they are returned or stored.
+    #[serde(default)]
+    pub normalization: Option<Normalization>,
+    /// Adds a percentile summary of the scores or, for wcc, louvain and label
+    /// propagation, of the community sizes to the result. Scores are
+    /// summarized before normalization.
+    #[serde(default)]
+    pub summary: bool,
+}
+
+/// Determines how algorithm results are returned to the client.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputeMode {
+    /// Stores the result as node property, which can be fetched via
+    /// `do_get` any number of times.
+    Mutate,
+    /// Returns a ticket to fetch the result once via `do_get` without
+    /// storing it as node property.
+    Stream,
+}
+
+impl Default for ComputeMode {
+    fn default() -> Self {
+        Self::Mutate
+    }
 }
 
 impl TryFrom<Action> for ComputeConfig {
@@ -279,11 +1271,60 @@ impl TryFrom<Action> for ComputeConfig {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct JobConfig {
+    pub job_id: u64,
+}
+
+impl TryFrom<Action> for JobConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
+/// Cancels either a single job or all running jobs and computations on a
+/// graph.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum CancelConfig {
+    Job { job_id: u64 },
+    Graph { graph_name: String },
+}
+
+impl TryFrom<Action> for CancelConfig {
+    type Error = Status;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        serde_json::from_slice::<Self>(&action.body).map_err(from_json_error)
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct PageRankResult {
     pub iterations: u64,
     pub error: f64,
     pub compute_millis: u128,
+    /// The highest-ranked nodes, if requested via `top_k`, with their scores
+    /// before normalization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_nodes: Option<Vec<NodeScore>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
+}
+
+/// A node together with its score, ordered by descending score in results.
+#[derive(Serialize, Debug)]
+pub struct NodeScore {
+    node_id: u64,
+    score: f32,
+}
+
+impl NodeScore {
+    pub fn new(node_id: u64, score: f32) -> Self {
+        Self { node_id, score }
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -295,11 +1336,80 @@ pub struct TriangleCountResult {
 #[derive(Serialize, Debug)]
 pub struct SsspResult {
     pub compute_millis: u128,
+    /// A summary of the finite distances, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
 }
 
 #[derive(Serialize, Debug)]
 pub struct WccResult {
     pub compute_millis: u128,
+    /// A summary of the component sizes, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PartitionResult {
+    pub edge_cut: u64,
+    pub imbalance: f64,
+    pub compute_millis: u128,
+}
+
+#[derive(Serialize, Debug)]
+pub struct LouvainResult {
+    pub modularity: f64,
+    pub levels: u64,
+    pub compute_millis: u128,
+    /// A summary of the community sizes, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct LabelPropagationResult {
+    pub iterations: u64,
+    pub compute_millis: u128,
+    /// A summary of the community sizes, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BetweennessResult {
+    pub compute_millis: u128,
+    /// The nodes with the highest betweenness, if requested via `top_k`,
+    /// with their scores before normalization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_nodes: Option<Vec<NodeScore>>,
+    /// A summary of the scores, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct KCoreResult {
+    /// The largest core number of all nodes.
+    pub degeneracy: u64,
+    pub compute_millis: u128,
+}
+
+#[derive(Serialize, Debug)]
+pub struct BfsResult {
+    /// The number of nodes reached from the start node, including itself.
+    pub reached_nodes: u64,
+    pub compute_millis: u128,
+    /// A summary of the finite distances, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DegreeCentralityResult {
+    pub compute_millis: u128,
+    /// A summary of the degrees, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<ResultSummary>,
 }
 
 #[derive(Serialize, Debug)]
@@ -317,13 +1427,39 @@ impl<T> MutateResult<T> {
     }
 }
 
+#[derive(Serialize, Debug)]
+pub struct StreamResult<T> {
+    ticket: StreamTicket,
+    algo_result: T,
+}
+
+impl<T> StreamResult<T> {
+    pub fn new(ticket: StreamTicket, algo_result: T) -> Self {
+        Self {
+            ticket,
+            algo_result,
+        }
+    }
+}
+
 pub fn from_json_error(error: serde_json::Error) -> Status {
     Status::internal(format!("JsonError: {error:?}"))
 }
 
+pub fn from_join_error(error: tokio::task::JoinError) -> Status {
+    Status::internal(format!("JoinError: {error:?}"))
+}
+
 pub fn into_flight_result<T: serde::Serialize>(result: T) -> FlightResult<arrow_flight::Result> {
     let result = serde_json::to_vec(&result).map_err(from_json_error)?;
     Ok(arrow_flight::Result {
         body: result.into(),
     })
 }
+
+pub fn into_put_result<T: serde::Serialize>(result: T) -> FlightResult<arrow_flight::PutResult> {
+    let result = serde_json::to_vec(&result).map_err(from_json_error)?;
+    Ok(arrow_flight::PutResult {
+        app_metadata: result.into(),
+    })
+}
@@ -0,0 +1,165 @@
+//! Token-based authentication and per-operation authorization.
+//!
+//! Clients send a token either via the Flight handshake or as bearer token in
+//! the `authorization` metadata of each request. Each token is mapped to a
+//! [`Role`] and an [`Authorizer`] decides which operations a role may perform.
+
+use std::{collections::HashMap, sync::Arc};
+
+use tonic::{metadata::MetadataMap, Request, Status};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    ReadOnly,
+    Admin,
+}
+
+/// The authenticated client of a request.
+#[derive(Clone, Debug)]
+pub struct Principal {
+    pub role: Role,
+}
+
+/// Decides whether a principal may perform an operation.
+///
+/// Operations are either flight action types, e.g., `create` or `compute`, or
+/// the names of the flight methods, i.e., `do_get`, `do_put`, `get_schema`,
+/// `get_flight_info`, `list_flights` and `list_actions`.
+pub trait Authorizer: Send + Sync {
+    fn authorize(&self, principal: &Principal, operation: &str) -> Result<(), Status>;
+}
+
+/// Admins may perform all operations, read-only principals may only list
+/// graphs and read results.
+pub struct RoleAuthorizer;
+
+const READ_ONLY_OPERATIONS: [&str; 10] = [
+    "list",
+    "stats",
+    "status",
+    "result",
+    "metrics",
+    "do_get",
+    "get_schema",
+    "get_flight_info",
+    "list_flights",
+    "list_actions",
+];
+
+impl Authorizer for RoleAuthorizer {
+    fn authorize(&self, principal: &Principal, operation: &str) -> Result<(), Status> {
+        match principal.role {
+            Role::Admin => Ok(()),
+            Role::ReadOnly if READ_ONLY_OPERATIONS.contains(&operation) => Ok(()),
+            Role::ReadOnly => Err(Status::permission_denied(format!(
+                "Operation '{operation}' requires an admin token"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Auth {
+    tokens: Arc<HashMap<String, Role>>,
+    authorizer: Arc<dyn Authorizer>,
+}
+
+impl Auth {
+    pub fn new(tokens: HashMap<String, Role>) -> Self {
+        Self {
+            tokens: Arc::new(tokens),
+            authorizer: Arc::new(RoleAuthorizer),
+        }
+    }
+
+    /// Replaces the default [`RoleAuthorizer`], e.g., to restrict single
+    /// actions further or to allow read-only principals more operations.
+    // The server binary only uses role-based authorization.
+    #[allow(dead_code)]
+    pub fn with_authorizer<A: Authorizer + 'static>(mut self, authorizer: A) -> Self {
+        self.authorizer = Arc::new(authorizer);
+        self
+    }
+
+    pub fn authenticate_token(&self, token: &str) -> Result<Principal, Status> {
+        self.tokens
+            .get(token)
+            .map(|role| Principal { role: *role })
+            .ok_or_else(|| Status::unauthenticated("Invalid token"))
+    }
+
+    /// Rejects requests with an invalid bearer token and attaches the
+    /// principal of a valid token to the request.
+    pub fn intercept(&self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if let Some(principal) = self.authenticate(request.metadata())? {
+            request.extensions_mut().insert(principal);
+        }
+        Ok(request)
+    }
+
+    pub fn authorize<T>(&self, request: &Request<T>, operation: &str) -> Result<(), Status> {
+        let principal = request
+            .extensions()
+            .get::<Principal>()
+            .ok_or_else(|| Status::unauthenticated("Missing authorization token"))?;
+        self.authorizer.authorize(principal, operation)
+    }
+
+    fn authenticate(&self, metadata: &MetadataMap) -> Result<Option<Principal>, Status> {
+        let Some(header) = metadata.get("authorization") else {
+            return Ok(None);
+        };
+        let token = header
+            .to_str()
+            .ok()
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .ok_or_else(|| Status::unauthenticated("Expected a bearer token"))?;
+        self.authenticate_token(token).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Only allows listing graphs, regardless of the role.
+    struct ListOnly;
+
+    impl Authorizer for ListOnly {
+        fn authorize(&self, _: &Principal, operation: &str) -> Result<(), Status> {
+            match operation {
+                "list" => Ok(()),
+                _ => Err(Status::permission_denied(operation.to_string())),
+            }
+        }
+    }
+
+    fn request(auth: &Auth, token: &str) -> Request<()> {
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        auth.intercept(request).unwrap()
+    }
+
+    #[test]
+    fn custom_authorizer() {
+        let tokens = HashMap::from([
+            (String::from("admin"), Role::Admin),
+            (String::from("reader"), Role::ReadOnly),
+        ]);
+        let auth = Auth::new(tokens);
+        assert!(auth.authorize(&request(&auth, "admin"), "compute").is_ok());
+        assert!(auth
+            .authorize(&request(&auth, "reader"), "compute")
+            .is_err());
+
+        let auth = auth.with_authorizer(ListOnly);
+        for token in ["admin", "reader"] {
+            let request = request(&auth, token);
+            assert!(auth.authorize(&request, "list").is_ok());
+            let error = auth.authorize(&request, "compute").unwrap_err();
+            assert_eq!(error.code(), tonic::Code::PermissionDenied);
+        }
+    }
+}
@@ -1,15 +1,36 @@
-use std::{collections::HashMap, marker::PhantomData, path::Path, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::File,
+    io::{BufRead, BufReader},
+    marker::PhantomData,
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use arrow::{
-    datatypes::{Field, Schema},
+    array::{as_primitive_array, ArrayRef, BooleanArray, Float32Array, UInt64Array},
+    compute::{cast, filter_record_batch},
+    datatypes::{DataType, Field, Float64Type, Schema, UInt64Type},
+    error::ArrowError,
     record_batch::RecordBatch,
 };
 use arrow_flight::Ticket;
 use graph::prelude::*;
+use log::info;
+use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use serde::{Deserialize, Serialize};
 use tonic::Status;
 
-use crate::actions::{from_json_error, FileFormat, GraphInfo, Orientation};
+use crate::actions::{
+    from_join_error, from_json_error, FileFormat, GraphDetails, GraphInfo, Orientation,
+    PropertyInfo,
+};
+use crate::persistence::SnapshotStore;
 
 pub enum GraphType {
     Directed(DirectedCsrGraph<u64>),
@@ -47,7 +68,6 @@ impl GraphType {
         }
     }
 
-    #[allow(dead_code)]
     pub fn from_edge_list_with_weights(
         edge_list: Vec<(u64, u64, f32)>,
         orientation: Orientation,
@@ -63,7 +83,38 @@ impl GraphType {
         }
     }
 
-    pub fn from_file<P: AsRef<Path>>(
+    /// Creates a graph from a GDL string.
+    ///
+    /// For weighted graphs, the edge weight is read from the `weight`
+    /// property of each relationship.
+    pub fn from_gdl(
+        gdl: &str,
+        orientation: Orientation,
+        csr_layout: CsrLayout,
+        weighted: bool,
+    ) -> Result<Self, Status> {
+        let builder = GraphBuilder::new()
+            .csr_layout(csr_layout)
+            .gdl_str::<u64, _>(gdl);
+        let graph = match (orientation, weighted) {
+            (Orientation::Directed, false) => {
+                GraphType::Directed(builder.build().map_err(from_gdl_error)?)
+            }
+            (Orientation::Undirected, false) => {
+                GraphType::Undirected(builder.build().map_err(from_gdl_error)?)
+            }
+            (Orientation::Directed, true) => {
+                GraphType::DirectedWeighted(builder.build().map_err(from_gdl_error)?)
+            }
+            (Orientation::Undirected, true) => {
+                GraphType::UndirectedWeighted(builder.build().map_err(from_gdl_error)?)
+            }
+        };
+        Ok(graph)
+    }
+
+    /// Reads a graph from a file without blocking the async runtime.
+    pub async fn from_file<P: AsRef<Path>>(
         path: P,
         format: FileFormat,
         orientation: Orientation,
@@ -75,7 +126,8 @@ impl GraphType {
                 let graph = builder
                     .file_format(EdgeListInput::default())
                     .path(path)
-                    .build()
+                    .build_async()
+                    .await
                     .map_err(from_graph_error)?;
                 Ok(GraphType::Directed(graph))
             }
@@ -83,7 +135,8 @@ impl GraphType {
                 let graph = builder
                     .file_format(EdgeListInput::default())
                     .path(path)
-                    .build()
+                    .build_async()
+                    .await
                     .map_err(from_graph_error)?;
                 Ok(GraphType::Undirected(graph))
             }
@@ -91,7 +144,8 @@ impl GraphType {
                 let graph = builder
                     .file_format(EdgeListInput::default())
                     .path(path)
-                    .build()
+                    .build_async()
+                    .await
                     .map_err(from_graph_error)?;
                 Ok(GraphType::DirectedWeighted(graph))
             }
@@ -99,7 +153,8 @@ impl GraphType {
                 let graph = builder
                     .file_format(EdgeListInput::default())
                     .path(path)
-                    .build()
+                    .build_async()
+                    .await
                     .map_err(from_graph_error)?;
                 Ok(GraphType::UndirectedWeighted(graph))
             }
@@ -107,7 +162,8 @@ impl GraphType {
                 let graph = builder
                     .file_format(Graph500Input::default())
                     .path(path)
-                    .build()
+                    .build_async()
+                    .await
                     .map_err(from_graph_error)?;
                 Ok(GraphType::Directed(graph))
             }
@@ -115,7 +171,8 @@ impl GraphType {
                 let graph = builder
                     .file_format(Graph500Input::default())
                     .path(path)
-                    .build()
+                    .build_async()
+                    .await
                     .map_err(from_graph_error)?;
                 Ok(GraphType::Undirected(graph))
             }
@@ -139,68 +196,868 @@ impl GraphType {
             GraphType::UndirectedWeighted(g) => g.edge_count(),
         }
     }
+
+    /// Returns `true`, if the edges of the graph have weights.
+    pub fn is_weighted(&self) -> bool {
+        matches!(
+            self,
+            GraphType::DirectedWeighted(_) | GraphType::UndirectedWeighted(_)
+        )
+    }
+
+    /// Returns the schema of the record batches returned by [`GraphType::edges`].
+    pub fn edge_schema(weighted: bool) -> Schema {
+        let mut fields = vec![
+            Field::new("source", DataType::UInt64, false),
+            Field::new("target", DataType::UInt64, false),
+        ];
+        if weighted {
+            fields.push(Field::new("weight", DataType::Float32, false));
+        }
+        Schema::new(fields)
+    }
+
+    /// Returns the edges of the graph as record batches.
+    ///
+    /// Edges of undirected graphs are returned once, with the smaller node id
+    /// as source.
+    pub fn edges(&self) -> Result<PropertyEntry, ArrowError> {
+        let schema = Arc::new(Self::edge_schema(self.is_weighted()));
+        let batches = match self {
+            GraphType::Directed(g) => edge_batches(g, &schema),
+            GraphType::Undirected(g) => edge_batches(g, &schema),
+            GraphType::DirectedWeighted(g) => edge_batches(g, &schema),
+            GraphType::UndirectedWeighted(g) => edge_batches(g, &schema),
+        }?;
+        Ok(PropertyEntry::new(schema, batches))
+    }
+
+    /// Returns the number of bytes occupied by the CSR arrays of the graph.
+    pub fn size_in_bytes(&self) -> u64 {
+        let bytes = match self {
+            GraphType::Directed(g) => {
+                csr_size(g.csr_out().offsets(), g.csr_out().targets_with_values_raw())
+                    + csr_size(g.csr_inc().offsets(), g.csr_inc().targets_with_values_raw())
+            }
+            GraphType::Undirected(g) => {
+                csr_size(g.csr().offsets(), g.csr().targets_with_values_raw())
+            }
+            GraphType::DirectedWeighted(g) => {
+                csr_size(g.csr_out().offsets(), g.csr_out().targets_with_values_raw())
+                    + csr_size(g.csr_inc().offsets(), g.csr_inc().targets_with_values_raw())
+            }
+            GraphType::UndirectedWeighted(g) => {
+                csr_size(g.csr().offsets(), g.csr().targets_with_values_raw())
+            }
+        };
+        bytes as u64
+    }
+
+    /// Returns the out-degree of the node in directed graphs and its degree
+    /// in undirected graphs.
+    pub fn degree(&self, node: u64) -> u64 {
+        match self {
+            GraphType::Directed(g) => g.out_degree(node),
+            GraphType::Undirected(g) => g.degree(node),
+            GraphType::DirectedWeighted(g) => g.out_degree(node),
+            GraphType::UndirectedWeighted(g) => g.degree(node),
+        }
+    }
+
+    /// Returns the number of nodes per degree, ordered by degree.
+    pub fn degree_histogram(&self) -> Vec<(u64, u64)> {
+        let mut histogram = BTreeMap::new();
+        for node in 0..self.node_count() {
+            *histogram.entry(self.degree(node)).or_insert(0) += 1;
+        }
+        histogram.into_iter().collect()
+    }
+
+    /// Returns the ratio of existing edges to possible edges, ignoring
+    /// self-loops.
+    pub fn density(&self) -> f64 {
+        let node_count = self.node_count() as f64;
+        if node_count < 2.0 {
+            return 0.0;
+        }
+        let possible_edges = match self {
+            GraphType::Directed(_) | GraphType::DirectedWeighted(_) => {
+                node_count * (node_count - 1.0)
+            }
+            GraphType::Undirected(_) | GraphType::UndirectedWeighted(_) => {
+                node_count * (node_count - 1.0) / 2.0
+            }
+        };
+        self.edge_count() as f64 / possible_edges
+    }
+
+    /// Returns the layout of the adjacency lists, i.e., `unsorted`, `sorted`
+    /// or, if they are sorted without parallel edges and self-loops,
+    /// `deduplicated`.
+    pub fn csr_layout(&self) -> &'static str {
+        let mut layout = "deduplicated";
+        for node in 0..self.node_count() {
+            let node_layout = match self {
+                GraphType::Directed(g) => adjacency_layout(node, g.out_neighbors(node).copied()),
+                GraphType::Undirected(g) => adjacency_layout(node, g.neighbors(node).copied()),
+                GraphType::DirectedWeighted(g) => {
+                    adjacency_layout(node, g.out_neighbors_with_values(node).map(|t| t.target))
+                }
+                GraphType::UndirectedWeighted(g) => {
+                    adjacency_layout(node, g.neighbors_with_values(node).map(|t| t.target))
+                }
+            };
+            match node_layout {
+                "unsorted" => return node_layout,
+                "sorted" => layout = node_layout,
+                _ => {}
+            }
+        }
+        layout
+    }
+
+    /// Calls `f` for every neighbor of the node, following relationships of
+    /// directed graphs in both directions.
+    fn for_each_neighbor<F: FnMut(u64)>(&self, node: u64, mut f: F) {
+        match self {
+            GraphType::Directed(g) => g
+                .out_neighbors(node)
+                .chain(g.in_neighbors(node))
+                .for_each(|n| f(*n)),
+            GraphType::Undirected(g) => g.neighbors(node).for_each(|n| f(*n)),
+            GraphType::DirectedWeighted(g) => g
+                .out_neighbors_with_values(node)
+                .chain(g.in_neighbors_with_values(node))
+                .for_each(|t| f(t.target)),
+            GraphType::UndirectedWeighted(g) => {
+                g.neighbors_with_values(node).for_each(|t| f(t.target))
+            }
+        }
+    }
+
+    /// Returns the node ids in descending degree order.
+    pub fn degree_order(&self) -> Vec<u64> {
+        let mut degree_node_pairs = (0..self.node_count())
+            .map(|node| (self.degree(node), node))
+            .collect::<Vec<_>>();
+        degree_node_pairs.sort_unstable_by(|left, right| left.cmp(right).reverse());
+        degree_node_pairs
+            .into_iter()
+            .map(|(_, node)| node)
+            .collect()
+    }
+
+    /// Returns the node ids in breadth-first order.
+    ///
+    /// If `reverse_cuthill_mckee` is set, each traversal starts at the node
+    /// with the smallest degree, neighbors are visited in ascending degree
+    /// order and the resulting order is reversed.
+    pub fn bfs_order(&self, reverse_cuthill_mckee: bool) -> Vec<u64> {
+        let node_count = self.node_count();
+        let mut starts = (0..node_count).collect::<Vec<_>>();
+        if reverse_cuthill_mckee {
+            starts.sort_by_key(|node| self.degree(*node));
+        }
+
+        let mut visited = vec![false; node_count as usize];
+        let mut order = Vec::with_capacity(node_count as usize);
+        let mut neighbors = Vec::new();
+
+        for start in starts {
+            if visited[start as usize] {
+                continue;
+            }
+            visited[start as usize] = true;
+            let mut next = order.len();
+            order.push(start);
+
+            while next < order.len() {
+                let node = order[next];
+                next += 1;
+
+                neighbors.clear();
+                self.for_each_neighbor(node, |neighbor| {
+                    if !visited[neighbor as usize] {
+                        visited[neighbor as usize] = true;
+                        neighbors.push(neighbor);
+                    }
+                });
+                if reverse_cuthill_mckee {
+                    neighbors.sort_by_key(|neighbor| self.degree(*neighbor));
+                }
+                order.extend_from_slice(&neighbors);
+            }
+        }
+
+        if reverse_cuthill_mckee {
+            order.reverse();
+        }
+        order
+    }
+
+    /// Relabels the nodes of the graph, node `u` becomes node `permutation[u]`.
+    pub fn relabel(&mut self, permutation: &[u64]) {
+        match self {
+            GraphType::Directed(g) => g.relabel(permutation),
+            GraphType::Undirected(g) => g.relabel(permutation),
+            GraphType::DirectedWeighted(g) => g.relabel(permutation),
+            GraphType::UndirectedWeighted(g) => g.relabel(permutation),
+        }
+    }
+
+    /// Creates the subgraph induced by the nodes that satisfy the predicate
+    /// and returns it together with the original ids of its nodes.
+    pub fn induced_subgraph<P>(&self, predicate: P) -> Result<(Self, Vec<u64>), Status>
+    where
+        P: Fn(u64) -> bool,
+    {
+        if !(0..self.node_count()).any(&predicate) {
+            return Err(Status::invalid_argument(
+                "The projection does not select any nodes",
+            ));
+        }
+
+        let subgraph = match self {
+            GraphType::Directed(g) => {
+                let (g, mapping) = g.induced_subgraph(predicate);
+                (GraphType::Directed(g), mapping)
+            }
+            GraphType::Undirected(g) => {
+                let (g, mapping) = g.induced_subgraph(predicate);
+                (GraphType::Undirected(g), mapping)
+            }
+            GraphType::DirectedWeighted(g) => {
+                let (g, mapping) = g.induced_subgraph(predicate);
+                (GraphType::DirectedWeighted(g), mapping)
+            }
+            GraphType::UndirectedWeighted(g) => {
+                let (g, mapping) = g.induced_subgraph(predicate);
+                (GraphType::UndirectedWeighted(g), mapping)
+            }
+        };
+        Ok(subgraph)
+    }
+
+    /// Estimates the number of bytes occupied by the CSR arrays of a graph
+    /// with the given number of nodes and edges.
+    pub fn estimate_size_in_bytes(
+        node_count: u64,
+        edge_count: u64,
+        orientation: Orientation,
+        weighted: bool,
+    ) -> u64 {
+        let target_size = if weighted {
+            std::mem::size_of::<Target<u64, f32>>()
+        } else {
+            std::mem::size_of::<Target<u64, ()>>()
+        } as u64;
+        // Saturates, so that huge node ids exceed any memory limit.
+        let offsets_size = node_count
+            .saturating_add(1)
+            .saturating_mul(std::mem::size_of::<u64>() as u64);
+        let targets_size = edge_count.saturating_mul(target_size);
+
+        // Directed graphs store each edge as outgoing and incoming edge,
+        // undirected graphs store each edge in both directions.
+        match orientation {
+            Orientation::Directed => offsets_size.saturating_add(targets_size).saturating_mul(2),
+            Orientation::Undirected => offsets_size.saturating_add(targets_size.saturating_mul(2)),
+        }
+    }
+
+    /// Estimates the number of bytes occupied by a graph loaded from the
+    /// given file without parsing it.
+    ///
+    /// Edge lists are assumed to contain one edge per line and at most as
+    /// many nodes as edges.
+    pub fn estimate_file_size_in_bytes<P: AsRef<Path>>(
+        path: P,
+        format: FileFormat,
+        orientation: Orientation,
+    ) -> Result<u64, Status> {
+        let path = path.as_ref();
+        let from_io_error =
+            |e: std::io::Error| Status::invalid_argument(format!("Could not read {path:?}: {e}"));
+
+        let (node_count, edge_count, weighted) = match format {
+            FileFormat::EdgeList | FileFormat::EdgeListWeighted => {
+                let mut reader = BufReader::new(File::open(path).map_err(from_io_error)?);
+                let mut line_count = 0;
+                loop {
+                    let buffer = reader.fill_buf().map_err(from_io_error)?;
+                    if buffer.is_empty() {
+                        break;
+                    }
+                    line_count += buffer.iter().filter(|b| **b == b'\n').count() as u64;
+                    let length = buffer.len();
+                    reader.consume(length);
+                }
+                let weighted = matches!(format, FileFormat::EdgeListWeighted);
+                (line_count, line_count, weighted)
+            }
+            FileFormat::Graph500 => {
+                // Graph500 files store each edge in 12 bytes and
+                // contain 16 edges per node.
+                let file_size = path.metadata().map_err(from_io_error)?.len();
+                let edge_count = file_size / 12;
+                (edge_count / 16, edge_count, false)
+            }
+        };
+
+        Ok(Self::estimate_size_in_bytes(
+            node_count,
+            edge_count,
+            orientation,
+            weighted,
+        ))
+    }
+}
+
+fn csr_size<T>(offsets: &[u64], targets: &[T]) -> usize {
+    std::mem::size_of_val(offsets) + std::mem::size_of_val(targets)
+}
+
+fn adjacency_layout<I: Iterator<Item = u64>>(node: u64, mut targets: I) -> &'static str {
+    let mut layout = "deduplicated";
+    let Some(mut previous) = targets.next() else {
+        return layout;
+    };
+    if previous == node {
+        layout = "sorted";
+    }
+    for target in targets {
+        if target < previous {
+            return "unsorted";
+        }
+        if target == previous || target == node {
+            layout = "sorted";
+        }
+        previous = target;
+    }
+    layout
 }
 
 fn from_graph_error(error: graph::prelude::Error) -> Status {
     Status::internal(format!("GraphError: {error:?}"))
 }
 
+fn from_gdl_error(error: graph::prelude::Error) -> Status {
+    Status::invalid_argument(format!("Invalid GDL: {error}"))
+}
+
+/// The namespace of graphs whose name is not qualified.
+pub const DEFAULT_NAMESPACE: &str = "default";
+
+/// Returns the namespace of a graph name of the form `namespace.graph_name`.
+pub fn namespace_of(graph_name: &str) -> &str {
+    graph_name
+        .split_once('.')
+        .map_or(DEFAULT_NAMESPACE, |(namespace, _)| namespace)
+}
+
+fn in_namespace(graph_name: &str, namespace: Option<&str>) -> bool {
+    namespace.map_or(true, |namespace| namespace_of(graph_name) == namespace)
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as u64)
+}
+
 pub struct GraphCatalog {
-    graphs: HashMap<String, GraphType>,
+    graphs: HashMap<String, CatalogEntry>,
+    snapshots: Option<SnapshotStore>,
+    // Maximum number of bytes occupied by loaded graphs, if any.
+    memory_limit: Option<u64>,
+    // Maximum number of bytes occupied by loaded graphs per namespace.
+    namespace_quotas: HashMap<String, u64>,
+    // Bytes reserved per namespace for graphs that are currently being
+    // created, which are released without locking the catalog.
+    reserved_bytes: Arc<Mutex<HashMap<String, u64>>>,
+    // Logical clock to determine the least recently used graph.
+    clock: AtomicU64,
+}
+
+struct CatalogEntry {
+    // Persisted graphs are loaded on first access.
+    graph: OnceLock<GraphType>,
+    // Only set for graphs that have been persisted in a previous run or
+    // that have been evicted from memory.
+    snapshot: Option<GraphInfo>,
+    // Value of the catalog clock at the last access.
+    last_access: AtomicU64,
+    // Milliseconds since the Unix epoch.
+    created_at: u64,
+}
+
+impl CatalogEntry {
+    fn loaded(graph: GraphType, last_access: u64) -> Self {
+        Self {
+            graph: OnceLock::from(graph),
+            snapshot: None,
+            last_access: AtomicU64::new(last_access),
+            created_at: now_millis(),
+        }
+    }
+
+    fn details(&self, graph_name: &str, properties: Vec<PropertyInfo>) -> GraphDetails {
+        let info = self.info(graph_name);
+        match self.graph.get() {
+            Some(graph) => GraphDetails::new(
+                info,
+                Some(graph.csr_layout()),
+                true,
+                graph.size_in_bytes(),
+                self.created_at,
+                properties,
+            ),
+            None => {
+                let size_in_bytes = GraphType::estimate_size_in_bytes(
+                    info.node_count(),
+                    info.edge_count(),
+                    info.orientation(),
+                    info.is_weighted(),
+                );
+                GraphDetails::new(
+                    info,
+                    None,
+                    false,
+                    size_in_bytes,
+                    self.created_at,
+                    properties,
+                )
+            }
+        }
+    }
+
+    fn info(&self, graph_name: &str) -> GraphInfo {
+        match (self.graph.get(), &self.snapshot) {
+            (Some(graph_type), _) => GraphInfo::new(
+                graph_name.to_string(),
+                graph_type.to_string(),
+                graph_type.node_count(),
+                graph_type.edge_count(),
+            ),
+            (None, Some(info)) => info.clone(),
+            (None, None) => unreachable!("graphs are either loaded or persisted"),
+        }
+    }
+}
+
+/// Memory reserved for a graph that is being created.
+///
+/// The reservation is released when the guard is dropped, i.e., after the
+/// graph has been inserted into the catalog or its creation failed.
+pub struct MemoryReservation {
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    reserved_bytes: Arc<Mutex<HashMap<String, u64>>>,
+    namespace: String,
+    bytes: u64,
+}
+
+impl MemoryReservation {
+    /// Inserts the graph for which memory has been reserved into the catalog
+    /// and releases the reservation.
+    ///
+    /// Inserting waits for running computations to release the catalog, so
+    /// it runs on the blocking thread pool.
+    pub async fn insert(self, graph_name: String, graph: GraphType) -> Result<(), Status> {
+        tokio::task::spawn_blocking(move || {
+            let result = self.graph_catalog.write().insert(graph_name, graph);
+            drop(self);
+            result
+        })
+        .await
+        .map_err(from_join_error)?
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        if let Some(reserved) = self.reserved_bytes.lock().get_mut(&self.namespace) {
+            *reserved -= self.bytes;
+        }
+    }
 }
 
 impl GraphCatalog {
     pub fn new() -> Self {
         Self {
             graphs: HashMap::new(),
+            snapshots: None,
+            memory_limit: None,
+            namespace_quotas: HashMap::new(),
+            reserved_bytes: Arc::new(Mutex::new(HashMap::new())),
+            clock: AtomicU64::new(0),
         }
     }
 
+    /// Creates a catalog that persists its graphs in the given snapshot store.
+    ///
+    /// Previously persisted graphs are registered, but only loaded on first
+    /// access.
+    pub fn with_snapshots(snapshots: SnapshotStore) -> Result<Self, Status> {
+        let graphs = snapshots
+            .graph_infos()?
+            .into_iter()
+            .map(|info| {
+                let entry = CatalogEntry {
+                    graph: OnceLock::new(),
+                    created_at: snapshots
+                        .created_at(info.graph_name())
+                        .unwrap_or_else(now_millis),
+                    snapshot: Some(info.clone()),
+                    last_access: AtomicU64::new(0),
+                };
+                (info.graph_name().to_string(), entry)
+            })
+            .collect::<HashMap<_, _>>();
+
+        info!("Found {} persisted graphs", graphs.len());
+
+        Ok(Self {
+            graphs,
+            snapshots: Some(snapshots),
+            ..Self::new()
+        })
+    }
+
+    /// Limits the number of bytes occupied by loaded graphs, see
+    /// [`GraphCatalog::reserve`].
+    pub fn set_memory_limit(&mut self, memory_limit: Option<u64>) {
+        self.memory_limit = memory_limit;
+    }
+
+    /// Limits the number of bytes occupied by loaded graphs of the given
+    /// namespace, see [`GraphCatalog::reserve`].
+    pub fn set_namespace_quota(&mut self, namespace: String, quota: u64) {
+        self.namespace_quotas.insert(namespace, quota);
+    }
+
     pub fn get<K: AsRef<str>>(&self, graph_name: K) -> Result<&GraphType, Status> {
-        self.graphs
+        let entry = self
+            .graphs
             .get(graph_name.as_ref())
+            .ok_or_else(|| GraphCatalog::graph_not_found(&graph_name))?;
+        entry.last_access.store(
+            self.clock.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+
+        // Loading a graph requires making room for it, see `read_loaded`.
+        entry.graph.get().ok_or_else(|| {
+            Status::internal(format!(
+                "Graph with name '{}' is not loaded",
+                graph_name.as_ref()
+            ))
+        })
+    }
+
+    /// Locks the catalog for reading after making sure that the graph is
+    /// loaded.
+    ///
+    /// Graphs that have been persisted in a previous run or evicted from
+    /// memory are loaded under the write lock, which makes room for them like
+    /// [`GraphCatalog::reserve`]. This waits for running computations to
+    /// release the catalog, so it must be called on the blocking thread pool.
+    pub fn read_loaded<'a>(
+        graph_catalog: &'a RwLock<Self>,
+        graph_name: &str,
+    ) -> Result<RwLockReadGuard<'a, Self>, Status> {
+        let catalog = graph_catalog.read();
+        if catalog.is_loaded(graph_name)? {
+            return Ok(catalog);
+        }
+        drop(catalog);
+
+        let mut catalog = graph_catalog.write();
+        catalog.load(graph_name)?;
+        Ok(RwLockWriteGuard::downgrade(catalog))
+    }
+
+    fn is_loaded(&self, graph_name: &str) -> Result<bool, Status> {
+        self.graphs
+            .get(graph_name)
+            .map(|entry| entry.graph.get().is_some())
             .ok_or_else(|| GraphCatalog::graph_not_found(graph_name))
     }
 
+    /// Loads the graph from the snapshot store, if it is not loaded yet, after
+    /// evicting other graphs if needed.
+    fn load(&mut self, graph_name: &str) -> Result<(), Status> {
+        if self.is_loaded(graph_name)? {
+            return Ok(());
+        }
+        let (Some(snapshots), Some(info)) = (
+            self.snapshots.clone(),
+            self.graphs[graph_name].snapshot.clone(),
+        ) else {
+            return Err(GraphCatalog::graph_not_found(graph_name));
+        };
+
+        let bytes = GraphType::estimate_size_in_bytes(
+            info.node_count(),
+            info.edge_count(),
+            info.orientation(),
+            info.is_weighted(),
+        );
+        self.make_room_for(graph_name, bytes)?;
+
+        let graph = snapshots.read_graph(&info)?;
+        let entry = &self.graphs[graph_name];
+        entry.last_access.store(
+            self.clock.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+        let _ = entry.graph.set(graph);
+        Ok(())
+    }
+
     pub fn get_mut<K: AsRef<str>>(&mut self, graph_name: K) -> Result<&mut GraphType, Status> {
+        self.load(graph_name.as_ref())?;
+
         self.graphs
             .get_mut(graph_name.as_ref())
+            .and_then(|entry| entry.graph.get_mut())
             .ok_or_else(|| GraphCatalog::graph_not_found(graph_name))
     }
 
-    pub fn insert<K: Into<String>>(&mut self, graph_name: K, graph: GraphType) {
-        self.graphs.insert(graph_name.into(), graph);
+    pub fn insert<K: Into<String>>(
+        &mut self,
+        graph_name: K,
+        graph: GraphType,
+    ) -> Result<(), Status> {
+        let graph_name = graph_name.into();
+        if let Some(snapshots) = &self.snapshots {
+            snapshots.write_graph(&graph_name, &graph)?;
+        }
+        let last_access = self.clock.fetch_add(1, Ordering::Relaxed);
+        self.graphs
+            .insert(graph_name, CatalogEntry::loaded(graph, last_access));
+        Ok(())
     }
 
-    pub fn list(&self) -> Vec<GraphInfo> {
+    pub fn graph_count(&self) -> usize {
+        self.graphs.len()
+    }
+
+    /// Returns the number of bytes occupied by all loaded graphs.
+    pub fn used_bytes(&self) -> u64 {
         self.graphs
+            .values()
+            .filter_map(|entry| entry.graph.get())
+            .map(GraphType::size_in_bytes)
+            .sum()
+    }
+
+    /// Returns the number of bytes occupied and reserved by the graphs of
+    /// the given namespace or of all namespaces.
+    fn claimed_bytes(&self, namespace: Option<&str>) -> u64 {
+        let used = self
+            .graphs
             .iter()
-            .map(|(graph_name, graph_type)| {
-                let graph_name = graph_name.clone();
-                let (node_count, edge_count) = match graph_type {
-                    GraphType::Directed(g) => (g.node_count(), g.edge_count()),
-                    GraphType::Undirected(g) => (g.node_count(), g.edge_count()),
-                    GraphType::DirectedWeighted(g) => (g.node_count(), g.edge_count()),
-                    GraphType::UndirectedWeighted(g) => (g.node_count(), g.edge_count()),
-                };
-                GraphInfo::new(graph_name, graph_type.to_string(), node_count, edge_count)
+            .filter(|(graph_name, _)| in_namespace(graph_name, namespace))
+            .filter_map(|(_, entry)| entry.graph.get())
+            .map(GraphType::size_in_bytes)
+            .sum::<u64>();
+        let reserved = self
+            .reserved_bytes
+            .lock()
+            .iter()
+            .filter(|(ns, _)| namespace.map_or(true, |namespace| namespace == ns.as_str()))
+            .map(|(_, bytes)| bytes)
+            .sum::<u64>();
+        used + reserved
+    }
+
+    /// Reserves memory for a graph that is about to be created.
+    ///
+    /// If the reservation would exceed the quota of the graph's namespace or
+    /// the memory limit, the least recently used graphs of the namespace or
+    /// of all namespaces, respectively, are evicted from memory until it fits.
+    /// Graphs that are used by running computations are not evicted, since
+    /// eviction requires the write lock of the catalog, which is acquired on
+    /// the blocking thread pool. Evicted graphs are reloaded from the snapshot
+    /// store on their next access, so without a snapshot store, no graphs are
+    /// evicted. If not enough memory can be freed, the reservation is rejected
+    /// with `resource_exhausted`.
+    pub async fn reserve(
+        graph_catalog: &Arc<RwLock<Self>>,
+        graph_name: &str,
+        bytes: u64,
+    ) -> Result<MemoryReservation, Status> {
+        let graph_catalog = Arc::clone(graph_catalog);
+        let namespace = namespace_of(graph_name).to_string();
+        tokio::task::spawn_blocking(move || {
+            let mut catalog = graph_catalog.write();
+            catalog.make_room_for(&namespace, bytes)?;
+            *catalog
+                .reserved_bytes
+                .lock()
+                .entry(namespace.clone())
+                .or_default() += bytes;
+            let reserved_bytes = Arc::clone(&catalog.reserved_bytes);
+            drop(catalog);
+
+            Ok(MemoryReservation {
+                graph_catalog,
+                reserved_bytes,
+                namespace,
+                bytes,
             })
+        })
+        .await
+        .map_err(from_join_error)?
+    }
+
+    /// Evicts graphs until the given number of bytes fits into the quota of
+    /// the namespace of the graph and into the memory limit.
+    fn make_room_for(&mut self, graph_name: &str, bytes: u64) -> Result<(), Status> {
+        let namespace = namespace_of(graph_name);
+        if let Some(quota) = self.namespace_quotas.get(namespace).copied() {
+            self.make_room(Some(namespace), bytes, quota)?;
+        }
+        if let Some(memory_limit) = self.memory_limit {
+            self.make_room(None, bytes, memory_limit)?;
+        }
+        Ok(())
+    }
+
+    fn make_room(&mut self, namespace: Option<&str>, bytes: u64, limit: u64) -> Result<(), Status> {
+        while self.claimed_bytes(namespace) + bytes > limit {
+            if !self.evict_least_recently_used(namespace) {
+                let available = limit.saturating_sub(self.claimed_bytes(namespace));
+                let scope = match namespace {
+                    Some(namespace) => format!("in namespace '{namespace}'"),
+                    None => "on the server".to_string(),
+                };
+                let hint = if self.snapshots.is_none() {
+                    ", graphs can only be evicted from memory if the server has a data directory"
+                } else {
+                    ""
+                };
+                return Err(Status::resource_exhausted(format!(
+                    "Graph requires an estimated {bytes} bytes, but only {available} of {limit} bytes are available {scope}{hint}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Unloads the least recently used graph of the given namespace or of all
+    /// namespaces and returns whether a graph was unloaded. Graphs are only
+    /// unloaded if they can be reloaded from the snapshot store.
+    fn evict_least_recently_used(&mut self, namespace: Option<&str>) -> bool {
+        if self.snapshots.is_none() {
+            return false;
+        }
+
+        let Some((graph_name, entry)) = self
+            .graphs
+            .iter_mut()
+            .filter(|(graph_name, _)| in_namespace(graph_name, namespace))
+            .filter(|(_, entry)| entry.graph.get().is_some())
+            .min_by_key(|(_, entry)| entry.last_access.load(Ordering::Relaxed))
+        else {
+            return false;
+        };
+
+        let graph = entry.graph.take().expect("graph is loaded");
+        entry.snapshot = Some(GraphInfo::new(
+            graph_name.clone(),
+            graph.to_string(),
+            graph.node_count(),
+            graph.edge_count(),
+        ));
+        info!(
+            "Evicted graph '{graph_name}' from memory, freeing {} bytes",
+            graph.size_in_bytes()
+        );
+        true
+    }
+
+    /// Writes the current state of the graph to the snapshot store, e.g.,
+    /// after it has been modified in place.
+    pub fn persist<K: AsRef<str>>(&self, graph_name: K) -> Result<(), Status> {
+        match &self.snapshots {
+            Some(snapshots) => snapshots.write_graph(graph_name.as_ref(), self.get(&graph_name)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Lists the graphs of the given namespace or of all namespaces.
+    pub fn list(&self, namespace: Option<&str>) -> Vec<GraphInfo> {
+        self.graphs
+            .iter()
+            .filter(|(graph_name, _)| in_namespace(graph_name, namespace))
+            .map(|(graph_name, entry)| entry.info(graph_name))
             .collect::<Vec<_>>()
     }
 
-    pub fn remove<K: AsRef<str>>(&mut self, graph_name: K) -> Result<GraphInfo, Status> {
+    /// Returns the details of the graphs in the given namespace or of all
+    /// graphs, without loading them.
+    pub fn details(
+        &self,
+        namespace: Option<&str>,
+        mut properties: HashMap<String, Vec<PropertyInfo>>,
+    ) -> Vec<GraphDetails> {
+        self.graphs
+            .iter()
+            .filter(|(graph_name, _)| in_namespace(graph_name, namespace))
+            .map(|(graph_name, entry)| {
+                entry.details(
+                    graph_name,
+                    properties.remove(graph_name).unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the info of the graph without loading it.
+    pub fn info<K: AsRef<str>>(&self, graph_name: K) -> Result<GraphInfo, Status> {
+        let entry = self
+            .graphs
+            .get(graph_name.as_ref())
+            .ok_or_else(|| GraphCatalog::graph_not_found(&graph_name))?;
+        Ok(entry.info(graph_name.as_ref()))
+    }
+
+    /// Returns the names of all graphs in the given namespace.
+    pub fn graph_names(&self, namespace: &str) -> Vec<String> {
+        self.graphs
+            .keys()
+            .filter(|graph_name| namespace_of(graph_name) == namespace)
+            .cloned()
+            .collect()
+    }
+
+    /// Removes the graph and returns its info and the number of bytes it
+    /// occupied in memory.
+    pub fn remove<K: AsRef<str>>(&mut self, graph_name: K) -> Result<(GraphInfo, u64), Status> {
         let graph_name = graph_name.as_ref();
-        self.graphs.remove(graph_name).map_or_else(
-            || Err(GraphCatalog::graph_not_found(graph_name)),
-            |g| {
-                Ok(GraphInfo::new(
+        let entry = self
+            .graphs
+            .remove(graph_name)
+            .ok_or_else(|| GraphCatalog::graph_not_found(graph_name))?;
+
+        if let Some(snapshots) = &self.snapshots {
+            snapshots.remove_graph(graph_name)?;
+        }
+
+        match (entry.graph.into_inner(), entry.snapshot) {
+            (Some(g), _) => {
+                let graph_info = GraphInfo::new(
                     graph_name.to_string(),
                     g.to_string(),
                     g.node_count(),
                     g.edge_count(),
-                ))
-            },
-        )
+                );
+                Ok((graph_info, g.size_in_bytes()))
+            }
+            (None, Some(info)) => Ok((info, 0)),
+            (None, None) => unreachable!("graphs are either loaded or persisted"),
+        }
     }
 
     fn graph_not_found<K: AsRef<str>>(graph_name: K) -> Status {
@@ -226,14 +1083,119 @@ impl PropertyId {
     }
 }
 
-impl TryFrom<Ticket> for PropertyId {
+/// The ticket of a `do_get` request, which either selects a stored property,
+/// a streamed algorithm result or the edges of a graph.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum GetTicket {
+    Stream(StreamTicket),
+    Property(PropertyTicket),
+    Topology(TopologyTicket),
+}
+
+impl TryFrom<Ticket> for GetTicket {
     type Error = Status;
 
     fn try_from(ticket: Ticket) -> Result<Self, Self::Error> {
-        serde_json::from_slice::<PropertyId>(&ticket.ticket).map_err(from_json_error)
+        serde_json::from_slice::<GetTicket>(&ticket.ticket).map_err(from_json_error)
+    }
+}
+
+/// A ticket that selects an algorithm result computed in `stream` mode.
+///
+/// ```json
+/// { "stream_id": 42 }
+/// ```
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StreamTicket {
+    pub stream_id: u64,
+}
+
+/// A ticket that selects the edges of a graph, see [`GraphType::edges`].
+///
+/// ```json
+/// { "graph_name": "g" }
+/// ```
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TopologyTicket {
+    pub graph_name: String,
+}
+
+/// A ticket that selects a property and optionally filters its rows.
+///
+/// Without a node range or filter, the property is streamed as is. Otherwise,
+/// only matching rows are streamed and each row is prefixed with its node id.
+///
+/// ```json
+/// {
+///     "graph_name": "g",
+///     "property_key": "page_rank",
+///     "node_range": { "start": 0, "end": 1000 },
+///     "filter": { "op": ">", "value": 0.5 }
+/// }
+/// ```
+#[derive(Deserialize, Debug)]
+pub struct PropertyTicket {
+    #[serde(flatten)]
+    pub property_id: PropertyId,
+    #[serde(default)]
+    pub node_range: Option<NodeRange>,
+    #[serde(default)]
+    pub filter: Option<PropertyFilter>,
+}
+
+impl PropertyTicket {
+    fn is_selective(&self) -> bool {
+        self.node_range.is_some() || self.filter.is_some()
+    }
+}
+
+/// A half-open range of node ids, `end` defaults to the node count.
+#[derive(Deserialize, Debug)]
+pub struct NodeRange {
+    #[serde(default)]
+    pub start: u64,
+    #[serde(default)]
+    pub end: Option<u64>,
+}
+
+/// Compares property values against a constant, e.g., `score > 0.5`.
+#[derive(Deserialize, Debug)]
+pub struct PropertyFilter {
+    pub op: Comparison,
+    pub value: f64,
+}
+
+#[derive(Deserialize, Debug)]
+pub enum Comparison {
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Ge,
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "!=")]
+    Ne,
+}
+
+impl PropertyFilter {
+    pub fn matches(&self, value: f64) -> bool {
+        match self.op {
+            Comparison::Lt => value < self.value,
+            Comparison::Le => value <= self.value,
+            Comparison::Gt => value > self.value,
+            Comparison::Ge => value >= self.value,
+            Comparison::Eq => value == self.value,
+            Comparison::Ne => value != self.value,
+        }
     }
 }
 
+#[derive(Clone)]
 pub struct PropertyEntry {
     pub schema: Arc<Schema>,
     pub batches: Vec<RecordBatch>,
@@ -243,28 +1205,283 @@ impl PropertyEntry {
     pub fn new(schema: Arc<Schema>, batches: Vec<RecordBatch>) -> Self {
         Self { schema, batches }
     }
+
+    /// Returns the rows selected by the given ticket.
+    ///
+    /// If the ticket selects a node range or filter, the returned entry
+    /// contains a `node_id` column followed by the property column.
+    pub fn select(&self, ticket: &PropertyTicket) -> Result<Cow<'_, PropertyEntry>, ArrowError> {
+        if !ticket.is_selective() {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        let mut fields = vec![Field::new("node_id", DataType::UInt64, false)];
+        fields.extend(self.schema.fields().iter().map(|f| f.as_ref().clone()));
+        let schema = Arc::new(Schema::new(fields));
+
+        let (start, end) = match &ticket.node_range {
+            Some(range) => (range.start, range.end.unwrap_or(u64::MAX)),
+            None => (0, u64::MAX),
+        };
+
+        let mut batches = Vec::new();
+        let mut offset = 0;
+
+        for batch in &self.batches {
+            let len = batch.num_rows() as u64;
+            let batch_start = u64::max(start, offset);
+            let batch_end = u64::min(end, offset + len);
+
+            if batch_start < batch_end {
+                let node_ids = UInt64Array::from_iter_values(batch_start..batch_end);
+                let mut columns: Vec<ArrayRef> = vec![Arc::new(node_ids)];
+                columns.extend(
+                    batch
+                        .slice(
+                            (batch_start - offset) as usize,
+                            (batch_end - batch_start) as usize,
+                        )
+                        .columns()
+                        .iter()
+                        .cloned(),
+                );
+                let mut batch = RecordBatch::try_new(schema.clone(), columns)?;
+
+                if let Some(filter) = &ticket.filter {
+                    let values = cast(batch.column(1), &DataType::Float64)?;
+                    let mask = as_primitive_array::<Float64Type>(&values)
+                        .iter()
+                        .map(|value| value.map(|value| filter.matches(value)))
+                        .collect::<BooleanArray>();
+                    batch = filter_record_batch(&batch, &mask)?;
+                }
+
+                if batch.num_rows() > 0 {
+                    batches.push(batch);
+                }
+            }
+
+            offset += len;
+        }
+
+        Ok(Cow::Owned(PropertyEntry::new(schema, batches)))
+    }
+
+    /// Returns for each node whether its property value matches the filter.
+    ///
+    /// Nodes without a value never match.
+    pub fn matching_nodes(&self, filter: &PropertyFilter) -> Result<Vec<bool>, ArrowError> {
+        let mut matches = Vec::new();
+        for batch in &self.batches {
+            let values = cast(batch.column(0), &DataType::Float64)?;
+            matches.extend(
+                as_primitive_array::<Float64Type>(&values)
+                    .iter()
+                    .map(|value| value.map_or(false, |value| filter.matches(value))),
+            );
+        }
+        Ok(matches)
+    }
+
+    /// Returns the number of distinct non-null values of the property.
+    pub fn distinct_count(&self) -> Result<u64, ArrowError> {
+        let mut values = HashSet::new();
+        for batch in &self.batches {
+            let column = cast(batch.column(0), &DataType::UInt64)?;
+            values.extend(as_primitive_array::<UInt64Type>(&column).iter().flatten());
+        }
+        Ok(values.len() as u64)
+    }
+
+    /// Returns the number of bytes occupied by the record batches.
+    pub fn size_in_bytes(&self) -> u64 {
+        self.batches
+            .iter()
+            .map(|batch| batch.get_array_memory_size() as u64)
+            .sum()
+    }
 }
 
 pub struct PropertyStore {
     properties: HashMap<PropertyId, PropertyEntry>,
+    snapshots: Option<SnapshotStore>,
+    // Algorithm results that are streamed once and never persisted.
+    streams: HashMap<u64, PropertyEntry>,
+    next_stream_id: u64,
 }
 
 impl PropertyStore {
     pub fn new() -> Self {
         Self {
             properties: HashMap::new(),
+            snapshots: None,
+            streams: HashMap::new(),
+            next_stream_id: 0,
         }
     }
 
+    /// Creates a property store that persists its properties in the given
+    /// snapshot store and loads all previously persisted properties.
+    pub fn with_snapshots(snapshots: SnapshotStore) -> Result<Self, Status> {
+        let properties = snapshots
+            .read_properties()?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        info!("Loaded {} persisted properties", properties.len());
+
+        Ok(Self {
+            properties,
+            snapshots: Some(snapshots),
+            ..Self::new()
+        })
+    }
+
     pub fn get(&self, property_id: &PropertyId) -> Result<&PropertyEntry, Status> {
         self.properties
             .get(property_id)
             .ok_or_else(|| Status::not_found(format!("Property Id not found: {property_id:?}")))
     }
 
-    pub fn insert(&mut self, property_id: PropertyId, entry: PropertyEntry) {
+    pub fn insert(&mut self, property_id: PropertyId, entry: PropertyEntry) -> Result<(), Status> {
+        if let Some(snapshots) = &self.snapshots {
+            snapshots.write_property(&property_id, &entry)?;
+        }
         self.properties.insert(property_id, entry);
+        Ok(())
+    }
+
+    /// Keeps an algorithm result until it is fetched via the returned ticket.
+    pub fn insert_stream(&mut self, entry: PropertyEntry) -> StreamTicket {
+        let stream_id = self.next_stream_id;
+        self.next_stream_id += 1;
+        self.streams.insert(stream_id, entry);
+        StreamTicket { stream_id }
+    }
+
+    pub fn take_stream(&mut self, stream_id: u64) -> Result<PropertyEntry, Status> {
+        self.streams.remove(&stream_id).ok_or_else(|| {
+            Status::not_found(format!(
+                "Stream with id '{stream_id}' not found, it may have been fetched already"
+            ))
+        })
+    }
+
+    /// Returns the ids of all properties of graphs in the given namespace or
+    /// of all graphs.
+    pub fn property_ids(&self, namespace: Option<&str>) -> Vec<PropertyId> {
+        self.properties
+            .keys()
+            .filter(|property_id| in_namespace(&property_id.graph_name, namespace))
+            .cloned()
+            .collect()
     }
+
+    /// Returns the key and data type of all properties of the graphs in the
+    /// given namespace or of all graphs, grouped by graph name.
+    pub fn property_infos(&self, namespace: Option<&str>) -> HashMap<String, Vec<PropertyInfo>> {
+        let mut infos = HashMap::<String, Vec<PropertyInfo>>::new();
+        for (property_id, entry) in &self.properties {
+            if !in_namespace(&property_id.graph_name, namespace) {
+                continue;
+            }
+            let data_type = entry
+                .schema
+                .fields()
+                .last()
+                .map_or_else(String::new, |field| field.data_type().to_string());
+            infos
+                .entry(property_id.graph_name.clone())
+                .or_default()
+                .push(PropertyInfo::new(
+                    property_id.property_key.clone(),
+                    data_type,
+                ));
+        }
+        for properties in infos.values_mut() {
+            properties.sort_by(|left, right| left.property_key().cmp(right.property_key()));
+        }
+        infos
+    }
+
+    pub fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
+    /// Returns the number of bytes occupied by all properties.
+    pub fn used_bytes(&self) -> u64 {
+        self.properties
+            .values()
+            .map(PropertyEntry::size_in_bytes)
+            .sum()
+    }
+
+    /// Removes all properties of the given graph and returns the number of
+    /// removed properties and the number of bytes they occupied.
+    pub fn remove_graph(&mut self, graph_name: &str) -> (usize, u64) {
+        let mut removed = 0;
+        let mut bytes = 0;
+        self.properties.retain(|property_id, entry| {
+            if property_id.graph_name == graph_name {
+                removed += 1;
+                bytes += entry.size_in_bytes();
+                false
+            } else {
+                true
+            }
+        });
+        (removed, bytes)
+    }
+}
+
+/// Edge values that are returned as `weight` column.
+trait WeightColumn: Sized {
+    fn column(values: Vec<Self>) -> Option<ArrayRef>;
+}
+
+impl WeightColumn for () {
+    fn column(_values: Vec<Self>) -> Option<ArrayRef> {
+        None
+    }
+}
+
+impl WeightColumn for f32 {
+    fn column(values: Vec<Self>) -> Option<ArrayRef> {
+        Some(Arc::new(Float32Array::from(values)))
+    }
+}
+
+fn edge_batches<G, EV>(graph: &G, schema: &Arc<Schema>) -> Result<Vec<RecordBatch>, ArrowError>
+where
+    G: OutputGraph<u64, (), EV>,
+    EV: WeightColumn + Copy,
+{
+    let edge_count = graph.edge_count() as usize;
+    let mut sources = Vec::with_capacity(edge_count);
+    let mut targets = Vec::with_capacity(edge_count);
+    let mut weights = Vec::with_capacity(edge_count);
+
+    graph.for_each_edge(|source, target, weight| {
+        sources.push(source);
+        targets.push(target);
+        weights.push(*weight);
+    });
+
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt64Array::from(sources)),
+        Arc::new(UInt64Array::from(targets)),
+    ];
+    columns.extend(EV::column(weights));
+    let batch = RecordBatch::try_new(Arc::clone(schema), columns)?;
+
+    let batches = (0..batch.num_rows())
+        .step_by(crate::server::CHUNK_SIZE)
+        .map(|offset| {
+            let len = usize::min(crate::server::CHUNK_SIZE, batch.num_rows() - offset);
+            batch.slice(offset, len)
+        })
+        .collect();
+    Ok(batches)
 }
 
 pub async fn to_record_batches<T: arrow::datatypes::ArrowPrimitiveType>(
@@ -286,3 +1503,206 @@ pub async fn to_record_batches<T: arrow::datatypes::ArrowPrimitiveType>(
 
     PropertyEntry::new(schema, batches)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use arrow::datatypes::Float32Type;
+    use tonic::Code;
+
+    use super::*;
+
+    fn triangle() -> GraphType {
+        GraphType::from_edge_list(
+            vec![(0, 1), (1, 2), (2, 0)],
+            Orientation::Directed,
+            CsrLayout::Sorted,
+        )
+    }
+
+    /// Persists a graph named `g` and returns the path of its snapshot file.
+    fn persist_graph(data_dir: &Path) -> PathBuf {
+        SnapshotStore::new(data_dir)
+            .unwrap()
+            .write_graph("g", &triangle())
+            .unwrap();
+        // The hex-encoded graph name, see the layout in `persistence`.
+        data_dir.join("graphs").join("67").join("graph.bin")
+    }
+
+    #[test]
+    fn truncated_snapshot_fails_to_load() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let graph_file = persist_graph(data_dir.path());
+        let bytes = std::fs::read(&graph_file).unwrap();
+        std::fs::write(&graph_file, &bytes[..bytes.len() / 2]).unwrap();
+
+        let catalog = RwLock::new(
+            GraphCatalog::with_snapshots(SnapshotStore::new(data_dir.path()).unwrap()).unwrap(),
+        );
+
+        let error = GraphCatalog::read_loaded(&catalog, "g")
+            .err()
+            .expect("graph must not be loaded");
+        assert_eq!(error.code(), Code::Internal);
+        // The graph stays registered and fails again on the next access.
+        assert_eq!(
+            GraphCatalog::read_loaded(&catalog, "g")
+                .err()
+                .map(|e| e.code()),
+            Some(Code::Internal)
+        );
+        assert_eq!(catalog.read().info("g").unwrap().node_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn memory_limit_without_snapshots_is_enforced() {
+        let mut catalog = GraphCatalog::new();
+        catalog.set_memory_limit(Some(triangle().size_in_bytes()));
+        catalog.insert("g", triangle()).unwrap();
+        let catalog = Arc::new(RwLock::new(catalog));
+
+        let error = GraphCatalog::reserve(&catalog, "h", 1)
+            .await
+            .err()
+            .expect("reservation must be rejected");
+        assert_eq!(error.code(), Code::ResourceExhausted);
+        // The graph cannot be reloaded, so it must not be evicted.
+        assert!(catalog.read().get("g").is_ok());
+        assert_eq!(catalog.read().used_bytes(), triangle().size_in_bytes());
+    }
+
+    #[test]
+    fn missing_snapshot_is_not_found() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let graph_file = persist_graph(data_dir.path());
+        std::fs::remove_file(graph_file).unwrap();
+
+        let catalog = RwLock::new(
+            GraphCatalog::with_snapshots(SnapshotStore::new(data_dir.path()).unwrap()).unwrap(),
+        );
+
+        let error = GraphCatalog::read_loaded(&catalog, "g")
+            .err()
+            .expect("graph must not be loaded");
+        assert_eq!(error.code(), Code::NotFound);
+    }
+
+    #[test]
+    fn reload_respects_memory_limit() {
+        let data_dir = tempfile::tempdir().unwrap();
+        persist_graph(data_dir.path());
+
+        let mut catalog =
+            GraphCatalog::with_snapshots(SnapshotStore::new(data_dir.path()).unwrap()).unwrap();
+        catalog.set_memory_limit(Some(triangle().size_in_bytes() - 1));
+        let catalog = RwLock::new(catalog);
+
+        let error = GraphCatalog::read_loaded(&catalog, "g")
+            .err()
+            .expect("graph must not be reloaded");
+        assert_eq!(error.code(), Code::ResourceExhausted);
+        assert_eq!(catalog.read().used_bytes(), 0);
+    }
+
+    #[test]
+    fn reload_evicts_least_recently_used_graph() {
+        let data_dir = tempfile::tempdir().unwrap();
+        persist_graph(data_dir.path());
+
+        let mut catalog =
+            GraphCatalog::with_snapshots(SnapshotStore::new(data_dir.path()).unwrap()).unwrap();
+        catalog.set_memory_limit(Some(triangle().size_in_bytes()));
+        catalog.insert("h", triangle()).unwrap();
+        let catalog = RwLock::new(catalog);
+
+        let loaded = GraphCatalog::read_loaded(&catalog, "g").unwrap();
+        assert_eq!(loaded.get("g").unwrap().node_count(), 3);
+        assert_eq!(loaded.used_bytes(), triangle().size_in_bytes());
+        assert!(loaded.get("h").is_err());
+    }
+
+    /// A nullable `rank` property of 4 nodes in two batches.
+    fn rank_entry() -> PropertyEntry {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "rank",
+            DataType::Float32,
+            true,
+        )]));
+        let batches = [vec![Some(0.1), Some(0.9)], vec![Some(0.6), None]]
+            .into_iter()
+            .map(|values| {
+                let values: ArrayRef = Arc::new(Float32Array::from(values));
+                RecordBatch::try_new(schema.clone(), vec![values]).unwrap()
+            })
+            .collect();
+        PropertyEntry::new(schema, batches)
+    }
+
+    fn property_ticket(json: &str) -> PropertyTicket {
+        serde_json::from_str(json).unwrap()
+    }
+
+    /// Returns the selected node ids and values.
+    fn rows(entry: &PropertyEntry) -> Vec<(u64, Option<f32>)> {
+        entry
+            .batches
+            .iter()
+            .flat_map(|batch| {
+                let node_ids = as_primitive_array::<UInt64Type>(batch.column(0));
+                let values = as_primitive_array::<Float32Type>(batch.column(1));
+                node_ids.values().iter().copied().zip(values.iter())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn select_existing_property() {
+        let mut store = PropertyStore::new();
+        let property_id = PropertyId::new("g".into(), "rank".into());
+        store.insert(property_id, rank_entry()).unwrap();
+
+        let ticket = property_ticket(r#"{"graph_name": "g", "property_key": "rank"}"#);
+        let entry = store.get(&ticket.property_id).unwrap();
+        assert!(matches!(entry.select(&ticket).unwrap(), Cow::Borrowed(_)));
+
+        let ticket = property_ticket(
+            r#"{"graph_name": "g", "property_key": "rank", "node_range": {"start": 1}}"#,
+        );
+        let selected = entry.select(&ticket).unwrap();
+        assert_eq!(selected.schema.field(0).name(), "node_id");
+        assert_eq!(
+            rows(&selected),
+            vec![(1, Some(0.9)), (2, Some(0.6)), (3, None)]
+        );
+
+        // Nodes without a value never match the filter.
+        let ticket = property_ticket(
+            r#"{"graph_name": "g", "property_key": "rank",
+                "node_range": {"start": 0, "end": 3}, "filter": {"op": ">", "value": 0.5}}"#,
+        );
+        let selected = entry.select(&ticket).unwrap();
+        assert_eq!(rows(&selected), vec![(1, Some(0.9)), (2, Some(0.6))]);
+
+        let ticket = property_ticket(
+            r#"{"graph_name": "g", "property_key": "rank", "node_range": {"start": 4}}"#,
+        );
+        assert!(rows(&entry.select(&ticket).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn select_missing_property() {
+        let mut store = PropertyStore::new();
+        let property_id = PropertyId::new("g".into(), "rank".into());
+        store.insert(property_id, rank_entry()).unwrap();
+
+        for json in [
+            r#"{"graph_name": "g", "property_key": "score", "node_range": {"start": 1}}"#,
+            r#"{"graph_name": "h", "property_key": "rank"}"#,
+        ] {
+            let error = store.get(&property_ticket(json).property_id).err().unwrap();
+            assert_eq!(error.code(), Code::NotFound, "{json}");
+        }
+    }
+}
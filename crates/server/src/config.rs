@@ -0,0 +1,222 @@
+//! Server configuration, layered from a TOML file, environment variables and
+//! command line flags.
+//!
+//! Command line flags take precedence over environment variables, which take
+//! precedence over the configuration file. An example configuration file:
+//!
+//! ```toml
+//! host = "0.0.0.0"
+//! port = 50051
+//! worker_threads = 8
+//! data_dir = "/var/lib/graph"
+//! export_dir = "/var/lib/graph/exports"
+//! memory_limit = 17179869184
+//! max_finished_jobs = 1000
+//! admin_tokens = ["secret"]
+//! read_only_tokens = ["public"]
+//! tls_cert = "/etc/graph/server.pem"
+//! tls_key = "/etc/graph/server.key"
+//!
+//! [namespace_quotas]
+//! tenant_a = 4294967296
+//! ```
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    net::{IpAddr, Ipv6Addr},
+    path::PathBuf,
+};
+
+use clap::Parser;
+use serde::Deserialize;
+
+const DEFAULT_PORT: u16 = 50051;
+
+#[derive(Debug, Parser)]
+#[clap(
+    version,
+    about = "Graph Arrow Server",
+    disable_help_subcommand = true,
+    infer_long_args = true
+)]
+pub struct CliOpts {
+    /// Host address [default: ::1]
+    #[clap(env = "GRAPH_SERVER_HOST", display_order = 0)]
+    host: Option<IpAddr>,
+
+    /// Port [default: 50051]
+    #[clap(env = "GRAPH_SERVER_PORT", display_order = 1)]
+    port: Option<u16>,
+
+    /// Path to a TOML configuration file
+    #[clap(long, env = "GRAPH_SERVER_CONFIG", display_order = 2)]
+    config: Option<PathBuf>,
+
+    /// Number of threads that handle requests [default: number of cores]
+    #[clap(long, env = "GRAPH_SERVER_WORKER_THREADS", display_order = 3)]
+    worker_threads: Option<usize>,
+
+    /// Directory in which graphs and properties are persisted across restarts
+    #[clap(long, env = "GRAPH_SERVER_DATA_DIR", display_order = 4)]
+    data_dir: Option<PathBuf>,
+
+    /// Token that grants access to all actions, can be repeated
+    #[clap(
+        long = "admin-token",
+        env = "GRAPH_SERVER_ADMIN_TOKENS",
+        value_delimiter = ',',
+        display_order = 5
+    )]
+    admin_tokens: Vec<String>,
+
+    /// Token that grants read-only access, can be repeated
+    #[clap(
+        long = "read-only-token",
+        env = "GRAPH_SERVER_READ_ONLY_TOKENS",
+        value_delimiter = ',',
+        display_order = 6
+    )]
+    read_only_tokens: Vec<String>,
+
+    /// Path to the PEM-encoded TLS certificate
+    #[clap(long, env = "GRAPH_SERVER_TLS_CERT", display_order = 7)]
+    tls_cert: Option<PathBuf>,
+
+    /// Path to the PEM-encoded TLS private key
+    #[clap(long, env = "GRAPH_SERVER_TLS_KEY", display_order = 8)]
+    tls_key: Option<PathBuf>,
+
+    /// Maximum number of bytes occupied by graphs
+    #[clap(long, env = "GRAPH_SERVER_MEMORY_LIMIT", display_order = 9)]
+    memory_limit: Option<u64>,
+
+    /// Maximum number of bytes occupied by the graphs of a namespace, given
+    /// as `NAMESPACE=BYTES`, can be repeated
+    #[clap(
+        long = "namespace-quota",
+        env = "GRAPH_SERVER_NAMESPACE_QUOTAS",
+        value_delimiter = ',',
+        value_parser = parse_namespace_quota,
+        display_order = 10
+    )]
+    namespace_quotas: Vec<(String, u64)>,
+
+    /// Maximum number of finished jobs whose results are kept [default: 1000]
+    #[clap(long, env = "GRAPH_SERVER_MAX_FINISHED_JOBS", display_order = 11)]
+    max_finished_jobs: Option<usize>,
+
+    /// Directory to which the `export` action writes files, exports are
+    /// disabled if not set
+    #[clap(long, env = "GRAPH_SERVER_EXPORT_DIR", display_order = 12)]
+    export_dir: Option<PathBuf>,
+}
+
+impl CliOpts {
+    pub fn new() -> Self {
+        Self::parse()
+    }
+}
+
+fn parse_namespace_quota(value: &str) -> Result<(String, u64), String> {
+    let (namespace, quota) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAMESPACE=BYTES, got '{value}'"))?;
+    let quota = quota.parse().map_err(|e| format!("invalid quota: {e}"))?;
+    Ok((namespace.to_string(), quota))
+}
+
+/// The contents of a configuration file, all settings are optional.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    host: Option<IpAddr>,
+    port: Option<u16>,
+    worker_threads: Option<usize>,
+    data_dir: Option<PathBuf>,
+    #[serde(default)]
+    admin_tokens: Vec<String>,
+    #[serde(default)]
+    read_only_tokens: Vec<String>,
+    tls_cert: Option<PathBuf>,
+    tls_key: Option<PathBuf>,
+    memory_limit: Option<u64>,
+    #[serde(default)]
+    namespace_quotas: HashMap<String, u64>,
+    max_finished_jobs: Option<usize>,
+    export_dir: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub struct TlsConfig {
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+/// The resolved server configuration.
+#[derive(Debug)]
+pub struct ServerConfig {
+    pub host: IpAddr,
+    pub port: u16,
+    pub worker_threads: Option<usize>,
+    pub data_dir: Option<PathBuf>,
+    pub admin_tokens: Vec<String>,
+    pub read_only_tokens: Vec<String>,
+    pub tls: Option<TlsConfig>,
+    pub memory_limit: Option<u64>,
+    pub namespace_quotas: HashMap<String, u64>,
+    pub max_finished_jobs: Option<usize>,
+    pub export_dir: Option<PathBuf>,
+}
+
+impl ServerConfig {
+    /// Resolves the configuration from the given flags and environment
+    /// variables and the configuration file they point to, if any.
+    pub fn load(cli: CliOpts) -> Result<Self, Box<dyn Error>> {
+        let file = match &cli.config {
+            Some(path) => toml::from_str::<ConfigFile>(&std::fs::read_to_string(path)?)
+                .map_err(|e| format!("Invalid configuration file {path:?}: {e}"))?,
+            None => ConfigFile::default(),
+        };
+
+        let tls = match (cli.tls_cert.or(file.tls_cert), cli.tls_key.or(file.tls_key)) {
+            (Some(cert), Some(key)) => Some(TlsConfig { cert, key }),
+            (None, None) => None,
+            _ => return Err("TLS requires both a certificate and a private key".into()),
+        };
+
+        if cli.worker_threads.or(file.worker_threads) == Some(0) {
+            return Err("The number of worker threads must be greater than zero".into());
+        }
+
+        // Quotas given via flags override the quotas of the same namespace
+        // in the configuration file.
+        let mut namespace_quotas = file.namespace_quotas;
+        namespace_quotas.extend(cli.namespace_quotas);
+
+        Ok(Self {
+            host: cli
+                .host
+                .or(file.host)
+                .unwrap_or(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            port: cli.port.or(file.port).unwrap_or(DEFAULT_PORT),
+            worker_threads: cli.worker_threads.or(file.worker_threads),
+            data_dir: cli.data_dir.or(file.data_dir),
+            admin_tokens: non_empty_or(cli.admin_tokens, file.admin_tokens),
+            read_only_tokens: non_empty_or(cli.read_only_tokens, file.read_only_tokens),
+            tls,
+            memory_limit: cli.memory_limit.or(file.memory_limit),
+            namespace_quotas,
+            max_finished_jobs: cli.max_finished_jobs.or(file.max_finished_jobs),
+            export_dir: cli.export_dir.or(file.export_dir),
+        })
+    }
+}
+
+fn non_empty_or<T>(values: Vec<T>, fallback: Vec<T>) -> Vec<T> {
+    if values.is_empty() {
+        fallback
+    } else {
+        values
+    }
+}
@@ -0,0 +1,252 @@
+//! Writes graphs and properties to server-side files, so that they can be
+//! processed by downstream batch jobs.
+//!
+//! Graphs are written as edge list, as Parquet file with a `source`, `target`
+//! and, for weighted graphs, a `weight` column, or in the binary snapshot
+//! format of the graph builder. Properties are always written as Parquet
+//! files.
+//!
+//! All files are written to the export directory of the server. Clients
+//! choose paths relative to it, which must not leave the directory.
+
+use std::{
+    fs::File,
+    io::{BufWriter, ErrorKind, Write},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use arrow::{datatypes::Schema, record_batch::RecordBatch};
+use graph::prelude::{write_graph, EdgeListOutput, SerializeGraphOp};
+use parquet::arrow::ArrowWriter;
+use tonic::Status;
+
+use crate::{
+    actions::ExportFormat,
+    catalog::{GraphType, PropertyEntry},
+};
+
+/// Resolves the client-provided path of a graph file within the export
+/// directory.
+///
+/// Paths must be relative and must not contain `..` components. Symlinks
+/// within the export directory are followed, but must not lead out of it.
+pub fn resolve_path(export_dir: &Path, path: &str) -> Result<PathBuf, Status> {
+    let path = Path::new(path);
+    if path.file_name().is_none() {
+        return Err(Status::invalid_argument(format!(
+            "Export path {path:?} does not name a file"
+        )));
+    }
+    if !path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(Status::invalid_argument(format!(
+            "Export path {path:?} must be relative to the export directory and must not contain '..'"
+        )));
+    }
+    let path = export_dir.join(path);
+    check_symlinks(export_dir, &path)?;
+    Ok(path)
+}
+
+/// Checks that the symlinks on the given path within the export directory,
+/// including the file itself, only point into the export directory.
+pub fn check_symlinks(export_dir: &Path, path: &Path) -> Result<(), Status> {
+    let export_dir_error = || {
+        Status::invalid_argument(format!(
+            "Export path {path:?} must not leave the export directory"
+        ))
+    };
+
+    let root = match export_dir.canonicalize() {
+        Ok(root) => root,
+        // There are no symlinks in a missing directory.
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(from_io_error(error)),
+    };
+    let relative_path = path
+        .strip_prefix(export_dir)
+        .map_err(|_| export_dir_error())?;
+
+    let mut resolved = root.clone();
+    for component in relative_path.components() {
+        resolved.push(component);
+        match resolved.symlink_metadata() {
+            Ok(metadata) if metadata.file_type().is_symlink() => {
+                // Dangling symlinks fail to resolve and are rejected as well.
+                resolved = resolved.canonicalize().map_err(|_| export_dir_error())?;
+                if !resolved.starts_with(&root) {
+                    return Err(export_dir_error());
+                }
+            }
+            Ok(_) => {}
+            // The remaining components do not exist yet.
+            Err(error) if error.kind() == ErrorKind::NotFound => break,
+            Err(error) => return Err(from_io_error(error)),
+        }
+    }
+    Ok(())
+}
+
+/// Returns the path of a property file next to the given graph file, i.e.,
+/// `<graph_path>.<property_key>.parquet`.
+///
+/// All characters of the property key except for ASCII letters, digits, `-`
+/// and `_` are replaced by `_`, so that the file stays in the directory of
+/// the graph file.
+pub fn property_path(graph_path: &Path, property_key: &str) -> PathBuf {
+    let property_key = property_key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    let mut path = graph_path.as_os_str().to_owned();
+    path.push(format!(".{property_key}.parquet"));
+    PathBuf::from(path)
+}
+
+/// Writes the graph to the given path and returns the size of the file.
+pub fn export_graph(graph: &GraphType, path: &Path, format: ExportFormat) -> Result<u64, Status> {
+    match format {
+        ExportFormat::EdgeList => match graph {
+            GraphType::Directed(g) => write_graph(g, path, EdgeListOutput),
+            GraphType::Undirected(g) => write_graph(g, path, EdgeListOutput),
+            GraphType::DirectedWeighted(g) => write_graph(g, path, EdgeListOutput),
+            GraphType::UndirectedWeighted(g) => write_graph(g, path, EdgeListOutput),
+        }
+        .map_err(from_graph_error)?,
+        ExportFormat::Parquet => {
+            let edges = graph.edges().map_err(from_arrow_error)?;
+            write_parquet(path, edges.schema, &edges.batches)?;
+        }
+        ExportFormat::Binary => {
+            let mut writer = BufWriter::new(File::create(path).map_err(from_io_error)?);
+            match graph {
+                GraphType::Directed(g) => g.serialize(&mut writer),
+                GraphType::Undirected(g) => g.serialize(&mut writer),
+                GraphType::DirectedWeighted(g) => g.serialize(&mut writer),
+                GraphType::UndirectedWeighted(g) => g.serialize(&mut writer),
+            }
+            .map_err(from_graph_error)?;
+            writer.flush().map_err(from_io_error)?;
+        }
+    }
+
+    file_size(path)
+}
+
+/// Writes the property to the given path and returns the size of the file.
+pub fn export_property(entry: &PropertyEntry, path: &Path) -> Result<u64, Status> {
+    write_parquet(path, entry.schema.clone(), &entry.batches)?;
+    file_size(path)
+}
+
+fn write_parquet(path: &Path, schema: Arc<Schema>, batches: &[RecordBatch]) -> Result<(), Status> {
+    let file = File::create(path).map_err(from_io_error)?;
+    let mut writer = ArrowWriter::try_new(file, schema, None).map_err(from_parquet_error)?;
+    for batch in batches {
+        writer.write(batch).map_err(from_parquet_error)?;
+    }
+    writer.close().map_err(from_parquet_error)?;
+    Ok(())
+}
+
+fn file_size(path: &Path) -> Result<u64, Status> {
+    Ok(path.metadata().map_err(from_io_error)?.len())
+}
+
+fn from_io_error(error: std::io::Error) -> Status {
+    Status::internal(format!("IoError: {error:?}"))
+}
+
+fn from_arrow_error(error: arrow::error::ArrowError) -> Status {
+    Status::internal(format!("ArrowError: {error:?}"))
+}
+
+fn from_parquet_error(error: parquet::errors::ParquetError) -> Status {
+    Status::internal(format!("ParquetError: {error:?}"))
+}
+
+fn from_graph_error(error: graph::prelude::Error) -> Status {
+    Status::internal(format!("GraphError: {error:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic::Code;
+
+    use super::*;
+
+    #[test]
+    fn paths_stay_in_export_dir() {
+        let export_dir = Path::new("/exports");
+        assert_eq!(
+            resolve_path(export_dir, "graphs/g.parquet").unwrap(),
+            Path::new("/exports/graphs/g.parquet")
+        );
+
+        for path in [
+            "/etc/passwd",
+            "../g.parquet",
+            "graphs/../../g.parquet",
+            "",
+            "..",
+        ] {
+            let error = resolve_path(export_dir, path).unwrap_err();
+            assert_eq!(error.code(), Code::InvalidArgument, "{path}");
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_stay_in_export_dir() {
+        use std::os::unix::fs::symlink;
+
+        let dir = tempfile::tempdir().unwrap();
+        let export_dir = dir.path().join("exports");
+        let outside = dir.path().join("outside");
+        std::fs::create_dir_all(export_dir.join("graphs")).unwrap();
+        std::fs::create_dir(&outside).unwrap();
+
+        symlink(export_dir.join("graphs"), export_dir.join("inside")).unwrap();
+        symlink(&outside, export_dir.join("escape")).unwrap();
+        symlink(outside.join("g.parquet"), export_dir.join("g.parquet")).unwrap();
+        symlink(export_dir.join("missing"), export_dir.join("dangling")).unwrap();
+
+        assert_eq!(
+            resolve_path(&export_dir, "inside/g.parquet").unwrap(),
+            export_dir.join("inside/g.parquet")
+        );
+        assert!(resolve_path(&export_dir, "new/g.parquet").is_ok());
+
+        for path in ["escape/g.parquet", "g.parquet", "dangling"] {
+            let error = resolve_path(&export_dir, path).unwrap_err();
+            assert_eq!(error.code(), Code::InvalidArgument, "{path}");
+        }
+
+        let graph_path = resolve_path(&export_dir, "graphs/g.parquet").unwrap();
+        let property_path = property_path(&graph_path, "rank");
+        symlink(outside.join("rank"), &property_path).unwrap();
+        assert!(check_symlinks(&export_dir, &property_path).is_err());
+    }
+
+    #[test]
+    fn property_keys_are_sanitized() {
+        let graph_path = Path::new("/exports/g.parquet");
+        assert_eq!(
+            property_path(graph_path, "page_rank"),
+            Path::new("/exports/g.parquet.page_rank.parquet")
+        );
+        assert_eq!(
+            property_path(graph_path, "../../etc/passwd"),
+            Path::new("/exports/g.parquet.______etc_passwd.parquet")
+        );
+    }
+}
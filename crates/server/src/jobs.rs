@@ -0,0 +1,354 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use graph::prelude::ProgressTracker;
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use tokio::{sync::oneshot, task::JoinHandle};
+use tonic::Status;
+
+use crate::server::FlightResult;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+struct Job {
+    graph_name: String,
+    description: String,
+    state: JobState,
+    started: Instant,
+    elapsed_millis: Option<u128>,
+    result: Option<FlightResult<arrow_flight::Result>>,
+    tracker: Arc<ComputeTracker>,
+    handle: JoinHandle<()>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct JobStatus {
+    job_id: u64,
+    description: String,
+    state: JobState,
+    elapsed_millis: u128,
+    progress: Option<Progress>,
+    error: Option<String>,
+}
+
+/// The last progress update of the algorithm of a job.
+#[derive(Serialize, Debug, Clone)]
+pub struct Progress {
+    task: String,
+    completed: usize,
+    total: Option<usize>,
+}
+
+/// A computation that runs as part of a `compute` action.
+struct Computation {
+    graph_name: String,
+    cancel: oneshot::Sender<()>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CancelGraphResult {
+    graph_name: String,
+    cancelled_jobs: Vec<JobStatus>,
+    cancelled_computations: usize,
+}
+
+/// Keeps track of computations that run in the background.
+///
+/// Running jobs are kept until they finish. Afterwards, their results are
+/// kept for the most recently finished jobs only, so that clients can poll
+/// the status and fetch the result until the job is evicted. Computations of
+/// `compute` actions are tracked while they are running, so that they can be
+/// cancelled per graph.
+pub struct JobRegistry {
+    next_job_id: u64,
+    jobs: HashMap<u64, Job>,
+    // Ids of finished jobs, in the order they finished
+    finished_jobs: VecDeque<u64>,
+    max_finished_jobs: usize,
+    next_computation_id: u64,
+    computations: HashMap<u64, Computation>,
+}
+
+impl JobRegistry {
+    pub const DEFAULT_MAX_FINISHED_JOBS: usize = 1000;
+
+    pub fn new() -> Self {
+        Self {
+            next_job_id: 0,
+            jobs: HashMap::new(),
+            finished_jobs: VecDeque::new(),
+            max_finished_jobs: Self::DEFAULT_MAX_FINISHED_JOBS,
+            next_computation_id: 0,
+            computations: HashMap::new(),
+        }
+    }
+
+    /// Limits the number of finished jobs whose results are kept. The jobs
+    /// that finished first are evicted first.
+    pub fn set_max_finished_jobs(&mut self, max_finished_jobs: usize) {
+        self.max_finished_jobs = max_finished_jobs;
+        self.evict_finished_jobs();
+    }
+
+    pub fn next_job_id(&mut self) -> u64 {
+        let job_id = self.next_job_id;
+        self.next_job_id += 1;
+        job_id
+    }
+
+    /// Registers a running job that is executed by the given task and reports
+    /// its progress to the given tracker.
+    pub fn submit(
+        &mut self,
+        job_id: u64,
+        graph_name: String,
+        description: String,
+        tracker: Arc<ComputeTracker>,
+        handle: JoinHandle<()>,
+    ) -> JobStatus {
+        let job = Job {
+            graph_name,
+            description,
+            state: JobState::Running,
+            started: Instant::now(),
+            elapsed_millis: None,
+            result: None,
+            tracker,
+            handle,
+        };
+        let status = Self::job_status(job_id, &job);
+        self.jobs.insert(job_id, job);
+        status
+    }
+
+    /// Stores the result of a job, unless the job has been cancelled.
+    pub fn finish(&mut self, job_id: u64, result: FlightResult<arrow_flight::Result>) {
+        if let Some(job) = self.jobs.get_mut(&job_id) {
+            if job.state == JobState::Running {
+                job.state = match result {
+                    Ok(_) => JobState::Completed,
+                    Err(_) => JobState::Failed,
+                };
+                job.elapsed_millis = Some(job.started.elapsed().as_millis());
+                job.result = Some(result);
+                self.finished_jobs.push_back(job_id);
+                self.evict_finished_jobs();
+            }
+        }
+    }
+
+    pub fn status(&self, job_id: u64) -> Result<JobStatus, Status> {
+        let job = self.get(job_id)?;
+        Ok(Self::job_status(job_id, job))
+    }
+
+    /// Returns the result of a completed job or the error of a failed job.
+    pub fn result(&self, job_id: u64) -> FlightResult<arrow_flight::Result> {
+        let job = self.get(job_id)?;
+        match &job.result {
+            Some(result) => result.clone(),
+            None => Err(Status::failed_precondition(format!(
+                "Job {job_id} is {:?}",
+                job.state
+            ))),
+        }
+    }
+
+    /// Cancels a running job.
+    ///
+    /// Algorithms that already started on the blocking thread pool stop
+    /// after their current iteration, see [`ComputeTracker`].
+    pub fn cancel(&mut self, job_id: u64) -> Result<JobStatus, Status> {
+        let job = self
+            .jobs
+            .get_mut(&job_id)
+            .ok_or_else(|| Self::job_not_found(job_id))?;
+
+        if Self::cancel_job(job) {
+            self.finished_jobs.push_back(job_id);
+        }
+        let status = Self::job_status(job_id, job);
+        self.evict_finished_jobs();
+        Ok(status)
+    }
+
+    /// Cancels all running jobs and computations on the given graph.
+    pub fn cancel_graph(&mut self, graph_name: &str) -> CancelGraphResult {
+        let mut cancelled_jobs = Vec::new();
+        for (job_id, job) in &mut self.jobs {
+            if job.graph_name == graph_name && Self::cancel_job(job) {
+                self.finished_jobs.push_back(*job_id);
+                cancelled_jobs.push(Self::job_status(*job_id, job));
+            }
+        }
+        self.evict_finished_jobs();
+
+        let computation_ids = self
+            .computations
+            .iter()
+            .filter(|(_, computation)| computation.graph_name == graph_name)
+            .map(|(computation_id, _)| *computation_id)
+            .collect::<Vec<_>>();
+        for computation_id in &computation_ids {
+            if let Some(computation) = self.computations.remove(computation_id) {
+                // The computation may have finished in the meantime.
+                let _ = computation.cancel.send(());
+            }
+        }
+
+        CancelGraphResult {
+            graph_name: graph_name.to_string(),
+            cancelled_jobs,
+            cancelled_computations: computation_ids.len(),
+        }
+    }
+
+    /// Tracks a running computation on the given graph.
+    ///
+    /// The returned receiver completes when the computation is cancelled.
+    /// The computation is tracked until the guard is dropped.
+    pub fn track(
+        job_registry: &Arc<RwLock<Self>>,
+        graph_name: String,
+    ) -> (ComputationGuard, oneshot::Receiver<()>) {
+        let (cancel, cancelled) = oneshot::channel();
+        let mut registry = job_registry.write();
+        let computation_id = registry.next_computation_id;
+        registry.next_computation_id += 1;
+        registry
+            .computations
+            .insert(computation_id, Computation { graph_name, cancel });
+
+        let guard = ComputationGuard {
+            job_registry: Arc::clone(job_registry),
+            computation_id,
+        };
+        (guard, cancelled)
+    }
+
+    /// Cancels the job if it is running and returns whether it was running.
+    fn cancel_job(job: &mut Job) -> bool {
+        if job.state == JobState::Running {
+            job.handle.abort();
+            job.state = JobState::Cancelled;
+            job.elapsed_millis = Some(job.started.elapsed().as_millis());
+            true
+        } else {
+            false
+        }
+    }
+
+    fn evict_finished_jobs(&mut self) {
+        while self.finished_jobs.len() > self.max_finished_jobs {
+            if let Some(job_id) = self.finished_jobs.pop_front() {
+                self.jobs.remove(&job_id);
+            }
+        }
+    }
+
+    fn get(&self, job_id: u64) -> Result<&Job, Status> {
+        self.jobs
+            .get(&job_id)
+            .ok_or_else(|| Self::job_not_found(job_id))
+    }
+
+    fn job_status(job_id: u64, job: &Job) -> JobStatus {
+        JobStatus {
+            job_id,
+            description: job.description.clone(),
+            state: job.state,
+            elapsed_millis: job
+                .elapsed_millis
+                .unwrap_or_else(|| job.started.elapsed().as_millis()),
+            progress: job.tracker.progress.lock().clone(),
+            error: match &job.result {
+                Some(Err(status)) => Some(status.message().to_string()),
+                _ => None,
+            },
+        }
+    }
+
+    fn job_not_found(job_id: u64) -> Status {
+        Status::not_found(format!("Job with id '{job_id}' not found"))
+    }
+}
+
+/// Records the progress of an algorithm that runs on the blocking thread pool
+/// and signals it to stop.
+///
+/// Dropping the future of a computation, e.g., on timeout or cancellation,
+/// does not stop the blocking task that runs the algorithm. Instead, the
+/// algorithm checks this tracker after each iteration and returns early once
+/// the computation has been dropped.
+#[derive(Default)]
+pub struct ComputeTracker {
+    cancelled: AtomicBool,
+    progress: Mutex<Option<Progress>>,
+}
+
+impl ComputeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a guard that cancels the algorithm when dropped.
+    pub fn cancel_on_drop(self: &Arc<Self>) -> CancelOnDrop {
+        CancelOnDrop(Arc::clone(self))
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+}
+
+impl ProgressTracker for ComputeTracker {
+    fn progress(&self, task: &str, completed: usize, total: Option<usize>) {
+        *self.progress.lock() = Some(Progress {
+            task: task.to_string(),
+            completed,
+            total,
+        });
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// Cancels the algorithm of a computation when dropped.
+pub struct CancelOnDrop(Arc<ComputeTracker>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+/// Stops tracking a computation when dropped, i.e., after it finished or the
+/// request has been dropped.
+pub struct ComputationGuard {
+    job_registry: Arc<RwLock<JobRegistry>>,
+    computation_id: u64,
+}
+
+impl Drop for ComputationGuard {
+    fn drop(&mut self) {
+        self.job_registry
+            .write()
+            .computations
+            .remove(&self.computation_id);
+    }
+}
@@ -6,57 +6,206 @@
 //! [pyarrow](https://pypi.org/project/pyarrow/). Server commands, also called
 //! Flight actions, are encoded via JSON. Currently supported commands include
 //! creating graphs, relabeling graphs and computing algorithms, such as PageRank,
-//! Triangle Count and SSSP. Algorithm results are streamed to the client via
+//! Triangle Count, SSSP, WCC, Louvain, Label Propagation, betweenness, k-core,
+//! BFS and degree centrality. Algorithm results are streamed to the client via
 //! the do_get command and nicely wrapped in Arrow record batches.
+//! In `stream` mode, results are not stored as node properties, but can be
+//! fetched once via the ticket returned by the compute command.
+//! Graphs and their properties can be discovered via `list_flights`,
+//! `get_flight_info` and `get_schema` using the path descriptors
+//! `[graph_name]` for the edges of a graph and `[graph_name, property_key]`
+//! for a node property. The returned endpoints contain `do_get` tickets.
+//! Long-running algorithms can be submitted as background jobs, whose status,
+//! progress and result can be polled via separate actions. The results of the
+//! 1000 most recently finished jobs are kept, which can be changed via
+//! `--max-finished-jobs`. Request counts, latencies
+//! and compute timings are exposed in the Prometheus text format via the
+//! `metrics` action.
+//!
+//! Graphs and algorithm results are kept in memory. If the server is started
+//! with `--data-dir`, they are also persisted in that directory and restored
+//! after a restart.
+//!
+//! The memory occupied by graphs can be limited via `--memory-limit`. Creating
+//! a graph that would exceed the limit evicts the least recently used graphs,
+//! which requires `--data-dir`, or is rejected otherwise.
+//!
+//! Graphs are organized in namespaces by naming them `namespace.graph_name`.
+//! Graphs without a namespace belong to the `default` namespace. Graphs can be
+//! listed and removed per namespace and the memory of a namespace can be
+//! limited via `--namespace-quota`.
+//!
+//! Node properties computed elsewhere, e.g., embeddings or labels, are uploaded
+//! via `do_put` with a `{"graph_name": ...}` command. Each value column is
+//! stored as node property of the graph. Rows either carry a `node_id` column
+//! or contain exactly one value per node in node id order.
+//!
+//! Besides server-side files, the `create` action accepts a GDL string in a
+//! `gdl` field or an inline edge array in an `edges` field, which is useful
+//! for tests and demos against a server without a shared filesystem.
+//!
+//! With `detail` set, the `list` action also returns the memory usage, CSR
+//! layout, orientation, creation time and the properties of each graph.
+//!
+//! The `export` action writes a graph and, optionally, some of its properties to
+//! server-side files. Graphs are written as edge list, Parquet or binary
+//! snapshot, properties are written as Parquet files next to the graph file.
+//! Exports are only enabled with `--export-dir` and paths are relative to that
+//! directory.
+//!
+//! The `to_relabeled` action orders nodes by degree, in breadth-first or reverse
+//! Cuthill-McKee order, randomly or by a permutation stored as node property.
+//! The original node ids are stored as node property of the relabeled graph.
+//!
+//! The `stats` action returns the degree distribution, the density and, given
+//! a component property, the number of components of a graph.
+//!
+//! The `pipeline` action runs a sequence of actions, e.g., create, compute and
+//! export, in a single round trip. It stops at the first failed step and
+//! reports the result or error of each step.
+//!
+//! The scores of PageRank, betweenness and degree centrality and the distances
+//! of SSSP and BFS can be post-processed via a `normalization` in the compute
+//! config, i.e., `min_max`, `l1`, `l2`, `standard`, `log` or `rank`.
+//! With `summary` set, the result of these algorithms, WCC, Louvain and Label
+//! Propagation contains the percentiles, the maximum and the mean of the scores
+//! or community sizes.
+//!
+//! A computation with `timeout_millis` fails if it does not finish in time.
+//! The `cancel` action cancels a job by `job_id` or all running jobs and
+//! computations on a graph by `graph_name`. The results of cancelled and timed
+//! out computations are discarded. PageRank, SSSP, Louvain, Label Propagation,
+//! betweenness and k-core stop after their current iteration, the remaining
+//! algorithms run to completion on the blocking thread pool in the background.
+//!
+//! Clients can be required to authenticate with a token, either via the
+//! Flight handshake or as bearer token, using `--admin-token` and
+//! `--read-only-token`. TLS is enabled via `--tls-cert` and `--tls-key`.
+//!
+//! All settings can also be read from a TOML file passed via `--config` or from
+//! `GRAPH_SERVER_*` environment variables, e.g., `GRAPH_SERVER_MEMORY_LIMIT`.
+//! Flags take precedence over environment variables, which take precedence
+//! over the configuration file. The number of request-handling threads is
+//! set via `--worker-threads`.
 //!
 //! Check the `examples` folder for scripts that demonstrate client-server interaction.
 
 mod actions;
+mod auth;
 mod catalog;
+mod config;
+mod export;
+mod jobs;
+mod metrics;
+mod persistence;
 mod server;
 
-use std::net::IpAddr;
+use std::collections::HashMap;
 
 use arrow_flight::flight_service_server::FlightServiceServer;
-use clap::Parser;
 use log::info;
-use tonic::transport::Server;
+use tonic::transport::{Identity, Server, ServerTlsConfig};
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+use crate::auth::{Auth, Role};
+use crate::config::{CliOpts, ServerConfig};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
 
-    let CliOpts { host, port } = CliOpts::new();
+    let config = ServerConfig::load(CliOpts::new())?;
+
+    let mut runtime = tokio::runtime::Builder::new_multi_thread();
+    if let Some(worker_threads) = config.worker_threads {
+        runtime.worker_threads(worker_threads);
+    }
+    runtime.enable_all().build()?.block_on(serve(config))
+}
+
+async fn serve(config: ServerConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let ServerConfig {
+        host,
+        port,
+        worker_threads: _,
+        data_dir,
+        admin_tokens,
+        read_only_tokens,
+        tls,
+        memory_limit,
+        namespace_quotas,
+        max_finished_jobs,
+        export_dir,
+    } = config;
 
     let addr = std::net::SocketAddr::new(host, port);
-    let service = crate::server::FlightServiceImpl::new();
-    let service = FlightServiceServer::new(service);
-    info!("Starting server at {addr}");
-    Server::builder().add_service(service).serve(addr).await?;
+    let service = match data_dir {
+        Some(data_dir) => {
+            info!("Persisting graphs in {data_dir:?}");
+            crate::server::FlightServiceImpl::with_data_dir(data_dir)?
+        }
+        None => crate::server::FlightServiceImpl::new(),
+    };
+    let service = match memory_limit {
+        Some(memory_limit) => {
+            info!("Limiting graph memory to {memory_limit} bytes");
+            service.with_memory_limit(memory_limit)
+        }
+        None => service,
+    };
+    let service = namespace_quotas
+        .into_iter()
+        .fold(service, |service, (namespace, quota)| {
+            info!("Limiting graph memory of namespace '{namespace}' to {quota} bytes");
+            service.with_namespace_quota(namespace, quota)
+        });
+    let service = match max_finished_jobs {
+        Some(max_finished_jobs) => {
+            info!("Keeping the results of {max_finished_jobs} finished jobs");
+            service.with_max_finished_jobs(max_finished_jobs)
+        }
+        None => service,
+    };
+    let service = match export_dir {
+        Some(export_dir) => {
+            info!("Exporting graphs to {export_dir:?}");
+            service.with_export_dir(export_dir)
+        }
+        None => service,
+    };
 
-    Ok(())
-}
+    let auth = if admin_tokens.is_empty() && read_only_tokens.is_empty() {
+        None
+    } else {
+        let tokens = admin_tokens
+            .into_iter()
+            .map(|token| (token, Role::Admin))
+            .chain(
+                read_only_tokens
+                    .into_iter()
+                    .map(|token| (token, Role::ReadOnly)),
+            )
+            .collect::<HashMap<_, _>>();
+        info!("Enabled token authentication");
+        Some(Auth::new(tokens))
+    };
 
-#[derive(Debug, Parser)]
-#[clap(
-    version,
-    about = "Graph Arrow Server",
-    arg_required_else_help = true,
-    disable_help_subcommand = true,
-    infer_long_args = true
-)]
-struct CliOpts {
-    /// Host address
-    #[clap(default_value = "::1", display_order = 0)]
-    host: IpAddr,
-
-    /// Port
-    #[clap(default_value_t = 50051, display_order = 1)]
-    port: u16,
-}
+    let service = match &auth {
+        Some(auth) => service.with_auth(auth.clone()),
+        None => service,
+    };
+    let service = FlightServiceServer::with_interceptor(service, move |request| match &auth {
+        Some(auth) => auth.intercept(request),
+        None => Ok(request),
+    });
 
-impl CliOpts {
-    fn new() -> Self {
-        Self::parse()
+    let mut server = Server::builder();
+    if let Some(tls) = tls {
+        let identity = Identity::from_pem(std::fs::read(tls.cert)?, std::fs::read(tls.key)?);
+        server = server.tls_config(ServerTlsConfig::new().identity(identity))?;
+        info!("Enabled TLS");
     }
+
+    info!("Starting server at {addr}");
+    server.add_service(service).serve(addr).await?;
+
+    Ok(())
 }
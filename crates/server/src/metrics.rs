@@ -0,0 +1,198 @@
+//! Operational metrics of the server.
+//!
+//! Metrics are rendered in the Prometheus text exposition format and returned
+//! by the `metrics` action, so that they can be scraped via a Flight client.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+
+#[derive(Default, Clone, Copy)]
+struct Timing {
+    count: u64,
+    errors: u64,
+    seconds: f64,
+}
+
+impl Timing {
+    fn record(&mut self, elapsed: Duration, success: bool) {
+        self.count += 1;
+        self.seconds += elapsed.as_secs_f64();
+        if !success {
+            self.errors += 1;
+        }
+    }
+}
+
+/// A snapshot of the catalog state at the time the metrics are rendered.
+pub struct CatalogGauges {
+    pub graph_count: usize,
+    pub graph_bytes: u64,
+    pub property_count: usize,
+    pub property_bytes: u64,
+}
+
+#[derive(Default)]
+pub struct Metrics {
+    // Request timings per operation, i.e., per action type or flight method
+    requests: Mutex<BTreeMap<String, Timing>>,
+    // Compute timings per algorithm
+    computations: Mutex<BTreeMap<&'static str, Timing>>,
+    // Number of bytes streamed to clients via do_get
+    streamed_bytes: AtomicU64,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_request(&self, operation: &str, elapsed: Duration, success: bool) {
+        self.requests
+            .lock()
+            .entry(operation.to_string())
+            .or_default()
+            .record(elapsed, success);
+    }
+
+    pub fn record_computation(&self, algorithm: &'static str, elapsed: Duration, success: bool) {
+        self.computations
+            .lock()
+            .entry(algorithm)
+            .or_default()
+            .record(elapsed, success);
+    }
+
+    pub fn record_streamed_bytes(&self, bytes: u64) {
+        self.streamed_bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Renders all metrics in the Prometheus text exposition format.
+    pub fn render(&self, gauges: CatalogGauges) -> String {
+        let mut out = String::new();
+
+        let requests = self.requests.lock().clone();
+        render_timings(
+            &mut out,
+            "graph_server_requests",
+            "handled requests",
+            "operation",
+            &requests,
+        );
+
+        let computations = self
+            .computations
+            .lock()
+            .iter()
+            .map(|(algorithm, timing)| (algorithm.to_string(), *timing))
+            .collect();
+        render_timings(
+            &mut out,
+            "graph_server_computations",
+            "algorithm computations",
+            "algorithm",
+            &computations,
+        );
+
+        render_metric(
+            &mut out,
+            "graph_server_streamed_bytes_total",
+            "counter",
+            "Bytes streamed to clients.",
+            self.streamed_bytes.load(Ordering::Relaxed),
+        );
+        render_metric(
+            &mut out,
+            "graph_server_graphs",
+            "gauge",
+            "Graphs in the catalog.",
+            gauges.graph_count,
+        );
+        render_metric(
+            &mut out,
+            "graph_server_graph_bytes",
+            "gauge",
+            "Bytes occupied by loaded graphs.",
+            gauges.graph_bytes,
+        );
+        render_metric(
+            &mut out,
+            "graph_server_properties",
+            "gauge",
+            "Properties in the property store.",
+            gauges.property_count,
+        );
+        render_metric(
+            &mut out,
+            "graph_server_property_bytes",
+            "gauge",
+            "Bytes occupied by properties.",
+            gauges.property_bytes,
+        );
+
+        out
+    }
+}
+
+fn render_metric<T: std::fmt::Display>(
+    out: &mut String,
+    name: &str,
+    metric_type: &str,
+    help: &str,
+    value: T,
+) {
+    // Writing to a string never fails.
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {metric_type}");
+    let _ = writeln!(out, "{name} {value}");
+}
+
+fn render_timings(
+    out: &mut String,
+    prefix: &str,
+    help: &str,
+    label: &str,
+    timings: &BTreeMap<String, Timing>,
+) {
+    let _ = writeln!(out, "# HELP {prefix}_total Number of {help}.");
+    let _ = writeln!(out, "# TYPE {prefix}_total counter");
+    for (key, timing) in timings {
+        let _ = writeln!(out, "{prefix}_total{{{label}=\"{key}\"}} {}", timing.count);
+    }
+
+    let _ = writeln!(
+        out,
+        "# HELP {prefix}_errors_total Number of {help} that failed."
+    );
+    let _ = writeln!(out, "# TYPE {prefix}_errors_total counter");
+    for (key, timing) in timings {
+        let _ = writeln!(
+            out,
+            "{prefix}_errors_total{{{label}=\"{key}\"}} {}",
+            timing.errors
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        "# HELP {prefix}_duration_seconds Duration of {help} in seconds."
+    );
+    let _ = writeln!(out, "# TYPE {prefix}_duration_seconds summary");
+    for (key, timing) in timings {
+        let _ = writeln!(
+            out,
+            "{prefix}_duration_seconds_sum{{{label}=\"{key}\"}} {}",
+            timing.seconds
+        );
+        let _ = writeln!(
+            out,
+            "{prefix}_duration_seconds_count{{{label}=\"{key}\"}} {}",
+            timing.count
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Persists graphs and properties in a data directory, so that they survive
+//! server restarts.
+//!
+//! Graphs are written in the binary snapshot format of the graph builder,
+//! properties are written as Arrow IPC files. The directory is laid out as
+//!
+//! ```text
+//! <data_dir>/graphs/<hex(graph_name)>/graph.bin
+//! <data_dir>/graphs/<hex(graph_name)>/info.json
+//! <data_dir>/properties/<hex(graph_name)>/<hex(property_key)>.arrow
+//! ```
+//!
+//! Names are hex-encoded to support arbitrary graph names and property keys.
+
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::UNIX_EPOCH,
+};
+
+use arrow::{
+    datatypes::Schema,
+    ipc::{reader::FileReader, writer::FileWriter},
+    record_batch::RecordBatch,
+};
+use graph::prelude::{DeserializeGraphOp, DirectedCsrGraph, SerializeGraphOp, UndirectedCsrGraph};
+use log::info;
+use tonic::Status;
+
+use crate::{
+    actions::{from_json_error, GraphInfo},
+    catalog::{GraphType, PropertyEntry, PropertyId},
+};
+
+const GRAPH_FILE: &str = "graph.bin";
+const INFO_FILE: &str = "info.json";
+const GRAPH_NAME_KEY: &str = "graph_name";
+const PROPERTY_KEY_KEY: &str = "property_key";
+
+#[derive(Clone, Debug)]
+pub struct SnapshotStore {
+    data_dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new<P: Into<PathBuf>>(data_dir: P) -> Result<Self, Status> {
+        let data_dir = data_dir.into();
+        fs::create_dir_all(data_dir.join("graphs")).map_err(from_io_error)?;
+        fs::create_dir_all(data_dir.join("properties")).map_err(from_io_error)?;
+        Ok(Self { data_dir })
+    }
+
+    /// Returns the infos of all persisted graphs without loading them.
+    pub fn graph_infos(&self) -> Result<Vec<GraphInfo>, Status> {
+        let mut infos = Vec::new();
+        for dir in fs::read_dir(self.data_dir.join("graphs")).map_err(from_io_error)? {
+            let info_path = dir.map_err(from_io_error)?.path().join(INFO_FILE);
+            if !info_path.exists() {
+                // incomplete snapshot, e.g., the server stopped while writing
+                continue;
+            }
+            let info = fs::read(info_path).map_err(from_io_error)?;
+            infos.push(serde_json::from_slice(&info).map_err(from_json_error)?);
+        }
+        Ok(infos)
+    }
+
+    pub fn write_graph(&self, graph_name: &str, graph: &GraphType) -> Result<(), Status> {
+        let dir = self.graph_dir(graph_name);
+        fs::create_dir_all(&dir).map_err(from_io_error)?;
+        // A missing info file marks the snapshot as incomplete.
+        remove_file_if_exists(&dir.join(INFO_FILE))?;
+
+        write_atomically(&dir.join(GRAPH_FILE), |writer| {
+            match graph {
+                GraphType::Directed(g) => g.serialize(writer),
+                GraphType::Undirected(g) => g.serialize(writer),
+                GraphType::DirectedWeighted(g) => g.serialize(writer),
+                GraphType::UndirectedWeighted(g) => g.serialize(writer),
+            }
+            .map_err(from_graph_error)
+        })?;
+
+        let info = GraphInfo::new(
+            graph_name.to_string(),
+            graph.to_string(),
+            graph.node_count(),
+            graph.edge_count(),
+        );
+        let info = serde_json::to_vec(&info).map_err(from_json_error)?;
+        write_atomically(&dir.join(INFO_FILE), |writer| {
+            writer.write_all(&info).map_err(from_io_error)
+        })?;
+
+        info!("Wrote snapshot of graph '{graph_name}' to {dir:?}");
+        Ok(())
+    }
+
+    /// Loads a persisted graph.
+    ///
+    /// Fails with `not_found` if the snapshot file is missing, e.g., because
+    /// it has been removed concurrently, and with `internal` if it cannot be
+    /// read, e.g., because it is truncated.
+    pub fn read_graph(&self, info: &GraphInfo) -> Result<GraphType, Status> {
+        let path = self.graph_dir(info.graph_name()).join(GRAPH_FILE);
+        let file = File::open(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => Status::not_found(format!(
+                "Snapshot of graph '{}' not found at {path:?}",
+                info.graph_name()
+            )),
+            _ => from_io_error(e),
+        })?;
+        let reader = BufReader::new(file);
+        let from_snapshot_error = |e: graph::prelude::Error| {
+            Status::internal(format!("Could not read snapshot {path:?}: {e:?}"))
+        };
+
+        let graph = match info.graph_type() {
+            "directed" => GraphType::Directed(
+                DirectedCsrGraph::deserialize(reader).map_err(from_snapshot_error)?,
+            ),
+            "undirected" => GraphType::Undirected(
+                UndirectedCsrGraph::deserialize(reader).map_err(from_snapshot_error)?,
+            ),
+            "directed+weighted" => GraphType::DirectedWeighted(
+                DirectedCsrGraph::deserialize(reader).map_err(from_snapshot_error)?,
+            ),
+            "undirected+weighted" => GraphType::UndirectedWeighted(
+                UndirectedCsrGraph::deserialize(reader).map_err(from_snapshot_error)?,
+            ),
+            graph_type => {
+                return Err(Status::internal(format!(
+                    "Unknown graph type '{graph_type}' in snapshot {path:?}"
+                )))
+            }
+        };
+
+        info!("Loaded graph '{}' from {path:?}", info.graph_name());
+        Ok(graph)
+    }
+
+    /// Returns the time at which the snapshot of the graph was written, in
+    /// milliseconds since the Unix epoch.
+    pub fn created_at(&self, graph_name: &str) -> Option<u64> {
+        let modified = fs::metadata(self.graph_dir(graph_name).join(GRAPH_FILE))
+            .and_then(|metadata| metadata.modified())
+            .ok()?;
+        let millis = modified.duration_since(UNIX_EPOCH).ok()?.as_millis();
+        Some(millis as u64)
+    }
+
+    pub fn remove_graph(&self, graph_name: &str) -> Result<(), Status> {
+        remove_dir_if_exists(&self.graph_dir(graph_name))?;
+        remove_dir_if_exists(&self.property_dir(graph_name))
+    }
+
+    pub fn write_property(
+        &self,
+        property_id: &PropertyId,
+        entry: &PropertyEntry,
+    ) -> Result<(), Status> {
+        let dir = self.property_dir(&property_id.graph_name);
+        fs::create_dir_all(&dir).map_err(from_io_error)?;
+
+        // The property id is stored in the schema metadata.
+        let metadata = HashMap::from([
+            (GRAPH_NAME_KEY.to_string(), property_id.graph_name.clone()),
+            (
+                PROPERTY_KEY_KEY.to_string(),
+                property_id.property_key.clone(),
+            ),
+        ]);
+        let schema = entry.schema.as_ref().clone().with_metadata(metadata);
+
+        let path = dir.join(format!("{}.arrow", encode(&property_id.property_key)));
+        write_atomically(&path, |writer| {
+            let mut writer = FileWriter::try_new(writer, &schema).map_err(from_arrow_error)?;
+            for batch in &entry.batches {
+                writer.write(batch).map_err(from_arrow_error)?;
+            }
+            writer.finish().map_err(from_arrow_error)
+        })
+    }
+
+    /// Reads all persisted properties.
+    pub fn read_properties(&self) -> Result<Vec<(PropertyId, PropertyEntry)>, Status> {
+        let mut properties = Vec::new();
+        for graph_dir in fs::read_dir(self.data_dir.join("properties")).map_err(from_io_error)? {
+            for file in
+                fs::read_dir(graph_dir.map_err(from_io_error)?.path()).map_err(from_io_error)?
+            {
+                let path = file.map_err(from_io_error)?.path();
+                if path.extension().map_or(true, |ext| ext != "arrow") {
+                    continue;
+                }
+                properties.push(read_property(&path)?);
+            }
+        }
+        Ok(properties)
+    }
+
+    fn graph_dir(&self, graph_name: &str) -> PathBuf {
+        self.data_dir.join("graphs").join(encode(graph_name))
+    }
+
+    fn property_dir(&self, graph_name: &str) -> PathBuf {
+        self.data_dir.join("properties").join(encode(graph_name))
+    }
+}
+
+fn read_property(path: &Path) -> Result<(PropertyId, PropertyEntry), Status> {
+    let reader = BufReader::new(File::open(path).map_err(from_io_error)?);
+    let reader = FileReader::try_new(reader, None).map_err(from_arrow_error)?;
+
+    let metadata = reader.schema().metadata().clone();
+    let (Some(graph_name), Some(property_key)) =
+        (metadata.get(GRAPH_NAME_KEY), metadata.get(PROPERTY_KEY_KEY))
+    else {
+        return Err(Status::internal(format!(
+            "Missing property id in snapshot {path:?}"
+        )));
+    };
+    let property_id = PropertyId::new(graph_name.clone(), property_key.clone());
+
+    let schema = Arc::new(Schema::new(reader.schema().fields().clone()));
+    let batches = reader
+        .map(|batch| RecordBatch::try_new(schema.clone(), batch?.columns().to_vec()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(from_arrow_error)?;
+
+    Ok((property_id, PropertyEntry::new(schema, batches)))
+}
+
+/// Writes to a temporary file first, so that readers never see partial files.
+fn write_atomically<F>(path: &Path, write: F) -> Result<(), Status>
+where
+    F: FnOnce(&mut BufWriter<File>) -> Result<(), Status>,
+{
+    let tmp_path = path.with_extension("tmp");
+    let mut writer = BufWriter::new(File::create(&tmp_path).map_err(from_io_error)?);
+    write(&mut writer)?;
+    writer.flush().map_err(from_io_error)?;
+    fs::rename(tmp_path, path).map_err(from_io_error)
+}
+
+fn remove_file_if_exists(path: &Path) -> Result<(), Status> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(from_io_error(e)),
+        _ => Ok(()),
+    }
+}
+
+fn remove_dir_if_exists(path: &Path) -> Result<(), Status> {
+    match fs::remove_dir_all(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(from_io_error(e)),
+        _ => Ok(()),
+    }
+}
+
+fn encode(name: &str) -> String {
+    name.bytes().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_io_error(error: std::io::Error) -> Status {
+    Status::internal(format!("IoError: {error:?}"))
+}
+
+fn from_arrow_error(error: arrow::error::ArrowError) -> Status {
+    Status::internal(format!("ArrowError: {error:?}"))
+}
+
+fn from_graph_error(error: graph::prelude::Error) -> Status {
+    Status::internal(format!("GraphError: {error:?}"))
+}
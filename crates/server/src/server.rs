@@ -1,33 +1,53 @@
 use crate::actions::*;
+use crate::auth::Auth;
 use crate::catalog::*;
+use crate::export;
+use crate::jobs::{ComputeTracker, JobRegistry};
+use crate::metrics::{CatalogGauges, Metrics};
+use crate::persistence::SnapshotStore;
 
-use std::borrow::Cow;
 use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
+use arrow::array::{new_null_array, Array, UInt64Array};
+use arrow::compute::{cast, concat, take};
+use arrow::datatypes::DataType;
+use arrow::datatypes::Field;
 use arrow::datatypes::Float32Type;
 use arrow::datatypes::Int64Type;
 use arrow::datatypes::UInt64Type;
 use arrow::error::ArrowError;
 use arrow::ipc::writer;
+use arrow::record_batch::RecordBatch;
 use arrow::{datatypes::Schema, ipc::writer::IpcWriteOptions};
 use arrow_flight::utils::flight_data_to_arrow_batch;
 use arrow_flight::{
-    flight_service_server::FlightService, Action, ActionType, Criteria, Empty, FlightData,
-    FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse, PutResult, SchemaAsIpc,
-    SchemaResult, Ticket,
+    flight_descriptor::DescriptorType, flight_service_server::FlightService, Action, ActionType,
+    Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
+    HandshakeResponse, PutResult, SchemaAsIpc, SchemaResult, Ticket,
 };
 use futures::stream::BoxStream;
 use futures::StreamExt;
 use graph::page_rank::PageRankConfig;
+use graph::prelude::BetweennessConfig;
 use graph::prelude::Components;
 use graph::prelude::DeltaSteppingConfig;
+use graph::prelude::LabelPropagationConfig;
+use graph::prelude::LouvainConfig;
+use graph::prelude::NeighborsWithinOp;
+use graph::prelude::OutNeighborsWithinOp;
+use graph::prelude::PartitionConfig;
+use graph::prelude::ResultSummary;
+use graph::prelude::TopK;
 use graph::prelude::WccConfig;
+use graph_builder::input::object_store::object_url;
 use log::error;
 use log::info;
 use parking_lot::RwLock;
+use serde::Serialize;
 use tonic::{Request, Response, Status, Streaming};
 
 // Used to chunk data into record batches
@@ -38,6 +58,14 @@ pub struct FlightServiceImpl {
     graph_catalog: Arc<RwLock<GraphCatalog>>,
     // Stores algorithm resuts
     property_store: Arc<RwLock<PropertyStore>>,
+    // Stores submitted jobs and their results
+    job_registry: Arc<RwLock<JobRegistry>>,
+    // Collects request and compute metrics
+    metrics: Arc<Metrics>,
+    // Authenticates and authorizes requests, if enabled
+    auth: Option<Auth>,
+    // Directory to which graphs and properties are exported, if enabled
+    export_dir: Option<PathBuf>,
 }
 
 impl FlightServiceImpl {
@@ -45,36 +73,143 @@ impl FlightServiceImpl {
         Self {
             graph_catalog: Arc::new(RwLock::new(GraphCatalog::new())),
             property_store: Arc::new(RwLock::new(PropertyStore::new())),
+            job_registry: Arc::new(RwLock::new(JobRegistry::new())),
+            metrics: Arc::new(Metrics::new()),
+            auth: None,
+            export_dir: None,
         }
     }
-}
 
-impl Default for FlightServiceImpl {
-    fn default() -> Self {
-        Self::new()
+    /// Creates a service that persists graphs and properties in the given
+    /// directory and restores them from previous runs.
+    pub fn with_data_dir<P: Into<PathBuf>>(data_dir: P) -> Result<Self, Status> {
+        let snapshots = SnapshotStore::new(data_dir)?;
+        Ok(Self {
+            graph_catalog: Arc::new(RwLock::new(GraphCatalog::with_snapshots(
+                snapshots.clone(),
+            )?)),
+            property_store: Arc::new(RwLock::new(PropertyStore::with_snapshots(snapshots)?)),
+            job_registry: Arc::new(RwLock::new(JobRegistry::new())),
+            metrics: Arc::new(Metrics::new()),
+            auth: None,
+            export_dir: None,
+        })
     }
-}
 
-pub(crate) type FlightResult<T> = Result<T, Status>;
+    /// Limits the memory occupied by graphs, see [`GraphCatalog::reserve`].
+    pub fn with_memory_limit(self, memory_limit: u64) -> Self {
+        self.graph_catalog
+            .write()
+            .set_memory_limit(Some(memory_limit));
+        self
+    }
 
-#[tonic::async_trait]
-impl FlightService for FlightServiceImpl {
-    type DoActionStream = BoxStream<'static, Result<arrow_flight::Result, Status>>;
-    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
-    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
-    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
-    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
-    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
-    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+    /// Limits the memory occupied by the graphs of a namespace, see
+    /// [`GraphCatalog::reserve`].
+    pub fn with_namespace_quota(self, namespace: String, quota: u64) -> Self {
+        self.graph_catalog
+            .write()
+            .set_namespace_quota(namespace, quota);
+        self
+    }
 
-    async fn do_get(&self, request: Request<Ticket>) -> FlightResult<Response<Self::DoGetStream>> {
-        let property_id = request.into_inner().try_into()?;
+    /// Limits the number of finished jobs whose results are kept, see
+    /// [`JobRegistry::set_max_finished_jobs`].
+    pub fn with_max_finished_jobs(self, max_finished_jobs: usize) -> Self {
+        self.job_registry
+            .write()
+            .set_max_finished_jobs(max_finished_jobs);
+        self
+    }
+
+    /// Enables the `export` action, which writes files to the given directory.
+    pub fn with_export_dir<P: Into<PathBuf>>(mut self, export_dir: P) -> Self {
+        self.export_dir = Some(export_dir.into());
+        self
+    }
+
+    /// Requires all requests to be authenticated and authorized by `auth`.
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    fn authorize<T>(&self, request: &Request<T>, operation: &str) -> Result<(), Status> {
+        match &self.auth {
+            Some(auth) => auth.authorize(request, operation),
+            None => Ok(()),
+        }
+    }
 
-        info!("Received GET request for ticket: {property_id:?}");
+    async fn get_property(&self, ticket: Ticket) -> FlightResult<Vec<FlightData>> {
+        let ticket: GetTicket = ticket.try_into()?;
 
-        let property_store = self.property_store.read();
-        let property_entry = property_store.get(&property_id)?;
+        info!("Received GET request for ticket: {ticket:?}");
 
+        match ticket {
+            GetTicket::Property(ticket) => {
+                let property_store = self.property_store.read();
+                let property_entry = property_store
+                    .get(&ticket.property_id)?
+                    .select(&ticket)
+                    .map_err(from_arrow_err)?;
+                Ok(self.encode(&property_entry))
+            }
+            GetTicket::Stream(ticket) => {
+                // Streamed results are removed once they are fetched.
+                let property_entry = self.property_store.write().take_stream(ticket.stream_id)?;
+                Ok(self.encode(&property_entry))
+            }
+            GetTicket::Topology(ticket) => {
+                let graph_catalog = Arc::clone(&self.graph_catalog);
+                let edges = tokio::task::spawn_blocking(move || {
+                    let catalog = GraphCatalog::read_loaded(&graph_catalog, &ticket.graph_name)?;
+                    catalog
+                        .get(&ticket.graph_name)?
+                        .edges()
+                        .map_err(from_arrow_err)
+                })
+                .await
+                .map_err(from_join_error)??;
+                Ok(self.encode(&edges))
+            }
+        }
+    }
+
+    /// Describes the data selected by the path without materializing it.
+    fn flight_info(&self, path: FlightPath) -> FlightResult<FlightInfo> {
+        let (schema, total_records, total_bytes) = match &path {
+            FlightPath::Edges(graph_name) => {
+                let info = self.graph_catalog.read().info(graph_name)?;
+                let schema = GraphType::edge_schema(info.is_weighted());
+                // The size of the encoded edges is unknown.
+                (schema, info.edge_count() as i64, -1)
+            }
+            FlightPath::Property(property_id) => {
+                let property_store = self.property_store.read();
+                let entry = property_store.get(property_id)?;
+                let total_records = entry
+                    .batches
+                    .iter()
+                    .map(|batch| batch.num_rows() as i64)
+                    .sum();
+                let schema = entry.schema.as_ref().clone();
+                (schema, total_records, entry.size_in_bytes() as i64)
+            }
+        };
+
+        let endpoint = FlightEndpoint::new().with_ticket(path.ticket()?);
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(from_arrow_err)?
+            .with_endpoint(endpoint)
+            .with_descriptor(path.descriptor())
+            .with_total_records(total_records)
+            .with_total_bytes(total_bytes);
+        Ok(info)
+    }
+
+    fn encode(&self, property_entry: &PropertyEntry) -> Vec<FlightData> {
         let ipc_write_options = IpcWriteOptions::default();
         // Record batches are pre-computed and are immediately available.
         // Imho, there is no need to implement lazy batch computation.
@@ -90,8 +225,7 @@ impl FlightService for FlightServiceImpl {
                     .expect("DictionaryTracker configured above to not error on replacement");
                 encoded_batch.into()
             })
-            .map(Ok)
-            .collect::<Vec<_>>();
+            .collect::<Vec<FlightData>>();
 
         info!(
             "Streaming {} record batches to the client",
@@ -101,24 +235,24 @@ impl FlightService for FlightServiceImpl {
         let schema_ipc = SchemaAsIpc::new(&property_entry.schema, &ipc_write_options);
         let schema_flight_data = FlightData::from(schema_ipc);
 
-        let batches = std::iter::once(Ok(schema_flight_data)).chain(record_batches);
+        let streamed_bytes = record_batches
+            .iter()
+            .map(|data| data.data_header.len() + data.data_body.len())
+            .sum::<usize>();
+        self.metrics.record_streamed_bytes(streamed_bytes as u64);
 
-        Ok(Response::new(Box::pin(futures::stream::iter(batches))))
+        std::iter::once(schema_flight_data)
+            .chain(record_batches)
+            .collect()
     }
 
-    async fn do_put(
-        &self,
-        request: Request<Streaming<FlightData>>,
-    ) -> FlightResult<Response<Self::DoPutStream>> {
-        let mut request = request.into_inner();
-        let mut schema_flight_data = request.next().await.unwrap()?;
+    async fn put(&self, mut request: Streaming<FlightData>) -> FlightResult<PutResult> {
+        let mut schema_flight_data = request
+            .next()
+            .await
+            .ok_or_else(|| Status::invalid_argument("Missing schema message"))??;
 
-        let CreateGraphCommand {
-            graph_name,
-            edge_count,
-            csr_layout,
-            orientation,
-        } = if let Some(descriptor) = schema_flight_data.flight_descriptor.take() {
+        let command = if let Some(descriptor) = schema_flight_data.flight_descriptor.take() {
             let command = descriptor.try_into();
             info!("Received PUT request with command: {command:?}");
             command
@@ -127,50 +261,227 @@ impl FlightService for FlightServiceImpl {
         }?;
 
         let schema = Arc::new(Schema::try_from(&schema_flight_data).map_err(from_arrow_err)?);
-        info!("Reading graph from schema = {schema:?}");
+        info!("Reading batches with schema = {schema:?}");
 
-        // all the remaining stream messages should be dictionary and record batches
-        let start = Instant::now();
-        let dicts = HashMap::new();
-        let mut edge_list = Vec::with_capacity(edge_count as usize);
-        while let Some(flight_data) = request.message().await? {
-            let batch = flight_data_to_arrow_batch(&flight_data, schema.clone(), &dicts)
-                .map_err(from_arrow_err)?;
+        match command {
+            PutCommand::CreateGraph(command) => {
+                check_edge_schema(&schema, &command.schema())?;
+                put_graph(command, schema, request, Arc::clone(&self.graph_catalog)).await
+            }
+            PutCommand::NodeProperties(command) => {
+                put_node_properties(
+                    command,
+                    schema,
+                    request,
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+        }
+    }
 
-            let source_ids = arrow::array::as_primitive_array::<Int64Type>(batch.column(0));
-            let target_ids = arrow::array::as_primitive_array::<Int64Type>(batch.column(1));
+    async fn run_action(&self, action: Action) -> FlightResult<arrow_flight::Result> {
+        let action: FlightAction = action.try_into()?;
 
-            let batch = source_ids
-                .iter()
-                .zip(target_ids.iter())
-                .map(|(s, t)| (s.unwrap() as u64, t.unwrap() as u64));
+        info!("Received ACTION {action:?}");
 
-            edge_list.extend(batch);
+        match action {
+            FlightAction::Pipeline(config) => self.run_pipeline(config).await,
+            action => self.run_step(action).await,
         }
+    }
 
-        let graph = tokio::task::spawn_blocking(move || {
-            GraphType::from_edge_list(edge_list, orientation, csr_layout)
-        })
-        .await
-        .unwrap();
+    async fn run_step(&self, action: FlightAction) -> FlightResult<arrow_flight::Result> {
+        match action {
+            FlightAction::Create(config) => {
+                create_graph(config, Arc::clone(&self.graph_catalog)).await
+            }
+            FlightAction::List(config) => {
+                list_graphs(
+                    config,
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+            FlightAction::Remove(config) => {
+                remove_graph(
+                    config,
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+            FlightAction::RemoveNamespace(config) => {
+                remove_namespace(
+                    config,
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+            FlightAction::ToRelabeled(config) => {
+                to_relabeled_graph(
+                    config,
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+            FlightAction::ToUndirected(config) => {
+                to_undirected_graph(config, Arc::clone(&self.graph_catalog)).await
+            }
+            FlightAction::Project(config) => {
+                project_graph(
+                    config,
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+            FlightAction::Export(config) => {
+                export_graph(
+                    config,
+                    self.export_dir.clone(),
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+            FlightAction::Stats(config) => {
+                graph_stats(
+                    config,
+                    Arc::clone(&self.graph_catalog),
+                    Arc::clone(&self.property_store),
+                )
+                .await
+            }
+            FlightAction::Compute(config) => {
+                let graph_name = config.graph_name.clone();
+                let (_guard, cancelled) =
+                    JobRegistry::track(&self.job_registry, graph_name.clone());
+                tokio::select! {
+                    result = compute(
+                        config,
+                        Arc::new(ComputeTracker::new()),
+                        Arc::clone(&self.graph_catalog),
+                        Arc::clone(&self.property_store),
+                        Arc::clone(&self.metrics),
+                    ) => result,
+                    _ = cancelled => Err(Status::cancelled(format!(
+                        "Computation on graph '{graph_name}' has been cancelled"
+                    ))),
+                }
+            }
+            FlightAction::Submit(config) => submit_job(
+                config,
+                Arc::clone(&self.graph_catalog),
+                Arc::clone(&self.property_store),
+                Arc::clone(&self.job_registry),
+                Arc::clone(&self.metrics),
+            ),
+            FlightAction::Status(config) => {
+                into_flight_result(self.job_registry.read().status(config.job_id)?)
+            }
+            FlightAction::Result(config) => self.job_registry.read().result(config.job_id),
+            FlightAction::Cancel(CancelConfig::Job { job_id }) => {
+                into_flight_result(self.job_registry.write().cancel(job_id)?)
+            }
+            FlightAction::Cancel(CancelConfig::Graph { graph_name }) => {
+                let result = self.job_registry.write().cancel_graph(&graph_name);
+                info!("Cancelled computations: {result:?}");
+                into_flight_result(result)
+            }
+            FlightAction::Pipeline(_) => {
+                Err(Status::invalid_argument("Pipelines cannot be nested"))
+            }
+            FlightAction::Metrics => {
+                let gauges = {
+                    let graph_catalog = self.graph_catalog.read();
+                    let property_store = self.property_store.read();
+                    CatalogGauges {
+                        graph_count: graph_catalog.graph_count(),
+                        graph_bytes: graph_catalog.used_bytes(),
+                        property_count: property_store.property_count(),
+                        property_bytes: property_store.used_bytes(),
+                    }
+                };
+                Ok(arrow_flight::Result {
+                    body: self.metrics.render(gauges).into_bytes().into(),
+                })
+            }
+        }
+    }
 
-        let result = CreateActionResult::new(
-            graph.node_count(),
-            graph.edge_count(),
-            start.elapsed().as_millis(),
-        );
+    /// Runs the steps of a pipeline in order and skips all steps after the
+    /// first failed step.
+    async fn run_pipeline(&self, config: PipelineConfig) -> FlightResult<arrow_flight::Result> {
+        let start = Instant::now();
+        let mut steps = Vec::with_capacity(config.steps.len());
+        let mut failed = false;
 
-        info!("Created graph '{graph_name}': {result:?}");
+        for step in config.steps {
+            if failed {
+                steps.push(StepResult::skipped(step.action_type));
+                continue;
+            }
 
-        self.graph_catalog
-            .write()
-            .insert(Cow::from(graph_name), graph);
+            let action_type = step.action_type.clone();
+            let result = match step.into_action().and_then(FlightAction::try_from) {
+                Ok(action) => self.run_step(action).await,
+                Err(status) => Err(status),
+            };
+            failed = result.is_err();
+            steps.push(StepResult::new(action_type, result));
+        }
 
-        let result = serde_json::to_vec(&result).map_err(from_json_error)?;
-        let result = arrow_flight::PutResult {
-            app_metadata: result.into(),
-        };
+        let result = PipelineResult::new(steps, start.elapsed().as_millis());
+        info!("Done running pipeline: {result:?}");
+        into_flight_result(result)
+    }
+}
+
+impl Default for FlightServiceImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) type FlightResult<T> = Result<T, Status>;
+
+#[tonic::async_trait]
+impl FlightService for FlightServiceImpl {
+    type DoActionStream = BoxStream<'static, Result<arrow_flight::Result, Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+
+    async fn do_get(&self, request: Request<Ticket>) -> FlightResult<Response<Self::DoGetStream>> {
+        self.authorize(&request, "do_get")?;
+        let start = Instant::now();
+        let result = self.get_property(request.into_inner()).await;
+        self.metrics
+            .record_request("do_get", start.elapsed(), result.is_ok());
+
+        let batches = result?.into_iter().map(Ok);
+        Ok(Response::new(Box::pin(futures::stream::iter(batches))))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> FlightResult<Response<Self::DoPutStream>> {
+        self.authorize(&request, "do_put")?;
+        let start = Instant::now();
+        let result = self.put(request.into_inner()).await;
+        self.metrics
+            .record_request("do_put", start.elapsed(), result.is_ok());
 
+        let result = result?;
         Ok(Response::new(Box::pin(futures::stream::once(async {
             Ok(result)
         }))))
@@ -178,8 +489,9 @@ impl FlightService for FlightServiceImpl {
 
     async fn list_actions(
         &self,
-        _request: Request<Empty>,
+        request: Request<Empty>,
     ) -> FlightResult<Response<Self::ListActionsStream>> {
+        self.authorize(&request, "list_actions")?;
         let actions = futures::stream::iter(FlightAction::action_types().into_iter().map(Ok));
         Ok(Response::new(Box::pin(actions)))
     }
@@ -188,70 +500,31 @@ impl FlightService for FlightServiceImpl {
         &self,
         request: Request<Action>,
     ) -> FlightResult<Response<Self::DoActionStream>> {
-        let action = request.into_inner();
-        let action: FlightAction = action.try_into()?;
-
-        info!("Received ACTION {action:?}");
-
-        let result = match action {
-            FlightAction::Create(config) => {
-                create_graph(config, Arc::clone(&self.graph_catalog)).await?
-            }
-            FlightAction::List => list_graphs(Arc::clone(&self.graph_catalog)).await?,
-            FlightAction::Remove(config) => {
-                remove_graph(config, Arc::clone(&self.graph_catalog)).await?
-            }
-            FlightAction::ToRelabeled(config) => {
-                to_relabeled_graph(config, Arc::clone(&self.graph_catalog)).await?
-            }
-            FlightAction::ToUndirected(config) => {
-                to_undirected_graph(config, Arc::clone(&self.graph_catalog)).await?
-            }
-            FlightAction::Compute(config) => {
-                let ComputeConfig {
-                    graph_name,
-                    algorithm,
-                    property_key,
-                } = config;
-
-                match algorithm {
-                    Algorithm::PageRank(config) => {
-                        compute_page_rank(
-                            config,
-                            Arc::clone(&self.graph_catalog),
-                            Arc::clone(&self.property_store),
-                            graph_name,
-                            property_key,
-                        )
-                        .await?
-                    }
-                    Algorithm::TriangleCount => {
-                        compute_triangle_count(Arc::clone(&self.graph_catalog), graph_name).await?
-                    }
-                    Algorithm::Sssp(config) => {
-                        compute_sssp(
-                            config,
-                            Arc::clone(&self.graph_catalog),
-                            Arc::clone(&self.property_store),
-                            graph_name,
-                            property_key,
-                        )
-                        .await?
-                    }
-                    Algorithm::Wcc(config) => {
-                        compute_wcc(
-                            config,
-                            Arc::clone(&self.graph_catalog),
-                            Arc::clone(&self.property_store),
-                            graph_name,
-                            property_key,
-                        )
-                        .await?
-                    }
-                }
+        self.authorize(&request, &request.get_ref().r#type)?;
+        // Every step of a pipeline requires the permission of its own action.
+        if request.get_ref().r#type == "pipeline" {
+            let config = PipelineConfig::try_from(request.get_ref().clone())?;
+            for step in &config.steps {
+                self.authorize(&request, &step.action_type)?;
             }
+        }
+        let action = request.into_inner();
+        // Unknown action types are not recorded individually to bound the number of metrics.
+        let operation = if FlightAction::action_types()
+            .iter()
+            .any(|action_type| action_type.r#type == action.r#type)
+        {
+            action.r#type.clone()
+        } else {
+            "unknown".to_string()
         };
 
+        let start = Instant::now();
+        let result = self.run_action(action).await;
+        self.metrics
+            .record_request(&operation, start.elapsed(), result.is_ok());
+
+        let result = result?;
         Ok(Response::new(Box::pin(futures::stream::once(async {
             Ok(result)
         }))))
@@ -259,30 +532,90 @@ impl FlightService for FlightServiceImpl {
 
     async fn handshake(
         &self,
-        _request: Request<Streaming<HandshakeRequest>>,
+        request: Request<Streaming<HandshakeRequest>>,
     ) -> FlightResult<Response<Self::HandshakeStream>> {
-        Err(Status::unimplemented("Not yet implemented"))
+        let handshake = request
+            .into_inner()
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("Missing handshake request"))?;
+
+        // The payload is the token, which the client sends as bearer token
+        // in subsequent requests.
+        let token = String::from_utf8(handshake.payload.to_vec())
+            .map_err(|_| Status::invalid_argument("Token must be valid UTF-8"))?;
+        if let Some(auth) = &self.auth {
+            auth.authenticate_token(&token)?;
+        }
+
+        let response = HandshakeResponse {
+            protocol_version: handshake.protocol_version,
+            payload: token.into_bytes().into(),
+        };
+
+        Ok(Response::new(Box::pin(futures::stream::once(async {
+            Ok(response)
+        }))))
     }
 
     async fn list_flights(
         &self,
-        _request: Request<Criteria>,
+        request: Request<Criteria>,
     ) -> FlightResult<Response<Self::ListFlightsStream>> {
-        Err(Status::unimplemented("Not yet implemented"))
+        self.authorize(&request, "list_flights")?;
+        // A non-empty criteria expression selects a namespace.
+        let namespace = String::from_utf8(request.into_inner().expression.to_vec())
+            .map_err(|_| Status::invalid_argument("Criteria must be valid UTF-8"))?;
+        let namespace = (!namespace.is_empty()).then_some(namespace.as_str());
+
+        let graph_names = self
+            .graph_catalog
+            .read()
+            .list(namespace)
+            .into_iter()
+            .map(|info| info.graph_name().to_string());
+        let property_ids = self.property_store.read().property_ids(namespace);
+        let paths = graph_names
+            .map(FlightPath::Edges)
+            .chain(property_ids.into_iter().map(FlightPath::Property));
+
+        // Graphs and properties that are removed concurrently are skipped.
+        let infos = paths
+            .filter_map(|path| self.flight_info(path).ok())
+            .map(Ok)
+            .collect::<Vec<_>>();
+        Ok(Response::new(Box::pin(futures::stream::iter(infos))))
     }
 
     async fn get_flight_info(
         &self,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> FlightResult<Response<FlightInfo>> {
-        Err(Status::unimplemented("Not yet implemented"))
+        self.authorize(&request, "get_flight_info")?;
+        let path = FlightPath::try_from(request.get_ref())?;
+        Ok(Response::new(self.flight_info(path)?))
     }
 
     async fn get_schema(
         &self,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> FlightResult<Response<SchemaResult>> {
-        Err(Status::unimplemented("Not yet implemented"))
+        self.authorize(&request, "get_schema")?;
+        let descriptor = request.into_inner();
+        let schema_result = if descriptor.r#type == DescriptorType::Path as i32 {
+            // Advertises the schema of the selected edges or property.
+            SchemaResult {
+                schema: self.flight_info(FlightPath::try_from(&descriptor)?)?.schema,
+            }
+        } else {
+            // Advertises the schema accepted by do_put for the given command.
+            let command: PutCommand = descriptor.try_into()?;
+            SchemaAsIpc::new(&command.schema(), &IpcWriteOptions::default())
+                .try_into()
+                .map_err(from_arrow_err)?
+        };
+
+        Ok(Response::new(schema_result))
     }
 
     async fn do_exchange(
@@ -293,284 +626,1981 @@ impl FlightService for FlightServiceImpl {
     }
 }
 
-async fn create_graph(
-    config: CreateGraphFromFileConfig,
+async fn put_graph(
+    command: CreateGraphCommand,
+    schema: Arc<Schema>,
+    mut request: Streaming<FlightData>,
     graph_catalog: Arc<RwLock<GraphCatalog>>,
-) -> FlightResult<arrow_flight::Result> {
-    let CreateGraphFromFileConfig {
+) -> FlightResult<PutResult> {
+    let CreateGraphCommand {
         graph_name,
-        file_format,
-        path,
+        edge_count,
         csr_layout,
         orientation,
-    } = config;
+        weighted,
+    } = command;
+
+    let estimated_bytes = GraphType::estimate_size_in_bytes(
+        edge_count as u64,
+        edge_count as u64,
+        orientation,
+        weighted,
+    );
+    let reservation = GraphCatalog::reserve(&graph_catalog, &graph_name, estimated_bytes).await?;
 
+    // all the remaining stream messages should be dictionary and record batches
     let start = Instant::now();
+    let dicts = HashMap::new();
+    // The declared edge count is not trusted, so the edges are not pre-allocated.
+    let mut edge_list = Vec::new();
+    let mut weights = Vec::new();
+    while let Some(flight_data) = request.message().await? {
+        let batch = flight_data_to_arrow_batch(&flight_data, schema.clone(), &dicts)
+            .map_err(from_arrow_err)?;
+        read_edges(&batch, &mut edge_list, weighted.then_some(&mut weights))?;
+    }
+
     let graph = tokio::task::spawn_blocking(move || {
-        GraphType::from_file(path, file_format, orientation, csr_layout)
+        if weighted {
+            let edge_list = edge_list
+                .into_iter()
+                .zip(weights)
+                .map(|((s, t), w)| (s, t, w))
+                .collect();
+            GraphType::from_edge_list_with_weights(edge_list, orientation, csr_layout)
+        } else {
+            GraphType::from_edge_list(edge_list, orientation, csr_layout)
+        }
     })
     .await
-    .unwrap()?;
+    .map_err(from_join_error)?;
+
+    // The reservation is based on the declared edge count, so the actual size
+    // of the graph is reserved as well if it is larger.
+    let additional_bytes = graph.size_in_bytes().saturating_sub(estimated_bytes);
+    let _additional_reservation = if additional_bytes > 0 {
+        Some(GraphCatalog::reserve(&graph_catalog, &graph_name, additional_bytes).await?)
+    } else {
+        None
+    };
 
     let result = CreateActionResult::new(
         graph.node_count(),
         graph.edge_count(),
         start.elapsed().as_millis(),
     );
-    graph_catalog.write().insert(Cow::from(&graph_name), graph);
-    info!("Done creating graph '{graph_name}': {result:?}");
-    into_flight_result(result)
-}
 
-async fn list_graphs(
-    graph_catalog: Arc<RwLock<GraphCatalog>>,
-) -> FlightResult<arrow_flight::Result> {
-    let graph_infos = graph_catalog.read().list();
-    let result = ListActionResult::new(graph_infos);
-    into_flight_result(result)
+    info!("Created graph '{graph_name}': {result:?}");
+
+    reservation.insert(graph_name, graph).await?;
+
+    into_put_result(result)
 }
 
-async fn remove_graph(
-    config: RemoveGraphConfig,
-    graph_catalog: Arc<RwLock<GraphCatalog>>,
-) -> FlightResult<arrow_flight::Result> {
-    let result = graph_catalog.write().remove(config.graph_name)?;
-    into_flight_result(result)
+/// Checks that the columns of the edge batches have the types of the expected
+/// schema, see [`CreateGraphCommand::schema`].
+fn check_edge_schema(schema: &Schema, expected_schema: &Schema) -> Result<(), Status> {
+    let matches = schema.fields().len() == expected_schema.fields().len()
+        && schema
+            .fields()
+            .iter()
+            .zip(expected_schema.fields().iter())
+            .all(|(field, expected)| field.data_type() == expected.data_type());
+    if matches {
+        Ok(())
+    } else {
+        Err(Status::invalid_argument(format!(
+            "Expected schema {expected_schema:?}, got {schema:?}"
+        )))
+    }
 }
 
-async fn to_relabeled_graph(
-    config: ToRelabeledConfig,
-    graph_catalog: Arc<RwLock<GraphCatalog>>,
-) -> FlightResult<arrow_flight::Result> {
-    let ToRelabeledConfig { graph_name } = config;
+/// Appends the edges and, if given, the weights of a batch that has been
+/// checked by [`check_edge_schema`].
+fn read_edges(
+    batch: &RecordBatch,
+    edge_list: &mut Vec<(u64, u64)>,
+    weights: Option<&mut Vec<f32>>,
+) -> Result<(), Status> {
+    if batch.columns().iter().any(|column| column.null_count() > 0) {
+        return Err(Status::invalid_argument("Edges must not contain nulls"));
+    }
 
-    let result = tokio::task::spawn_blocking(move || {
-        let mut catalog = graph_catalog.write();
-        let graph = catalog.get_mut(graph_name)?;
-        if let GraphType::Undirected(graph) = graph {
-            use graph::prelude::RelabelByDegreeOp;
-            let start = Instant::now();
-            graph.make_degree_ordered();
-            Ok(ToRelabeledResult {
-                to_relabeled_millis: start.elapsed().as_millis(),
-            })
-        } else {
-            Err(Status::invalid_argument(
-                "Relabelling directed graphs is not supported.",
-            ))
-        }
-    })
-    .await
-    .unwrap()?;
+    let source_ids = arrow::array::as_primitive_array::<Int64Type>(batch.column(0));
+    let target_ids = arrow::array::as_primitive_array::<Int64Type>(batch.column(1));
+    let node_id = |id: i64| {
+        u64::try_from(id).map_err(|_| {
+            Status::invalid_argument(format!("Node ids must not be negative, got {id}"))
+        })
+    };
+    for (&source, &target) in source_ids.values().iter().zip(target_ids.values().iter()) {
+        edge_list.push((node_id(source)?, node_id(target)?));
+    }
 
-    info!("Done relabeling graph: {result:?}");
-    into_flight_result(result)
+    if let Some(weights) = weights {
+        let weight_column = arrow::array::as_primitive_array::<Float32Type>(batch.column(2));
+        weights.extend_from_slice(weight_column.values());
+    }
+
+    Ok(())
 }
 
-async fn to_undirected_graph(
-    config: ToUndirectedConfig,
+async fn put_node_properties(
+    command: NodePropertiesCommand,
+    schema: Arc<Schema>,
+    mut request: Streaming<FlightData>,
     graph_catalog: Arc<RwLock<GraphCatalog>>,
-) -> FlightResult<arrow_flight::Result> {
-    let ToUndirectedConfig {
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<PutResult> {
+    let NodePropertiesCommand {
         graph_name,
-        csr_layout,
-    } = config;
-
-    let result = tokio::task::spawn_blocking(move || -> Result<ToUndirectedResult, Status> {
-        use graph::prelude::ToUndirectedOp;
-        let mut catalog = graph_catalog.write();
-        let graph_type = catalog.get_mut(graph_name.as_str())?;
-        let csr_layout = Some(csr_layout);
-        let start = Instant::now();
+        property_key,
+    } = command;
+
+    let node_count = graph_catalog.read().info(&graph_name)?.node_count();
+
+    let has_node_ids = schema
+        .fields()
+        .first()
+        .map_or(false, |field| field.name() == "node_id");
+    let value_fields = schema.fields()[usize::from(has_node_ids)..].to_vec();
+    let property_keys = match (property_key, value_fields.len()) {
+        (_, 0) => {
+            return Err(Status::invalid_argument(
+                "Expected at least one value column",
+            ))
+        }
+        (Some(property_key), 1) => vec![property_key],
+        (Some(_), _) => {
+            return Err(Status::invalid_argument(
+                "A property key can only be given for a single value column",
+            ))
+        }
+        (None, _) => value_fields
+            .iter()
+            .map(|field| field.name().clone())
+            .collect(),
+    };
 
-        match graph_type {
-            GraphType::Directed(graph) => {
-                *graph_type = GraphType::Undirected(graph.to_undirected(csr_layout))
+    let start = Instant::now();
+    let dicts = HashMap::new();
+    let mut node_ids = Vec::new();
+    let mut seen = vec![false; if has_node_ids { node_count as usize } else { 0 }];
+    let mut row_count = 0;
+    let mut value_columns = vec![Vec::new(); value_fields.len()];
+    while let Some(flight_data) = request.message().await? {
+        let batch = flight_data_to_arrow_batch(&flight_data, schema.clone(), &dicts)
+            .map_err(from_arrow_err)?;
+        row_count += batch.num_rows() as u64;
+
+        if has_node_ids {
+            let ids = cast(batch.column(0), &DataType::UInt64).map_err(from_arrow_err)?;
+            for id in arrow::array::as_primitive_array::<UInt64Type>(&ids).iter() {
+                match id {
+                    Some(id) if id < node_count => {
+                        if std::mem::replace(&mut seen[id as usize], true) {
+                            return Err(Status::invalid_argument(format!(
+                                "Node id {id} occurs more than once"
+                            )));
+                        }
+                        node_ids.push(id);
+                    }
+                    _ => {
+                        return Err(Status::invalid_argument(format!(
+                            "Node id {id:?} is not contained in graph '{graph_name}'"
+                        )))
+                    }
+                }
             }
-            GraphType::DirectedWeighted(graph) => {
+        } else if row_count > node_count {
+            return Err(Status::invalid_argument(format!(
+                "Expected one row per node, but graph '{graph_name}' has only {node_count} nodes"
+            )));
+        }
+
+        let values = &batch.columns()[usize::from(has_node_ids)..];
+        for (columns, values) in value_columns.iter_mut().zip(values) {
+            columns.push(Arc::clone(values));
+        }
+    }
+
+    if !has_node_ids && row_count != node_count {
+        return Err(Status::invalid_argument(format!(
+            "Expected one row per node, got {row_count} rows for {node_count} nodes"
+        )));
+    }
+
+    let property_ids = property_keys
+        .into_iter()
+        .map(|property_key| PropertyId::new(graph_name.clone(), property_key))
+        .collect::<Vec<_>>();
+
+    let entries = {
+        let property_ids = property_ids.clone();
+        tokio::task::spawn_blocking(move || -> Result<Vec<PropertyEntry>, ArrowError> {
+            // Nodes without a value map to a null index and thus a null value.
+            let indices = has_node_ids.then(|| {
+                let mut indices = vec![None; node_count as usize];
+                for (row, node_id) in node_ids.into_iter().enumerate() {
+                    indices[node_id as usize] = Some(row as u64);
+                }
+                UInt64Array::from(indices)
+            });
+
+            property_ids
+                .iter()
+                .zip(value_fields.iter())
+                .zip(value_columns)
+                .map(|((property_id, field), value_columns)| {
+                    let data_type = field.data_type();
+                    let values = if value_columns.is_empty() {
+                        new_null_array(data_type, 0)
+                    } else {
+                        let value_columns =
+                            value_columns.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+                        concat(&value_columns)?
+                    };
+                    let values = match &indices {
+                        Some(indices) => take(values.as_ref(), indices, None)?,
+                        None => values,
+                    };
+
+                    let field =
+                        Field::new(property_id.property_key.as_str(), data_type.clone(), true);
+                    let schema = Arc::new(Schema::new(vec![field]));
+
+                    let batches = (0..values.len())
+                        .step_by(CHUNK_SIZE)
+                        .map(|offset| {
+                            let len = usize::min(CHUNK_SIZE, values.len() - offset);
+                            RecordBatch::try_new(schema.clone(), vec![values.slice(offset, len)])
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    Ok(PropertyEntry::new(schema, batches))
+                })
+                .collect()
+        })
+        .await
+        .map_err(from_join_error)?
+        .map_err(from_arrow_err)?
+    };
+
+    {
+        let mut property_store = property_store.write();
+        for (property_id, entry) in property_ids.iter().zip(entries) {
+            property_store.insert(property_id.clone(), entry)?;
+        }
+    }
+
+    let result = NodePropertiesResult::new(property_ids, row_count, start.elapsed().as_millis());
+    info!("Done attaching node properties: {result:?}");
+    into_put_result(result)
+}
+
+async fn compute(
+    config: ComputeConfig,
+    tracker: Arc<ComputeTracker>,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    metrics: Arc<Metrics>,
+) -> FlightResult<arrow_flight::Result> {
+    let ComputeConfig {
+        graph_name,
+        algorithm,
+        property_key,
+        mode,
+        timeout_millis,
+        post_processing,
+    } = config;
+
+    if matches!(mode, ComputeMode::Mutate) && property_key.is_empty() {
+        return Err(Status::invalid_argument(
+            "A property key is required in mutate mode",
+        ));
+    }
+
+    let algorithm_name = algorithm.name();
+    if post_processing.normalization.is_some() && !algorithm.has_scores() {
+        return Err(Status::invalid_argument(format!(
+            "Normalization is not supported for {algorithm_name}"
+        )));
+    }
+    if post_processing.summary && !algorithm.has_summary() {
+        return Err(Status::invalid_argument(format!(
+            "Summaries are not supported for {algorithm_name}"
+        )));
+    }
+
+    // The algorithm keeps running on the blocking thread pool when this future
+    // is dropped, e.g., on timeout or cancellation, unless it is told to stop.
+    let _cancel_on_drop = tracker.cancel_on_drop();
+
+    let start = Instant::now();
+    let computation = async move {
+        match algorithm {
+            Algorithm::PageRank(config) => {
+                compute_page_rank(
+                    config,
+                    tracker,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing,
+                )
+                .await
+            }
+            Algorithm::TriangleCount => compute_triangle_count(graph_catalog, graph_name).await,
+            Algorithm::Sssp(config) => {
+                compute_sssp(
+                    config,
+                    tracker,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing,
+                )
+                .await
+            }
+            Algorithm::Wcc(config) => {
+                compute_wcc(
+                    config,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing.summary,
+                )
+                .await
+            }
+            Algorithm::Partition(config) => {
+                compute_partition(
+                    config,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                )
+                .await
+            }
+            Algorithm::Louvain(config) => {
+                compute_louvain(
+                    config,
+                    tracker,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing.summary,
+                )
+                .await
+            }
+            Algorithm::LabelPropagation(config) => {
+                compute_label_propagation(
+                    config,
+                    tracker,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing.summary,
+                )
+                .await
+            }
+            Algorithm::Betweenness(config) => {
+                compute_betweenness(
+                    config,
+                    tracker,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing,
+                )
+                .await
+            }
+            Algorithm::KCore => {
+                compute_k_core(
+                    tracker,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                )
+                .await
+            }
+            Algorithm::Bfs(config) => {
+                compute_bfs(
+                    config,
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing,
+                )
+                .await
+            }
+            Algorithm::DegreeCentrality => {
+                compute_degree_centrality(
+                    graph_catalog,
+                    property_store,
+                    graph_name,
+                    property_key,
+                    mode,
+                    post_processing,
+                )
+                .await
+            }
+        }
+    };
+    // Results of timed out computations are discarded before they are stored.
+    let result = match timeout_millis {
+        Some(timeout_millis) => {
+            tokio::time::timeout(Duration::from_millis(timeout_millis), computation)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(Status::deadline_exceeded(format!(
+                        "{algorithm_name} did not finish within {timeout_millis} ms"
+                    )))
+                })
+        }
+        None => computation.await,
+    };
+    metrics.record_computation(algorithm_name, start.elapsed(), result.is_ok());
+    result
+}
+
+fn submit_job(
+    config: ComputeConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    job_registry: Arc<RwLock<JobRegistry>>,
+    metrics: Arc<Metrics>,
+) -> FlightResult<arrow_flight::Result> {
+    // Hold the lock while spawning, so the job cannot finish before it is registered.
+    let mut registry = job_registry.write();
+    let job_id = registry.next_job_id();
+    let graph_name = config.graph_name.clone();
+    let description = format!("{:?}", config.algorithm);
+    let tracker = Arc::new(ComputeTracker::new());
+
+    let handle = tokio::spawn({
+        let job_registry = Arc::clone(&job_registry);
+        let tracker = Arc::clone(&tracker);
+        async move {
+            let result = compute(config, tracker, graph_catalog, property_store, metrics).await;
+            job_registry.write().finish(job_id, result);
+        }
+    });
+
+    let result = registry.submit(job_id, graph_name, description, tracker, handle);
+    info!("Submitted job: {result:?}");
+    into_flight_result(result)
+}
+
+async fn create_graph(
+    config: CreateGraphConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+) -> FlightResult<arrow_flight::Result> {
+    match config {
+        CreateGraphConfig::FromFile(config) => create_graph_from_file(config, graph_catalog).await,
+        CreateGraphConfig::FromGdl(config) => {
+            let CreateGraphFromGdlConfig {
+                graph_name,
+                gdl,
+                csr_layout,
+                orientation,
+                weighted,
+            } = config;
+            create_graph_from_body(graph_name, graph_catalog, None, move || {
+                GraphType::from_gdl(&gdl, orientation, csr_layout, weighted)
+            })
+            .await
+        }
+        CreateGraphConfig::FromEdges(config) => {
+            let CreateGraphFromEdgesConfig {
+                graph_name,
+                edges,
+                csr_layout,
+                orientation,
+            } = config;
+            let estimated_bytes = GraphType::estimate_size_in_bytes(
+                edges.node_count()?,
+                edges.len() as u64,
+                orientation,
+                edges.is_weighted(),
+            );
+            create_graph_from_body(
+                graph_name,
+                graph_catalog,
+                Some(estimated_bytes),
+                move || {
+                    Ok(match edges {
+                        InlineEdges::Unweighted(edges) => {
+                            GraphType::from_edge_list(edges, orientation, csr_layout)
+                        }
+                        InlineEdges::Weighted(edges) => {
+                            GraphType::from_edge_list_with_weights(edges, orientation, csr_layout)
+                        }
+                    })
+                },
+            )
+            .await
+        }
+    }
+}
+
+async fn create_graph_from_file(
+    config: CreateGraphFromFileConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+) -> FlightResult<arrow_flight::Result> {
+    let CreateGraphFromFileConfig {
+        graph_name,
+        file_format,
+        path,
+        csr_layout,
+        orientation,
+    } = config;
+
+    let start = Instant::now();
+    // The size of an object in an object store is only known after it has
+    // been downloaded and decompressed, so memory is reserved after the
+    // graph has been built, using its actual size.
+    let reservation = if object_url(Path::new(&path)).is_some() {
+        None
+    } else {
+        let graph_catalog = Arc::clone(&graph_catalog);
+        let graph_name = graph_name.clone();
+        let path = path.clone();
+        let estimated_bytes = tokio::task::spawn_blocking(move || {
+            GraphType::estimate_file_size_in_bytes(path, file_format, orientation)
+        })
+        .await
+        .map_err(from_join_error)??;
+        Some(GraphCatalog::reserve(&graph_catalog, &graph_name, estimated_bytes).await?)
+    };
+
+    let graph = GraphType::from_file(path, file_format, orientation, csr_layout).await?;
+    let reservation = match reservation {
+        Some(reservation) => reservation,
+        None => GraphCatalog::reserve(&graph_catalog, &graph_name, graph.size_in_bytes()).await?,
+    };
+
+    let result = CreateActionResult::new(
+        graph.node_count(),
+        graph.edge_count(),
+        start.elapsed().as_millis(),
+    );
+    reservation.insert(graph_name.clone(), graph).await?;
+    info!("Done creating graph '{graph_name}': {result:?}");
+    into_flight_result(result)
+}
+
+/// Creates a graph from data carried in the action body.
+///
+/// If the size of the graph can be estimated upfront, memory is reserved
+/// before the graph is built. Otherwise, e.g., for GDL strings, memory is
+/// reserved after the graph has been built, using its actual size.
+async fn create_graph_from_body<F>(
+    graph_name: String,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    estimated_bytes: Option<u64>,
+    build: F,
+) -> FlightResult<arrow_flight::Result>
+where
+    F: FnOnce() -> Result<GraphType, Status> + Send + 'static,
+{
+    let start = Instant::now();
+    let reservation = match estimated_bytes {
+        Some(bytes) => Some(GraphCatalog::reserve(&graph_catalog, &graph_name, bytes).await?),
+        None => None,
+    };
+
+    let graph = tokio::task::spawn_blocking(build)
+        .await
+        .map_err(from_join_error)??;
+    let reservation = match reservation {
+        Some(reservation) => reservation,
+        None => GraphCatalog::reserve(&graph_catalog, &graph_name, graph.size_in_bytes()).await?,
+    };
+
+    let result = CreateActionResult::new(
+        graph.node_count(),
+        graph.edge_count(),
+        start.elapsed().as_millis(),
+    );
+    reservation.insert(graph_name.clone(), graph).await?;
+    info!("Done creating graph '{graph_name}': {result:?}");
+    into_flight_result(result)
+}
+
+async fn list_graphs(
+    config: ListConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<arrow_flight::Result> {
+    let ListConfig { namespace, detail } = config;
+    if detail {
+        // Deriving the layout scans the adjacency lists of all loaded graphs.
+        let graph_details = tokio::task::spawn_blocking(move || {
+            let properties = property_store.read().property_infos(namespace.as_deref());
+            graph_catalog
+                .read()
+                .details(namespace.as_deref(), properties)
+        })
+        .await
+        .map_err(from_join_error)?;
+        into_flight_result(ListActionResult::new(graph_details))
+    } else {
+        let graph_infos = graph_catalog.read().list(namespace.as_deref());
+        into_flight_result(ListActionResult::new(graph_infos))
+    }
+}
+
+async fn remove_graph(
+    config: RemoveGraphConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<arrow_flight::Result> {
+    let RemoveGraphConfig { graph_name } = config;
+
+    let result = remove_graph_and_properties(&graph_name, &graph_catalog, &property_store)?;
+    info!("Done removing graph '{graph_name}': {result:?}");
+    into_flight_result(result)
+}
+
+async fn remove_namespace(
+    config: NamespaceConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<arrow_flight::Result> {
+    let NamespaceConfig { namespace } = config;
+
+    let graph_names = graph_catalog.read().graph_names(&namespace);
+    let removed_graphs = graph_names
+        .iter()
+        .map(|graph_name| remove_graph_and_properties(graph_name, &graph_catalog, &property_store))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let result = RemoveNamespaceResult::new(namespace, removed_graphs);
+    info!("Done removing namespace: {result:?}");
+    into_flight_result(result)
+}
+
+fn remove_graph_and_properties(
+    graph_name: &str,
+    graph_catalog: &RwLock<GraphCatalog>,
+    property_store: &RwLock<PropertyStore>,
+) -> Result<RemoveActionResult, Status> {
+    let (graph_info, graph_bytes) = graph_catalog.write().remove(graph_name)?;
+    let (removed_properties, property_bytes) = property_store.write().remove_graph(graph_name);
+    Ok(RemoveActionResult::new(
+        graph_info,
+        removed_properties,
+        graph_bytes + property_bytes,
+    ))
+}
+
+async fn to_relabeled_graph(
+    config: ToRelabeledConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<arrow_flight::Result> {
+    let ToRelabeledConfig {
+        graph_name,
+        ordering,
+        mapping_property_key,
+    } = config;
+
+    let (mapping, to_relabeled_millis) = {
+        let graph_name = graph_name.clone();
+        let property_store = Arc::clone(&property_store);
+        tokio::task::spawn_blocking(move || {
+            let mut catalog = graph_catalog.write();
+            let start = Instant::now();
+
+            let graph = &*catalog.get_mut(&graph_name)?;
+            let (permutation, mapping) = match ordering {
+                RelabelOrdering::Degree => from_order(graph.degree_order()),
+                RelabelOrdering::Bfs => from_order(graph.bfs_order(false)),
+                RelabelOrdering::Rcm => from_order(graph.bfs_order(true)),
+                RelabelOrdering::Random { seed } => {
+                    use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+                    let mut rng = match seed {
+                        Some(seed) => StdRng::seed_from_u64(seed),
+                        None => StdRng::from_entropy(),
+                    };
+                    let mut order = (0..graph.node_count()).collect::<Vec<_>>();
+                    order.shuffle(&mut rng);
+                    from_order(order)
+                }
+                RelabelOrdering::Custom { property_key } => {
+                    let property_id = PropertyId::new(graph_name.clone(), property_key);
+                    let permutation = read_permutation(property_store.read().get(&property_id)?)?;
+                    from_permutation(permutation, graph.node_count())?
+                }
+            };
+
+            catalog.get_mut(&graph_name)?.relabel(&permutation);
+            let to_relabeled_millis = start.elapsed().as_millis();
+            catalog.persist(&graph_name)?;
+            Ok::<_, Status>((mapping, to_relabeled_millis))
+        })
+        .await
+        .map_err(from_join_error)??
+    };
+
+    let property_id = PropertyId::new(graph_name, mapping_property_key.clone());
+    let record_batches = crate::catalog::to_record_batches(
+        &mapping,
+        mapping_property_key,
+        PhantomData::<UInt64Type>,
+    )
+    .await;
+    property_store
+        .write()
+        .insert(property_id.clone(), record_batches)?;
+
+    let result = ToRelabeledResult {
+        to_relabeled_millis,
+        mapping: property_id,
+    };
+    info!("Done relabeling graph: {result:?}");
+    into_flight_result(result)
+}
+
+/// Turns an order of node ids into a permutation, which maps each node to its
+/// position, and returns both.
+fn from_order(order: Vec<u64>) -> (Vec<u64>, Vec<u64>) {
+    let mut permutation = vec![0; order.len()];
+    for (position, node) in order.iter().enumerate() {
+        permutation[*node as usize] = position as u64;
+    }
+    (permutation, order)
+}
+
+/// Validates a client-supplied permutation and returns it with its inverse.
+fn from_permutation(
+    permutation: Vec<u64>,
+    node_count: u64,
+) -> Result<(Vec<u64>, Vec<u64>), Status> {
+    if permutation.len() as u64 != node_count {
+        return Err(Status::invalid_argument(format!(
+            "Expected a permutation of {node_count} node ids, got {} values",
+            permutation.len()
+        )));
+    }
+    let mut order = vec![None; permutation.len()];
+    for (node, new) in permutation.iter().enumerate() {
+        match order.get_mut(*new as usize) {
+            Some(slot @ None) => *slot = Some(node as u64),
+            Some(Some(_)) => {
+                return Err(Status::invalid_argument(format!(
+                    "Node id {new} is assigned more than once"
+                )))
+            }
+            None => {
+                return Err(Status::invalid_argument(format!(
+                    "Node id {new} is out of range"
+                )))
+            }
+        }
+    }
+    // All slots are set, since there are as many distinct ids as nodes.
+    let order = order.into_iter().flatten().collect();
+    Ok((permutation, order))
+}
+
+fn read_permutation(entry: &PropertyEntry) -> Result<Vec<u64>, Status> {
+    let mut permutation = Vec::new();
+    for batch in &entry.batches {
+        let values = cast(batch.column(0), &DataType::UInt64).map_err(from_arrow_err)?;
+        let values = values
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .expect("values are cast to UInt64");
+        if values.null_count() > 0 {
+            return Err(Status::invalid_argument(
+                "The permutation must not contain null values",
+            ));
+        }
+        permutation.extend(values.values().iter().copied());
+    }
+    Ok(permutation)
+}
+
+async fn to_undirected_graph(
+    config: ToUndirectedConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+) -> FlightResult<arrow_flight::Result> {
+    let ToUndirectedConfig {
+        graph_name,
+        csr_layout,
+    } = config;
+
+    let result = tokio::task::spawn_blocking(move || -> Result<ToUndirectedResult, Status> {
+        use graph::prelude::ToUndirectedOp;
+        let mut catalog = graph_catalog.write();
+        let graph_type = catalog.get_mut(graph_name.as_str())?;
+        let csr_layout = Some(csr_layout);
+        let start = Instant::now();
+
+        match graph_type {
+            GraphType::Directed(graph) => {
+                *graph_type = GraphType::Undirected(graph.to_undirected(csr_layout))
+            }
+            GraphType::DirectedWeighted(graph) => {
                 *graph_type = GraphType::UndirectedWeighted(graph.to_undirected(csr_layout))
             }
             GraphType::Undirected(_) | GraphType::UndirectedWeighted(_) => {
                 info!("Graph {graph_name} is already undirected, skipping transformation.");
+                return Ok(ToUndirectedResult {
+                    to_undirected_millis: start.elapsed().as_millis(),
+                });
             }
         }
 
-        Ok(ToUndirectedResult {
-            to_undirected_millis: start.elapsed().as_millis(),
-        })
+        let result = ToUndirectedResult {
+            to_undirected_millis: start.elapsed().as_millis(),
+        };
+        catalog.persist(&graph_name)?;
+        Ok(result)
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    info!("Done converting graph to undirected: {result:?}");
+    into_flight_result(result)
+}
+
+async fn project_graph(
+    config: ProjectConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<arrow_flight::Result> {
+    let ProjectConfig {
+        graph_name,
+        projected_graph_name,
+        min_degree,
+        max_degree,
+        node_filter,
+    } = config;
+
+    // The projection is at most as large as the original graph.
+    let info = graph_catalog.read().info(&graph_name)?;
+    let estimated_bytes = GraphType::estimate_size_in_bytes(
+        info.node_count(),
+        info.edge_count(),
+        info.orientation(),
+        info.is_weighted(),
+    );
+    let reservation =
+        GraphCatalog::reserve(&graph_catalog, &projected_graph_name, estimated_bytes).await?;
+
+    let start = Instant::now();
+    let (graph, mapping) = {
+        let graph_catalog = Arc::clone(&graph_catalog);
+        let property_store = Arc::clone(&property_store);
+        tokio::task::spawn_blocking(move || {
+            let matching_nodes = match node_filter {
+                Some(NodePropertyFilter {
+                    property_key,
+                    filter,
+                }) => {
+                    let property_id = PropertyId::new(graph_name.clone(), property_key);
+                    let matching_nodes = property_store
+                        .read()
+                        .get(&property_id)?
+                        .matching_nodes(&filter)
+                        .map_err(from_arrow_err)?;
+                    Some(matching_nodes)
+                }
+                None => None,
+            };
+
+            let catalog = GraphCatalog::read_loaded(&graph_catalog, &graph_name)?;
+            let graph = catalog.get(&graph_name)?;
+            graph.induced_subgraph(|node| {
+                let degree = graph.degree(node);
+                min_degree.map_or(true, |min_degree| degree >= min_degree)
+                    && max_degree.map_or(true, |max_degree| degree <= max_degree)
+                    && matching_nodes.as_ref().map_or(true, |matching_nodes| {
+                        matching_nodes.get(node as usize).copied().unwrap_or(false)
+                    })
+            })
+        })
+        .await
+        .map_err(from_join_error)??
+    };
+    let project_millis = start.elapsed().as_millis();
+
+    let node_count = graph.node_count();
+    let edge_count = graph.edge_count();
+    reservation
+        .insert(projected_graph_name.clone(), graph)
+        .await?;
+
+    let property_id = PropertyId::new(projected_graph_name, "original_node_id".to_string());
+    let record_batches =
+        crate::catalog::to_record_batches(&mapping, "original_node_id", PhantomData::<UInt64Type>)
+            .await;
+    property_store
+        .write()
+        .insert(property_id.clone(), record_batches)?;
+
+    let result = ProjectResult::new(node_count, edge_count, project_millis, property_id);
+    info!("Done projecting graph: {result:?}");
+    into_flight_result(result)
+}
+
+async fn export_graph(
+    config: ExportConfig,
+    export_dir: Option<PathBuf>,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<arrow_flight::Result> {
+    let ExportConfig {
+        graph_name,
+        path,
+        format,
+        property_keys,
+    } = config;
+
+    let export_dir = export_dir.ok_or_else(|| {
+        Status::failed_precondition("Exports require the server to be started with --export-dir")
+    })?;
+
+    let result = tokio::task::spawn_blocking(move || -> Result<ExportResult, Status> {
+        let start = Instant::now();
+        let path = export::resolve_path(&export_dir, &path)?;
+        let mut files = Vec::with_capacity(property_keys.len() + 1);
+
+        let size_bytes = {
+            let catalog = GraphCatalog::read_loaded(&graph_catalog, &graph_name)?;
+            let graph = catalog.get(&graph_name)?;
+            export::export_graph(graph, &path, format)?
+        };
+        files.push(ExportedFile::new(path.display().to_string(), size_bytes));
+
+        let property_store = property_store.read();
+        for property_key in property_keys {
+            let property_path = export::property_path(&path, &property_key);
+            export::check_symlinks(&export_dir, &property_path)?;
+            let property_id = PropertyId::new(graph_name.clone(), property_key);
+            let entry = property_store.get(&property_id)?;
+            let size_bytes = export::export_property(entry, &property_path)?;
+            files.push(ExportedFile::new(
+                property_path.display().to_string(),
+                size_bytes,
+            ));
+        }
+
+        Ok(ExportResult::new(files, start.elapsed().as_millis()))
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    info!("Done exporting graph: {result:?}");
+    into_flight_result(result)
+}
+
+async fn graph_stats(
+    config: StatsConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+) -> FlightResult<arrow_flight::Result> {
+    let StatsConfig {
+        graph_name,
+        component_property_key,
+        format,
+    } = config;
+
+    let result = tokio::task::spawn_blocking(move || -> Result<GraphStats, Status> {
+        let start = Instant::now();
+        let (node_count, edge_count, density, histogram) = {
+            let catalog = GraphCatalog::read_loaded(&graph_catalog, &graph_name)?;
+            let graph = catalog.get(&graph_name)?;
+            (
+                graph.node_count(),
+                graph.edge_count(),
+                graph.density(),
+                graph.degree_histogram(),
+            )
+        };
+
+        let mut property_store = property_store.write();
+        let component_count = match component_property_key {
+            Some(property_key) => {
+                let property_id = PropertyId::new(graph_name, property_key);
+                let component_count = property_store
+                    .get(&property_id)?
+                    .distinct_count()
+                    .map_err(from_arrow_err)?;
+                Some(component_count)
+            }
+            None => None,
+        };
+
+        let degree_histogram = histogram
+            .iter()
+            .map(|(degree, count)| DegreeCount::new(*degree, *count))
+            .collect::<Vec<_>>();
+        let stats = GraphStats::new(
+            node_count,
+            edge_count,
+            density,
+            &degree_histogram,
+            start.elapsed().as_millis(),
+        );
+        let stats = match format {
+            StatsFormat::Json => stats.with_degree_histogram(degree_histogram),
+            StatsFormat::Arrow => {
+                let schema = Arc::new(Schema::new(vec![
+                    Field::new("degree", DataType::UInt64, false),
+                    Field::new("count", DataType::UInt64, false),
+                ]));
+                let (degrees, counts): (Vec<_>, Vec<_>) = histogram.into_iter().unzip();
+                let batch = RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(UInt64Array::from(degrees)),
+                        Arc::new(UInt64Array::from(counts)),
+                    ],
+                )
+                .map_err(from_arrow_err)?;
+                let ticket = property_store.insert_stream(PropertyEntry::new(schema, vec![batch]));
+                stats.with_histogram_ticket(ticket)
+            }
+        };
+
+        Ok(match component_count {
+            Some(component_count) => stats.with_component_count(component_count),
+            None => stats,
+        })
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    info!("Done computing graph stats: {result:?}");
+    into_flight_result(result)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn compute_page_rank(
+    config: PageRankConfig,
+    tracker: Arc<ComputeTracker>,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    graph_name: String,
+    property_key: String,
+    mode: ComputeMode,
+    post_processing: PostProcessing,
+) -> FlightResult<arrow_flight::Result> {
+    let catalog_key = graph_name.clone();
+
+    let (ranks, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
+
+        if let GraphType::Directed(graph) = catalog.get(catalog_key)? {
+            let start = Instant::now();
+            let (mut ranks, iterations, error) =
+                graph::page_rank::page_rank_with_progress(graph, config, &*tracker);
+            let top_nodes = node_scores(&config, &ranks);
+            let summary = post_process(&mut ranks, post_processing);
+            let result = PageRankResult {
+                iterations: iterations as u64,
+                error,
+                compute_millis: start.elapsed().as_millis(),
+                top_nodes,
+                summary,
+            };
+            Ok((ranks, result))
+        } else {
+            error!("Attempted running page rank on undirected graph");
+            Err(Status::invalid_argument(
+                "Page Rank requires a directed graph",
+            ))
+        }
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    let record_batches =
+        crate::catalog::to_record_batches(&ranks, "page_rank", PhantomData::<Float32Type>).await;
+
+    info!("Done computing page rank: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
+}
+
+async fn compute_triangle_count(
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    graph_name: String,
+) -> FlightResult<arrow_flight::Result> {
+    let graph_name = graph_name.clone();
+
+    let result = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &graph_name)?;
+        if let GraphType::Undirected(graph) = catalog.get(graph_name)? {
+            let start = Instant::now();
+            let tc = graph::triangle_count::global_triangle_count(graph);
+            let res = TriangleCountResult {
+                triangle_count: tc,
+                compute_millis: start.elapsed().as_millis(),
+            };
+            Ok(res)
+        } else {
+            error!("Attempted running triangle count on directed graph");
+            Err(Status::invalid_argument(
+                "Triangle count requires an undirected graph",
+            ))
+        }
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    info!("Done computing triangle count: {result:?}");
+    into_flight_result(result)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn compute_sssp(
+    config: DeltaSteppingConfig,
+    tracker: Arc<ComputeTracker>,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    graph_name: String,
+    property_key: String,
+    mode: ComputeMode,
+    post_processing: PostProcessing,
+) -> FlightResult<arrow_flight::Result> {
+    let catalog_key = graph_name.clone();
+
+    let (distances, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
+
+        if let GraphType::DirectedWeighted(graph) = catalog.get(catalog_key)? {
+            let start = Instant::now();
+            let distances = graph::sssp::delta_stepping_with_progress(graph, config, &*tracker);
+            let compute_millis = start.elapsed().as_millis();
+
+            let mut distances = distances
+                .into_iter()
+                .map(|d| d.into_inner())
+                .collect::<Vec<_>>();
+            let summary = post_process(&mut distances, post_processing);
+            let result = SsspResult {
+                compute_millis,
+                summary,
+            };
+
+            Ok((distances, result))
+        } else {
+            error!("Incompatible graph");
+            Err(Status::invalid_argument(
+                "Sssp requires a directed, weighted graph",
+            ))
+        }
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    let record_batches =
+        crate::catalog::to_record_batches(&distances, "distance", PhantomData::<Float32Type>).await;
+
+    info!("Done computing sssp: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
+}
+
+async fn compute_wcc(
+    config: WccConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    graph_name: String,
+    property_key: String,
+    mode: ComputeMode,
+    summary: bool,
+) -> FlightResult<arrow_flight::Result> {
+    let catalog_key = graph_name.clone();
+
+    let (components, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
+
+        if let GraphType::Directed(graph) = catalog.get(catalog_key)? {
+            let start = Instant::now();
+            let components = graph::wcc::wcc_afforest(graph, config).to_vec();
+            let compute_millis = start.elapsed().as_millis();
+            let summary = if summary {
+                ResultSummary::from_communities(&components)
+            } else {
+                None
+            };
+            let result = WccResult {
+                compute_millis,
+                summary,
+            };
+            Ok((components, result))
+        } else {
+            error!("Attempted running wcc on undirected graph");
+            Err(Status::invalid_argument("Wcc requires a directed graph"))
+        }
     })
     .await
-    .unwrap()?;
+    .map_err(from_join_error)??;
 
-    info!("Done converting graph to undirected: {result:?}");
-    into_flight_result(result)
+    let record_batches =
+        crate::catalog::to_record_batches(&components, "component", PhantomData::<UInt64Type>)
+            .await;
+
+    info!("Done computing wcc: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
 }
 
-async fn compute_page_rank(
-    config: PageRankConfig,
+async fn compute_partition(
+    config: PartitionConfig,
     graph_catalog: Arc<RwLock<GraphCatalog>>,
     property_store: Arc<RwLock<PropertyStore>>,
     graph_name: String,
     property_key: String,
+    mode: ComputeMode,
 ) -> FlightResult<arrow_flight::Result> {
     let catalog_key = graph_name.clone();
 
-    let (ranks, result) = tokio::task::spawn_blocking(move || {
-        let catalog = graph_catalog.read();
+    let (partitions, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
 
-        if let GraphType::Directed(graph) = catalog.get(catalog_key).unwrap() {
+        if let GraphType::Undirected(graph) = catalog.get(catalog_key)? {
             let start = Instant::now();
-            let (ranks, iterations, error) = graph::page_rank::page_rank(graph, config);
-            let result = PageRankResult {
-                iterations: iterations as u64,
-                error,
+            let partitioning = graph::partition::partition(graph, config);
+            let result = PartitionResult {
+                edge_cut: partitioning.edge_cut,
+                imbalance: partitioning.imbalance(),
                 compute_millis: start.elapsed().as_millis(),
             };
-            Ok((ranks, result))
+            let partitions = partitioning
+                .partitions
+                .into_iter()
+                .map(|p| p as u64)
+                .collect::<Vec<_>>();
+            Ok((partitions, result))
         } else {
-            error!("Attempted running page rank on undirected graph");
+            error!("Attempted running partitioning on directed graph");
             Err(Status::invalid_argument(
-                "Page Rank requires a directed graph",
+                "Partitioning requires an undirected graph",
             ))
         }
     })
     .await
-    .unwrap()?;
+    .map_err(from_join_error)??;
 
-    let property_id = PropertyId::new(graph_name, property_key);
     let record_batches =
-        crate::catalog::to_record_batches(&ranks, "page_rank", PhantomData::<Float32Type>).await;
-
-    property_store
-        .write()
-        .insert(property_id.clone(), record_batches);
+        crate::catalog::to_record_batches(&partitions, "partition", PhantomData::<UInt64Type>)
+            .await;
 
-    let result = MutateResult::new(property_id, result);
-    info!("Done computing page rank: {result:?}");
-    into_flight_result(result)
+    info!("Done computing partitioning: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
 }
 
-async fn compute_triangle_count(
+#[allow(clippy::too_many_arguments)]
+async fn compute_louvain(
+    config: LouvainConfig,
+    tracker: Arc<ComputeTracker>,
     graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
     graph_name: String,
+    property_key: String,
+    mode: ComputeMode,
+    summary: bool,
 ) -> FlightResult<arrow_flight::Result> {
-    let graph_name = graph_name.clone();
+    let catalog_key = graph_name.clone();
 
-    let result = tokio::task::spawn_blocking(move || {
-        let catalog = graph_catalog.read();
-        if let GraphType::Undirected(graph) = catalog.get(graph_name).unwrap() {
+    let (communities, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
+
+        if let GraphType::Undirected(graph) = catalog.get(catalog_key)? {
             let start = Instant::now();
-            let tc = graph::triangle_count::global_triangle_count(graph);
-            let res = TriangleCountResult {
-                triangle_count: tc,
-                compute_millis: start.elapsed().as_millis(),
+            let louvain = graph::louvain::louvain_with_progress(graph, config, &*tracker);
+            let compute_millis = start.elapsed().as_millis();
+            let summary = if summary {
+                ResultSummary::from_communities(&louvain.communities)
+            } else {
+                None
             };
-            Ok(res)
+            let result = LouvainResult {
+                modularity: louvain.modularity,
+                levels: louvain.levels as u64,
+                compute_millis,
+                summary,
+            };
+            Ok((louvain.communities, result))
         } else {
-            error!("Attempted running triangle count on directed graph");
+            error!("Attempted running louvain on directed graph");
             Err(Status::invalid_argument(
-                "Triangle count requires an undirected graph",
+                "Louvain requires an undirected graph",
             ))
         }
     })
     .await
-    .unwrap()?;
+    .map_err(from_join_error)??;
 
-    info!("Done computing triangle count: {result:?}");
-    into_flight_result(result)
+    let record_batches =
+        crate::catalog::to_record_batches(&communities, "community", PhantomData::<UInt64Type>)
+            .await;
+
+    info!("Done computing louvain: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
 }
 
-async fn compute_sssp(
-    config: DeltaSteppingConfig,
+#[allow(clippy::too_many_arguments)]
+async fn compute_label_propagation(
+    config: LabelPropagationConfig,
+    tracker: Arc<ComputeTracker>,
     graph_catalog: Arc<RwLock<GraphCatalog>>,
     property_store: Arc<RwLock<PropertyStore>>,
     graph_name: String,
     property_key: String,
+    mode: ComputeMode,
+    summary: bool,
 ) -> FlightResult<arrow_flight::Result> {
     let catalog_key = graph_name.clone();
 
-    let (distances, result) = tokio::task::spawn_blocking(move || {
-        let catalog = graph_catalog.read();
+    let (communities, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
 
-        if let GraphType::DirectedWeighted(graph) = catalog.get(catalog_key).unwrap() {
+        if let GraphType::Undirected(graph) = catalog.get(catalog_key)? {
             let start = Instant::now();
-            let distances = graph::sssp::delta_stepping(graph, config);
-            let result = SsspResult {
-                compute_millis: start.elapsed().as_millis(),
+            let (communities, iterations) =
+                graph::label_propagation::label_propagation_with_progress(graph, config, &*tracker);
+            let compute_millis = start.elapsed().as_millis();
+            let summary = if summary {
+                ResultSummary::from_communities(&communities)
+            } else {
+                None
             };
-
-            let distances = distances
-                .into_iter()
-                .map(|d| d.into_inner())
-                .collect::<Vec<_>>();
-
-            Ok((distances, result))
+            let result = LabelPropagationResult {
+                iterations: iterations as u64,
+                compute_millis,
+                summary,
+            };
+            Ok((communities, result))
         } else {
-            error!("Incompatible graph");
+            error!("Attempted running label propagation on directed graph");
             Err(Status::invalid_argument(
-                "Sssp requires a directed, weighted graph",
+                "Label propagation requires an undirected graph",
             ))
         }
     })
     .await
-    .unwrap()?;
+    .map_err(from_join_error)??;
 
-    let property_id = PropertyId::new(graph_name, property_key);
     let record_batches =
-        crate::catalog::to_record_batches(&distances, "distance", PhantomData::<Float32Type>).await;
+        crate::catalog::to_record_batches(&communities, "community", PhantomData::<UInt64Type>)
+            .await;
 
-    property_store
-        .write()
-        .insert(property_id.clone(), record_batches);
+    info!("Done computing label propagation: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
+}
 
-    let result = MutateResult::new(property_id, result);
-    info!("Done computing sssp: {result:?}");
-    into_flight_result(result)
+#[allow(clippy::too_many_arguments)]
+async fn compute_betweenness(
+    config: BetweennessConfig,
+    tracker: Arc<ComputeTracker>,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    graph_name: String,
+    property_key: String,
+    mode: ComputeMode,
+    post_processing: PostProcessing,
+) -> FlightResult<arrow_flight::Result> {
+    let catalog_key = graph_name.clone();
+
+    let (scores, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
+
+        let start = Instant::now();
+        let scores = match catalog.get(catalog_key)? {
+            GraphType::Directed(graph) => {
+                graph::betweenness::betweenness_with_progress(graph, config, &*tracker)
+            }
+            GraphType::Undirected(graph) => {
+                graph::betweenness::betweenness_undirected_with_progress(graph, config, &*tracker)
+            }
+            GraphType::DirectedWeighted(_) | GraphType::UndirectedWeighted(_) => {
+                error!("Attempted running betweenness on weighted graph");
+                return Err(Status::invalid_argument(
+                    "Betweenness requires an unweighted graph",
+                ));
+            }
+        };
+        let compute_millis = start.elapsed().as_millis();
+
+        let mut scores = scores
+            .into_iter()
+            .map(|score| score as f32)
+            .collect::<Vec<_>>();
+        let top_nodes = node_scores(&config, &scores);
+        let summary = post_process(&mut scores, post_processing);
+        let result = BetweennessResult {
+            compute_millis,
+            top_nodes,
+            summary,
+        };
+        Ok((scores, result))
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    let record_batches =
+        crate::catalog::to_record_batches(&scores, "betweenness", PhantomData::<Float32Type>).await;
+
+    info!("Done computing betweenness: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
 }
 
-async fn compute_wcc(
-    config: WccConfig,
+async fn compute_k_core(
+    tracker: Arc<ComputeTracker>,
     graph_catalog: Arc<RwLock<GraphCatalog>>,
     property_store: Arc<RwLock<PropertyStore>>,
     graph_name: String,
     property_key: String,
+    mode: ComputeMode,
 ) -> FlightResult<arrow_flight::Result> {
     let catalog_key = graph_name.clone();
 
-    let (components, result) = tokio::task::spawn_blocking(move || {
-        let catalog = graph_catalog.read();
+    let (core_numbers, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
 
-        if let GraphType::Directed(graph) = catalog.get(catalog_key).unwrap() {
+        if let GraphType::Undirected(graph) = catalog.get(catalog_key)? {
             let start = Instant::now();
-            let components = graph::wcc::wcc_afforest(graph, config);
-            let result = WccResult {
+            let core_numbers = graph::k_core::k_core_with_progress(graph, &*tracker);
+            let result = KCoreResult {
+                degeneracy: core_numbers.iter().copied().max().unwrap_or_default(),
                 compute_millis: start.elapsed().as_millis(),
             };
-            Ok((components.to_vec(), result))
+            Ok((core_numbers, result))
         } else {
-            error!("Attempted running page rank on undirected graph");
+            error!("Attempted running k-core on directed graph");
             Err(Status::invalid_argument(
-                "Page Rank requires a directed graph",
+                "K-core requires an undirected graph",
             ))
         }
     })
     .await
-    .unwrap()?;
+    .map_err(from_join_error)??;
 
-    let property_id = PropertyId::new(graph_name, property_key);
     let record_batches =
-        crate::catalog::to_record_batches(&components, "component", PhantomData::<UInt64Type>)
+        crate::catalog::to_record_batches(&core_numbers, "core_number", PhantomData::<UInt64Type>)
             .await;
 
-    property_store
-        .write()
-        .insert(property_id.clone(), record_batches);
+    info!("Done computing k-core: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
+}
 
-    let result = MutateResult::new(property_id, result);
-    info!("Done computing wcc: {result:?}");
-    into_flight_result(result)
+async fn compute_bfs(
+    config: BfsConfig,
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    graph_name: String,
+    property_key: String,
+    mode: ComputeMode,
+    post_processing: PostProcessing,
+) -> FlightResult<arrow_flight::Result> {
+    let catalog_key = graph_name.clone();
+    let BfsConfig {
+        start_node,
+        max_depth,
+    } = config;
+
+    let (distances, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
+        let graph = catalog.get(catalog_key)?;
+
+        let node_count = graph.node_count();
+        if start_node >= node_count {
+            return Err(Status::invalid_argument(format!(
+                "Start node {start_node} does not exist in a graph with {node_count} nodes"
+            )));
+        }
+
+        let start = Instant::now();
+        let max_depth = max_depth.map_or(usize::MAX, |depth| depth as usize);
+        let reached = match graph {
+            GraphType::Directed(g) => g.out_neighbors_within_with_distance(start_node, max_depth),
+            GraphType::Undirected(g) => g.neighbors_within_with_distance(start_node, max_depth),
+            GraphType::DirectedWeighted(g) => {
+                g.out_neighbors_within_with_distance(start_node, max_depth)
+            }
+            GraphType::UndirectedWeighted(g) => {
+                g.neighbors_within_with_distance(start_node, max_depth)
+            }
+        };
+        // Unreachable nodes keep an infinite distance, as in sssp.
+        let mut distances = vec![f32::INFINITY; node_count as usize];
+        distances[start_node as usize] = 0.0;
+        for &(node, distance) in &reached {
+            distances[node as usize] = distance as f32;
+        }
+        let compute_millis = start.elapsed().as_millis();
+
+        let summary = post_process(&mut distances, post_processing);
+        let result = BfsResult {
+            reached_nodes: reached.len() as u64 + 1,
+            compute_millis,
+            summary,
+        };
+        Ok((distances, result))
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    let record_batches =
+        crate::catalog::to_record_batches(&distances, "distance", PhantomData::<Float32Type>).await;
+
+    info!("Done computing bfs: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
+}
+
+async fn compute_degree_centrality(
+    graph_catalog: Arc<RwLock<GraphCatalog>>,
+    property_store: Arc<RwLock<PropertyStore>>,
+    graph_name: String,
+    property_key: String,
+    mode: ComputeMode,
+    post_processing: PostProcessing,
+) -> FlightResult<arrow_flight::Result> {
+    let catalog_key = graph_name.clone();
+
+    let (degrees, result) = tokio::task::spawn_blocking(move || {
+        let catalog = GraphCatalog::read_loaded(&graph_catalog, &catalog_key)?;
+        let graph = catalog.get(catalog_key)?;
+
+        let start = Instant::now();
+        // Directed graphs use the out-degree, as in the `stats` action.
+        let mut degrees = (0..graph.node_count())
+            .map(|node| graph.degree(node) as f32)
+            .collect::<Vec<_>>();
+        let compute_millis = start.elapsed().as_millis();
+
+        let summary = post_process(&mut degrees, post_processing);
+        let result = DegreeCentralityResult {
+            compute_millis,
+            summary,
+        };
+        Ok::<_, Status>((degrees, result))
+    })
+    .await
+    .map_err(from_join_error)??;
+
+    let record_batches =
+        crate::catalog::to_record_batches(&degrees, "degree", PhantomData::<Float32Type>).await;
+
+    info!("Done computing degree centrality: {result:?}");
+    let property_id = PropertyId::new(graph_name, property_key);
+    store_result(mode, property_store, property_id, record_batches, result)
+}
+
+/// Returns the highest-scoring nodes, if requested via the `top_k` option of
+/// the algorithm config.
+fn node_scores(config: &impl TopK, scores: &[f32]) -> Option<Vec<NodeScore>> {
+    config.top_nodes::<u64, _>(scores).map(|top_nodes| {
+        top_nodes
+            .into_iter()
+            .map(|(node_id, score)| NodeScore::new(node_id, score))
+            .collect()
+    })
+}
+
+/// Summarizes the scores, if requested, and normalizes them afterwards.
+fn post_process(scores: &mut [f32], post_processing: PostProcessing) -> Option<ResultSummary> {
+    let summary = if post_processing.summary {
+        ResultSummary::from_scores(scores)
+    } else {
+        None
+    };
+    if let Some(normalization) = post_processing.normalization {
+        graph::normalize::normalize(scores, normalization);
+    }
+    summary
+}
+
+/// Stores the result as node property in `mutate` mode or keeps it for a
+/// single `do_get` request in `stream` mode.
+fn store_result<T: Serialize>(
+    mode: ComputeMode,
+    property_store: Arc<RwLock<PropertyStore>>,
+    property_id: PropertyId,
+    record_batches: PropertyEntry,
+    algo_result: T,
+) -> FlightResult<arrow_flight::Result> {
+    match mode {
+        ComputeMode::Mutate => {
+            property_store
+                .write()
+                .insert(property_id.clone(), record_batches)?;
+            into_flight_result(MutateResult::new(property_id, algo_result))
+        }
+        ComputeMode::Stream => {
+            let ticket = property_store.write().insert_stream(record_batches);
+            into_flight_result(StreamResult::new(ticket, algo_result))
+        }
+    }
 }
 
 fn from_arrow_err(e: ArrowError) -> Status {
     Status::internal(format!("ArrowError: {e:?}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Int64Array;
+    use arrow::datatypes::Float64Type;
+    use graph::prelude::CsrLayout;
+    use tonic::Code;
+
+    use super::*;
+
+    /// Creates a catalog with the undirected graph `g`, which consists of two
+    /// triangles connected by the edge (2, 3).
+    fn two_triangles() -> Arc<RwLock<GraphCatalog>> {
+        let graph = GraphType::from_edge_list(
+            vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)],
+            Orientation::Undirected,
+            CsrLayout::Sorted,
+        );
+        let mut catalog = GraphCatalog::new();
+        catalog.insert("g", graph).unwrap();
+        Arc::new(RwLock::new(catalog))
+    }
+
+    /// Runs the algorithm on `g` in mutate mode and returns the algorithm
+    /// result together with the stored property values.
+    async fn compute_property(
+        algorithm: Algorithm,
+    ) -> Result<(serde_json::Value, Vec<f64>), Status> {
+        let property_store = Arc::new(RwLock::new(PropertyStore::new()));
+        let config = ComputeConfig {
+            graph_name: "g".to_string(),
+            algorithm,
+            property_key: "p".to_string(),
+            mode: ComputeMode::Mutate,
+            timeout_millis: None,
+            post_processing: PostProcessing::default(),
+        };
+        let result = compute(
+            config,
+            Arc::new(ComputeTracker::new()),
+            two_triangles(),
+            Arc::clone(&property_store),
+            Arc::new(Metrics::new()),
+        )
+        .await?;
+
+        let result = serde_json::from_slice::<serde_json::Value>(&result.body).unwrap();
+        let property_store = property_store.read();
+        let entry = property_store.get(&PropertyId::new("g".to_string(), "p".to_string()))?;
+        let values = entry
+            .batches
+            .iter()
+            .flat_map(|batch| {
+                let column = cast(batch.column(0), &DataType::Float64).unwrap();
+                arrow::array::as_primitive_array::<Float64Type>(&column)
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        Ok((result["algo_result"].clone(), values))
+    }
+
+    /// Returns a config that runs page rank on `g` until it is cancelled, as
+    /// the error never drops below a tolerance of zero.
+    fn endless_page_rank(timeout_millis: Option<u64>) -> ComputeConfig {
+        ComputeConfig {
+            graph_name: "g".to_string(),
+            algorithm: Algorithm::PageRank(PageRankConfig::new(usize::MAX, 0.0, 0.85)),
+            property_key: String::new(),
+            mode: ComputeMode::Stream,
+            timeout_millis,
+            post_processing: PostProcessing::default(),
+        }
+    }
+
+    fn directed_triangle() -> Arc<RwLock<GraphCatalog>> {
+        let graph = GraphType::from_edge_list(
+            vec![(0, 1), (1, 2), (2, 0)],
+            Orientation::Directed,
+            CsrLayout::Sorted,
+        );
+        let mut catalog = GraphCatalog::new();
+        catalog.insert("g", graph).unwrap();
+        Arc::new(RwLock::new(catalog))
+    }
+
+    /// Waits for the algorithm to release its read lock on the catalog.
+    async fn algorithm_stops(graph_catalog: Arc<RwLock<GraphCatalog>>) -> bool {
+        tokio::task::spawn_blocking(move || {
+            graph_catalog
+                .try_write_for(Duration::from_secs(10))
+                .is_some()
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Creates an edge batch whose columns are declared as nullable, which
+    /// passes [`check_edge_schema`].
+    fn edge_batch(sources: Vec<Option<i64>>, targets: Vec<Option<i64>>) -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("source", DataType::Int64, true),
+            Field::new("target", DataType::Int64, true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(sources)),
+                Arc::new(Int64Array::from(targets)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn put_graph_validates_edges() {
+        let mut edge_list = Vec::new();
+        read_edges(
+            &edge_batch(vec![Some(0), Some(1)], vec![Some(1), Some(2)]),
+            &mut edge_list,
+            None,
+        )
+        .unwrap();
+        assert_eq!(edge_list, vec![(0, 1), (1, 2)]);
+
+        let null_id = edge_batch(vec![Some(0), None], vec![Some(1), Some(2)]);
+        let negative_id = edge_batch(vec![Some(0), Some(1)], vec![Some(-1), Some(2)]);
+        for batch in [null_id, negative_id] {
+            let error = read_edges(&batch, &mut Vec::new(), None).unwrap_err();
+            assert_eq!(error.code(), Code::InvalidArgument);
+        }
+    }
+
+    #[test]
+    fn put_graph_validates_schema() {
+        let expected = edge_batch(vec![], vec![]).schema();
+        assert!(check_edge_schema(&expected, &expected).is_ok());
+
+        let schema = Schema::new(vec![
+            Field::new("source", DataType::Utf8, false),
+            Field::new("target", DataType::Int64, false),
+        ]);
+        let error = check_edge_schema(&schema, &expected).unwrap_err();
+        assert_eq!(error.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn create_graph_validates_body() {
+        let graph_catalog = Arc::new(RwLock::new(GraphCatalog::new()));
+        let from_gdl = |gdl: &str| {
+            CreateGraphConfig::FromGdl(CreateGraphFromGdlConfig {
+                graph_name: String::from("gdl"),
+                gdl: gdl.to_string(),
+                csr_layout: CsrLayout::Sorted,
+                orientation: Orientation::Directed,
+                weighted: true,
+            })
+        };
+        let from_edges = |edges: Vec<(u64, u64)>| {
+            CreateGraphConfig::FromEdges(CreateGraphFromEdgesConfig {
+                graph_name: String::from("edges"),
+                edges: InlineEdges::Unweighted(edges),
+                csr_layout: CsrLayout::Sorted,
+                orientation: Orientation::Directed,
+            })
+        };
+
+        for config in [
+            from_gdl("(a)-[{weight: 'heavy'}]->(b)"),
+            from_edges(vec![(0, u64::MAX)]),
+        ] {
+            let error = create_graph(config, Arc::clone(&graph_catalog))
+                .await
+                .unwrap_err();
+            assert_eq!(error.code(), Code::InvalidArgument);
+        }
+
+        create_graph(
+            from_gdl("(a)-[{weight: 2}]->(b)"),
+            Arc::clone(&graph_catalog),
+        )
+        .await
+        .unwrap();
+        create_graph(from_edges(vec![(0, 1)]), Arc::clone(&graph_catalog))
+            .await
+            .unwrap();
+        assert_eq!(graph_catalog.read().list(None).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn cancelled_job_stops() {
+        let graph_catalog = directed_triangle();
+        let job_registry = Arc::new(RwLock::new(JobRegistry::new()));
+        submit_job(
+            endless_page_rank(None),
+            Arc::clone(&graph_catalog),
+            Arc::new(RwLock::new(PropertyStore::new())),
+            Arc::clone(&job_registry),
+            Arc::new(Metrics::new()),
+        )
+        .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(graph_catalog.try_write().is_none());
+
+        job_registry.write().cancel(0).unwrap();
+        assert!(algorithm_stops(graph_catalog).await);
+    }
+
+    #[tokio::test]
+    async fn finished_jobs_are_evicted() {
+        let graph_catalog = directed_triangle();
+        let job_registry = Arc::new(RwLock::new(JobRegistry::new()));
+        job_registry.write().set_max_finished_jobs(2);
+
+        for job_id in 0..3 {
+            submit_job(
+                ComputeConfig {
+                    graph_name: "g".to_string(),
+                    algorithm: Algorithm::PageRank(PageRankConfig::default()),
+                    property_key: String::new(),
+                    mode: ComputeMode::Stream,
+                    timeout_millis: None,
+                    post_processing: PostProcessing::default(),
+                },
+                Arc::clone(&graph_catalog),
+                Arc::new(RwLock::new(PropertyStore::new())),
+                Arc::clone(&job_registry),
+                Arc::new(Metrics::new()),
+            )
+            .unwrap();
+
+            while job_registry.read().result(job_id).is_err() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        }
+
+        let registry = job_registry.read();
+        assert_eq!(registry.status(0).unwrap_err().code(), Code::NotFound);
+
+        let status = serde_json::to_value(registry.status(2).unwrap()).unwrap();
+        assert_eq!(status["state"], "Completed");
+        assert_eq!(status["progress"]["task"], "page_rank");
+        assert_eq!(
+            status["progress"]["total"],
+            PageRankConfig::DEFAULT_MAX_ITERATIONS
+        );
+    }
+
+    #[tokio::test]
+    async fn timed_out_computation_stops() {
+        let graph_catalog = directed_triangle();
+        let error = compute(
+            endless_page_rank(Some(100)),
+            Arc::new(ComputeTracker::new()),
+            Arc::clone(&graph_catalog),
+            Arc::new(RwLock::new(PropertyStore::new())),
+            Arc::new(Metrics::new()),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.code(), Code::DeadlineExceeded);
+        assert!(algorithm_stops(graph_catalog).await);
+    }
+
+    #[tokio::test]
+    async fn compute_communities() {
+        let (result, communities) = compute_property(Algorithm::Louvain(LouvainConfig::default()))
+            .await
+            .unwrap();
+        assert!(result["modularity"].as_f64().unwrap() > 0.0);
+        assert_eq!(communities[0], communities[1]);
+        assert_eq!(communities[0], communities[2]);
+        assert_eq!(communities[3], communities[5]);
+        assert_ne!(communities[0], communities[3]);
+
+        let (_, labels) = compute_property(Algorithm::LabelPropagation(
+            LabelPropagationConfig::default(),
+        ))
+        .await
+        .unwrap();
+        assert_eq!(labels[0], labels[1]);
+        assert_ne!(labels[0], labels[4]);
+    }
+
+    #[tokio::test]
+    async fn compute_centralities() {
+        let config = BetweennessConfig {
+            top_k: Some(2),
+            ..BetweennessConfig::default()
+        };
+        let (result, scores) = compute_property(Algorithm::Betweenness(config))
+            .await
+            .unwrap();
+        assert_eq!(scores, vec![0.0, 0.0, 6.0, 6.0, 0.0, 0.0]);
+        assert_eq!(
+            result["top_nodes"],
+            serde_json::json!([{"node_id": 2, "score": 6.0}, {"node_id": 3, "score": 6.0}])
+        );
+
+        let (_, degrees) = compute_property(Algorithm::DegreeCentrality).await.unwrap();
+        assert_eq!(degrees, vec![2.0, 2.0, 3.0, 3.0, 2.0, 2.0]);
+
+        let (result, core_numbers) = compute_property(Algorithm::KCore).await.unwrap();
+        assert_eq!(result["degeneracy"], 2);
+        assert_eq!(core_numbers, vec![2.0; 6]);
+    }
+
+    #[tokio::test]
+    async fn compute_bfs_distances() {
+        let config = BfsConfig {
+            start_node: 0,
+            max_depth: None,
+        };
+        let (result, distances) = compute_property(Algorithm::Bfs(config)).await.unwrap();
+        assert_eq!(result["reached_nodes"], 6);
+        assert_eq!(distances, vec![0.0, 1.0, 1.0, 2.0, 3.0, 3.0]);
+
+        let config = BfsConfig {
+            start_node: 0,
+            max_depth: Some(1),
+        };
+        let (result, distances) = compute_property(Algorithm::Bfs(config)).await.unwrap();
+        assert_eq!(result["reached_nodes"], 3);
+        assert_eq!(&distances[..3], &[0.0, 1.0, 1.0]);
+        assert!(distances[3..].iter().all(|d| d.is_infinite()));
+
+        let config = BfsConfig {
+            start_node: 6,
+            max_depth: None,
+        };
+        let error = compute_property(Algorithm::Bfs(config)).await.unwrap_err();
+        assert_eq!(error.code(), Code::InvalidArgument);
+    }
+}